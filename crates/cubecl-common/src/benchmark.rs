@@ -44,6 +44,61 @@ impl ProfileDuration {
     }
 }
 
+/// Profile of named markers placed between operations, each measured as the duration of the
+/// segment since the previous marker.
+#[derive(Default)]
+pub struct ProfileTimeline {
+    segments: Vec<(String, ProfileDuration)>,
+}
+
+/// A marker of a resolved [ProfileTimeline].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileMarker {
+    /// The name of the marker.
+    pub name: String,
+    /// The time elapsed between the start of the profile and the marker.
+    pub timestamp: Duration,
+    /// The time elapsed between the previous marker, or the start of the profile for the first
+    /// one, and this marker.
+    pub delta: Duration,
+}
+
+impl ProfileTimeline {
+    /// Add a marker at the end of the timeline, `segment` being the profile of everything executed
+    /// since the previous marker.
+    pub fn push(&mut self, name: String, segment: ProfileDuration) {
+        self.segments.push((name, segment));
+    }
+
+    /// The number of markers in the timeline.
+    pub fn len(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Whether the timeline doesn't have any marker.
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Resolve the timestamps of all the markers, in the order they were placed.
+    pub async fn resolve(self) -> Vec<ProfileMarker> {
+        let mut markers = Vec::with_capacity(self.segments.len());
+        let mut timestamp = Duration::ZERO;
+
+        for (name, segment) in self.segments {
+            let delta = segment.resolve().await;
+            timestamp += delta;
+            markers.push(ProfileMarker {
+                name,
+                timestamp,
+                delta,
+            });
+        }
+
+        markers
+    }
+}
+
 /// How a benchmark's execution times are measured.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -43,6 +43,7 @@ pub struct CacheOption {
     separator: Option<Vec<u8>>,
     version: Option<String>,
     root: Option<String>,
+    base_dir: Option<PathBuf>,
     lock_max_duration: Option<Duration>,
 }
 
@@ -89,7 +90,13 @@ impl CacheOption {
         self
     }
 
-    fn resolve(self) -> (Vec<u8>, String, String, Duration) {
+    /// The directory containing the root directory of the cache, "$HOME/.cache" by default.
+    pub fn base_dir<P: Into<PathBuf>>(mut self, base_dir: P) -> Self {
+        self.base_dir = Some(base_dir.into());
+        self
+    }
+
+    fn resolve(self) -> (Vec<u8>, String, String, Option<PathBuf>, Duration) {
         let separator = self.separator.unwrap_or_else(|| b"\n".to_vec());
         let version = self
             .version
@@ -99,7 +106,7 @@ impl CacheOption {
             .lock_max_duration
             .unwrap_or_else(|| Duration::from_secs(30));
 
-        (separator, root, version, duration)
+        (separator, root, version, self.base_dir, duration)
     }
 }
 
@@ -114,8 +121,8 @@ impl<T: Serialize + DeserializeOwned + PartialEq + Eq + Clone> CacheValue for T
 impl<K: CacheKey, V: CacheValue> Cache<K, V> {
     /// Create a new cache and load the data from the provided path if it exists.
     pub fn new<P: AsRef<Path>>(path: P, option: CacheOption) -> Self {
-        let (separator, root, version, lock_max_duration) = option.resolve();
-        let path = get_persistent_cache_file_path(path, root, version);
+        let (separator, root, version, base_dir, lock_max_duration) = option.resolve();
+        let path = get_persistent_cache_file_path(path, root, version, base_dir);
 
         let mut this = Self {
             in_memory_cache: HashMap::new(),
@@ -273,13 +280,17 @@ fn get_persistent_cache_file_path<P: AsRef<Path>>(
     path_partial: P,
     root: String,
     version: String,
+    base_dir: Option<PathBuf>,
 ) -> PathBuf {
     let path_partial: &Path = path_partial.as_ref();
-    let home_dir = dirs::home_dir().expect("An home directory should exist");
+    let base_dir = base_dir.unwrap_or_else(|| {
+        dirs::home_dir()
+            .expect("An home directory should exist")
+            .join(".cache")
+    });
     let add_extension = !path_partial.ends_with("json.log");
 
-    let mut path = home_dir
-        .join(".cache")
+    let mut path = base_dir
         .join(sanitize_path_segment(&root))
         .join(sanitize_path_segment(&version));
 
@@ -1,4 +1,5 @@
 use cubecl_common::ExecutionMode;
+pub use cubecl_runtime::server::CompilationError;
 
 use crate::{compute::KernelDefinition, ir::Elem};
 
@@ -14,13 +15,32 @@ pub trait Compiler: Sync + Send + 'static + Clone + core::fmt::Debug {
         kernel: KernelDefinition,
         compilation_options: &Self::CompilationOptions,
         mode: ExecutionMode,
-    ) -> Self::Representation;
+    ) -> Result<Self::Representation, CompilationError>;
     /// The size of the given element in bytes.
     fn elem_size(&self, elem: Elem) -> usize;
 
     /// The default extension for the runtime's kernel/shader code.
     /// Might change based on which compiler is used.
     fn extension(&self) -> &'static str;
+
+    /// The target the kernels are compiled for, visible to kernels during their expansion with
+    /// [compilation_target](crate::frontend::compilation_target).
+    fn target(&self) -> CompilationTarget;
+}
+
+/// The language or backend a [compiler](Compiler) generates code for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CompilationTarget {
+    /// CUDA C++, compiled with NVRTC.
+    Cuda,
+    /// HIP C++, compiled for AMD GPUs.
+    Hip,
+    /// Metal Shading Language.
+    Metal,
+    /// WGSL shaders.
+    Wgsl,
+    /// SPIR-V modules.
+    SpirV,
 }
 
 // We cannot put this struct in cubecl-wgpu crate due to circular dependencies.
@@ -28,4 +48,14 @@ pub trait Compiler: Sync + Send + 'static + Clone + core::fmt::Debug {
 pub struct WgpuCompilationOptions {
     pub supports_fp_fast_math: bool,
     pub supports_u64: bool,
+    /// Maximum amount of workgroup memory per cube in bytes, not validated when `None`.
+    pub max_shared_memory_size: Option<usize>,
+    /// Whether kernels declaring push constants can receive their scalars as push constants.
+    pub supports_push_constants: bool,
+    /// Whether bf16 values can be stored with the native SPIR-V type from `SPV_KHR_bfloat16`,
+    /// instead of being packed in `u16` bits.
+    pub supports_bf16: bool,
+    /// Smallest plane size the device can run kernels with, plane partitions are not validated
+    /// when `None`.
+    pub plane_size_min: Option<u32>,
 }
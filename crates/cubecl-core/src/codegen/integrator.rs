@@ -37,6 +37,32 @@ pub struct KernelOptions {
     pub debug_symbols: bool,
     pub fp_math_mode: FastMath,
     pub cluster_dim: Option<CubeDim>,
+    /// Preferred percentage of the unified L1 and shared memory to use as shared memory, see
+    /// [KernelSettings::shared_memory_carveout].
+    pub shared_memory_carveout: Option<u32>,
+    /// Send the scalars as push constants where supported, see [Push](crate::prelude::Push).
+    pub push_constants: bool,
+}
+
+/// Replaces the characters that aren't ASCII letters, digits or underscores by underscores. The
+/// name can't start with a digit nor with two underscores, which are reserved in WGSL.
+fn sanitize_kernel_name(name: &str) -> String {
+    let mut sanitized: String = name
+        .chars()
+        .map(|c| match c.is_ascii_alphanumeric() {
+            true => c,
+            false => '_',
+        })
+        .collect();
+
+    if sanitized.starts_with("__") {
+        sanitized = sanitized.trim_start_matches('_').to_string();
+    }
+    if sanitized.is_empty() || sanitized.starts_with(|c: char| c.is_ascii_digit()) {
+        sanitized.insert_str(0, "kernel_");
+    }
+
+    sanitized
 }
 
 impl KernelSettings {
@@ -47,10 +73,12 @@ impl KernelSettings {
         self
     }
 
-    /// Set kernel name.
+    /// Set kernel name, used as the entry point of the kernel.
+    ///
+    /// The name is sanitized into a valid identifier for every backend.
     #[allow(dead_code)]
     pub fn kernel_name<S: AsRef<str>>(mut self, name: S) -> Self {
-        self.options.kernel_name = name.as_ref().to_string();
+        self.options.kernel_name = sanitize_kernel_name(name.as_ref());
         self
     }
 
@@ -71,6 +99,19 @@ impl KernelSettings {
         self.options.cluster_dim = Some(cluster_dim);
         self
     }
+
+    /// Set the preferred percentage of the unified L1 and shared memory to use as shared memory,
+    /// from `0` to prefer L1 to `100` to prefer shared memory.
+    ///
+    /// Only a hint used on CUDA, other backends ignore it.
+    pub fn shared_memory_carveout(mut self, percent: u32) -> Self {
+        assert!(
+            percent <= 100,
+            "The carveout is a percentage, got {percent}"
+        );
+        self.options.shared_memory_carveout = Some(percent);
+        self
+    }
 }
 
 /// Information related to a buffer binding.
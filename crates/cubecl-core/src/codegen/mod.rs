@@ -1,8 +1,10 @@
 mod integrator;
 mod metadata;
+mod pass;
 
 mod compiler;
 
 pub use compiler::*;
 pub use integrator::*;
 pub use metadata::*;
+pub use pass::*;
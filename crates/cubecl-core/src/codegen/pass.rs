@@ -0,0 +1,104 @@
+use std::sync::{
+    Arc, RwLock,
+    atomic::{AtomicU64, Ordering},
+};
+
+use cubecl_ir::Scope;
+
+/// A transformation of the IR that runs on every kernel after the frontend expansion and before
+/// the backend compilation.
+///
+/// Passes run in the order they were registered with [register_compiler_pass], on the body of
+/// the kernel. The backends then run their own optimizations on the result.
+///
+/// # Invariants
+///
+/// A pass must leave the scope in a state the backends can still compile:
+///
+/// - Every variable read by an instruction must be a binding of the kernel, a builtin, a constant
+///   or a variable allocated through the [allocator](crate::ir::Allocator) of the scope.
+/// - The type of a variable must not change, and instructions must keep matching the types of
+///   their operands and outputs.
+/// - Nested scopes (branches and loops) must stay children of the scope they were created in.
+/// - The kernel bindings are not part of the scope, so a pass can't add or remove inputs and
+///   outputs.
+///
+/// The registered passes are part of the [kernel id](crate::KernelId), so a kernel compiled
+/// before a pass was registered or unregistered is compiled again with the new passes.
+///
+/// # Example
+///
+/// ```
+/// use cubecl_core::{CompilerPass, ir::Scope};
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// /// Counts the instructions at the root of every compiled kernel.
+/// #[derive(Default)]
+/// struct InstructionCounter {
+///     count: AtomicUsize,
+/// }
+///
+/// impl CompilerPass for InstructionCounter {
+///     fn run(&self, scope: &mut Scope) {
+///         self.count
+///             .fetch_add(scope.instructions.len(), Ordering::Relaxed);
+///     }
+/// }
+///
+/// let pass = cubecl_core::register_compiler_pass(InstructionCounter::default());
+/// // Compile and launch kernels...
+/// cubecl_core::unregister_compiler_pass(pass);
+/// ```
+pub trait CompilerPass: Send + Sync {
+    /// Transform the IR of the kernel body.
+    fn run(&self, scope: &mut Scope);
+}
+
+/// The identifier of a registered [compiler pass](CompilerPass), used to unregister it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CompilerPassId(u64);
+
+static COMPILER_PASSES: RwLock<Vec<(CompilerPassId, Arc<dyn CompilerPass>)>> =
+    RwLock::new(Vec::new());
+static NEXT_COMPILER_PASS_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Register a [compiler pass](CompilerPass) that runs on every kernel compiled from now on.
+pub fn register_compiler_pass<P: CompilerPass + 'static>(pass: P) -> CompilerPassId {
+    let id = CompilerPassId(NEXT_COMPILER_PASS_ID.fetch_add(1, Ordering::Relaxed));
+    COMPILER_PASSES.write().unwrap().push((id, Arc::new(pass)));
+    id
+}
+
+/// Remove a registered [compiler pass](CompilerPass), so it doesn't run on the kernels compiled
+/// from now on.
+pub fn unregister_compiler_pass(id: CompilerPassId) {
+    COMPILER_PASSES
+        .write()
+        .unwrap()
+        .retain(|(registered, _)| *registered != id);
+}
+
+/// Remove every registered [compiler pass](CompilerPass).
+pub fn clear_compiler_passes() {
+    COMPILER_PASSES.write().unwrap().clear();
+}
+
+/// The identifiers of the registered [compiler passes](CompilerPass), in order.
+pub(crate) fn compiler_pass_ids() -> Vec<CompilerPassId> {
+    COMPILER_PASSES
+        .read()
+        .unwrap()
+        .iter()
+        .map(|(id, _)| *id)
+        .collect()
+}
+
+/// Run the registered [compiler passes](CompilerPass) in order.
+pub(crate) fn run_compiler_passes(scope: &mut Scope) {
+    // Clone the passes first, so a pass can safely register other passes.
+    let passes = COMPILER_PASSES.read().unwrap().clone();
+
+    for (_, pass) in passes {
+        pass.run(scope);
+    }
+}
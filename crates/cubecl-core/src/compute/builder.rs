@@ -8,7 +8,20 @@ use crate::prelude::KernelDefinition;
 use crate::{BufferInfo, KernelSettings, ScalarInfo};
 use crate::{KernelExpansion, KernelIntegrator};
 
-use super::Visibility;
+use super::{ScalarBinding, Visibility};
+
+/// Maximum size in bytes of the scalars of a kernel using push constants, the minimum guaranteed
+/// by Vulkan.
+pub const MAX_PUSH_CONSTANTS_SIZE: usize = 128;
+
+/// Size in bytes of the push constant block holding the `scalars`, laid out in order as one array
+/// per type, each array aligned on the size of its type.
+pub fn push_constants_size(scalars: &[ScalarBinding]) -> usize {
+    scalars.iter().fold(0, |offset, binding| {
+        let size = binding.elem.size();
+        offset.next_multiple_of(size) + size * binding.count
+    })
+}
 
 /// Prepare a kernel to create a [kernel definition](crate::KernelDefinition).
 pub struct KernelBuilder {
@@ -17,6 +30,7 @@ pub struct KernelBuilder {
     buffers: Vec<BufferInfo>,
     scalars: BTreeMap<Elem, usize>,
     tensor_maps: Vec<Id>,
+    push_constants: bool,
 }
 
 impl KernelBuilder {
@@ -28,6 +42,13 @@ impl KernelBuilder {
         expand
     }
 
+    /// Register a scalar sent as a push constant and return the [element](ExpandElement) to be
+    /// used for kernel expansion.
+    pub fn push_constant(&mut self, elem: Elem) -> ExpandElement {
+        self.push_constants = true;
+        self.scalar(elem)
+    }
+
     fn buffer_id(&self) -> Id {
         self.buffers.len() as Id + self.tensor_maps.len() as Id
     }
@@ -102,7 +123,15 @@ impl KernelBuilder {
     }
 
     /// Build the [kernel definition](KernelDefinition).
-    pub fn build(self, settings: KernelSettings) -> KernelDefinition {
+    ///
+    /// The size of the push constants isn't checked here, compiling a kernel whose push constants
+    /// exceed [MAX_PUSH_CONSTANTS_SIZE] fails with
+    /// [PushConstantsExceeded](cubecl_runtime::server::CompilationError::PushConstantsExceeded).
+    pub fn build(self, mut settings: KernelSettings) -> KernelDefinition {
+        if self.push_constants {
+            settings.options.push_constants = true;
+        }
+
         let scalars = self
             .scalars
             .into_iter()
@@ -123,6 +152,7 @@ impl KernelBuilder {
             buffers: Default::default(),
             scalars: Default::default(),
             tensor_maps: Default::default(),
+            push_constants: false,
         }
     }
 }
@@ -1,11 +1,14 @@
 use std::{fmt::Display, marker::PhantomData};
 
-use crate::{Compiler, Kernel, KernelId, KernelOptions};
+use crate::{CompilationError, Compiler, Feature, Kernel, KernelId, KernelOptions};
 use alloc::sync::Arc;
 use cubecl_common::{CubeDim, ExecutionMode};
 use cubecl_ir::{Elem, Id, Item, Scope};
+use cubecl_runtime::DeviceProperties;
 use serde::{Deserialize, Serialize};
 
+use super::{KernelCost, RooflineEstimate};
+
 /// A kernel, compiled in the target language
 pub struct CompiledKernel<C: Compiler> {
     /// The name of the kernel entrypoint.
@@ -46,6 +49,108 @@ pub struct CompiledKernel<C: Compiler> {
     pub cube_dim: CubeDim,
     /// Extra debugging information about the compiled kernel.
     pub debug_info: Option<DebugInformation>,
+    /// Bindings expected by the kernel, see [bindings](CompiledKernel::bindings).
+    pub bindings: Vec<BindingInfo>,
+    /// Operations and global memory traffic of a unit, see
+    /// [roofline_estimate](CompiledKernel::roofline_estimate).
+    pub cost: KernelCost,
+}
+
+impl<C: Compiler> CompiledKernel<C> {
+    /// The ordered list of bindings expected by the kernel.
+    ///
+    /// Buffers and tensor maps come first in declaration order, followed by the scalars
+    /// grouped by element type, matching the order in which the launcher registers arguments.
+    pub fn bindings(&self) -> &[BindingInfo] {
+        &self.bindings
+    }
+
+    /// Estimate where the kernel sits on the roofline of the device with the given properties,
+    /// from the floating-point operations and the global memory traffic of a unit counted in its
+    /// IR, see [KernelCost].
+    ///
+    /// The ridge point and the attainable throughput are only known when the device reports its
+    /// peak throughput and bandwidth.
+    pub fn roofline_estimate(&self, properties: &DeviceProperties<Feature>) -> RooflineEstimate {
+        self.cost.roofline_estimate(properties)
+    }
+}
+
+/// Description of a binding expected by a [compiled kernel](CompiledKernel).
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct BindingInfo {
+    /// The kind of argument bound.
+    pub kind: BindingKind,
+    /// The item of the binding, with its element type and line size.
+    ///
+    /// Tensor maps aren't typed and always report a boolean item.
+    pub item: Item,
+    /// Whether the kernel can write to the binding.
+    pub visibility: Visibility,
+}
+
+/// Kind of argument bound to a kernel.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum BindingKind {
+    /// A tensor, with its shape and strides in the metadata.
+    Tensor,
+    /// An array, with only its length in the metadata.
+    Array,
+    /// A tensor map used for TMA loads.
+    TensorMap,
+    /// A group of `count` uniform scalars of the same element type.
+    Scalar {
+        /// Number of scalars of this element type.
+        count: usize,
+    },
+}
+
+impl BindingInfo {
+    fn from_definition(definition: &KernelDefinition) -> Vec<Self> {
+        let mut buffers = definition
+            .buffers
+            .iter()
+            .map(|binding| {
+                let kind = match binding.has_extended_meta {
+                    true => BindingKind::Tensor,
+                    false => BindingKind::Array,
+                };
+                (
+                    binding.id,
+                    BindingInfo {
+                        kind,
+                        item: binding.item,
+                        visibility: binding.visibility,
+                    },
+                )
+            })
+            .chain(definition.tensor_maps.iter().map(|id| {
+                (
+                    *id,
+                    BindingInfo {
+                        kind: BindingKind::TensorMap,
+                        item: Item::new(Elem::Bool),
+                        visibility: Visibility::Read,
+                    },
+                )
+            }))
+            .collect::<Vec<_>>();
+        buffers.sort_by_key(|(id, _)| *id);
+
+        let scalars = definition.scalars.iter().map(|scalar| BindingInfo {
+            kind: BindingKind::Scalar {
+                count: scalar.count,
+            },
+            item: Item::new(scalar.elem),
+            visibility: Visibility::Read,
+        });
+
+        buffers
+            .into_iter()
+            .map(|(_, binding)| binding)
+            .chain(scalars)
+            .collect()
+    }
 }
 
 /// Extra debugging information about the compiled kernel.
@@ -248,7 +353,7 @@ pub trait CubeTask<C: Compiler>: Send + Sync {
         compiler: &mut C,
         compilation_options: &C::CompilationOptions,
         mode: ExecutionMode,
-    ) -> CompiledKernel<C>;
+    ) -> Result<CompiledKernel<C>, CompilationError>;
     fn name(&self) -> &'static str {
         core::any::type_name::<Self>()
     }
@@ -267,24 +372,43 @@ impl<C: Compiler, K: Kernel> CubeTask<C> for KernelTask<C, K> {
         compiler: &mut C,
         compilation_options: &C::CompilationOptions,
         mode: ExecutionMode,
-    ) -> CompiledKernel<C> {
-        let gpu_ir = self.kernel_definition.define();
+    ) -> Result<CompiledKernel<C>, CompilationError> {
+        let mut gpu_ir = crate::frontend::with_compilation_target(compiler.target(), || {
+            self.kernel_definition.define()
+        });
+        crate::codegen::run_compiler_passes(&mut gpu_ir.body);
+        if gpu_ir.options.push_constants {
+            let requested = super::push_constants_size(&gpu_ir.scalars);
+            if requested > super::MAX_PUSH_CONSTANTS_SIZE {
+                return Err(CompilationError::PushConstantsExceeded {
+                    requested,
+                    limit: super::MAX_PUSH_CONSTANTS_SIZE,
+                });
+            }
+        }
         let entrypoint_name = gpu_ir.options.kernel_name.clone();
         let cube_dim = gpu_ir.cube_dim;
-        let lower_level_ir = compiler.compile(gpu_ir, compilation_options, mode);
+        let bindings = BindingInfo::from_definition(&gpu_ir);
+        let cost = KernelCost::from_scope(&gpu_ir.body);
+        let lower_level_ir = compiler.compile(gpu_ir, compilation_options, mode)?;
 
-        CompiledKernel {
+        Ok(CompiledKernel {
             entrypoint_name,
             debug_name: Some(core::any::type_name::<K>()),
             source: lower_level_ir.to_string(),
             repr: Some(lower_level_ir),
             cube_dim,
             debug_info: None,
-        }
+            bindings,
+            cost,
+        })
     }
 
     fn id(&self) -> KernelId {
-        self.kernel_definition.id().clone()
+        self.kernel_definition
+            .id()
+            .clone()
+            .compiler_passes(crate::codegen::compiler_pass_ids())
     }
 
     fn name(&self) -> &'static str {
@@ -298,7 +422,7 @@ impl<C: Compiler> CubeTask<C> for Arc<dyn CubeTask<C>> {
         compiler: &mut C,
         compilation_options: &C::CompilationOptions,
         mode: ExecutionMode,
-    ) -> CompiledKernel<C> {
+    ) -> Result<CompiledKernel<C>, CompilationError> {
         self.as_ref().compile(compiler, compilation_options, mode)
     }
 
@@ -316,7 +440,7 @@ impl<C: Compiler> CubeTask<C> for Box<dyn CubeTask<C>> {
         compiler: &mut C,
         compilation_options: &C::CompilationOptions,
         mode: ExecutionMode,
-    ) -> CompiledKernel<C> {
+    ) -> Result<CompiledKernel<C>, CompilationError> {
         self.as_ref().compile(compiler, compilation_options, mode)
     }
 
@@ -1,4 +1,4 @@
-use std::marker::PhantomData;
+use std::{fmt::Display, marker::PhantomData};
 
 use crate::MetadataBuilder;
 use crate::compute::KernelTask;
@@ -6,7 +6,7 @@ use crate::prelude::{ArrayArg, TensorArg, TensorMapArg};
 use crate::{Kernel, Runtime};
 use crate::{KernelSettings, prelude::CubePrimitive};
 use bytemuck::{AnyBitPattern, NoUninit};
-use cubecl_runtime::server::{Binding, CubeCount, ScalarBinding, TensorMapBinding};
+use cubecl_runtime::server::{Binding, CubeCount, Handle, ScalarBinding, TensorMapBinding};
 use cubecl_runtime::{client::ComputeClient, server::Bindings};
 
 /// Prepare a kernel for [launch](KernelLauncher::launch).
@@ -25,6 +25,7 @@ pub struct KernelLauncher<R: Runtime> {
     scalar_i16: ScalarState<i16>,
     scalar_i8: ScalarState<i8>,
     pub settings: KernelSettings,
+    allowed_aliases: Vec<(usize, usize)>,
     runtime: PhantomData<R>,
 }
 
@@ -44,6 +45,18 @@ impl<R: Runtime> KernelLauncher<R> {
         self.tensors.push_array(array);
     }
 
+    /// Allow the buffers at the given positions in the registered bindings to point to overlapping
+    /// memory, e.g. the same handle bound to two arguments.
+    ///
+    /// Aliased buffers are rejected by [try_launch](KernelLauncher::try_launch), since a unit
+    /// writing to one of them can clobber what another unit reads from the other. The framework
+    /// trusts that the kernel accesses the allowed pairs safely, for instance by only reading
+    /// them.
+    pub fn allow_aliasing(&mut self, first: usize, second: usize) {
+        self.allowed_aliases
+            .push((first.min(second), first.max(second)));
+    }
+
     /// Register a u8 scalar to be launched.
     pub fn register_u8(&mut self, scalar: u8) {
         self.scalar_u8.push(scalar);
@@ -105,6 +118,9 @@ impl<R: Runtime> KernelLauncher<R> {
     }
 
     /// Launch the kernel.
+    ///
+    /// The bindings aren't validated, see [try_launch](Self::try_launch) for launches that can
+    /// fail.
     pub fn launch<K: Kernel>(
         self,
         cube_count: CubeCount,
@@ -117,6 +133,20 @@ impl<R: Runtime> KernelLauncher<R> {
         client.execute(kernel, cube_count, bindings);
     }
 
+    /// Launch the kernel after validating its bindings and cube count, returning an error instead
+    /// of panicking when they can't be used on the device.
+    pub fn try_launch<K: Kernel>(
+        self,
+        cube_count: CubeCount,
+        kernel: K,
+        client: &ComputeClient<R::Server, R::Channel>,
+    ) -> Result<(), LaunchError> {
+        self.validate(&cube_count, client)?;
+        self.launch(cube_count, kernel, client);
+
+        Ok(())
+    }
+
     /// Launch the kernel without check bounds.
     ///
     /// # Safety
@@ -139,6 +169,80 @@ impl<R: Runtime> KernelLauncher<R> {
         }
     }
 
+    fn validate(
+        &self,
+        cube_count: &CubeCount,
+        client: &ComputeClient<R::Server, R::Channel>,
+    ) -> Result<(), LaunchError> {
+        let properties = client.properties().hardware_properties();
+        self.validate_indexing()?;
+
+        if let TensorState::Some {
+            buffers,
+            tensor_maps,
+            buffer_sizes,
+            ..
+        } = &self.tensors
+        {
+            let requested = (buffers.len() + tensor_maps.len()) as u32;
+            if requested > properties.max_bindings {
+                return Err(LaunchError::TooManyBindings {
+                    requested,
+                    limit: properties.max_bindings,
+                });
+            }
+
+            for (index, buffer) in buffer_sizes.iter().enumerate() {
+                if buffer.required > buffer.size {
+                    return Err(LaunchError::BufferTooSmall {
+                        index,
+                        required: buffer.required,
+                        size: buffer.size,
+                    });
+                }
+            }
+
+            for (first, buffer) in buffer_sizes.iter().enumerate() {
+                for (second, other) in buffer_sizes.iter().enumerate().skip(first + 1) {
+                    if buffer.handle.aliases(&other.handle)
+                        && !self.allowed_aliases.contains(&(first, second))
+                    {
+                        return Err(LaunchError::AliasedBuffers { first, second });
+                    }
+                }
+            }
+        }
+
+        if let CubeCount::Static(x, y, z) = *cube_count {
+            let max = properties.max_cube_count;
+            if x > max.x || y > max.y || z > max.z {
+                return Err(LaunchError::CubeCountExceeded {
+                    requested: (x, y, z),
+                    limit: (max.x, max.y, max.z),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The metadata of the buffers is made of 32-bit integers, so any value that doesn't fit would
+    /// saturate, and bounds checks would reject the indexes past it.
+    fn validate_indexing(&self) -> Result<(), LaunchError> {
+        if let TensorState::Some { buffer_sizes, .. } = &self.tensors {
+            for (index, buffer) in buffer_sizes.iter().enumerate() {
+                if buffer.max_index > MAX_INDEX {
+                    return Err(LaunchError::IndexOverflow {
+                        index,
+                        required: buffer.max_index,
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// We need to create the bindings in the same order they are defined in the compilation step.
     ///
     /// The function [crate::KernelIntegrator::integrate] stars by registering the input tensors followed
@@ -170,6 +274,80 @@ impl<R: Runtime> KernelLauncher<R> {
     }
 }
 
+/// Error that happened while validating a kernel launch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LaunchError {
+    /// More buffers are bound than the device allows.
+    TooManyBindings {
+        /// The number of buffers bound to the kernel.
+        requested: u32,
+        /// The maximum number of bindings supported by the device.
+        limit: u32,
+    },
+    /// A buffer is smaller than the data its shape and strides describe.
+    BufferTooSmall {
+        /// The position of the buffer in the registered bindings.
+        index: usize,
+        /// The number of bytes accessible through the argument.
+        required: u64,
+        /// The size of the buffer, in bytes.
+        size: u64,
+    },
+    /// A buffer is addressed with indexes that don't fit in the 32-bit integers of the metadata.
+    IndexOverflow {
+        /// The position of the buffer in the registered bindings.
+        index: usize,
+        /// The largest index or metadata value of the argument, in elements.
+        required: u64,
+    },
+    /// The cube count is bigger than the device allows in at least one dimension.
+    CubeCountExceeded {
+        /// The requested cube count.
+        requested: (u32, u32, u32),
+        /// The maximum cube count supported by the device.
+        limit: (u32, u32, u32),
+    },
+    /// Two buffers point to overlapping memory without being
+    /// [allowed to alias](KernelLauncher::allow_aliasing).
+    AliasedBuffers {
+        /// The position of the first buffer in the registered bindings.
+        first: usize,
+        /// The position of the second buffer in the registered bindings.
+        second: usize,
+    },
+}
+
+impl Display for LaunchError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::TooManyBindings { requested, limit } => write!(
+                f,
+                "The kernel is launched with {requested} bindings, but the device only supports {limit}."
+            ),
+            Self::BufferTooSmall {
+                index,
+                required,
+                size,
+            } => write!(
+                f,
+                "The buffer {index} has {size} bytes, but its argument requires {required} bytes."
+            ),
+            Self::IndexOverflow { index, required } => write!(
+                f,
+                "The buffer {index} is addressed up to {required}, but the metadata only supports indexes up to {MAX_INDEX}."
+            ),
+            Self::CubeCountExceeded { requested, limit } => write!(
+                f,
+                "The cube count {requested:?} exceeds the maximum cube count {limit:?} of the device."
+            ),
+            Self::AliasedBuffers { first, second } => write!(
+                f,
+                "The buffers {first} and {second} point to overlapping memory, but aren't allowed to alias."
+            ),
+        }
+    }
+}
+
 /// Handles the tensor state.
 pub enum TensorState<R: Runtime> {
     /// No tensor is registered yet.
@@ -179,10 +357,35 @@ pub enum TensorState<R: Runtime> {
         buffers: Vec<Binding>,
         tensor_maps: Vec<TensorMapBinding>,
         metadata: MetadataBuilder,
+        buffer_sizes: Vec<BufferSize>,
         runtime: PhantomData<R>,
     },
 }
 
+/// The largest index supported by the metadata of the tensors, shared by every backend since
+/// lengths, shapes and strides are 32-bit unsigned integers.
+///
+/// Metadata values past it saturate instead of wrapping around. Kernels can still address larger
+/// buffers on backends with 64-bit integers, using 64-bit indexes with
+/// [index_unchecked_u64](crate::prelude::Array::index_unchecked_u64) in an unchecked launch, since
+/// bounds checks rely on the metadata.
+pub const MAX_INDEX: u64 = u32::MAX as u64;
+
+/// A metadata value, saturated at [MAX_INDEX].
+fn metadata_value(value: u64) -> u32 {
+    value.min(MAX_INDEX) as u32
+}
+
+/// The size of a buffer, along with the size required by the argument bound to it.
+pub struct BufferSize {
+    required: u64,
+    size: u64,
+    /// The largest index or metadata value of the argument.
+    max_index: u64,
+    /// The handle bound, to detect aliased buffers.
+    handle: Handle,
+}
+
 /// Handles the scalar state of an element type
 ///
 /// The scalars are grouped to reduce the number of buffers needed to send data to the compute device.
@@ -200,6 +403,7 @@ impl<R: Runtime> TensorState<R> {
                 buffers: Vec::new(),
                 tensor_maps: Vec::new(),
                 metadata: MetadataBuilder::default(),
+                buffer_sizes: Vec::new(),
                 runtime: PhantomData,
             };
         }
@@ -221,6 +425,14 @@ impl<R: Runtime> TensorState<R> {
         tensor_maps
     }
 
+    fn buffer_sizes(&mut self) -> &mut Vec<BufferSize> {
+        self.maybe_init();
+        let TensorState::Some { buffer_sizes, .. } = self else {
+            panic!("Should be init");
+        };
+        buffer_sizes
+    }
+
     fn metadata(&mut self) -> &mut MetadataBuilder {
         self.maybe_init();
         let TensorState::Some { metadata, .. } = self else {
@@ -231,8 +443,34 @@ impl<R: Runtime> TensorState<R> {
 
     /// Push a new input tensor to the state.
     pub fn push_tensor(&mut self, tensor: &TensorArg<'_, R>) {
-        if let Some(tensor) = self.process_tensor(tensor) {
-            self.buffers().push(tensor);
+        if let Some(binding) = self.process_tensor(tensor) {
+            if let TensorArg::Handle { handle, .. } = tensor {
+                let num_elems = match handle.shape.contains(&0) {
+                    true => 0,
+                    false => {
+                        let last = handle
+                            .shape
+                            .iter()
+                            .zip(handle.strides)
+                            .map(|(shape, stride)| (shape - 1) * stride)
+                            .sum::<usize>();
+                        last + 1
+                    }
+                };
+                let len = handle.shape.iter().product::<usize>();
+                let metadata = handle.shape.iter().chain(handle.strides).max();
+                let buffer_len = handle.handle.size() / handle.elem_size as u64;
+                let max_index = (num_elems.max(len) as u64)
+                    .max(metadata.copied().unwrap_or(0) as u64)
+                    .max(buffer_len);
+                self.buffer_sizes().push(BufferSize {
+                    required: (num_elems * handle.elem_size) as u64,
+                    size: handle.handle.size(),
+                    max_index,
+                    handle: handle.handle.clone(),
+                });
+            }
+            self.buffers().push(binding);
         }
     }
 
@@ -251,18 +489,35 @@ impl<R: Runtime> TensorState<R> {
         let len = tensor.shape.iter().product::<usize>() / *vectorization as usize;
         self.metadata().with_tensor(
             tensor.strides.len() as u32,
-            buffer_len as u32,
-            len as u32,
-            tensor.shape.iter().map(|it| *it as u32).collect(),
-            tensor.strides.iter().map(|it| *it as u32).collect(),
+            metadata_value(buffer_len),
+            metadata_value(len as u64),
+            tensor
+                .shape
+                .iter()
+                .map(|it| metadata_value(*it as u64))
+                .collect(),
+            tensor
+                .strides
+                .iter()
+                .map(|it| metadata_value(*it as u64))
+                .collect(),
         );
         Some(tensor.handle.clone().binding())
     }
 
     /// Push a new input array to the state.
     pub fn push_array(&mut self, array: &ArrayArg<'_, R>) {
-        if let Some(tensor) = self.process_array(array) {
-            self.buffers().push(tensor);
+        if let Some(binding) = self.process_array(array) {
+            if let ArrayArg::Handle { handle, .. } = array {
+                let buffer_len = handle.handle.size() / handle.elem_size as u64;
+                self.buffer_sizes().push(BufferSize {
+                    required: (handle.length[0] * handle.elem_size) as u64,
+                    size: handle.handle.size(),
+                    max_index: (handle.length[0] as u64).max(buffer_len),
+                    handle: handle.handle.clone(),
+                });
+            }
+            self.buffers().push(binding);
         }
     }
 
@@ -278,8 +533,10 @@ impl<R: Runtime> TensorState<R> {
 
         let elem_size = array.elem_size * *vectorization as usize;
         let buffer_len = array.handle.size() / elem_size as u64;
-        self.metadata()
-            .with_array(buffer_len as u32, array.length[0] as u32);
+        self.metadata().with_array(
+            metadata_value(buffer_len),
+            metadata_value(array.length[0] as u64),
+        );
         Some(array.handle.clone().binding())
     }
 
@@ -351,6 +608,7 @@ impl<R: Runtime> Default for KernelLauncher<R> {
             scalar_i16: ScalarState::Empty,
             scalar_i8: ScalarState::Empty,
             settings: Default::default(),
+            allowed_aliases: Vec::new(),
             runtime: PhantomData,
         }
     }
@@ -1,7 +1,9 @@
 mod builder;
 mod kernel;
 mod launcher;
+mod roofline;
 
 pub use builder::*;
 pub use kernel::*;
 pub use launcher::*;
+pub use roofline::*;
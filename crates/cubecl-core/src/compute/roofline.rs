@@ -0,0 +1,233 @@
+use alloc::vec::Vec;
+
+use cubecl_ir::{
+    Arithmetic, Branch, Elem, Instruction, Item, Operation, Operator, RangeLoop, Scope, Variable,
+    VariableKind,
+};
+use cubecl_runtime::DeviceProperties;
+
+use crate::Feature;
+
+/// The floating-point operations and the global memory traffic of a single unit of a kernel,
+/// counted from its IR.
+///
+/// Each lane of a [line](crate::frontend::Line) counts as a separate operation, and a fused
+/// multiply-add counts as two. Loops are counted once per iteration when their bounds are known at
+/// compile time, and once otherwise. Of the branches of a condition, only the most expensive is
+/// counted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KernelCost {
+    /// The floating-point operations of a unit.
+    pub flops: u64,
+    /// The bytes read from and written to global memory by a unit.
+    pub global_bytes: u64,
+}
+
+/// Where a kernel sits on the roofline of a device, from its [cost](KernelCost) and the peaks of
+/// the device.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RooflineEstimate {
+    /// The floating-point operations of a unit.
+    pub flops: u64,
+    /// The bytes read from and written to global memory by a unit.
+    pub global_bytes: u64,
+    /// The floating-point operations per byte of global memory traffic.
+    pub arithmetic_intensity: f64,
+    /// The arithmetic intensity above which the kernel is compute-bound on the device, if its
+    /// peaks are known.
+    pub ridge_point: Option<f64>,
+    /// The highest throughput in FLOP/s the kernel can reach on the device, if its peaks are
+    /// known.
+    pub attainable_flops: Option<f64>,
+}
+
+impl RooflineEstimate {
+    /// Whether the kernel is limited by the memory bandwidth rather than the compute throughput of
+    /// the device, if its peaks are known.
+    pub fn is_memory_bound(&self) -> Option<bool> {
+        self.ridge_point
+            .map(|ridge_point| self.arithmetic_intensity < ridge_point)
+    }
+}
+
+impl KernelCost {
+    /// Count the operations and the global memory traffic of the kernel body.
+    pub fn from_scope(scope: &Scope) -> Self {
+        CostCounter::default().count_scope(scope)
+    }
+
+    /// Place the kernel on the roofline of the device with the given properties.
+    pub fn roofline_estimate(&self, properties: &DeviceProperties<Feature>) -> RooflineEstimate {
+        let hardware = properties.hardware_properties();
+        let arithmetic_intensity = match self.global_bytes {
+            0 => f64::INFINITY,
+            bytes => self.flops as f64 / bytes as f64,
+        };
+        let peaks = hardware.peak_flops.zip(hardware.peak_memory_bandwidth);
+
+        RooflineEstimate {
+            flops: self.flops,
+            global_bytes: self.global_bytes,
+            arithmetic_intensity,
+            ridge_point: peaks.map(|(flops, bandwidth)| flops / bandwidth),
+            attainable_flops: peaks
+                .map(|(flops, bandwidth)| flops.min(bandwidth * arithmetic_intensity)),
+        }
+    }
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            flops: self.flops + other.flops,
+            global_bytes: self.global_bytes + other.global_bytes,
+        }
+    }
+
+    fn max(self, other: Self) -> Self {
+        Self {
+            flops: self.flops.max(other.flops),
+            global_bytes: self.global_bytes.max(other.global_bytes),
+        }
+    }
+
+    fn repeat(self, times: u64) -> Self {
+        Self {
+            flops: self.flops * times,
+            global_bytes: self.global_bytes * times,
+        }
+    }
+}
+
+/// Counts the cost of scopes, keeping track of the slices of global memory.
+#[derive(Default)]
+struct CostCounter {
+    global_slices: Vec<Variable>,
+}
+
+impl CostCounter {
+    fn count_scope(&mut self, scope: &Scope) -> KernelCost {
+        scope
+            .instructions
+            .iter()
+            .fold(KernelCost::default(), |cost, inst| {
+                cost.add(self.count_instruction(inst))
+            })
+    }
+
+    fn count_instruction(&mut self, inst: &Instruction) -> KernelCost {
+        match &inst.operation {
+            Operation::Arithmetic(op) => KernelCost {
+                flops: inst.out.map(|out| flops(op, out)).unwrap_or(0),
+                global_bytes: 0,
+            },
+            Operation::Operator(op) => KernelCost {
+                flops: 0,
+                global_bytes: self.global_bytes(op, inst.out),
+            },
+            Operation::Branch(branch) => self.count_branch(branch),
+            _ => KernelCost::default(),
+        }
+    }
+
+    fn count_branch(&mut self, branch: &Branch) -> KernelCost {
+        match branch {
+            Branch::If(if_) => self.count_scope(&if_.scope),
+            Branch::IfElse(if_else) => {
+                let cost_if = self.count_scope(&if_else.scope_if);
+                let cost_else = self.count_scope(&if_else.scope_else);
+                cost_if.max(cost_else)
+            }
+            Branch::Switch(switch) => switch.cases.iter().fold(
+                self.count_scope(&switch.scope_default),
+                |cost, (_, scope)| cost.max(self.count_scope(scope)),
+            ),
+            Branch::RangeLoop(range_loop) => self
+                .count_scope(&range_loop.scope)
+                .repeat(iterations(range_loop).unwrap_or(1)),
+            Branch::Loop(loop_) => self.count_scope(&loop_.scope),
+            Branch::Call(call) => self.count_scope(&call.scope),
+            Branch::Return | Branch::Break => KernelCost::default(),
+        }
+    }
+
+    fn global_bytes(&mut self, op: &Operator, out: Option<Variable>) -> u64 {
+        match op {
+            Operator::Index(op) | Operator::UncheckedIndex(op) if self.is_global(op.lhs) => {
+                out.map(|out| item_size(out.item)).unwrap_or(0)
+            }
+            Operator::IndexAssign(op) | Operator::UncheckedIndexAssign(op) => match out {
+                Some(out) if self.is_global(out) => item_size(op.rhs.item),
+                _ => 0,
+            },
+            Operator::CopyMemory(op) => {
+                let size = item_size(op.input.item);
+                let read = if self.is_global(op.input) { size } else { 0 };
+                let written = match out {
+                    Some(out) if self.is_global(out) => size,
+                    _ => 0,
+                };
+                read + written
+            }
+            Operator::Slice(op) if self.is_global(op.input) => {
+                self.global_slices.extend(out);
+                0
+            }
+            Operator::ReinterpretSlice(op) if self.is_global(op.input) => {
+                self.global_slices.extend(out);
+                0
+            }
+            _ => 0,
+        }
+    }
+
+    fn is_global(&self, var: Variable) -> bool {
+        match var.kind {
+            VariableKind::GlobalInputArray(_) | VariableKind::GlobalOutputArray(_) => true,
+            VariableKind::Slice { .. } => self.global_slices.contains(&var),
+            _ => false,
+        }
+    }
+}
+
+/// The floating-point operations of an arithmetic instruction, none if it isn't on floats.
+fn flops(op: &Arithmetic, out: Variable) -> u64 {
+    if !matches!(out.elem(), Elem::Float(_)) {
+        return 0;
+    }
+
+    let lanes = out.vectorization_factor() as u64;
+    match op {
+        Arithmetic::Fma(_) => 2 * lanes,
+        // Reduce the lanes of the input to a scalar, with a multiply-add per lane.
+        Arithmetic::Dot(op) => 2 * op.lhs.vectorization_factor() as u64,
+        Arithmetic::Magnitude(op) | Arithmetic::Normalize(op) => {
+            2 * op.input.vectorization_factor() as u64
+        }
+        _ => lanes,
+    }
+}
+
+fn item_size(item: Item) -> u64 {
+    let lanes = item.vectorization.map(|v| v.get()).unwrap_or(1) as u64;
+    item.elem.size() as u64 * lanes
+}
+
+/// The number of iterations of the loop, when its bounds are known at compile time.
+fn iterations(range_loop: &RangeLoop) -> Option<u64> {
+    let start = range_loop.start.as_const()?.try_as_i64()?;
+    let end = range_loop.end.as_const()?.try_as_i64()?;
+    let step = match range_loop.step {
+        Some(step) => step.as_const()?.try_as_i64()?,
+        None => 1,
+    };
+    let end = match (range_loop.inclusive, step > 0) {
+        (true, true) => end + 1,
+        (true, false) => end - 1,
+        (false, _) => end,
+    };
+
+    match step {
+        step if step > 0 && end > start => Some(((end - start) as u64).div_ceil(step as u64)),
+        step if step < 0 && start > end => Some(((start - end) as u64).div_ceil(-step as u64)),
+        _ => Some(0),
+    }
+}
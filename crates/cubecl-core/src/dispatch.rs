@@ -0,0 +1,121 @@
+//! Dispatch of runtime element types to generic host functions.
+
+#[doc(hidden)]
+pub use half::{bf16, f16};
+
+/// Call a generic function with the concrete type matching a runtime [element type](crate::ir::Elem).
+///
+/// The closure-like body is expanded once per supported type, with `T` bound to the concrete
+/// type. Element types that have no host equivalent (e.g. atomics or booleans) panic at runtime.
+///
+/// # Example
+///
+/// ```ignore
+/// dispatch_dtype!(elem, |T| {
+///     my_kernel::launch::<T, R>(&client, cube_count, cube_dim, input, output)
+/// });
+/// ```
+#[macro_export]
+macro_rules! dispatch_dtype {
+    ($elem:expr, |$ty:ident| $body:expr) => {{
+        use $crate::ir::{Elem, FloatKind, IntKind, UIntKind};
+
+        match $elem {
+            Elem::Float(FloatKind::F16) => {
+                type $ty = $crate::dispatch::f16;
+                $body
+            }
+            Elem::Float(FloatKind::BF16) => {
+                type $ty = $crate::dispatch::bf16;
+                $body
+            }
+            Elem::Float(FloatKind::Flex32) => {
+                type $ty = $crate::flex32;
+                $body
+            }
+            Elem::Float(FloatKind::F32) => {
+                type $ty = f32;
+                $body
+            }
+            Elem::Float(FloatKind::TF32) => {
+                type $ty = $crate::tf32;
+                $body
+            }
+            Elem::Float(FloatKind::F64) => {
+                type $ty = f64;
+                $body
+            }
+            Elem::Int(IntKind::I8) => {
+                type $ty = i8;
+                $body
+            }
+            Elem::Int(IntKind::I16) => {
+                type $ty = i16;
+                $body
+            }
+            Elem::Int(IntKind::I32) => {
+                type $ty = i32;
+                $body
+            }
+            Elem::Int(IntKind::I64) => {
+                type $ty = i64;
+                $body
+            }
+            Elem::UInt(UIntKind::U8) => {
+                type $ty = u8;
+                $body
+            }
+            Elem::UInt(UIntKind::U16) => {
+                type $ty = u16;
+                $body
+            }
+            Elem::UInt(UIntKind::U32) => {
+                type $ty = u32;
+                $body
+            }
+            Elem::UInt(UIntKind::U64) => {
+                type $ty = u64;
+                $body
+            }
+            other => panic!("Element type {other} is not supported by dispatch_dtype!"),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ir::{Elem, FloatKind, IntKind, UIntKind};
+    use crate::prelude::CubePrimitive;
+
+    fn dispatched(elem: Elem) -> Elem {
+        dispatch_dtype!(elem, |T| T::as_elem_native_unchecked())
+    }
+
+    #[test]
+    fn dispatch_dtype_selects_matching_type() {
+        let elems = [
+            Elem::Float(FloatKind::F16),
+            Elem::Float(FloatKind::BF16),
+            Elem::Float(FloatKind::F32),
+            Elem::Float(FloatKind::F64),
+            Elem::Int(IntKind::I8),
+            Elem::Int(IntKind::I16),
+            Elem::Int(IntKind::I32),
+            Elem::Int(IntKind::I64),
+            Elem::UInt(UIntKind::U8),
+            Elem::UInt(UIntKind::U16),
+            Elem::UInt(UIntKind::U32),
+            Elem::UInt(UIntKind::U64),
+        ];
+
+        for elem in elems {
+            assert_eq!(dispatched(elem), elem);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "not supported by dispatch_dtype")]
+    fn dispatch_dtype_unsupported_panics() {
+        dispatched(Elem::Bool);
+    }
+}
@@ -1,10 +1,12 @@
-use cubecl_ir::ExpandElement;
+use cubecl_ir::{Arithmetic, Elem, ExpandElement};
 use num_traits::NumCast;
 
+use crate::frontend::operation::base::binary_expand;
 use crate::ir::Switch;
-use crate::ir::{Branch, If, IfElse, Item, Loop, RangeLoop, Scope};
+use crate::ir::{Branch, If, IfElse, Instruction, Item, Loop, Operation, RangeLoop, Scope};
+use crate::unexpanded;
 
-use super::{CubePrimitive, CubeType, ExpandElementTyped, Int, Numeric, assign};
+use super::{Cast, CubePrimitive, CubeType, ExpandElementTyped, Int, Numeric, assign};
 
 /// Something that can be iterated on by a for loop. Currently only includes `Range`, `StepBy` and
 /// `Sequence`.
@@ -27,6 +29,22 @@ pub trait Iterable<T: CubeType>: Sized {
         scope: &mut Scope,
         body: impl FnMut(&mut Scope, <T as CubeType>::ExpandType),
     );
+    /// Expand a runtime loop partially unrolled by `factor`. Iterables that can't be partially
+    /// unrolled expand a runtime loop without unrolling.
+    ///
+    /// # Arguments
+    /// * `scope` - the expansion scope
+    /// * `factor` - the number of iterations executed by each iteration of the unrolled loop
+    /// * `body` - the loop body to be executed repeatedly
+    fn expand_partial_unroll(
+        self,
+        scope: &mut Scope,
+        factor: u32,
+        body: impl FnMut(&mut Scope, <T as CubeType>::ExpandType),
+    ) {
+        let _ = factor;
+        self.expand(scope, body);
+    }
 }
 
 pub struct RangeExpand<I: Int> {
@@ -63,18 +81,8 @@ impl<I: Int> Iterable<I> for RangeExpand<I> {
         scope: &mut Scope,
         mut body: impl FnMut(&mut Scope, <I as CubeType>::ExpandType),
     ) {
-        let start = self
-            .start
-            .expand
-            .as_const()
-            .expect("Only constant start can be unrolled.")
-            .as_i64();
-        let end = self
-            .end
-            .expand
-            .as_const()
-            .expect("Only constant end can be unrolled.")
-            .as_i64();
+        let start = comptime_bound(&self.start.expand, "start");
+        let end = comptime_bound(&self.end.expand, "end");
 
         if self.inclusive {
             for i in start..=end {
@@ -94,6 +102,9 @@ impl<I: Int> Iterable<I> for RangeExpand<I> {
         scope: &mut Scope,
         mut body: impl FnMut(&mut Scope, <I as CubeType>::ExpandType),
     ) {
+        let start = read_bound(scope, self.start.expand);
+        let end = read_bound(scope, self.end.expand);
+
         let mut child = scope.child();
         let index_ty = Item::new(I::as_elem(scope));
         let i = child.create_local_restricted(index_ty);
@@ -102,13 +113,30 @@ impl<I: Int> Iterable<I> for RangeExpand<I> {
 
         scope.register(Branch::RangeLoop(Box::new(RangeLoop {
             i: *i,
-            start: *self.start.expand,
-            end: *self.end.expand,
+            start: *start,
+            end: *end,
             step: None,
             scope: child,
             inclusive: self.inclusive,
         })));
     }
+
+    fn expand_partial_unroll(
+        self,
+        scope: &mut Scope,
+        factor: u32,
+        body: impl FnMut(&mut Scope, <I as CubeType>::ExpandType),
+    ) {
+        register_counted_loop(
+            scope,
+            self.start.expand,
+            self.end.expand,
+            None,
+            self.inclusive,
+            factor,
+            body,
+        );
+    }
 }
 
 pub struct SteppedRangeExpand<I: Int> {
@@ -124,6 +152,24 @@ impl<I: Int + Into<ExpandElement>> Iterable<I> for SteppedRangeExpand<I> {
         scope: &mut Scope,
         mut body: impl FnMut(&mut Scope, <I as CubeType>::ExpandType),
     ) {
+        // Range loops only count up, count the iterations of a descending range instead.
+        if constant_step::<I>(scope, &self.step.expand).is_some_and(|step| step < 0) {
+            register_counted_loop(
+                scope,
+                self.start.expand,
+                self.end.expand,
+                Some(self.step.expand),
+                self.inclusive,
+                1,
+                body,
+            );
+            return;
+        }
+
+        let start = read_bound(scope, self.start.expand);
+        let end = read_bound(scope, self.end.expand);
+        let step = read_bound(scope, self.step.expand);
+
         let mut child = scope.child();
         let index_ty = Item::new(I::as_elem(scope));
         let i = child.create_local_restricted(index_ty);
@@ -132,50 +178,212 @@ impl<I: Int + Into<ExpandElement>> Iterable<I> for SteppedRangeExpand<I> {
 
         scope.register(Branch::RangeLoop(Box::new(RangeLoop {
             i: *i,
-            start: *self.start.expand,
-            end: *self.end.expand,
-            step: Some(*self.step.expand),
+            start: *start,
+            end: *end,
+            step: Some(*step),
             scope: child,
             inclusive: self.inclusive,
         })));
     }
 
+    fn expand_partial_unroll(
+        self,
+        scope: &mut Scope,
+        factor: u32,
+        body: impl FnMut(&mut Scope, <I as CubeType>::ExpandType),
+    ) {
+        register_counted_loop(
+            scope,
+            self.start.expand,
+            self.end.expand,
+            Some(self.step.expand),
+            self.inclusive,
+            factor,
+            body,
+        );
+    }
+
     fn expand_unroll(
         self,
         scope: &mut Scope,
         mut body: impl FnMut(&mut Scope, <I as CubeType>::ExpandType),
     ) {
-        let start = self
-            .start
-            .expand
-            .as_const()
-            .expect("Only constant start can be unrolled.")
-            .as_i64();
-        let end = self
-            .end
-            .expand
-            .as_const()
-            .expect("Only constant end can be unrolled.")
-            .as_i64();
-        let step = self
-            .step
-            .expand
-            .as_const()
-            .expect("Only constant step can be unrolled.")
-            .as_usize();
+        let start = comptime_bound(&self.start.expand, "start");
+        let end = comptime_bound(&self.end.expand, "end");
+        comptime_bound(&self.step.expand, "step");
+        let step = constant_step::<I>(scope, &self.step.expand).unwrap();
+        assert_ne!(step, 0, "Can't iterate over a range with a step of 0.");
+
+        let in_range = |i: i64| match (step > 0, self.inclusive) {
+            (true, false) => i < end,
+            (true, true) => i <= end,
+            (false, false) => i > end,
+            (false, true) => i >= end,
+        };
+        let mut i = start;
+        while in_range(i) {
+            let var = I::from_int(i);
+            body(scope, var.into());
+            i += step;
+        }
+    }
+}
 
-        if self.inclusive {
-            for i in (start..=end).step_by(step) {
-                let var = I::from_int(i);
-                body(scope, var.into())
-            }
-        } else {
-            for i in (start..end).step_by(step) {
-                let var = I::from_int(i);
-                body(scope, var.into())
-            }
+/// The value of a loop bound, which must be known at compile time to fully unroll the loop.
+pub(crate) fn comptime_bound(bound: &ExpandElement, name: &str) -> i64 {
+    match bound.as_const() {
+        Some(value) => value.as_i64(),
+        None => panic!(
+            "Can't fully unroll a loop whose {name} is only known at runtime, use a comptime \
+             {name} or partially unroll the loop with `#[unroll(n)]`."
+        ),
+    }
+}
+
+/// Read a runtime loop bound once before the loop, like a Rust range, so that the body can't
+/// change the number of iterations by mutating the variable the bound was read from.
+fn read_bound(scope: &mut Scope, bound: ExpandElement) -> ExpandElement {
+    if bound.is_immutable() {
+        return bound;
+    }
+
+    let value = scope.create_local(bound.item);
+    scope.register(Instruction::new(Operation::Copy(*bound), *value));
+    value
+}
+
+/// The value of a constant step, as a signed value for signed indices.
+///
+/// Steps are always `u32`, so a negative step of a signed range is wrapped around when converted.
+/// `None` if the step is only known at runtime, in which case it can't be negative.
+fn constant_step<I: Int>(scope: &Scope, step: &ExpandElement) -> Option<i64> {
+    let step = step.as_const()?.as_i64();
+
+    match I::as_elem(scope) {
+        Elem::Int(_) => Some(step as u32 as i32 as i64),
+        _ => Some(step),
+    }
+}
+
+/// Registers a range loop partially unrolled by `factor`, or a loop over a descending range when
+/// `factor` is 1.
+///
+/// The number of iterations is computed before the loop, using the sign of the [constant
+/// step](constant_step). A main loop then runs the body replicated `factor` times per
+/// iteration, followed by a loop over the remaining iterations, both counting iterations instead
+/// of indices.
+fn register_counted_loop<I: Int>(
+    scope: &mut Scope,
+    start: ExpandElement,
+    end: ExpandElement,
+    step: Option<ExpandElement>,
+    inclusive: bool,
+    factor: u32,
+    mut body: impl FnMut(&mut Scope, ExpandElementTyped<I>),
+) {
+    assert!(factor > 0, "Can't unroll a loop by a factor of 0.");
+
+    let step_value = match &step {
+        Some(step) => constant_step::<I>(scope, step),
+        None => Some(1),
+    };
+    assert_ne!(
+        step_value,
+        Some(0),
+        "Can't iterate over a range with a step of 0."
+    );
+
+    let start = read_bound(scope, start);
+    let end = read_bound(scope, end);
+
+    let index_ty = Item::new(I::as_elem(scope));
+    let constant = |value: i64| -> ExpandElement {
+        let value: ExpandElementTyped<I> = I::from_int(value).into();
+        value.expand
+    };
+
+    // The distance covered by the range, the absolute value of the step and the step itself.
+    let (span, stride, step) = match step_value {
+        Some(step) if step < 0 => {
+            let end_excl = match inclusive {
+                true => binary_expand(scope, end, constant(1), Arithmetic::Sub),
+                false => end,
+            };
+            // Empty ranges cover no distance instead of a negative one.
+            let end_excl = binary_expand(scope, end_excl, start.clone(), Arithmetic::Min);
+            let span = binary_expand(scope, start.clone(), end_excl, Arithmetic::Sub);
+            (span, constant(-step), constant(step))
         }
+        step_value => {
+            let step = match step_value {
+                Some(step) => constant(step),
+                None => {
+                    let step = read_bound(scope, step.unwrap());
+                    <I as Cast>::__expand_cast_from(scope, ExpandElementTyped::<u32>::from(step))
+                        .expand
+                }
+            };
+            let end_excl = match inclusive {
+                true => binary_expand(scope, end, constant(1), Arithmetic::Add),
+                false => end,
+            };
+            let end_excl = binary_expand(scope, end_excl, start.clone(), Arithmetic::Max);
+            let span = binary_expand(scope, end_excl, start.clone(), Arithmetic::Sub);
+            (span, step.clone(), step)
+        }
+    };
+    let span = binary_expand(scope, span, stride.clone(), Arithmetic::Add);
+    let span = binary_expand(scope, span, constant(1), Arithmetic::Sub);
+    let iterations = binary_expand(scope, span, stride, Arithmetic::Div);
+
+    let factor_i = constant(factor as i64);
+    let (main_iterations, chunk) = match factor {
+        1 => (iterations.clone(), step.clone()),
+        _ => (
+            binary_expand(scope, iterations.clone(), factor_i.clone(), Arithmetic::Div),
+            binary_expand(scope, step.clone(), factor_i.clone(), Arithmetic::Mul),
+        ),
+    };
+    let offsets: Vec<_> = (1..factor)
+        .map(|k| binary_expand(scope, step.clone(), constant(k as i64), Arithmetic::Mul))
+        .collect();
+
+    let mut main = scope.child();
+    let n = main.create_local_restricted(index_ty);
+    let chunk_offset = binary_expand(&mut main, n.clone(), chunk, Arithmetic::Mul);
+    let base = binary_expand(&mut main, start.clone(), chunk_offset, Arithmetic::Add);
+    body(&mut main, base.clone().into());
+    for offset in offsets {
+        let i = binary_expand(&mut main, base.clone(), offset, Arithmetic::Add);
+        body(&mut main, i.into());
+    }
+    scope.register(Branch::RangeLoop(Box::new(RangeLoop {
+        i: *n,
+        start: *constant(0),
+        end: *main_iterations,
+        step: None,
+        scope: main,
+        inclusive: false,
+    })));
+
+    if factor == 1 {
+        return;
     }
+
+    let remainder_start = binary_expand(scope, main_iterations, factor_i, Arithmetic::Mul);
+    let mut remainder = scope.child();
+    let n = remainder.create_local_restricted(index_ty);
+    let offset = binary_expand(&mut remainder, n.clone(), step, Arithmetic::Mul);
+    let i = binary_expand(&mut remainder, start, offset, Arithmetic::Add);
+    body(&mut remainder, i.into());
+    scope.register(Branch::RangeLoop(Box::new(RangeLoop {
+        i: *n,
+        start: *remainder_start,
+        end: *iterations,
+        step: None,
+        scope: remainder,
+        inclusive: false,
+    })));
 }
 
 /// integer range. Equivalent to:
@@ -248,6 +456,54 @@ pub mod range_stepped {
     }
 }
 
+/// The indices processed by this unit in a grid-stride loop over `total_elements`. Equivalent to:
+///
+/// ```ignore
+/// (ABSOLUTE_POS..total_elements).step_by(CUBE_COUNT * CUBE_DIM)
+/// ```
+///
+/// Each index below `total_elements` is processed by exactly one unit, whatever the number of
+/// units launched, so kernels don't have to match their launch to the size of the input.
+///
+/// # Example
+///
+/// ```ignore
+/// #[cube(launch)]
+/// fn double(input: &Array<f32>, output: &mut Array<f32>) {
+///     for i in grid_stride_range(input.len()) {
+///         output[i] = input[i] * 2.0;
+///     }
+/// }
+/// ```
+pub fn grid_stride_range(total_elements: u32) -> core::iter::StepBy<core::ops::Range<u32>> {
+    let _ = total_elements;
+    unexpanded!()
+}
+
+pub mod grid_stride_range {
+    use cubecl_ir::Scope;
+
+    use crate::frontend::{ABSOLUTE_POS, CUBE_COUNT, CUBE_DIM, mul};
+    use crate::prelude::ExpandElementTyped;
+
+    use super::SteppedRangeExpand;
+
+    pub fn expand(
+        scope: &mut Scope,
+        total_elements: ExpandElementTyped<u32>,
+    ) -> SteppedRangeExpand<u32> {
+        let units = CUBE_DIM::expand(scope);
+        let cubes = CUBE_COUNT::expand(scope);
+
+        SteppedRangeExpand {
+            start: ABSOLUTE_POS::expand(scope),
+            end: total_elements,
+            step: mul::expand(scope, cubes, units),
+            inclusive: false,
+        }
+    }
+}
+
 pub fn for_expand<I: Numeric>(
     scope: &mut Scope,
     range: impl Iterable<I>,
@@ -261,6 +517,15 @@ pub fn for_expand<I: Numeric>(
     }
 }
 
+pub fn for_expand_partial_unroll<I: Numeric>(
+    scope: &mut Scope,
+    range: impl Iterable<I>,
+    factor: u32,
+    body: impl FnMut(&mut Scope, ExpandElementTyped<I>),
+) {
+    range.expand_partial_unroll(scope, factor, body);
+}
+
 pub fn if_expand(scope: &mut Scope, runtime_cond: ExpandElement, block: impl FnOnce(&mut Scope)) {
     let comptime_cond = runtime_cond.as_const().map(|it| it.as_bool());
     match comptime_cond {
@@ -0,0 +1,44 @@
+use cubecl_ir::{ExpandElement, FunctionCall, Scope, Variable};
+
+use super::{CubePrimitive, ExpandElementTyped};
+
+/// Values that can be returned by a helper marked `noinline`.
+///
+/// Only a single primitive can be copied out of the function, other types have to be written to
+/// a mutable argument instead.
+pub trait NoInlineReturn: Sized {
+    /// The variable holding the returned value, if any.
+    fn ret(&self) -> Option<Variable>;
+    /// Wraps the variable of the caller receiving the returned value.
+    fn from_out(out: Option<ExpandElement>) -> Self;
+}
+
+impl NoInlineReturn for () {
+    fn ret(&self) -> Option<Variable> {
+        None
+    }
+
+    fn from_out(_out: Option<ExpandElement>) -> Self {}
+}
+
+impl<T: CubePrimitive> NoInlineReturn for ExpandElementTyped<T> {
+    fn ret(&self) -> Option<Variable> {
+        Some(*self.expand)
+    }
+
+    fn from_out(out: Option<ExpandElement>) -> Self {
+        out.expect("Should have a variable receiving the returned value")
+            .into()
+    }
+}
+
+/// Registers the body of a `noinline` helper as a [call](FunctionCall) instead of expanding it
+/// in the scope of the caller.
+pub fn noinline_call_expand<R: NoInlineReturn>(
+    scope: &mut Scope,
+    name: &str,
+    body: impl FnOnce(&mut Scope) -> R,
+) -> R {
+    let out = FunctionCall::register(scope, name, |scope| body(scope).ret());
+    R::from_out(out)
+}
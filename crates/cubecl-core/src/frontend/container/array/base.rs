@@ -14,6 +14,12 @@ use crate::{
     prelude::{assign, index, index_assign},
 };
 
+/// The maximum number of elements of an array created with [from_data](Array::from_data).
+///
+/// The values are baked into the kernel source as a constant array literal, which backend
+/// compilers handle poorly past this size.
+pub const MAX_CONST_ARRAY_LEN: usize = 16384;
+
 /// A contiguous array of elements.
 pub struct Array<E> {
     _val: PhantomData<E>,
@@ -31,7 +37,11 @@ mod new {
             Array { _val: PhantomData }
         }
 
-        /// Create an array from data.
+        /// Create an array from data known at comptime, such as a host-computed `[T; N]` lookup
+        /// table.
+        ///
+        /// The values are baked into the kernel source as a constant array, so no buffer needs
+        /// to be bound or uploaded. The data can't have more than [MAX_CONST_ARRAY_LEN] elements.
         pub fn from_data<C: CubePrimitive>(_data: impl IntoIterator<Item = C>) -> Self {
             Array { _val: PhantomData }
         }
@@ -54,6 +64,12 @@ mod new {
             scope: &mut Scope,
             data: ArrayData<C>,
         ) -> <Self as CubeType>::ExpandType {
+            assert!(
+                data.values.len() <= MAX_CONST_ARRAY_LEN,
+                "Constant array of {} elements exceeds the limit of {MAX_CONST_ARRAY_LEN} elements \
+                 that can be baked into the kernel source. Upload the data in a buffer instead.",
+                data.values.len()
+            );
             let var = scope.create_const_array(Item::new(T::as_elem(scope)), data.values);
             ExpandElementTyped::new(var)
         }
@@ -288,7 +304,61 @@ mod indexation {
         }
     }
 
+    impl<E: CubePrimitive> Array<E> {
+        /// Perform an unchecked index into the array with a 64-bit index, to address arrays
+        /// with more than [MAX_INDEX](crate::compute::MAX_INDEX) elements on backends with
+        /// 64-bit integers.
+        ///
+        /// # Safety
+        /// Out of bounds indexing causes undefined behaviour and may segfault. Ensure index is
+        /// always in bounds
+        pub unsafe fn index_unchecked_u64(&self, _i: u64) -> &E {
+            unexpanded!()
+        }
+
+        /// Perform an unchecked index assignment into the array with a 64-bit index, see
+        /// [index_unchecked_u64](Array::index_unchecked_u64).
+        ///
+        /// # Safety
+        /// Out of bounds indexing causes undefined behaviour and may segfault. Ensure index is
+        /// always in bounds
+        pub unsafe fn index_assign_unchecked_u64(&mut self, _i: u64, _value: E) {
+            unexpanded!()
+        }
+    }
+
     impl<E: CubePrimitive> ExpandElementTyped<Array<E>> {
+        pub fn __expand_index_unchecked_u64_method(
+            self,
+            scope: &mut Scope,
+            i: ExpandElementTyped<u64>,
+        ) -> ExpandElementTyped<E> {
+            let out = scope.create_local(self.expand.item);
+            scope.register(Instruction::new(
+                Operator::UncheckedIndex(BinaryOperator {
+                    lhs: *self.expand,
+                    rhs: i.expand.consume(),
+                }),
+                *out,
+            ));
+            out.into()
+        }
+
+        pub fn __expand_index_assign_unchecked_u64_method(
+            self,
+            scope: &mut Scope,
+            i: ExpandElementTyped<u64>,
+            value: ExpandElementTyped<E>,
+        ) {
+            scope.register(Instruction::new(
+                Operator::UncheckedIndexAssign(BinaryOperator {
+                    lhs: i.expand.consume(),
+                    rhs: value.expand.consume(),
+                }),
+                *self.expand,
+            ));
+        }
+
         pub fn __expand_index_unchecked_method(
             self,
             scope: &mut Scope,
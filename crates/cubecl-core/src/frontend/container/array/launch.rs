@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 use crate::{
     Runtime,
     compute::{KernelBuilder, KernelLauncher},
-    ir::{Id, Item, Vectorization},
+    ir::{Elem, Id, Item, Vectorization},
     prelude::{
         ArgSettings, CompilationArg, CubePrimitive, ExpandElementTyped, LaunchArg, LaunchArgExpand,
         TensorHandleRef,
@@ -18,6 +18,7 @@ use super::Array;
 pub struct ArrayCompilationArg {
     pub inplace: Option<Id>,
     pub vectorisation: Vectorization,
+    pub stored_elem: Option<Elem>,
 }
 
 impl CompilationArg for ArrayCompilationArg {}
@@ -52,7 +53,8 @@ impl<C: CubePrimitive> LaunchArgExpand for Array<C> {
             Some(id) => builder.inplace_output(id).into(),
             None => builder
                 .output_array(Item::vectorized(
-                    C::as_elem(&builder.context),
+                    arg.stored_elem
+                        .unwrap_or_else(|| C::as_elem(&builder.context)),
                     arg.vectorisation,
                 ))
                 .into(),
@@ -67,6 +69,9 @@ pub enum ArrayArg<'a, R: Runtime> {
         handle: ArrayHandleRef<'a, R>,
         /// The vectorization factor.
         vectorization_factor: u8,
+        /// The element type the array is stored with, when it differs from the one the kernel
+        /// computes with. See [ArrayArg::stored_as].
+        stored_elem: Option<Elem>,
     },
     /// The array is aliasing another input array.
     Alias {
@@ -100,6 +105,7 @@ impl<'a, R: Runtime> ArrayArg<'a, R> {
                     E::size().expect("Element should have a size"),
                 ),
                 vectorization_factor,
+                stored_elem: None,
             }
         }
     }
@@ -119,9 +125,29 @@ impl<'a, R: Runtime> ArrayArg<'a, R> {
             ArrayArg::Handle {
                 handle: ArrayHandleRef::from_raw_parts(handle, length, elem_size),
                 vectorization_factor,
+                stored_elem: None,
             }
         }
     }
+
+    /// Store the output array as `E` while the kernel computes with its own element type. Every
+    /// store into the array casts the value to `E`.
+    ///
+    /// Same restrictions as [TensorArg::stored_as](crate::prelude::TensorArg::stored_as).
+    pub fn stored_as<E: CubePrimitive>(self) -> Self {
+        match self {
+            Self::Handle {
+                handle,
+                vectorization_factor,
+                ..
+            } => Self::Handle {
+                handle,
+                vectorization_factor,
+                stored_elem: Some(E::as_elem_native_unchecked()),
+            },
+            alias => alias,
+        }
+    }
 }
 
 impl<'a, R: Runtime> ArrayHandleRef<'a, R> {
@@ -164,14 +190,17 @@ impl<C: CubePrimitive> LaunchArg for Array<C> {
         match runtime_arg {
             ArrayArg::Handle {
                 vectorization_factor,
+                stored_elem,
                 ..
             } => ArrayCompilationArg {
                 inplace: None,
                 vectorisation: Vectorization::Some(NonZero::new(*vectorization_factor).unwrap()),
+                stored_elem: *stored_elem,
             },
             ArrayArg::Alias { input_pos } => ArrayCompilationArg {
                 inplace: Some(*input_pos as Id),
                 vectorisation: Vectorization::None,
+                stored_elem: None,
             },
         }
     }
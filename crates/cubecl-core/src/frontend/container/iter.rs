@@ -1,8 +1,12 @@
+use core::marker::PhantomData;
+
 use cubecl_ir::ExpandElement;
 
 use crate::{
+    frontend::branch::comptime_bound,
     ir::{Branch, Item, RangeLoop, Scope},
     prelude::{CubeIndex, CubePrimitive, CubeType, ExpandElementTyped, Iterable, index},
+    unexpanded,
 };
 
 use super::Array;
@@ -18,6 +22,116 @@ pub trait SizedContainer:
         let val: ExpandElementTyped<Array<Self::Item>> = val.clone().into();
         val.__expand_len_method(scope).expand
     }
+
+    /// Iterate over the elements of the container, in order.
+    ///
+    /// `for x in container.iter()` is lowered to an index loop over the length of the container,
+    /// with the element bound to `x` on each iteration.
+    fn iter(&self) -> ContainerIter<Self>
+    where
+        Self: Sized,
+    {
+        unexpanded!()
+    }
+}
+
+/// Iterator over the elements of a [sized container](SizedContainer).
+pub struct ContainerIter<T: SizedContainer> {
+    _container: PhantomData<T>,
+}
+
+impl<T: SizedContainer> ContainerIter<T> {
+    /// Iterate over the elements along with their index in the container.
+    pub fn enumerate(self) -> Enumerate<T> {
+        unexpanded!()
+    }
+}
+
+impl<T: SizedContainer> Iterator for ContainerIter<T> {
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        unexpanded!()
+    }
+}
+
+/// Iterator over the elements of a [sized container](SizedContainer) along with their `u32`
+/// index.
+pub struct Enumerate<T: SizedContainer> {
+    _container: PhantomData<T>,
+}
+
+impl<T: SizedContainer> Iterator for Enumerate<T> {
+    type Item = (u32, T::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        unexpanded!()
+    }
+}
+
+/// Expand type of [ContainerIter].
+pub struct ContainerIterExpand<T: SizedContainer> {
+    container: ExpandElementTyped<T>,
+}
+
+/// Expand type of [Enumerate].
+pub struct EnumerateExpand<T: SizedContainer> {
+    container: ExpandElementTyped<T>,
+}
+
+impl<T: SizedContainer> ExpandElementTyped<T> {
+    pub fn __expand_iter_method(self, _scope: &mut Scope) -> ContainerIterExpand<T> {
+        ContainerIterExpand { container: self }
+    }
+}
+
+impl<T: SizedContainer> ContainerIterExpand<T> {
+    pub fn __expand_enumerate_method(self, _scope: &mut Scope) -> EnumerateExpand<T> {
+        EnumerateExpand {
+            container: self.container,
+        }
+    }
+}
+
+/// Registers a loop over every index of `container`, calling `body` with the index and the
+/// element at that index.
+fn expand_indexed<T: SizedContainer>(
+    container: ExpandElementTyped<T>,
+    scope: &mut Scope,
+    mut body: impl FnMut(&mut Scope, ExpandElementTyped<u32>, ExpandElementTyped<T::Item>),
+) {
+    let len: ExpandElement = T::len(&container.expand, scope);
+    let index_ty = Item::new(u32::as_elem(scope));
+
+    let mut child = scope.child();
+    let i = child.create_local_restricted(index_ty);
+
+    let item = index::expand(&mut child, container, i.clone().into());
+    body(&mut child, i.clone().into(), item);
+
+    scope.register(Branch::RangeLoop(Box::new(RangeLoop {
+        i: *i,
+        start: 0u32.into(),
+        end: *len,
+        step: None,
+        inclusive: false,
+        scope: child,
+    })));
+}
+
+/// Unrolls the loop over every index of `container`, whose length must be known at compile time.
+fn expand_indexed_unroll<T: SizedContainer>(
+    container: ExpandElementTyped<T>,
+    scope: &mut Scope,
+    mut body: impl FnMut(&mut Scope, ExpandElementTyped<u32>, ExpandElementTyped<T::Item>),
+) {
+    let len: ExpandElement = T::len(&container.expand, scope);
+
+    for i in 0..comptime_bound(&len, "length") as u32 {
+        let i: ExpandElementTyped<u32> = i.into();
+        let item = index::expand(scope, container.clone(), i.clone());
+        body(scope, i, item);
+    }
 }
 
 impl<T: SizedContainer> Iterable<T::Item> for ExpandElementTyped<T> {
@@ -26,30 +140,54 @@ impl<T: SizedContainer> Iterable<T::Item> for ExpandElementTyped<T> {
         scope: &mut Scope,
         mut body: impl FnMut(&mut Scope, <T::Item as CubeType>::ExpandType),
     ) {
-        let index_ty = Item::new(u32::as_elem(scope));
-        let len: ExpandElement = T::len(&self.expand, scope);
+        expand_indexed(self, scope, |scope, _, item| body(scope, item));
+    }
 
-        let mut child = scope.child();
-        let i = child.create_local_restricted(index_ty);
+    fn expand_unroll(
+        self,
+        scope: &mut Scope,
+        mut body: impl FnMut(&mut Scope, <T::Item as CubeType>::ExpandType),
+    ) {
+        expand_indexed_unroll(self, scope, |scope, _, item| body(scope, item));
+    }
+}
 
-        let item = index::expand(&mut child, self, i.clone().into());
-        body(&mut child, item);
+impl<T: SizedContainer> Iterable<T::Item> for ContainerIterExpand<T> {
+    fn expand(
+        self,
+        scope: &mut Scope,
+        body: impl FnMut(&mut Scope, <T::Item as CubeType>::ExpandType),
+    ) {
+        Iterable::expand(self.container, scope, body);
+    }
 
-        scope.register(Branch::RangeLoop(Box::new(RangeLoop {
-            i: *i,
-            start: 0u32.into(),
-            end: *len,
-            step: None,
-            inclusive: false,
-            scope: child,
-        })));
+    fn expand_unroll(
+        self,
+        scope: &mut Scope,
+        body: impl FnMut(&mut Scope, <T::Item as CubeType>::ExpandType),
+    ) {
+        Iterable::expand_unroll(self.container, scope, body);
+    }
+}
+
+impl<T: SizedContainer> Iterable<(u32, T::Item)> for EnumerateExpand<T> {
+    fn expand(
+        self,
+        scope: &mut Scope,
+        mut body: impl FnMut(&mut Scope, <(u32, T::Item) as CubeType>::ExpandType),
+    ) {
+        expand_indexed(self.container, scope, |scope, i, item| {
+            body(scope, (i, item))
+        });
     }
 
     fn expand_unroll(
         self,
-        _scope: &mut Scope,
-        _body: impl FnMut(&mut Scope, <T::Item as CubeType>::ExpandType),
+        scope: &mut Scope,
+        mut body: impl FnMut(&mut Scope, <(u32, T::Item) as CubeType>::ExpandType),
     ) {
-        unimplemented!("Can't unroll array iterator")
+        expand_indexed_unroll(self.container, scope, |scope, i, item| {
+            body(scope, (i, item))
+        });
     }
 }
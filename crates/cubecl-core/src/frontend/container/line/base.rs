@@ -114,6 +114,46 @@ mod empty {
     }
 }
 
+/// Module that contains the implementation details of the cast function.
+mod cast {
+    use crate::prelude::cast;
+
+    use super::*;
+
+    impl<P: CubePrimitive> Line<P> {
+        /// Convert every element of the line to `T`, the line size is preserved.
+        ///
+        /// Conversions between `f32` and `f16` are packed two elements at a time on backends
+        /// supporting it, for example with `__float22half2_rn` on CUDA.
+        pub fn cast<T: CubePrimitive>(self) -> Line<T> {
+            unexpanded!()
+        }
+
+        /// Expand function of [cast](Self::cast).
+        pub fn __expand_cast<T: CubePrimitive>(
+            scope: &mut Scope,
+            line: ExpandElementTyped<Self>,
+        ) -> ExpandElementTyped<Line<T>> {
+            line.__expand_cast_method(scope)
+        }
+    }
+
+    impl<P: CubePrimitive> ExpandElementTyped<Line<P>> {
+        /// Expand method of [cast](Line::cast).
+        pub fn __expand_cast_method<T: CubePrimitive>(
+            self,
+            scope: &mut Scope,
+        ) -> ExpandElementTyped<Line<T>> {
+            let length = self.expand.item.vectorization;
+            let output = scope.create_local(Item::vectorized(T::as_elem(scope), length));
+
+            cast::expand::<Line<T>>(scope, self.expand.into(), output.clone().into());
+
+            output.into()
+        }
+    }
+}
+
 /// Module that contains the implementation details of the size function.
 mod size {
     use super::*;
@@ -289,6 +329,44 @@ mod bool_or {
     }
 }
 
+mod dot_i32 {
+    use cubecl_ir::IntKind;
+
+    use super::*;
+
+    impl Line<i8> {
+        /// Return the dot product of the lines accumulated into an `i32`, so it can't overflow
+        /// like the [dot](Dot::dot) product of `i8`.
+        ///
+        /// Lines of 4 elements are compiled to a single packed dot product instruction where
+        /// available, like `__dp4a` on CUDA and `OpSDot` on Vulkan.
+        pub fn dot_i32(self, _rhs: Self) -> i32 {
+            unexpanded!()
+        }
+
+        /// Expand function of [dot_i32](Self::dot_i32).
+        pub fn __expand_dot_i32(
+            scope: &mut Scope,
+            lhs: ExpandElementTyped<Self>,
+            rhs: ExpandElementTyped<Self>,
+        ) -> ExpandElementTyped<i32> {
+            lhs.__expand_dot_i32_method(scope, rhs)
+        }
+    }
+
+    impl ExpandElementTyped<Line<i8>> {
+        /// Expand method of [dot_i32](Line::dot_i32).
+        pub fn __expand_dot_i32_method(
+            self,
+            scope: &mut Scope,
+            rhs: Self,
+        ) -> ExpandElementTyped<i32> {
+            let item = Item::new(Elem::Int(IntKind::I32));
+            binary_expand_fixed_output(scope, self.expand, rhs.expand, item, Arithmetic::Dot).into()
+        }
+    }
+}
+
 impl<P: CubePrimitive> CubeType for Line<P> {
     type ExpandType = ExpandElementTyped<Self>;
 }
@@ -4,10 +4,10 @@ use num_traits::{NumCast, ToPrimitive};
 use crate::{
     frontend::{
         Abs, Ceil, Clamp, Cos, CubeIndex, CubeIndexMut, CubePrimitive, Erf, Exp,
-        ExpandElementTyped, Floor, Log, Log1p, Max, Min, Powf, Recip, Remainder, Round, Sin, Sqrt,
-        Tanh,
+        ExpandElementTyped, Floor, Log, Log1p, Max, Min, Powf, Recip, RecipFast, Remainder, Round,
+        RsqrtFast, Sin, Sqrt, Tanh,
     },
-    prelude::{BitwiseNot, CountOnes, FindFirstSet, LeadingZeros, ReverseBits},
+    prelude::{BitwiseNot, CountOnes, FindFirstSet, LeadingZeros, ReverseBits, TrailingZeros},
     unexpanded,
 };
 
@@ -245,6 +245,8 @@ impl<P: CubePrimitive + Cos> Cos for Line<P> {}
 impl<P: CubePrimitive + Sin> Sin for Line<P> {}
 impl<P: CubePrimitive + Tanh> Tanh for Line<P> {}
 impl<P: CubePrimitive + Recip> Recip for Line<P> {}
+impl<P: CubePrimitive + RecipFast> RecipFast for Line<P> {}
+impl<P: CubePrimitive + RsqrtFast> RsqrtFast for Line<P> {}
 impl<P: CubePrimitive + Remainder> Remainder for Line<P> {}
 impl<P: CubePrimitive + Round> Round for Line<P> {}
 impl<P: CubePrimitive + Floor> Floor for Line<P> {}
@@ -308,6 +310,37 @@ impl<P: LeadingZeros> ExpandElementTyped<Line<P>> {
     }
 }
 
+impl<P: TrailingZeros> Line<P> {
+    pub fn trailing_zeros(self) -> Line<u32> {
+        unexpanded!()
+    }
+
+    pub fn __expand_trailing_zeros(
+        scope: &mut Scope,
+        value: ExpandElementTyped<Self>,
+    ) -> ExpandElementTyped<Line<u32>> {
+        value.__expand_trailing_zeros_method(scope)
+    }
+}
+
+impl<P: TrailingZeros> ExpandElementTyped<Line<P>> {
+    pub fn __expand_trailing_zeros_method(
+        self,
+        scope: &mut Scope,
+    ) -> ExpandElementTyped<Line<u32>> {
+        let mut out_item = self.expand.item;
+        out_item.elem = Elem::UInt(UIntKind::U32);
+        let out = scope.create_local(out_item);
+        scope.register(Instruction::new(
+            Bitwise::TrailingZeros(UnaryOperator {
+                input: *self.expand,
+            }),
+            *out,
+        ));
+        out.into()
+    }
+}
+
 impl<P: FindFirstSet> Line<P> {
     pub fn find_first_set(self) -> Line<u32> {
         unexpanded!()
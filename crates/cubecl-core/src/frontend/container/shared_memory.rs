@@ -109,7 +109,7 @@ impl<T: CubePrimitive + Clone> SharedMemory<T> {
 
 /// Module that contains the implementation details of the index functions.
 mod indexation {
-    use cubecl_ir::Operator;
+    use cubecl_ir::{ClusterLoadOperator, Operator, Synchronization};
 
     use crate::{
         ir::{BinaryOperator, Instruction},
@@ -143,6 +143,30 @@ mod indexation {
         {
             unexpanded!()
         }
+
+        /// Synchronize all units in the cube, then load the value at index `i`. The loaded value
+        /// is guaranteed to be uniform across the cube, so writes to the shared memory made by any
+        /// unit before the call are visible to every unit.
+        ///
+        /// Both the call and the index must be uniform, i.e. every unit in the cube must execute
+        /// it with the same index. This is required by the WebGPU memory model to read values
+        /// written by other units, and maps to `workgroupUniformLoad` in WGSL. Other backends
+        /// emit a barrier before and after a regular load.
+        pub fn uniform_load(&self, _i: u32) -> E {
+            unexpanded!()
+        }
+
+        /// Load the value at index `i` from this shared memory in the cube `rank` of the cluster,
+        /// known as distributed shared memory. The rank ranges over
+        /// `0..CUBE_CLUSTER_DIM`, and [CUBE_POS_CLUSTER](crate::prelude::CUBE_POS_CLUSTER) is the
+        /// rank of the current cube.
+        ///
+        /// Writes made by the other cube are only visible after a [sync_cluster](crate::prelude::sync_cluster), and no cube may
+        /// exit while others can still read its shared memory. On targets without clusters, each
+        /// cube is its own cluster so this loads from the local shared memory.
+        pub fn cluster_load(&self, _rank: u32, _i: u32) -> E {
+            unexpanded!()
+        }
     }
 
     impl<E: CubePrimitive> ExpandElementTyped<SharedMemory<E>> {
@@ -162,6 +186,40 @@ mod indexation {
             out.into()
         }
 
+        pub fn __expand_uniform_load_method(
+            self,
+            scope: &mut Scope,
+            i: ExpandElementTyped<u32>,
+        ) -> ExpandElementTyped<E> {
+            let out = scope.create_local(self.expand.item);
+            scope.register(Instruction::new(
+                Synchronization::UniformLoad(BinaryOperator {
+                    lhs: *self.expand,
+                    rhs: i.expand.consume(),
+                }),
+                *out,
+            ));
+            out.into()
+        }
+
+        pub fn __expand_cluster_load_method(
+            self,
+            scope: &mut Scope,
+            rank: ExpandElementTyped<u32>,
+            i: ExpandElementTyped<u32>,
+        ) -> ExpandElementTyped<E> {
+            let out = scope.create_local(self.expand.item);
+            scope.register(Instruction::new(
+                Synchronization::ClusterLoad(ClusterLoadOperator {
+                    shared: *self.expand,
+                    rank: rank.expand.consume(),
+                    index: i.expand.consume(),
+                }),
+                *out,
+            ));
+            out.into()
+        }
+
         pub fn __expand_index_assign_unchecked_method(
             self,
             scope: &mut Scope,
@@ -12,6 +12,13 @@ use std::{marker::PhantomData, num::NonZero};
 
 /// The tensor type is similar to the [array type](crate::prelude::Array), however it comes with more
 /// metadata such as [stride](Tensor::stride) and [shape](Tensor::shape).
+///
+/// Shapes and strides are 32-bit unsigned integers on every backend, so offsets are correct up to
+/// [MAX_INDEX](crate::compute::MAX_INDEX) elements, past the range of `i32`. A [validated
+/// launch](crate::compute::KernelLauncher::try_launch) of a tensor that reaches further fails with
+/// [IndexOverflow](crate::compute::LaunchError::IndexOverflow). Larger tensors can be addressed
+/// with 64-bit offsets computed by the kernel, see
+/// [index_unchecked_u64](Tensor::index_unchecked_u64).
 #[derive(new)]
 pub struct Tensor<T: CubeType> {
     _val: PhantomData<T>,
@@ -255,7 +262,61 @@ mod indexation {
         }
     }
 
+    impl<E: CubePrimitive> Tensor<E> {
+        /// Perform an unchecked index into the tensor with a 64-bit index, to address tensors
+        /// with more than [MAX_INDEX](crate::compute::MAX_INDEX) elements on backends with
+        /// 64-bit integers.
+        ///
+        /// # Safety
+        /// Out of bounds indexing causes undefined behaviour and may segfault. Ensure index is
+        /// always in bounds
+        pub unsafe fn index_unchecked_u64(&self, _i: u64) -> &E {
+            unexpanded!()
+        }
+
+        /// Perform an unchecked index assignment into the tensor with a 64-bit index, see
+        /// [index_unchecked_u64](Tensor::index_unchecked_u64).
+        ///
+        /// # Safety
+        /// Out of bounds indexing causes undefined behaviour and may segfault. Ensure index is
+        /// always in bounds
+        pub unsafe fn index_assign_unchecked_u64(&mut self, _i: u64, _value: E) {
+            unexpanded!()
+        }
+    }
+
     impl<E: CubePrimitive> ExpandElementTyped<Tensor<E>> {
+        pub fn __expand_index_unchecked_u64_method(
+            self,
+            scope: &mut Scope,
+            i: ExpandElementTyped<u64>,
+        ) -> ExpandElementTyped<E> {
+            let out = scope.create_local(self.expand.item);
+            scope.register(Instruction::new(
+                Operator::UncheckedIndex(BinaryOperator {
+                    lhs: *self.expand,
+                    rhs: i.expand.consume(),
+                }),
+                *out,
+            ));
+            out.into()
+        }
+
+        pub fn __expand_index_assign_unchecked_u64_method(
+            self,
+            scope: &mut Scope,
+            i: ExpandElementTyped<u64>,
+            value: ExpandElementTyped<E>,
+        ) {
+            scope.register(Instruction::new(
+                Operator::UncheckedIndexAssign(BinaryOperator {
+                    lhs: i.expand.consume(),
+                    rhs: value.expand.consume(),
+                }),
+                *self.expand,
+            ));
+        }
+
         pub fn __expand_index_unchecked_method(
             self,
             scope: &mut Scope,
@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 use crate::{
     Runtime,
     compute::{KernelBuilder, KernelLauncher},
-    ir::{Id, Item, Vectorization},
+    ir::{Elem, Id, Item, Vectorization},
     prelude::{
         ArgSettings, CompilationArg, CubePrimitive, ExpandElementTyped, LaunchArg, LaunchArgExpand,
     },
@@ -22,6 +22,9 @@ pub enum TensorArg<'a, R: Runtime> {
         handle: TensorHandleRef<'a, R>,
         /// The vectorization factor.
         vectorization_factor: u8,
+        /// The element type the tensor is stored with, when it differs from the one the kernel
+        /// computes with. See [TensorArg::stored_as].
+        stored_elem: Option<Elem>,
     },
     /// The tensor is aliasing another input tensor.
     Alias {
@@ -61,6 +64,7 @@ impl<R: Runtime> core::fmt::Debug for TensorHandleRef<'_, R> {
 pub struct TensorCompilationArg {
     pub inplace: Option<Id>,
     pub vectorisation: Vectorization,
+    pub stored_elem: Option<Elem>,
 }
 
 impl CompilationArg for TensorCompilationArg {}
@@ -87,7 +91,8 @@ impl<C: CubePrimitive> LaunchArgExpand for Tensor<C> {
             Some(id) => builder.inplace_output(id).into(),
             None => builder
                 .output_tensor(Item::vectorized(
-                    C::as_elem(&builder.context),
+                    arg.stored_elem
+                        .unwrap_or_else(|| C::as_elem(&builder.context)),
                     arg.vectorisation,
                 ))
                 .into(),
@@ -102,14 +107,17 @@ impl<C: CubePrimitive> LaunchArg for Tensor<C> {
         match runtime_arg {
             TensorArg::Handle {
                 vectorization_factor,
+                stored_elem,
                 ..
             } => TensorCompilationArg {
                 inplace: None,
                 vectorisation: Vectorization::Some(NonZero::new(*vectorization_factor).unwrap()),
+                stored_elem: *stored_elem,
             },
             TensorArg::Alias { input_pos } => TensorCompilationArg {
                 inplace: Some(*input_pos as Id),
                 vectorisation: Vectorization::None,
+                stored_elem: None,
             },
         }
     }
@@ -137,6 +145,7 @@ impl<'a, R: Runtime> TensorArg<'a, R> {
                     E::size().expect("Element should have a size"),
                 ),
                 vectorization_factor: factor,
+                stored_elem: None,
             }
         }
     }
@@ -159,6 +168,7 @@ impl<'a, R: Runtime> TensorArg<'a, R> {
             Self::Handle {
                 handle: TensorHandleRef::from_raw_parts(handle, strides, shape, elem_size),
                 vectorization_factor: factor,
+                stored_elem: None,
             }
         }
     }
@@ -169,6 +179,28 @@ impl<'a, R: Runtime> TensorArg<'a, R> {
             input_pos: position,
         }
     }
+
+    /// Store the output tensor as `E` while the kernel computes with its own element type, e.g.
+    /// to compute in `f32` and write `f16` without a separate cast pass. Every store into the
+    /// tensor casts the value to `E`.
+    ///
+    /// Only output tensors written by the kernel support this: reading back from the tensor
+    /// in the kernel isn't supported, and the argument is ignored for inputs and aliases. The
+    /// handle must be created with the element size of `E`.
+    pub fn stored_as<E: CubePrimitive>(self) -> Self {
+        match self {
+            Self::Handle {
+                handle,
+                vectorization_factor,
+                ..
+            } => Self::Handle {
+                handle,
+                vectorization_factor,
+                stored_elem: Some(E::as_elem_native_unchecked()),
+            },
+            alias => alias,
+        }
+    }
 }
 
 impl<R: Runtime> ArgSettings<R> for TensorArg<'_, R> {
@@ -0,0 +1,68 @@
+use crate::prelude::*;
+use crate::{self as cubecl, unexpanded};
+
+/// Whether [device_assert!](crate::device_assert) records failures, which is the case in debug
+/// builds or when the `device-assert` feature is enabled. It compiles to nothing otherwise.
+pub const fn device_asserts_enabled() -> bool {
+    cfg!(any(debug_assertions, feature = "device-assert"))
+}
+
+/// Record `code` and the absolute position of the unit in `errors` when `cond` is false.
+///
+/// `errors` holds two values, the first code to fail followed by the position of the unit that
+/// failed it, and must be zeroed before the launch. Only the first failure is kept, so `code`
+/// should never be zero.
+pub fn device_assert(_errors: &Array<Atomic<u32>>, _cond: bool, _code: u32) {
+    unexpanded!()
+}
+
+/// Expand module of [device_assert()].
+pub mod device_assert {
+    use super::*;
+
+    /// Expand function of [device_assert()].
+    pub fn expand(
+        scope: &mut Scope,
+        errors: ExpandElementTyped<Array<Atomic<u32>>>,
+        cond: ExpandElementTyped<bool>,
+        code: ExpandElementTyped<u32>,
+    ) {
+        if device_asserts_enabled() {
+            record_failure::expand(scope, errors, cond, code);
+        }
+    }
+}
+
+#[cube]
+fn record_failure(errors: &Array<Atomic<u32>>, cond: bool, code: u32) {
+    if !cond {
+        if Atomic::compare_and_swap(&errors[0], 0, code) == 0 {
+            Atomic::store(&errors[1], ABSOLUTE_POS);
+        }
+    }
+}
+
+/// Check a condition on the device, recording `code` and the position of the first unit that
+/// fails it into a buffer read by the host after the launch. See [device_assert()].
+#[macro_export]
+macro_rules! device_assert {
+    ($errors:expr, $cond:expr, $code:expr) => {{
+        let _ = $errors;
+        let _ = $cond;
+        let _ = $code;
+    }};
+    ($errors:expr, $cond:expr, $code:expr,) => {
+        $crate::device_assert!($errors, $cond, $code)
+    };
+}
+
+/// Expand macro of [device_assert!](crate::device_assert).
+#[macro_export]
+macro_rules! device_assert_expand {
+    ($scope:expr, $errors:expr, $cond:expr, $code:expr) => {
+        $crate::frontend::device_assert::expand($scope, $errors, $cond, $code)
+    };
+    ($scope:expr, $errors:expr, $cond:expr, $code:expr,) => {
+        $crate::device_assert_expand!($scope, $errors, $cond, $code)
+    };
+}
@@ -4,9 +4,13 @@ use super::{
     ExpandElementBaseInit, ExpandElementTyped, Int, LaunchArgExpand, Numeric, init_expand_element,
 };
 use crate::{
+    self as cubecl,
     frontend::{CubePrimitive, CubeType},
     ir::{BinaryOperator, CompareAndSwapOperator, Elem, Instruction, Item, Scope, UnaryOperator},
-    prelude::KernelBuilder,
+    prelude::{
+        Array, KernelBuilder, Line, UNIT_POS_PLANE, cube, plane_broadcast, plane_exclusive_sum,
+        plane_sum,
+    },
     unexpanded,
 };
 
@@ -340,3 +344,45 @@ impl<Inner: CubePrimitive> LaunchArgExpand for Atomic<Inner> {
         builder.scalar(Self::as_elem_native_unchecked()).into()
     }
 }
+
+/// Atomically add each lane of `value` to the adjacent counters of the line at `index`, i.e. to
+/// `counters[index * line_size + i]`. Returns the old values of the counters.
+///
+/// Indexing by line keeps the counters contiguous and aligned on the line size. No backend currently
+/// exposes vectorized atomics for every element type, so each lane is lowered to a scalar atomic.
+#[cube]
+pub fn atomic_add_line<I: Numeric>(
+    counters: &Array<Atomic<I>>,
+    index: u32,
+    value: Line<I>,
+) -> Line<I> {
+    let line_size = value.size();
+    let offset = index * line_size;
+    let mut old = Line::empty(line_size);
+
+    #[unroll]
+    for i in 0..line_size {
+        old[i] = Atomic::add(&counters[offset + i], value[i]);
+    }
+
+    old
+}
+
+/// Atomically add `value` to the `counter` for every unit of the plane, with a single atomic
+/// operation per plane instead of one per unit. Returns the value of the counter before the
+/// contribution of the unit, as if the units of the plane had added their values one after the
+/// other, so it can be used as the slot of the unit when appending to a buffer.
+///
+/// Must be called by every unit of the plane, with `0` for units that don't contribute.
+#[cube]
+pub fn atomic_add_aggregated<I: Numeric>(counter: &Atomic<I>, value: I) -> I {
+    let offset = plane_exclusive_sum(value);
+    let total = plane_sum(value);
+
+    let mut base = I::from_int(0);
+    if UNIT_POS_PLANE == 0 {
+        base = Atomic::add(counter, total);
+    }
+
+    plane_broadcast(base, 0) + offset
+}
@@ -1,8 +1,8 @@
 use std::num::NonZero;
 
-use cubecl_ir::{ExpandElement, Operator};
+use cubecl_ir::{Elem, ExpandElement, FloatKind, Operator};
 
-use crate::frontend::{CubePrimitive, CubeType, cast};
+use crate::frontend::{CubePrimitive, CubeType, cast, stochastic_round_bits};
 use crate::ir::{Instruction, Item, Scope, UnaryOperator, Variable};
 use crate::unexpanded;
 
@@ -34,6 +34,119 @@ impl<P: CubePrimitive> Cast for P {
     }
 }
 
+/// Casts with an explicit rounding mode, e.g. to reproduce the reference implementation of a
+/// conversion from `f32` to `f16` or `bf16`.
+///
+/// The rounding of [Cast] is left to the target, some don't specify it for narrowing conversions.
+/// These lower to the rounding intrinsics of the target where it has them, like
+/// `__float2half_rz` on CUDA, and to an exact conversion in software otherwise. The software
+/// conversion only rounds casts from `f32`, other casts are left to the target.
+pub trait CastRounded: CubePrimitive {
+    /// Cast rounding to the nearest representable value, with ties to even.
+    #[allow(unused_variables)]
+    fn cast_rte<From: CubePrimitive>(value: From) -> Self {
+        unexpanded!()
+    }
+
+    /// Cast rounding toward zero, so the result is never larger in magnitude than the value.
+    /// Values too large for the target become its largest finite value.
+    #[allow(unused_variables)]
+    fn cast_rtz<From: CubePrimitive>(value: From) -> Self {
+        unexpanded!()
+    }
+
+    /// Cast an `f32` with stochastic rounding, rounding away from zero with a probability
+    /// proportional to the distance to the value toward zero.
+    ///
+    /// `random` holds uniformly distributed `u32` bits with the line size of the value, supplied
+    /// by the caller since there is no random number generator on the device, e.g. from a hash of
+    /// the position and a seed or from a buffer filled by the host. The rounding is only unbiased
+    /// when the bits are independent across the values and launches that are accumulated.
+    ///
+    /// The same bits always round the same way, with all bits unset it's the same as
+    /// [cast_rtz](CastRounded::cast_rtz). Values below the smallest normal `f16` are rounded with
+    /// the precision of normal values, so they're biased toward zero.
+    #[allow(unused_variables)]
+    fn cast_stochastic<From: CubePrimitive, Random: CubePrimitive>(
+        value: From,
+        random: Random,
+    ) -> Self {
+        unexpanded!()
+    }
+
+    fn __expand_cast_rte<From: CubePrimitive>(
+        scope: &mut Scope,
+        value: ExpandElementTyped<From>,
+    ) -> <Self as CubeType>::ExpandType {
+        cast_rounded::<From, Self>(scope, value, Operator::CastRte)
+    }
+
+    fn __expand_cast_rtz<From: CubePrimitive>(
+        scope: &mut Scope,
+        value: ExpandElementTyped<From>,
+    ) -> <Self as CubeType>::ExpandType {
+        cast_rounded::<From, Self>(scope, value, Operator::CastRtz)
+    }
+
+    fn __expand_cast_stochastic<From: CubePrimitive, Random: CubePrimitive>(
+        scope: &mut Scope,
+        value: ExpandElementTyped<From>,
+        random: ExpandElementTyped<Random>,
+    ) -> <Self as CubeType>::ExpandType {
+        let f32 = Elem::Float(FloatKind::F32);
+        assert_eq!(
+            value.expand.elem(),
+            f32,
+            "Stochastic rounding casts from f32"
+        );
+        assert_eq!(random.expand.elem().size(), 4, "Random bits should be u32");
+
+        // The bits of f32 mantissa not kept by the target.
+        let dropped_bits = match <Self as CubePrimitive>::as_elem(scope) {
+            Elem::Float(FloatKind::F16) | Elem::Float(FloatKind::TF32) => 13,
+            Elem::Float(FloatKind::BF16) => 16,
+            _ => 0,
+        };
+        let value = match dropped_bits {
+            0 => value.expand,
+            _ => {
+                stochastic_round_bits::expand(
+                    scope,
+                    value.expand.into(),
+                    random.expand.into(),
+                    dropped_bits,
+                )
+                .expand
+            }
+        };
+
+        cast_rounded::<f32, Self>(scope, value.into(), Operator::CastRtz)
+    }
+}
+
+impl<P: CubePrimitive> CastRounded for P {}
+
+fn cast_rounded<From: CubePrimitive, To: CubePrimitive>(
+    scope: &mut Scope,
+    value: ExpandElementTyped<From>,
+    operator: fn(UnaryOperator) -> Operator,
+) -> <To as CubeType>::ExpandType {
+    if core::any::TypeId::of::<To>() == core::any::TypeId::of::<From>() {
+        return value.expand.into();
+    }
+    let new_var = scope.create_local(Item::vectorized(
+        <To as CubePrimitive>::as_elem(scope),
+        value.expand.item.vectorization,
+    ));
+    scope.register(Instruction::new(
+        operator(UnaryOperator {
+            input: *value.expand,
+        }),
+        *new_var.clone(),
+    ));
+    new_var.into()
+}
+
 /// Enables reinterpetring the bits from any value to any other type of the same size.
 pub trait Reinterpret: CubePrimitive {
     /// Reinterpret the bits of another primitive as this primitive without conversion.
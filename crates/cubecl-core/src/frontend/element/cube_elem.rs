@@ -1,7 +1,7 @@
 use cubecl_ir::ExpandElement;
 use cubecl_runtime::{channel::ComputeChannel, client::ComputeClient, server::ComputeServer};
 
-use crate::ir::{Elem, Scope};
+use crate::ir::{Elem, FloatKind, Scope};
 use crate::{Feature, frontend::CubeType};
 
 use super::{ExpandElementBaseInit, ExpandElementTyped};
@@ -57,4 +57,49 @@ pub trait CubePrimitive:
     fn __expand_elem_size(context: &Scope) -> u32 {
         Self::as_elem(context).size() as u32
     }
+
+    /// Whether the element type is [f16](half::f16).
+    ///
+    /// The result is known when the kernel is expanded, so branching on it inside a generic
+    /// kernel only keeps the branch of the concrete type:
+    ///
+    /// ```ignore
+    /// if F::is_f16() {
+    ///     // Only compiled for the `f16` instantiation.
+    /// }
+    /// ```
+    fn is_f16() -> bool {
+        Self::as_elem_native() == Some(Elem::Float(FloatKind::F16))
+    }
+
+    fn __expand_is_f16(context: &Scope) -> bool {
+        Self::as_elem(context) == Elem::Float(FloatKind::F16)
+    }
+
+    /// Whether the element type is [bf16](half::bf16). See [is_f16](Self::is_f16).
+    fn is_bf16() -> bool {
+        Self::as_elem_native() == Some(Elem::Float(FloatKind::BF16))
+    }
+
+    fn __expand_is_bf16(context: &Scope) -> bool {
+        Self::as_elem(context) == Elem::Float(FloatKind::BF16)
+    }
+
+    /// Whether the element type is [f32]. See [is_f16](Self::is_f16).
+    fn is_f32() -> bool {
+        Self::as_elem_native() == Some(Elem::Float(FloatKind::F32))
+    }
+
+    fn __expand_is_f32(context: &Scope) -> bool {
+        Self::as_elem(context) == Elem::Float(FloatKind::F32)
+    }
+
+    /// Whether the element type is [f64]. See [is_f16](Self::is_f16).
+    fn is_f64() -> bool {
+        Self::as_elem_native() == Some(Elem::Float(FloatKind::F64))
+    }
+
+    fn __expand_is_f64(context: &Scope) -> bool {
+        Self::as_elem(context) == Elem::Float(FloatKind::F64)
+    }
 }
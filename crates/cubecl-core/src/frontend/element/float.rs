@@ -50,8 +50,10 @@ pub trait Float:
     const EPSILON: Self;
     const INFINITY: Self;
     const MANTISSA_DIGITS: u32;
+    const MAX: Self;
     const MAX_10_EXP: i32;
     const MAX_EXP: i32;
+    const MIN: Self;
     const MIN_10_EXP: i32;
     const MIN_EXP: i32;
     const MIN_POSITIVE: Self;
@@ -111,8 +113,10 @@ macro_rules! impl_float {
             const EPSILON: Self = $primitive::EPSILON;
             const INFINITY: Self = $primitive::INFINITY;
             const MANTISSA_DIGITS: u32 = $primitive::MANTISSA_DIGITS;
+            const MAX: Self = $primitive::MAX;
             const MAX_10_EXP: i32 = $primitive::MAX_10_EXP;
             const MAX_EXP: i32 = $primitive::MAX_EXP;
+            const MIN: Self = $primitive::MIN;
             const MIN_10_EXP: i32 = $primitive::MIN_10_EXP;
             const MIN_EXP: i32 = $primitive::MIN_EXP;
             const MIN_POSITIVE: Self = $primitive::MIN_POSITIVE;
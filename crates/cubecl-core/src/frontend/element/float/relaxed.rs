@@ -51,11 +51,17 @@ impl Float for flex32 {
 
     const MANTISSA_DIGITS: u32 = f32::MANTISSA_DIGITS;
 
+    /// Largest finite [`flex32`](crate::frontend::flex32) value
+    const MAX: Self = flex32::from_f32(f32::MAX);
+
     /// Maximum possible [`flex32`](crate::frontend::flex32) power of 10 exponent
     const MAX_10_EXP: i32 = f32::MAX_10_EXP;
     /// Maximum possible [`flex32`](crate::frontend::flex32) power of 2 exponent
     const MAX_EXP: i32 = f32::MAX_EXP;
 
+    /// Smallest finite [`flex32`](crate::frontend::flex32) value
+    const MIN: Self = flex32::from_f32(f32::MIN);
+
     /// Minimum possible normal [`flex32`](crate::frontend::flex32) power of 10 exponent
     const MIN_10_EXP: i32 = f32::MIN_10_EXP;
     /// One greater than the minimum possible normal [`flex32`](crate::frontend::flex32) power of 2 exponent
@@ -52,11 +52,18 @@ impl Float for tf32 {
 
     const MANTISSA_DIGITS: u32 = 10;
 
+    /// Largest finite [`tf32`](crate::frontend::tf32) value, `f32::MAX` with the mantissa truncated
+    /// to 10 bits
+    const MAX: Self = tf32::from_f32(f32::from_bits(0x7F7F_E000));
+
     /// Maximum possible [`tf32`](crate::frontend::tf32) power of 10 exponent
     const MAX_10_EXP: i32 = 38;
     /// Maximum possible [`tf32`](crate::frontend::tf32) power of 2 exponent
     const MAX_EXP: i32 = 128;
 
+    /// Smallest finite [`tf32`](crate::frontend::tf32) value
+    const MIN: Self = tf32::from_f32(f32::from_bits(0xFF7F_E000));
+
     /// Minimum possible normal [`tf32`](crate::frontend::tf32) power of 10 exponent
     const MIN_10_EXP: i32 = -37;
     /// One greater than the minimum possible normal [`tf32`](crate::frontend::tf32) power of 2 exponent
@@ -178,13 +178,21 @@ impl<const POS: u8> CubePrimitive for FloatExpand<POS> {
 
 impl<const POS: u8> From<FloatExpand<POS>> for Variable {
     fn from(val: FloatExpand<POS>) -> Self {
+        // The bounds are the ones of `f64`, narrowed to the bounds of the element type once the
+        // constant is converted to it.
+        let (value, kind) = if val.0 == f32::MAX {
+            (f64::MAX, FloatKind::F64)
+        } else if val.0 == f32::MIN {
+            (f64::MIN, FloatKind::F64)
+        } else {
+            (val.0 as f64, FloatKind::F32)
+        };
         // TODO: Fix how we create literal.
         Variable::new(
             crate::ir::VariableKind::ConstantScalar(crate::ir::ConstantScalarValue::Float(
-                val.0 as f64,
-                FloatKind::F32,
+                value, kind,
             )),
-            crate::ir::Item::new(Elem::Float(FloatKind::F32)),
+            crate::ir::Item::new(Elem::Float(kind)),
         )
     }
 }
@@ -254,11 +262,18 @@ impl<const POS: u8> Float for FloatExpand<POS> {
 
     const MANTISSA_DIGITS: u32 = f32::MANTISSA_DIGITS;
 
+    /// The element type is only known at expansion, so the constant becomes the largest finite
+    /// value of the element type once converted to it, e.g. `65504` for `f16`
+    const MAX: Self = FloatExpand::from_f32(f32::MAX);
+
     /// Maximum possible [`tf32`](crate::frontend::tf32) power of 10 exponent
     const MAX_10_EXP: i32 = f32::MAX_10_EXP;
     /// Maximum possible [`tf32`](crate::frontend::tf32) power of 2 exponent
     const MAX_EXP: i32 = f32::MAX_EXP;
 
+    /// See [`MAX`](Self::MAX)
+    const MIN: Self = FloatExpand::from_f32(f32::MIN);
+
     /// Minimum possible normal [`tf32`](crate::frontend::tf32) power of 10 exponent
     const MIN_10_EXP: i32 = f32::MIN_10_EXP;
     /// One greater than the minimum possible normal [`tf32`](crate::frontend::tf32) power of 2 exponent
@@ -4,7 +4,7 @@ use crate::Runtime;
 use crate::frontend::{CubeType, Numeric};
 use crate::ir::{Elem, IntKind, Scope};
 use crate::prelude::BitwiseNot;
-use crate::prelude::{FindFirstSet, LeadingZeros};
+use crate::prelude::{FindFirstSet, LeadingZeros, TrailingZeros};
 use crate::{
     compute::{KernelBuilder, KernelLauncher},
     prelude::{CountOnes, ReverseBits},
@@ -26,6 +26,7 @@ pub trait Int:
     + BitwiseNot
     + LeadingZeros
     + FindFirstSet
+    + TrailingZeros
     + std::ops::Rem<Output = Self>
     + core::ops::Add<Output = Self>
     + core::ops::Sub<Output = Self>
@@ -51,6 +52,8 @@ pub trait Int:
     + std::cmp::PartialEq
 {
     const BITS: u32;
+    const MAX: Self;
+    const MIN: Self;
 
     fn new(val: i64) -> Self;
     fn __expand_new(scope: &mut Scope, val: i64) -> <Self as CubeType>::ExpandType {
@@ -94,6 +97,8 @@ macro_rules! impl_int {
 
         impl Int for $type {
             const BITS: u32 = $type::BITS;
+            const MAX: Self = $type::MAX;
+            const MIN: Self = $type::MIN;
 
             fn new(val: i64) -> Self {
                 val as $type
@@ -202,6 +202,7 @@ impl<const POS: u8> ReverseBits for IntExpand<POS> {}
 impl<const POS: u8> CountOnes for IntExpand<POS> {}
 impl<const POS: u8> FindFirstSet for IntExpand<POS> {}
 impl<const POS: u8> LeadingZeros for IntExpand<POS> {}
+impl<const POS: u8> TrailingZeros for IntExpand<POS> {}
 
 impl<T: Index, const POS: u8> CubeIndex<T> for IntExpand<POS> {
     type Output = Self;
@@ -210,6 +211,8 @@ impl<T: Index, const POS: u8> CubeIndexMut<T> for IntExpand<POS> {}
 
 impl<const POS: u8> Int for IntExpand<POS> {
     const BITS: u32 = 32;
+    const MAX: Self = IntExpand(i32::MAX as i64);
+    const MIN: Self = IntExpand(i32::MIN as i64);
 
     fn new(val: i64) -> Self {
         IntExpand(val)
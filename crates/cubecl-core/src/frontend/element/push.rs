@@ -0,0 +1,39 @@
+use core::marker::PhantomData;
+
+use super::{ExpandElementTyped, LaunchArg, LaunchArgExpand, Numeric, ScalarArg};
+use crate::{
+    Runtime,
+    frontend::{CubePrimitive, CubeType},
+    prelude::KernelBuilder,
+};
+
+/// A scalar kernel argument sent as a push constant, for tiny values that change at every launch.
+///
+/// Inside the kernel it behaves exactly like a scalar of type `T`, and it's launched with a
+/// [ScalarArg]. On Vulkan, declaring a push constant sends all the scalars of the kernel as push
+/// constants instead of binding them as buffers, so they must fit in
+/// [MAX_PUSH_CONSTANTS_SIZE](crate::compute::MAX_PUSH_CONSTANTS_SIZE) bytes, padding included
+/// (see [push_constants_size](crate::compute::push_constants_size)). Other backends keep
+/// their usual scalar path, CUDA already passes scalars inline as kernel parameters.
+pub struct Push<T: Numeric> {
+    _elem: PhantomData<T>,
+}
+
+impl<T: Numeric> CubeType for Push<T> {
+    type ExpandType = ExpandElementTyped<T>;
+}
+
+impl<T: Numeric> LaunchArgExpand for Push<T> {
+    type CompilationArg = ();
+
+    fn expand(_: &Self::CompilationArg, builder: &mut KernelBuilder) -> ExpandElementTyped<T> {
+        builder.push_constant(T::as_elem(&builder.context)).into()
+    }
+}
+
+impl<T: Numeric> LaunchArg for Push<T> {
+    type RuntimeArg<'a, R: Runtime> = ScalarArg<T>;
+
+    fn compilation_arg<R: Runtime>(_runtime_arg: &Self::RuntimeArg<'_, R>) -> Self::CompilationArg {
+    }
+}
@@ -63,6 +63,8 @@ macro_rules! declare_uint {
 
         impl Int for $primitive {
             const BITS: u32 = $primitive::BITS;
+            const MAX: Self = $primitive::MAX;
+            const MIN: Self = $primitive::MIN;
 
             fn new(val: i64) -> Self {
                 val as $primitive
@@ -0,0 +1,45 @@
+//! Size and alignment of element types, known when the kernel is expanded.
+//!
+//! Import them explicitly, e.g. `use cubecl::frontend::mem::size_of;`, since they aren't part of
+//! the prelude to avoid shadowing [core::mem::size_of] in host code. Inside a `#[cube]` function
+//! they fold to comptime constants:
+//!
+//! ```ignore
+//! let index = byte_offset / size_of::<F>();
+//! ```
+
+use super::CubePrimitive;
+
+/// The size in bytes of one element of `T`, e.g. 4 for `f32` or `Line<f32>`.
+pub fn size_of<T: CubePrimitive>() -> u32 {
+    T::elem_size()
+}
+
+/// Module containing the expand function for [size_of()].
+pub mod size_of {
+    use super::*;
+    use crate::ir::Scope;
+
+    /// Expand method of [size_of()].
+    pub fn expand<T: CubePrimitive>(scope: &mut Scope) -> u32 {
+        T::__expand_elem_size(scope)
+    }
+}
+
+/// The alignment in bytes of one element of `T`, the one of the matching Rust type on the host,
+/// e.g. 4 for `f32` or `Line<f32>`. It can be smaller than [size_of()], e.g. for `u64` on 32-bit
+/// x86.
+pub fn align_of<T: CubePrimitive>() -> u32 {
+    T::as_elem_native_unchecked().align() as u32
+}
+
+/// Module containing the expand function for [align_of()].
+pub mod align_of {
+    use super::*;
+    use crate::ir::Scope;
+
+    /// Expand method of [align_of()].
+    pub fn expand<T: CubePrimitive>(scope: &mut Scope) -> u32 {
+        T::as_elem(scope).align() as u32
+    }
+}
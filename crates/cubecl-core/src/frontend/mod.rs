@@ -1,15 +1,18 @@
 pub mod barrier;
 pub mod branch;
 pub mod cmma;
+pub mod mem;
 pub mod pipeline;
 pub mod synchronization;
 
 mod base;
+mod call;
 mod comment;
 pub mod comptime_error;
 mod const_expand;
 mod container;
 mod debug;
+mod device_assert;
 mod element;
 mod indexation;
 mod list;
@@ -17,13 +20,17 @@ mod operation;
 mod options;
 mod plane;
 mod polyfills;
+mod prefetch;
+mod target;
 mod topology;
 
-pub use branch::{RangeExpand, SteppedRangeExpand, range, range_stepped};
+pub use branch::{RangeExpand, SteppedRangeExpand, grid_stride_range, range, range_stepped};
+pub use call::*;
 pub use comment::*;
 pub use const_expand::*;
 pub use container::*;
 pub use debug::*;
+pub use device_assert::*;
 pub use element::*;
 pub use indexation::*;
 pub use list::*;
@@ -31,6 +38,8 @@ pub use operation::*;
 pub use options::*;
 pub use plane::*;
 pub use polyfills::*;
+pub use prefetch::*;
+pub use target::*;
 pub use topology::*;
 
-pub use crate::{debug_print, debug_print_expand};
+pub use crate::{debug_print, debug_print_expand, device_assert, device_assert_expand};
@@ -1,4 +1,5 @@
 use crate::{
+    frontend::branch::if_expand,
     ir::{Operator, Scope, Select},
     prelude::*,
 };
@@ -75,3 +76,60 @@ pub mod select_many {
         select::expand(scope, condition.expand.into(), then, or_else)
     }
 }
+
+/// Writes `value` at `index` of `list` only when `condition` is true.
+///
+/// This is sugar for an `if` block containing only the write: no predicated instruction is
+/// emitted, and the backend compiler is left to turn the branch into a predicated store.
+#[allow(unused_variables)]
+pub fn store_if<T: CubePrimitive, L: ListMut<T>>(
+    condition: bool,
+    list: &mut L,
+    index: u32,
+    value: T,
+) {
+    unexpanded!()
+}
+
+/// Adds `value` to the atomic at `pointer` only when `condition` is true.
+///
+/// Units with a false condition skip the atomic operation entirely. Like [`store_if`], this is
+/// sugar for an `if` block containing only the atomic addition.
+#[allow(unused_variables)]
+pub fn atomic_add_if<I: Numeric>(condition: bool, pointer: &Atomic<I>, value: I) {
+    unexpanded!()
+}
+
+pub mod store_if {
+    use super::*;
+
+    pub fn expand<
+        T: CubePrimitive,
+        L: ListMut<T> + CubeType<ExpandType = ExpandElementTyped<L>>,
+    >(
+        scope: &mut Scope,
+        condition: ExpandElementTyped<bool>,
+        list: ExpandElementTyped<L>,
+        index: ExpandElementTyped<u32>,
+        value: ExpandElementTyped<T>,
+    ) {
+        if_expand(scope, condition.into(), |scope| {
+            L::__expand_write(scope, list, index, value)
+        });
+    }
+}
+
+pub mod atomic_add_if {
+    use super::*;
+
+    pub fn expand<I: Numeric>(
+        scope: &mut Scope,
+        condition: ExpandElementTyped<bool>,
+        pointer: ExpandElementTyped<Atomic<I>>,
+        value: ExpandElementTyped<I>,
+    ) {
+        if_expand(scope, condition.into(), |scope| {
+            Atomic::<I>::__expand_add(scope, pointer, value);
+        });
+    }
+}
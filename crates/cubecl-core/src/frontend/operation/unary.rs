@@ -30,7 +30,8 @@ pub mod neg {
 }
 
 macro_rules! impl_unary_func {
-    ($trait_name:ident, $method_name:ident, $method_name_expand:ident, $operator:expr, $($type:ty),*) => {
+    ($(#[$attr:meta])* $trait_name:ident, $method_name:ident, $method_name_expand:ident, $operator:expr, $($type:ty),*) => {
+        $(#[$attr])*
         pub trait $trait_name: CubePrimitive + Sized {
             #[allow(unused_variables)]
             fn $method_name(x: Self) -> Self {
@@ -250,6 +251,29 @@ impl_unary_func!(
     f32,
     f64
 );
+impl_unary_func!(
+    /// Approximate reciprocal `1 / x`, lowered to the fast hardware instruction when available.
+    ///
+    /// The result may be off by a few ulps and isn't guaranteed to handle denormals, use
+    /// [Recip] when the result must be correctly rounded.
+    RecipFast,
+    recip_fast,
+    __expand_recip_fast,
+    Arithmetic::RecipFast,
+    f32
+);
+impl_unary_func!(
+    /// Approximate reciprocal square root `1 / sqrt(x)`, lowered to the fast hardware instruction
+    /// when available.
+    ///
+    /// The result may be off by a few ulps and isn't guaranteed to handle denormals, use
+    /// `x.sqrt().recip()` when the result must be correctly rounded.
+    RsqrtFast,
+    rsqrt_fast,
+    __expand_rsqrt_fast,
+    Arithmetic::RsqrtFast,
+    f32
+);
 impl_unary_func_fixed_out_vectorization!(
     Magnitude,
     magnitude,
@@ -334,6 +358,23 @@ impl_unary_func_fixed_out_ty!(
     u64,
     i64
 );
+impl_unary_func_fixed_out_ty!(
+    /// Count the trailing zeros, the bit width of the type when the value is zero, like
+    /// [u32::trailing_zeros].
+    TrailingZeros,
+    trailing_zeros,
+    __expand_trailing_zeros,
+    u32,
+    Bitwise::TrailingZeros,
+    u8,
+    i8,
+    u16,
+    i16,
+    u32,
+    i32,
+    u64,
+    i64
+);
 impl_unary_func_fixed_out_ty!(
     FindFirstSet,
     find_first_set,
@@ -3,7 +3,7 @@ use cubecl_ir::ExpandElement;
 use super::{CubePrimitive, Line};
 use crate::prelude::ExpandElementTyped;
 use crate::{
-    ir::{Elem, Instruction, Item, Plane, Scope, UnaryOperator},
+    ir::{Elem, Instruction, Item, Plane, PlanePartitionOperator, Scope, UnaryOperator},
     unexpanded,
 };
 
@@ -28,6 +28,31 @@ pub mod plane_elect {
     }
 }
 
+/// The number of units in a plane, as a constant when the plane size is `fixed` for the device,
+/// like the `Some(32)` returned by [fixed_plane_dim](crate::Runtime::fixed_plane_dim) on CUDA, and
+/// as the runtime [PLANE_DIM](crate::prelude::PLANE_DIM) otherwise.
+///
+/// Only the `fixed` size itself is known at compile time, so pass it as a comptime argument to
+/// size arrays or unroll loops over the units of a plane.
+#[allow(unused_variables)]
+pub fn plane_dim(fixed: Option<u32>) -> u32 {
+    unexpanded!()
+}
+
+/// Module containing the expand function for [plane_dim()].
+pub mod plane_dim {
+
+    use super::*;
+
+    /// Expand method of [plane_dim()].
+    pub fn expand(scope: &mut Scope, fixed: Option<u32>) -> ExpandElementTyped<u32> {
+        match fixed {
+            Some(plane_dim) => plane_dim.into(),
+            None => crate::frontend::PLANE_DIM::expand(scope),
+        }
+    }
+}
+
 /// Broadcasts the value from the specified plane unit at the given index
 /// to all active units within that plane.
 #[allow(unused_variables)]
@@ -60,6 +85,76 @@ pub mod plane_broadcast {
     }
 }
 
+/// Returns the value of the plane unit `delta` lanes below the current unit, e.g. to read the
+/// left neighbor of a stencil with a delta of 1.
+///
+/// Units without a unit `delta` lanes below them, the first `delta` units of the plane, get
+/// their own value back.
+#[allow(unused_variables)]
+pub fn plane_shuffle_up<E: CubePrimitive>(value: E, delta: u32) -> E {
+    unexpanded!()
+}
+
+/// Module containing the expand function for [plane_shuffle_up()].
+pub mod plane_shuffle_up {
+
+    use super::*;
+
+    /// Expand method of [plane_shuffle_up()].
+    pub fn expand<E: CubePrimitive>(
+        scope: &mut Scope,
+        value: ExpandElementTyped<E>,
+        delta: ExpandElementTyped<u32>,
+    ) -> ExpandElementTyped<E> {
+        let output = scope.create_local(value.expand.item);
+        let out = *output;
+        let lhs = *value.expand;
+        let rhs = *delta.expand;
+
+        scope.register(Instruction::new(
+            Plane::ShuffleUp(crate::ir::BinaryOperator { lhs, rhs }),
+            out,
+        ));
+
+        output.into()
+    }
+}
+
+/// Returns the value of the plane unit `delta` lanes above the current unit, e.g. to read the
+/// right neighbor of a stencil with a delta of 1.
+///
+/// Units without a unit `delta` lanes above them, the last `delta` units of the plane, get their
+/// own value back.
+#[allow(unused_variables)]
+pub fn plane_shuffle_down<E: CubePrimitive>(value: E, delta: u32) -> E {
+    unexpanded!()
+}
+
+/// Module containing the expand function for [plane_shuffle_down()].
+pub mod plane_shuffle_down {
+
+    use super::*;
+
+    /// Expand method of [plane_shuffle_down()].
+    pub fn expand<E: CubePrimitive>(
+        scope: &mut Scope,
+        value: ExpandElementTyped<E>,
+        delta: ExpandElementTyped<u32>,
+    ) -> ExpandElementTyped<E> {
+        let output = scope.create_local(value.expand.item);
+        let out = *output;
+        let lhs = *value.expand;
+        let rhs = *delta.expand;
+
+        scope.register(Instruction::new(
+            Plane::ShuffleDown(crate::ir::BinaryOperator { lhs, rhs }),
+            out,
+        ));
+
+        output.into()
+    }
+}
+
 /// Perform a reduce sum operation across all units in a plane.
 #[allow(unused_variables)]
 pub fn plane_sum<E: CubePrimitive>(value: E) -> E {
@@ -393,3 +488,243 @@ pub mod plane_ballot {
         output.into()
     }
 }
+
+/// Perform a reduce sum operation across each partition of `partition_size` consecutive units
+/// in a plane, starting at unit 0.
+///
+/// The partition size must be a power of two dividing the plane size, kernels using a partition
+/// larger than the plane fail to compile.
+#[allow(unused_variables)]
+pub fn plane_partition_sum<E: CubePrimitive>(elem: E, partition_size: u32) -> E {
+    unexpanded!()
+}
+
+/// Module containing the expand function for [plane_partition_sum()].
+pub mod plane_partition_sum {
+    use super::*;
+
+    /// Expand method of [plane_partition_sum()].
+    pub fn expand<E: CubePrimitive>(
+        scope: &mut Scope,
+        elem: ExpandElementTyped<E>,
+        partition_size: u32,
+    ) -> ExpandElementTyped<E> {
+        expand_partition(scope, elem, partition_size, Plane::PartitionSum)
+    }
+}
+
+/// Perform a reduce product operation across each partition of `partition_size` consecutive units
+/// in a plane, starting at unit 0.
+///
+/// The partition size must be a power of two dividing the plane size, kernels using a partition
+/// larger than the plane fail to compile.
+#[allow(unused_variables)]
+pub fn plane_partition_prod<E: CubePrimitive>(elem: E, partition_size: u32) -> E {
+    unexpanded!()
+}
+
+/// Module containing the expand function for [plane_partition_prod()].
+pub mod plane_partition_prod {
+    use super::*;
+
+    /// Expand method of [plane_partition_prod()].
+    pub fn expand<E: CubePrimitive>(
+        scope: &mut Scope,
+        elem: ExpandElementTyped<E>,
+        partition_size: u32,
+    ) -> ExpandElementTyped<E> {
+        expand_partition(scope, elem, partition_size, Plane::PartitionProd)
+    }
+}
+
+/// Perform a reduce min operation across each partition of `partition_size` consecutive units
+/// in a plane, starting at unit 0.
+///
+/// The partition size must be a power of two dividing the plane size, kernels using a partition
+/// larger than the plane fail to compile.
+#[allow(unused_variables)]
+pub fn plane_partition_min<E: CubePrimitive>(elem: E, partition_size: u32) -> E {
+    unexpanded!()
+}
+
+/// Module containing the expand function for [plane_partition_min()].
+pub mod plane_partition_min {
+    use super::*;
+
+    /// Expand method of [plane_partition_min()].
+    pub fn expand<E: CubePrimitive>(
+        scope: &mut Scope,
+        elem: ExpandElementTyped<E>,
+        partition_size: u32,
+    ) -> ExpandElementTyped<E> {
+        expand_partition(scope, elem, partition_size, Plane::PartitionMin)
+    }
+}
+
+/// Perform a reduce max operation across each partition of `partition_size` consecutive units
+/// in a plane, starting at unit 0.
+///
+/// The partition size must be a power of two dividing the plane size, kernels using a partition
+/// larger than the plane fail to compile.
+#[allow(unused_variables)]
+pub fn plane_partition_max<E: CubePrimitive>(elem: E, partition_size: u32) -> E {
+    unexpanded!()
+}
+
+/// Module containing the expand function for [plane_partition_max()].
+pub mod plane_partition_max {
+    use super::*;
+
+    /// Expand method of [plane_partition_max()].
+    pub fn expand<E: CubePrimitive>(
+        scope: &mut Scope,
+        elem: ExpandElementTyped<E>,
+        partition_size: u32,
+    ) -> ExpandElementTyped<E> {
+        expand_partition(scope, elem, partition_size, Plane::PartitionMax)
+    }
+}
+
+/// Perform an inclusive sum operation across each partition of `partition_size` consecutive
+/// units in a plane, starting at unit 0.
+/// This sums all values to the "left" of the unit within its partition, including this
+/// unit's value.
+///
+/// The partition size must be a power of two dividing the plane size, kernels using a partition
+/// larger than the plane fail to compile.
+///
+/// # Example
+/// `plane_partition_inclusive_sum([1, 2, 3, 4, 5, 6], 2) == [1, 3, 3, 7, 5, 11]`
+#[allow(unused_variables)]
+pub fn plane_partition_inclusive_sum<E: CubePrimitive>(elem: E, partition_size: u32) -> E {
+    unexpanded!()
+}
+
+/// Module containing the expand function for [plane_partition_inclusive_sum()].
+pub mod plane_partition_inclusive_sum {
+    use super::*;
+
+    /// Expand method of [plane_partition_inclusive_sum()].
+    pub fn expand<E: CubePrimitive>(
+        scope: &mut Scope,
+        elem: ExpandElementTyped<E>,
+        partition_size: u32,
+    ) -> ExpandElementTyped<E> {
+        expand_partition(scope, elem, partition_size, Plane::PartitionInclusiveSum)
+    }
+}
+
+/// Perform an exclusive sum operation across each partition of `partition_size` consecutive
+/// units in a plane, starting at unit 0.
+/// This sums all values to the "left" of the unit within its partition, excluding this
+/// unit's value. The first unit of each partition will be set to `E::zero()`.
+///
+/// The partition size must be a power of two dividing the plane size, kernels using a partition
+/// larger than the plane fail to compile.
+///
+/// # Example
+/// `plane_partition_exclusive_sum([1, 2, 3, 4, 5, 6], 2) == [0, 1, 0, 3, 0, 5]`
+#[allow(unused_variables)]
+pub fn plane_partition_exclusive_sum<E: CubePrimitive>(elem: E, partition_size: u32) -> E {
+    unexpanded!()
+}
+
+/// Module containing the expand function for [plane_partition_exclusive_sum()].
+pub mod plane_partition_exclusive_sum {
+    use super::*;
+
+    /// Expand method of [plane_partition_exclusive_sum()].
+    pub fn expand<E: CubePrimitive>(
+        scope: &mut Scope,
+        elem: ExpandElementTyped<E>,
+        partition_size: u32,
+    ) -> ExpandElementTyped<E> {
+        expand_partition(scope, elem, partition_size, Plane::PartitionExclusiveSum)
+    }
+}
+
+/// Perform an inclusive product operation across each partition of `partition_size` consecutive
+/// units in a plane, starting at unit 0.
+/// This multiplies all values to the "left" of the unit within its partition, including
+/// this unit's value.
+///
+/// The partition size must be a power of two dividing the plane size, kernels using a partition
+/// larger than the plane fail to compile.
+///
+/// # Example
+/// `plane_partition_inclusive_prod([1, 2, 3, 4, 5, 6], 2) == [1, 2, 3, 12, 5, 30]`
+#[allow(unused_variables)]
+pub fn plane_partition_inclusive_prod<E: CubePrimitive>(elem: E, partition_size: u32) -> E {
+    unexpanded!()
+}
+
+/// Module containing the expand function for [plane_partition_inclusive_prod()].
+pub mod plane_partition_inclusive_prod {
+    use super::*;
+
+    /// Expand method of [plane_partition_inclusive_prod()].
+    pub fn expand<E: CubePrimitive>(
+        scope: &mut Scope,
+        elem: ExpandElementTyped<E>,
+        partition_size: u32,
+    ) -> ExpandElementTyped<E> {
+        expand_partition(scope, elem, partition_size, Plane::PartitionInclusiveProd)
+    }
+}
+
+/// Perform an exclusive product operation across each partition of `partition_size` consecutive
+/// units in a plane, starting at unit 0.
+/// This multiplies all values to the "left" of the unit within its partition, excluding
+/// this unit's value. The first unit of each partition will be set to `E::one()`.
+///
+/// The partition size must be a power of two dividing the plane size, kernels using a partition
+/// larger than the plane fail to compile.
+///
+/// # Example
+/// `plane_partition_exclusive_prod([1, 2, 3, 4, 5, 6], 2) == [1, 1, 1, 3, 1, 5]`
+#[allow(unused_variables)]
+pub fn plane_partition_exclusive_prod<E: CubePrimitive>(elem: E, partition_size: u32) -> E {
+    unexpanded!()
+}
+
+/// Module containing the expand function for [plane_partition_exclusive_prod()].
+pub mod plane_partition_exclusive_prod {
+    use super::*;
+
+    /// Expand method of [plane_partition_exclusive_prod()].
+    pub fn expand<E: CubePrimitive>(
+        scope: &mut Scope,
+        elem: ExpandElementTyped<E>,
+        partition_size: u32,
+    ) -> ExpandElementTyped<E> {
+        expand_partition(scope, elem, partition_size, Plane::PartitionExclusiveProd)
+    }
+}
+
+fn expand_partition<E: CubePrimitive>(
+    scope: &mut Scope,
+    elem: ExpandElementTyped<E>,
+    partition_size: u32,
+    operation: fn(PlanePartitionOperator) -> Plane,
+) -> ExpandElementTyped<E> {
+    assert!(
+        partition_size.is_power_of_two(),
+        "The plane partition size must be a power of two, got {partition_size}"
+    );
+
+    let elem: ExpandElement = elem.into();
+    let output = scope.create_local(elem.item);
+
+    let out = *output;
+    let input = *elem;
+
+    scope.register(Instruction::new(
+        operation(PlanePartitionOperator {
+            input,
+            partition_size,
+        }),
+        out,
+    ));
+
+    output.into()
+}
@@ -1,4 +1,4 @@
-use cubecl_ir::{Elem, ExpandElement, Variable};
+use cubecl_ir::{Elem, ExpandElement, FloatKind, Variable};
 
 use crate::prelude::*;
 use crate::{self as cubecl, unexpanded};
@@ -153,3 +153,123 @@ pub fn expand_himul_sim(scope: &mut Scope, lhs: Variable, rhs: Variable, out: Va
     );
     assign::expand(scope, res, ExpandElement::Plain(out).into());
 }
+
+/// Add random bits below the precision kept by a cast rounding toward zero, so it rounds away
+/// from zero with a probability proportional to the dropped part of the value.
+#[cube]
+pub fn stochastic_round_bits(
+    value: Line<f32>,
+    random: Line<u32>,
+    #[comptime] dropped_bits: u32,
+) -> Line<f32> {
+    let size = value.size();
+    let bits = Line::<u32>::reinterpret(value);
+    let sign = bits & Line::empty(size).fill(0x80000000);
+    let magnitude = bits & Line::empty(size).fill(0x7FFFFFFF);
+
+    let noise = random & Line::empty(size).fill(comptime!((1u32 << dropped_bits) - 1));
+    // Stay below infinity, the cast clamps to the largest finite value of the target.
+    let noisy = Min::min(magnitude + noise, Line::empty(size).fill(0x7F7FFFFF));
+    let finite = magnitude.less_than(Line::empty(size).fill(0x7F800000));
+
+    Line::<f32>::reinterpret(sign | select_many(finite, noisy, magnitude))
+}
+
+/// Round an `f32` to a value exactly representable by a narrower float, so casting it to that
+/// float doesn't round.
+///
+/// The narrower float keeps `23 - dropped_bits` bits of mantissa, `max_finite` is the bits of its
+/// largest finite value as an `f32` and `subnormal_ulp` the spacing of its subnormal values, or
+/// zero when it has the exponent range of `f32`.
+#[cube]
+fn round_f32(
+    value: Line<f32>,
+    #[comptime] dropped_bits: u32,
+    #[comptime] max_finite: u32,
+    #[comptime] subnormal_ulp: f32,
+    #[comptime] nearest: bool,
+) -> Line<f32> {
+    let size = value.size();
+    let mask = comptime!((1u32 << dropped_bits) - 1);
+    let bits = Line::<u32>::reinterpret(value);
+    let sign = bits & Line::empty(size).fill(0x80000000);
+    let magnitude = bits & Line::empty(size).fill(0x7FFFFFFF);
+
+    let mut rounded = magnitude;
+    if nearest {
+        // Add just under half of the dropped range, and the last kept bit so ties round to even.
+        let last_kept =
+            (magnitude >> Line::empty(size).fill(dropped_bits)) & Line::empty(size).fill(1);
+        rounded += Line::empty(size).fill(comptime!(mask >> 1)) + last_kept;
+    }
+    rounded = rounded & Line::empty(size).fill(comptime!(!mask));
+
+    // To nearest, values past the largest finite value become infinite.
+    let overflow = comptime!(if nearest { 0x7F800000u32 } else { max_finite });
+    rounded = select_many(
+        rounded.greater_than(Line::empty(size).fill(max_finite)),
+        Line::empty(size).fill(overflow),
+        rounded,
+    );
+
+    if comptime!(subnormal_ulp > 0.0) {
+        // Subnormal values of the target are evenly spaced, so round the number of steps.
+        let min_normal = comptime!(subnormal_ulp * (1u32 << (23 - dropped_bits)) as f32);
+        let abs = Line::<f32>::reinterpret(magnitude);
+        let steps = abs * Line::empty(size).fill(comptime!(1.0 / subnormal_ulp));
+        let mut whole_steps = Floor::floor(steps);
+
+        if nearest {
+            let fraction = steps - whole_steps;
+            let half = Line::empty(size).fill(0.5);
+            let odd = (Line::<u32>::cast_from(whole_steps) & Line::empty(size).fill(1))
+                .equal(Line::empty(size).fill(1));
+            let round_up = select_many(fraction.equal(half), odd, fraction.greater_than(half));
+            whole_steps += select_many(
+                round_up,
+                Line::empty(size).fill(1.0),
+                Line::empty(size).fill(0.0),
+            );
+        }
+
+        let subnormal =
+            Line::<u32>::reinterpret(whole_steps * Line::empty(size).fill(subnormal_ulp));
+        rounded = select_many(
+            abs.less_than(Line::empty(size).fill(min_normal)),
+            subnormal,
+            rounded,
+        );
+    }
+
+    // Infinity and NaN are kept as is.
+    let special = magnitude.greater_equal(Line::empty(size).fill(0x7F800000));
+    Line::<f32>::reinterpret(sign | select_many(special, magnitude, rounded))
+}
+
+/// Expand a cast with a rounding mode, rounding casts from `f32` to a narrower float in software
+/// and leaving the others to a regular cast.
+#[allow(missing_docs)]
+pub fn expand_cast_rounded(scope: &mut Scope, input: Variable, out: Variable, nearest: bool) {
+    // Mantissa bits dropped, bits of the largest finite value and spacing of subnormal values.
+    let params = match (input.elem(), out.elem()) {
+        (Elem::Float(FloatKind::F32), Elem::Float(FloatKind::F16)) => {
+            Some((13, 0x477FE000, 1.0 / 16777216.0))
+        }
+        (Elem::Float(FloatKind::F32), Elem::Float(FloatKind::BF16)) => Some((16, 0x7F7F0000, 0.0)),
+        (Elem::Float(FloatKind::F32), Elem::Float(FloatKind::TF32)) => Some((13, 0x7F7FE000, 0.0)),
+        _ => None,
+    };
+
+    let input = match params {
+        Some((dropped_bits, max_finite, subnormal_ulp)) => round_f32::expand(
+            scope,
+            ExpandElement::Plain(input).into(),
+            dropped_bits,
+            max_finite,
+            subnormal_ulp,
+            nearest,
+        ),
+        None => ExpandElement::Plain(input).into(),
+    };
+    cast::expand::<Line<f32>>(scope, input, ExpandElement::Plain(out).into());
+}
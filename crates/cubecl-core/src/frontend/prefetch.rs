@@ -0,0 +1,33 @@
+use crate::{
+    frontend::{CubePrimitive, ExpandElementTyped, Tensor},
+    ir::{NonSemantic, Scope},
+    unexpanded,
+};
+
+/// Hint the hardware that `bytes` bytes starting at `tensor[offset]` will be read soon, so it can
+/// start pulling them into cache while the current work is computed.
+///
+/// `bytes` must be known at compile time. This is a performance hint only and never changes the
+/// result of the kernel. It lowers to an L2 prefetch on CUDA and is ignored on backends without a
+/// prefetch instruction.
+#[allow(unused_variables)]
+pub fn prefetch<T: CubePrimitive>(tensor: &Tensor<T>, offset: u32, bytes: u32) {
+    unexpanded!()
+}
+
+pub mod prefetch {
+    use super::*;
+
+    pub fn expand<T: CubePrimitive>(
+        scope: &mut Scope,
+        tensor: ExpandElementTyped<Tensor<T>>,
+        offset: ExpandElementTyped<u32>,
+        bytes: u32,
+    ) {
+        scope.register(NonSemantic::Prefetch {
+            list: *tensor.expand,
+            index: *offset.expand,
+            bytes,
+        });
+    }
+}
@@ -2,6 +2,8 @@ use crate::{
     ir::{Scope, Synchronization},
     unexpanded,
 };
+
+pub use crate::ir::{MemoryOrder, MemoryScope};
 // Among all backends, the memory order guarantee of WebGPU is the weakest
 // So Cubecl's memory order cannot be stronger than that of WebGPU
 
@@ -49,3 +51,37 @@ pub mod sync_proxy_shared {
         scope.register(Synchronization::SyncProxyShared)
     }
 }
+
+/// Orders the memory accesses of the current unit around the fence, as observed by the units of
+/// `scope`, without waiting for the other units like [sync_units] and [sync_storage] do.
+///
+/// Backends may provide a stronger ordering than requested: CUDA fences are always sequentially
+/// consistent. WGSL has no standalone fence, so kernels using one can't be compiled to WGSL and
+/// fail with [CompilationError::UnsupportedInstruction](crate::compute::CompilationError).
+pub fn memory_fence(_scope: MemoryScope, _order: MemoryOrder) {}
+
+pub mod memory_fence {
+    use super::*;
+
+    pub fn expand(scope: &mut Scope, memory_scope: MemoryScope, order: MemoryOrder) {
+        scope.register(Synchronization::MemoryFence {
+            scope: memory_scope,
+            order,
+        })
+    }
+}
+
+/// Synchronize all units in every cube of the cluster, i.e. [sync_units] across cubes. Writes to
+/// shared memory made before the barrier are visible to every cube of the cluster through
+/// [cluster_load](crate::prelude::SharedMemory::cluster_load) after it.
+///
+/// On targets without clusters, each cube is its own cluster so this is the same as [sync_units].
+pub fn sync_cluster() {}
+
+pub mod sync_cluster {
+    use super::*;
+
+    pub fn expand(scope: &mut Scope) {
+        scope.register(Synchronization::SyncCluster)
+    }
+}
@@ -0,0 +1,39 @@
+use core::cell::Cell;
+
+use crate::CompilationTarget;
+
+std::thread_local! {
+    static TARGET: Cell<Option<CompilationTarget>> = const { Cell::new(None) };
+}
+
+/// The target the kernel currently expanded is compiled for, `None` when the kernel is only
+/// expanded, without a compiler.
+///
+/// It is a host function, meant to be called in `comptime!` to choose between the code paths of
+/// different backends while expanding the kernel:
+///
+/// ```ignore
+/// if comptime!(target_is_cuda()) {
+///     // CUDA-only fast path
+/// } else {
+///     // Portable fallback
+/// }
+/// ```
+///
+/// Kernels are cached per compiler, so each target gets its own expansion of the kernel.
+pub fn compilation_target() -> Option<CompilationTarget> {
+    TARGET.with(|target| target.get())
+}
+
+/// Whether the kernel currently expanded is compiled for CUDA, see [compilation_target].
+pub fn target_is_cuda() -> bool {
+    compilation_target() == Some(CompilationTarget::Cuda)
+}
+
+/// Run `func`, usually the expansion of a kernel, with the given compilation target.
+pub fn with_compilation_target<T>(target: CompilationTarget, func: impl FnOnce() -> T) -> T {
+    let previous = TARGET.with(|current| current.replace(Some(target)));
+    let output = func();
+    TARGET.with(|current| current.set(previous));
+    output
+}
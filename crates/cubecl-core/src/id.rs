@@ -5,12 +5,15 @@ use std::sync::Arc;
 use cubecl_common::ExecutionMode;
 use cubecl_runtime::client::ComputeClient;
 
+use crate::CompilerPassId;
+
 /// Kernel unique identifier.
 #[derive(Clone, Debug)]
 pub struct KernelId {
     pub(crate) type_id: core::any::TypeId,
     pub(crate) info: Option<Info>,
     pub(crate) mode: Option<ExecutionMode>,
+    pub(crate) compiler_passes: Vec<CompilerPassId>,
     type_name: &'static str,
 }
 
@@ -19,12 +22,16 @@ impl Hash for KernelId {
         self.type_id.hash(state);
         self.info.hash(state);
         self.mode.hash(state);
+        self.compiler_passes.hash(state);
     }
 }
 
 impl PartialEq for KernelId {
     fn eq(&self, other: &Self) -> bool {
-        self.type_id == other.type_id && self.mode == other.mode && self.info == other.info
+        self.type_id == other.type_id
+            && self.mode == other.mode
+            && self.info == other.info
+            && self.compiler_passes == other.compiler_passes
     }
 }
 
@@ -38,6 +45,7 @@ impl KernelId {
             type_name: core::any::type_name::<T>(),
             info: None,
             mode: None,
+            compiler_passes: Vec::new(),
         }
     }
 
@@ -45,7 +53,11 @@ impl KernelId {
     ///
     /// Can be used as a persistent kernel cache key.
     pub fn stable_format(&self) -> String {
-        format!("{}-{:?}-{:?}", self.type_name, self.info, self.mode)
+        let key = format!("{}-{:?}-{:?}", self.type_name, self.info, self.mode);
+        match self.compiler_passes.is_empty() {
+            true => key,
+            false => format!("{key}-{:?}", self.compiler_passes),
+        }
     }
 
     /// Add information to the [kernel id](KernelId).
@@ -64,6 +76,12 @@ impl KernelId {
     pub fn mode(&mut self, mode: ExecutionMode) {
         self.mode = Some(mode);
     }
+
+    /// Set the [compiler passes](crate::CompilerPass) the kernel is compiled with.
+    pub(crate) fn compiler_passes(mut self, passes: Vec<CompilerPassId>) -> Self {
+        self.compiler_passes = passes;
+        self
+    }
 }
 
 /// Extra information
@@ -11,7 +11,7 @@ pub mod io;
 /// Some future utilities that work across environments.
 pub use cubecl_common::{PLANE_DIM_APPROX, future};
 
-pub use cubecl_runtime::memory_management::MemoryConfiguration;
+pub use cubecl_runtime::memory_management::{MemoryConfiguration, OutOfMemoryPolicy};
 pub use frontend::cmma;
 
 /// Cube Language Internal Representation.
@@ -24,6 +24,9 @@ pub mod prelude;
 mod pod;
 mod runtime;
 
+#[doc(hidden)]
+pub mod dispatch;
+
 pub use codegen::*;
 pub use pod::*;
 pub use runtime::*;
@@ -57,14 +60,67 @@ pub trait Kernel: Send + Sync + 'static + Sized {
 /// Calculate the number of cubes required to execute an operation where one cube unit is
 /// assigned to one element.
 pub fn calculate_cube_count_elemwise(num_elems: usize, cube_dim: CubeDim) -> CubeCount {
-    let num_elems_per_cube = cube_dim.num_elems();
-    let cube_counts = f32::max(1.0, f32::ceil(num_elems as f32 / num_elems_per_cube as f32));
-    let cube_count_x = f32::ceil(f32::sqrt(cube_counts));
-    let cube_count_y = f32::ceil(num_elems as f32 / (cube_count_x * num_elems_per_cube as f32));
+    // Integer arithmetic, `f32` can't represent large element counts exactly and would round the
+    // cube count down.
+    let num_elems_per_cube = cube_dim.num_elems() as u64;
+    let cube_counts = u64::max(1, (num_elems as u64).div_ceil(num_elems_per_cube));
+    let cube_count_x = f64::ceil(f64::sqrt(cube_counts as f64)) as u64;
+    let cube_count_y = cube_counts.div_ceil(cube_count_x);
 
     CubeCount::Static(cube_count_x as u32, cube_count_y as u32, 1)
 }
 
+/// Maximum number of elements covered by a single [chunk](ElemwiseChunk), small enough for
+/// every index of the chunk to fit in an `i32`.
+pub const MAX_ELEMWISE_CHUNK_SIZE: u64 = 1 << 30;
+
+/// A part of an element-wise operation over more elements than a single dispatch can index.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ElemwiseChunk {
+    /// Index of the first element of the chunk.
+    pub offset: u64,
+    /// Number of elements in the chunk.
+    pub num_elems: u64,
+    /// Cube count to launch the chunk with.
+    pub cube_count: CubeCount,
+}
+
+/// Split an element-wise operation over `num_elems` elements into chunks that can each be
+/// launched with 32-bit indexing and a cube count within `max_cube_count`.
+///
+/// Each chunk should be launched on the buffers offset by [ElemwiseChunk::offset] elements.
+/// The cube count is capped to `max_cube_count`, so kernels should iterate over their chunk with
+/// a grid-stride loop, stepping by `CUBE_COUNT * CUBE_DIM`, to cover every element.
+pub fn calculate_cube_count_elemwise_chunked(
+    num_elems: u64,
+    cube_dim: CubeDim,
+    max_cube_count: CubeDim,
+) -> Vec<ElemwiseChunk> {
+    let mut chunks = Vec::new();
+    let mut offset = 0;
+
+    while offset < num_elems || chunks.is_empty() {
+        let chunk_size = u64::min(MAX_ELEMWISE_CHUNK_SIZE, num_elems - offset);
+        let cube_count = match calculate_cube_count_elemwise(chunk_size as usize, cube_dim) {
+            CubeCount::Static(x, y, z) => CubeCount::Static(
+                x.min(max_cube_count.x),
+                y.min(max_cube_count.y),
+                z.min(max_cube_count.z),
+            ),
+            CubeCount::Dynamic(_) => unreachable!(),
+        };
+
+        chunks.push(ElemwiseChunk {
+            offset,
+            num_elems: chunk_size,
+            cube_count,
+        });
+        offset += chunk_size;
+    }
+
+    chunks
+}
+
 pub fn tensor_vectorization_factor(
     factors: &[u8],
     shape: &[usize],
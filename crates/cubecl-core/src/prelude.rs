@@ -3,6 +3,7 @@ pub use crate::{CubeLaunch, CubeType, Kernel, RuntimeArg, cube};
 pub use crate::codegen::{KernelExpansion, KernelIntegrator, KernelSettings};
 pub use crate::compute::{
     CompiledKernel, CubeTask, KernelBuilder, KernelDefinition, KernelLauncher, KernelTask,
+    LaunchError,
 };
 pub use crate::frontend::cmma;
 pub use crate::frontend::pipeline;
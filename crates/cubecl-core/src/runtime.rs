@@ -1,6 +1,13 @@
 use crate::DeviceId;
-use crate::{codegen::Compiler, compute::CubeTask, ir::Elem};
-use cubecl_runtime::{channel::ComputeChannel, client::ComputeClient, server::ComputeServer};
+use crate::{
+    codegen::Compiler,
+    compute::CubeTask,
+    ir::{Elem, FloatKind},
+};
+use cubecl_runtime::{
+    DeviceProperties, channel::ComputeChannel, client::ComputeClient, default_client,
+    server::ComputeServer,
+};
 
 pub use cubecl_runtime::channel;
 pub use cubecl_runtime::client;
@@ -12,9 +19,10 @@ pub trait Runtime: Send + Sync + 'static + core::fmt::Debug {
     /// The compiler used to compile the inner representation into tokens.
     type Compiler: Compiler;
     /// The compute server used to run kernels and perform autotuning.
-    type Server: ComputeServer<Kernel = Box<dyn CubeTask<Self::Compiler>>, Feature = Feature>;
+    type Server: ComputeServer<Kernel = Box<dyn CubeTask<Self::Compiler>>, Feature = Feature>
+        + 'static;
     /// The channel used to communicate with the compute server.
-    type Channel: ComputeChannel<Self::Server>;
+    type Channel: ComputeChannel<Self::Server> + 'static;
     /// The device used to retrieve the compute client.
     type Device: Default + Clone + core::fmt::Debug + Send + Sync;
 
@@ -24,6 +32,28 @@ pub trait Runtime: Send + Sync + 'static + core::fmt::Debug {
     /// Retrieve the compute client from the runtime device.
     fn client(device: &Self::Device) -> ComputeClient<Self::Server, Self::Channel>;
 
+    /// Retrieve the client [set as default](Runtime::set_default_client), or the client of the
+    /// default device when none is set.
+    ///
+    /// Meant for code that doesn't pass a client around, functions taking a client explicitly
+    /// always use that client instead.
+    fn default_client() -> ComputeClient<Self::Server, Self::Channel> {
+        default_client::get::<Self, _>().unwrap_or_else(|| Self::client(&Default::default()))
+    }
+
+    /// Set the client returned by [default_client](Runtime::default_client) on every thread,
+    /// replacing the previous one. The runtime keeps the client alive until it's replaced or
+    /// [cleared](Runtime::clear_default_client).
+    fn set_default_client(client: ComputeClient<Self::Server, Self::Channel>) {
+        default_client::set::<Self, _>(client)
+    }
+
+    /// Clear the default client, returning it if one was set, so that
+    /// [default_client](Runtime::default_client) falls back to the client of the default device.
+    fn clear_default_client() -> Option<ComputeClient<Self::Server, Self::Channel>> {
+        default_client::clear::<Self, _>()
+    }
+
     /// The runtime name on the given device.
     fn name(client: &ComputeClient<Self::Server, Self::Channel>) -> &'static str;
 
@@ -35,7 +65,11 @@ pub trait Runtime: Send + Sync + 'static + core::fmt::Debug {
     /// Returns the supported line sizes for the current runtime's compiler.
     fn supported_line_sizes() -> &'static [u8];
 
-    /// Returns all line sizes that are useful to perform IO operation on the given element.
+    /// Returns all line sizes that are useful to perform IO operation on the given element, from
+    /// the widest.
+    ///
+    /// Those are the [supported line sizes](Runtime::supported_line_sizes) fitting in a single
+    /// access of 128 bits, so 16-bit types pack twice as many elements as `f32`.
     fn line_size_elem(elem: &Elem) -> impl Iterator<Item = u8> + Clone {
         Self::supported_line_sizes()
             .iter()
@@ -43,8 +77,117 @@ pub trait Runtime: Send + Sync + 'static + core::fmt::Debug {
             .cloned() // 128 bits
     }
 
+    /// Returns the line sizes natively supported for the given element, from the widest.
+    ///
+    /// Lines of those sizes are loaded and stored with a single vector access. Other
+    /// [supported line sizes](Runtime::supported_line_sizes) still compile, but may be lowered to
+    /// narrower accesses, down to one per element. Defaults to the
+    /// [line sizes fitting in 128 bits](Runtime::line_size_elem), for backends with vector
+    /// accesses of up to 128 bits whatever the number of elements.
+    fn native_line_sizes(elem: &Elem) -> Vec<u8> {
+        Self::line_size_elem(elem).collect()
+    }
+
+    /// The plane size every kernel runs with on the device of the client, when it is guaranteed
+    /// to be fixed, like the warp size of 32 on CUDA. `None` when it can vary from one kernel to
+    /// another, in which case only the runtime `PLANE_DIM` is known.
+    ///
+    /// Pass it to [plane_dim](crate::frontend::plane_dim) as a comptime argument to specialize
+    /// plane algorithms at compile time.
+    fn fixed_plane_dim(client: &ComputeClient<Self::Server, Self::Channel>) -> Option<u32> {
+        client
+            .properties()
+            .hardware_properties()
+            .defined_plane_size()
+    }
+
     /// Returns the maximum cube count on each dimension that can be launched.
     fn max_cube_count() -> (u32, u32, u32);
+
+    /// The capabilities of the given device, in the same form for every runtime.
+    fn features(device: &Self::Device) -> RuntimeFeatures {
+        RuntimeFeatures::from_properties(Self::client(device).properties())
+    }
+}
+
+/// Summary of the capabilities of a device, consistent across runtimes.
+///
+/// Derived from the [features](Feature) and hardware properties registered by each runtime from
+/// its native queries, so portable code can branch on capabilities without knowing the backend.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuntimeFeatures {
+    /// Plane (warp/subgroup) operations are supported.
+    pub supports_plane: bool,
+    /// Cooperative matrix-multiply and accumulate is supported for at least one configuration.
+    pub supports_cmma: bool,
+    /// Every `(m, n, k)` shape supported for cooperative matrix-multiply and accumulate by at
+    /// least one combination of element types, sorted. Only `(8, 8, 8)` on Metal.
+    pub cmma_shapes: Vec<(u8, u8, u8)>,
+    /// `f16` is supported.
+    pub supports_f16: bool,
+    /// `bf16` is supported.
+    pub supports_bf16: bool,
+    /// `f64` is supported.
+    pub supports_f64: bool,
+    /// Atomic addition of floats is supported.
+    pub supports_atomic_float: bool,
+    /// Asynchronous copies through pipelines are supported.
+    pub supports_async_copy: bool,
+    /// Asynchronous copies through barriers are supported.
+    pub supports_barrier: bool,
+    /// Tensor memory accelerator loads are supported.
+    pub supports_tma: bool,
+    /// Clustered launches are supported.
+    pub supports_cube_cluster: bool,
+    /// The minimum size of a plane.
+    pub plane_size_min: u32,
+    /// The maximum size of a plane.
+    pub plane_size_max: u32,
+    /// Maximum amount of shared memory per cube, in bytes.
+    pub max_shared_memory: usize,
+    /// Maximum number of units in a cube.
+    pub max_units_per_cube: u32,
+    /// Maximum number of bindings for a kernel.
+    pub max_bindings: u32,
+}
+
+impl RuntimeFeatures {
+    /// Summarize the capabilities described by the device properties.
+    pub fn from_properties(properties: &DeviceProperties<Feature>) -> Self {
+        let float = |kind| properties.feature_enabled(Feature::Type(Elem::Float(kind)));
+        let hardware = properties.hardware_properties();
+        let mut cmma_shapes = properties
+            .features()
+            .filter_map(|feature| match feature {
+                Feature::Cmma { m, n, k, .. } => Some((*m, *n, *k)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        cmma_shapes.sort();
+        cmma_shapes.dedup();
+
+        Self {
+            supports_plane: properties.feature_enabled(Feature::Plane),
+            supports_cmma: properties
+                .features()
+                .any(|feature| matches!(feature, Feature::Cmma { .. })),
+            cmma_shapes,
+            supports_f16: float(FloatKind::F16),
+            supports_bf16: float(FloatKind::BF16),
+            supports_f64: float(FloatKind::F64),
+            supports_atomic_float: properties
+                .feature_enabled(Feature::AtomicFloat(AtomicFeature::Add)),
+            supports_async_copy: properties.feature_enabled(Feature::Pipeline),
+            supports_barrier: properties.feature_enabled(Feature::Barrier),
+            supports_tma: properties.feature_enabled(Feature::Tma(TmaFeature::Base)),
+            supports_cube_cluster: properties.feature_enabled(Feature::CubeCluster),
+            plane_size_min: hardware.plane_size_min,
+            plane_size_max: hardware.plane_size_max,
+            max_shared_memory: hardware.max_shared_memory_size,
+            max_units_per_cube: hardware.max_units_per_cube,
+            max_bindings: hardware.max_bindings,
+        }
+    }
 }
 
 /// Every feature that can be supported by a [cube runtime](Runtime).
@@ -76,6 +219,9 @@ pub enum Feature {
     CubeCluster,
     /// Enables to change the line size of containers during kernel execution.
     DynamicLineSize,
+    /// Packed dot products of lines of 4 8-bit integers accumulated into 32-bit integers, like
+    /// `dp4a`, used by [dot_i32](crate::prelude::Line::dot_i32).
+    IntegerDotProduct,
 }
 
 /// Atomic features that may be supported by a [cube runtime](Runtime).
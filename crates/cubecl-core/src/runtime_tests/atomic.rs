@@ -124,6 +124,177 @@ pub fn test_kernel_atomic_max<R: Runtime, F: Numeric + CubeElement>(
     assert_eq!(actual[0], F::from_int(12));
 }
 
+#[cube(launch)]
+pub fn kernel_atomic_add_line(
+    groups: &Array<u32>,
+    counts: &Array<Line<u32>>,
+    histogram: &mut Array<Atomic<u32>>,
+) {
+    if ABSOLUTE_POS < groups.len() {
+        atomic_add_line(histogram, groups[ABSOLUTE_POS], counts[ABSOLUTE_POS]);
+    }
+}
+
+/// Builds a histogram with groups of 4 adjacent bins, each unit adding a line of counts to one
+/// group, and compares it to a scalar reference.
+pub fn test_kernel_atomic_add_line<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    if !supports_feature::<R, u32>(&client, AtomicFeature::Add) {
+        println!("u32 Add not supported - skipped");
+        return;
+    };
+
+    let line_size = 4;
+    let num_groups = 3;
+    let num_units = 32;
+    let groups: Vec<u32> = (0..num_units).map(|i| (i * 7 + 1) % num_groups).collect();
+    let counts: Vec<u32> = (0..num_units * line_size).map(|i| i % 5 + 1).collect();
+
+    let mut expected = vec![0; (num_groups * line_size) as usize];
+    for (unit, group) in groups.iter().enumerate() {
+        for lane in 0..line_size as usize {
+            expected[*group as usize * line_size as usize + lane] +=
+                counts[unit * line_size as usize + lane];
+        }
+    }
+
+    let groups_handle = client.create(u32::as_bytes(&groups));
+    let counts_handle = client.create(u32::as_bytes(&counts));
+    let histogram_handle = client.create(u32::as_bytes(&vec![0; expected.len()]));
+
+    kernel_atomic_add_line::launch::<R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new_1d(num_units),
+        unsafe { ArrayArg::from_raw_parts::<u32>(&groups_handle, num_units as usize, 1) },
+        unsafe {
+            ArrayArg::from_raw_parts::<u32>(&counts_handle, num_units as usize, line_size as u8)
+        },
+        unsafe { ArrayArg::from_raw_parts::<u32>(&histogram_handle, expected.len(), 1) },
+    );
+
+    let actual = client.read_one(histogram_handle.binding());
+    let actual = u32::from_bytes(&actual);
+
+    assert_eq!(actual, expected);
+}
+
+#[cube(launch)]
+pub fn kernel_atomic_add_aggregated(counter: &mut Array<Atomic<u32>>, output: &mut Array<u32>) {
+    let keep = ABSOLUTE_POS % 3 != 0;
+    let slot = atomic_add_aggregated(&counter[0], select(keep, 1u32, 0u32));
+
+    if keep {
+        output[slot] = ABSOLUTE_POS;
+    }
+}
+
+/// Appends the positions of two units out of three to a buffer and checks that none are lost.
+pub fn test_kernel_atomic_add_aggregated<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    if !client.properties().feature_enabled(Feature::Plane)
+        || !supports_feature::<R, u32>(&client, AtomicFeature::Add)
+    {
+        println!("Plane or u32 Add not supported - skipped");
+        return;
+    };
+
+    let num_cubes = 4;
+    let cube_dim = CubeDim::new_1d(64);
+    let num_units = num_cubes * cube_dim.num_elems();
+    let expected: Vec<u32> = (0..num_units).filter(|pos| pos % 3 != 0).collect();
+
+    let counter = client.create(u32::as_bytes(&[0]));
+    let output = client.create(u32::as_bytes(&vec![u32::MAX; num_units as usize]));
+
+    kernel_atomic_add_aggregated::launch::<R>(
+        &client,
+        CubeCount::Static(num_cubes, 1, 1),
+        cube_dim,
+        unsafe { ArrayArg::from_raw_parts::<u32>(&counter, 1, 1) },
+        unsafe { ArrayArg::from_raw_parts::<u32>(&output, num_units as usize, 1) },
+    );
+
+    let count = u32::from_bytes(&client.read_one(counter.binding()))[0];
+    assert_eq!(count as usize, expected.len());
+
+    let actual = client.read_one(output.binding());
+    let mut actual = u32::from_bytes(&actual)[..expected.len()].to_vec();
+    actual.sort();
+    assert_eq!(actual, expected);
+}
+
+#[cube(launch)]
+pub fn kernel_shared_histogram(
+    input: &Array<u32>,
+    output: &mut Array<Atomic<u32>>,
+    #[comptime] num_bins: u32,
+) {
+    // The bins, followed by the min and max of the values and a flag claimed by a single unit.
+    let mut shared = SharedMemory::<Atomic<u32>>::new(num_bins + 3);
+    if UNIT_POS < num_bins + 3 {
+        Atomic::store(&shared[UNIT_POS], select(UNIT_POS == num_bins, u32::MAX, 0));
+    }
+    sync_cube();
+
+    let value = input[ABSOLUTE_POS];
+    Atomic::add(&shared[value % num_bins], 1);
+    Atomic::min(&shared[num_bins], value);
+    Atomic::max(&shared[num_bins + 1], value);
+    if Atomic::compare_and_swap(&shared[num_bins + 2], 0, 1) == 0 {
+        Atomic::add(&output[num_bins + 2], 1);
+    }
+    sync_cube();
+
+    if UNIT_POS < num_bins {
+        Atomic::add(&output[UNIT_POS], Atomic::load(&shared[UNIT_POS]));
+    }
+    if UNIT_POS == 0 {
+        Atomic::min(&output[num_bins], Atomic::load(&shared[num_bins]));
+        Atomic::max(&output[num_bins + 1], Atomic::load(&shared[num_bins + 1]));
+    }
+}
+
+/// Builds a histogram per cube with atomics in shared memory, then merges them into the global
+/// histogram.
+pub fn test_kernel_shared_histogram<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    if !supports_feature::<R, u32>(&client, AtomicFeature::Add) {
+        println!("u32 Add not supported - skipped");
+        return;
+    };
+
+    let num_cubes = 4;
+    let num_bins = 16;
+    let cube_dim = CubeDim::new_1d(64);
+    let num_units = num_cubes * cube_dim.num_elems();
+    let input: Vec<u32> = (0..num_units).map(|pos| (pos * 7 + 3) % 1000).collect();
+
+    let mut expected = vec![0; num_bins as usize];
+    for value in input.iter() {
+        expected[(value % num_bins) as usize] += 1;
+    }
+    expected.push(*input.iter().min().unwrap());
+    expected.push(*input.iter().max().unwrap());
+    expected.push(num_cubes);
+
+    let mut initial = vec![0; num_bins as usize + 3];
+    initial[num_bins as usize] = u32::MAX;
+    let input_handle = client.create(u32::as_bytes(&input));
+    let output_handle = client.create(u32::as_bytes(&initial));
+
+    kernel_shared_histogram::launch::<R>(
+        &client,
+        CubeCount::Static(num_cubes, 1, 1),
+        cube_dim,
+        unsafe { ArrayArg::from_raw_parts::<u32>(&input_handle, num_units as usize, 1) },
+        unsafe { ArrayArg::from_raw_parts::<u32>(&output_handle, initial.len(), 1) },
+        num_bins,
+    );
+
+    let actual = client.read_one(output_handle.binding());
+    let actual = u32::from_bytes(&actual);
+
+    assert_eq!(actual, expected);
+}
+
 #[allow(missing_docs)]
 #[macro_export]
 macro_rules! testgen_atomic_int {
@@ -153,6 +324,26 @@ macro_rules! testgen_atomic_int {
                 client,
             );
         }
+
+        #[test]
+        fn test_atomic_add_line() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::atomic::test_kernel_atomic_add_line::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_atomic_add_aggregated() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::atomic::test_kernel_atomic_add_aggregated::<TestRuntime>(
+                client,
+            );
+        }
+
+        #[test]
+        fn test_atomic_shared_histogram() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::atomic::test_kernel_shared_histogram::<TestRuntime>(client);
+        }
     };
 }
 
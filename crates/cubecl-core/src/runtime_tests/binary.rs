@@ -1,9 +1,13 @@
 use std::fmt::Display;
 
-use crate::{self as cubecl, as_type};
+use crate::{
+    self as cubecl, Feature, as_type,
+    ir::{Elem, FloatKind},
+};
 
 use cubecl::prelude::*;
 use cubecl_runtime::server::Handle;
+use half::bf16;
 
 #[track_caller]
 pub(crate) fn assert_equals_approx<
@@ -207,6 +211,54 @@ test_mulhi_impl!(
     ]
 );
 
+#[cube(launch_unchecked)]
+fn test_bf16_kernel(
+    lhs: &Array<Line<bf16>>,
+    rhs: &Array<Line<bf16>>,
+    output: &mut Array<Line<bf16>>,
+) {
+    if ABSOLUTE_POS < output.len() {
+        output[ABSOLUTE_POS] = lhs[ABSOLUTE_POS] * rhs[ABSOLUTE_POS] + lhs[ABSOLUTE_POS];
+    }
+}
+
+pub fn test_bf16_elementwise<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    if !client
+        .properties()
+        .feature_enabled(Feature::Type(Elem::Float(FloatKind::BF16)))
+    {
+        return;
+    }
+
+    let lhs = [1.0, 2.0, -3.0, 0.5, 8.0, -0.25, 4.0, 16.0].map(bf16::from_f32);
+    let rhs = [2.0, -1.0, 0.5, 4.0, 0.125, 2.0, -2.0, 0.0].map(bf16::from_f32);
+    let expected: Vec<bf16> = lhs
+        .iter()
+        .zip(rhs.iter())
+        .map(|(l, r)| bf16::from_f32(l.to_f32() * r.to_f32() + l.to_f32()))
+        .collect();
+
+    for line_size in [1, 4] {
+        let lhs_handle = client.create(bf16::as_bytes(&lhs));
+        let rhs_handle = client.create(bf16::as_bytes(&rhs));
+        let output_handle = client.empty(lhs.len() * core::mem::size_of::<bf16>());
+
+        unsafe {
+            test_bf16_kernel::launch_unchecked::<R>(
+                &client,
+                CubeCount::Static(1, 1, 1),
+                CubeDim::new_1d(lhs.len() as u32 / line_size as u32),
+                ArrayArg::from_raw_parts::<bf16>(&lhs_handle, lhs.len(), line_size),
+                ArrayArg::from_raw_parts::<bf16>(&rhs_handle, rhs.len(), line_size),
+                ArrayArg::from_raw_parts::<bf16>(&output_handle, lhs.len(), line_size),
+            )
+        };
+
+        let actual = client.read_one(output_handle.binding());
+        assert_eq!(bf16::from_bytes(&actual), expected.as_slice());
+    }
+}
+
 #[allow(missing_docs)]
 #[macro_export]
 macro_rules! testgen_binary {
@@ -249,6 +301,7 @@ macro_rules! testgen_binary_untyped {
             }
 
             add_test!(test_mulhi);
+            add_test!(test_bf16_elementwise);
         }
     };
 }
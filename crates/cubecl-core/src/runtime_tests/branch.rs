@@ -1,4 +1,4 @@
-use crate::{self as cubecl, as_bytes};
+use crate::{self as cubecl, as_bytes, as_type};
 
 use cubecl::prelude::*;
 
@@ -50,6 +50,18 @@ pub fn kernel_select<F: Float>(output: &mut Array<F>, cond: u32) {
     }
 }
 
+#[cube(launch)]
+pub fn kernel_store_if<F: Float>(
+    input: &Array<F>,
+    output: &mut Array<F>,
+    counter: &mut Array<Atomic<u32>>,
+    len: u32,
+) {
+    let in_bounds = UNIT_POS < len;
+    store_if(in_bounds, output, UNIT_POS, input[UNIT_POS]);
+    atomic_add_if(in_bounds, &counter[0], 1u32);
+}
+
 pub fn test_switch_statement<R: Runtime, F: Float + CubeElement>(
     client: ComputeClient<R::Server, R::Channel>,
 ) {
@@ -162,6 +174,32 @@ pub fn test_select<R: Runtime, F: Float + CubeElement>(
     }
 }
 
+pub fn test_store_if<R: Runtime, F: Float + CubeElement>(
+    client: ComputeClient<R::Server, R::Channel>,
+) {
+    let input = client.create(as_bytes![F: 1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let output = client.create(as_bytes![F: -1.0, -1.0, -1.0, -1.0, -1.0, -1.0]);
+    let counter = client.create(u32::as_bytes(&[10]));
+
+    kernel_store_if::launch::<F, R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new_1d(6),
+        unsafe { ArrayArg::from_raw_parts::<F>(&input, 6, 1) },
+        unsafe { ArrayArg::from_raw_parts::<F>(&output, 6, 1) },
+        unsafe { ArrayArg::from_raw_parts::<u32>(&counter, 1, 1) },
+        ScalarArg::new(4),
+    );
+
+    let actual = client.read_one(output.binding());
+    let actual = F::from_bytes(&actual);
+    let count = client.read_one(counter.binding());
+    let count = u32::from_bytes(&count);
+
+    assert_eq!(actual, as_type![F: 1.0, 2.0, 3.0, 4.0, -1.0, -1.0]);
+    assert_eq!(count[0], 14);
+}
+
 #[allow(missing_docs)]
 #[macro_export]
 macro_rules! testgen_branch {
@@ -213,5 +251,11 @@ macro_rules! testgen_branch {
                 client, false,
             );
         }
+
+        #[test]
+        fn test_store_if() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::branch::test_store_if::<TestRuntime, FloatType>(client);
+        }
     };
 }
@@ -0,0 +1,153 @@
+use crate::{
+    self as cubecl, Feature,
+    ir::{Elem, FloatKind},
+};
+
+use cubecl::prelude::*;
+use half::f16;
+
+#[cube(launch_unchecked)]
+fn kernel_cast_rounded(
+    input: &Array<Line<f32>>,
+    random: &Array<Line<u32>>,
+    rte: &mut Array<Line<f16>>,
+    rtz: &mut Array<Line<f16>>,
+    stochastic: &mut Array<Line<f16>>,
+) {
+    if ABSOLUTE_POS < input.len() {
+        let value = input[ABSOLUTE_POS];
+        rte[ABSOLUTE_POS] = Line::<f16>::cast_rte(value);
+        rtz[ABSOLUTE_POS] = Line::<f16>::cast_rtz(value);
+        stochastic[ABSOLUTE_POS] = Line::<f16>::cast_stochastic(value, random[ABSOLUTE_POS]);
+    }
+}
+
+/// Values on the boundaries of `f16`, with how they round to nearest even and toward zero.
+fn boundary_values() -> [(f32, f32, f32); 10] {
+    let ulp = 2f32.powi(-10);
+    let subnormal_ulp = 2f32.powi(-24);
+    [
+        // Tie between 1 and its successor, to the even 1.
+        (1.0 + ulp / 2.0, 1.0, 1.0),
+        // Tie between the successor of 1 and the next value, to the even next value.
+        (1.0 + 3.0 * ulp / 2.0, 1.0 + 2.0 * ulp, 1.0 + ulp),
+        // Just above a tie.
+        (1.0 + ulp / 2.0 + 2f32.powi(-20), 1.0 + ulp, 1.0),
+        (-(1.0 + 3.0 * ulp / 2.0), -(1.0 + 2.0 * ulp), -(1.0 + ulp)),
+        // Below and on the tie between the largest finite value and infinity.
+        (65519.0, 65504.0, 65504.0),
+        (65520.0, f32::INFINITY, 65504.0),
+        (-1.0e6, f32::NEG_INFINITY, -65504.0),
+        // Ties between subnormal values.
+        (1.5 * subnormal_ulp, 2.0 * subnormal_ulp, subnormal_ulp),
+        (
+            2.5 * subnormal_ulp,
+            2.0 * subnormal_ulp,
+            2.0 * subnormal_ulp,
+        ),
+        (f32::INFINITY, f32::INFINITY, f32::INFINITY),
+    ]
+}
+
+fn launch_cast_rounded<R: Runtime>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: &[f32],
+    random: &[u32],
+    line_size: u8,
+) -> [Vec<f16>; 3] {
+    let input_handle = client.create(f32::as_bytes(input));
+    let random_handle = client.create(u32::as_bytes(random));
+    let outputs = [(); 3].map(|_| client.empty(input.len() * size_of::<f16>()));
+
+    unsafe {
+        kernel_cast_rounded::launch_unchecked::<R>(
+            client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new_1d(input.len() as u32 / line_size as u32),
+            ArrayArg::from_raw_parts::<f32>(&input_handle, input.len(), line_size),
+            ArrayArg::from_raw_parts::<u32>(&random_handle, random.len(), line_size),
+            ArrayArg::from_raw_parts::<f16>(&outputs[0], input.len(), line_size),
+            ArrayArg::from_raw_parts::<f16>(&outputs[1], input.len(), line_size),
+            ArrayArg::from_raw_parts::<f16>(&outputs[2], input.len(), line_size),
+        )
+    };
+
+    outputs.map(|output| f16::from_bytes(&client.read_one(output.binding())).to_vec())
+}
+
+pub fn test_cast_rounded<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    if !client
+        .properties()
+        .feature_enabled(Feature::Type(Elem::Float(FloatKind::F16)))
+    {
+        return;
+    }
+
+    let values = boundary_values();
+    let input = values.map(|(value, _, _)| value);
+    let expected_rte = values.map(|(_, rte, _)| f16::from_f32(rte)).to_vec();
+    let expected_rtz = values.map(|(_, _, rtz)| f16::from_f32(rtz)).to_vec();
+
+    for line_size in [1, 2] {
+        let [rte, rtz, stochastic] = launch_cast_rounded::<R>(&client, &input, &[0; 10], line_size);
+
+        assert_eq!(rte, expected_rte, "Round to nearest even");
+        assert_eq!(rtz, expected_rtz, "Round toward zero");
+        assert_eq!(
+            stochastic, expected_rtz,
+            "Stochastic rounding without random bits rounds toward zero"
+        );
+    }
+}
+
+pub fn test_cast_stochastic<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    if !client
+        .properties()
+        .feature_enabled(Feature::Type(Elem::Float(FloatKind::F16)))
+    {
+        return;
+    }
+
+    let ulp = 2f32.powi(-10);
+    let subnormal_ulp = 2f32.powi(-24);
+    // With all random bits set, values that aren't representable round away from zero, except
+    // past the largest finite value and below the smallest normal value.
+    let values = [
+        (1.0, 1.0),
+        (1.0 + ulp / 2.0, 1.0 + ulp),
+        (1.0 + 2f32.powi(-20), 1.0 + ulp),
+        (-(1.0 + 3.0 * ulp / 2.0), -(1.0 + 2.0 * ulp)),
+        (65504.0, 65504.0),
+        (65519.0, 65504.0),
+        (-1.0e6, -65504.0),
+        (1.5 * subnormal_ulp, subnormal_ulp),
+        (f32::INFINITY, f32::INFINITY),
+        (f32::NEG_INFINITY, f32::NEG_INFINITY),
+    ];
+    let input = values.map(|(value, _)| value);
+    let expected = values.map(|(_, rounded)| f16::from_f32(rounded)).to_vec();
+
+    let [_, _, stochastic] = launch_cast_rounded::<R>(&client, &input, &[u32::MAX; 10], 1);
+
+    assert_eq!(stochastic, expected);
+}
+
+#[allow(missing_docs)]
+#[macro_export]
+macro_rules! testgen_cast {
+    () => {
+        use super::*;
+
+        #[test]
+        fn test_cast_rounded() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::cast::test_cast_rounded::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_cast_stochastic() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::cast::test_cast_stochastic::<TestRuntime>(client);
+        }
+    };
+}
@@ -0,0 +1,80 @@
+use crate as cubecl;
+
+use cubecl::prelude::*;
+
+#[cube]
+fn apply<F: Fn(f32) -> f32>(data: &mut Array<f32>, f: F) {
+    data[UNIT_POS] = f(data[UNIT_POS]);
+}
+
+#[cube]
+fn apply_twice(data: &mut Array<f32>, f: impl Fn(f32) -> f32) {
+    data[UNIT_POS] = f(f(data[UNIT_POS]));
+}
+
+#[cube(launch)]
+fn kernel_closure(data: &mut Array<f32>) {
+    apply(data, |x| x * 2.0 + 1.0);
+}
+
+#[cube(launch)]
+fn kernel_closure_capturing_comptime(data: &mut Array<f32>, #[comptime] double: bool) {
+    apply_twice(data, |x| if double { x * 2.0 } else { x + 1.0 });
+}
+
+pub fn test_closure<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let data = client.create(f32::as_bytes(&[0.0, 1.0, 2.0, 3.0]));
+
+    kernel_closure::launch::<R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new_1d(4),
+        unsafe { ArrayArg::from_raw_parts::<f32>(&data, 4, 1) },
+    );
+
+    let actual = client.read_one(data.binding());
+    let actual = f32::from_bytes(&actual);
+
+    assert_eq!(actual, &[1.0, 3.0, 5.0, 7.0]);
+}
+
+pub fn test_closure_capturing_comptime<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    for (double, expected) in [(true, [0.0, 4.0, 8.0, 12.0]), (false, [2.0, 3.0, 4.0, 5.0])] {
+        let data = client.create(f32::as_bytes(&[0.0, 1.0, 2.0, 3.0]));
+
+        kernel_closure_capturing_comptime::launch::<R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new_1d(4),
+            unsafe { ArrayArg::from_raw_parts::<f32>(&data, 4, 1) },
+            double,
+        );
+
+        let actual = client.read_one(data.binding());
+        let actual = f32::from_bytes(&actual);
+
+        assert_eq!(actual, &expected, "double: {double}");
+    }
+}
+
+#[allow(missing_docs)]
+#[macro_export]
+macro_rules! testgen_closure {
+    () => {
+        use super::*;
+
+        #[test]
+        fn test_closure() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::closure::test_closure::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_closure_capturing_comptime() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::closure::test_closure_capturing_comptime::<TestRuntime>(
+                client,
+            );
+        }
+    };
+}
@@ -62,6 +62,42 @@ pub fn test_cluster_meta<R: Runtime>(client: ComputeClient<R::Server, R::Channel
     assert_eq!(actual, &expected);
 }
 
+#[cube(launch, cluster_dim = CubeDim::new_1d(2))]
+fn cluster_exchange_kernel(out: &mut Array<u32>) {
+    let mut shared = SharedMemory::<u32>::new(32);
+    shared[UNIT_POS] = CUBE_POS * 100 + UNIT_POS;
+
+    sync_cluster();
+    out[ABSOLUTE_POS] = shared.cluster_load(1 - CUBE_POS_CLUSTER, UNIT_POS);
+    // Keep the shared memory alive until the other cube is done reading it.
+    sync_cluster();
+}
+
+/// Each cube of a 2x1x1 cluster reads the shared memory written by the other one.
+pub fn test_cluster_shared_exchange<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    if !client.properties().feature_enabled(Feature::CubeCluster) {
+        return;
+    }
+
+    let handle = client.empty(64 * size_of::<u32>());
+
+    cluster_exchange_kernel::launch::<R>(
+        &client,
+        CubeCount::Static(2, 1, 1),
+        CubeDim::new_1d(32),
+        unsafe { ArrayArg::from_raw_parts::<u32>(&handle, 64, 1) },
+    );
+
+    let actual = client.read_one(handle.binding());
+    let actual = u32::from_bytes(&actual);
+
+    let expected: Vec<u32> = (0..2)
+        .flat_map(|cube| (0..32).map(move |unit| (1 - cube) * 100 + unit))
+        .collect();
+
+    assert_eq!(actual, &expected);
+}
+
 #[allow(missing_docs)]
 #[macro_export]
 macro_rules! testgen_cluster {
@@ -73,5 +109,13 @@ macro_rules! testgen_cluster {
             let client = TestRuntime::client(&Default::default());
             cubecl_core::runtime_tests::cluster::test_cluster_meta::<TestRuntime>(client);
         }
+
+        #[test]
+        fn test_cluster_shared_exchange() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::cluster::test_cluster_shared_exchange::<TestRuntime>(
+                client,
+            );
+        }
     };
 }
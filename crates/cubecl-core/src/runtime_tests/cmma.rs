@@ -567,6 +567,98 @@ pub fn test_cmma_strided<R: Runtime>(
     assert_eq!(expected, actual);
 }
 
+#[cube(launch)]
+/// Executes Out = Lhs @ Rhs with a 32x32x8 matrix, which is only supported by MFMA.
+pub fn kernel_32x32x8(lhs: &Array<f16>, rhs: &Array<f16>, out: &mut Array<f32>) {
+    let a = cmma::Matrix::<f16>::from_slice(
+        cmma::MatrixIdent::A,
+        32,
+        32,
+        8,
+        cmma::MatrixLayout::RowMajor,
+        &lhs.to_slice(),
+        8,
+    );
+    let b = cmma::Matrix::<f16>::from_slice(
+        cmma::MatrixIdent::B,
+        32,
+        32,
+        8,
+        cmma::MatrixLayout::RowMajor,
+        &rhs.to_slice(),
+        32,
+    );
+    let c = cmma::Matrix::<f32>::from_value(
+        cmma::MatrixIdent::Accumulator,
+        32,
+        32,
+        8,
+        cmma::MatrixLayout::Undefined,
+        0.0,
+    );
+
+    cmma::execute::<f16, f16, f32, f32>(&a, &b, &c, &c);
+
+    cmma::store(
+        &mut out.to_slice_mut(),
+        &c,
+        32,
+        cmma::MatrixLayout::RowMajor,
+    );
+}
+
+pub fn test_cmma_32x32x8<R: Runtime>(
+    client: ComputeClient<R::Server, R::Channel>,
+    cube_dimensions: CubeDim,
+) {
+    let (m, n, k) = (32, 32, 8);
+    if !client.properties().feature_enabled(Feature::Cmma {
+        a: Elem::Float(FloatKind::F16),
+        b: Elem::Float(FloatKind::F16),
+        c: Elem::Float(FloatKind::F32),
+        m: m as u8,
+        k: k as u8,
+        n: n as u8,
+    }) {
+        // We can't execute the test, skip.
+        return;
+    }
+
+    let lhs: Vec<f32> = (0..m * k).map(|i| (i % 7) as f32).collect();
+    let rhs: Vec<f32> = (0..k * n).map(|i| (i % 5) as f32).collect();
+
+    let mut expected = vec![0.0f32; m * n];
+    for row in 0..m {
+        for col in 0..n {
+            for i in 0..k {
+                expected[row * n + col] += lhs[row * k + i] * rhs[i * n + col];
+            }
+        }
+    }
+
+    let lhs: Vec<f16> = lhs.into_iter().map(f16::from_f32).collect();
+    let rhs: Vec<f16> = rhs.into_iter().map(f16::from_f32).collect();
+    let lhs = client.create(f16::as_bytes(&lhs));
+    let rhs = client.create(f16::as_bytes(&rhs));
+    let out = client.empty(core::mem::size_of::<f32>() * m * n);
+
+    unsafe {
+        kernel_32x32x8::launch::<R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            cube_dimensions,
+            ArrayArg::from_raw_parts::<f16>(&lhs, m * k, 1),
+            ArrayArg::from_raw_parts::<f16>(&rhs, k * n, 1),
+            ArrayArg::from_raw_parts::<f32>(&out, m * n, 1),
+        )
+    };
+
+    let actual = client.read_one(out.binding());
+    let actual = f32::from_bytes(&actual);
+
+    assert_eq!(expected, actual);
+}
+
 #[allow(missing_docs)]
 #[macro_export]
 macro_rules! testgen_cmma {
@@ -621,5 +713,16 @@ macro_rules! testgen_cmma {
                 cube_dimensions,
             );
         }
+
+        #[test]
+        fn test_cmma_32x32x8() {
+            let client = TestRuntime::client(&Default::default());
+            // MFMA instructions run on a full wavefront of 64 lanes
+            let cube_dimensions = CubeDim::new(64, 1, 1);
+            cubecl_core::runtime_tests::cmma::test_cmma_32x32x8::<TestRuntime>(
+                client,
+                cube_dimensions,
+            );
+        }
     };
 }
@@ -0,0 +1,679 @@
+use crate::{
+    self as cubecl, ExecutionMode, Kernel, KernelId, KernelSettings,
+    compute::{
+        BindingInfo, BindingKind, KernelBuilder, KernelCost, KernelDefinition, KernelTask,
+        Visibility,
+    },
+    ir::{Elem, FloatKind, Item, Scope, UIntKind},
+    server::{CompilationError, LaunchFeasibility},
+};
+
+use cubecl::prelude::*;
+
+#[cube]
+fn reverse_through_shared_memory(output: &mut Array<f32>, #[comptime] length: u32) {
+    let mut shared = SharedMemory::<f32>::new(length);
+
+    shared[UNIT_POS] = output[UNIT_POS];
+    sync_cube();
+    output[UNIT_POS] = shared[length - 1 - UNIT_POS];
+}
+
+/// Kernel declaring a shared memory of `length` elements.
+struct SharedMemoryKernel {
+    length: u32,
+}
+
+impl Kernel for SharedMemoryKernel {
+    fn define(&self) -> KernelDefinition {
+        let mut builder = KernelBuilder::default();
+        let output = builder.output_array(Item::new(Elem::Float(FloatKind::F32)));
+
+        reverse_through_shared_memory::expand(&mut builder.context, output.into(), self.length);
+
+        builder.build(
+            KernelSettings::default()
+                .cube_dim(CubeDim::new_1d(32))
+                .kernel_name("shared_memory_kernel"),
+        )
+    }
+
+    fn id(&self) -> KernelId {
+        KernelId::new::<Self>().info(self.length)
+    }
+}
+
+fn shared_memory_kernel<R: Runtime>(length: u32) -> Box<dyn CubeTask<R::Compiler>> {
+    Box::new(KernelTask::<R::Compiler, _>::new(SharedMemoryKernel {
+        length,
+    }))
+}
+
+pub fn test_shared_memory_within_limit<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let result = client.compile(shared_memory_kernel::<R>(32));
+
+    assert!(result.is_ok(), "{result:?}");
+}
+
+pub fn test_shared_memory_exceeded<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let limit = client
+        .properties()
+        .hardware_properties()
+        .max_shared_memory_size;
+    let length = (limit / core::mem::size_of::<f32>()) as u32 + 1024;
+
+    let result = client.compile(shared_memory_kernel::<R>(length));
+
+    assert_eq!(
+        result,
+        Err(CompilationError::SharedMemoryExceeded {
+            requested: length as usize * core::mem::size_of::<f32>(),
+            limit,
+        })
+    );
+}
+
+pub fn test_can_launch<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let max = client.properties().hardware_properties().max_units_per_cube;
+
+    let feasibility = client.can_launch(
+        shared_memory_kernel::<R>(32),
+        CubeDim::new_1d(max * 2),
+        32 * core::mem::size_of::<f32>(),
+    );
+    assert_eq!(
+        feasibility,
+        LaunchFeasibility::UnitsPerCubeExceeded {
+            requested: max * 2,
+            max,
+        }
+    );
+
+    let feasibility = client.can_launch(
+        shared_memory_kernel::<R>(32),
+        CubeDim::new_1d(32),
+        32 * core::mem::size_of::<f32>(),
+    );
+    assert!(feasibility.is_feasible(), "{feasibility}");
+}
+
+#[cube]
+fn gather_through_shared_memories(output: &mut Array<f32>, #[comptime] length: u32) {
+    let mut values = SharedMemory::<f32>::new(length);
+    let mut indices = SharedMemory::<u32>::new(length);
+
+    values[UNIT_POS] = output[UNIT_POS];
+    indices[UNIT_POS] = length - 1 - UNIT_POS;
+    sync_cube();
+    output[UNIT_POS] = values[indices[UNIT_POS]];
+}
+
+/// Kernel declaring two shared memories of `length` elements of different types.
+struct SharedMemoriesKernel {
+    length: u32,
+}
+
+impl Kernel for SharedMemoriesKernel {
+    fn define(&self) -> KernelDefinition {
+        let mut builder = KernelBuilder::default();
+        let output = builder.output_array(Item::new(Elem::Float(FloatKind::F32)));
+
+        gather_through_shared_memories::expand(&mut builder.context, output.into(), self.length);
+
+        builder.build(
+            KernelSettings::default()
+                .cube_dim(CubeDim::new_1d(32))
+                .kernel_name("shared_memories_kernel"),
+        )
+    }
+
+    fn id(&self) -> KernelId {
+        KernelId::new::<Self>().info(self.length)
+    }
+}
+
+/// Checks that the shared memory limit applies to the total of all the shared memories, when
+/// each of them is within the limit.
+pub fn test_shared_memories_exceeded<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let limit = client
+        .properties()
+        .hardware_properties()
+        .max_shared_memory_size;
+    let length = (limit / (2 * size_of::<f32>())).next_multiple_of(4) as u32 + 256;
+
+    let kernel = Box::new(KernelTask::<R::Compiler, _>::new(SharedMemoriesKernel {
+        length,
+    }));
+    let result = client.compile(kernel);
+
+    assert_eq!(
+        result,
+        Err(CompilationError::SharedMemoryExceeded {
+            requested: length as usize * (size_of::<f32>() + size_of::<u32>()),
+            limit,
+        })
+    );
+}
+
+#[cube(launch, create_dummy_kernel, name = "attention fwd-2")]
+fn kernel_named(output: &mut Array<f32>) {
+    output[UNIT_POS] = f32::new(1.0);
+}
+
+/// Compiles a kernel with a custom name and checks that the sanitized name is used as its entry
+/// point.
+pub fn test_kernel_name<R: Runtime>(
+    client: ComputeClient<R::Server, R::Channel>,
+    compiler: &mut R::Compiler,
+) {
+    let output = client.empty(core::mem::size_of::<f32>());
+    let kernel = kernel_named::create_dummy_kernel::<R>(
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new_1d(1),
+        unsafe { ArrayArg::from_raw_parts::<f32>(&output, 1, 1) },
+    );
+    let compiled = KernelTask::<R::Compiler, _>::new(kernel)
+        .compile(compiler, &Default::default(), ExecutionMode::Checked)
+        .unwrap();
+
+    assert_eq!(compiled.entrypoint_name, "attention_fwd_2");
+    assert!(compiled.source.contains("attention_fwd_2"));
+}
+
+#[cube(launch, create_dummy_kernel)]
+fn kernel_scaled_gather(
+    input: &Tensor<Line<f32>>,
+    indices: &Array<u32>,
+    output: &mut Array<f32>,
+    scale: f32,
+    offset: u32,
+) {
+    let line = input[indices[UNIT_POS] + offset];
+    output[UNIT_POS] = line[0] * scale;
+}
+
+/// Compiles a kernel and checks that the reported bindings match its signature.
+pub fn test_kernel_bindings<R: Runtime>(
+    client: ComputeClient<R::Server, R::Channel>,
+    compiler: &mut R::Compiler,
+) {
+    let input = client.empty(4 * core::mem::size_of::<f32>());
+    let indices = client.empty(core::mem::size_of::<u32>());
+    let output = client.empty(core::mem::size_of::<f32>());
+    let kernel = kernel_scaled_gather::create_dummy_kernel::<R>(
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new_1d(1),
+        unsafe { TensorArg::from_raw_parts::<f32>(&input, &[4, 1], &[1, 4], 4) },
+        unsafe { ArrayArg::from_raw_parts::<u32>(&indices, 1, 1) },
+        unsafe { ArrayArg::from_raw_parts::<f32>(&output, 1, 1) },
+        ScalarArg::new(2.0),
+        ScalarArg::new(0),
+    );
+    let compiled = KernelTask::<R::Compiler, _>::new(kernel)
+        .compile(compiler, &Default::default(), ExecutionMode::Checked)
+        .unwrap();
+
+    let float = Elem::Float(FloatKind::F32);
+    let uint = Elem::UInt(UIntKind::U32);
+    let line = |elem, line_size| Item::vectorized(elem, core::num::NonZero::new(line_size));
+
+    assert_eq!(
+        compiled.bindings(),
+        &[
+            BindingInfo {
+                kind: BindingKind::Tensor,
+                item: line(float, 4),
+                visibility: Visibility::Read,
+            },
+            BindingInfo {
+                kind: BindingKind::Array,
+                item: line(uint, 1),
+                visibility: Visibility::Read,
+            },
+            BindingInfo {
+                kind: BindingKind::Array,
+                item: line(float, 1),
+                visibility: Visibility::ReadWrite,
+            },
+            BindingInfo {
+                kind: BindingKind::Scalar { count: 1 },
+                item: Item::new(float),
+                visibility: Visibility::Read,
+            },
+            BindingInfo {
+                kind: BindingKind::Scalar { count: 1 },
+                item: Item::new(uint),
+                visibility: Visibility::Read,
+            },
+        ]
+    );
+}
+
+#[cube(launch, create_dummy_kernel)]
+fn kernel_multiply_accumulate(input: &Array<Line<f32>>, output: &mut Array<Line<f32>>) {
+    let mut acc = input[0];
+    for i in 1..4 {
+        acc = acc * input[i] + acc;
+    }
+    output[UNIT_POS] = acc;
+}
+
+/// Compiles a kernel and checks that its operations and global memory traffic match a count by
+/// hand, then places it on the roofline of the device.
+pub fn test_roofline_estimate<R: Runtime>(
+    client: ComputeClient<R::Server, R::Channel>,
+    compiler: &mut R::Compiler,
+) {
+    let input = client.empty(16 * core::mem::size_of::<f32>());
+    let output = client.empty(4 * core::mem::size_of::<f32>());
+    let kernel = kernel_multiply_accumulate::create_dummy_kernel::<R>(
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new_1d(1),
+        unsafe { ArrayArg::from_raw_parts::<f32>(&input, 4, 4) },
+        unsafe { ArrayArg::from_raw_parts::<f32>(&output, 1, 4) },
+    );
+    let compiled = KernelTask::<R::Compiler, _>::new(kernel)
+        .compile(compiler, &Default::default(), ExecutionMode::Checked)
+        .unwrap();
+
+    // 3 iterations of a multiply and an add over lines of 4 elements.
+    let flops = 3 * 2 * 4;
+    // 4 lines of 4 `f32` read from the input and one written to the output.
+    let global_bytes = (4 + 1) * 4 * core::mem::size_of::<f32>() as u64;
+    assert_eq!(
+        compiled.cost,
+        KernelCost {
+            flops,
+            global_bytes
+        }
+    );
+
+    let estimate = compiled.roofline_estimate(client.properties());
+    assert_eq!(estimate.flops, flops);
+    assert_eq!(estimate.global_bytes, global_bytes);
+    assert_eq!(estimate.arithmetic_intensity, 24.0 / 80.0);
+
+    let hardware = client.properties().hardware_properties();
+    if let (Some(peak_flops), Some(peak_bandwidth)) =
+        (hardware.peak_flops, hardware.peak_memory_bandwidth)
+    {
+        assert_eq!(estimate.ridge_point, Some(peak_flops / peak_bandwidth));
+        assert_eq!(
+            estimate.attainable_flops,
+            Some(peak_flops.min(peak_bandwidth * 24.0 / 80.0))
+        );
+    } else {
+        assert_eq!(estimate.ridge_point, None);
+        assert_eq!(estimate.is_memory_bound(), None);
+    }
+}
+
+#[cube(inline, debug_symbols)]
+fn add_one_attributed(value: f32) -> f32 {
+    value + 1.0
+}
+
+#[cube(debug_symbols)]
+fn add_one_own_function(value: f32) -> f32 {
+    value + 1.0
+}
+
+#[cube(debug_symbols)]
+fn add_ones(output: &mut Array<f32>) {
+    let value = add_one_attributed(output[UNIT_POS]);
+    output[UNIT_POS] = add_one_own_function(value);
+}
+
+/// Kernel calling an `inline` and a regular helper, with debug symbols enabled.
+struct HelpersKernel;
+
+impl Kernel for HelpersKernel {
+    fn define(&self) -> KernelDefinition {
+        let mut builder = KernelBuilder::default();
+        builder.context = Scope::root(true);
+        let output = builder.output_array(Item::new(Elem::Float(FloatKind::F32)));
+
+        add_ones::expand(&mut builder.context, output.into());
+
+        builder.build(
+            KernelSettings::default()
+                .cube_dim(CubeDim::new_1d(1))
+                .kernel_name("helpers_kernel")
+                .debug_symbols(),
+        )
+    }
+
+    fn id(&self) -> KernelId {
+        KernelId::new::<Self>()
+    }
+}
+
+/// Compiles a kernel with debug symbols and checks that only the helper without `inline` shows up
+/// as a separate debug function.
+pub fn test_inline_helper_debug_info<R: Runtime>(compiler: &mut R::Compiler) {
+    let compiled = KernelTask::<R::Compiler, _>::new(HelpersKernel)
+        .compile(compiler, &Default::default(), ExecutionMode::Checked)
+        .unwrap();
+
+    assert!(!compiled.source.contains("add_one_attributed"));
+    assert!(compiled.source.contains("add_one_own_function"));
+}
+
+#[cube(inline)]
+fn add_one_inlined(value: f32) -> f32 {
+    value + 1.0
+}
+
+#[cube(noinline)]
+fn add_one_not_inlined(value: f32) -> f32 {
+    value + 1.0
+}
+
+#[cube]
+fn call_inlined(output: &mut Array<f32>) {
+    output[UNIT_POS] = add_one_inlined(output[UNIT_POS]);
+}
+
+#[cube]
+fn call_not_inlined(output: &mut Array<f32>) {
+    output[UNIT_POS] = add_one_not_inlined(output[UNIT_POS]);
+}
+
+#[cube]
+fn written_out(output: &mut Array<f32>) {
+    output[UNIT_POS] = output[UNIT_POS] + 1.0;
+}
+
+/// Kernel adding one to its output with `body`, always under the same name so the sources of
+/// different bodies can be compared.
+struct AddOneKernel {
+    body: fn(&mut Scope, ExpandElementTyped<Array<f32>>),
+}
+
+impl Kernel for AddOneKernel {
+    fn define(&self) -> KernelDefinition {
+        let mut builder = KernelBuilder::default();
+        let output = builder.output_array(Item::new(Elem::Float(FloatKind::F32)));
+
+        (self.body)(&mut builder.context, output.into());
+
+        builder.build(
+            KernelSettings::default()
+                .cube_dim(CubeDim::new_1d(1))
+                .kernel_name("add_one_kernel"),
+        )
+    }
+
+    fn id(&self) -> KernelId {
+        KernelId::new::<Self>().info(self.body as usize)
+    }
+}
+
+fn add_one_source<R: Runtime>(
+    compiler: &mut R::Compiler,
+    body: fn(&mut Scope, ExpandElementTyped<Array<f32>>),
+) -> String {
+    KernelTask::<R::Compiler, _>::new(AddOneKernel { body })
+        .compile(compiler, &Default::default(), ExecutionMode::Checked)
+        .unwrap()
+        .source
+}
+
+/// Checks that calling an `inline` helper compiles to the same source as writing its body in the
+/// caller, so no separate function is left.
+pub fn test_inline_helper<R: Runtime>(compiler: &mut R::Compiler) {
+    let inlined = add_one_source::<R>(compiler, call_inlined::expand);
+    let manual = add_one_source::<R>(compiler, written_out::expand);
+
+    assert_eq!(inlined, manual);
+}
+
+/// Checks that calling a `noinline` helper compiles to a separate function, marked with
+/// `noinline_marker` in the source.
+pub fn test_noinline_helper<R: Runtime>(compiler: &mut R::Compiler, noinline_marker: &str) {
+    let not_inlined = add_one_source::<R>(compiler, call_not_inlined::expand);
+    let manual = add_one_source::<R>(compiler, written_out::expand);
+
+    assert!(not_inlined.contains(noinline_marker));
+    assert!(not_inlined.contains("add_one_not_inlined"));
+    assert!(!manual.contains(noinline_marker));
+}
+
+/// Compiles a kernel with debug symbols and checks that its source maps the instructions back to
+/// this file, with `line_marker` marking a line.
+pub fn test_debug_line_info<R: Runtime>(compiler: &mut R::Compiler, line_marker: &str) {
+    let compiled = KernelTask::<R::Compiler, _>::new(HelpersKernel)
+        .compile(compiler, &Default::default(), ExecutionMode::Checked)
+        .unwrap();
+
+    assert!(compiled.source.contains(line_marker));
+    assert!(compiled.source.contains("compilation.rs"));
+}
+
+#[cube(launch, create_dummy_kernel, fast_math = FastMath::all())]
+fn kernel_fast_math(output: &mut Array<f32>) {
+    output[UNIT_POS] = output[UNIT_POS] * 2.0 + 1.0;
+}
+
+/// Kernel using fast math, so its code depends on what the compile target supports.
+pub fn fast_math_kernel<R: Runtime>(
+    client: &ComputeClient<R::Server, R::Channel>,
+) -> Box<dyn CubeTask<R::Compiler>> {
+    let output = client.empty(core::mem::size_of::<f32>());
+    let kernel = kernel_fast_math::create_dummy_kernel::<R>(
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new_1d(1),
+        unsafe { ArrayArg::from_raw_parts::<f32>(&output, 1, 1) },
+    );
+
+    Box::new(KernelTask::<R::Compiler, _>::new(kernel))
+}
+
+#[cube(launch, create_dummy_kernel)]
+fn kernel_spill(input: &Array<f32>, output: &mut Array<f32>) {
+    let mut values = Array::<f32>::new(512);
+    let mut value = input[UNIT_POS];
+
+    #[unroll]
+    for i in 0..512 {
+        value = value * input[i] + 1.0;
+        values[i] = value;
+    }
+
+    // Reading the values in reverse order keeps all of them alive at once.
+    let mut sum = 0.0;
+    #[unroll]
+    for i in 0..512 {
+        sum += values[511 - i] * input[i];
+    }
+    output[UNIT_POS] = sum;
+}
+
+/// Kernel keeping more values alive than there are registers, so they are spilled to local
+/// memory.
+pub fn spilling_kernel<R: Runtime>(
+    client: &ComputeClient<R::Server, R::Channel>,
+) -> Box<dyn CubeTask<R::Compiler>> {
+    let input = client.empty(512 * core::mem::size_of::<f32>());
+    let output = client.empty(32 * core::mem::size_of::<f32>());
+    let kernel = kernel_spill::create_dummy_kernel::<R>(
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new_1d(32),
+        unsafe { ArrayArg::from_raw_parts::<f32>(&input, 512, 1) },
+        unsafe { ArrayArg::from_raw_parts::<f32>(&output, 32, 1) },
+    );
+
+    Box::new(KernelTask::<R::Compiler, _>::new(kernel))
+}
+
+#[cube(launch, create_dummy_kernel)]
+fn kernel_memory_fence(
+    output: &mut Array<u32>,
+    #[comptime] memory_scope: MemoryScope,
+    #[comptime] order: MemoryOrder,
+) {
+    output[0] = 1;
+    memory_fence(memory_scope, order);
+    output[1] = 1;
+}
+
+/// Compiles a kernel with a memory fence for each scope and order, and checks that the source
+/// contains the instruction `expected` returns for it.
+pub fn test_memory_fences<R: Runtime>(
+    client: ComputeClient<R::Server, R::Channel>,
+    compiler: &mut R::Compiler,
+    expected: impl Fn(MemoryScope, MemoryOrder) -> &'static str,
+) {
+    let output = client.empty(2 * core::mem::size_of::<u32>());
+
+    for memory_scope in [MemoryScope::Cube, MemoryScope::Device] {
+        for order in [
+            MemoryOrder::Acquire,
+            MemoryOrder::Release,
+            MemoryOrder::AcquireRelease,
+        ] {
+            let kernel = kernel_memory_fence::create_dummy_kernel::<R>(
+                CubeCount::Static(1, 1, 1),
+                CubeDim::new_1d(1),
+                unsafe { ArrayArg::from_raw_parts::<u32>(&output, 2, 1) },
+                memory_scope,
+                order,
+            );
+            let compiled = KernelTask::<R::Compiler, _>::new(kernel)
+                .compile(compiler, &Default::default(), ExecutionMode::Checked)
+                .unwrap();
+
+            let instruction = expected(memory_scope, order);
+            assert!(
+                compiled.source.contains(instruction),
+                "{memory_scope:?} {order:?} fence should compile to {instruction}"
+            );
+        }
+    }
+}
+
+#[cube(launch, create_dummy_kernel)]
+fn kernel_cmma_fill(output: &mut Array<f32>) {
+    let matrix = cmma::Matrix::<f32>::from_value(
+        cmma::MatrixIdent::Accumulator,
+        16,
+        16,
+        16,
+        cmma::MatrixLayout::Undefined,
+        1.0,
+    );
+    cmma::store(
+        &mut output.to_slice_mut(),
+        &matrix,
+        16,
+        cmma::MatrixLayout::RowMajor,
+    );
+}
+
+/// Compiles a kernel using cooperative matrices with a compiler that doesn't support them, and
+/// checks that it's rejected with [CompilationError::UnsupportedInstruction] from `backend`.
+pub fn test_unsupported_instruction<R: Runtime>(
+    client: ComputeClient<R::Server, R::Channel>,
+    compiler: &mut R::Compiler,
+    backend: &'static str,
+) {
+    let output = client.empty(256 * core::mem::size_of::<f32>());
+    let kernel = kernel_cmma_fill::create_dummy_kernel::<R>(
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new_1d(32),
+        unsafe { ArrayArg::from_raw_parts::<f32>(&output, 256, 1) },
+    );
+
+    assert_unsupported::<R, _>(
+        kernel,
+        compiler,
+        "cooperative matrix-multiply and accumulate",
+        backend,
+    );
+}
+
+/// Compiles a kernel with a memory fence for each scope with a compiler that doesn't support
+/// them, and checks that it's rejected with [CompilationError::UnsupportedInstruction] from
+/// `backend`.
+pub fn test_unsupported_memory_fences<R: Runtime>(
+    client: ComputeClient<R::Server, R::Channel>,
+    compiler: &mut R::Compiler,
+    backend: &'static str,
+) {
+    let output = client.empty(2 * core::mem::size_of::<u32>());
+
+    for memory_scope in [MemoryScope::Cube, MemoryScope::Device] {
+        let kernel = kernel_memory_fence::create_dummy_kernel::<R>(
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new_1d(1),
+            unsafe { ArrayArg::from_raw_parts::<u32>(&output, 2, 1) },
+            memory_scope,
+            MemoryOrder::AcquireRelease,
+        );
+
+        assert_unsupported::<R, _>(kernel, compiler, "memory fence", backend);
+    }
+}
+
+fn assert_unsupported<R: Runtime, K: Kernel>(
+    kernel: K,
+    compiler: &mut R::Compiler,
+    expected_op: &str,
+    backend: &'static str,
+) {
+    let result = KernelTask::<R::Compiler, _>::new(kernel).compile(
+        compiler,
+        &Default::default(),
+        ExecutionMode::Checked,
+    );
+
+    match result {
+        Err(CompilationError::UnsupportedInstruction {
+            op,
+            backend: actual,
+        }) => {
+            assert_eq!(op, expected_op);
+            assert_eq!(actual, backend);
+        }
+        Err(err) => panic!("Expected an unsupported instruction, got {err}"),
+        Ok(_) => panic!("Expected an unsupported instruction, the kernel compiled"),
+    }
+}
+
+#[allow(missing_docs)]
+#[macro_export]
+macro_rules! testgen_compilation {
+    () => {
+        use super::*;
+
+        #[test]
+        fn test_compilation_shared_memory_within_limit() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::compilation::test_shared_memory_within_limit::<TestRuntime>(
+                client,
+            );
+        }
+
+        #[test]
+        fn test_compilation_shared_memory_exceeded() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::compilation::test_shared_memory_exceeded::<TestRuntime>(
+                client,
+            );
+        }
+
+        #[test]
+        fn test_compilation_shared_memories_exceeded() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::compilation::test_shared_memories_exceeded::<TestRuntime>(
+                client,
+            );
+        }
+
+        #[test]
+        fn test_compilation_can_launch() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::compilation::test_can_launch::<TestRuntime>(client);
+        }
+    };
+}
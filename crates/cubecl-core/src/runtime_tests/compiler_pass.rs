@@ -0,0 +1,70 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::{self as cubecl, CompilerPass, ir::NonSemantic, ir::Operation, ir::Scope};
+
+use cubecl::prelude::*;
+
+const MARKER: &str = "compiler_pass_marker";
+
+static OBSERVED_INSTRUCTIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// Records the number of instructions of kernels containing the marker comment.
+struct MarkerObserver;
+
+impl CompilerPass for MarkerObserver {
+    fn run(&self, scope: &mut Scope) {
+        let has_marker = scope.instructions.iter().any(|inst| {
+            matches!(
+                &inst.operation,
+                Operation::NonSemantic(NonSemantic::Comment { content }) if content == MARKER
+            )
+        });
+
+        if has_marker {
+            OBSERVED_INSTRUCTIONS.store(scope.instructions.len(), Ordering::Relaxed);
+        }
+    }
+}
+
+#[cube(launch)]
+pub fn kernel_compiler_pass(output: &mut Array<u32>) {
+    comment!("compiler_pass_marker");
+    output[0] = 5u32;
+}
+
+pub fn test_compiler_pass_observes_ir<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let pass = crate::register_compiler_pass(MarkerObserver);
+
+    let handle = client.create(u32::as_bytes(&[0]));
+
+    kernel_compiler_pass::launch::<R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new(1, 1, 1),
+        unsafe { ArrayArg::from_raw_parts::<u32>(&handle, 1, 1) },
+    );
+
+    let actual = client.read_one(handle.binding());
+    let actual = u32::from_bytes(&actual);
+    crate::unregister_compiler_pass(pass);
+
+    assert_eq!(actual[0], 5);
+    // At least the comment and the write to the output.
+    assert!(OBSERVED_INSTRUCTIONS.load(Ordering::Relaxed) >= 2);
+}
+
+#[allow(missing_docs)]
+#[macro_export]
+macro_rules! testgen_compiler_pass {
+    () => {
+        use super::*;
+
+        #[test]
+        fn test_compiler_pass_observes_ir() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::compiler_pass::test_compiler_pass_observes_ir::<
+                TestRuntime,
+            >(client);
+        }
+    };
+}
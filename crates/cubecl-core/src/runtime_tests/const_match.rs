@@ -1,7 +1,10 @@
 use core::hash;
 use std::fmt::Debug;
 
-use crate::{self as cubecl, as_bytes};
+use crate::{
+    self as cubecl, Kernel, as_bytes,
+    ir::{Arithmetic, Elem, FloatKind, Item, Operation as IrOperation, Scope},
+};
 
 use cubecl::prelude::*;
 
@@ -48,6 +51,132 @@ pub fn test_kernel_const_match<
     assert_eq!(actual[index], F::new(value));
 }
 
+/// Activation selected at comptime.
+#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
+pub enum Activation {
+    Relu,
+    Gelu,
+    Silu,
+}
+
+impl Activation {
+    fn apply(self, x: f32) -> f32 {
+        match self {
+            Activation::Relu => x.max(0.0),
+            Activation::Gelu => x / (1.0 + (-1.702 * x).exp()),
+            Activation::Silu => x / (1.0 + (-x).exp()),
+        }
+    }
+}
+
+#[cube]
+pub fn apply_activation<F: Float>(x: F, #[comptime] activation: Activation) -> F {
+    match activation {
+        Activation::Relu => F::max(x, F::new(0.0)),
+        Activation::Gelu => x / (F::new(1.0) + F::exp(F::new(-1.702) * x)),
+        Activation::Silu => x / (F::new(1.0) + F::exp(-x)),
+    }
+}
+
+#[cube(launch, create_dummy_kernel)]
+pub fn kernel_activation<F: Float>(
+    input: &Array<F>,
+    output: &mut Array<F>,
+    #[comptime] activation: Activation,
+) {
+    output[UNIT_POS] = apply_activation::<F>(input[UNIT_POS], activation);
+}
+
+pub fn test_kernel_activation<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let values = [-2.0, -0.5, 0.5, 2.0];
+    let input = client.create(f32::as_bytes(&values));
+
+    for activation in [Activation::Relu, Activation::Gelu, Activation::Silu] {
+        let output = client.empty(values.len() * core::mem::size_of::<f32>());
+
+        kernel_activation::launch::<f32, R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new_1d(values.len() as u32),
+            unsafe { ArrayArg::from_raw_parts::<f32>(&input, values.len(), 1) },
+            unsafe { ArrayArg::from_raw_parts::<f32>(&output, values.len(), 1) },
+            activation,
+        );
+
+        let actual = client.read_one(output.binding());
+        let actual = f32::from_bytes(&actual);
+
+        for (value, actual) in values.iter().zip(actual) {
+            let expected = activation.apply(*value);
+            assert!(
+                (expected - actual).abs() < 1e-5,
+                "{activation:?}({value}): expected {expected}, got {actual}"
+            );
+        }
+    }
+}
+
+/// Expands [apply_activation] and returns the arithmetic operations it lowered to.
+fn activation_operations(activation: Activation) -> Vec<Arithmetic> {
+    let mut scope = Scope::root(false);
+    let x = scope.create_local(Item::new(Elem::Float(FloatKind::F32)));
+
+    apply_activation::expand::<f32>(&mut scope, x.into(), activation);
+
+    scope
+        .instructions
+        .into_iter()
+        .filter_map(|inst| match inst.operation {
+            IrOperation::Arithmetic(arithmetic) => Some(arithmetic),
+            _ => None,
+        })
+        .collect()
+}
+
+pub fn test_activation_lowering() {
+    let relu = activation_operations(Activation::Relu);
+    let gelu = activation_operations(Activation::Gelu);
+    let silu = activation_operations(Activation::Silu);
+
+    let count = |ops: &[Arithmetic], matches: fn(&Arithmetic) -> bool| {
+        ops.iter().filter(|op| matches(op)).count()
+    };
+    let is_exp = |op: &Arithmetic| matches!(op, Arithmetic::Exp(_));
+    let is_neg = |op: &Arithmetic| matches!(op, Arithmetic::Neg(_));
+
+    // Only the arm of the selected variant is expanded.
+    assert!(matches!(relu.as_slice(), [Arithmetic::Max(_)]));
+    assert_eq!(count(&gelu, is_exp), 1);
+    assert_eq!(count(&gelu, is_neg), 0);
+    assert_eq!(count(&silu, is_exp), 1);
+    assert_eq!(count(&silu, is_neg), 1);
+    assert!(
+        gelu.iter()
+            .chain(silu.iter())
+            .all(|op| !matches!(op, Arithmetic::Max(_)))
+    );
+}
+
+pub fn test_activation_kernel_ids<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let input = client.empty(core::mem::size_of::<f32>());
+    let output = client.empty(core::mem::size_of::<f32>());
+
+    let ids = [Activation::Relu, Activation::Gelu, Activation::Silu].map(|activation| {
+        kernel_activation::create_dummy_kernel::<f32, R>(
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new_1d(1),
+            unsafe { ArrayArg::from_raw_parts::<f32>(&input, 1, 1) },
+            unsafe { ArrayArg::from_raw_parts::<f32>(&output, 1, 1) },
+            activation,
+        )
+        .id()
+    });
+
+    assert_ne!(ids[0], ids[1]);
+    assert_ne!(ids[0], ids[2]);
+    assert_ne!(ids[1], ids[2]);
+}
+
 #[allow(missing_docs)]
 #[macro_export]
 macro_rules! testgen_const_match {
@@ -63,5 +192,24 @@ macro_rules! testgen_const_match {
                 UintType,
             >(client);
         }
+
+        #[test]
+        fn test_const_match_activation() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::const_match::test_kernel_activation::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_const_match_activation_lowering() {
+            cubecl_core::runtime_tests::const_match::test_activation_lowering();
+        }
+
+        #[test]
+        fn test_const_match_activation_kernel_ids() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::const_match::test_activation_kernel_ids::<TestRuntime>(
+                client,
+            );
+        }
     };
 }
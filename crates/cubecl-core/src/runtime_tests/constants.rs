@@ -29,6 +29,96 @@ pub fn test_constant_array<R: Runtime>(client: ComputeClient<R::Server, R::Chann
     assert_eq!(actual[0], 5.0);
 }
 
+/// Gamma correction table with an exponent of `2.2`, computed on the host.
+const GAMMA_TABLE: [f32; 8] = [
+    0.0, 0.013843, 0.063479, 0.154900, 0.291101, 0.474088, 0.705569, 1.0,
+];
+
+#[cube(launch)]
+fn gamma_lookup_kernel(input: &Array<u32>, output: &mut Array<f32>) {
+    let table = Array::<f32>::from_data(GAMMA_TABLE);
+    output[UNIT_POS] = table[input[UNIT_POS]];
+}
+
+pub fn test_baked_lookup_table<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let indices = [7, 0, 3, 5, 1, 6];
+    let input = client.create(u32::as_bytes(&indices));
+    let output = client.empty(indices.len() * core::mem::size_of::<f32>());
+
+    gamma_lookup_kernel::launch::<R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new_1d(indices.len() as u32),
+        unsafe { ArrayArg::from_raw_parts::<u32>(&input, indices.len(), 1) },
+        unsafe { ArrayArg::from_raw_parts::<f32>(&output, indices.len(), 1) },
+    );
+
+    let actual = client.read_one(output.binding());
+    let actual = f32::from_bytes(&actual);
+    let expected = indices.map(|index| GAMMA_TABLE[index as usize]);
+
+    assert_eq!(actual, expected);
+}
+
+#[cube]
+fn oversized_constant_array(#[comptime] len: u32) -> f32 {
+    let array = Array::<f32>::from_data(comptime!(vec![0.0f32; len as usize]));
+    array[0]
+}
+
+pub fn test_constant_array_too_large() {
+    let mut scope = crate::ir::Scope::root(false);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        oversized_constant_array::expand(&mut scope, MAX_CONST_ARRAY_LEN as u32 + 1);
+    }));
+
+    let message = result.expect_err("Oversized constant array should be rejected");
+    let message = message.downcast_ref::<String>().unwrap();
+    assert!(message.contains("Upload the data in a buffer instead"));
+}
+
+#[cube(launch)]
+fn max_from_neg_infinity_kernel<F: Float>(input: &Array<F>, output: &mut Array<F>) {
+    if UNIT_POS == 0 {
+        let mut max = F::NEG_INFINITY.runtime();
+        for i in 0..input.len() {
+            max = F::max(max, input[i]);
+        }
+        output[0] = max;
+        output[1] = F::MAX;
+        output[2] = F::MIN;
+    }
+}
+
+/// Reduces the maximum of negative values from negative infinity, and checks `F::MAX` and `F::MIN`
+/// are the bounds of the element type, `max` and `min`, written as host constants.
+pub fn test_max_from_neg_infinity<R: Runtime, F: Float + CubeElement>(
+    client: ComputeClient<R::Server, R::Channel>,
+    max: F,
+    min: F,
+) {
+    if !F::is_supported(&client) {
+        return;
+    }
+
+    let values = [-7.5, -0.5, -3.0, -1024.0].map(F::new);
+    let input = client.create(F::as_bytes(&values));
+    let output = client.empty(3 * core::mem::size_of::<F>());
+
+    max_from_neg_infinity_kernel::launch::<F, R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::default(),
+        unsafe { ArrayArg::from_raw_parts::<F>(&input, values.len(), 1) },
+        unsafe { ArrayArg::from_raw_parts::<F>(&output, 3, 1) },
+    );
+
+    let actual = client.read_one(output.binding());
+    let actual = F::from_bytes(&actual);
+
+    assert_eq!(actual, [F::new(-0.5), max, min]);
+}
+
 #[allow(missing_docs)]
 #[macro_export]
 macro_rules! testgen_constants {
@@ -40,5 +130,45 @@ macro_rules! testgen_constants {
             let client = TestRuntime::client(&Default::default());
             cubecl_core::runtime_tests::constants::test_constant_array::<TestRuntime>(client);
         }
+
+        #[test]
+        fn test_baked_lookup_table() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::constants::test_baked_lookup_table::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_max_from_neg_infinity_f32() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::constants::test_max_from_neg_infinity::<TestRuntime, f32>(
+                client,
+                f32::MAX,
+                f32::MIN,
+            );
+        }
+
+        #[test]
+        fn test_max_from_neg_infinity_f16() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::constants::test_max_from_neg_infinity::<
+                TestRuntime,
+                half::f16,
+            >(client, half::f16::MAX, half::f16::MIN);
+        }
+
+        #[test]
+        fn test_max_from_neg_infinity_f64() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::constants::test_max_from_neg_infinity::<TestRuntime, f64>(
+                client,
+                f64::MAX,
+                f64::MIN,
+            );
+        }
+
+        #[test]
+        fn test_constant_array_too_large() {
+            cubecl_core::runtime_tests::constants::test_constant_array_too_large();
+        }
     };
 }
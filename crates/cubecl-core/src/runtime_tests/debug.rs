@@ -1,5 +1,10 @@
 use crate::prelude::*;
-use crate::{self as cubecl, debug_print};
+use crate::{
+    self as cubecl,
+    compute::KernelBuilder,
+    debug_print, device_assert,
+    ir::{Elem, FloatKind, Item},
+};
 
 #[cube]
 fn helper_fn<F: Float>(num: F) -> F {
@@ -92,6 +97,66 @@ pub fn test_debug_print<R: Runtime>(client: ComputeClient<R::Server, R::Channel>
     assert_eq!(actual[0], 100.0);
 }
 
+#[cube(launch)]
+fn device_assert_kernel(errors: &Array<Atomic<u32>>) {
+    device_assert!(errors, UNIT_POS < 8, 42u32);
+}
+
+pub fn test_device_assert<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let errors = client.create(u32::as_bytes(&[0, 0]));
+
+    device_assert_kernel::launch::<R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new_1d(32),
+        unsafe { ArrayArg::from_raw_parts::<u32>(&errors, 2, 1) },
+    );
+
+    let actual = client.read_one(errors.binding());
+    let actual = u32::from_bytes(&actual);
+
+    if device_asserts_enabled() {
+        assert_eq!(actual[0], 42);
+        assert!(
+            (8..32).contains(&actual[1]),
+            "unit {} didn't fail",
+            actual[1]
+        );
+    } else {
+        assert_eq!(actual, &[0, 0]);
+    }
+}
+
+#[cube]
+fn ir_listing_kernel(input: &Array<f32>, output: &mut Array<f32>) {
+    if UNIT_POS < 4 {
+        output[UNIT_POS] = input[UNIT_POS] * 2.0;
+    }
+}
+
+pub fn test_ir_listing() {
+    let mut builder = KernelBuilder::default();
+    let item = Item::new(Elem::Float(FloatKind::F32));
+    let input = builder.input_array(item);
+    let output = builder.output_array(item);
+
+    ir_listing_kernel::expand(&mut builder.context, input.into(), output.into());
+    let listing = builder.context.to_debug_string();
+
+    let (condition, body) = listing
+        .split_once(" {\n")
+        .expect("The branch should open a block");
+    assert!(condition.contains("if("), "{listing}");
+    // The body of the branch is indented under it, with the types of the outputs.
+    let multiply = body
+        .lines()
+        .find(|line| line.contains(" * "))
+        .expect("The multiplication should be listed");
+    assert!(multiply.starts_with("    "), "{listing}");
+    assert!(multiply.ends_with(": f32"), "{listing}");
+    assert!(body.contains("output(1)["), "{listing}");
+}
+
 #[allow(missing_docs)]
 #[macro_export]
 macro_rules! testgen_debug {
@@ -115,5 +180,16 @@ macro_rules! testgen_debug {
             let client = TestRuntime::client(&Default::default());
             cubecl_core::runtime_tests::debug::test_debug_print::<TestRuntime>(client);
         }
+
+        #[test]
+        fn test_device_assert() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::debug::test_device_assert::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_ir_listing() {
+            cubecl_core::runtime_tests::debug::test_ir_listing();
+        }
     };
 }
@@ -0,0 +1,52 @@
+use crate as cubecl;
+
+use cubecl::prelude::*;
+
+#[cube(launch)]
+pub fn kernel_double(output: &mut Array<f32>) {
+    if ABSOLUTE_POS < output.len() {
+        output[ABSOLUTE_POS] *= 2.0;
+    }
+}
+
+/// Double `[1, 2, 3]` on the [default client](Runtime::default_client) of the runtime.
+fn double_on_default_client<R: Runtime>() -> Vec<f32> {
+    let client = R::default_client();
+    let handle = client.create(f32::as_bytes(&[1.0, 2.0, 3.0]));
+
+    kernel_double::launch::<R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new_1d(3),
+        unsafe { ArrayArg::from_raw_parts::<f32>(&handle, 3, 1) },
+    );
+
+    f32::from_bytes(&client.read_one(handle.binding())).to_vec()
+}
+
+pub fn test_default_client<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    R::set_default_client(client.clone());
+
+    assert!(R::default_client().ptr_eq(&client));
+    assert_eq!(double_on_default_client::<R>(), [2.0, 4.0, 6.0]);
+
+    let cleared = R::clear_default_client().expect("The default client should be set");
+    assert!(cleared.ptr_eq(&client));
+    assert!(R::clear_default_client().is_none());
+    assert!(R::default_client().ptr_eq(&R::client(&Default::default())));
+    assert_eq!(double_on_default_client::<R>(), [2.0, 4.0, 6.0]);
+}
+
+#[allow(missing_docs)]
+#[macro_export]
+macro_rules! testgen_default_client {
+    () => {
+        use super::*;
+
+        #[test]
+        fn test_default_client() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::default_client::test_default_client::<TestRuntime>(client);
+        }
+    };
+}
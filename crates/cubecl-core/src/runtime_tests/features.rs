@@ -0,0 +1,96 @@
+use crate::{
+    Feature, RuntimeFeatures,
+    ir::{Elem, FloatKind},
+    prelude::*,
+};
+
+/// Checks the features of the runtime agree with its device properties and with the guarantees of
+/// its backend.
+pub fn test_runtime_features<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let features = R::features(&Default::default());
+
+    assert_eq!(
+        features,
+        RuntimeFeatures::from_properties(client.properties())
+    );
+    assert!(features.plane_size_min <= features.plane_size_max);
+    assert!(features.max_units_per_cube > 0);
+    assert_eq!(
+        features.supports_f64,
+        client
+            .properties()
+            .feature_enabled(Feature::Type(Elem::Float(FloatKind::F64)))
+    );
+    assert_eq!(features.supports_cmma, !features.cmma_shapes.is_empty());
+
+    match R::name(&client) {
+        // Every CUDA device since compute capability 2.0 has 32-wide warps, 1024 threads per
+        // block, 48 KiB of shared memory per block, and supports warp operations and `f64`.
+        "cuda" => {
+            assert!(features.supports_plane);
+            assert!(features.supports_f64);
+            assert_eq!(features.plane_size_min, 32);
+            assert_eq!(features.plane_size_max, 32);
+            assert_eq!(features.max_units_per_cube, 1024);
+            assert!(features.max_shared_memory >= 48 * 1024);
+        }
+        // AMD wavefronts are 32 or 64 wide, fixed for a given device.
+        "hip" => {
+            assert!(features.supports_plane);
+            assert!(features.supports_f64);
+            assert_eq!(features.plane_size_min, features.plane_size_max);
+            assert!([32, 64].contains(&features.plane_size_max));
+            assert_eq!(features.max_units_per_cube, 1024);
+        }
+        // WebGPU has no cube clusters nor tensor memory accelerator, and every adapter supports
+        // the default limits of the specification.
+        name if name.starts_with("wgpu") => {
+            assert!(!features.supports_cube_cluster);
+            assert!(!features.supports_tma);
+            assert!(features.max_units_per_cube >= 256);
+            assert!(features.max_shared_memory >= 16 * 1024);
+            assert!(features.max_bindings >= 8);
+        }
+        _ => {}
+    }
+}
+
+/// Checks the backend and device are named, and the api version is a version number when queried.
+pub fn test_backend_info<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let info = client.backend_info();
+
+    assert!(!info.name.is_empty());
+    assert!(!info.device_name.is_empty());
+    // Left empty when the query fails, otherwise ending with a version like `12.4` or `1.3.280`.
+    if !info.api_version.is_empty() {
+        let version = info.api_version.split_whitespace().last().unwrap();
+        let parts = version.split('.').collect::<Vec<_>>();
+
+        assert!(parts.len() >= 2, "api version {}", info.api_version);
+        assert!(
+            parts.iter().all(|part| part.parse::<u32>().is_ok()),
+            "api version {}",
+            info.api_version
+        );
+    }
+}
+
+#[allow(missing_docs)]
+#[macro_export]
+macro_rules! testgen_features {
+    () => {
+        use super::*;
+
+        #[test]
+        fn test_runtime_features() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::features::test_runtime_features::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_backend_info() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::features::test_backend_info::<TestRuntime>(client);
+        }
+    };
+}
@@ -1,6 +1,11 @@
 use crate::{self as cubecl, as_type};
 
-use cubecl::prelude::*;
+use cubecl::{
+    Feature,
+    frontend::mem::{align_of, size_of},
+    ir::{Elem, UIntKind},
+    prelude::*,
+};
 
 #[cube(launch)]
 pub fn kernel_assign<F: Float>(output: &mut Array<F>) {
@@ -41,6 +46,164 @@ pub fn test_kernel_index_scalar<R: Runtime, F: Float + CubeElement>(
     assert_eq!(actual[2], F::new(123.0));
 }
 
+#[cube(launch)]
+pub fn kernel_index_byte_offset<F: Float>(input: &Array<F>, output: &mut Array<F>) {
+    // Skip the first 8 bytes of the input, whatever the size of `F`.
+    let offset = 8 / size_of::<F>();
+    output[UNIT_POS] = input[UNIT_POS + offset];
+}
+
+pub fn test_kernel_index_byte_offset<R: Runtime, F: Float + CubeElement>(
+    client: ComputeClient<R::Server, R::Channel>,
+) {
+    let input = client.create(F::as_bytes(
+        as_type![F: 0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0],
+    ));
+    let output = client.empty(2 * size_of::<F>() as usize);
+    let skipped = 8 / size_of::<F>() as usize;
+
+    kernel_index_byte_offset::launch::<F, R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new_1d(2),
+        unsafe { ArrayArg::from_raw_parts::<F>(&input, 8, 1) },
+        unsafe { ArrayArg::from_raw_parts::<F>(&output, 2, 1) },
+    );
+
+    let actual = client.read_one(output.binding());
+    let actual = F::from_bytes(&actual);
+
+    assert_eq!(actual[0], F::new(skipped as f32));
+    assert_eq!(actual[1], F::new(skipped as f32 + 1.0));
+    assert_eq!(align_of::<F>() as usize, core::mem::align_of::<F>());
+}
+
+#[cube(launch)]
+pub fn kernel_index_large_tensor(
+    input: &Tensor<f32>,
+    indices: &Array<u32>,
+    output: &mut Array<f32>,
+) {
+    if UNIT_POS < indices.len() {
+        // Translate the linear index with the shape and strides, like a strided elementwise op.
+        let mut remainder = indices[UNIT_POS];
+        let mut offset = 0;
+        let rank = input.rank();
+        for i in 0..rank {
+            let dim = rank - 1 - i;
+            offset += remainder % input.shape(dim) * input.stride(dim);
+            remainder /= input.shape(dim);
+        }
+        output[UNIT_POS] = input[offset];
+    }
+}
+
+/// Reads a broadcast `[6, 2^29]` view of 6 values, whose linear indexes reach past `i32::MAX`,
+/// at indexes on both sides of 2^31.
+pub fn test_kernel_index_large_tensor<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let cols = 1usize << 29;
+    let input = client.create(f32::as_bytes(&[0.0, 1.0, 2.0, 3.0, 4.0, 5.0]));
+    let (shape, strides) = ([6, cols], [1, 0]);
+    let indices = [
+        3 * cols as u32 - 1,
+        1 << 31,
+        (1 << 31) + 5,
+        6 * cols as u32 - 1,
+    ];
+    let indices_handle = client.create(u32::as_bytes(&indices));
+    let output = client.empty(indices.len() * size_of::<f32>() as usize);
+
+    kernel_index_large_tensor::launch::<R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new_1d(indices.len() as u32),
+        unsafe { TensorArg::from_raw_parts::<f32>(&input, &strides, &shape, 1) },
+        unsafe { ArrayArg::from_raw_parts::<u32>(&indices_handle, indices.len(), 1) },
+        unsafe { ArrayArg::from_raw_parts::<f32>(&output, indices.len(), 1) },
+    );
+
+    let actual = client.read_one(output.binding());
+    let expected: Vec<f32> = indices
+        .iter()
+        .map(|index| (*index as usize / cols) as f32)
+        .collect();
+
+    assert_eq!(f32::from_bytes(&actual), expected);
+}
+
+/// A view of `2^32` elements can't be indexed with 32-bit integers, so launching it fails
+/// instead of truncating its length.
+pub fn test_try_launch_index_overflow<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let input = client.create(f32::as_bytes(&[0.0; 8]));
+    let (shape, strides) = ([8, 1usize << 29], [1, 0]);
+    let indices = client.create(u32::as_bytes(&[0]));
+    let output = client.empty(size_of::<f32>() as usize);
+
+    let result = kernel_index_large_tensor::try_launch::<R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new_1d(1),
+        unsafe { TensorArg::from_raw_parts::<f32>(&input, &strides, &shape, 1) },
+        unsafe { ArrayArg::from_raw_parts::<u32>(&indices, 1, 1) },
+        unsafe { ArrayArg::from_raw_parts::<f32>(&output, 1, 1) },
+    );
+
+    assert_eq!(
+        result,
+        Err(LaunchError::IndexOverflow {
+            index: 0,
+            required: 1 << 32,
+        })
+    );
+}
+
+#[cube(launch_unchecked)]
+pub fn kernel_index_past_u32(buffer: &mut Array<u8>, output: &mut Array<u32>, low: u64, high: u64) {
+    unsafe {
+        buffer.index_assign_unchecked_u64(low, 1u8);
+        buffer.index_assign_unchecked_u64(high, 2u8);
+        output[0] = u32::cast_from(*buffer.index_unchecked_u64(low));
+        output[1] = u32::cast_from(*buffer.index_unchecked_u64(high));
+    }
+}
+
+/// Writes and reads back elements whose indexes are `2^32` apart with 64-bit indexes, which
+/// would alias if the indexes were truncated to 32 bits. Skipped on devices without 64-bit
+/// integers or that can't allocate such a buffer.
+///
+/// The buffer takes 4 GiB, so the test is ignored by default and must be run explicitly with
+/// `--ignored`.
+pub fn test_kernel_index_past_u32<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let properties = client.properties();
+    let size = (1u64 << 32) + 16;
+    if !properties.feature_enabled(Feature::Type(Elem::UInt(UIntKind::U64)))
+        || !properties.feature_enabled(Feature::Type(Elem::UInt(UIntKind::U8)))
+        || properties.memory_properties().max_page_size < size
+    {
+        return;
+    }
+
+    let buffer = client.empty(size as usize);
+    let output = client.empty(2 * size_of::<u32>() as usize);
+    let (low, high) = (3u64, (1u64 << 32) + 3);
+
+    unsafe {
+        kernel_index_past_u32::launch_unchecked::<R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new_1d(1),
+            ArrayArg::from_raw_parts::<u8>(&buffer, size as usize, 1),
+            ArrayArg::from_raw_parts::<u32>(&output, 2, 1),
+            ScalarArg::new(low),
+            ScalarArg::new(high),
+        )
+    };
+
+    let actual = client.read_one(output.binding());
+
+    assert_eq!(u32::from_bytes(&actual), [1, 2]);
+}
+
 #[allow(missing_docs)]
 #[macro_export]
 macro_rules! testgen_index {
@@ -54,5 +217,37 @@ macro_rules! testgen_index {
                 client,
             );
         }
+
+        #[test]
+        fn test_index_byte_offset() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::index::test_kernel_index_byte_offset::<
+                TestRuntime,
+                FloatType,
+            >(client);
+        }
+
+        #[test]
+        fn test_index_large_tensor() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::index::test_kernel_index_large_tensor::<TestRuntime>(
+                client,
+            );
+        }
+
+        #[test]
+        #[ignore = "allocates 4 GiB of device memory"]
+        fn test_index_past_u32() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::index::test_kernel_index_past_u32::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_try_launch_index_overflow() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::index::test_try_launch_index_overflow::<TestRuntime>(
+                client,
+            );
+        }
     };
 }
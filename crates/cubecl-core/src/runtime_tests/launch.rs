@@ -1,4 +1,7 @@
-use crate::{self as cubecl, as_bytes};
+use crate::{
+    self as cubecl, ElemwiseChunk, MAX_ELEMWISE_CHUNK_SIZE, as_bytes,
+    calculate_cube_count_elemwise_chunked,
+};
 use cubecl::prelude::*;
 
 #[derive(CubeLaunch, CubeType)]
@@ -99,6 +102,410 @@ pub fn test_kernel_without_generics<R: Runtime>(client: ComputeClient<R::Server,
     assert_eq!(actual[0], 5.0);
 }
 
+pub fn test_try_launch_buffer_too_small<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let handle = client.create(f32::as_bytes(&[0.0, 1.0]));
+
+    let result = kernel_without_generics::try_launch::<R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::default(),
+        unsafe { ArrayArg::from_raw_parts::<f32>(&handle, 4, 1) },
+    );
+
+    assert_eq!(
+        result,
+        Err(LaunchError::BufferTooSmall {
+            index: 0,
+            required: 4 * size_of::<f32>() as u64,
+            size: 2 * size_of::<f32>() as u64,
+        })
+    );
+}
+
+#[cube(launch)]
+pub fn kernel_sum_neighbors(lhs: &Array<f32>, rhs: &Array<f32>, output: &mut Array<f32>) {
+    if ABSOLUTE_POS < output.len() {
+        output[ABSOLUTE_POS] = lhs[ABSOLUTE_POS] + rhs[(ABSOLUTE_POS + 1) % rhs.len()];
+    }
+}
+
+/// Binds the same buffer to the two read arguments of a kernel, which is only accepted once the
+/// aliasing is allowed.
+pub fn test_try_launch_aliased<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let input = client.create(f32::as_bytes(&[1.0, 2.0, 3.0, 4.0]));
+    let output = client.empty(4 * size_of::<f32>());
+
+    let result = kernel_sum_neighbors::try_launch::<R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new_1d(4),
+        unsafe { ArrayArg::from_raw_parts::<f32>(&input, 4, 1) },
+        unsafe { ArrayArg::from_raw_parts::<f32>(&input, 4, 1) },
+        unsafe { ArrayArg::from_raw_parts::<f32>(&output, 4, 1) },
+    );
+    assert_eq!(
+        result,
+        Err(LaunchError::AliasedBuffers {
+            first: 0,
+            second: 1
+        })
+    );
+
+    let result = kernel_sum_neighbors::try_launch_aliased::<R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new_1d(4),
+        &[(0, 1)],
+        unsafe { ArrayArg::from_raw_parts::<f32>(&input, 4, 1) },
+        unsafe { ArrayArg::from_raw_parts::<f32>(&input, 4, 1) },
+        unsafe { ArrayArg::from_raw_parts::<f32>(&output, 4, 1) },
+    );
+    assert_eq!(result, Ok(()));
+
+    let actual = client.read_one(output.binding());
+    assert_eq!(f32::from_bytes(&actual), [3.0, 5.0, 7.0, 5.0]);
+}
+
+pub fn test_try_launch_cube_count_exceeded<R: Runtime>(
+    client: ComputeClient<R::Server, R::Channel>,
+) {
+    let max = client.properties().hardware_properties().max_cube_count;
+    if max.x == u32::MAX {
+        return;
+    }
+    let handle = client.create(f32::as_bytes(&[0.0, 1.0]));
+
+    let result = kernel_without_generics::try_launch::<R>(
+        &client,
+        CubeCount::Static(max.x + 1, 1, 1),
+        CubeDim::default(),
+        unsafe { ArrayArg::from_raw_parts::<f32>(&handle, 2, 1) },
+    );
+
+    assert_eq!(
+        result,
+        Err(LaunchError::CubeCountExceeded {
+            requested: (max.x + 1, 1, 1),
+            limit: (max.x, max.y, max.z),
+        })
+    );
+
+    let result = kernel_without_generics::try_launch::<R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::default(),
+        unsafe { ArrayArg::from_raw_parts::<f32>(&handle, 2, 1) },
+    );
+    assert_eq!(result, Ok(()));
+
+    let actual = client.read_one(handle.binding());
+    let actual = f32::from_bytes(&actual);
+
+    assert_eq!(actual[0], 5.0);
+}
+
+#[cube(launch)]
+pub fn kernel_index_chunk(output: &mut Array<u32>, offset: u32) {
+    for i in range_stepped(ABSOLUTE_POS, output.len(), CUBE_COUNT * CUBE_DIM) {
+        output[i] = offset + i;
+    }
+}
+
+pub fn test_elemwise_chunks() {
+    let num_elems = 3 * MAX_ELEMWISE_CHUNK_SIZE + 5;
+    let chunks = calculate_cube_count_elemwise_chunked(
+        num_elems,
+        CubeDim::new_1d(256),
+        CubeDim::new_3d(65535, 65535, 65535),
+    );
+
+    assert_eq!(chunks.len(), 4);
+    assert_eq!(chunks[3].num_elems, 5);
+    assert_eq!(
+        chunks.iter().map(|chunk| chunk.num_elems).sum::<u64>(),
+        num_elems
+    );
+    for (chunk, next) in chunks.iter().zip(chunks.iter().skip(1)) {
+        assert_eq!(chunk.offset + chunk.num_elems, next.offset);
+    }
+    for chunk in chunks {
+        let CubeCount::Static(x, y, z) = chunk.cube_count else {
+            unreachable!()
+        };
+        assert!(x as u64 * y as u64 * z as u64 * 256 >= chunk.num_elems);
+        assert!(x <= 65535 && y <= 65535);
+    }
+}
+
+/// Writes its index to every element of a buffer with more than `2^31` elements, one chunk at a
+/// time. Skipped on devices that can't allocate such a buffer.
+///
+/// The buffer takes 8 GiB, so the test is ignored by default and must be run explicitly with
+/// `--ignored`.
+pub fn test_launch_chunked_large_buffer<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let num_elems = (1u64 << 31) + 1024;
+    let size = num_elems * size_of::<u32>() as u64;
+    if client.properties().memory_properties().max_page_size < size {
+        return;
+    }
+
+    let handle = client.empty(size as usize);
+    let cube_dim = CubeDim::new_1d(256);
+    let max_cube_count = client.properties().hardware_properties().max_cube_count;
+    let chunks = calculate_cube_count_elemwise_chunked(num_elems, cube_dim, max_cube_count);
+
+    let chunk_handle = |chunk: &ElemwiseChunk| {
+        let start = chunk.offset * size_of::<u32>() as u64;
+        let end = (chunk.offset + chunk.num_elems) * size_of::<u32>() as u64;
+        handle.clone().offset_start(start).offset_end(size - end)
+    };
+
+    for chunk in chunks.iter() {
+        let output = chunk_handle(chunk);
+        kernel_index_chunk::launch::<R>(
+            &client,
+            chunk.cube_count.clone(),
+            cube_dim,
+            unsafe { ArrayArg::from_raw_parts::<u32>(&output, chunk.num_elems as usize, 1) },
+            ScalarArg::new(chunk.offset as u32),
+        );
+    }
+
+    for chunk in chunks.iter() {
+        let actual = client.read_one(chunk_handle(chunk).binding());
+        let actual = u32::from_bytes(&actual);
+
+        for (i, value) in actual.iter().enumerate() {
+            let index = chunk.offset + i as u64;
+            assert_eq!(*value, index as u32, "Element {index} wasn't written");
+        }
+    }
+}
+
+#[cube(launch)]
+pub fn kernel_double_add(values: &mut Array<u32>, value: u32) {
+    values[UNIT_POS] = values[UNIT_POS] * 2 + value;
+}
+
+/// Launches a chain of dependent kernels in a batch, which must run in launch order.
+pub fn test_batch_preserves_order<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let handle = client.create(u32::as_bytes(&[0, 1]));
+
+    client.batch(|client| {
+        for value in 0..8 {
+            kernel_double_add::launch::<R>(
+                client,
+                CubeCount::Static(1, 1, 1),
+                CubeDim::new_1d(2),
+                unsafe { ArrayArg::from_raw_parts::<u32>(&handle, 2, 1) },
+                ScalarArg::new(value),
+            );
+        }
+    });
+
+    let actual = client.read_one(handle.binding());
+    let actual = u32::from_bytes(&actual);
+    let expected = [0u32, 1].map(|start| (0..8).fold(start, |acc, value| acc * 2 + value));
+
+    assert_eq!(actual, expected);
+}
+
+/// Panics inside a batch, and checks that the kernels recorded before the panic run and that the
+/// client still batches afterward.
+pub fn test_batch_ends_on_panic<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let handle = client.create(u32::as_bytes(&[0, 1]));
+    let launch = |client: &ComputeClient<R::Server, R::Channel>, value| {
+        kernel_double_add::launch::<R>(
+            client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new_1d(2),
+            unsafe { ArrayArg::from_raw_parts::<u32>(&handle, 2, 1) },
+            ScalarArg::new(value),
+        );
+    };
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        client.batch(|client| {
+            launch(client, 1);
+            panic!("Panic inside the batch");
+        })
+    }));
+    assert!(result.is_err());
+
+    client.batch(|client| launch(client, 2));
+
+    let actual = client.read_one(handle.binding());
+    let actual = u32::from_bytes(&actual);
+
+    assert_eq!(actual, [0u32, 1].map(|start| (start * 2 + 1) * 2 + 2));
+}
+
+#[cube(launch, create_dummy_kernel)]
+pub fn kernel_push_constants(values: &mut Array<f32>, scale: Push<f32>, iteration: Push<u32>) {
+    values[UNIT_POS] = values[UNIT_POS] * scale + f32::cast_from(iteration);
+}
+
+/// Kernel taking its scalars as push constants, to check how a backend declares them.
+pub fn push_constants_kernel<R: Runtime>(
+    client: &ComputeClient<R::Server, R::Channel>,
+) -> Box<dyn CubeTask<R::Compiler>> {
+    let handle = client.empty(2 * core::mem::size_of::<f32>());
+    let kernel = kernel_push_constants::create_dummy_kernel::<R>(
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new_1d(2),
+        unsafe { ArrayArg::from_raw_parts::<f32>(&handle, 2, 1) },
+        ScalarArg::new(2.0),
+        ScalarArg::new(0),
+    );
+
+    Box::new(crate::compute::KernelTask::<R::Compiler, _>::new(kernel))
+}
+
+/// Relaunches a kernel taking its scalars as push constants, which must change at every launch.
+pub fn test_push_constants<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let handle = client.create(f32::as_bytes(&[1.0, 2.0]));
+
+    for iteration in 0..4 {
+        kernel_push_constants::launch::<R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new_1d(2),
+            unsafe { ArrayArg::from_raw_parts::<f32>(&handle, 2, 1) },
+            ScalarArg::new(2.0),
+            ScalarArg::new(iteration),
+        );
+    }
+
+    let actual = client.read_one(handle.binding());
+    let actual = f32::from_bytes(&actual);
+    let expected = [1.0f32, 2.0].map(|start| (0..4).fold(start, |acc, i| acc * 2.0 + i as f32));
+
+    assert_eq!(actual, expected);
+}
+
+/// Kernel declaring one push constant for each of its elements.
+struct PushConstantsKernel {
+    elems: Vec<crate::ir::Elem>,
+}
+
+impl Kernel for PushConstantsKernel {
+    fn define(&self) -> crate::prelude::KernelDefinition {
+        let mut builder = KernelBuilder::default();
+        for elem in self.elems.iter() {
+            builder.push_constant(*elem);
+        }
+        builder.build(KernelSettings::default().kernel_name("push_constants_kernel"))
+    }
+
+    fn id(&self) -> crate::KernelId {
+        crate::KernelId::new::<Self>().info(self.elems.clone())
+    }
+}
+
+/// Push constants larger than the guaranteed limit are rejected when compiling the kernel.
+pub fn test_push_constants_too_large<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let elem = crate::ir::Elem::Float(crate::ir::FloatKind::F64);
+    let count = crate::compute::MAX_PUSH_CONSTANTS_SIZE / elem.size() + 1;
+    let kernel = PushConstantsKernel {
+        elems: vec![elem; count],
+    };
+
+    let result = client.compile(Box::new(crate::compute::KernelTask::<R::Compiler, _>::new(
+        kernel,
+    )));
+
+    assert_eq!(
+        result,
+        Err(crate::server::CompilationError::PushConstantsExceeded {
+            requested: count * elem.size(),
+            limit: crate::compute::MAX_PUSH_CONSTANTS_SIZE,
+        })
+    );
+}
+
+/// The size of the push constants includes the padding aligning each type on its size.
+pub fn test_push_constants_size() {
+    use crate::{
+        compute::{ScalarBinding, push_constants_size},
+        ir::{Elem, FloatKind, UIntKind},
+    };
+
+    let scalars = [
+        ScalarBinding {
+            elem: Elem::UInt(UIntKind::U8),
+            count: 1,
+        },
+        ScalarBinding {
+            elem: Elem::Float(FloatKind::F32),
+            count: 3,
+        },
+        ScalarBinding {
+            elem: Elem::Float(FloatKind::F64),
+            count: 2,
+        },
+    ];
+
+    // 1 byte padded to 4, then 12 bytes padded to 16, then 16 bytes.
+    assert_eq!(push_constants_size(&scalars), 32);
+}
+
+#[cube(launch)]
+pub fn kernel_busy(output: &mut Array<f32>, iterations: u32) {
+    let mut value = f32::cast_from(ABSOLUTE_POS);
+    for _ in 0..iterations {
+        value = value * 0.5 + 1.0;
+    }
+    output[ABSOLUTE_POS] = value;
+}
+
+/// Polls the client after a long launch, which must be done once the client is synced. Whether it
+/// is done before depends on the speed of the device, so it isn't checked.
+pub fn test_poll<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let num_elems = 64 * 256;
+    let output = client.empty(num_elems * core::mem::size_of::<f32>());
+    crate::future::block_on(client.sync());
+
+    kernel_busy::launch::<R>(
+        &client,
+        CubeCount::Static(64, 1, 1),
+        CubeDim::new_1d(256),
+        unsafe { ArrayArg::from_raw_parts::<f32>(&output, num_elems, 1) },
+        ScalarArg::new(1 << 22),
+    );
+
+    assert!(client.sync_timeout(core::time::Duration::from_secs(60)));
+    assert!(client.poll());
+}
+
+/// Registers a callback after a long launch, which must only be invoked once the launch is done.
+pub fn test_on_complete<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    use core::sync::atomic::{AtomicBool, Ordering};
+
+    let num_elems = 64 * 256;
+    let output = client.empty(num_elems * core::mem::size_of::<f32>());
+    crate::future::block_on(client.sync());
+
+    kernel_busy::launch::<R>(
+        &client,
+        CubeCount::Static(64, 1, 1),
+        CubeDim::new_1d(256),
+        unsafe { ArrayArg::from_raw_parts::<f32>(&output, num_elems, 1) },
+        ScalarArg::new(1 << 22),
+    );
+
+    let completed = alloc::sync::Arc::new(AtomicBool::new(false));
+    let flag = completed.clone();
+    client.on_complete(move || flag.store(true, Ordering::SeqCst));
+
+    assert!(
+        !completed.load(Ordering::SeqCst),
+        "The callback shouldn't be invoked before the launch is done"
+    );
+    assert!(client.sync_timeout(core::time::Duration::from_secs(60)));
+    crate::future::block_on(client.sync());
+    assert!(completed.load(Ordering::SeqCst));
+}
+
 #[allow(missing_docs)]
 #[macro_export]
 macro_rules! testgen_launch {
@@ -126,5 +533,84 @@ macro_rules! testgen_launch {
                 client,
             );
         }
+
+        #[test]
+        fn test_try_launch_buffer_too_small() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::launch::test_try_launch_buffer_too_small::<TestRuntime>(
+                client,
+            );
+        }
+
+        #[test]
+        fn test_try_launch_aliased() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::launch::test_try_launch_aliased::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_try_launch_cube_count_exceeded() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::launch::test_try_launch_cube_count_exceeded::<TestRuntime>(
+                client,
+            );
+        }
+
+        #[test]
+        fn test_elemwise_chunks() {
+            cubecl_core::runtime_tests::launch::test_elemwise_chunks();
+        }
+
+        #[test]
+        #[ignore = "allocates 8 GiB of device memory"]
+        fn test_launch_chunked_large_buffer() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::launch::test_launch_chunked_large_buffer::<TestRuntime>(
+                client,
+            );
+        }
+
+        #[test]
+        fn test_batch_preserves_order() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::launch::test_batch_preserves_order::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_batch_ends_on_panic() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::launch::test_batch_ends_on_panic::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_push_constants() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::launch::test_push_constants::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_push_constants_too_large() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::launch::test_push_constants_too_large::<TestRuntime>(
+                client,
+            );
+        }
+
+        #[test]
+        fn test_push_constants_size() {
+            cubecl_core::runtime_tests::launch::test_push_constants_size();
+        }
+
+        #[test]
+        fn test_poll() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::launch::test_poll::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_on_complete() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::launch::test_on_complete::<TestRuntime>(client);
+        }
     };
 }
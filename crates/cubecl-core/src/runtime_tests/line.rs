@@ -1,5 +1,10 @@
-use crate::{self as cubecl, as_bytes};
+use crate::{
+    self as cubecl, Feature, Kernel, KernelId, KernelSettings, as_bytes,
+    compute::{KernelBuilder, KernelDefinition, KernelTask},
+    ir::{Elem, FloatKind, Item},
+};
 use cubecl::prelude::*;
+use half::f16;
 
 #[cube(launch_unchecked)]
 pub fn kernel_line_index<F: Float>(output: &mut Array<F>, #[comptime] line_size: u32) {
@@ -104,6 +109,130 @@ pub fn test_line_loop_unroll<R: Runtime, F: Float + CubeElement>(
     }
 }
 
+#[cube]
+fn line_cast_round_trip(input: &Array<f32>, output: &mut Array<f32>, #[comptime] line_size: u32) {
+    let mut line = Line::<f32>::empty(line_size);
+    #[unroll]
+    for i in 0..line_size {
+        line[i] = input[i];
+    }
+
+    let back = line.cast::<f16>().cast::<f32>();
+
+    #[unroll]
+    for i in 0..line_size {
+        output[i] = back[i];
+    }
+}
+
+#[cube(launch_unchecked)]
+pub fn kernel_line_cast_round_trip(
+    input: &Array<f32>,
+    output: &mut Array<f32>,
+    #[comptime] line_size: u32,
+) {
+    if UNIT_POS == 0 {
+        line_cast_round_trip(input, output, line_size);
+    }
+}
+
+pub fn test_line_cast_round_trip<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    if !client
+        .properties()
+        .feature_enabled(Feature::Type(Elem::Float(FloatKind::F16)))
+    {
+        return;
+    }
+
+    let values = [1.0, -2.5, 0.3333, 1000.1, -0.0078, 65.25, 3.1415, -12.0];
+
+    // Odd sizes exercise the element left over after the packed pairs.
+    for line_size in [1, 2, 3, 4, 5, 8] {
+        let input = client.create(f32::as_bytes(&values[..line_size]));
+        let output = client.empty(line_size * core::mem::size_of::<f32>());
+
+        unsafe {
+            kernel_line_cast_round_trip::launch_unchecked::<R>(
+                &client,
+                CubeCount::new_single(),
+                CubeDim::new_single(),
+                ArrayArg::from_raw_parts::<f32>(&input, line_size, 1),
+                ArrayArg::from_raw_parts::<f32>(&output, line_size, 1),
+                line_size as u32,
+            );
+        }
+
+        let actual = client.read_one(output.binding());
+        let actual = f32::from_bytes(&actual);
+
+        for (expected, actual) in values[..line_size].iter().zip(actual) {
+            let tolerance = expected.abs() * f16::EPSILON.to_f32();
+            assert!(
+                (expected - actual).abs() <= tolerance,
+                "line size {line_size}: expected {expected}, got {actual}"
+            );
+        }
+    }
+}
+
+/// Checks the native line sizes of `f32`, and that `f16` packs at least as many elements in a
+/// vector access without going past 128 bits.
+pub fn test_native_line_sizes<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let f32_sizes = R::native_line_sizes(&Elem::Float(FloatKind::F32));
+
+    assert_eq!(f32_sizes, [4, 2, 1], "f32 line sizes");
+
+    if !client
+        .properties()
+        .feature_enabled(Feature::Type(Elem::Float(FloatKind::F16)))
+    {
+        return;
+    }
+
+    let f16_sizes = R::native_line_sizes(&Elem::Float(FloatKind::F16));
+
+    for size in f32_sizes {
+        assert!(f16_sizes.contains(&size), "f16 line sizes {f16_sizes:?}");
+    }
+    assert!(
+        f16_sizes.iter().all(|size| *size as usize * 2 <= 16),
+        "f16 line sizes {f16_sizes:?}"
+    );
+}
+
+/// Kernel round-tripping a line of `line_size` elements through `f16`.
+struct LineCastKernel {
+    line_size: u32,
+}
+
+impl Kernel for LineCastKernel {
+    fn define(&self) -> KernelDefinition {
+        let mut builder = KernelBuilder::default();
+        let input = builder.input_array(Item::new(Elem::Float(FloatKind::F32)));
+        let output = builder.output_array(Item::new(Elem::Float(FloatKind::F32)));
+
+        line_cast_round_trip::expand(
+            &mut builder.context,
+            input.into(),
+            output.into(),
+            self.line_size,
+        );
+
+        builder.build(KernelSettings::default().kernel_name("line_cast_kernel"))
+    }
+
+    fn id(&self) -> KernelId {
+        KernelId::new::<Self>().info(self.line_size)
+    }
+}
+
+/// The round-trip cast kernel, used to inspect the code generated for line casts.
+pub fn line_cast_kernel<R: Runtime>(line_size: u32) -> Box<dyn CubeTask<R::Compiler>> {
+    Box::new(KernelTask::<R::Compiler, _>::new(LineCastKernel {
+        line_size,
+    }))
+}
+
 macro_rules! impl_line_comparison {
     ($cmp:ident, $expected:expr) => {
         ::paste::paste! {
@@ -180,6 +309,18 @@ macro_rules! testgen_line {
             );
         }
 
+        #[test]
+        fn test_line_cast_round_trip() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::line::test_line_cast_round_trip::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_native_line_sizes() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::line::test_native_line_sizes::<TestRuntime>(client);
+        }
+
         #[test]
         fn test_line_equal() {
             let client = TestRuntime::client(&Default::default());
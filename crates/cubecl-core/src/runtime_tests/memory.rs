@@ -0,0 +1,120 @@
+use crate as cubecl;
+
+use cubecl::prelude::*;
+
+pub fn test_zeros<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    // Write and release a buffer first, so the zeroed allocation is likely to reuse dirty memory.
+    let dirty = client.create(u32::as_bytes(&[u32::MAX; 256]));
+    core::mem::drop(dirty);
+
+    let handle = client.zeros(256 * core::mem::size_of::<u32>());
+
+    let actual = client.read_one(handle.binding());
+    let actual = u32::from_bytes(&actual);
+
+    assert_eq!(actual, &[0u32; 256]);
+}
+
+pub fn test_fill<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let handle = client.create(u32::as_bytes(&[0u32; 256]));
+
+    client.fill(&handle, 0xDEADBEEF);
+
+    let actual = client.read_one(handle.binding());
+    let actual = u32::from_bytes(&actual);
+
+    assert_eq!(actual, &[0xDEADBEEFu32; 256]);
+}
+
+pub fn test_write<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let handle = client.create(u32::as_bytes(&[0u32; 256]));
+    let data = (0..64).collect::<Vec<u32>>();
+
+    let size = core::mem::size_of::<u32>() as u64;
+    let part = handle
+        .clone()
+        .offset_start(64 * size)
+        .offset_end(128 * size);
+    client.write(&part, u32::as_bytes(&data));
+
+    let actual = client.read_one(handle.binding());
+    let actual = u32::from_bytes(&actual);
+
+    assert_eq!(&actual[..64], &[0u32; 64]);
+    assert_eq!(&actual[64..128], data.as_slice());
+    assert_eq!(&actual[128..], &[0u32; 128]);
+}
+
+#[cube(launch)]
+fn kernel_add_one(input: &Array<u32>, output: &mut Array<u32>) {
+    if ABSOLUTE_POS < input.len() {
+        output[ABSOLUTE_POS] = input[ABSOLUTE_POS] + 1;
+    }
+}
+
+pub fn test_process_chunks<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    const CHUNK_LEN: usize = 1024;
+    // Goes through each of the three device buffers more than three times, ending with a shorter
+    // chunk.
+    let dataset = (0..10 * CHUNK_LEN as u32 + 17).collect::<Vec<u32>>();
+    let mut indices = Vec::new();
+    let mut actual = Vec::new();
+
+    client.process_chunks(
+        u32::as_bytes(&dataset).chunks(CHUNK_LEN * core::mem::size_of::<u32>()),
+        |client, input| {
+            let len = input.size() as usize / core::mem::size_of::<u32>();
+            let output = client.empty(input.size() as usize);
+
+            kernel_add_one::launch::<R>(
+                client,
+                CubeCount::Static(len.div_ceil(256) as u32, 1, 1),
+                CubeDim::new_1d(256),
+                unsafe { ArrayArg::from_raw_parts::<u32>(&input, len, 1) },
+                unsafe { ArrayArg::from_raw_parts::<u32>(&output, len, 1) },
+            );
+            output
+        },
+        |index, bytes| {
+            indices.push(index);
+            actual.extend_from_slice(u32::from_bytes(&bytes));
+        },
+    );
+
+    assert_eq!(indices, (0..11).collect::<Vec<_>>());
+    // The values on each side of the chunk boundaries come from different buffers.
+    let expected = dataset.iter().map(|value| value + 1).collect::<Vec<_>>();
+    assert_eq!(actual, expected);
+}
+
+#[allow(missing_docs)]
+#[macro_export]
+macro_rules! testgen_memory {
+    () => {
+        use super::*;
+
+        #[test]
+        fn test_memory_zeros() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::memory::test_zeros::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_memory_fill() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::memory::test_fill::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_memory_write() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::memory::test_write::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_memory_process_chunks() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::memory::test_process_chunks::<TestRuntime>(client);
+        }
+    };
+}
@@ -3,26 +3,37 @@ pub mod atomic;
 pub mod barrier;
 pub mod binary;
 pub mod branch;
+pub mod cast;
+pub mod closure;
 pub mod cluster;
 pub mod cmma;
+pub mod compilation;
+pub mod compiler_pass;
 pub mod const_match;
 pub mod constants;
 pub mod debug;
+pub mod default_client;
 pub mod different_rank;
 pub mod enums;
+pub mod features;
 pub mod index;
 pub mod launch;
 pub mod line;
+pub mod memory;
 pub mod metadata;
 pub mod pipeline;
 pub mod plane;
 pub mod sequence;
+pub mod shared_memory;
 pub mod slice;
+pub mod specialization;
 pub mod tensor;
 pub mod tensormap;
 pub mod topology;
 pub mod traits;
+pub mod tuple;
 pub mod unary;
+pub mod unroll;
 
 #[allow(missing_docs)]
 #[macro_export]
@@ -90,7 +101,9 @@ macro_rules! testgen_float {
         cubecl_core::testgen_pipeline!();
         cubecl_core::testgen_plane!();
         cubecl_core::testgen_sequence!();
+        cubecl_core::testgen_shared_memory!();
         cubecl_core::testgen_slice!();
+        cubecl_core::testgen_specialization!();
         cubecl_core::testgen_unary!();
         cubecl_core::testgen_atomic_float!();
         cubecl_core::testgen_tensormap!();
@@ -119,16 +132,24 @@ macro_rules! testgen_uint {
 macro_rules! testgen_untyped {
     () => {
         cubecl_core::testgen_cmma!();
+        cubecl_core::testgen_compiler_pass!();
+        cubecl_core::testgen_memory!();
         cubecl_core::testgen_metadata!();
         cubecl_core::testgen_topology!();
 
         cubecl_core::testgen_constants!();
         cubecl_core::testgen_tensor_indexing!();
         cubecl_core::testgen_debug!();
+        cubecl_core::testgen_default_client!();
+        cubecl_core::testgen_closure!();
         cubecl_core::testgen_binary_untyped!();
+        cubecl_core::testgen_cast!();
         cubecl_core::testgen_cluster!();
 
         cubecl_core::testgen_enums!();
+        cubecl_core::testgen_tuple!();
+        cubecl_core::testgen_unroll!();
+        cubecl_core::testgen_features!();
     };
 }
 
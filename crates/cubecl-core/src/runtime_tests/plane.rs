@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-use crate::{self as cubecl};
+use crate::{self as cubecl, compute::KernelTask, server::CompilationError};
 use crate::{Feature, runtime_tests::binary::assert_equals_approx};
 use cubecl::prelude::*;
 
@@ -117,6 +117,89 @@ pub fn kernel_ballot(output: &mut Tensor<Line<u32>>) {
     }
 }
 
+#[cube(launch)]
+pub fn kernel_all_any(input: &Array<u32>, all: &mut Array<u32>, any: &mut Array<u32>) {
+    let predicate = input[UNIT_POS] > 0;
+    all[UNIT_POS] = u32::cast_from(plane_all(predicate));
+    any[UNIT_POS] = u32::cast_from(plane_any(predicate));
+}
+
+#[cube(launch)]
+pub fn kernel_shuffle_stencil<F: Float>(input: &Tensor<F>, output: &mut Tensor<F>, delta: u32) {
+    let val = input[UNIT_POS];
+    let left = plane_shuffle_up(val, delta);
+    let right = plane_shuffle_down(val, delta);
+
+    output[UNIT_POS] = left + F::new(2.0) * val + right;
+}
+
+#[cube(launch)]
+pub fn kernel_shared_stencil<F: Float>(
+    input: &Tensor<F>,
+    output: &mut Tensor<F>,
+    delta: u32,
+    #[comptime] cube_size: u32,
+) {
+    let mut shared = SharedMemory::<F>::new(cube_size);
+    let val = input[UNIT_POS];
+    shared[UNIT_POS] = val;
+    sync_cube();
+
+    // Units without a neighbor in their own plane keep their own value, like the shuffles.
+    let mut left = val;
+    if UNIT_POS_PLANE >= delta {
+        left = shared[UNIT_POS - delta];
+    }
+    let mut right = val;
+    if UNIT_POS_PLANE + delta < PLANE_DIM {
+        right = shared[UNIT_POS + delta];
+    }
+
+    output[UNIT_POS] = left + F::new(2.0) * val + right;
+}
+
+#[cube(launch, create_dummy_kernel)]
+pub fn kernel_partition_sum<F: Float>(output: &mut Tensor<F>, #[comptime] partition_size: u32) {
+    let val = output[UNIT_POS];
+    output[UNIT_POS] = plane_partition_sum(val, partition_size);
+}
+
+#[cube(launch)]
+pub fn kernel_partition_inclusive_sum<F: Float>(
+    output: &mut Tensor<F>,
+    #[comptime] partition_size: u32,
+) {
+    let val = output[UNIT_POS];
+    output[UNIT_POS] = plane_partition_inclusive_sum(val, partition_size);
+}
+
+#[cube(launch)]
+pub fn kernel_partition_exclusive_sum<F: Float>(
+    output: &mut Tensor<F>,
+    #[comptime] partition_size: u32,
+) {
+    let val = output[UNIT_POS];
+    output[UNIT_POS] = plane_partition_exclusive_sum(val, partition_size);
+}
+
+#[cube(launch)]
+pub fn kernel_partition_inclusive_prod<F: Float>(
+    output: &mut Tensor<F>,
+    #[comptime] partition_size: u32,
+) {
+    let val = output[UNIT_POS];
+    output[UNIT_POS] = plane_partition_inclusive_prod(val, partition_size);
+}
+
+#[cube(launch)]
+pub fn kernel_partition_exclusive_prod<F: Float>(
+    output: &mut Tensor<F>,
+    #[comptime] partition_size: u32,
+) {
+    let val = output[UNIT_POS];
+    output[UNIT_POS] = plane_partition_exclusive_prod(val, partition_size);
+}
+
 pub fn test_plane_sum<
     TestRuntime: Runtime,
     F: Float + num_traits::Float + CubeElement + Display,
@@ -154,6 +237,297 @@ pub fn test_plane_sum<
     );
 }
 
+/// Sums each partition of 8 consecutive units of a plane of 32, every unit receiving the sum of
+/// its own partition.
+pub fn test_plane_partition_sum<
+    TestRuntime: Runtime,
+    F: Float + num_traits::Float + CubeElement + Display,
+>(
+    client: ComputeClient<TestRuntime::Server, TestRuntime::Channel>,
+    vectorization: u8,
+) {
+    let plane_size = 32;
+    let partition_size = 8;
+    let vectorization = vectorization as usize;
+    let input: Vec<f32> = (0..plane_size * vectorization).map(|x| x as f32).collect();
+    let mut expected = vec![0.0; input.len()];
+
+    for unit in 0..plane_size {
+        let partition = unit / partition_size * partition_size;
+        for v in 0..vectorization {
+            expected[unit * vectorization + v] = (partition..partition + partition_size)
+                .map(|k| input[k * vectorization + v])
+                .sum();
+        }
+    }
+    let input: Vec<F> = input.into_iter().map(|x| F::new(x)).collect();
+    let expected: Vec<F> = expected.into_iter().map(|x| F::new(x)).collect();
+
+    test_plane_operation::<TestRuntime, F, _>(
+        &input,
+        &expected,
+        vectorization as u8,
+        client.clone(),
+        |cube_count, handle| {
+            kernel_partition_sum::launch::<F, TestRuntime>(
+                &client,
+                cube_count,
+                CubeDim::new(plane_size as u32, 1, 1),
+                handle,
+                partition_size as u32,
+            )
+        },
+    );
+}
+
+/// Scans each partition of 8 consecutive units of a plane of 32, every unit receiving
+/// the inclusive sum of its own partition.
+pub fn test_plane_partition_inclusive_sum<
+    TestRuntime: Runtime,
+    F: Float + num_traits::Float + CubeElement + Display,
+>(
+    client: ComputeClient<TestRuntime::Server, TestRuntime::Channel>,
+    vectorization: u8,
+) {
+    let plane_size = 32;
+    let partition_size = 8;
+    let input: Vec<f32> = (0..plane_size * vectorization as usize)
+        .map(|x| x as f32)
+        .collect();
+    let expected = partition_scan(
+        &input,
+        vectorization as usize,
+        partition_size,
+        0.0,
+        true,
+        |acc, x| acc + x,
+    );
+
+    test_plane_partition_scan::<TestRuntime, F, _>(
+        &input,
+        &expected,
+        vectorization,
+        client.clone(),
+        |cube_count, handle| {
+            kernel_partition_inclusive_sum::launch::<F, TestRuntime>(
+                &client,
+                cube_count,
+                CubeDim::new(plane_size as u32, 1, 1),
+                handle,
+                partition_size as u32,
+            )
+        },
+    );
+}
+
+/// Scans each partition of 8 consecutive units of a plane of 32, every unit receiving
+/// the exclusive sum of its own partition.
+pub fn test_plane_partition_exclusive_sum<
+    TestRuntime: Runtime,
+    F: Float + num_traits::Float + CubeElement + Display,
+>(
+    client: ComputeClient<TestRuntime::Server, TestRuntime::Channel>,
+    vectorization: u8,
+) {
+    let plane_size = 32;
+    let partition_size = 8;
+    let input: Vec<f32> = (0..plane_size * vectorization as usize)
+        .map(|x| x as f32)
+        .collect();
+    let expected = partition_scan(
+        &input,
+        vectorization as usize,
+        partition_size,
+        0.0,
+        false,
+        |acc, x| acc + x,
+    );
+
+    test_plane_partition_scan::<TestRuntime, F, _>(
+        &input,
+        &expected,
+        vectorization,
+        client.clone(),
+        |cube_count, handle| {
+            kernel_partition_exclusive_sum::launch::<F, TestRuntime>(
+                &client,
+                cube_count,
+                CubeDim::new(plane_size as u32, 1, 1),
+                handle,
+                partition_size as u32,
+            )
+        },
+    );
+}
+
+/// Scans each partition of 8 consecutive units of a plane of 32, every unit receiving
+/// the inclusive product of its own partition.
+pub fn test_plane_partition_inclusive_prod<
+    TestRuntime: Runtime,
+    F: Float + num_traits::Float + CubeElement + Display,
+>(
+    client: ComputeClient<TestRuntime::Server, TestRuntime::Channel>,
+    vectorization: u8,
+) {
+    let plane_size = 32;
+    let partition_size = 8;
+    let input: Vec<f32> = (0..plane_size * vectorization as usize)
+        .map(|x| match x % 3 {
+            0 => 0.5,
+            1 => 1.25,
+            2 => 1.75,
+            _ => unreachable!(),
+        }) // keep the values relatively close to 1 to avoid overflow.
+        .collect();
+    let expected = partition_scan(
+        &input,
+        vectorization as usize,
+        partition_size,
+        1.0,
+        true,
+        |acc, x| acc * x,
+    );
+
+    test_plane_partition_scan::<TestRuntime, F, _>(
+        &input,
+        &expected,
+        vectorization,
+        client.clone(),
+        |cube_count, handle| {
+            kernel_partition_inclusive_prod::launch::<F, TestRuntime>(
+                &client,
+                cube_count,
+                CubeDim::new(plane_size as u32, 1, 1),
+                handle,
+                partition_size as u32,
+            )
+        },
+    );
+}
+
+/// Scans each partition of 8 consecutive units of a plane of 32, every unit receiving
+/// the exclusive product of its own partition.
+pub fn test_plane_partition_exclusive_prod<
+    TestRuntime: Runtime,
+    F: Float + num_traits::Float + CubeElement + Display,
+>(
+    client: ComputeClient<TestRuntime::Server, TestRuntime::Channel>,
+    vectorization: u8,
+) {
+    let plane_size = 32;
+    let partition_size = 8;
+    let input: Vec<f32> = (0..plane_size * vectorization as usize)
+        .map(|x| match x % 3 {
+            0 => 0.5,
+            1 => 1.25,
+            2 => 1.75,
+            _ => unreachable!(),
+        }) // keep the values relatively close to 1 to avoid overflow.
+        .collect();
+    let expected = partition_scan(
+        &input,
+        vectorization as usize,
+        partition_size,
+        1.0,
+        false,
+        |acc, x| acc * x,
+    );
+
+    test_plane_partition_scan::<TestRuntime, F, _>(
+        &input,
+        &expected,
+        vectorization,
+        client.clone(),
+        |cube_count, handle| {
+            kernel_partition_exclusive_prod::launch::<F, TestRuntime>(
+                &client,
+                cube_count,
+                CubeDim::new(plane_size as u32, 1, 1),
+                handle,
+                partition_size as u32,
+            )
+        },
+    );
+}
+
+/// Scans the values of each partition of `partition_size` units with `op`, starting from
+/// `identity` at the first unit of each partition.
+fn partition_scan(
+    input: &[f32],
+    vectorization: usize,
+    partition_size: usize,
+    identity: f32,
+    inclusive: bool,
+    op: fn(f32, f32) -> f32,
+) -> Vec<f32> {
+    let mut expected = vec![identity; input.len()];
+
+    for unit in 0..input.len() / vectorization {
+        let start = unit / partition_size * partition_size;
+        let end = match inclusive {
+            true => unit + 1,
+            false => unit,
+        };
+        for v in 0..vectorization {
+            expected[unit * vectorization + v] = (start..end)
+                .map(|k| input[k * vectorization + v])
+                .fold(identity, op);
+        }
+    }
+
+    expected
+}
+
+fn test_plane_partition_scan<
+    TestRuntime: Runtime,
+    F: Float + num_traits::Float + CubeElement + Display,
+    Launch,
+>(
+    input: &[f32],
+    expected: &[f32],
+    vectorization: u8,
+    client: ComputeClient<TestRuntime::Server, TestRuntime::Channel>,
+    launch: Launch,
+) where
+    Launch: Fn(CubeCount, TensorArg<'_, TestRuntime>),
+{
+    let input: Vec<F> = input.iter().map(|x| F::new(*x)).collect();
+    let expected: Vec<F> = expected.iter().map(|x| F::new(*x)).collect();
+
+    test_plane_operation::<TestRuntime, F, _>(&input, &expected, vectorization, client, launch);
+}
+
+/// Checks that a kernel partitioning the plane in groups larger than the plane is rejected.
+pub fn test_plane_partition_too_large<TestRuntime: Runtime>(
+    client: ComputeClient<TestRuntime::Server, TestRuntime::Channel>,
+) {
+    if !client.properties().feature_enabled(Feature::Plane) {
+        // Can't execute the test.
+        return;
+    }
+
+    let partition_size = 2 * client.properties().hardware_properties().plane_size_max;
+    let output = client.empty(size_of::<f32>());
+    let kernel = kernel_partition_sum::create_dummy_kernel::<f32, TestRuntime>(
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new_1d(1),
+        unsafe { TensorArg::from_raw_parts::<f32>(&output, &[1], &[1], 1) },
+        partition_size,
+    );
+    let result = client.compile(Box::new(KernelTask::<TestRuntime::Compiler, _>::new(
+        kernel,
+    )));
+
+    assert!(
+        matches!(
+            result,
+            Err(CompilationError::InvalidPlanePartition { partition_size: size, .. })
+                if size == partition_size
+        ),
+        "{result:?}"
+    );
+}
+
 pub fn test_plane_inclusive_sum<
     TestRuntime: Runtime,
     F: Float + num_traits::Float + CubeElement + Display,
@@ -558,6 +932,103 @@ pub fn test_plane_ballot<TestRuntime: Runtime>(
     assert_eq!(u32::from_bytes(&actual), &expected);
 }
 
+#[cube(launch)]
+pub fn kernel_fixed_plane_dim(output: &mut Array<u32>, #[comptime] fixed: Option<u32>) {
+    if UNIT_POS == 0 {
+        // The fixed plane size is known at compile time, so it can size an array.
+        let size = comptime!(fixed.unwrap());
+        let mut lanes = Array::<u32>::new(size);
+
+        #[unroll]
+        for i in 0..size {
+            lanes[i] = 1;
+        }
+
+        let mut count = 0;
+        #[unroll]
+        for i in 0..size {
+            count += lanes[i];
+        }
+
+        output[0] = count;
+        output[1] = plane_dim(fixed);
+        output[2] = PLANE_DIM;
+    }
+}
+
+/// Sizes an array with the fixed plane size of the device, which must then match the runtime
+/// plane size. CUDA always has a fixed plane size of 32.
+pub fn test_fixed_plane_dim<TestRuntime: Runtime>(
+    client: ComputeClient<TestRuntime::Server, TestRuntime::Channel>,
+) {
+    if !client.properties().feature_enabled(Feature::Plane) {
+        // Can't execute the test.
+        return;
+    }
+
+    let fixed = TestRuntime::fixed_plane_dim(&client);
+    if TestRuntime::name(&client) == "cuda" {
+        assert_eq!(fixed, Some(32));
+    }
+    let Some(plane_size) = fixed else {
+        // Only known at runtime.
+        return;
+    };
+
+    let handle = client.empty(size_of::<u32>() * 3);
+
+    kernel_fixed_plane_dim::launch::<TestRuntime>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new(plane_size, 1, 1),
+        unsafe { ArrayArg::from_raw_parts::<u32>(&handle, 3, 1) },
+        fixed,
+    );
+
+    let actual = client.read_one(handle.binding());
+
+    assert_eq!(u32::from_bytes(&actual), &[plane_size; 3]);
+}
+
+/// Reduces a predicate true for all lanes or only some lanes of a partial plane, where the
+/// units past the end of the cube must not change the result.
+pub fn test_plane_all_any_partial<TestRuntime: Runtime>(
+    client: ComputeClient<TestRuntime::Server, TestRuntime::Channel>,
+) {
+    if !client.properties().feature_enabled(Feature::Plane) {
+        // Can't execute the test.
+        return;
+    }
+
+    let num_units = 20;
+    let all_true = vec![1u32; num_units];
+    let mut some_true = vec![0u32; num_units];
+    some_true[3] = 1;
+
+    for (input, expected_all, expected_any) in [(all_true, 1, 1), (some_true, 0, 1)] {
+        let input = client.create(u32::as_bytes(&input));
+        let all = client.empty(size_of::<u32>() * num_units);
+        let any = client.empty(size_of::<u32>() * num_units);
+
+        unsafe {
+            kernel_all_any::launch::<TestRuntime>(
+                &client,
+                CubeCount::Static(1, 1, 1),
+                CubeDim::new_1d(num_units as u32),
+                ArrayArg::from_raw_parts::<u32>(&input, num_units, 1),
+                ArrayArg::from_raw_parts::<u32>(&all, num_units, 1),
+                ArrayArg::from_raw_parts::<u32>(&any, num_units, 1),
+            );
+        }
+
+        let all = client.read_one(all.binding());
+        let any = client.read_one(any.binding());
+
+        assert_eq!(u32::from_bytes(&all), vec![expected_all; num_units]);
+        assert_eq!(u32::from_bytes(&any), vec![expected_any; num_units]);
+    }
+}
+
 pub fn test_plane_elect<
     TestRuntime: Runtime,
     F: Float + num_traits::Float + CubeElement + Display,
@@ -625,6 +1096,54 @@ pub fn test_plane_broadcast<
     );
 }
 
+/// Computes a 1D stencil across the units of each plane with shuffles, checked against the same
+/// stencil read from shared memory.
+pub fn test_plane_shuffle_stencil<
+    TestRuntime: Runtime,
+    F: Float + num_traits::Float + CubeElement + Display,
+>(
+    client: ComputeClient<TestRuntime::Server, TestRuntime::Channel>,
+    delta: u32,
+) {
+    if !client.properties().feature_enabled(Feature::Plane) {
+        // Can't execute the test.
+        return;
+    }
+
+    // A multiple of the plane size, so every plane is full.
+    let cube_size = client.properties().hardware_properties().plane_size_max;
+    let input: Vec<F> = (0..cube_size).map(|x| F::new((x * x % 7) as f32)).collect();
+    let input = client.create(F::as_bytes(&input));
+    let shuffled = client.empty(cube_size as usize * core::mem::size_of::<F>());
+    let reference = client.empty(cube_size as usize * core::mem::size_of::<F>());
+    let (shape, strides) = ([cube_size as usize], [1]);
+
+    unsafe {
+        kernel_shuffle_stencil::launch::<F, TestRuntime>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(cube_size, 1, 1),
+            TensorArg::from_raw_parts::<F>(&input, &strides, &shape, 1),
+            TensorArg::from_raw_parts::<F>(&shuffled, &strides, &shape, 1),
+            ScalarArg::new(delta),
+        );
+        kernel_shared_stencil::launch::<F, TestRuntime>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(cube_size, 1, 1),
+            TensorArg::from_raw_parts::<F>(&input, &strides, &shape, 1),
+            TensorArg::from_raw_parts::<F>(&reference, &strides, &shape, 1),
+            ScalarArg::new(delta),
+            cube_size,
+        );
+    }
+
+    let expected = client.read_one(reference.binding());
+    let expected = F::from_bytes(&expected);
+
+    assert_equals_approx::<TestRuntime, F>(&client, shuffled, expected, 1e-5);
+}
+
 fn test_plane_operation<
     TestRuntime: Runtime,
     F: Float + num_traits::Float + CubeElement + Display,
@@ -802,6 +1321,94 @@ macro_rules! testgen_plane {
             impl_test_plane_max(4);
         }
 
+        fn impl_test_plane_partition_sum(vectorization: u8) {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::plane::test_plane_partition_sum::<TestRuntime, FloatType>(
+                client.clone(),
+                vectorization,
+            );
+        }
+        #[test]
+        fn test_plane_partition_sum_vec1() {
+            impl_test_plane_partition_sum(1);
+        }
+        #[test]
+        fn test_plane_partition_sum_vec4() {
+            impl_test_plane_partition_sum(4);
+        }
+
+        fn impl_test_plane_partition_inclusive_sum(vectorization: u8) {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::plane::test_plane_partition_inclusive_sum::<
+                TestRuntime,
+                FloatType,
+            >(client.clone(), vectorization);
+        }
+        #[test]
+        fn test_plane_partition_inclusive_sum_vec1() {
+            impl_test_plane_partition_inclusive_sum(1);
+        }
+        #[test]
+        fn test_plane_partition_inclusive_sum_vec4() {
+            impl_test_plane_partition_inclusive_sum(4);
+        }
+
+        fn impl_test_plane_partition_exclusive_sum(vectorization: u8) {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::plane::test_plane_partition_exclusive_sum::<
+                TestRuntime,
+                FloatType,
+            >(client.clone(), vectorization);
+        }
+        #[test]
+        fn test_plane_partition_exclusive_sum_vec1() {
+            impl_test_plane_partition_exclusive_sum(1);
+        }
+        #[test]
+        fn test_plane_partition_exclusive_sum_vec4() {
+            impl_test_plane_partition_exclusive_sum(4);
+        }
+
+        fn impl_test_plane_partition_inclusive_prod(vectorization: u8) {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::plane::test_plane_partition_inclusive_prod::<
+                TestRuntime,
+                FloatType,
+            >(client.clone(), vectorization);
+        }
+        #[test]
+        fn test_plane_partition_inclusive_prod_vec1() {
+            impl_test_plane_partition_inclusive_prod(1);
+        }
+        #[test]
+        fn test_plane_partition_inclusive_prod_vec4() {
+            impl_test_plane_partition_inclusive_prod(4);
+        }
+
+        fn impl_test_plane_partition_exclusive_prod(vectorization: u8) {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::plane::test_plane_partition_exclusive_prod::<
+                TestRuntime,
+                FloatType,
+            >(client.clone(), vectorization);
+        }
+        #[test]
+        fn test_plane_partition_exclusive_prod_vec1() {
+            impl_test_plane_partition_exclusive_prod(1);
+        }
+        #[test]
+        fn test_plane_partition_exclusive_prod_vec4() {
+            impl_test_plane_partition_exclusive_prod(4);
+        }
+
+        #[test]
+        fn test_plane_partition_too_large() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::plane::test_plane_partition_too_large::<TestRuntime>(
+                client.clone(),
+            );
+        }
+
         fn impl_test_plane_min(vectorization: u8) {
             let client = TestRuntime::client(&Default::default());
             cubecl_core::runtime_tests::plane::test_plane_min::<TestRuntime, FloatType>(
@@ -905,10 +1512,40 @@ macro_rules! testgen_plane {
             impl_test_plane_broadcast(4);
         }
 
+        fn impl_test_plane_shuffle_stencil(delta: u32) {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::plane::test_plane_shuffle_stencil::<TestRuntime, FloatType>(
+                client.clone(),
+                delta,
+            );
+        }
+        #[test]
+        fn test_plane_shuffle_stencil_delta1() {
+            impl_test_plane_shuffle_stencil(1);
+        }
+        #[test]
+        fn test_plane_shuffle_stencil_delta3() {
+            impl_test_plane_shuffle_stencil(3);
+        }
+
         #[test]
         fn test_plane_ballot() {
             let client = TestRuntime::client(&Default::default());
             cubecl_core::runtime_tests::plane::test_plane_ballot::<TestRuntime>(client.clone());
         }
+
+        #[test]
+        fn test_fixed_plane_dim() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::plane::test_fixed_plane_dim::<TestRuntime>(client.clone());
+        }
+
+        #[test]
+        fn test_plane_all_any_partial() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::plane::test_plane_all_any_partial::<TestRuntime>(
+                client.clone(),
+            );
+        }
     };
 }
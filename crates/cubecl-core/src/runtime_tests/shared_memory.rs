@@ -0,0 +1,149 @@
+use crate::{self as cubecl, as_bytes};
+use cubecl::prelude::*;
+
+#[cube(launch)]
+pub fn kernel_uniform_load<F: Float>(input: &Array<F>, output: &mut Array<F>) {
+    let mut smem = SharedMemory::<F>::new(1u32);
+
+    // The last unit is usually in a different plane than most readers, which is what makes a
+    // plain load racy under the WebGPU memory model.
+    if UNIT_POS == CUBE_DIM - 1 {
+        smem[0] = input[0];
+    }
+
+    let value = smem.uniform_load(0u32);
+    output[UNIT_POS] = value;
+}
+
+pub fn test_uniform_load<R: Runtime, F: Float + CubeElement>(
+    client: ComputeClient<R::Server, R::Channel>,
+) {
+    let num_units = 256;
+    let input = client.create(as_bytes![F: 3.0]);
+    let output = client.empty(num_units * core::mem::size_of::<F>());
+
+    unsafe {
+        kernel_uniform_load::launch::<F, R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(num_units as u32, 1, 1),
+            ArrayArg::from_raw_parts::<F>(&input, 1, 1),
+            ArrayArg::from_raw_parts::<F>(&output, num_units, 1),
+        )
+    };
+
+    let actual = client.read_one(output.binding());
+    let actual = F::from_bytes(&actual);
+
+    assert!(actual.iter().all(|value| *value == F::new(3.0)));
+}
+
+#[cube(launch)]
+pub fn kernel_multiple_shared_memories(input: &Array<f32>, output: &mut Array<f32>) {
+    let mut tile = SharedMemory::<f32>::new(32u32);
+    let mut indices = SharedMemory::<u32>::new(32u32);
+
+    tile[UNIT_POS] = input[UNIT_POS];
+    indices[UNIT_POS] = CUBE_DIM - 1 - UNIT_POS;
+    sync_cube();
+
+    output[UNIT_POS] = tile[indices[UNIT_POS]];
+}
+
+/// Uses two shared memories of different types in the same kernel.
+pub fn test_multiple_shared_memories<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let num_units = 32;
+    let values: Vec<f32> = (0..num_units).map(|i| i as f32).collect();
+    let input = client.create(f32::as_bytes(&values));
+    let output = client.empty(num_units * size_of::<f32>());
+
+    kernel_multiple_shared_memories::launch::<R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new_1d(num_units as u32),
+        unsafe { ArrayArg::from_raw_parts::<f32>(&input, num_units, 1) },
+        unsafe { ArrayArg::from_raw_parts::<f32>(&output, num_units, 1) },
+    );
+
+    let actual = client.read_one(output.binding());
+    let actual = f32::from_bytes(&actual);
+    let expected: Vec<f32> = values.into_iter().rev().collect();
+
+    assert_eq!(actual, &expected);
+}
+
+/// The amount of shared memory every device supports without opting into a larger limit.
+const STATIC_SHARED_MEMORY_LIMIT: usize = 48 * 1024;
+
+#[cube(launch, shared_memory_carveout = 100)]
+pub fn kernel_large_shared_memory(output: &mut Array<f32>, #[comptime] length: u32) {
+    let mut smem = SharedMemory::<f32>::new(length);
+
+    for i in range_stepped(UNIT_POS, length, CUBE_DIM) {
+        smem[i] = f32::cast_from(i);
+    }
+    sync_cube();
+
+    output[UNIT_POS] = smem[length - 1 - UNIT_POS];
+}
+
+/// Allocates more shared memory than the default limit, on devices supporting it.
+pub fn test_large_shared_memory<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let limit = client
+        .properties()
+        .hardware_properties()
+        .max_shared_memory_size;
+    if limit <= STATIC_SHARED_MEMORY_LIMIT {
+        return;
+    }
+
+    let num_units = 256;
+    let length = (STATIC_SHARED_MEMORY_LIMIT + 16 * 1024).min(limit) / size_of::<f32>();
+    let output = client.empty(num_units * size_of::<f32>());
+
+    kernel_large_shared_memory::launch::<R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new_1d(num_units as u32),
+        unsafe { ArrayArg::from_raw_parts::<f32>(&output, num_units, 1) },
+        length as u32,
+    );
+
+    let actual = client.read_one(output.binding());
+    let actual = f32::from_bytes(&actual);
+    let expected: Vec<f32> = (0..num_units).map(|i| (length - 1 - i) as f32).collect();
+
+    assert_eq!(actual, &expected);
+}
+
+#[allow(missing_docs)]
+#[macro_export]
+macro_rules! testgen_shared_memory {
+    () => {
+        use super::*;
+
+        #[test]
+        fn test_shared_memory_uniform_load() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::shared_memory::test_uniform_load::<TestRuntime, FloatType>(
+                client,
+            );
+        }
+
+        #[test]
+        fn test_shared_memory_multiple() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::shared_memory::test_multiple_shared_memories::<TestRuntime>(
+                client,
+            );
+        }
+
+        #[test]
+        fn test_shared_memory_large() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::shared_memory::test_large_shared_memory::<TestRuntime>(
+                client,
+            );
+        }
+    };
+}
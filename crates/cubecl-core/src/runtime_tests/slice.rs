@@ -30,6 +30,23 @@ pub fn slice_for<F: Float>(input: &Array<F>, output: &mut Array<F>) {
     }
 }
 
+#[cube(launch)]
+pub fn slice_enumerate<F: Float>(input: &Array<F>, output: &mut Array<F>) {
+    let mut shared = SharedMemory::<F>::new(8);
+    shared[UNIT_POS] = input[UNIT_POS];
+    sync_cube();
+
+    if UNIT_POS == 0 {
+        let mut sum = F::new(0.0);
+
+        for (i, value) in shared.to_slice().iter().enumerate() {
+            sum += F::cast_from(i) * value;
+        }
+
+        output[0] = sum;
+    }
+}
+
 #[cube(launch)]
 pub fn slice_mut_assign<F: Float>(input: &Array<F>, output: &mut Array<F>) {
     if UNIT_POS == 0 {
@@ -46,6 +63,29 @@ pub fn slice_mut_len(output: &mut Array<u32>) {
     }
 }
 
+#[cube]
+fn sum_slice<F: Float>(values: &Slice<F>) -> F {
+    let mut sum = F::new(0.0);
+
+    for i in 0..values.len() {
+        sum += values[i];
+    }
+
+    sum
+}
+
+#[cube(launch)]
+pub fn slice_shared_memory_helper<F: Float>(input: &Array<F>, output: &mut Array<F>) {
+    let mut shared = SharedMemory::<F>::new(8);
+    shared[UNIT_POS] = input[UNIT_POS];
+    sync_cube();
+
+    if UNIT_POS == 0 {
+        output[0] = sum_slice::<F>(&shared[2..6]);
+        output[1] = sum_slice::<F>(&shared[..3]);
+    }
+}
+
 pub fn test_slice_select<R: Runtime, F: Float + CubeElement>(
     client: ComputeClient<R::Server, R::Channel>,
 ) {
@@ -112,6 +152,29 @@ pub fn test_slice_for<R: Runtime, F: Float + CubeElement>(
     assert_eq!(actual[0], F::new(5.0));
 }
 
+pub fn test_slice_enumerate<R: Runtime, F: Float + CubeElement>(
+    client: ComputeClient<R::Server, R::Channel>,
+) {
+    let input = client.create(as_bytes![F: 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+    let output = client.create(as_bytes![F: 0.0]);
+
+    unsafe {
+        slice_enumerate::launch::<F, R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(8, 1, 1),
+            ArrayArg::from_raw_parts::<F>(&input, 8, 1),
+            ArrayArg::from_raw_parts::<F>(&output, 1, 1),
+        )
+    };
+
+    let actual = client.read_one(output.binding());
+    let actual = F::from_bytes(&actual);
+
+    // Sum of `i * (i + 1)` for `i` in `0..8`.
+    assert_eq!(actual[0], F::new(168.0));
+}
+
 pub fn test_slice_mut_assign<R: Runtime, F: Float + CubeElement>(
     client: ComputeClient<R::Server, R::Channel>,
 ) {
@@ -152,6 +215,28 @@ pub fn test_slice_mut_len<R: Runtime>(client: ComputeClient<R::Server, R::Channe
     assert_eq!(actual[0], 2);
 }
 
+pub fn test_slice_shared_memory_helper<R: Runtime, F: Float + CubeElement>(
+    client: ComputeClient<R::Server, R::Channel>,
+) {
+    let input = client.create(as_bytes![F: 0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]);
+    let output = client.empty(core::mem::size_of::<F>() * 2);
+
+    unsafe {
+        slice_shared_memory_helper::launch::<F, R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(8, 1, 1),
+            ArrayArg::from_raw_parts::<F>(&input, 8, 1),
+            ArrayArg::from_raw_parts::<F>(&output, 2, 1),
+        )
+    };
+
+    let actual = client.read_one(output.binding());
+    let actual = F::from_bytes(&actual);
+
+    assert_eq!(actual, as_type![F: 14.0, 3.0]);
+}
+
 #[allow(missing_docs)]
 #[macro_export]
 macro_rules! testgen_slice {
@@ -176,6 +261,14 @@ macro_rules! testgen_slice {
             cubecl_core::runtime_tests::slice::test_slice_for::<TestRuntime, FloatType>(client);
         }
 
+        #[test]
+        fn test_slice_enumerate() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::slice::test_slice_enumerate::<TestRuntime, FloatType>(
+                client,
+            );
+        }
+
         #[test]
         fn test_slice_mut_assign() {
             let client = TestRuntime::client(&Default::default());
@@ -189,5 +282,14 @@ macro_rules! testgen_slice {
             let client = TestRuntime::client(&Default::default());
             cubecl_core::runtime_tests::slice::test_slice_mut_len::<TestRuntime>(client);
         }
+
+        #[test]
+        fn test_slice_shared_memory_helper() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::slice::test_slice_shared_memory_helper::<
+                TestRuntime,
+                FloatType,
+            >(client);
+        }
     };
 }
@@ -0,0 +1,193 @@
+use crate::{
+    self as cubecl, CompilationTarget,
+    ir::{Arithmetic, Item, Operation, Scope},
+};
+
+use cubecl::prelude::*;
+
+#[cube]
+pub fn specialized_op<F: Float>(x: F) -> F {
+    if F::is_f16() {
+        x * x
+    } else if F::is_f32() {
+        x + x
+    } else {
+        x
+    }
+}
+
+#[cube(launch)]
+pub fn kernel_specialized<F: Float>(input: &Array<F>, output: &mut Array<F>) {
+    if UNIT_POS == 0 {
+        output[0] = specialized_op::<F>(input[0]);
+    }
+}
+
+pub fn test_kernel_specialized<R: Runtime, F: Float + CubeElement>(
+    client: ComputeClient<R::Server, R::Channel>,
+) {
+    let input = client.create(F::as_bytes(&[F::new(3.0)]));
+    let output = client.empty(core::mem::size_of::<F>());
+
+    kernel_specialized::launch::<F, R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::default(),
+        unsafe { ArrayArg::from_raw_parts::<F>(&input, 1, 1) },
+        unsafe { ArrayArg::from_raw_parts::<F>(&output, 1, 1) },
+    );
+
+    let actual = client.read_one(output.binding());
+    let actual = F::from_bytes(&actual);
+
+    let expected = if F::is_f16() {
+        F::new(9.0)
+    } else if F::is_f32() {
+        F::new(6.0)
+    } else {
+        F::new(3.0)
+    };
+    assert_eq!(actual[0], expected);
+}
+
+/// Expands [specialized_op] for `F` and returns the operations it lowered to.
+fn specialized_operations<F: Float>() -> Vec<Operation> {
+    let mut scope = Scope::root(false);
+    let x = scope.create_local(Item::new(F::as_elem_native_unchecked()));
+
+    specialized_op::expand::<F>(&mut scope, x.into());
+
+    scope
+        .instructions
+        .into_iter()
+        .map(|inst| inst.operation)
+        .collect()
+}
+
+pub fn test_specialization_lowering() {
+    let ops_f16 = specialized_operations::<half::f16>();
+    let ops_f32 = specialized_operations::<f32>();
+
+    // Only the branch of the concrete type is expanded, no runtime branch remains.
+    assert!(matches!(
+        ops_f16.as_slice(),
+        [Operation::Arithmetic(Arithmetic::Mul(_))]
+    ));
+    assert!(matches!(
+        ops_f32.as_slice(),
+        [Operation::Arithmetic(Arithmetic::Add(_))]
+    ));
+}
+
+#[cube]
+pub fn target_specialized_op(x: f32) -> f32 {
+    if comptime!(target_is_cuda()) {
+        // Stands in for a CUDA-only fast path, the other targets take the portable fallback.
+        x * x
+    } else {
+        f32::powf(x, 2.0)
+    }
+}
+
+#[cube(launch)]
+pub fn kernel_target_specialized(input: &Array<f32>, output: &mut Array<f32>) {
+    if UNIT_POS == 0 {
+        output[0] = target_specialized_op(input[0]);
+    }
+}
+
+pub fn test_kernel_target_specialized<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let input = client.create(f32::as_bytes(&[3.0]));
+    let output = client.empty(core::mem::size_of::<f32>());
+
+    kernel_target_specialized::launch::<R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::default(),
+        unsafe { ArrayArg::from_raw_parts::<f32>(&input, 1, 1) },
+        unsafe { ArrayArg::from_raw_parts::<f32>(&output, 1, 1) },
+    );
+
+    let actual = client.read_one(output.binding());
+    let actual = f32::from_bytes(&actual);
+
+    assert!((actual[0] - 9.0).abs() < 1e-4, "{} != 9", actual[0]);
+}
+
+/// Expands [target_specialized_op] for the target and returns the operations it lowered to.
+fn target_specialized_operations(target: Option<CompilationTarget>) -> Vec<Operation> {
+    let expand = || {
+        let mut scope = Scope::root(false);
+        let x = scope.create_local(Item::new(f32::as_elem_native_unchecked()));
+
+        target_specialized_op::expand(&mut scope, x.into());
+
+        scope
+            .instructions
+            .into_iter()
+            .map(|inst| inst.operation)
+            .collect()
+    };
+
+    match target {
+        Some(target) => with_compilation_target(target, expand),
+        None => expand(),
+    }
+}
+
+pub fn test_target_specialization_lowering() {
+    let ops_cuda = target_specialized_operations(Some(CompilationTarget::Cuda));
+    let ops_wgsl = target_specialized_operations(Some(CompilationTarget::Wgsl));
+    let ops_none = target_specialized_operations(None);
+
+    assert!(matches!(
+        ops_cuda.as_slice(),
+        [Operation::Arithmetic(Arithmetic::Mul(_))]
+    ));
+    assert!(matches!(
+        ops_wgsl.as_slice(),
+        [Operation::Arithmetic(Arithmetic::Powf(_))]
+    ));
+    // Without a compiler, the target is unknown and the portable fallback is expanded.
+    assert!(matches!(
+        ops_none.as_slice(),
+        [Operation::Arithmetic(Arithmetic::Powf(_))]
+    ));
+    // The target is only set while expanding.
+    assert_eq!(compilation_target(), None);
+}
+
+#[allow(missing_docs)]
+#[macro_export]
+macro_rules! testgen_specialization {
+    () => {
+        use super::*;
+
+        #[test]
+        fn test_specialized() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::specialization::test_kernel_specialized::<
+                TestRuntime,
+                FloatType,
+            >(client);
+        }
+
+        #[test]
+        fn test_specialization_lowering() {
+            cubecl_core::runtime_tests::specialization::test_specialization_lowering();
+        }
+
+        #[test]
+        fn test_target_specialized() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::specialization::test_kernel_target_specialized::<
+                TestRuntime,
+            >(client);
+        }
+
+        #[test]
+        fn test_target_specialization_lowering() {
+            cubecl_core::runtime_tests::specialization::test_target_specialization_lowering();
+        }
+    };
+}
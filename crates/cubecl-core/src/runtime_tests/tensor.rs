@@ -46,6 +46,75 @@ pub fn test_tensor_coordinate<R: Runtime>(client: ComputeClient<R::Server, R::Ch
     }
 }
 
+#[cube(launch)]
+pub fn tensor_scale(input: &Tensor<f32>, output: &mut Tensor<f32>) {
+    if ABSOLUTE_POS < output.len() {
+        output[ABSOLUTE_POS] = input[ABSOLUTE_POS] * 3.0 + 0.1;
+    }
+}
+
+#[cube(launch)]
+pub fn tensor_cast_f16(input: &Tensor<f32>, output: &mut Tensor<half::f16>) {
+    if ABSOLUTE_POS < output.len() {
+        output[ABSOLUTE_POS] = half::f16::cast_from(input[ABSOLUTE_POS]);
+    }
+}
+
+pub fn test_tensor_stored_as<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    if !half::f16::is_supported(&client) {
+        return;
+    }
+
+    let shape = [4, 8];
+    let strides = [8, 1];
+    let size = shape.iter().product::<usize>();
+    let values = (0..size)
+        .map(|i| i as f32 * 1.37 - 20.0)
+        .collect::<Vec<_>>();
+    let input = client.create(f32::as_bytes(&values));
+
+    // Computed in f32 and stored as f16 directly.
+    let output = client.empty(core::mem::size_of::<half::f16>() * size);
+    unsafe {
+        tensor_scale::launch::<R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(size as u32, 1, 1),
+            TensorArg::from_raw_parts::<f32>(&input, &strides, &shape, 1),
+            TensorArg::from_raw_parts::<half::f16>(&output, &strides, &shape, 1)
+                .stored_as::<half::f16>(),
+        )
+    };
+
+    // Computed in f32, then cast to f16 by a separate kernel.
+    let scaled = client.empty(core::mem::size_of::<f32>() * size);
+    let expected = client.empty(core::mem::size_of::<half::f16>() * size);
+    unsafe {
+        tensor_scale::launch::<R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(size as u32, 1, 1),
+            TensorArg::from_raw_parts::<f32>(&input, &strides, &shape, 1),
+            TensorArg::from_raw_parts::<f32>(&scaled, &strides, &shape, 1),
+        );
+        tensor_cast_f16::launch::<R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(size as u32, 1, 1),
+            TensorArg::from_raw_parts::<f32>(&scaled, &strides, &shape, 1),
+            TensorArg::from_raw_parts::<half::f16>(&expected, &strides, &shape, 1),
+        );
+    }
+
+    let actual = client.read_one(output.binding());
+    let expected = client.read_one(expected.binding());
+
+    assert_eq!(
+        half::f16::from_bytes(&actual),
+        half::f16::from_bytes(&expected)
+    );
+}
+
 #[allow(missing_docs)]
 #[macro_export]
 macro_rules! testgen_tensor_indexing {
@@ -57,5 +126,11 @@ macro_rules! testgen_tensor_indexing {
             let client = TestRuntime::client(&Default::default());
             cubecl_core::runtime_tests::tensor::test_tensor_coordinate::<TestRuntime>(client);
         }
+
+        #[test]
+        fn test_tensor_stored_as() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::tensor::test_tensor_stored_as::<TestRuntime>(client);
+        }
     };
 }
@@ -11,6 +11,22 @@ pub fn kernel_absolute_pos(output1: &mut Array<u32>) {
     output1[ABSOLUTE_POS] = ABSOLUTE_POS;
 }
 
+#[cube(launch)]
+pub fn kernel_grid_stride(output: &mut Array<u32>, cube_count: &mut Array<u32>) {
+    let stride = CUBE_COUNT_X * CUBE_DIM_X;
+
+    for i in range_stepped(ABSOLUTE_POS_X, output.len(), stride) {
+        output[i] = i;
+    }
+
+    if ABSOLUTE_POS == 0 {
+        cube_count[0] = CUBE_COUNT_X;
+        cube_count[1] = CUBE_COUNT_Y;
+        cube_count[2] = CUBE_COUNT_Z;
+        cube_count[3] = CUBE_COUNT;
+    }
+}
+
 pub fn test_kernel_topology_absolute_pos<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
     let cube_count = (3, 5, 7);
     let cube_dim = (16, 16, 1);
@@ -34,6 +50,65 @@ pub fn test_kernel_topology_absolute_pos<R: Runtime>(client: ComputeClient<R::Se
     assert_eq!(actual, &expect);
 }
 
+pub fn test_kernel_topology_grid_stride<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    // Launch a lot fewer units than there are elements, each unit has to loop over the array
+    // using the actual grid size read at runtime.
+    let length = 1000;
+    let handle = client.empty(length * core::mem::size_of::<u32>());
+    let cube_count = client.empty(4 * core::mem::size_of::<u32>());
+
+    unsafe {
+        kernel_grid_stride::launch::<R>(
+            &client,
+            CubeCount::Static(3, 1, 1),
+            CubeDim::new(32, 1, 1),
+            ArrayArg::from_raw_parts::<u32>(&handle, length, 1),
+            ArrayArg::from_raw_parts::<u32>(&cube_count, 4, 1),
+        )
+    };
+
+    let actual = client.read_one(handle.binding());
+    let actual = u32::from_bytes(&actual);
+    let expect: Vec<u32> = (0..length as u32).collect();
+    assert_eq!(actual, &expect);
+
+    let actual = client.read_one(cube_count.binding());
+    let actual = u32::from_bytes(&actual);
+    assert_eq!(actual, &[3, 1, 1, 3]);
+}
+
+#[cube(launch)]
+pub fn kernel_grid_stride_range(visits: &mut Array<Atomic<u32>>) {
+    for i in grid_stride_range(visits.len()) {
+        Atomic::add(&visits[i], 1);
+    }
+}
+
+pub fn test_kernel_topology_grid_stride_range<R: Runtime>(
+    client: ComputeClient<R::Server, R::Channel>,
+) {
+    // 128 units over 1000 elements, so the last round of the loop only covers part of the units.
+    let length = 1000;
+    let handle = client.create(u32::as_bytes(&vec![0; length]));
+
+    unsafe {
+        kernel_grid_stride_range::launch::<R>(
+            &client,
+            CubeCount::Static(2, 2, 1),
+            CubeDim::new(8, 4, 1),
+            ArrayArg::from_raw_parts::<u32>(&handle, length, 1),
+        )
+    };
+
+    let actual = client.read_one(handle.binding());
+    let actual = u32::from_bytes(&actual);
+    assert_eq!(
+        actual,
+        &vec![1; length],
+        "Each element should be visited once"
+    );
+}
+
 #[allow(missing_docs)]
 #[macro_export]
 macro_rules! testgen_topology {
@@ -47,5 +122,21 @@ macro_rules! testgen_topology {
                 client,
             );
         }
+
+        #[test]
+        fn test_topology_grid_stride() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::topology::test_kernel_topology_grid_stride::<TestRuntime>(
+                client,
+            );
+        }
+
+        #[test]
+        fn test_topology_grid_stride_range() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::topology::test_kernel_topology_grid_stride_range::<
+                TestRuntime,
+            >(client);
+        }
     };
 }
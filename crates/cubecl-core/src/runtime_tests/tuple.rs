@@ -0,0 +1,76 @@
+use crate::{self as cubecl, as_bytes};
+use cubecl::prelude::*;
+
+#[cube]
+fn sum_difference(lhs: Line<f32>, rhs: Line<f32>) -> (Line<f32>, Line<f32>) {
+    (lhs + rhs, lhs - rhs)
+}
+
+#[cube]
+fn sum_difference_product(lhs: Line<f32>, rhs: Line<f32>) -> ((Line<f32>, Line<f32>), Line<f32>) {
+    (sum_difference(lhs, rhs), lhs * rhs)
+}
+
+#[cube(launch)]
+pub fn kernel_tuple_return(
+    lhs: &Array<Line<f32>>,
+    rhs: &Array<Line<f32>>,
+    output: &mut Array<Line<f32>>,
+) {
+    if UNIT_POS == 0 {
+        let (sum, difference) = sum_difference(lhs[0], rhs[0]);
+        output[0] = sum;
+        output[1] = difference;
+
+        // The nested tuple comes first, so the outer one must still be readable after it.
+        let ((sum, difference), product) = sum_difference_product(lhs[0], rhs[0]);
+        output[2] = sum + difference;
+        output[3] = product;
+
+        let pair: (Line<f32>, Line<f32>) = sum_difference(lhs[0], rhs[0]);
+        output[4] = pair.0 * pair.1;
+    }
+}
+
+pub fn test_tuple_return<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let lhs = client.create(as_bytes![f32: 1.0, 2.0, 3.0, 4.0]);
+    let rhs = client.create(as_bytes![f32: 4.0, 3.0, 2.0, 1.0]);
+    let output = client.empty(5 * 4 * core::mem::size_of::<f32>());
+
+    kernel_tuple_return::launch::<R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new_single(),
+        unsafe { ArrayArg::from_raw_parts::<f32>(&lhs, 4, 4) },
+        unsafe { ArrayArg::from_raw_parts::<f32>(&rhs, 4, 4) },
+        unsafe { ArrayArg::from_raw_parts::<f32>(&output, 20, 4) },
+    );
+
+    let actual = client.read_one(output.binding());
+    let actual = f32::from_bytes(&actual);
+
+    assert_eq!(
+        actual,
+        [
+            5.0, 5.0, 5.0, 5.0, // sum
+            -3.0, -1.0, 1.0, 3.0, // difference
+            2.0, 4.0, 6.0, 8.0, // sum + difference
+            4.0, 6.0, 6.0, 4.0, // product
+            -15.0, -5.0, 5.0, 15.0, // sum * difference
+        ]
+    );
+}
+
+#[allow(missing_docs)]
+#[macro_export]
+macro_rules! testgen_tuple {
+    () => {
+        use super::*;
+
+        #[test]
+        fn test_tuple_return() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::tuple::test_tuple_return::<TestRuntime>(client);
+        }
+    };
+}
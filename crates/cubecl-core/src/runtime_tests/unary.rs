@@ -1,6 +1,9 @@
 use std::fmt::Display;
 
-use crate::{self as cubecl, as_type};
+use crate::{
+    self as cubecl, Feature, as_type,
+    ir::{Elem, UIntKind},
+};
 
 use cubecl::prelude::*;
 use cubecl_runtime::server::Handle;
@@ -259,6 +262,58 @@ test_unary_impl_int_fixed!(test_count_ones, I, u32, I::count_ones, [
     }
 ]);
 
+#[cube(launch)]
+fn kernel_fast_recip_rsqrt(
+    input: &Array<Line<f32>>,
+    fast: &mut Array<Line<f32>>,
+    exact: &mut Array<Line<f32>>,
+) {
+    if ABSOLUTE_POS < input.len() {
+        let x = input[ABSOLUTE_POS];
+        fast[2 * ABSOLUTE_POS] = Line::<f32>::recip_fast(x);
+        fast[2 * ABSOLUTE_POS + 1] = Line::<f32>::rsqrt_fast(x);
+        exact[2 * ABSOLUTE_POS] = Line::<f32>::recip(x);
+        exact[2 * ABSOLUTE_POS + 1] = Line::<f32>::recip(Line::<f32>::sqrt(x));
+    }
+}
+
+/// Checks that the approximate reciprocal and reciprocal square root stay within a few ulps of the
+/// precise versions.
+pub fn test_fast_recip_rsqrt<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    const LINE_SIZE: u8 = 4;
+    // The fast intrinsics are accurate to about 2 ulps (`__fdividef` and `rsqrtf` in CUDA,
+    // `fast::divide` and `fast::rsqrt` in Metal), on top of the rounding of the precise versions.
+    const MAX_RELATIVE_ERROR: f32 = 4.0 * f32::EPSILON;
+
+    let values: Vec<f32> = (0..256).map(|i| 0.01 * 1.07f32.powi(i)).collect();
+    let lines = values.len() as u32 / LINE_SIZE as u32;
+    let input = client.create(f32::as_bytes(&values));
+    let fast = client.empty(2 * values.len() * core::mem::size_of::<f32>());
+    let exact = client.empty(2 * values.len() * core::mem::size_of::<f32>());
+
+    kernel_fast_recip_rsqrt::launch::<R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new_1d(lines),
+        unsafe { ArrayArg::from_raw_parts::<f32>(&input, values.len(), LINE_SIZE) },
+        unsafe { ArrayArg::from_raw_parts::<f32>(&fast, 2 * values.len(), LINE_SIZE) },
+        unsafe { ArrayArg::from_raw_parts::<f32>(&exact, 2 * values.len(), LINE_SIZE) },
+    );
+
+    let fast = client.read_one(fast.binding());
+    let fast = f32::from_bytes(&fast);
+    let exact = client.read_one(exact.binding());
+    let exact = f32::from_bytes(&exact);
+
+    for (i, (fast, exact)) in fast.iter().zip(exact.iter()).enumerate() {
+        let error = ((fast - exact) / exact).abs();
+        assert!(
+            error < MAX_RELATIVE_ERROR,
+            "Relative error {error} too large at index {i}: fast={fast}, exact={exact}"
+        );
+    }
+}
+
 macro_rules! shift {
     ($value:expr) => {{
         let shift = (size_of::<I>() - 1) * 8;
@@ -336,6 +391,131 @@ test_unary_impl_int_fixed!(test_find_first_set, I, u32, I::find_first_set, [
     }
 ]);
 
+macro_rules! int_bits {
+    () => {
+        size_of::<I>() as u32 * 8
+    };
+}
+
+test_unary_impl_int_fixed!(test_trailing_zeros, I, u32, I::trailing_zeros, [
+    {
+        input_vectorization: 1,
+        out_vectorization: 1,
+        input: as_type![I: 0b1110_0010, 0b0000_0000, 0b0010_1000],
+        expected: &[1, int_bits!(), 3]
+    },
+    {
+        input_vectorization: 2,
+        out_vectorization: 2,
+        input: as_type![I: 0b1110_0010, 0b0000_0000, 0b0010_1000, 0b1111_1111],
+        expected: &[1, int_bits!(), 3, 0]
+    },
+    {
+        input_vectorization: 4,
+        out_vectorization: 4,
+        input: as_type![I: 0b1110_0010, 0b0000_0000, 0b0010_1000, 0b1000_0000],
+        expected: &[1, int_bits!(), 3, 7]
+    }
+]);
+
+#[cube(launch_unchecked)]
+fn kernel_bit_counts<I: Int>(
+    input: &Array<Line<I>>,
+    ones: &mut Array<Line<u32>>,
+    leading: &mut Array<Line<u32>>,
+    trailing: &mut Array<Line<u32>>,
+) {
+    if ABSOLUTE_POS < input.len() {
+        let value = input[ABSOLUTE_POS];
+        ones[ABSOLUTE_POS] = Line::count_ones(value);
+        leading[ABSOLUTE_POS] = Line::leading_zeros(value);
+        trailing[ABSOLUTE_POS] = Line::trailing_zeros(value);
+    }
+}
+
+/// Launches [kernel_bit_counts] on `values` with every line size, and compares the population
+/// count, leading zeros and trailing zeros with the `expected` ones of each value.
+fn test_bit_counts<R: Runtime, I: Int + CubeElement>(
+    client: ComputeClient<R::Server, R::Channel>,
+    values: &[I],
+    expected: &[(u32, u32, u32)],
+) {
+    for line_size in [1, 2, 4] {
+        let input = client.create(I::as_bytes(values));
+        let outputs = [(); 3].map(|_| client.empty(values.len() * size_of::<u32>()));
+
+        unsafe {
+            kernel_bit_counts::launch_unchecked::<I, R>(
+                &client,
+                CubeCount::Static(1, 1, 1),
+                CubeDim::new_1d(values.len() as u32 / line_size as u32),
+                ArrayArg::from_raw_parts::<I>(&input, values.len(), line_size),
+                ArrayArg::from_raw_parts::<u32>(&outputs[0], values.len(), line_size),
+                ArrayArg::from_raw_parts::<u32>(&outputs[1], values.len(), line_size),
+                ArrayArg::from_raw_parts::<u32>(&outputs[2], values.len(), line_size),
+            )
+        };
+
+        let [ones, leading, trailing] =
+            outputs.map(|output| u32::from_bytes(&client.read_one(output.binding())).to_vec());
+        let actual: Vec<_> = (0..values.len())
+            .map(|i| (ones[i], leading[i], trailing[i]))
+            .collect();
+        assert_eq!(actual, expected, "Line size {line_size}");
+    }
+}
+
+pub fn test_bit_counts_u32<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let values = [
+        0,
+        u32::MAX,
+        1,
+        1 << 31,
+        0b1011_0000,
+        0x00F0_0000,
+        0x7FFF_FFFF,
+        0x1234_5678,
+    ];
+    let expected = values.map(|value| {
+        (
+            value.count_ones(),
+            value.leading_zeros(),
+            value.trailing_zeros(),
+        )
+    });
+
+    test_bit_counts::<R, u32>(client, &values, &expected);
+}
+
+pub fn test_bit_counts_u64<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    if !client
+        .properties()
+        .feature_enabled(Feature::Type(Elem::UInt(UIntKind::U64)))
+    {
+        return;
+    }
+
+    let values = [
+        0,
+        u64::MAX,
+        1,
+        1 << 63,
+        1 << 32,
+        0x0000_0000_8000_0000,
+        0x00F0_0000_0000_0000,
+        0x1234_5678_9ABC_DEF0,
+    ];
+    let expected = values.map(|value| {
+        (
+            value.count_ones(),
+            value.leading_zeros(),
+            value.trailing_zeros(),
+        )
+    });
+
+    test_bit_counts::<R, u64>(client, &values, &expected);
+}
+
 #[allow(missing_docs)]
 #[macro_export]
 macro_rules! testgen_unary {
@@ -357,6 +537,12 @@ macro_rules! testgen_unary {
 
             add_test!(test_normalize);
             add_test!(test_magnitude);
+
+            #[test]
+            fn test_fast_recip_rsqrt() {
+                let client = TestRuntime::client(&Default::default());
+                cubecl_core::runtime_tests::unary::test_fast_recip_rsqrt::<TestRuntime>(client);
+            }
         }
     };
 }
@@ -384,6 +570,19 @@ macro_rules! testgen_unary_int {
             add_test!(test_reverse_bits);
             add_test!(test_leading_zeros);
             add_test!(test_find_first_set);
+            add_test!(test_trailing_zeros);
+
+            #[test]
+            fn test_bit_counts_u32() {
+                let client = TestRuntime::client(&Default::default());
+                cubecl_core::runtime_tests::unary::test_bit_counts_u32::<TestRuntime>(client);
+            }
+
+            #[test]
+            fn test_bit_counts_u64() {
+                let client = TestRuntime::client(&Default::default());
+                cubecl_core::runtime_tests::unary::test_bit_counts_u64::<TestRuntime>(client);
+            }
         }
     };
 }
@@ -0,0 +1,354 @@
+use crate::{
+    self as cubecl,
+    compute::KernelBuilder,
+    ir::{Bitwise, Branch, Elem, Item, Operation, Scope, UIntKind},
+};
+
+use cubecl::prelude::*;
+
+#[cube(launch)]
+pub fn kernel_unroll_full(input: &Array<u32>, output: &mut Array<u32>) {
+    #[unroll]
+    for i in 0..4 {
+        output[i] = input[i] ^ 5;
+    }
+}
+
+#[cube(launch)]
+pub fn kernel_unroll_partial(input: &Array<u32>, output: &mut Array<u32>) {
+    #[unroll(4)]
+    for i in 0..input.len() {
+        output[i] = input[i] ^ 5;
+    }
+}
+
+#[cube(launch)]
+pub fn kernel_unroll_partial_stepped(input: &Array<u32>, output: &mut Array<u32>) {
+    #[unroll(2)]
+    for i in range_stepped(1, input.len(), 3) {
+        output[i] = input[i] ^ 5;
+    }
+}
+
+#[cube(launch)]
+pub fn kernel_unroll_partial_signed(output: &mut Array<i32>, end: i32) {
+    let mut n = 0u32;
+    #[unroll(2)]
+    for i in range_stepped(-5i32, end, 3) {
+        output[n] = i;
+        n += 1;
+    }
+}
+
+#[cube(launch)]
+pub fn kernel_descending(output: &mut Array<i32>) {
+    let mut n = 0u32;
+    for i in range_stepped(8i32, -1i32, comptime![-2i32 as u32]) {
+        output[n] = i;
+        n += 1;
+    }
+}
+
+#[cube(launch)]
+pub fn kernel_unroll_partial_descending(output: &mut Array<i32>) {
+    let mut n = 0u32;
+    #[unroll(2)]
+    for i in range_stepped(8i32, -1i32, comptime![-2i32 as u32]) {
+        output[n] = i;
+        n += 1;
+    }
+}
+
+#[cube]
+pub fn kernel_unroll_full_runtime_bound(input: &Array<u32>, output: &mut Array<u32>) {
+    #[unroll]
+    for i in 0..input.len() {
+        output[i] = input[i] ^ 5;
+    }
+}
+
+#[cube]
+pub fn kernel_unroll_full_runtime_length(input: &Array<u32>, output: &mut Array<u32>) {
+    #[unroll]
+    for (i, value) in input.iter().enumerate() {
+        output[i] = value ^ 5;
+    }
+}
+
+#[cube(launch)]
+pub fn kernel_runtime_bound(output: &mut Array<u32>, n: u32) {
+    let mut end = n;
+    let mut sum = 0u32;
+    for i in 0..end {
+        sum += i;
+        // The bound is read once when the loop starts, like a Rust range.
+        end -= 1;
+    }
+    output[0] = sum;
+    output[1] = end;
+}
+
+#[cube(launch)]
+pub fn kernel_comptime_bound(output: &mut Array<u32>, #[comptime] n: u32) {
+    #[unroll]
+    for i in 0..n {
+        output[i] = i * 2;
+    }
+}
+
+pub fn test_runtime_loop_bound<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    for n in [0u32, 1, 7, 32] {
+        let output = client.create(u32::as_bytes(&[0; 2]));
+
+        kernel_runtime_bound::launch::<R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new_1d(1),
+            unsafe { ArrayArg::from_raw_parts::<u32>(&output, 2, 1) },
+            ScalarArg::new(n),
+        );
+
+        let actual = client.read_one(output.binding());
+        let actual = u32::from_bytes(&actual);
+
+        assert_eq!(actual, [(0..n).sum::<u32>(), 0], "n = {n}");
+    }
+}
+
+pub fn test_comptime_loop_bound<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    for n in [1u32, 3, 8] {
+        let output = client.create(u32::as_bytes(&[u32::MAX; 8]));
+
+        kernel_comptime_bound::launch::<R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new_1d(1),
+            unsafe { ArrayArg::from_raw_parts::<u32>(&output, 8, 1) },
+            n,
+        );
+
+        let actual = client.read_one(output.binding());
+        let actual = u32::from_bytes(&actual);
+        let expected: Vec<u32> = (0..8)
+            .map(|i| if i < n { i * 2 } else { u32::MAX })
+            .collect();
+
+        assert_eq!(actual, expected, "n = {n}");
+    }
+}
+
+pub fn test_unroll_partial<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let values: Vec<u32> = (0..10).collect();
+    let input = client.create(u32::as_bytes(&values));
+    let output = client.create(u32::as_bytes(&[0; 10]));
+
+    kernel_unroll_partial::launch::<R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new_1d(1),
+        unsafe { ArrayArg::from_raw_parts::<u32>(&input, 10, 1) },
+        unsafe { ArrayArg::from_raw_parts::<u32>(&output, 10, 1) },
+    );
+
+    let actual = client.read_one(output.binding());
+    let actual = u32::from_bytes(&actual);
+    let expected: Vec<u32> = values.iter().map(|value| value ^ 5).collect();
+
+    assert_eq!(actual, expected);
+}
+
+pub fn test_unroll_partial_stepped<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let values: Vec<u32> = (0..10).collect();
+    let input = client.create(u32::as_bytes(&values));
+    let output = client.create(u32::as_bytes(&[0; 10]));
+
+    kernel_unroll_partial_stepped::launch::<R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new_1d(1),
+        unsafe { ArrayArg::from_raw_parts::<u32>(&input, 10, 1) },
+        unsafe { ArrayArg::from_raw_parts::<u32>(&output, 10, 1) },
+    );
+
+    let actual = client.read_one(output.binding());
+    let actual = u32::from_bytes(&actual);
+
+    assert_eq!(actual, [0, 1 ^ 5, 0, 0, 4 ^ 5, 0, 0, 7 ^ 5, 0, 0]);
+}
+
+pub fn test_unroll_partial_signed<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    for end in [-10i32, -5, -4, 1, 2, 5] {
+        let output = client.create(i32::as_bytes(&[i32::MAX; 4]));
+
+        kernel_unroll_partial_signed::launch::<R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new_1d(1),
+            unsafe { ArrayArg::from_raw_parts::<i32>(&output, 4, 1) },
+            ScalarArg::new(end),
+        );
+
+        let actual = client.read_one(output.binding());
+        let actual = i32::from_bytes(&actual);
+        let mut expected: Vec<i32> = (-5..end).step_by(3).collect();
+        expected.resize(4, i32::MAX);
+
+        assert_eq!(actual, expected, "end = {end}");
+    }
+}
+
+pub fn test_descending_range<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let expected = [8, 6, 4, 2, 0, i32::MAX];
+
+    let output = client.create(i32::as_bytes(&[i32::MAX; 6]));
+    kernel_descending::launch::<R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new_1d(1),
+        unsafe { ArrayArg::from_raw_parts::<i32>(&output, 6, 1) },
+    );
+    let actual = client.read_one(output.binding());
+    assert_eq!(i32::from_bytes(&actual), expected);
+
+    let output = client.create(i32::as_bytes(&[i32::MAX; 6]));
+    kernel_unroll_partial_descending::launch::<R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new_1d(1),
+        unsafe { ArrayArg::from_raw_parts::<i32>(&output, 6, 1) },
+    );
+    let actual = client.read_one(output.binding());
+    assert_eq!(i32::from_bytes(&actual), expected);
+}
+
+/// Expands a kernel over an input and an output array and returns the root scope.
+fn expand_unroll_kernel(
+    expand: impl FnOnce(&mut Scope, ExpandElementTyped<Array<u32>>, ExpandElementTyped<Array<u32>>),
+) -> Scope {
+    let mut builder = KernelBuilder::default();
+    let item = Item::new(Elem::UInt(UIntKind::U32));
+    let input = builder.input_array(item);
+    let output = builder.output_array(item);
+
+    expand(&mut builder.context, input.into(), output.into());
+
+    builder.context
+}
+
+/// The number of times the loop body was expanded directly in the scope.
+fn count_bodies(scope: &Scope) -> usize {
+    scope
+        .instructions
+        .iter()
+        .filter(|inst| matches!(inst.operation, Operation::Bitwise(Bitwise::BitwiseXor(_))))
+        .count()
+}
+
+fn range_loops(scope: &Scope) -> Vec<&crate::ir::RangeLoop> {
+    scope
+        .instructions
+        .iter()
+        .filter_map(|inst| match &inst.operation {
+            Operation::Branch(Branch::RangeLoop(range_loop)) => Some(range_loop.as_ref()),
+            _ => None,
+        })
+        .collect()
+}
+
+pub fn test_unroll_full_ir() {
+    let scope = expand_unroll_kernel(|scope, input, output| {
+        kernel_unroll_full::expand(scope, input, output)
+    });
+
+    assert_eq!(count_bodies(&scope), 4);
+    assert!(range_loops(&scope).is_empty());
+}
+
+pub fn test_unroll_partial_ir() {
+    let scope = expand_unroll_kernel(|scope, input, output| {
+        kernel_unroll_partial::expand(scope, input, output)
+    });
+
+    // The main loop with the replicated body, followed by the remainder loop.
+    let loops = range_loops(&scope);
+    assert_eq!(loops.len(), 2);
+    assert_eq!(count_bodies(&loops[0].scope), 4);
+    assert_eq!(count_bodies(&loops[1].scope), 1);
+    assert_eq!(count_bodies(&scope), 0);
+}
+
+pub fn test_unroll_full_runtime_bound_ir() {
+    let kernels = [
+        kernel_unroll_full_runtime_bound::expand,
+        kernel_unroll_full_runtime_length::expand,
+    ];
+
+    for kernel in kernels {
+        let panic = std::panic::catch_unwind(|| {
+            expand_unroll_kernel(|scope, input, output| kernel(scope, input, output))
+        })
+        .expect_err("Full unroll with a runtime bound should be rejected");
+        let message = panic.downcast_ref::<String>().unwrap();
+
+        assert!(message.contains("only known at runtime"), "{message}");
+    }
+}
+
+#[allow(missing_docs)]
+#[macro_export]
+macro_rules! testgen_unroll {
+    () => {
+        use super::*;
+
+        #[test]
+        fn test_unroll_partial() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::unroll::test_unroll_partial::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_unroll_partial_stepped() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::unroll::test_unroll_partial_stepped::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_unroll_partial_signed() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::unroll::test_unroll_partial_signed::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_descending_range() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::unroll::test_descending_range::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_runtime_loop_bound() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::unroll::test_runtime_loop_bound::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_comptime_loop_bound() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::unroll::test_comptime_loop_bound::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_unroll_full_ir() {
+            cubecl_core::runtime_tests::unroll::test_unroll_full_ir();
+        }
+
+        #[test]
+        fn test_unroll_partial_ir() {
+            cubecl_core::runtime_tests::unroll::test_unroll_partial_ir();
+        }
+
+        #[test]
+        fn test_unroll_full_runtime_bound_ir() {
+            cubecl_core::runtime_tests::unroll::test_unroll_full_runtime_bound_ir();
+        }
+    };
+}
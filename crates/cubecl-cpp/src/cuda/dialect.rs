@@ -1,14 +1,14 @@
 use std::collections::HashSet;
 
-use cubecl_core::ir::Id;
+use cubecl_core::{CompilationTarget, ir::Id};
 
 use crate::{
     Dialect,
     cuda::ptx::TMA_LOAD_IM2COL,
     shared::{
         self, Binding, Component, DialectBindings, DialectCubeBuiltins, DialectIncludes,
-        DialectInstructions, DialectTypes, DialectWmmaCompiler, Elem, Flags, Instruction, Item,
-        SharedMemory, Variable, WarpInstruction, unary,
+        DialectInstructions, DialectTypes, DialectWmmaCompiler, Elem, Flags, FmtLeft, Instruction,
+        Item, SharedMemory, Variable, WarpInstruction, unary,
     },
 };
 
@@ -19,7 +19,9 @@ pub struct CudaDialect {}
 
 // Base dialect
 
-impl Dialect for CudaDialect {}
+impl Dialect for CudaDialect {
+    const TARGET: CompilationTarget = CompilationTarget::Cuda;
+}
 
 // Includes
 
@@ -250,6 +252,27 @@ impl DialectInstructions<Self> for CudaDialect {
         writeln!(f, "__threadfence();")
     }
 
+    fn compile_instruction_thread_fence_block(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "__threadfence_block();")
+    }
+
+    // memory hints
+    fn compile_instruction_prefetch(
+        f: &mut std::fmt::Formatter<'_>,
+        input: &Variable<Self>,
+        index: &Variable<Self>,
+        bytes: u32,
+    ) -> std::fmt::Result {
+        // One L2 prefetch per 128 bytes cache line
+        write!(
+            f,
+            "for (uint prefetchOffset = 0; prefetchOffset < {bytes}; prefetchOffset += 128) {{
+  asm volatile(\"prefetch.global.L2 [%0];\" :: \"l\"(reinterpret_cast<const char*>(&{input}[{index}]) + prefetchOffset));
+}}
+"
+        )
+    }
+
     // unary
     fn compile_instruction_find_first_set<T: Component<Self>>(
         f: &mut std::fmt::Formatter<'_>,
@@ -289,6 +312,29 @@ impl DialectInstructions<Self> for CudaDialect {
     }
 
     // others
+    fn compile_instruction_dot_i8x4(
+        f: &mut std::fmt::Formatter<'_>,
+        lhs: &Variable<Self>,
+        rhs: &Variable<Self>,
+        out: &Variable<Self>,
+    ) -> std::fmt::Result {
+        // Lines of 4 `i8` are 4 bytes aligned, so they can be packed into an `int` as is. `__dp4a`
+        // is only available from sm_61.
+        let muls = (0..4)
+            .map(|i| format!("{} * {}", lhs.index(i), rhs.index(i)))
+            .collect::<Vec<_>>();
+        let out = out.fmt_left();
+        writeln!(
+            f,
+            "#if __CUDA_ARCH__ >= 610
+{out} = __dp4a(reinterpret_cast<const int&>({lhs}), reinterpret_cast<const int&>({rhs}), 0);
+#else
+{out} = {};
+#endif",
+            muls.join(" + ")
+        )
+    }
+
     fn compile_instruction_max_function_name(
         f: &mut std::fmt::Formatter<'_>,
         item: Item<Self>,
@@ -343,17 +389,18 @@ impl DialectInstructions<Self> for CudaDialect {
     ) -> std::fmt::Result {
         write!(f, "__shfl_down_sync(-1, {var}, {offset})")
     }
+    // Only the active lanes vote, so the missing lanes of a partial warp don't affect the result.
     fn compile_warp_all<T: Component<Self>>(
         f: &mut std::fmt::Formatter<'_>,
         input: &T,
     ) -> std::fmt::Result {
-        write!(f, "__all_sync(-1, {input})")
+        write!(f, "__all_sync(__activemask(), {input})")
     }
     fn compile_warp_any<T: Component<Self>>(
         f: &mut std::fmt::Formatter<'_>,
         input: &T,
     ) -> std::fmt::Result {
-        write!(f, "__any_sync(-1, {input})")
+        write!(f, "__any_sync(__activemask(), {input})")
     }
 
     fn compile_warp_ballot(
@@ -1,7 +1,7 @@
 use std::fmt::Display;
 use std::{collections::HashSet, marker::PhantomData};
 
-use cubecl_core::ir::Id;
+use cubecl_core::{CompilationTarget, ir::Id};
 
 use crate::shared::{
     Component, DialectInstructions, Elem, Instruction, SharedMemory, Variable, unary,
@@ -25,7 +25,9 @@ pub struct HipDialect<M> {
 
 // Base dialect
 
-impl<M: DialectWmmaCompiler<Self>> Dialect for HipDialect<M> {}
+impl<M: DialectWmmaCompiler<Self>> Dialect for HipDialect<M> {
+    const TARGET: CompilationTarget = CompilationTarget::Hip;
+}
 
 // Includes
 
@@ -258,6 +260,10 @@ impl<M: DialectWmmaCompiler<Self>> DialectInstructions<Self> for HipDialect<M> {
         CudaDialect::compile_instruction_thread_fence(f)
     }
 
+    fn compile_instruction_thread_fence_block(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        CudaDialect::compile_instruction_thread_fence_block(f)
+    }
+
     // unary
     fn compile_instruction_find_first_set<T: Component<Self>>(
         f: &mut std::fmt::Formatter<'_>,
@@ -0,0 +1,334 @@
+use crate::{
+    hip::{HipDialect, arch::AMDArchitecture},
+    shared::{
+        Architecture, Component, DialectWmmaCompiler, Elem, Fragment, FragmentIdent,
+        FragmentLayout, SupportedWmmaCombinations, Variable, WmmaInstruction,
+    },
+};
+use cubecl_core::ir::{self as gpu};
+
+/// Matrix-core compiler for CDNA devices (gfx908, gfx90a, gfx94x) using the MFMA builtins.
+///
+/// MFMA instructions always run on a full wavefront of 64 lanes and always accumulate in
+/// full precision. The 16x16x16 and 32x32x8 shapes are supported, other shapes are rejected when
+/// compiling the fragments.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MfmaIntrinsicCompiler {}
+
+/// The shape of an MFMA instruction, which determines how the fragments are split across the
+/// lanes of the wavefront.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum MfmaShape {
+    M16N16K16,
+    M32N32K8,
+}
+
+impl MfmaShape {
+    fn of(fragment: &Fragment<HipDialect<MfmaIntrinsicCompiler>>) -> Self {
+        match (fragment.m, fragment.n, fragment.k) {
+            (16, 16, 16) => MfmaShape::M16N16K16,
+            (32, 32, 8) => MfmaShape::M32N32K8,
+            (m, n, k) => {
+                panic!("unsupported MFMA shape m{m}n{n}k{k}, expected m16n16k16 or m32n32k8")
+            }
+        }
+    }
+
+    fn of_variable(variable: &Variable<HipDialect<MfmaIntrinsicCompiler>>) -> Self {
+        match variable {
+            Variable::WmmaFragment { frag, .. } => Self::of(frag),
+            other => panic!("{other} is not a WMMMA fragment!"),
+        }
+    }
+
+    /// The local variables holding the lane in a group and the group of the current unit.
+    fn lane_and_group(&self) -> (&'static str, &'static str) {
+        match self {
+            MfmaShape::M16N16K16 => ("mfmaLane16", "mfmaGroup16"),
+            MfmaShape::M32N32K8 => ("mfmaLane32", "mfmaGroup32"),
+        }
+    }
+
+    /// The number of groups of lanes in the wavefront.
+    fn groups(&self) -> u32 {
+        match self {
+            MfmaShape::M16N16K16 => 4,
+            MfmaShape::M32N32K8 => 2,
+        }
+    }
+
+    /// The number of elements of the accumulator held by each lane.
+    fn accumulator_len(&self) -> u32 {
+        match self {
+            MfmaShape::M16N16K16 => 4,
+            MfmaShape::M32N32K8 => 16,
+        }
+    }
+
+    fn accumulator_type(&self) -> &'static str {
+        match self {
+            MfmaShape::M16N16K16 => "floatx4_t",
+            MfmaShape::M32N32K8 => "floatx16_t",
+        }
+    }
+
+    fn builtin(&self) -> &'static str {
+        match self {
+            MfmaShape::M16N16K16 => "__builtin_amdgcn_mfma_f32_16x16x16f16",
+            MfmaShape::M32N32K8 => "__builtin_amdgcn_mfma_f32_32x32x8f16",
+        }
+    }
+
+    /// The row of the accumulator held in the register `index` of the lane, for its column at
+    /// the lane in its group.
+    ///
+    /// The rows are split in blocks of 4 consecutive rows per group, repeated until all the
+    /// registers of the lane are used.
+    fn accumulator_row(&self, index: &str) -> String {
+        let (_, group) = self.lane_and_group();
+        let block = 4 * self.groups();
+        format!("(uint({block}) * ({index} / uint(4)) + {group} * uint(4) + {index} % uint(4))")
+    }
+}
+
+impl DialectWmmaCompiler<HipDialect<Self>> for MfmaIntrinsicCompiler {
+    type Architecture = AMDArchitecture;
+
+    fn compile_wmma_includes(_f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // nothing to do
+        Ok(())
+    }
+
+    fn compile_wmma_type_definitions(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("typedef _Float16 halfx4_t __attribute__((ext_vector_type(4)));\n")?;
+        f.write_str("typedef float floatx4_t __attribute__((ext_vector_type(4)));\n")?;
+        f.write_str("typedef float floatx16_t __attribute__((ext_vector_type(16)));\n")
+    }
+
+    fn compile_local_variables(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // lanes are split in 4 groups of 16 lanes for 16x16x16 and in 2 groups of 32 lanes for
+        // 32x32x8, each group holds 4 consecutive elements along the K dimension (A and B) or
+        // along the M dimension (C and D)
+        f.write_str("uint mfmaLane16 = uint(threadIdx.x % 16);\n")?;
+        f.write_str("uint mfmaGroup16 = uint((threadIdx.x % 64) / 16);\n")?;
+        f.write_str("uint mfmaLane32 = uint(threadIdx.x % 32);\n")?;
+        f.write_str("uint mfmaGroup32 = uint((threadIdx.x % 64) / 32);\n")
+    }
+
+    fn compile_fragment_ident(
+        _ident: &FragmentIdent<HipDialect<Self>>,
+        _f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        // nothing to do
+        Ok(())
+    }
+
+    fn compile_fragment_layout(
+        _layout: &FragmentLayout<HipDialect<Self>>,
+        _f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        // nothing to do
+        Ok(())
+    }
+
+    fn compile_fragment(
+        fragment: &Fragment<HipDialect<Self>>,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        let shape = MfmaShape::of(fragment);
+        match fragment.ident {
+            FragmentIdent::A | FragmentIdent::B => match fragment.elem {
+                Elem::F16 => write!(f, "halfx4_t"),
+                other => panic!("unsupported type {other} for {fragment}"),
+            },
+            FragmentIdent::Accumulator => match fragment.elem {
+                Elem::F32 => write!(f, "{}", shape.accumulator_type()),
+                other => panic!("unsupported type {other} for {fragment}"),
+            },
+            FragmentIdent::_Dialect(_) => Ok(()),
+        }
+    }
+
+    fn compile_instruction(
+        instruction: &WmmaInstruction<HipDialect<Self>>,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match instruction {
+            WmmaInstruction::Fill { frag, value } => {
+                let fill_with_zeros =
+                    matches!(value, Variable::ConstantScalar(number, _) if number.is_zero());
+                if fill_with_zeros {
+                    write!(
+                        f,
+                        "// fill
+{frag} = {{}};
+"
+                    )
+                } else {
+                    let len = match frag {
+                        Variable::WmmaFragment { frag: inner, .. }
+                            if inner.ident == FragmentIdent::Accumulator =>
+                        {
+                            MfmaShape::of(inner).accumulator_len()
+                        }
+                        _ => 4,
+                    };
+                    write!(
+                        f,
+                        "// fill
+for (uint i = 0; i < uint({len}); ++i) {{
+  {frag}[i] = {value};
+}}
+"
+                    )
+                }
+            }
+            WmmaInstruction::Load {
+                frag,
+                value,
+                stride,
+                layout,
+            } => {
+                // Layout of v_mfma_f32_16x16x16f16 for lane l and register i in [0, 4):
+                // - A holds A[l % 16][4 * (l / 16) + i]
+                // - B holds B[4 * (l / 16) + i][l % 16]
+                // - C and D hold C[4 * (l / 16) + i][l % 16]
+                //
+                // Layout of v_mfma_f32_32x32x8f16 for lane l, register i in [0, 4) of A and B
+                // and register j in [0, 16) of C and D:
+                // - A holds A[l % 32][4 * (l / 32) + i]
+                // - B holds B[4 * (l / 32) + i][l % 32]
+                // - C and D hold C[8 * (j / 4) + 4 * (l / 32) + j % 4][l % 32]
+                let item = value.item();
+                let elem = item.elem;
+                let mut value_ident = format!("{value}");
+                if item.vectorization > 1 {
+                    writeln!(
+                        f,
+                        "{elem}* {value}_scalar = reinterpret_cast<{elem}*>({value});"
+                    )?;
+                    value_ident = format!("{value}_scalar");
+                }
+                let shape = MfmaShape::of_variable(frag);
+                let (lane, group) = shape.lane_and_group();
+                let (row, col, layout, len) = match frag {
+                    Variable::WmmaFragment { frag: inner, .. } => match inner.ident {
+                        FragmentIdent::A => (
+                            lane.to_string(),
+                            format!("({group} * uint(4) + i)"),
+                            inner.layout.unwrap(),
+                            4,
+                        ),
+                        FragmentIdent::B => (
+                            format!("({group} * uint(4) + i)"),
+                            lane.to_string(),
+                            inner.layout.unwrap(),
+                            4,
+                        ),
+                        FragmentIdent::Accumulator => (
+                            shape.accumulator_row("i"),
+                            lane.to_string(),
+                            layout.expect(
+                                "cannot load data to an accumulator without knowing the layout of the data",
+                            ),
+                            shape.accumulator_len(),
+                        ),
+                        other => panic!("unknown matrix identifier {other}"),
+                    },
+                    other => panic!("{other} is not a WMMMA fragment!"),
+                };
+                let index = match layout {
+                    FragmentLayout::RowMajor => format!("{row} * {stride} + {col}"),
+                    FragmentLayout::ColMajor => format!("{col} * {stride} + {row}"),
+                    FragmentLayout::_Dialect(_) => String::new(),
+                };
+                write!(
+                    f,
+                    "// load
+for (uint i = 0; i < uint({len}); ++i) {{
+  {frag}[i] = {value_ident}[{index}];
+}}
+"
+                )
+            }
+            WmmaInstruction::Execute {
+                frag_a,
+                frag_b,
+                frag_c,
+                frag_d,
+                warp_size,
+            } => {
+                if *warp_size != 64 {
+                    panic!("MFMA instructions require a wavefront size of 64, got {warp_size}.")
+                }
+                let builtin = MfmaShape::of_variable(frag_a).builtin();
+                writeln!(
+                    f,
+                    "{frag_d} = {builtin}({frag_a}, {frag_b}, {frag_c}, 0, 0, 0);"
+                )
+            }
+            WmmaInstruction::Store {
+                output,
+                frag,
+                stride,
+                layout,
+            } => {
+                let item = output.item();
+                let elem = item.elem;
+                let mut output_ident = format!("{output}");
+                if item.vectorization > 1 {
+                    writeln!(
+                        f,
+                        "{elem}* {output}_scalar = reinterpret_cast<{elem}*>({output});"
+                    )?;
+                    output_ident = format!("{output}_scalar");
+                }
+                let shape = MfmaShape::of_variable(frag);
+                let (lane, _) = shape.lane_and_group();
+                let len = shape.accumulator_len();
+                let row = shape.accumulator_row("elemIdx");
+                // FragmentLayout here represents the desired layout of the matrix C
+                let output_idx = match layout {
+                    FragmentLayout::RowMajor => format!("rowIdx * {stride} + {lane}"),
+                    FragmentLayout::ColMajor => format!("{lane} * {stride} + rowIdx"),
+                    FragmentLayout::_Dialect(_) => String::new(),
+                };
+                write!(
+                    f,
+                    "// store
+for (uint elemIdx = 0; elemIdx < uint({len}); ++elemIdx) {{
+  const uint rowIdx = {row};
+  {output_ident}[{output_idx}] = {frag}[elemIdx];
+}}
+"
+                )
+            }
+            WmmaInstruction::Cast { input, output } => {
+                let len = MfmaShape::of_variable(input).accumulator_len();
+                write!(
+                    f,
+                    "// cast
+for (uint elemIdx = 0; elemIdx < uint({len}); ++elemIdx) {{
+  {output}[elemIdx] = {input}[elemIdx];
+}}
+"
+                )
+            }
+        }
+    }
+
+    fn supported_wmma_combinations(arch: &Self::Architecture) -> SupportedWmmaCombinations {
+        // Reference: https://gpuopen.com/learn/amd-lab-notes/amd-lab-notes-matrix-cores-readme/
+        let mut result: SupportedWmmaCombinations = vec![];
+        if arch.is_mfma_capable() {
+            result.push((
+                gpu::Elem::Float(gpu::FloatKind::F16), // i
+                gpu::Elem::Float(gpu::FloatKind::F16), // o
+                gpu::Elem::Float(gpu::FloatKind::F32), // c
+                //    m   n   k
+                vec![(16, 16, 16), (32, 32, 8)],
+            ));
+        }
+        result
+    }
+}
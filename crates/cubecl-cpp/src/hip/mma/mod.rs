@@ -2,3 +2,5 @@ pub mod rocwmma_compiler;
 pub use rocwmma_compiler::*;
 pub mod wmma_intrinsics_compiler;
 pub use wmma_intrinsics_compiler::*;
+pub mod mfma_intrinsics_compiler;
+pub use mfma_intrinsics_compiler::*;
@@ -1,6 +1,8 @@
 use core::panic;
 use std::fmt::Display;
 
+use cubecl_core::CompilationTarget;
+
 use crate::{
     Dialect,
     shared::{
@@ -27,7 +29,9 @@ pub struct MslDialect {}
 
 // Base dialect
 
-impl Dialect for MslDialect {}
+impl Dialect for MslDialect {
+    const TARGET: CompilationTarget = CompilationTarget::Metal;
+}
 
 // Includes
 
@@ -672,6 +676,21 @@ impl DialectInstructions<Self> for MslDialect {
         }
     }
 
+    // fast math
+    fn compile_instruction_recip_fast_scalar<T: Component<Self>>(
+        f: &mut std::fmt::Formatter<'_>,
+        input: T,
+    ) -> std::fmt::Result {
+        write!(f, "fast::divide(1.0f, {input})")
+    }
+
+    fn compile_instruction_rsqrt_fast_scalar<T: Component<Self>>(
+        f: &mut std::fmt::Formatter<'_>,
+        input: T,
+    ) -> std::fmt::Result {
+        write!(f, "fast::rsqrt({input})")
+    }
+
     // sync
     fn compile_instruction_sync_threads(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "threadgroup_barrier(mem_flags::mem_threadgroup);")
@@ -681,6 +700,13 @@ impl DialectInstructions<Self> for MslDialect {
         writeln!(f, "threadgroup_thread_fence(mem_flags::mem_device);")
     }
 
+    fn compile_instruction_thread_fence_block(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "atomic_thread_fence(mem_flags::mem_threadgroup | mem_flags::mem_device, memory_order_seq_cst, thread_scope_threadgroup);"
+        )
+    }
+
     // trigo
     fn compile_instruction_tanh_scalar<T: Component<Self>>(
         f: &mut std::fmt::Formatter<'_>,
@@ -712,6 +738,31 @@ impl DialectInstructions<Self> for MslDialect {
         write!(f, "{out_elem}(clz({input}))")
     }
 
+    fn compile_instruction_trailing_zeros_scalar<T: Component<Self>>(
+        f: &mut std::fmt::Formatter<'_>,
+        input: T,
+        out_elem: Elem<Self>,
+    ) -> std::fmt::Result {
+        write!(f, "{out_elem}(ctz({input}))")
+    }
+
+    fn compile_instruction_cast_rounded_scalar<T: Component<Self>>(
+        f: &mut std::fmt::Formatter<'_>,
+        input: T,
+        out_elem: Elem<Self>,
+        nearest: bool,
+    ) -> std::fmt::Result {
+        // Conversions to half round to nearest even, rounding toward zero steps the magnitude back
+        // when it was rounded up.
+        match (input.elem(), out_elem, nearest) {
+            (Elem::F32, Elem::F16 | Elem::BF16, false) => write!(
+                f,
+                "(abs(float({out_elem}({input}))) > abs({input}) ? as_type<{out_elem}>(ushort(as_type<ushort>({out_elem}({input})) - 1)) : {out_elem}({input}))"
+            ),
+            _ => write!(f, "{out_elem}({input})"),
+        }
+    }
+
     fn compile_instruction_popcount_scalar<T: Component<Self>>(
         f: &mut std::fmt::Formatter<'_>,
         input: T,
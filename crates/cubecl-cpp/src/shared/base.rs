@@ -4,7 +4,7 @@ use cubecl_common::ExecutionMode;
 use cubecl_core::ir::{ExpandElement, UIntKind, VariableKind};
 use cubecl_core::prelude::{FloatExpand, Line};
 use cubecl_core::{
-    Compiler, Feature,
+    CompilationError, CompilationTarget, Compiler, Feature,
     ir::{self as gpu},
 };
 use cubecl_core::{CubeDim, io::read_tensor_checked};
@@ -30,6 +30,13 @@ pub struct CompilationOptions {
     pub warp_size: u32,
     pub grid_constants: bool,
     pub supports_clusters: bool,
+    /// Maximum amount of shared memory per cube in bytes, not validated when `None`.
+    pub max_shared_memory_size: Option<usize>,
+    /// Maximum amount of shared memory per cube that can be declared statically in bytes. Kernels
+    /// using more allocate it dynamically at launch instead, always static when `None`.
+    pub max_static_shared_memory_size: Option<usize>,
+    /// Maximum number of bindings per kernel, not validated when `None`.
+    pub max_bindings: Option<u32>,
 }
 
 impl Default for CompilationOptions {
@@ -38,6 +45,9 @@ impl Default for CompilationOptions {
             warp_size: 32,
             grid_constants: false,
             supports_clusters: false,
+            max_shared_memory_size: None,
+            max_static_shared_memory_size: None,
+            max_bindings: None,
         }
     }
 }
@@ -72,6 +82,8 @@ pub struct Flags {
     pub op_barrier: bool,
     pub op_pipeline: bool,
     pub inst_fast_math: bool,
+    /// Whether the kernel was built with debug symbols, to compile it for device debugging.
+    pub debug_symbols: bool,
     pub inst_tma: bool,
     pub inst_tma_im2col: bool,
     pub inst_wmma: bool,
@@ -79,6 +91,8 @@ pub struct Flags {
     pub static_meta_length: usize,
     pub has_dynamic_meta: bool,
     pub cluster_dim: Option<CubeDim>,
+    /// Largest partition size used by a partitioned plane operation, `0` when there are none.
+    pub max_plane_partition_size: u32,
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -109,18 +123,24 @@ impl<D: Dialect> Compiler for CppCompiler<D> {
         mut kernel: KernelDefinition,
         compilation_options: &Self::CompilationOptions,
         strategy: ExecutionMode,
-    ) -> Self::Representation {
+    ) -> Result<Self::Representation, CompilationError> {
         self.compilation_options = compilation_options.clone();
         self.strategy = strategy;
 
-        if !self.compilation_options.supports_clusters {
+        if !self.compilation_options.supports_clusters && kernel.options.cluster_dim.is_some() {
+            log::warn!(
+                "Kernel {} requests a cluster dim, but the target doesn't support clusters. \
+                 Ignoring it, each cube is its own cluster.",
+                kernel.options.kernel_name
+            );
             kernel.options.cluster_dim = None;
         }
         self.cluster_dim = kernel.options.cluster_dim.unwrap_or(CubeDim::new_single());
 
         let ir = self.clone().compile_ir(kernel);
         COUNTER_TMP_VAR.store(0, std::sync::atomic::Ordering::Relaxed);
-        ir
+        self.validate_limits(&ir)?;
+        Ok(ir)
     }
 
     fn elem_size(&self, elem: gpu::Elem) -> usize {
@@ -130,9 +150,45 @@ impl<D: Dialect> Compiler for CppCompiler<D> {
     fn extension(&self) -> &'static str {
         "cpp"
     }
+
+    fn target(&self) -> CompilationTarget {
+        D::TARGET
+    }
 }
 
 impl<D: Dialect> CppCompiler<D> {
+    fn validate_limits(&self, kernel: &ComputeKernel<D>) -> Result<(), CompilationError> {
+        if let Some(limit) = self.compilation_options.max_shared_memory_size {
+            let requested = kernel.shared_memory_size();
+            if requested > limit {
+                return Err(CompilationError::SharedMemoryExceeded { requested, limit });
+            }
+        }
+
+        if let Some(limit) = self.compilation_options.max_bindings {
+            let requested = (kernel.buffers.len() + kernel.tensor_maps.len()) as u32;
+            if requested > limit {
+                return Err(CompilationError::TooManyBindings { requested, limit });
+            }
+        }
+
+        let partition_size = kernel.flags.max_plane_partition_size;
+        let plane_size = self.compilation_options.warp_size;
+        if partition_size > plane_size {
+            return Err(CompilationError::InvalidPlanePartition {
+                partition_size,
+                plane_size,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn register_plane_partition(&mut self, partition_size: u32) {
+        self.flags.max_plane_partition_size =
+            self.flags.max_plane_partition_size.max(partition_size);
+    }
+
     fn compile_ir(mut self, mut value: KernelDefinition) -> ComputeKernel<D> {
         self.build_metadata(&value);
 
@@ -160,6 +216,7 @@ impl<D: Dialect> CppCompiler<D> {
                 .options
                 .fp_math_mode
                 .contains(FastMath::ReducedPrecision),
+            debug_symbols: value.options.debug_symbols,
             inst_tma: self.flags.inst_tma,
             inst_tma_im2col: self.flags.inst_tma_im2col,
             use_grid_constants: self.compilation_options.grid_constants,
@@ -168,16 +225,21 @@ impl<D: Dialect> CppCompiler<D> {
             has_dynamic_meta: self.metadata.static_len() > 0,
             static_meta_length: self.metadata.static_len() as usize,
             cluster_dim: value.options.cluster_dim,
+            max_plane_partition_size: self.flags.max_plane_partition_size,
         };
 
-        let body = Body {
+        let mut body = Body {
             instructions,
             shared_memories: self.shared_memories,
+            dynamic_shared_memory: false,
             pipelines: self.pipelines,
             barriers: self.barriers,
             const_arrays: self.const_arrays,
             local_arrays: self.local_arrays,
         };
+        if let Some(limit) = self.compilation_options.max_static_shared_memory_size {
+            body.dynamic_shared_memory = body.shared_memory_size() > limit;
+        }
 
         let mut cluster_dim = value.options.cluster_dim;
         if !self.compilation_options.supports_clusters {
@@ -196,6 +258,7 @@ impl<D: Dialect> CppCompiler<D> {
             items: self.items,
             kernel_name: value.options.kernel_name,
             cluster_dim,
+            shared_memory_carveout: value.options.shared_memory_carveout,
         }
     }
 
@@ -294,6 +357,40 @@ impl<D: Dialect> CppCompiler<D> {
                     self.flags.inst_tma = true;
                     instructions.push(Instruction::ProxySharedFence)
                 }
+                gpu::Synchronization::UniformLoad(op) => {
+                    instructions.push(Instruction::SyncThreads);
+                    instructions.push(Instruction::Index(self.compile_binary(op, out.unwrap())));
+                    instructions.push(Instruction::SyncThreads);
+                }
+                // CUDA fences are sequentially consistent, so the order is always satisfied.
+                gpu::Synchronization::MemoryFence { scope, .. } => match scope {
+                    gpu::MemoryScope::Cube => instructions.push(Instruction::ThreadFenceBlock),
+                    gpu::MemoryScope::Device => instructions.push(Instruction::ThreadFence),
+                },
+                gpu::Synchronization::SyncCluster if self.compilation_options.supports_clusters => {
+                    self.flags.indexes.cluster_pos = true;
+                    instructions.push(Instruction::SyncCluster)
+                }
+                gpu::Synchronization::ClusterLoad(op)
+                    if self.compilation_options.supports_clusters =>
+                {
+                    self.flags.indexes.cluster_pos = true;
+                    instructions.push(Instruction::ClusterLoad {
+                        shared: self.compile_variable(op.shared),
+                        rank: self.compile_variable(op.rank),
+                        index: self.compile_variable(op.index),
+                        out: self.compile_variable(out.unwrap()),
+                    })
+                }
+                // Fallback if clusters aren't supported, each cube is its own cluster.
+                gpu::Synchronization::SyncCluster => instructions.push(Instruction::SyncThreads),
+                gpu::Synchronization::ClusterLoad(op) => {
+                    instructions.push(Instruction::Index(BinaryInstruction {
+                        lhs: self.compile_variable(op.shared),
+                        rhs: self.compile_variable(op.index),
+                        out: self.compile_variable(out.unwrap()),
+                    }))
+                }
             },
             gpu::Operation::Plane(op) => {
                 self.flags.indexes.plane_dim_checked = true;
@@ -303,6 +400,7 @@ impl<D: Dialect> CppCompiler<D> {
                         let instruction = WarpInstruction::ReduceSum {
                             input: self.compile_variable(op.input),
                             out,
+                            partition_size: None,
                         };
                         D::register_warp_instruction_extension(&mut self.extensions, &instruction);
                         instructions.push(Instruction::Warp(instruction));
@@ -312,6 +410,7 @@ impl<D: Dialect> CppCompiler<D> {
                         instructions.push(Instruction::Warp(WarpInstruction::InclusiveSum {
                             input: self.compile_variable(op.input),
                             out,
+                            partition_size: None,
                         }))
                     }
                     gpu::Plane::InclusiveProd(op) => {
@@ -319,6 +418,7 @@ impl<D: Dialect> CppCompiler<D> {
                         instructions.push(Instruction::Warp(WarpInstruction::InclusiveProd {
                             input: self.compile_variable(op.input),
                             out,
+                            partition_size: None,
                         }))
                     }
                     gpu::Plane::ExclusiveSum(op) => {
@@ -326,6 +426,7 @@ impl<D: Dialect> CppCompiler<D> {
                         instructions.push(Instruction::Warp(WarpInstruction::ExclusiveSum {
                             input: self.compile_variable(op.input),
                             out,
+                            partition_size: None,
                         }))
                     }
                     gpu::Plane::ExclusiveProd(op) => {
@@ -333,12 +434,14 @@ impl<D: Dialect> CppCompiler<D> {
                         instructions.push(Instruction::Warp(WarpInstruction::ExclusiveProd {
                             input: self.compile_variable(op.input),
                             out,
+                            partition_size: None,
                         }))
                     }
                     gpu::Plane::Prod(op) => {
                         let instruction = WarpInstruction::ReduceProd {
                             input: self.compile_variable(op.input),
                             out,
+                            partition_size: None,
                         };
                         D::register_warp_instruction_extension(&mut self.extensions, &instruction);
                         instructions.push(Instruction::Warp(instruction))
@@ -347,6 +450,7 @@ impl<D: Dialect> CppCompiler<D> {
                         let instruction = WarpInstruction::ReduceMax {
                             input: self.compile_variable(op.input),
                             out,
+                            partition_size: None,
                         };
                         D::register_warp_instruction_extension(&mut self.extensions, &instruction);
                         instructions.push(Instruction::Warp(instruction))
@@ -355,10 +459,87 @@ impl<D: Dialect> CppCompiler<D> {
                         let instruction = WarpInstruction::ReduceMin {
                             input: self.compile_variable(op.input),
                             out,
+                            partition_size: None,
+                        };
+                        D::register_warp_instruction_extension(&mut self.extensions, &instruction);
+                        instructions.push(Instruction::Warp(instruction))
+                    }
+                    gpu::Plane::PartitionSum(op) => {
+                        self.register_plane_partition(op.partition_size);
+                        let instruction = WarpInstruction::ReduceSum {
+                            input: self.compile_variable(op.input),
+                            out,
+                            partition_size: Some(op.partition_size),
+                        };
+                        D::register_warp_instruction_extension(&mut self.extensions, &instruction);
+                        instructions.push(Instruction::Warp(instruction))
+                    }
+                    gpu::Plane::PartitionProd(op) => {
+                        self.register_plane_partition(op.partition_size);
+                        let instruction = WarpInstruction::ReduceProd {
+                            input: self.compile_variable(op.input),
+                            out,
+                            partition_size: Some(op.partition_size),
+                        };
+                        D::register_warp_instruction_extension(&mut self.extensions, &instruction);
+                        instructions.push(Instruction::Warp(instruction))
+                    }
+                    gpu::Plane::PartitionMin(op) => {
+                        self.register_plane_partition(op.partition_size);
+                        let instruction = WarpInstruction::ReduceMin {
+                            input: self.compile_variable(op.input),
+                            out,
+                            partition_size: Some(op.partition_size),
+                        };
+                        D::register_warp_instruction_extension(&mut self.extensions, &instruction);
+                        instructions.push(Instruction::Warp(instruction))
+                    }
+                    gpu::Plane::PartitionMax(op) => {
+                        self.register_plane_partition(op.partition_size);
+                        let instruction = WarpInstruction::ReduceMax {
+                            input: self.compile_variable(op.input),
+                            out,
+                            partition_size: Some(op.partition_size),
                         };
                         D::register_warp_instruction_extension(&mut self.extensions, &instruction);
                         instructions.push(Instruction::Warp(instruction))
                     }
+                    gpu::Plane::PartitionInclusiveSum(op) => {
+                        self.register_plane_partition(op.partition_size);
+                        self.flags.indexes.unit_pos_plane = true;
+                        instructions.push(Instruction::Warp(WarpInstruction::InclusiveSum {
+                            input: self.compile_variable(op.input),
+                            out,
+                            partition_size: Some(op.partition_size),
+                        }))
+                    }
+                    gpu::Plane::PartitionInclusiveProd(op) => {
+                        self.register_plane_partition(op.partition_size);
+                        self.flags.indexes.unit_pos_plane = true;
+                        instructions.push(Instruction::Warp(WarpInstruction::InclusiveProd {
+                            input: self.compile_variable(op.input),
+                            out,
+                            partition_size: Some(op.partition_size),
+                        }))
+                    }
+                    gpu::Plane::PartitionExclusiveSum(op) => {
+                        self.register_plane_partition(op.partition_size);
+                        self.flags.indexes.unit_pos_plane = true;
+                        instructions.push(Instruction::Warp(WarpInstruction::ExclusiveSum {
+                            input: self.compile_variable(op.input),
+                            out,
+                            partition_size: Some(op.partition_size),
+                        }))
+                    }
+                    gpu::Plane::PartitionExclusiveProd(op) => {
+                        self.register_plane_partition(op.partition_size);
+                        self.flags.indexes.unit_pos_plane = true;
+                        instructions.push(Instruction::Warp(WarpInstruction::ExclusiveProd {
+                            input: self.compile_variable(op.input),
+                            out,
+                            partition_size: Some(op.partition_size),
+                        }))
+                    }
                     gpu::Plane::Elect => {
                         instructions.push(Instruction::Warp(WarpInstruction::Elect { out }))
                     }
@@ -387,6 +568,20 @@ impl<D: Dialect> CppCompiler<D> {
                             out,
                         }))
                     }
+                    gpu::Plane::ShuffleUp(op) => {
+                        instructions.push(Instruction::Warp(WarpInstruction::ShuffleUp {
+                            input: self.compile_variable(op.lhs),
+                            delta: self.compile_variable(op.rhs),
+                            out,
+                        }))
+                    }
+                    gpu::Plane::ShuffleDown(op) => {
+                        instructions.push(Instruction::Warp(WarpInstruction::ShuffleDown {
+                            input: self.compile_variable(op.lhs),
+                            delta: self.compile_variable(op.rhs),
+                            out,
+                        }))
+                    }
                 }
             }
             gpu::Operation::CoopMma(cmma) => instructions.push(self.compile_cmma(cmma, out)),
@@ -404,6 +599,13 @@ impl<D: Dialect> CppCompiler<D> {
                 gpu::NonSemantic::Comment { content } => {
                     instructions.push(Instruction::Comment { content })
                 }
+                gpu::NonSemantic::Prefetch { list, index, bytes } => {
+                    instructions.push(Instruction::Prefetch {
+                        input: self.compile_variable(list),
+                        index: self.compile_variable(index),
+                        bytes,
+                    })
+                }
                 // Don't need to handle scopes
                 _ => {}
             },
@@ -776,6 +978,28 @@ impl<D: Dialect> CppCompiler<D> {
             gpu::Branch::Loop(mut op) => instructions.push(Instruction::Loop {
                 instructions: self.compile_scope(&mut op.scope),
             }),
+            gpu::Branch::Call(mut call) => {
+                // A `return` in the body would only leave the lambda instead of the kernel, so the
+                // body is expanded in place instead.
+                let expand_in_place = contains_return(&call.scope);
+                let body = self.compile_scope(&mut call.scope);
+                let ret = call.ret.map(|it| self.compile_variable(it));
+                let out = call.out.map(|it| self.compile_variable(it));
+
+                if expand_in_place {
+                    instructions.extend(body);
+                    if let (Some(input), Some(out)) = (ret, out) {
+                        instructions.push(Instruction::Assign(UnaryInstruction { input, out }));
+                    }
+                } else {
+                    instructions.push(Instruction::Call {
+                        name: call.name,
+                        instructions: body,
+                        ret,
+                        out,
+                    });
+                }
+            }
         };
     }
 
@@ -924,6 +1148,12 @@ impl<D: Dialect> CppCompiler<D> {
                     out: self.compile_variable(out),
                 }))
             }
+            gpu::Arithmetic::RecipFast(op) => {
+                instructions.push(Instruction::RecipFast(self.compile_unary(op, out)))
+            }
+            gpu::Arithmetic::RsqrtFast(op) => {
+                instructions.push(Instruction::RsqrtFast(self.compile_unary(op, out)))
+            }
             gpu::Arithmetic::Round(op) => {
                 instructions.push(Instruction::Round(self.compile_unary(op, out)))
             }
@@ -1026,6 +1256,9 @@ impl<D: Dialect> CppCompiler<D> {
                 D::register_instruction_extension(&mut self.extensions, &instruction);
                 instructions.push(instruction)
             }
+            gpu::Bitwise::TrailingZeros(op) => {
+                instructions.push(Instruction::TrailingZeros(self.compile_unary(op, out)))
+            }
         };
     }
 
@@ -1158,6 +1391,12 @@ impl<D: Dialect> CppCompiler<D> {
             gpu::Operator::Cast(op) => {
                 instructions.push(Instruction::Assign(self.compile_unary(op, out)))
             }
+            gpu::Operator::CastRte(op) => {
+                instructions.push(Instruction::CastRte(self.compile_unary(op, out)))
+            }
+            gpu::Operator::CastRtz(op) => {
+                instructions.push(Instruction::CastRtz(self.compile_unary(op, out)))
+            }
             gpu::Operator::Reinterpret(op) => {
                 instructions.push(Instruction::Bitcast(self.compile_unary(op, out)))
             }
@@ -1512,6 +1751,27 @@ impl<D: Dialect> CppCompiler<D> {
     }
 }
 
+fn contains_return(scope: &gpu::Scope) -> bool {
+    scope.instructions.iter().any(|inst| match &inst.operation {
+        gpu::Operation::Branch(branch) => match branch {
+            gpu::Branch::Return => true,
+            gpu::Branch::If(op) => contains_return(&op.scope),
+            gpu::Branch::IfElse(op) => {
+                contains_return(&op.scope_if) || contains_return(&op.scope_else)
+            }
+            gpu::Branch::Switch(op) => {
+                contains_return(&op.scope_default)
+                    || op.cases.iter().any(|(_, scope)| contains_return(scope))
+            }
+            gpu::Branch::RangeLoop(op) => contains_return(&op.scope),
+            gpu::Branch::Loop(op) => contains_return(&op.scope),
+            gpu::Branch::Call(op) => contains_return(&op.scope),
+            gpu::Branch::Break => false,
+        },
+        _ => false,
+    })
+}
+
 fn const_u32<D: Dialect>(value: u32) -> Variable<D> {
     Variable::ConstantScalar(
         gpu::ConstantScalarValue::UInt(value as u64, UIntKind::U32),
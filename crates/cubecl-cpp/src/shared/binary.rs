@@ -34,12 +34,31 @@ pub trait Binary<D: Dialect> {
         Lhs: Component<D>,
         Rhs: Component<D>;
 
+    /// Whether the operation can be done on packed pairs of half precision values
+    /// (`__half2`/`__nv_bfloat162`).
+    fn supports_packed_pairs() -> bool {
+        false
+    }
+
     fn unroll_vec(
         f: &mut Formatter<'_>,
         lhs: &Variable<D>,
         rhs: &Variable<D>,
         out: &Variable<D>,
     ) -> core::fmt::Result {
+        let item_out = out.item();
+        // Even line sizes are packed through the optimized items below, odd ones are packed
+        // by pairs with the last element computed as a scalar.
+        if item_out.vectorization % 2 == 1
+            && Self::supports_packed_pairs()
+            && item_out.can_be_optimized()
+            && matches!(item_out.elem, Elem::F16 | Elem::BF16)
+            && lhs.item() == item_out
+            && rhs.item() == item_out
+        {
+            return Self::unroll_vec_packed_pairs(f, lhs, rhs, out);
+        }
+
         let optimized = Variable::optimized_args([*lhs, *rhs, *out]);
         let [lhs, rhs, out_optimized] = optimized.args;
 
@@ -82,13 +101,71 @@ pub trait Binary<D: Dialect> {
             Ok(())
         }
     }
+
+    fn unroll_vec_packed_pairs(
+        f: &mut Formatter<'_>,
+        lhs: &Variable<D>,
+        rhs: &Variable<D>,
+        out: &Variable<D>,
+    ) -> core::fmt::Result {
+        let item_out = out.item();
+        let item_pair = match item_out.elem {
+            Elem::F16 => Item::new(Elem::F162, 1, item_out.native),
+            Elem::BF16 => Item::new(Elem::BF162, 1, item_out.native),
+            _ => unreachable!("Only half precision values can be packed"),
+        };
+        let item_scalar = Item::new(item_out.elem, 1, item_out.native);
+        let last = item_out.vectorization - 1;
+        let mut elems = Vec::with_capacity(item_out.vectorization);
+
+        for i in (0..last).step_by(2) {
+            let lhs_pair = Variable::tmp(item_pair);
+            let rhs_pair = Variable::tmp(item_pair);
+            let out_pair = Variable::tmp(item_pair);
+            writeln!(
+                f,
+                "{} = {item_pair}{{{}, {}}};",
+                lhs_pair.fmt_left(),
+                lhs.index(i),
+                lhs.index(i + 1)
+            )?;
+            writeln!(
+                f,
+                "{} = {item_pair}{{{}, {}}};",
+                rhs_pair.fmt_left(),
+                rhs.index(i),
+                rhs.index(i + 1)
+            )?;
+            write!(f, "{} = ", out_pair.fmt_left())?;
+            Self::format_scalar(f, lhs_pair, rhs_pair, item_pair)?;
+            f.write_str(";\n")?;
+            elems.push(format!("{out_pair}.x"));
+            elems.push(format!("{out_pair}.y"));
+        }
+
+        let out_last = Variable::tmp(item_scalar);
+        write!(f, "{} = ", out_last.fmt_left())?;
+        Self::format_scalar(f, lhs.index(last), rhs.index(last), item_scalar)?;
+        f.write_str(";\n")?;
+        elems.push(format!("{out_last}"));
+
+        let out = out.fmt_left();
+        writeln!(f, "{out} = {item_out}{{{}}};", elems.join(", "))
+    }
 }
 
 macro_rules! operator {
     ($name:ident, $op:expr) => {
+        operator!($name, $op, false);
+    };
+    ($name:ident, $op:expr, $packed:expr) => {
         pub struct $name;
 
         impl<D: Dialect> Binary<D> for $name {
+            fn supports_packed_pairs() -> bool {
+                $packed
+            }
+
             fn format_scalar<Lhs: Display, Rhs: Display>(
                 f: &mut std::fmt::Formatter<'_>,
                 lhs: Lhs,
@@ -110,10 +187,12 @@ macro_rules! operator {
     };
 }
 
-operator!(Add, "+");
-operator!(Sub, "-");
-operator!(Div, "/");
-operator!(Mul, "*");
+// Only the arithmetic operators have packed intrinsics (`__hadd2`, `__hsub2`, `__h2div`,
+// `__hmul2`), the comparisons on pairs return pairs instead of booleans.
+operator!(Add, "+", true);
+operator!(Sub, "-", true);
+operator!(Div, "/", true);
+operator!(Mul, "*", true);
 operator!(Modulo, "%");
 operator!(Equal, "==");
 operator!(NotEqual, "!=");
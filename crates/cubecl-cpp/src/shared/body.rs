@@ -6,20 +6,77 @@ use std::fmt::Display;
 pub struct Body<D: Dialect> {
     pub instructions: Vec<Instruction<D>>,
     pub shared_memories: Vec<super::SharedMemory<D>>,
+    /// Whether the shared memories are carved out of the dynamic shared memory allocated at
+    /// launch instead of being declared statically, which is required above 48KB on CUDA.
+    pub dynamic_shared_memory: bool,
     pub pipelines: Vec<PipelineOps<D>>,
     pub barriers: Vec<BarrierOps<D>>,
     pub const_arrays: Vec<super::ConstArray<D>>,
     pub local_arrays: Vec<super::LocalArray<D>>,
 }
 
+/// Minimum alignment of the shared memories carved out of the dynamic shared memory, enough for
+/// the widest vectorized item.
+const DYNAMIC_SHARED_MEMORY_ALIGN: usize = 16;
+
+impl<D: Dialect> Body<D> {
+    /// The offset of each shared memory in bytes, followed by the total size, when they are laid out
+    /// back to back.
+    fn shared_memory_offsets(&self) -> (Vec<usize>, usize) {
+        let mut offsets = Vec::with_capacity(self.shared_memories.len());
+        let mut current = 0usize;
+
+        for shared in self.shared_memories.iter() {
+            if self.dynamic_shared_memory {
+                let align = shared.align.unwrap_or(0) as usize;
+                current = current.next_multiple_of(align.max(DYNAMIC_SHARED_MEMORY_ALIGN));
+            }
+            offsets.push(current);
+
+            let elem_size_bytes = shared.item.elem().size();
+            current += shared.size as usize * shared.item.vectorization * elem_size_bytes;
+        }
+
+        (offsets, current)
+    }
+
+    /// The amount of shared memory used by the kernel in bytes, including the padding between
+    /// dynamic shared memories.
+    pub fn shared_memory_size(&self) -> usize {
+        self.shared_memory_offsets().1
+    }
+}
+
 impl<D: Dialect> Display for Body<D> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for shared in self.shared_memories.iter() {
-            let item = &shared.item;
-            let index = &shared.index;
-            let size = &shared.size;
-            D::compile_shared_memory_qualifier(f, shared)?;
-            writeln!(f, " {item} shared_memory_{index}[{size}];",)?;
+        if self.dynamic_shared_memory {
+            let align = self
+                .shared_memories
+                .iter()
+                .filter_map(|shared| shared.align)
+                .fold(DYNAMIC_SHARED_MEMORY_ALIGN as u32, u32::max);
+            writeln!(
+                f,
+                "extern __shared__ __align__({align}) uint8 dynamic_shared_memory[];"
+            )?;
+
+            let (offsets, _) = self.shared_memory_offsets();
+            for (shared, offset) in self.shared_memories.iter().zip(offsets) {
+                let item = &shared.item;
+                let index = &shared.index;
+                writeln!(
+                    f,
+                    "{item}* shared_memory_{index} = reinterpret_cast<{item}*>(dynamic_shared_memory + {offset});"
+                )?;
+            }
+        } else {
+            for shared in self.shared_memories.iter() {
+                let item = &shared.item;
+                let index = &shared.index;
+                let size = &shared.size;
+                D::compile_shared_memory_qualifier(f, shared)?;
+                writeln!(f, " {item} shared_memory_{index}[{size}];",)?;
+            }
         }
 
         for pipeline in self.pipelines.iter() {
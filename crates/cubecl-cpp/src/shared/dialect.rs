@@ -1,7 +1,7 @@
 use std::hash::Hash;
 use std::{collections::HashSet, fmt::Debug};
 
-use cubecl_core::ir::Id;
+use cubecl_core::{CompilationTarget, ir::Id};
 
 use crate::shared::FmtLeft;
 
@@ -9,6 +9,7 @@ use super::{
     Architecture, AtomicKind, Binding, Component, CubeIndexFlags, Elem, Flags, Fragment,
     FragmentIdent, FragmentLayout, Instruction, Item, SharedMemory, SupportedWmmaCombinations,
     Variable, WarpInstruction, WmmaInstruction,
+    unary::{Assign, Unary},
 };
 
 // Base dialect
@@ -30,6 +31,8 @@ pub trait Dialect:
     + Hash
     + 'static
 {
+    /// The target the kernels of the dialect are compiled for.
+    const TARGET: CompilationTarget;
 }
 
 // Includes
@@ -488,6 +491,17 @@ pub trait DialectInstructions<D: Dialect> {
         writeln!(f, "printf(\"{format_string}\"{args});")
     }
 
+    // memory hints
+    fn compile_instruction_prefetch(
+        _f: &mut std::fmt::Formatter<'_>,
+        _input: &Variable<D>,
+        _index: &Variable<D>,
+        _bytes: u32,
+    ) -> std::fmt::Result {
+        // prefetching is only a hint, ignore it by default
+        Ok(())
+    }
+
     // logs
     fn compile_instruction_log1p_scalar<T: Component<D>>(
         f: &mut std::fmt::Formatter<'_>,
@@ -502,9 +516,24 @@ pub trait DialectInstructions<D: Dialect> {
         }
     }
 
+    // fast math
+    fn compile_instruction_recip_fast_scalar<T: Component<D>>(
+        f: &mut std::fmt::Formatter<'_>,
+        input: T,
+    ) -> std::fmt::Result {
+        write!(f, "__fdividef(1.0f, {input})")
+    }
+    fn compile_instruction_rsqrt_fast_scalar<T: Component<D>>(
+        f: &mut std::fmt::Formatter<'_>,
+        input: T,
+    ) -> std::fmt::Result {
+        write!(f, "rsqrtf({input})")
+    }
+
     // sync
     fn compile_instruction_sync_threads(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result;
     fn compile_instruction_thread_fence(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result;
+    fn compile_instruction_thread_fence_block(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result;
 
     // trigo
     fn compile_instruction_tanh_scalar<T: Component<D>>(
@@ -532,6 +561,18 @@ pub trait DialectInstructions<D: Dialect> {
         out_elem: Elem<D>,
     ) -> std::fmt::Result;
 
+    fn compile_instruction_trailing_zeros_scalar<T: Component<D>>(
+        f: &mut std::fmt::Formatter<'_>,
+        input: T,
+        out_elem: Elem<D>,
+    ) -> std::fmt::Result {
+        // The first set bit is one-based, and zero when no bit is set.
+        let bits = input.elem().size() * 8;
+        write!(f, "({input} == 0 ? {out_elem}({bits}) : ")?;
+        Self::compile_instruction_find_first_set(f, input, out_elem)?;
+        write!(f, " - 1)")
+    }
+
     fn compile_instruction_popcount_scalar<T: Component<D>>(
         f: &mut std::fmt::Formatter<'_>,
         input: T,
@@ -570,6 +611,38 @@ pub trait DialectInstructions<D: Dialect> {
     }
 
     // others
+    /// Cast with a rounding mode, to nearest with ties to even or toward zero, falling back to a
+    /// plain assignment for the conversions without a rounding intrinsic.
+    fn compile_instruction_cast_rounded_scalar<T: Component<D>>(
+        f: &mut std::fmt::Formatter<'_>,
+        input: T,
+        out_elem: Elem<D>,
+        nearest: bool,
+    ) -> std::fmt::Result {
+        let mode = if nearest { "rn" } else { "rz" };
+        match (input.elem(), out_elem) {
+            (Elem::F32, Elem::F16) => write!(f, "__float2half_{mode}({input})"),
+            (Elem::F32, Elem::BF16) => write!(f, "__float2bfloat16_{mode}({input})"),
+            (Elem::F64, Elem::F32) => write!(f, "__double2float_{mode}({input})"),
+            _ => <Assign as Unary<D>>::format_scalar(f, input, out_elem),
+        }
+    }
+
+    /// The dot product of two lines of 4 `i8` accumulated into an `i32` `out`.
+    fn compile_instruction_dot_i8x4(
+        f: &mut std::fmt::Formatter<'_>,
+        lhs: &Variable<D>,
+        rhs: &Variable<D>,
+        out: &Variable<D>,
+    ) -> std::fmt::Result {
+        // The `i8` products are promoted to `int`, so the sum doesn't overflow.
+        let muls = (0..4)
+            .map(|i| format!("{} * {}", lhs.index(i), rhs.index(i)))
+            .collect::<Vec<_>>();
+        let out = out.fmt_left();
+        writeln!(f, "{out} = {};", muls.join(" + "))
+    }
+
     fn compile_instruction_max_function_name(
         f: &mut std::fmt::Formatter<'_>,
         item: Item<D>,
@@ -82,6 +82,14 @@ pub enum Instruction<D: Dialect> {
     Loop {
         instructions: Vec<Self>,
     },
+    /// A helper that shouldn't be inlined, compiled to a lambda capturing the locals of the caller
+    /// and called right away.
+    Call {
+        name: String,
+        instructions: Vec<Self>,
+        ret: Option<Variable<D>>,
+        out: Option<Variable<D>>,
+    },
     If {
         cond: Variable<D>,
         instructions: Vec<Self>,
@@ -139,6 +147,7 @@ pub enum Instruction<D: Dialect> {
     BitwiseNot(UnaryInstruction<D>),
     LeadingZeros(UnaryInstruction<D>),
     FindFirstSet(UnaryInstruction<D>),
+    TrailingZeros(UnaryInstruction<D>),
     Abs(UnaryInstruction<D>),
     Exp(UnaryInstruction<D>),
     Log(UnaryInstruction<D>),
@@ -148,6 +157,8 @@ pub enum Instruction<D: Dialect> {
     Tanh(UnaryInstruction<D>),
     Powf(BinaryInstruction<D>),
     Sqrt(UnaryInstruction<D>),
+    RecipFast(UnaryInstruction<D>),
+    RsqrtFast(UnaryInstruction<D>),
     Min(BinaryInstruction<D>),
     Max(BinaryInstruction<D>),
     Not(UnaryInstruction<D>),
@@ -160,7 +171,15 @@ pub enum Instruction<D: Dialect> {
         out: Variable<D>,
     },
     SyncThreads,
+    SyncCluster,
+    ClusterLoad {
+        shared: Variable<D>,
+        rank: Variable<D>,
+        index: Variable<D>,
+        out: Variable<D>,
+    },
     ThreadFence,
+    ThreadFenceBlock,
     ProxySharedFence,
     BulkCommitGroup,
     BulkWaitGroup {
@@ -175,6 +194,8 @@ pub enum Instruction<D: Dialect> {
     Warp(WarpInstruction<D>),
     Wmma(WmmaInstruction<D>),
     Bitcast(UnaryInstruction<D>),
+    CastRte(UnaryInstruction<D>),
+    CastRtz(UnaryInstruction<D>),
     AtomicLoad(UnaryInstruction<D>),
     AtomicStore(UnaryInstruction<D>),
     AtomicSwap(BinaryInstruction<D>),
@@ -215,6 +236,11 @@ pub enum Instruction<D: Dialect> {
     Comment {
         content: String,
     },
+    Prefetch {
+        input: Variable<D>,
+        index: Variable<D>,
+        bytes: u32,
+    },
     Pipeline(PipelineOps<D>),
     Barrier(BarrierOps<D>),
     MemCopyAsyncTensorSharedToGlobal {
@@ -290,6 +316,7 @@ impl<D: Dialect> Display for Instruction<D> {
             Instruction::ReverseBits(it) => ReverseBits::format(f, &it.input, &it.out),
             Instruction::LeadingZeros(it) => LeadingZeros::format(f, &it.input, &it.out),
             Instruction::FindFirstSet(it) => FindFirstSet::format(f, &it.input, &it.out),
+            Instruction::TrailingZeros(it) => TrailingZeros::format(f, &it.input, &it.out),
             Instruction::ShiftLeft(it) => ShiftLeft::format(f, &it.lhs, &it.rhs, &it.out),
             Instruction::ShiftRight(it) => ShiftRight::format(f, &it.lhs, &it.rhs, &it.out),
             Instruction::Index(it) => Index::format(f, &it.lhs, &it.rhs, &it.out),
@@ -348,6 +375,29 @@ for ({i_ty} {i} = {start}; {i} {cmp} {end}; {increment}) {{
                 }
                 f.write_str("}\n")
             }
+            Instruction::Call {
+                name,
+                instructions,
+                ret,
+                out,
+            } => {
+                writeln!(f, "// call {name}")?;
+                match out {
+                    Some(out) => {
+                        let item = out.item();
+                        let out = out.fmt_left();
+                        writeln!(f, "{out} = [&]() __attribute__((noinline)) -> {item} {{")?;
+                    }
+                    None => writeln!(f, "[&]() __attribute__((noinline)) {{")?,
+                }
+                for i in instructions {
+                    write!(f, "{i}")?;
+                }
+                if let Some(ret) = ret {
+                    writeln!(f, "return {ret};")?;
+                }
+                f.write_str("}();\n")
+            }
             Instruction::If { cond, instructions } => {
                 writeln!(f, "if ({cond}) {{")?;
                 for i in instructions {
@@ -484,6 +534,8 @@ for ({i_ty} {i} = {start}; {i} {cmp} {end}; {increment}) {{
             Instruction::Tanh(it) => Tanh::format(f, &it.input, &it.out),
             Instruction::Powf(it) => Powf::format(f, &it.lhs, &it.rhs, &it.out),
             Instruction::Sqrt(it) => Sqrt::format(f, &it.input, &it.out),
+            Instruction::RecipFast(it) => RecipFast::format(f, &it.input, &it.out),
+            Instruction::RsqrtFast(it) => RsqrtFast::format(f, &it.input, &it.out),
             Instruction::Max(it) => Max::format(f, &it.lhs, &it.rhs, &it.out),
             Instruction::Min(it) => Min::format(f, &it.lhs, &it.rhs, &it.out),
             Instruction::Not(it) => Not::format(f, &it.input, &it.out),
@@ -497,7 +549,21 @@ for ({i_ty} {i} = {start}; {i} {cmp} {end}; {increment}) {{
                 out,
             } => Clamp::format(f, input, min_value, max_value, out),
             Instruction::SyncThreads => D::compile_instruction_sync_threads(f),
-            Instruction::ThreadFence => f.write_str("__threadfence();\n"),
+            Instruction::SyncCluster => writeln!(f, "cluster.sync();"),
+            Instruction::ClusterLoad {
+                shared,
+                rank,
+                index,
+                out,
+            } => {
+                let out = out.fmt_left();
+                writeln!(
+                    f,
+                    "{out} = cluster.map_shared_rank({shared}, {rank})[{index}];"
+                )
+            }
+            Instruction::ThreadFence => D::compile_instruction_thread_fence(f),
+            Instruction::ThreadFenceBlock => D::compile_instruction_thread_fence_block(f),
             Instruction::Round(it) => Round::format(f, &it.input, &it.out),
             Instruction::Ceil(it) => Ceil::format(f, &it.input, &it.out),
             Instruction::Floor(it) => Floor::format(f, &it.input, &it.out),
@@ -512,6 +578,8 @@ for ({i_ty} {i} = {start}; {i} {cmp} {end}; {increment}) {{
             Instruction::Warp(it) => write!(f, "{it}"),
             Instruction::Fma { a, b, c, out } => Fma::format(f, a, b, c, out),
             Instruction::Wmma(it) => write!(f, "{it}"),
+            Instruction::CastRte(it) => CastRte::format(f, &it.input, &it.out),
+            Instruction::CastRtz(it) => CastRtz::format(f, &it.input, &it.out),
             Instruction::Bitcast(UnaryInstruction { input, out }) => {
                 let qualifier = out.const_qualifier();
                 let input_item = input.item();
@@ -594,6 +662,11 @@ for ({i_ty} {i} = {start}; {i} {cmp} {end}; {increment}) {{
                     writeln!(f, "// {content}")
                 }
             }
+            Instruction::Prefetch {
+                input,
+                index,
+                bytes,
+            } => D::compile_instruction_prefetch(f, input, index, *bytes),
             Instruction::Pipeline(pipeline_ops) => write!(f, "{pipeline_ops}"),
             Instruction::Barrier(barrier_ops) => write!(f, "{barrier_ops}"),
             Instruction::Line { file, line } => writeln!(f, "#line {line} \"{file}\""),
@@ -874,6 +947,9 @@ impl<D: Dialect> Dot<D> {
         out: &Variable<D>,
     ) -> core::fmt::Result {
         let num = lhs.item().vectorization;
+        if num == 4 && lhs.elem() == Elem::I8 && out.elem() == Elem::I32 {
+            return D::compile_instruction_dot_i8x4(f, lhs, rhs, out);
+        }
 
         let muls = (0..num)
             .map(|i| {
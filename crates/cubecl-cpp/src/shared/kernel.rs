@@ -67,6 +67,8 @@ pub struct ComputeKernel<D: Dialect> {
     pub body: Body<D>,
     pub cube_dim: CubeDim,
     pub cluster_dim: Option<CubeDim>,
+    /// Preferred percentage of the unified L1 and shared memory to use as shared memory.
+    pub shared_memory_carveout: Option<u32>,
     pub extensions: Vec<D::Extension>,
     pub flags: Flags,
     pub items: HashSet<super::Item<D>>,
@@ -75,15 +77,15 @@ pub struct ComputeKernel<D: Dialect> {
 
 impl<D: Dialect> ComputeKernel<D> {
     pub fn shared_memory_size(&self) -> usize {
-        let mut current = 0usize;
+        self.body.shared_memory_size()
+    }
 
-        for var in self.body.shared_memories.iter() {
-            let factor = var.item.vectorization;
-            let elem_size_bytes = var.item.elem().size();
-            current += (var.size as usize) * factor * elem_size_bytes;
+    /// The amount of shared memory to allocate dynamically when launching the kernel, in bytes.
+    pub fn dynamic_shared_memory_size(&self) -> usize {
+        match self.body.dynamic_shared_memory {
+            true => self.body.shared_memory_size(),
+            false => 0,
         }
-
-        current
     }
 }
 
@@ -1,4 +1,4 @@
-use super::{Component, Dialect, Elem, FmtLeft, Variable};
+use super::{Component, Dialect, Elem, FmtLeft, Item, Variable};
 use std::fmt::Display;
 
 pub trait Unary<D: Dialect> {
@@ -176,6 +176,38 @@ impl<D: Dialect> Unary<D> for Log1p {
     }
 }
 
+pub struct RecipFast;
+
+impl<D: Dialect> Unary<D> for RecipFast {
+    fn format_scalar<Input: Component<D>>(
+        f: &mut std::fmt::Formatter<'_>,
+        input: Input,
+        _out_elem: Elem<D>,
+    ) -> std::fmt::Result {
+        D::compile_instruction_recip_fast_scalar(f, input)
+    }
+
+    fn can_optimize() -> bool {
+        false
+    }
+}
+
+pub struct RsqrtFast;
+
+impl<D: Dialect> Unary<D> for RsqrtFast {
+    fn format_scalar<Input: Component<D>>(
+        f: &mut std::fmt::Formatter<'_>,
+        input: Input,
+        _out_elem: Elem<D>,
+    ) -> std::fmt::Result {
+        D::compile_instruction_rsqrt_fast_scalar(f, input)
+    }
+
+    fn can_optimize() -> bool {
+        false
+    }
+}
+
 pub struct Tanh;
 
 impl<D: Dialect> Unary<D> for Tanh {
@@ -250,6 +282,18 @@ impl<D: Dialect> Unary<D> for FindFirstSet {
     }
 }
 
+pub struct TrailingZeros;
+
+impl<D: Dialect> Unary<D> for TrailingZeros {
+    fn format_scalar<Input: Component<D>>(
+        f: &mut std::fmt::Formatter<'_>,
+        input: Input,
+        out_elem: Elem<D>,
+    ) -> std::fmt::Result {
+        D::compile_instruction_trailing_zeros_scalar(f, input, out_elem)
+    }
+}
+
 pub struct BitwiseNot;
 
 impl<D: Dialect> Unary<D> for BitwiseNot {
@@ -280,6 +324,40 @@ impl<D: Dialect> Unary<D> for Not {
     }
 }
 
+/// Cast rounding to the nearest representable value, with ties to even.
+pub struct CastRte;
+
+impl<D: Dialect> Unary<D> for CastRte {
+    fn format_scalar<Input: Component<D>>(
+        f: &mut std::fmt::Formatter<'_>,
+        input: Input,
+        out_elem: Elem<D>,
+    ) -> std::fmt::Result {
+        D::compile_instruction_cast_rounded_scalar(f, input, out_elem, true)
+    }
+
+    fn can_optimize() -> bool {
+        false
+    }
+}
+
+/// Cast rounding toward zero.
+pub struct CastRtz;
+
+impl<D: Dialect> Unary<D> for CastRtz {
+    fn format_scalar<Input: Component<D>>(
+        f: &mut std::fmt::Formatter<'_>,
+        input: Input,
+        out_elem: Elem<D>,
+    ) -> std::fmt::Result {
+        D::compile_instruction_cast_rounded_scalar(f, input, out_elem, false)
+    }
+
+    fn can_optimize() -> bool {
+        false
+    }
+}
+
 pub struct Assign;
 
 impl<D: Dialect> Unary<D> for Assign {
@@ -294,6 +372,8 @@ impl<D: Dialect> Unary<D> for Assign {
             write!(f, "{} = ", out.fmt_left())?;
             Self::format_scalar(f, *input, item.elem)?;
             f.write_str(";\n")
+        } else if Self::is_packed_half_cast(input, out) {
+            Self::format_packed_half_cast(f, input, out)
         } else {
             Self::unroll_vec(f, input, out, item.elem, item.vectorization)
         }
@@ -318,3 +398,62 @@ impl<D: Dialect> Unary<D> for Assign {
         }
     }
 }
+
+impl Assign {
+    /// Whether the cast is a conversion between `f32` and `f16` lines of the same size that can
+    /// use the packed `half2` intrinsics.
+    fn is_packed_half_cast<D: Dialect>(input: &Variable<D>, out: &Variable<D>) -> bool {
+        let (input_item, out_item) = (input.item(), out.item());
+
+        D::item_can_be_optimized()
+            && input_item.vectorization == out_item.vectorization
+            && matches!(
+                (input_item.elem, out_item.elem),
+                (Elem::F32, Elem::F16) | (Elem::F16, Elem::F32)
+            )
+    }
+
+    /// Convert pairs of elements with a single packed conversion, the last element of lines
+    /// with an odd size is converted alone.
+    fn format_packed_half_cast<D: Dialect>(
+        f: &mut std::fmt::Formatter<'_>,
+        input: &Variable<D>,
+        out: &Variable<D>,
+    ) -> std::fmt::Result {
+        let item = out.item();
+        let to_half = item.elem == Elem::F16;
+        let mut lanes = Vec::with_capacity(item.vectorization);
+
+        for pair in 0..item.vectorization / 2 {
+            let low = input.index(2 * pair);
+            let high = input.index(2 * pair + 1);
+            let packed = Variable::<D>::tmp(Item::scalar(Elem::F162, true));
+
+            if to_half {
+                writeln!(
+                    f,
+                    "const __half2 {packed} = __float22half2_rn(make_float2({low}, {high}));"
+                )?;
+                lanes.push(format!("__low2half({packed})"));
+                lanes.push(format!("__high2half({packed})"));
+            } else {
+                writeln!(
+                    f,
+                    "const float2 {packed} = __half22float2(__halves2half2({low}, {high}));"
+                )?;
+                lanes.push(format!("{packed}.x"));
+                lanes.push(format!("{packed}.y"));
+            }
+        }
+
+        if item.vectorization % 2 == 1 {
+            let last = input.index(item.vectorization - 1);
+            match to_half {
+                true => lanes.push(format!("__float2half_rn({last})")),
+                false => lanes.push(format!("__half2float({last})")),
+            }
+        }
+
+        writeln!(f, "{} = {item}{{{}}};", out.fmt_left(), lanes.join(","))
+    }
+}
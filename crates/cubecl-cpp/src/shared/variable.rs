@@ -215,6 +215,14 @@ impl<D: Dialect> Display for Variable<D> {
                     gpu::IntKind::I32 => write!(f, "{elem}({})", *val as i32),
                     gpu::IntKind::I64 => write!(f, "{elem}({})", *val),
                 },
+                // Infinities and NaN have no literal, so build them from the compiler builtins.
+                ConstantScalarValue::Float(val, _) if val.is_nan() => {
+                    write!(f, "{elem}(__builtin_nanf(\"\"))")
+                }
+                ConstantScalarValue::Float(val, _) if val.is_infinite() => {
+                    let sign = if val.is_sign_negative() { "-" } else { "" };
+                    write!(f, "{elem}({sign}__builtin_huge_valf())")
+                }
                 ConstantScalarValue::Float(val, kind) => match kind {
                     gpu::FloatKind::F16 => {
                         write!(f, "{elem}({:?})", half::f16::from_f64(*val))
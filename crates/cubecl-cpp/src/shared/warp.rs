@@ -9,34 +9,46 @@ pub enum WarpInstruction<D: Dialect> {
     ReduceSum {
         input: Variable<D>,
         out: Variable<D>,
+        /// Restrict the reduction to aligned partitions of this many units, `None` reduces the
+        /// whole plane.
+        partition_size: Option<u32>,
     },
     InclusiveSum {
         input: Variable<D>,
         out: Variable<D>,
+        /// Restrict the scan to aligned partitions of this many units, `None` scans the whole
+        /// plane.
+        partition_size: Option<u32>,
     },
     ExclusiveSum {
         input: Variable<D>,
         out: Variable<D>,
+        partition_size: Option<u32>,
     },
     ReduceProd {
         input: Variable<D>,
         out: Variable<D>,
+        partition_size: Option<u32>,
     },
     InclusiveProd {
         input: Variable<D>,
         out: Variable<D>,
+        partition_size: Option<u32>,
     },
     ExclusiveProd {
         input: Variable<D>,
         out: Variable<D>,
+        partition_size: Option<u32>,
     },
     ReduceMax {
         input: Variable<D>,
         out: Variable<D>,
+        partition_size: Option<u32>,
     },
     ReduceMin {
         input: Variable<D>,
         out: Variable<D>,
+        partition_size: Option<u32>,
     },
     Elect {
         out: Variable<D>,
@@ -58,19 +70,53 @@ pub enum WarpInstruction<D: Dialect> {
         id: Variable<D>,
         out: Variable<D>,
     },
+    ShuffleUp {
+        input: Variable<D>,
+        delta: Variable<D>,
+        out: Variable<D>,
+    },
+    ShuffleDown {
+        input: Variable<D>,
+        delta: Variable<D>,
+        out: Variable<D>,
+    },
 }
 
 impl<D: Dialect> Display for WarpInstruction<D> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            WarpInstruction::ReduceSum { input, out } => reduce_operator(f, input, out, "+="),
-            WarpInstruction::ReduceProd { input, out } => reduce_operator(f, input, out, "*="),
-            WarpInstruction::ReduceMax { input, out } => {
-                reduce_comparison(f, input, out, D::compile_instruction_max_function_name)
-            }
-            WarpInstruction::ReduceMin { input, out } => {
-                reduce_comparison(f, input, out, D::compile_instruction_min_function_name)
-            }
+            WarpInstruction::ReduceSum {
+                input,
+                out,
+                partition_size,
+            } => reduce_operator(f, input, out, *partition_size, "+="),
+            WarpInstruction::ReduceProd {
+                input,
+                out,
+                partition_size,
+            } => reduce_operator(f, input, out, *partition_size, "*="),
+            WarpInstruction::ReduceMax {
+                input,
+                out,
+                partition_size,
+            } => reduce_comparison(
+                f,
+                input,
+                out,
+                *partition_size,
+                D::compile_instruction_max_function_name,
+            ),
+            WarpInstruction::ReduceMin {
+                input,
+                out,
+                partition_size,
+            } => reduce_comparison(
+                f,
+                input,
+                out,
+                *partition_size,
+                D::compile_instruction_min_function_name,
+            ),
             WarpInstruction::All { input, out } => {
                 reduce_quantifier(f, input, out, D::compile_warp_all::<IndexedVariable<D>>)
             }
@@ -93,6 +139,14 @@ impl<D: Dialect> Display for WarpInstruction<D> {
                 writeln!(f, ", 0, 0, 0 }};")
             }
             WarpInstruction::Broadcast { input, id, out } => reduce_broadcast(f, input, out, id),
+            // The warp shuffles of every dialect already keep the value of the units without a
+            // source unit.
+            WarpInstruction::ShuffleUp { input, delta, out } => {
+                shuffle_relative(f, input, out, delta, D::compile_warp_shuffle_up)
+            }
+            WarpInstruction::ShuffleDown { input, delta, out } => {
+                shuffle_relative(f, input, out, delta, D::compile_warp_shuffle_down)
+            }
             WarpInstruction::Elect { out } => write!(
                 f,
                 "
@@ -101,14 +155,26 @@ unsigned int leader = __ffs(mask) - 1;
 {out} = threadIdx.x % warpSize == leader;
             "
             ),
-            WarpInstruction::InclusiveSum { input, out } => reduce_inclusive(f, input, out, "+="),
-            WarpInstruction::InclusiveProd { input, out } => reduce_inclusive(f, input, out, "*="),
-            WarpInstruction::ExclusiveSum { input, out } => {
-                reduce_exclusive(f, input, out, "+=", "0")
-            }
-            WarpInstruction::ExclusiveProd { input, out } => {
-                reduce_exclusive(f, input, out, "*=", "1")
-            }
+            WarpInstruction::InclusiveSum {
+                input,
+                out,
+                partition_size,
+            } => reduce_inclusive(f, input, out, *partition_size, "+="),
+            WarpInstruction::InclusiveProd {
+                input,
+                out,
+                partition_size,
+            } => reduce_inclusive(f, input, out, *partition_size, "*="),
+            WarpInstruction::ExclusiveSum {
+                input,
+                out,
+                partition_size,
+            } => reduce_exclusive(f, input, out, *partition_size, "+=", "0"),
+            WarpInstruction::ExclusiveProd {
+                input,
+                out,
+                partition_size,
+            } => reduce_exclusive(f, input, out, *partition_size, "*=", "1"),
         }
     }
 }
@@ -117,12 +183,13 @@ fn reduce_operator<D: Dialect>(
     f: &mut core::fmt::Formatter<'_>,
     input: &Variable<D>,
     out: &Variable<D>,
+    partition_size: Option<u32>,
     op: &str,
 ) -> core::fmt::Result {
     let in_optimized = input.optimized();
     let acc_item = in_optimized.item();
 
-    reduce_with_loop(f, input, out, acc_item, |f, acc, index| {
+    reduce_with_loop(f, input, out, acc_item, partition_size, |f, acc, index| {
         let acc_indexed = maybe_index(acc, index);
         write!(f, "{acc_indexed} {op} ")?;
         D::compile_warp_shuffle_xor(f, &acc_indexed, acc.item().elem(), "offset")?;
@@ -137,11 +204,12 @@ fn reduce_comparison<
     f: &mut core::fmt::Formatter<'_>,
     input: &Variable<D>,
     out: &Variable<D>,
+    partition_size: Option<u32>,
     instruction: I,
 ) -> core::fmt::Result {
     let in_optimized = input.optimized();
     let acc_item = in_optimized.item();
-    reduce_with_loop(f, input, out, acc_item, |f, acc, index| {
+    reduce_with_loop(f, input, out, acc_item, partition_size, |f, acc, index| {
         let acc_indexed = maybe_index(acc, index);
         let acc_elem = acc_item.elem();
         write!(f, "        {acc_indexed} = ")?;
@@ -156,16 +224,19 @@ fn reduce_inclusive<D: Dialect>(
     f: &mut core::fmt::Formatter<'_>,
     input: &Variable<D>,
     out: &Variable<D>,
+    partition_size: Option<u32>,
     op: &str,
 ) -> core::fmt::Result {
     let in_optimized = input.optimized();
     let acc_item = in_optimized.item();
+    let lane_id = partition_lane::<D>(partition_size);
 
-    reduce_with_loop(f, input, out, acc_item, |f, acc, index| {
+    // Hillis-Steele scan, the units closer than `offset` to the start of their partition keep
+    // their value.
+    reduce_with_loop(f, input, out, acc_item, partition_size, |f, acc, index| {
         let acc_indexed = maybe_index(acc, index);
         let tmp = Variable::tmp(Item::scalar(acc_item.elem, false));
         let tmp_left = tmp.fmt_left();
-        let lane_id = Variable::<D>::UnitPosPlane;
         write!(
             f,
             "
@@ -187,6 +258,7 @@ fn reduce_exclusive<D: Dialect>(
     f: &mut core::fmt::Formatter<'_>,
     input: &Variable<D>,
     out: &Variable<D>,
+    partition_size: Option<u32>,
     op: &str,
     default: &str,
 ) -> core::fmt::Result {
@@ -194,7 +266,7 @@ fn reduce_exclusive<D: Dialect>(
     let acc_item = in_optimized.item();
 
     let inclusive = Variable::tmp(acc_item);
-    reduce_inclusive(f, input, &inclusive, op)?;
+    reduce_inclusive(f, input, &inclusive, partition_size, op)?;
     let shfl = Variable::tmp(acc_item);
     writeln!(f, "{} = {{", shfl.fmt_left())?;
     for k in 0..acc_item.vectorization {
@@ -204,7 +276,7 @@ fn reduce_exclusive<D: Dialect>(
         D::compile_warp_shuffle_up(f, &inclusive_indexed.to_string(), "1")?;
     }
     writeln!(f, "}};")?;
-    let lane_id = Variable::<D>::UnitPosPlane;
+    let lane_id = partition_lane::<D>(partition_size);
 
     write!(
         f,
@@ -218,6 +290,15 @@ fn reduce_exclusive<D: Dialect>(
     writeln!(f, "}} : {};", cast(&shfl, out.item()))
 }
 
+/// Position of the unit within its partition, or within the plane without partitions.
+fn partition_lane<D: Dialect>(partition_size: Option<u32>) -> String {
+    let lane_id = Variable::<D>::UnitPosPlane;
+    match partition_size {
+        Some(partition_size) => format!("({lane_id} % {partition_size})"),
+        None => format!("{lane_id}"),
+    }
+}
+
 fn reduce_broadcast<D: Dialect>(
     f: &mut core::fmt::Formatter<'_>,
     input: &Variable<D>,
@@ -234,6 +315,23 @@ fn reduce_broadcast<D: Dialect>(
     writeln!(f, " }};")
 }
 
+fn shuffle_relative<D: Dialect>(
+    f: &mut core::fmt::Formatter<'_>,
+    input: &Variable<D>,
+    out: &Variable<D>,
+    delta: &Variable<D>,
+    shuffle: fn(&mut core::fmt::Formatter<'_>, &str, &str) -> core::fmt::Result,
+) -> core::fmt::Result {
+    let out_fmt = out.fmt_left();
+    write!(f, "{out_fmt} = {{ ")?;
+    for i in 0..input.item().vectorization {
+        let comma = if i > 0 { ", " } else { "" };
+        write!(f, "{comma}")?;
+        shuffle(f, &format!("{}", input.index(i)), &format!("{delta}"))?;
+    }
+    writeln!(f, " }};")
+}
+
 fn reduce_with_loop<
     D: Dialect,
     I: Fn(&mut core::fmt::Formatter<'_>, &Variable<D>, usize) -> std::fmt::Result,
@@ -242,6 +340,7 @@ fn reduce_with_loop<
     input: &Variable<D>,
     out: &Variable<D>,
     acc_item: Item<D>,
+    partition_size: Option<u32>,
     instruction: I,
 ) -> core::fmt::Result {
     let acc = Variable::Named {
@@ -253,7 +352,12 @@ fn reduce_with_loop<
     writeln!(f, "auto plane_{out} = [&]() -> {} {{", out.item())?;
     writeln!(f, "    {} {} = {};", acc_item, acc, cast(input, acc_item))?;
     write!(f, "    for (uint offset = 1; offset < ")?;
-    D::compile_plane_dim_checked(f)?;
+    // Offsets below the partition size keep the xor butterfly within the partition of the unit,
+    // and the scans skip the values shuffled up from a previous partition.
+    match partition_size {
+        Some(partition_size) => write!(f, "{partition_size}")?,
+        None => D::compile_plane_dim_checked(f)?,
+    }
     writeln!(f, "; offset *=2 ) {{")?;
     for k in 0..vectorization {
         instruction(f, &acc, k)?;
@@ -89,4 +89,10 @@ impl SyncStream {
             cudarc::driver::result::stream::synchronize(self.stream).unwrap();
         }
     }
+
+    /// Blocks like [wait](SyncStream::wait), but returns the error of the stream instead of
+    /// panicking, e.g. when a kernel hit an illegal memory access.
+    pub fn try_wait(self) -> Result<(), cudarc::driver::result::DriverError> {
+        unsafe { cudarc::driver::result::stream::synchronize(self.stream) }
+    }
 }
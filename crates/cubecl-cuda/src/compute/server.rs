@@ -7,19 +7,24 @@ use cubecl_runtime::kernel_timestamps::KernelTimestamps;
 use serde::{Deserialize, Serialize};
 
 use super::fence::{Fence, SyncStream};
+use super::staging::CopyStreams;
 use super::storage::CudaStorage;
 use super::{CudaResource, uninit_vec};
 use cubecl_core::{
     Feature,
     ir::FloatKind,
-    server::{BindingWithMeta, Bindings, Handle, TensorMapBinding},
+    server::{
+        BindingWithMeta, Bindings, CompilationError, CompileWarning, DeviceError, Handle,
+        KernelResources, LaunchInfo, LaunchObserver, LaunchObserverSlot, TensorMapBinding,
+        WarningSeverity,
+    },
 };
 use cubecl_core::{KernelId, prelude::*};
 use cubecl_core::{
     compute::DebugInformation,
     ir::{Elem, IntKind, UIntKind},
 };
-use cubecl_runtime::memory_management::MemoryUsage;
+use cubecl_runtime::memory_management::{AllocationError, MemoryUsage};
 use cubecl_runtime::storage::BindingResource;
 use cubecl_runtime::{
     debug::{DebugLogger, ProfileLevel},
@@ -33,15 +38,18 @@ use cudarc::driver::sys::{
     CUDA_MEMCPY2D_st, CUctx_st, CUmemorytype, CUtensorMap, CUtensorMapDataType,
     CUtensorMapFloatOOBfill, CUtensorMapL2promotion, CUtensorMapSwizzle,
 };
-use cudarc::driver::sys::{CUfunc_st, CUtensorMapInterleave};
-use std::collections::HashMap;
+use cudarc::driver::sys::{CUfunc_st, CUfunction_attribute, CUjit_option, CUtensorMapInterleave};
+use std::collections::{HashMap, VecDeque};
 use std::future::Future;
 use std::path::PathBuf;
+use std::sync::{Arc, LazyLock, Mutex};
 use std::{ffi::CStr, os::raw::c_void};
 use std::{ffi::CString, mem::MaybeUninit};
 
 #[cfg(feature = "compilation-cache")]
 use cubecl_common::cache::{Cache, CacheOption};
+#[cfg(feature = "compilation-cache")]
+use cubecl_runtime::binary_cache::BinaryCache;
 
 #[derive(Debug)]
 pub struct CudaServer {
@@ -53,13 +61,20 @@ pub struct CudaServer {
 pub(crate) struct CudaContext {
     context: *mut CUctx_st,
     stream: cudarc::driver::sys::CUstream,
+    /// Streams overlapping the staged transfers with the kernels of `stream`.
+    copy_streams: CopyStreams,
     memory_management: MemoryManagement<CudaStorage>,
     module_names: HashMap<KernelId, CompiledKernel>,
     #[cfg(feature = "compilation-cache")]
     ptx_cache: Cache<String, PtxCacheEntry>,
+    #[cfg(feature = "compilation-cache")]
+    binary_cache: BinaryCache,
     timestamps: KernelTimestamps,
     pub(crate) arch: CudaArchitecture,
     compilation_options: CompilationOptions,
+    launch_observer: LaunchObserverSlot,
+    /// The name of the last launched kernel, to know which one faulted.
+    last_kernel: Option<&'static str>,
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
@@ -67,13 +82,26 @@ pub struct PtxCacheEntry {
     entrypoint_name: String,
     cube_dim: (u32, u32, u32),
     cluster_dim: Option<(u32, u32, u32)>,
+    #[serde(default)]
+    shared_memory: SharedMemoryConfig,
     ptx: Vec<i8>,
 }
 
+/// How the shared memory of a kernel is configured when it is loaded and launched.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+struct SharedMemoryConfig {
+    /// The amount of dynamic shared memory to allocate at launch in bytes.
+    dynamic_size: u32,
+    /// Preferred percentage of the unified L1 and shared memory to use as shared memory.
+    carveout: Option<u32>,
+}
+
 #[derive(Debug)]
 struct CompiledKernel {
     cube_dim: CubeDim,
     func: *mut CUfunc_st,
+    dynamic_shared_memory: u32,
+    warnings: Vec<CompileWarning>,
 }
 
 unsafe impl Send for CudaServer {}
@@ -286,6 +314,19 @@ impl ComputeServer for CudaServer {
         server::Handle::new(handle, None, None, size as u64)
     }
 
+    fn try_empty(&mut self, size: usize) -> Result<server::Handle, AllocationError> {
+        let ctx = self.get_context();
+        let handle = ctx.memory_management.try_reserve(size as u64, None)?;
+        Ok(server::Handle::new(handle, None, None, size as u64))
+    }
+
+    unsafe fn register_external(&mut self, ptr: u64, size: u64) -> server::Handle {
+        let ctx = self.get_context();
+        let storage = ctx.memory_management.storage().register_external(ptr, size);
+        let handle = ctx.memory_management.register_external(storage);
+        server::Handle::new(handle, None, None, size)
+    }
+
     fn empty_tensor(&mut self, shape: &[usize], elem_size: usize) -> (Handle, Vec<usize>) {
         let rank = shape.len();
         let ctx = self.get_context();
@@ -311,6 +352,110 @@ impl ComputeServer for CudaServer {
         (mem_handle, strides)
     }
 
+    fn fill(&mut self, binding: server::Binding, pattern: u32) {
+        let ctx = self.get_context();
+        let resource = find_resource(ctx, binding);
+        let size = resource.size() as usize;
+
+        unsafe {
+            let lib = cudarc::driver::sys::lib();
+            if pattern == 0 {
+                lib.cuMemsetD8Async(resource.ptr, 0, size, ctx.stream)
+                    .result()
+                    .unwrap();
+            } else {
+                assert_eq!(
+                    size % 4,
+                    0,
+                    "Fill pattern must evenly divide the buffer size"
+                );
+                lib.cuMemsetD32Async(resource.ptr, pattern, size / 4, ctx.stream)
+                    .result()
+                    .unwrap();
+            }
+        }
+    }
+
+    fn write(&mut self, binding: server::Binding, data: &[u8]) {
+        let ctx = self.get_context();
+        let resource = find_resource(ctx, binding);
+        assert_eq!(data.len(), resource.size() as usize);
+
+        // Pageable memory is staged by the driver before returning, so `data` can be released
+        // right after.
+        unsafe {
+            cudarc::driver::result::memcpy_htod_async(resource.ptr, data, ctx.stream).unwrap();
+        }
+    }
+
+    fn write_staged(&mut self, binding: server::Binding, data: &[u8]) {
+        let ctx = self.get_context();
+        let resource = find_resource(ctx, binding);
+        assert_eq!(data.len(), resource.size() as usize);
+
+        ctx.copy_streams.upload(ctx.stream, resource.ptr, data);
+    }
+
+    fn read_staged(
+        &mut self,
+        binding: server::Binding,
+    ) -> impl Future<Output = Vec<u8>> + Send + 'static {
+        let ctx = self.get_context();
+        let resource = find_resource(ctx, binding);
+
+        ctx.copy_streams
+            .download(ctx.stream, resource.ptr, resource.size() as usize)
+    }
+
+    fn compile(
+        &mut self,
+        kernel: Self::Kernel,
+        mode: ExecutionMode,
+    ) -> Result<Vec<CompileWarning>, CompilationError> {
+        let mut kernel_id = kernel.id();
+        kernel_id.mode(mode);
+
+        let (ctx, logger) = self.get_context_with_logger();
+
+        if !ctx.module_names.contains_key(&kernel_id) {
+            ctx.compile_kernel(&kernel_id, kernel, logger, mode)?;
+        }
+
+        Ok(ctx.module_names[&kernel_id].warnings.clone())
+    }
+
+    fn kernel_resources(
+        &mut self,
+        kernel: Self::Kernel,
+        mode: ExecutionMode,
+    ) -> Result<KernelResources, CompilationError> {
+        let mut kernel_id = kernel.id();
+        kernel_id.mode(mode);
+
+        let (ctx, logger) = self.get_context_with_logger();
+
+        if !ctx.module_names.contains_key(&kernel_id) {
+            ctx.compile_kernel(&kernel_id, kernel, logger, mode)?;
+        }
+
+        let func = ctx.module_names[&kernel_id].func;
+        let mut registers = 0;
+        unsafe {
+            cudarc::driver::sys::lib()
+                .cuFuncGetAttribute(
+                    &mut registers,
+                    CUfunction_attribute::CU_FUNC_ATTRIBUTE_NUM_REGS,
+                    func,
+                )
+                .result()
+                .map_err(|err| CompilationError::BackendError(format!("{err:?}")))?;
+        }
+
+        Ok(KernelResources {
+            registers_per_unit: Some(registers as u32),
+        })
+    }
+
     unsafe fn execute(
         &mut self,
         kernel: Self::Kernel,
@@ -387,12 +532,25 @@ impl ComputeServer for CudaServer {
             (Vec::new(), handles)
         };
 
+        let kernel_name = kernel.name();
         let (ctx, logger) = self.get_context_with_logger();
 
-        if !ctx.module_names.contains_key(&kernel_id) {
-            ctx.compile_kernel(&kernel_id, kernel, logger, mode);
+        let compiled = !ctx.module_names.contains_key(&kernel_id);
+        if compiled {
+            ctx.compile_kernel(&kernel_id, kernel, logger, mode)
+                .unwrap_or_else(|err| panic!("{err}"));
         }
 
+        ctx.last_kernel = Some(kernel_name);
+        ctx.launch_observer.observe(|| LaunchInfo {
+            name: kernel_name,
+            id: kernel_id.stable_format(),
+            cube_count: CubeCount::Static(count.0, count.1, count.2),
+            cube_dim: ctx.module_names[&kernel_id].cube_dim,
+            bindings: &bindings,
+            compiled,
+        });
+
         let tensor_maps: Vec<_> = bindings
             .tensor_maps
             .into_iter()
@@ -530,6 +688,15 @@ impl ComputeServer for CudaServer {
         }
     }
 
+    fn set_launch_observer(&mut self, observer: Option<LaunchObserver>) {
+        self.ctx.launch_observer.set(observer);
+    }
+
+    // Kernels are already enqueued in order on a single stream, without any submission.
+    fn start_batch(&mut self) {}
+
+    fn end_batch(&mut self) {}
+
     fn flush(&mut self) {}
 
     fn sync(&mut self) -> impl Future<Output = ()> + 'static {
@@ -537,6 +704,53 @@ impl ComputeServer for CudaServer {
         self.sync_stream_async()
     }
 
+    fn try_sync(&mut self) -> impl Future<Output = Result<(), DeviceError>> + 'static {
+        self.logger.profile_summary();
+        let ctx = self.get_context();
+        let sync = ctx.lazy_sync_stream();
+        let kernel = ctx.last_kernel;
+
+        async move {
+            // Errors of kernels are sticky, so they are reported by the next synchronization.
+            sync.try_wait().map_err(|err| DeviceError::Faulted {
+                code: format!("{:?}", err.0),
+                kernel: kernel.map(String::from),
+            })
+        }
+    }
+
+    fn poll(&mut self) -> bool {
+        let ctx = self.get_context();
+        let status = unsafe { cudarc::driver::sys::lib().cuStreamQuery(ctx.stream) };
+
+        match status {
+            cudarc::driver::sys::CUresult::CUDA_SUCCESS => true,
+            cudarc::driver::sys::CUresult::CUDA_ERROR_NOT_READY => false,
+            status => panic!("Failed to query the stream: {status:?}"),
+        }
+    }
+
+    fn on_complete(&mut self, callback: server::HostCallback) {
+        // Called by the driver thread once the work enqueued before it on the stream is done. A
+        // panic can't unwind into the driver, so it's caught and logged instead.
+        unsafe extern "C" fn invoke(data: *mut c_void) {
+            let callback = unsafe { Box::from_raw(data as *mut server::HostCallback) };
+            if std::panic::catch_unwind(std::panic::AssertUnwindSafe(callback)).is_err() {
+                log::error!("A host callback panicked, the panic was caught");
+            }
+        }
+
+        let ctx = self.get_context();
+        let data = Box::into_raw(Box::new(callback)) as *mut c_void;
+        let status =
+            unsafe { cudarc::driver::sys::lib().cuLaunchHostFunc(ctx.stream, Some(invoke), data) };
+
+        if status != cudarc::driver::sys::CUresult::CUDA_SUCCESS {
+            drop(unsafe { Box::from_raw(data as *mut server::HostCallback) });
+            panic!("Failed to enqueue the host callback: {status:?}");
+        }
+    }
+
     fn start_profile(&mut self) {
         // Wait for current work to be done.
         self.ctx.sync();
@@ -549,6 +763,29 @@ impl ComputeServer for CudaServer {
         self.ctx.timestamps.stop()
     }
 
+    fn read_into(&mut self, binding: server::Binding, destination: &mut [u8]) {
+        let ctx = self.get_context();
+        let resource = ctx
+            .memory_management
+            .get_resource(binding.memory, binding.offset_start, binding.offset_end)
+            .expect("Failed to find resource");
+
+        assert_eq!(
+            destination.len(),
+            resource.size() as usize,
+            "Destination of {} bytes doesn't match the {} bytes of the binding",
+            destination.len(),
+            resource.size()
+        );
+
+        unsafe {
+            cudarc::driver::result::memcpy_dtoh_async(destination, resource.ptr, ctx.stream)
+                .unwrap();
+        };
+
+        ctx.sync();
+    }
+
     fn get_resource(&mut self, binding: server::Binding) -> BindingResource<CudaResource> {
         let ctx = self.get_context();
         BindingResource::new(
@@ -568,6 +805,17 @@ impl ComputeServer for CudaServer {
     }
 }
 
+/// The NVRTC version, used to invalidate the compiled binaries of previous compilers.
+#[cfg(feature = "compilation-cache")]
+fn nvrtc_version() -> String {
+    let mut major = 0;
+    let mut minor = 0;
+    unsafe {
+        cudarc::nvrtc::sys::lib().nvrtcVersion(&mut major, &mut minor);
+    }
+    format!("nvrtc-{major}.{minor}")
+}
+
 fn find_resource(ctx: &mut CudaContext, binding: server::Binding) -> CudaResource {
     ctx.memory_management
         .get_resource(binding.memory, binding.offset_start, binding.offset_end)
@@ -588,10 +836,15 @@ impl CudaContext {
             module_names: HashMap::new(),
             #[cfg(feature = "compilation-cache")]
             ptx_cache: Cache::new("cuda/ptx", CacheOption::default()),
+            #[cfg(feature = "compilation-cache")]
+            binary_cache: BinaryCache::new("cuda", &format!("sm_{arch}"), &nvrtc_version()),
             stream,
+            copy_streams: CopyStreams::new(),
             arch,
             timestamps: KernelTimestamps::default(),
             compilation_options,
+            launch_observer: LaunchObserverSlot::default(),
+            last_kernel: None,
         }
     }
 
@@ -615,7 +868,7 @@ impl CudaContext {
         kernel: Box<dyn CubeTask<CudaCompiler>>,
         logger: &mut DebugLogger,
         mode: ExecutionMode,
-    ) {
+    ) -> Result<(), CompilationError> {
         #[cfg(feature = "compilation-cache")]
         let name = kernel_id.stable_format();
 
@@ -631,13 +884,14 @@ impl CudaContext {
                     y: entry.cube_dim.1,
                     z: entry.cube_dim.2,
                 },
-            );
-            return;
+                entry.shared_memory,
+            )?;
+            return Ok(());
         }
         log::trace!("Compiling kernel");
 
         let mut kernel_compiled =
-            kernel.compile(&mut Default::default(), &self.compilation_options, mode);
+            kernel.compile(&mut Default::default(), &self.compilation_options, mode)?;
 
         if logger.is_activated() {
             kernel_compiled.debug_info = Some(DebugInformation::new("cpp", kernel_id.clone()));
@@ -650,39 +904,36 @@ impl CudaContext {
         let compute_kernel = kernel_compiled.repr.as_ref().unwrap();
         let cube_dim = kernel_compiled.cube_dim;
         let fast_math = compute_kernel.flags.inst_fast_math;
-        let arch = format!("--gpu-architecture=sm_{}", self.arch);
-
-        let include_path = include_path();
-        let include_option = format!("--include-path={}", include_path.to_str().unwrap());
-        let mut options = vec![arch.as_str(), include_option.as_str(), "-lineinfo"];
-        if fast_math {
-            options.push("--use_fast_math");
-        }
+        let options = nvrtc_options(
+            self.arch.version,
+            fast_math,
+            compute_kernel.flags.debug_symbols,
+        );
+        let shared_memory = SharedMemoryConfig {
+            dynamic_size: compute_kernel.dynamic_shared_memory_size() as u32,
+            carveout: compute_kernel.shared_memory_carveout,
+        };
 
         #[cfg(feature = "compilation-cache")]
         let cluster_dim = compute_kernel.cluster_dim;
 
         let kernel_compiled = logger.debug(kernel_compiled);
 
-        let ptx = unsafe {
-            let program = cudarc::nvrtc::result::create_program(kernel_compiled.source).unwrap();
-            if cudarc::nvrtc::result::compile_program(program, &options).is_err() {
-                let log_raw = cudarc::nvrtc::result::get_program_log(program).unwrap();
-                let log_ptr = log_raw.as_ptr();
-                let log = CStr::from_ptr(log_ptr).to_str().unwrap();
-                let mut message = "[Compilation Error] ".to_string();
-                for line in log.split('\n') {
-                    if !line.is_empty() {
-                        message += format!("\n    {line}").as_str();
-                    }
-                }
-                let source = kernel
-                    .compile(&mut Default::default(), &self.compilation_options, mode)
-                    .source;
-                panic!("{message}\n[Source]  \n{source}");
-            };
-            cudarc::nvrtc::result::get_ptx(program).unwrap()
-        };
+        let compile = || compile_ptx(&kernel_compiled.source, &options);
+
+        // The options are part of the key, since they change the generated PTX.
+        #[cfg(feature = "compilation-cache")]
+        let ptx = self
+            .binary_cache
+            .get_or_compile(
+                &format!("{}\n{}", options.join(" "), kernel_compiled.source),
+                || Ok(compile()?.iter().map(|byte| *byte as u8).collect()),
+            )?
+            .into_iter()
+            .map(|byte| byte as i8)
+            .collect::<Vec<_>>();
+        #[cfg(not(feature = "compilation-cache"))]
+        let ptx = compile()?.to_vec();
 
         #[cfg(feature = "compilation-cache")]
         self.ptx_cache
@@ -692,6 +943,7 @@ impl CudaContext {
                     entrypoint_name: kernel_compiled.entrypoint_name.clone(),
                     cube_dim: (cube_dim.x, cube_dim.y, cube_dim.z),
                     cluster_dim: cluster_dim.map(|cluster| (cluster.x, cluster.y, cluster.z)),
+                    shared_memory,
                     ptx: ptx.clone(),
                 },
             )
@@ -702,7 +954,8 @@ impl CudaContext {
             kernel_id.clone(),
             kernel_compiled.entrypoint_name,
             cube_dim,
-        );
+            shared_memory,
+        )
     }
 
     fn load_ptx(
@@ -711,16 +964,77 @@ impl CudaContext {
         kernel_id: KernelId,
         entrypoint_name: String,
         cube_dim: CubeDim,
-    ) {
+        shared_memory: SharedMemoryConfig,
+    ) -> Result<(), CompilationError> {
         let func_name = CString::new(entrypoint_name).unwrap();
+        let mut info_log = vec![0u8; JIT_LOG_SIZE];
+        let mut error_log = vec![0u8; JIT_LOG_SIZE];
+        // The driver compiles the PTX with ptxas, which reports register usage and spills in its
+        // verbose log.
+        let mut options = [
+            CUjit_option::CU_JIT_INFO_LOG_BUFFER,
+            CUjit_option::CU_JIT_INFO_LOG_BUFFER_SIZE_BYTES,
+            CUjit_option::CU_JIT_ERROR_LOG_BUFFER,
+            CUjit_option::CU_JIT_ERROR_LOG_BUFFER_SIZE_BYTES,
+            CUjit_option::CU_JIT_LOG_VERBOSE,
+        ];
+        let mut values = [
+            info_log.as_mut_ptr() as *mut c_void,
+            JIT_LOG_SIZE as *mut c_void,
+            error_log.as_mut_ptr() as *mut c_void,
+            JIT_LOG_SIZE as *mut c_void,
+            1usize as *mut c_void,
+        ];
+
         let func = unsafe {
-            let module =
-                cudarc::driver::result::module::load_data(ptx.as_ptr() as *const _).unwrap();
-            cudarc::driver::result::module::get_function(module, func_name).unwrap()
+            let mut module = MaybeUninit::uninit();
+            cudarc::driver::sys::lib()
+                .cuModuleLoadDataEx(
+                    module.as_mut_ptr(),
+                    ptx.as_ptr() as *const c_void,
+                    options.len() as u32,
+                    options.as_mut_ptr(),
+                    values.as_mut_ptr(),
+                )
+                .result()
+                .map_err(|err| {
+                    CompilationError::BackendError(format!("{err:?}\n{}", jit_log(&error_log)))
+                })?;
+            cudarc::driver::result::module::get_function(module.assume_init(), func_name).unwrap()
+        };
+        let warnings = parse_ptxas_log(&jit_log(&info_log));
+
+        let set_attribute = |attribute, value: u32| unsafe {
+            cudarc::driver::sys::lib()
+                .cuFuncSetAttribute(func, attribute, value as i32)
+                .result()
+                .map_err(|err| CompilationError::BackendError(format!("{attribute:?}: {err:?}")))
         };
+        // Dynamic shared memory above the default limit must be opted into for each kernel.
+        if shared_memory.dynamic_size > 0 {
+            set_attribute(
+                CUfunction_attribute::CU_FUNC_ATTRIBUTE_MAX_DYNAMIC_SHARED_SIZE_BYTES,
+                shared_memory.dynamic_size,
+            )?;
+        }
+        if let Some(carveout) = shared_memory.carveout {
+            set_attribute(
+                CUfunction_attribute::CU_FUNC_ATTRIBUTE_PREFERRED_SHARED_MEMORY_CARVEOUT,
+                carveout,
+            )?;
+        }
 
-        self.module_names
-            .insert(kernel_id.clone(), CompiledKernel { cube_dim, func });
+        self.module_names.insert(
+            kernel_id.clone(),
+            CompiledKernel {
+                cube_dim,
+                func,
+                dynamic_shared_memory: shared_memory.dynamic_size,
+                warnings,
+            },
+        );
+
+        Ok(())
     }
 
     fn execute_task(
@@ -745,10 +1059,9 @@ impl CudaContext {
                 kernel.func,
                 dispatch_count,
                 (cube_dim.x, cube_dim.y, cube_dim.z),
-                // Shared memory is specified statically in the kernel, and no dynamic shared
-                // memory is supported yet in the kernel, which would be that value for the
-                // current kernel launch.
-                0,
+                // Shared memory is declared statically in the kernel, unless it exceeds the
+                // static limit.
+                kernel.dynamic_shared_memory,
                 self.stream,
                 &mut bindings,
             )
@@ -776,6 +1089,195 @@ impl CudaServer {
     }
 }
 
+/// The NVRTC options to compile a kernel for the given architecture.
+///
+/// Kernels always carry line info for profilers. Kernels built with debug symbols are also
+/// compiled for device debugging with `-G`, which disables the optimizations of the device code
+/// so they can be stepped through in `cuda-gdb`.
+pub(crate) fn nvrtc_options(arch: u32, fast_math: bool, device_debug: bool) -> Vec<String> {
+    let include_path = include_path();
+    let mut options = vec![
+        format!("--gpu-architecture=sm_{arch}"),
+        format!("--include-path={}", include_path.to_str().unwrap()),
+    ];
+    if device_debug {
+        options.push("-G".to_string());
+    } else {
+        options.push("-lineinfo".to_string());
+    }
+    if fast_math {
+        options.push("--use_fast_math".to_string());
+    }
+    options
+}
+
+/// Maximum number of PTX modules kept by [PTX_CACHE].
+const PTX_CACHE_CAPACITY: usize = 512;
+
+/// PTX compiled by NVRTC in this process. Identical kernels, e.g. loaded on several devices or
+/// with different kernel ids, only go through NVRTC once and are only assembled by `ptxas` when
+/// loaded.
+static PTX_CACHE: LazyLock<Mutex<PtxCache>> =
+    LazyLock::new(|| Mutex::new(PtxCache::new(PTX_CACHE_CAPACITY)));
+
+/// PTX keyed by the MD5 digest of the options and the source, so the sources aren't kept in
+/// memory. Holds at most `capacity` modules, evicting the oldest one first.
+pub(crate) struct PtxCache {
+    capacity: usize,
+    entries: HashMap<[u8; 16], Arc<Vec<i8>>>,
+    order: VecDeque<[u8; 16]>,
+}
+
+impl PtxCache {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    pub(crate) fn key(source: &str, options: &[String]) -> [u8; 16] {
+        let mut context = md5::Context::new();
+        for option in options {
+            context.consume(option.as_bytes());
+            context.consume([0u8]);
+        }
+        context.consume(source.as_bytes());
+        context.compute().0
+    }
+
+    pub(crate) fn get(&self, key: &[u8; 16]) -> Option<Arc<Vec<i8>>> {
+        self.entries.get(key).cloned()
+    }
+
+    pub(crate) fn insert(&mut self, key: [u8; 16], ptx: Arc<Vec<i8>>) {
+        if self.entries.insert(key, ptx).is_some() {
+            return;
+        }
+        self.order.push_back(key);
+
+        while self.order.len() > self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.entries.remove(&oldest);
+            }
+        }
+    }
+}
+
+/// Compile the CUDA source to PTX with NVRTC, which doesn't need any device, reusing the PTX of
+/// an identical compilation when possible.
+pub(crate) fn compile_ptx(
+    source: &str,
+    options: &[String],
+) -> Result<Arc<Vec<i8>>, CompilationError> {
+    let key = PtxCache::key(source, options);
+    if let Some(ptx) = PTX_CACHE.lock().unwrap().get(&key) {
+        log::trace!("Using cached PTX");
+        return Ok(ptx);
+    }
+
+    let ptx = Arc::new(nvrtc_compile(source, options)?);
+    PTX_CACHE.lock().unwrap().insert(key, ptx.clone());
+    Ok(ptx)
+}
+
+fn nvrtc_compile(source: &str, options: &[String]) -> Result<Vec<i8>, CompilationError> {
+    unsafe {
+        let program = cudarc::nvrtc::result::create_program(source).unwrap();
+        if cudarc::nvrtc::result::compile_program(program, options).is_err() {
+            let log_raw = cudarc::nvrtc::result::get_program_log(program).unwrap();
+            let log_ptr = log_raw.as_ptr();
+            let log = CStr::from_ptr(log_ptr).to_str().unwrap();
+            if let Some(err) = parse_shared_memory_error(log) {
+                return Err(err);
+            }
+            let mut message = String::new();
+            for line in log.split('\n') {
+                if !line.is_empty() {
+                    message += format!("\n    {line}").as_str();
+                }
+            }
+            message += format!("\n[Source]  \n{source}").as_str();
+            return Err(CompilationError::BackendError(message));
+        };
+        Ok(cudarc::nvrtc::result::get_ptx(program).unwrap())
+    }
+}
+
+/// Extracts the requested and maximum shared memory from a `ptxas` error of the form
+/// `uses too much shared data (0x10000 bytes, 0xc000 max)`.
+fn parse_shared_memory_error(log: &str) -> Option<CompilationError> {
+    let (_, rest) = log.split_once("too much shared data (")?;
+    let (requested, rest) = rest.split_once(" bytes, ")?;
+    let (limit, _) = rest.split_once(" max)")?;
+    let parse = |value: &str| usize::from_str_radix(value.trim_start_matches("0x"), 16).ok();
+
+    Some(CompilationError::SharedMemoryExceeded {
+        requested: parse(requested)?,
+        limit: parse(limit)?,
+    })
+}
+
+/// Size of the buffers receiving the logs of the driver's JIT compiler.
+const JIT_LOG_SIZE: usize = 16 * 1024;
+
+/// Reads the null-terminated log written by the JIT compiler.
+fn jit_log(buffer: &[u8]) -> String {
+    CStr::from_bytes_until_nul(buffer)
+        .map(|log| log.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// Parses the verbose log of `ptxas` into warnings, attributing each message to the entry
+/// function being compiled. Function properties reporting spilled registers are warnings, other
+/// information about the generated code is kept as info.
+fn parse_ptxas_log(log: &str) -> Vec<CompileWarning> {
+    let mut warnings = Vec::new();
+    let mut function = None;
+
+    for line in log.lines().filter(|line| !line.trim().is_empty()) {
+        let (severity, message) = if let Some(rest) = line.strip_prefix("ptxas warning") {
+            (WarningSeverity::Warning, rest)
+        } else if let Some(rest) = line.strip_prefix("ptxas info") {
+            (WarningSeverity::Info, rest)
+        } else if reports_spills(line) {
+            (WarningSeverity::Warning, line)
+        } else {
+            (WarningSeverity::Info, line)
+        };
+        let message = message.trim_start().trim_start_matches(':').trim();
+
+        if let Some(name) = message
+            .strip_prefix("Compiling entry function '")
+            .and_then(|rest| rest.split_once('\''))
+        {
+            function = Some(name.0.to_string());
+        }
+
+        warnings.push(CompileWarning {
+            message: message.to_string(),
+            severity,
+            location: function.clone(),
+        });
+    }
+
+    warnings
+}
+
+/// Whether a line of function properties, e.g. `8 bytes stack frame, 8 bytes spill stores,
+/// 8 bytes spill loads`, reports registers spilled to local memory.
+fn reports_spills(line: &str) -> bool {
+    line.split(',').any(|part| {
+        let part = part.trim();
+        (part.ends_with("spill stores") || part.ends_with("spill loads"))
+            && part
+                .split_whitespace()
+                .next()
+                .is_some_and(|count| count != "0")
+    })
+}
+
 fn include_path() -> PathBuf {
     let mut path = cuda_path().expect("
         CUDA installation not found.
@@ -0,0 +1,209 @@
+use cudarc::driver::sys::{CUevent, CUevent_flags, CUevent_wait_flags, CUstream};
+use std::{
+    ffi::c_void,
+    future::Future,
+    sync::{Arc, Mutex},
+};
+
+/// The number of pinned buffers kept for uploads, one per chunk in flight when processing a
+/// dataset chunk by chunk.
+const NUM_UPLOAD_BUFFERS: usize = 3;
+
+/// Copy streams and pinned host buffers used to overlap the transfers with the kernels of the
+/// compute stream.
+#[derive(Debug)]
+pub(crate) struct CopyStreams {
+    upload: CUstream,
+    download: CUstream,
+    uploads: [Option<StagedUpload>; NUM_UPLOAD_BUFFERS],
+    next_upload: usize,
+    downloads: Arc<Mutex<Vec<PinnedBuffer>>>,
+}
+
+/// A pinned buffer with the event marking the end of the last copy out of it.
+#[derive(Debug)]
+struct StagedUpload {
+    buffer: PinnedBuffer,
+    copied: CopyEvent,
+}
+
+impl CopyStreams {
+    pub fn new() -> Self {
+        let create_stream = || {
+            cudarc::driver::result::stream::create(
+                cudarc::driver::result::stream::StreamKind::NonBlocking,
+            )
+            .unwrap()
+        };
+
+        Self {
+            upload: create_stream(),
+            download: create_stream(),
+            uploads: Default::default(),
+            next_upload: 0,
+            downloads: Default::default(),
+        }
+    }
+
+    /// Copies `data` to `dst` on the upload stream, making the kernels submitted afterwards on
+    /// `compute` wait for the copy.
+    pub fn upload(&mut self, compute: CUstream, dst: u64, data: &[u8]) {
+        let slot = &mut self.uploads[self.next_upload];
+        self.next_upload = (self.next_upload + 1) % NUM_UPLOAD_BUFFERS;
+
+        let mut buffer = match slot.take() {
+            Some(StagedUpload { buffer, copied }) => {
+                copied.wait();
+                buffer
+            }
+            None => PinnedBuffer::new(data.len()),
+        };
+        if buffer.size < data.len() {
+            buffer = PinnedBuffer::new(data.len());
+        }
+        let staged = &mut buffer.as_mut_slice()[..data.len()];
+        staged.copy_from_slice(data);
+
+        unsafe {
+            cudarc::driver::result::memcpy_htod_async(dst, &*staged, self.upload).unwrap();
+        }
+        let copied = CopyEvent::record(self.upload);
+        copied.block(compute);
+
+        *slot = Some(StagedUpload { buffer, copied });
+    }
+
+    /// Copies `size` bytes from `src` on the download stream once the kernels submitted before
+    /// on `compute` are done, returning a future for the bytes.
+    pub fn download(
+        &mut self,
+        compute: CUstream,
+        src: u64,
+        size: usize,
+    ) -> impl Future<Output = Vec<u8>> + Send + 'static {
+        // The event can be released right away, the download stream still waits for it.
+        CopyEvent::record(compute).block(self.download);
+
+        let pool = self.downloads.clone();
+        let mut buffer = {
+            let mut buffers = pool.lock().unwrap();
+            match buffers.iter().position(|buffer| buffer.size >= size) {
+                Some(index) => buffers.swap_remove(index),
+                None => PinnedBuffer::new(size),
+            }
+        };
+
+        unsafe {
+            cudarc::driver::result::memcpy_dtoh_async(
+                &mut buffer.as_mut_slice()[..size],
+                src,
+                self.download,
+            )
+            .unwrap();
+        }
+        let copied = CopyEvent::record(self.download);
+
+        async move {
+            copied.wait();
+            let data = buffer.as_mut_slice()[..size].to_vec();
+            pool.lock().unwrap().push(buffer);
+            data
+        }
+    }
+}
+
+/// Page-locked host memory, which the device can copy from and to asynchronously.
+#[derive(Debug)]
+struct PinnedBuffer {
+    ptr: *mut u8,
+    size: usize,
+}
+
+// The buffer is only accessed by its owner, and the copies that use it are waited on before it is
+// reused or freed.
+unsafe impl Send for PinnedBuffer {}
+
+impl PinnedBuffer {
+    fn new(size: usize) -> Self {
+        let mut ptr: *mut c_void = std::ptr::null_mut();
+        unsafe {
+            cudarc::driver::sys::lib()
+                .cuMemAllocHost_v2(&mut ptr, size.max(1))
+                .result()
+                .expect("Should allocate pinned host memory");
+        }
+
+        Self {
+            ptr: ptr as *mut u8,
+            size,
+        }
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [u8] {
+        unsafe { std::slice::from_raw_parts_mut(self.ptr, self.size) }
+    }
+}
+
+impl Drop for PinnedBuffer {
+    fn drop(&mut self) {
+        unsafe {
+            cudarc::driver::sys::lib()
+                .cuMemFreeHost(self.ptr as *mut c_void)
+                .result()
+                .unwrap();
+        }
+    }
+}
+
+/// An event recorded on a stream, marking the end of the work submitted to it before.
+#[derive(Debug)]
+struct CopyEvent {
+    event: CUevent,
+}
+
+// Events can be waited on from any thread, and the event is destroyed once.
+unsafe impl Send for CopyEvent {}
+
+impl CopyEvent {
+    fn record(stream: CUstream) -> Self {
+        unsafe {
+            let event =
+                cudarc::driver::result::event::create(CUevent_flags::CU_EVENT_DISABLE_TIMING)
+                    .unwrap();
+            cudarc::driver::result::event::record(event, stream).unwrap();
+
+            Self { event }
+        }
+    }
+
+    /// Makes the work submitted afterwards to `stream` wait for the event, without blocking the
+    /// host.
+    fn block(&self, stream: CUstream) {
+        unsafe {
+            cudarc::driver::result::stream::wait_event(
+                stream,
+                self.event,
+                CUevent_wait_flags::CU_EVENT_WAIT_DEFAULT,
+            )
+            .unwrap();
+        }
+    }
+
+    /// Blocks the host until the event is reached.
+    fn wait(self) {
+        unsafe {
+            cudarc::driver::sys::lib()
+                .cuEventSynchronize(self.event)
+                .result()
+                .unwrap();
+        }
+    }
+}
+
+impl Drop for CopyEvent {
+    fn drop(&mut self) {
+        unsafe {
+            cudarc::driver::result::event::destroy(self.event).unwrap();
+        }
+    }
+}
@@ -1,6 +1,8 @@
-use cubecl_runtime::storage::{ComputeStorage, StorageHandle, StorageId, StorageUtilization};
+use cubecl_runtime::storage::{
+    ComputeStorage, DeviceAddress, StorageHandle, StorageId, StorageUtilization,
+};
 use cudarc::driver::sys::CUstream;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use super::uninit_vec;
 
@@ -8,6 +10,7 @@ use super::uninit_vec;
 pub struct CudaStorage {
     memory: HashMap<StorageId, cudarc::driver::sys::CUdeviceptr>,
     deallocations: Vec<StorageId>,
+    external: HashSet<StorageId>,
     stream: cudarc::driver::sys::CUstream,
     ptr_bindings: PtrBindings,
 }
@@ -55,15 +58,32 @@ impl CudaStorage {
         Self {
             memory: HashMap::new(),
             deallocations: Vec::new(),
+            external: HashSet::new(),
             stream,
             ptr_bindings: PtrBindings::new(),
         }
     }
 
+    /// Registers `size` bytes of memory allocated outside of the storage at `ptr`. The memory is
+    /// never freed by the storage, deallocating it only forgets about it.
+    pub fn register_external(
+        &mut self,
+        ptr: cudarc::driver::sys::CUdeviceptr,
+        size: u64,
+    ) -> StorageHandle {
+        let id = StorageId::new();
+        self.memory.insert(id, ptr);
+        self.external.insert(id);
+        StorageHandle::new(id, StorageUtilization { offset: 0, size })
+    }
+
     /// Actually deallocates buffers tagged to be deallocated.
     pub fn perform_deallocations(&mut self) {
         for id in self.deallocations.drain(..) {
             if let Some(ptr) = self.memory.remove(&id) {
+                if self.external.remove(&id) {
+                    continue;
+                }
                 unsafe {
                     cudarc::driver::result::free_async(ptr, self.stream).unwrap();
                 }
@@ -84,6 +104,12 @@ pub struct CudaResource {
 
 unsafe impl Send for CudaResource {}
 
+impl DeviceAddress for CudaResource {
+    fn device_ptr(&self) -> u64 {
+        self.ptr
+    }
+}
+
 pub type Binding = *mut std::ffi::c_void;
 
 impl CudaResource {
@@ -5,9 +5,11 @@ extern crate alloc;
 mod compute;
 mod device;
 mod runtime;
+mod target;
 
 pub use device::*;
 pub use runtime::*;
+pub use target::*;
 
 #[cfg(test)]
 #[allow(unexpected_cfgs)]
@@ -18,6 +20,7 @@ mod tests {
 
     cubecl_core::testgen_all!(f32: [f16, bf16, f32, f64], i32: [i8, i16, i32, i64], u32: [u8, u16, u32, u64]);
 
+    cubecl_core::testgen_compilation!();
     cubecl_std::testgen!();
 
     cubecl_linalg::testgen_matmul_accelerated!([f16]);
@@ -27,6 +30,218 @@ mod tests {
     cubecl_linalg::testgen_matmul_tiling2d!([f16, bf16, f32]);
     cubecl_linalg::testgen_conv2d_accelerated!([f16: f16, bf16: bf16, f32: tf32]);
     cubecl_linalg::testgen_tensor_identity!([f16, bf16, f32, u32]);
+    cubecl_linalg::testgen_tensor_transpose!([f16, bf16, f32, u32]);
+    cubecl_linalg::testgen_tensor_reinterpret!();
+    cubecl_linalg::testgen_tensor_contiguous!();
+    cubecl_linalg::testgen_tensor_elemwise!();
     cubecl_reduce::testgen_reduce!([f16, bf16, f32, f64]);
     cubecl_reduce::testgen_shared_sum!([f16, bf16, f32, f64]);
+
+    #[test]
+    fn test_line_cast_emits_packed_half_conversions() {
+        use cubecl_core::{ExecutionMode, prelude::CubeTask};
+
+        let kernel = cubecl_core::runtime_tests::line::line_cast_kernel::<TestRuntime>(5);
+        let compiled = kernel
+            .compile(
+                &mut Default::default(),
+                &Default::default(),
+                ExecutionMode::Checked,
+            )
+            .unwrap();
+
+        assert!(compiled.source.contains("__float22half2_rn"));
+        assert!(compiled.source.contains("__half22float2"));
+    }
+
+    #[test]
+    fn test_compile_reports_register_spills() {
+        use cubecl_core::{Runtime, server::WarningSeverity};
+
+        let client = TestRuntime::client(&Default::default());
+        let kernel =
+            cubecl_core::runtime_tests::compilation::spilling_kernel::<TestRuntime>(&client);
+        let warnings = client.compile(kernel).unwrap();
+
+        assert!(
+            warnings
+                .iter()
+                .any(|warning| warning.severity == WarningSeverity::Warning
+                    && warning.message.contains("spill")),
+            "{warnings:?}"
+        );
+    }
+
+    #[test]
+    fn test_register_external_memory() {
+        use cubecl_core::{CubeElement, Runtime};
+
+        let client = TestRuntime::client(&Default::default());
+        let data = [1.0f32, 2.0, 3.0, 4.0];
+        let size = size_of_val(&data);
+
+        // Makes the context of the client current on this thread.
+        client.empty(1);
+        let ptr = unsafe { cudarc::driver::result::malloc_sync(size).unwrap() };
+        unsafe { cudarc::driver::result::memcpy_htod_sync(ptr, &data).unwrap() };
+
+        let handle = unsafe { client.register_external(ptr, size as u64) };
+        assert_eq!(client.device_ptr(handle.clone().binding()), ptr);
+
+        let actual = client.read_one(handle.binding());
+        assert_eq!(f32::from_bytes(&actual), data);
+
+        client.memory_cleanup();
+        cubecl_common::future::block_on(client.sync());
+        unsafe { cudarc::driver::result::free_sync(ptr).unwrap() };
+    }
+
+    #[test]
+    fn test_kernel_name_is_the_entry_point() {
+        let client = TestRuntime::client(&Default::default());
+        cubecl_core::runtime_tests::compilation::test_kernel_name::<TestRuntime>(
+            client,
+            &mut Default::default(),
+        );
+    }
+
+    #[test]
+    fn test_kernel_bindings_match_signature() {
+        let client = TestRuntime::client(&Default::default());
+        cubecl_core::runtime_tests::compilation::test_kernel_bindings::<TestRuntime>(
+            client,
+            &mut Default::default(),
+        );
+    }
+
+    #[test]
+    fn test_roofline_estimate_counts_operations() {
+        let client = TestRuntime::client(&Default::default());
+        cubecl_core::runtime_tests::compilation::test_roofline_estimate::<TestRuntime>(
+            client,
+            &mut Default::default(),
+        );
+    }
+
+    #[test]
+    fn test_compile_for_other_targets() {
+        use cubecl_core::ExecutionMode;
+
+        let client = TestRuntime::client(&Default::default());
+
+        // At least one of the targets isn't the local device.
+        for target in ["sm_80", "sm_90"] {
+            let kernel =
+                cubecl_core::runtime_tests::compilation::fast_math_kernel::<TestRuntime>(&client);
+            let compiled =
+                crate::compile_for_target(kernel, target.parse().unwrap(), ExecutionMode::Checked)
+                    .unwrap();
+
+            assert!(compiled.ptx.contains(&format!(".target {target}")));
+            assert!(compiled.ptx.contains(&compiled.entrypoint_name));
+        }
+    }
+
+    #[test]
+    fn test_identical_compilation_reuses_ptx() {
+        use cubecl_core::{ExecutionMode, prelude::CubeTask};
+
+        let client = TestRuntime::client(&Default::default());
+        let compile = || {
+            let kernel =
+                cubecl_core::runtime_tests::compilation::spilling_kernel::<TestRuntime>(&client);
+            let compiled = kernel
+                .compile(
+                    &mut Default::default(),
+                    &Default::default(),
+                    ExecutionMode::Checked,
+                )
+                .unwrap();
+            crate::compute::compile_ptx(
+                &compiled.source,
+                &crate::compute::nvrtc_options(80, false, false),
+            )
+            .unwrap()
+        };
+
+        let first = compile();
+        let second = compile();
+
+        // NVRTC would have produced a new PTX.
+        assert!(alloc::sync::Arc::ptr_eq(&first, &second));
+    }
+
+    #[test]
+    fn test_native_line_sizes_of_f16() {
+        use cubecl_core::ir::{Elem, FloatKind};
+
+        let sizes =
+            <TestRuntime as cubecl_core::Runtime>::native_line_sizes(&Elem::Float(FloatKind::F16));
+
+        assert_eq!(sizes, [8, 4, 2, 1]);
+    }
+
+    #[test]
+    fn test_ptx_cache_evicts_the_oldest_module() {
+        use crate::compute::PtxCache;
+
+        let options = crate::compute::nvrtc_options(80, false, false);
+        let keys = ["first", "second", "third"].map(|source| PtxCache::key(source, &options));
+        let mut cache = PtxCache::new(2);
+
+        for (i, key) in keys.iter().enumerate() {
+            cache.insert(*key, alloc::sync::Arc::new(vec![i as i8]));
+        }
+
+        assert!(cache.get(&keys[0]).is_none());
+        assert_eq!(*cache.get(&keys[1]).unwrap(), vec![1]);
+        assert_eq!(*cache.get(&keys[2]).unwrap(), vec![2]);
+        assert_ne!(keys[0], PtxCache::key("first", &[]));
+    }
+
+    #[test]
+    fn test_debug_symbols_emit_line_directives() {
+        cubecl_core::runtime_tests::compilation::test_debug_line_info::<TestRuntime>(
+            &mut Default::default(),
+            "#line ",
+        );
+    }
+
+    #[test]
+    fn test_inline_helper_is_expanded_in_caller() {
+        cubecl_core::runtime_tests::compilation::test_inline_helper::<TestRuntime>(
+            &mut Default::default(),
+        );
+    }
+
+    #[test]
+    fn test_noinline_helper_is_a_separate_function() {
+        cubecl_core::runtime_tests::compilation::test_noinline_helper::<TestRuntime>(
+            &mut Default::default(),
+            "__attribute__((noinline))",
+        );
+    }
+
+    #[test]
+    fn test_debug_symbols_compile_for_device_debugging() {
+        let options = crate::compute::nvrtc_options(80, false, true);
+
+        assert!(options.contains(&"-G".to_string()));
+        assert!(!options.contains(&"-lineinfo".to_string()));
+    }
+
+    #[test]
+    fn test_memory_fences_emit_thread_fences() {
+        use cubecl_core::prelude::MemoryScope;
+
+        let client = TestRuntime::client(&Default::default());
+        cubecl_core::runtime_tests::compilation::test_memory_fences::<TestRuntime>(
+            client,
+            &mut Default::default(),
+            |scope, _| match scope {
+                MemoryScope::Cube => "__threadfence_block();",
+                MemoryScope::Device => "__threadfence();",
+            },
+        );
+    }
 }
@@ -1,11 +1,12 @@
 use std::mem::MaybeUninit;
 
 use cubecl_core::{
-    AtomicFeature, CubeDim, DeviceId, Feature, MemoryConfiguration, Runtime, TmaFeature,
+    AtomicFeature, CubeDim, DeviceId, Feature, MemoryConfiguration, OutOfMemoryPolicy, Runtime,
+    TmaFeature,
     ir::{Elem, FloatKind},
 };
 use cubecl_runtime::{
-    ComputeRuntime, DeviceProperties,
+    BackendInfo, ComputeRuntime, DeviceProperties,
     channel::MutexComputeChannel,
     client::ComputeClient,
     memory_management::{HardwareProperties, MemoryDeviceProperties, MemoryManagement},
@@ -28,6 +29,14 @@ use cubecl_cpp::{
 pub struct RuntimeOptions {
     /// Configures the memory management.
     pub memory_config: MemoryConfiguration,
+    /// Maximum number of bytes of device memory to reserve, `None` for no limit.
+    ///
+    /// Allocations beyond this limit fail, after releasing unused memory and retrying with the
+    /// default [policy](Self::on_oom). See
+    /// [try_empty](cubecl_runtime::client::ComputeClient::try_empty) to handle the failure.
+    pub memory_limit: Option<u64>,
+    /// What to do when an allocation would exceed the memory limit, unused without a limit.
+    pub on_oom: OutOfMemoryPolicy,
 }
 
 #[derive(Debug)]
@@ -83,8 +92,16 @@ fn create_client(device: &CudaDevice, options: RuntimeOptions) -> ComputeClient<
     let hardware_props = unsafe {
         use cudarc::driver::{result::device::get_attribute, sys::CUdevice_attribute::*};
         let warp_size = get_attribute(device_ptr, CU_DEVICE_ATTRIBUTE_WARP_SIZE).unwrap() as u32;
-        let max_shared = get_attribute(device_ptr, CU_DEVICE_ATTRIBUTE_MAX_SHARED_MEMORY_PER_BLOCK)
-            .unwrap() as usize;
+        // Shared memory above the default limit must be allocated dynamically, after opting into
+        // the larger limit supported by the device.
+        let max_static_shared =
+            get_attribute(device_ptr, CU_DEVICE_ATTRIBUTE_MAX_SHARED_MEMORY_PER_BLOCK).unwrap()
+                as usize;
+        let max_shared = get_attribute(
+            device_ptr,
+            CU_DEVICE_ATTRIBUTE_MAX_SHARED_MEMORY_PER_BLOCK_OPTIN,
+        )
+        .unwrap() as usize;
         let max_threads =
             get_attribute(device_ptr, CU_DEVICE_ATTRIBUTE_MAX_THREADS_PER_BLOCK).unwrap() as u32;
         let block_dim_x = get_attribute(device_ptr, CU_DEVICE_ATTRIBUTE_MAX_BLOCK_DIM_X).unwrap();
@@ -104,7 +121,28 @@ fn create_client(device: &CudaDevice, options: RuntimeOptions) -> ComputeClient<
         );
         let num_tensor_cores = tensor_cores_per_sm(arch.version);
 
+        // Each core issues a fused multiply-add, two operations, per clock cycle.
+        let clock_rate_khz = get_attribute(device_ptr, CU_DEVICE_ATTRIBUTE_CLOCK_RATE).unwrap();
+        let peak_flops = num_streaming_multiprocessors
+            .zip(fp32_cores_per_sm(arch.version))
+            .map(|(sms, cores)| 2.0 * (sms * cores) as f64 * clock_rate_khz as f64 * 1000.0);
+        // The memory transfers data on both edges of the clock.
+        let memory_clock_rate_khz =
+            get_attribute(device_ptr, CU_DEVICE_ATTRIBUTE_MEMORY_CLOCK_RATE).unwrap();
+        let bus_width_bits =
+            get_attribute(device_ptr, CU_DEVICE_ATTRIBUTE_GLOBAL_MEMORY_BUS_WIDTH).unwrap();
+        let peak_memory_bandwidth =
+            Some(2.0 * memory_clock_rate_khz as f64 * 1000.0 * bus_width_bits as f64 / 8.0);
+
+        let max_registers_per_cube =
+            get_attribute(device_ptr, CU_DEVICE_ATTRIBUTE_MAX_REGISTERS_PER_BLOCK)
+                .ok()
+                .map(|registers| registers as u32);
+
         comp_opts.warp_size = warp_size;
+        comp_opts.max_shared_memory_size = Some(max_shared);
+        comp_opts.max_static_shared_memory_size = Some(max_static_shared);
+        comp_opts.max_bindings = Some(crate::device::CUDA_MAX_BINDINGS);
 
         HardwareProperties {
             plane_size_min: warp_size,
@@ -116,11 +154,16 @@ fn create_client(device: &CudaDevice, options: RuntimeOptions) -> ComputeClient<
             max_cube_dim,
             num_streaming_multiprocessors,
             num_tensor_cores,
+            peak_flops,
+            peak_memory_bandwidth,
+            max_registers_per_cube,
         }
     };
 
-    let memory_management =
+    let mut memory_management =
         MemoryManagement::from_configuration(storage, &mem_properties, options.memory_config);
+    memory_management.set_memory_limit(options.memory_limit);
+    memory_management.set_out_of_memory_policy(options.on_oom);
 
     let mut compilation_options = CompilationOptions::default();
     let mut device_props = DeviceProperties::new(
@@ -130,10 +173,14 @@ fn create_client(device: &CudaDevice, options: RuntimeOptions) -> ComputeClient<
         cubecl_runtime::TimeMeasurement::System,
     );
     register_supported_types(&mut device_props);
+    device_props.set_backend_info(backend_info(device_ptr));
     device_props.register_feature(Feature::Type(Elem::Float(FloatKind::TF32)));
     if arch.version >= 60 {
         device_props.register_feature(Feature::Type(Elem::AtomicFloat(FloatKind::F64)));
     }
+    if arch.version >= 61 {
+        device_props.register_feature(Feature::IntegerDotProduct);
+    }
     if arch.version >= 70 {
         device_props.register_feature(Feature::Type(Elem::AtomicFloat(FloatKind::F16)));
         device_props.register_feature(Feature::Pipeline);
@@ -166,6 +213,45 @@ fn create_client(device: &CudaDevice, options: RuntimeOptions) -> ComputeClient<
     ComputeClient::new(MutexComputeChannel::new(server), device_props, ())
 }
 
+fn backend_info(device_ptr: cudarc::driver::sys::CUdevice) -> BackendInfo {
+    let device_name = cudarc::driver::result::device::get_name(device_ptr).unwrap_or_default();
+    let mut driver_version = 0;
+    let driver_status =
+        unsafe { cudarc::driver::sys::lib().cuDriverGetVersion(&mut driver_version) };
+    let (mut nvrtc_major, mut nvrtc_minor) = (0, 0);
+    let nvrtc_status =
+        unsafe { cudarc::nvrtc::sys::lib().nvrtcVersion(&mut nvrtc_major, &mut nvrtc_minor) };
+
+    BackendInfo {
+        name: "cuda".to_string(),
+        device_name,
+        api_version: match nvrtc_status {
+            cudarc::nvrtc::sys::nvrtcResult::NVRTC_SUCCESS => {
+                format!("nvrtc {nvrtc_major}.{nvrtc_minor}")
+            }
+            _ => String::new(),
+        },
+        driver_version: match driver_status {
+            // Encoded as `1000 * major + 10 * minor`.
+            cudarc::driver::sys::CUresult::CUDA_SUCCESS => {
+                format!("{}.{}", driver_version / 1000, (driver_version % 1000) / 10)
+            }
+            _ => String::new(),
+        },
+    }
+}
+
+fn fp32_cores_per_sm(version: u32) -> Option<u32> {
+    match version {
+        // P100, Volta, Turing, A100
+        60 | 70 | 72 | 75 | 80 => Some(64),
+        // Pascal, Ampere, Ada, Hopper, Blackwell
+        61 | 62 | 86 | 87 | 89 | 90 | 100 | 120 => Some(128),
+        // Unknown or unsupported architecture
+        _ => None,
+    }
+}
+
 fn tensor_cores_per_sm(version: u32) -> Option<u32> {
     match version {
         70 | 75 => Some(8),                           // Volta, Turing
@@ -199,6 +285,11 @@ impl Runtime for CudaRuntime {
         true
     }
 
+    fn fixed_plane_dim(_client: &ComputeClient<Self::Server, Self::Channel>) -> Option<u32> {
+        // Every CUDA device runs warps of 32 threads.
+        Some(32)
+    }
+
     fn supported_line_sizes() -> &'static [u8] {
         &[8, 4, 2, 1]
     }
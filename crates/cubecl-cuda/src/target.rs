@@ -0,0 +1,95 @@
+use std::{ffi::CStr, fmt::Display, str::FromStr};
+
+use cubecl_core::{
+    ExecutionMode,
+    prelude::{CubeDim, CubeTask},
+    server::CompilationError,
+};
+use cubecl_cpp::{CudaCompiler, shared::CompilationOptions};
+
+use crate::compute::{compile_ptx, nvrtc_options};
+
+/// The amount of shared memory every architecture supports declaring statically, in bytes.
+const STATIC_SHARED_MEMORY_LIMIT: usize = 48 * 1024;
+
+/// CUDA architecture to generate code for, which doesn't have to be present on the machine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CompileTarget {
+    /// The compute capability of the target, `90` for `sm_90`.
+    pub arch: u32,
+}
+
+impl CompileTarget {
+    /// Target the given compute capability.
+    pub fn sm(arch: u32) -> Self {
+        Self { arch }
+    }
+
+    /// The compilation options supported by every device of the target architecture.
+    pub fn compilation_options(&self) -> CompilationOptions {
+        CompilationOptions {
+            grid_constants: self.arch >= 70,
+            supports_clusters: self.arch >= 90,
+            max_static_shared_memory_size: Some(STATIC_SHARED_MEMORY_LIMIT),
+            ..Default::default()
+        }
+    }
+}
+
+impl FromStr for CompileTarget {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let arch = s
+            .strip_prefix("sm_")
+            .unwrap_or(s)
+            .parse()
+            .map_err(|e| format!("bad cuda compile target: {e}"))?;
+        Ok(Self { arch })
+    }
+}
+
+impl Display for CompileTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "sm_{}", self.arch)
+    }
+}
+
+/// Kernel compiled ahead of time for a [target](CompileTarget).
+#[derive(Debug, Clone)]
+pub struct PtxKernel {
+    /// The name of the kernel entrypoint.
+    pub entrypoint_name: String,
+    /// The cube dimension the kernel was compiled for.
+    pub cube_dim: CubeDim,
+    /// The amount of dynamic shared memory to allocate when launching the kernel, in bytes.
+    pub dynamic_shared_memory_size: usize,
+    /// The generated PTX.
+    pub ptx: String,
+}
+
+/// Compile the kernel to PTX for the given target, without using any local device.
+pub fn compile_for_target(
+    kernel: Box<dyn CubeTask<CudaCompiler>>,
+    target: CompileTarget,
+    mode: ExecutionMode,
+) -> Result<PtxKernel, CompilationError> {
+    let compiled = kernel.compile(&mut Default::default(), &target.compilation_options(), mode)?;
+    let compute_kernel = compiled.repr.as_ref().unwrap();
+    let fast_math = compute_kernel.flags.inst_fast_math;
+    let device_debug = compute_kernel.flags.debug_symbols;
+    let dynamic_shared_memory_size = compute_kernel.dynamic_shared_memory_size();
+
+    let ptx = compile_ptx(
+        &compiled.source,
+        &nvrtc_options(target.arch, fast_math, device_debug),
+    )?;
+    let ptx = unsafe { CStr::from_ptr(ptx.as_ptr()) };
+
+    Ok(PtxKernel {
+        entrypoint_name: compiled.entrypoint_name,
+        cube_dim: compiled.cube_dim,
+        dynamic_shared_memory_size,
+        ptx: ptx.to_string_lossy().into_owned(),
+    })
+}
@@ -0,0 +1,35 @@
+//! A fault corrupts the CUDA context shared by every client of the device, so this test lives in
+//! its own binary to keep it from breaking the other tests.
+
+use cubecl_core as cubecl;
+use cubecl_core::{prelude::*, server::DeviceError};
+use cubecl_cuda::CudaRuntime;
+
+#[cube(launch_unchecked)]
+fn kernel_out_of_bounds(output: &mut Array<u32>) {
+    output[UNIT_POS + 0x7FFF_FFF0] = 1;
+}
+
+#[test]
+fn out_of_bounds_access_reports_fault() {
+    let client = CudaRuntime::client(&Default::default());
+    let output = client.empty(32 * core::mem::size_of::<u32>());
+
+    unsafe {
+        kernel_out_of_bounds::launch_unchecked::<CudaRuntime>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new_1d(32),
+            ArrayArg::from_raw_parts::<u32>(&output, 32, 1),
+        );
+    }
+    let result = cubecl_core::future::block_on(client.try_sync());
+
+    match result {
+        Err(DeviceError::Faulted { code, kernel }) => {
+            assert_eq!(code, "CUDA_ERROR_ILLEGAL_ADDRESS");
+            assert!(kernel.unwrap().contains("kernel_out_of_bounds"));
+        }
+        result => panic!("expected a fault, got {result:?}"),
+    }
+}
@@ -8,12 +8,18 @@ use super::storage::HipStorage;
 use super::{HipResource, uninit_vec};
 use cubecl_common::benchmark::ProfileDuration;
 use cubecl_core::compute::DebugInformation;
-use cubecl_core::{Feature, server::Bindings};
+use cubecl_core::{
+    Feature,
+    server::{
+        Bindings, CompilationError, CompileWarning, DeviceError, KernelResources, LaunchInfo,
+        LaunchObserver, LaunchObserverSlot,
+    },
+};
 use cubecl_core::{KernelId, prelude::*};
 use cubecl_hip_sys::{HIP_SUCCESS, hiprtcResult_HIPRTC_SUCCESS};
 use cubecl_runtime::debug::{DebugLogger, ProfileLevel};
 use cubecl_runtime::kernel_timestamps::KernelTimestamps;
-use cubecl_runtime::memory_management::MemoryUsage;
+use cubecl_runtime::memory_management::{AllocationError, MemoryUsage};
 use cubecl_runtime::storage::BindingResource;
 use cubecl_runtime::{
     memory_management::MemoryManagement,
@@ -43,6 +49,7 @@ pub(crate) struct HipContext {
     compilation_options: CompilationOptions,
     #[cfg(feature = "compilation-cache")]
     compilation_cache: Cache<String, CompilationCacheEntry>,
+    launch_observer: LaunchObserverSlot,
 }
 
 #[cfg(feature = "compilation-cache")]
@@ -201,6 +208,22 @@ impl ComputeServer for HipServer {
         server::Handle::new(handle, None, None, size as u64)
     }
 
+    fn try_empty(&mut self, size: usize) -> Result<server::Handle, AllocationError> {
+        let ctx = self.get_context();
+        let handle = ctx.memory_management.try_reserve(size as u64, None)?;
+        Ok(server::Handle::new(handle, None, None, size as u64))
+    }
+
+    unsafe fn register_external(&mut self, ptr: u64, size: u64) -> server::Handle {
+        let ctx = self.get_context();
+        let storage = ctx
+            .memory_management
+            .storage()
+            .register_external(ptr as cubecl_hip_sys::hipDeviceptr_t, size);
+        let handle = ctx.memory_management.register_external(storage);
+        server::Handle::new(handle, None, None, size)
+    }
+
     fn empty_tensor(&mut self, shape: &[usize], elem_size: usize) -> (server::Handle, Vec<usize>) {
         let strides = contiguous_strides(shape);
         let size = shape.iter().product::<usize>() * elem_size;
@@ -208,6 +231,75 @@ impl ComputeServer for HipServer {
         (handle, strides)
     }
 
+    fn fill(&mut self, binding: server::Binding, pattern: u32) {
+        let ctx = self.get_context();
+        let resource = find_resource(ctx, binding);
+        let size = resource.size as usize;
+
+        unsafe {
+            let status = if pattern == 0 {
+                cubecl_hip_sys::hipMemsetD8Async(resource.ptr, 0, size, ctx.stream)
+            } else {
+                assert_eq!(
+                    size % 4,
+                    0,
+                    "Fill pattern must evenly divide the buffer size"
+                );
+                cubecl_hip_sys::hipMemsetD32Async(
+                    resource.ptr,
+                    pattern as i32,
+                    size / 4,
+                    ctx.stream,
+                )
+            };
+            assert_eq!(status, HIP_SUCCESS, "Should fill device memory");
+        }
+    }
+
+    fn write(&mut self, binding: server::Binding, data: &[u8]) {
+        let ctx = self.get_context();
+        let resource = find_resource(ctx, binding);
+        assert_eq!(data.len(), resource.size as usize);
+
+        unsafe {
+            let status = cubecl_hip_sys::hipMemcpyHtoDAsync(
+                resource.ptr,
+                data as *const _ as *mut _,
+                data.len(),
+                ctx.stream,
+            );
+            assert_eq!(status, HIP_SUCCESS, "Should send data to device");
+        }
+    }
+
+    fn compile(
+        &mut self,
+        kernel: Self::Kernel,
+        mode: ExecutionMode,
+    ) -> Result<Vec<CompileWarning>, CompilationError> {
+        let mut kernel_id = kernel.id();
+        kernel_id.mode(mode);
+
+        let (ctx, logger) = self.get_context_with_logger();
+
+        if !ctx.module_names.contains_key(&kernel_id) {
+            ctx.compile_kernel(&kernel_id, kernel, logger, mode)?;
+        }
+
+        // Warnings of the HIP compiler aren't captured yet.
+        Ok(Vec::new())
+    }
+
+    fn kernel_resources(
+        &mut self,
+        kernel: Self::Kernel,
+        mode: ExecutionMode,
+    ) -> Result<KernelResources, CompilationError> {
+        // The registers of HIP kernels aren't queried yet.
+        self.compile(kernel, mode)
+            .map(|_| KernelResources::default())
+    }
+
     unsafe fn execute(
         &mut self,
         kernel: Self::Kernel,
@@ -241,6 +333,24 @@ impl ComputeServer for HipServer {
             }
         };
 
+        let kernel_name = kernel.name();
+        let (ctx, logger) = self.get_context_with_logger();
+
+        let compiled = !ctx.module_names.contains_key(&kernel_id);
+        if compiled {
+            ctx.compile_kernel(&kernel_id, kernel, logger, mode)
+                .unwrap_or_else(|err| panic!("{err}"));
+        }
+
+        ctx.launch_observer.observe(|| LaunchInfo {
+            name: kernel_name,
+            id: kernel_id.stable_format(),
+            cube_count: CubeCount::Static(count.0, count.1, count.2),
+            cube_dim: ctx.module_names[&kernel_id].cube_dim,
+            bindings: &bindings,
+            compiled,
+        });
+
         let Bindings {
             buffers,
             metadata,
@@ -252,11 +362,7 @@ impl ComputeServer for HipServer {
         let info = self.create(bytemuck::cast_slice(&metadata.data));
         let scalars: Vec<_> = scalars.values().map(|s| self.create(s.data())).collect();
 
-        let (ctx, logger) = self.get_context_with_logger();
-
-        if !ctx.module_names.contains_key(&kernel_id) {
-            ctx.compile_kernel(&kernel_id, kernel, logger, mode);
-        }
+        let ctx = self.get_context();
 
         let mut resources: Vec<_> = buffers.into_iter().map(|b| find_resource(ctx, b)).collect();
         resources.push(find_resource(ctx, info.clone().binding()));
@@ -289,6 +395,15 @@ impl ComputeServer for HipServer {
         }
     }
 
+    fn set_launch_observer(&mut self, observer: Option<LaunchObserver>) {
+        self.ctx.launch_observer.set(observer);
+    }
+
+    // Kernels are already enqueued in order on a single stream, without any submission.
+    fn start_batch(&mut self) {}
+
+    fn end_batch(&mut self) {}
+
     fn flush(&mut self) {}
 
     fn sync(&mut self) -> impl Future<Output = ()> + 'static {
@@ -296,6 +411,45 @@ impl ComputeServer for HipServer {
         self.sync_stream_async()
     }
 
+    fn try_sync(&mut self) -> impl Future<Output = Result<(), DeviceError>> + 'static {
+        let sync = self.sync();
+        async move {
+            sync.await;
+            Ok(())
+        }
+    }
+
+    fn poll(&mut self) -> bool {
+        let ctx = self.get_context();
+        let status = unsafe { cubecl_hip_sys::hipStreamQuery(ctx.stream) };
+
+        match status {
+            HIP_SUCCESS => true,
+            cubecl_hip_sys::hipError_t_hipErrorNotReady => false,
+            status => panic!("Failed to query the stream: {status}"),
+        }
+    }
+
+    fn on_complete(&mut self, callback: server::HostCallback) {
+        // Called by the runtime thread once the work enqueued before it on the stream is done. A
+        // panic can't unwind into the runtime, so it's caught and logged instead.
+        unsafe extern "C" fn invoke(data: *mut std::ffi::c_void) {
+            let callback = unsafe { Box::from_raw(data as *mut server::HostCallback) };
+            if std::panic::catch_unwind(std::panic::AssertUnwindSafe(callback)).is_err() {
+                log::error!("A host callback panicked, the panic was caught");
+            }
+        }
+
+        let ctx = self.get_context();
+        let data = Box::into_raw(Box::new(callback)) as *mut std::ffi::c_void;
+        let status = unsafe { cubecl_hip_sys::hipLaunchHostFunc(ctx.stream, Some(invoke), data) };
+
+        if status != HIP_SUCCESS {
+            drop(unsafe { Box::from_raw(data as *mut server::HostCallback) });
+            panic!("Failed to enqueue the host callback: {status}");
+        }
+    }
+
     fn start_profile(&mut self) {
         cubecl_common::future::block_on(self.sync());
         self.ctx.timestamps.start();
@@ -307,6 +461,33 @@ impl ComputeServer for HipServer {
         self.ctx.timestamps.stop()
     }
 
+    fn read_into(&mut self, binding: server::Binding, destination: &mut [u8]) {
+        let ctx = self.get_context();
+        let resource = ctx
+            .memory_management
+            .get_resource(binding.memory, binding.offset_start, binding.offset_end)
+            .expect("Failed to find resource");
+
+        assert_eq!(
+            destination.len(),
+            resource.size as usize,
+            "Destination of {} bytes doesn't match the {} bytes of the binding",
+            destination.len(),
+            resource.size
+        );
+
+        unsafe {
+            let status = cubecl_hip_sys::hipMemcpyDtoHAsync(
+                destination.as_mut_ptr() as *mut _,
+                resource.ptr,
+                resource.size as usize,
+                ctx.stream,
+            );
+            assert_eq!(status, HIP_SUCCESS, "Should copy data from device to host");
+        };
+        ctx.sync();
+    }
+
     fn get_resource(&mut self, binding: server::Binding) -> BindingResource<HipResource> {
         let ctx = self.get_context();
         BindingResource::new(
@@ -338,6 +519,7 @@ impl HipContext {
             compilation_options,
             #[cfg(feature = "compilation-cache")]
             compilation_cache: Cache::new("hip/compilation", CacheOption::default()),
+            launch_observer: LaunchObserverSlot::default(),
         }
     }
 
@@ -370,7 +552,7 @@ impl HipContext {
         cube_kernel: Box<dyn CubeTask<HipCompiler>>,
         logger: &mut DebugLogger,
         mode: ExecutionMode,
-    ) {
+    ) -> Result<(), CompilationError> {
         #[cfg(feature = "compilation-cache")]
         let name = kernel_id.stable_format();
         #[cfg(feature = "compilation-cache")]
@@ -386,13 +568,13 @@ impl HipContext {
                     z: entry.cube_dim.2,
                 },
             );
-            return;
+            return Ok(());
         }
 
         // CubeCL compilation
         // jitc = just-in-time compiled
         let mut jitc_kernel =
-            cube_kernel.compile(&mut Default::default(), &self.compilation_options, mode);
+            cube_kernel.compile(&mut Default::default(), &self.compilation_options, mode)?;
 
         if logger.is_activated() {
             jitc_kernel.debug_info = Some(DebugInformation::new("cpp", kernel_id.clone()));
@@ -448,7 +630,7 @@ impl HipContext {
                     "Should retrieve the compilation log contents"
                 );
                 let log = CStr::from_ptr(log_buffer.as_ptr());
-                let mut message = "[Compilation Error] ".to_string();
+                let mut message = String::new();
                 if log_size > 0 {
                     for line in log.to_string_lossy().split('\n') {
                         if !line.is_empty() {
@@ -458,7 +640,8 @@ impl HipContext {
                 } else {
                     message += "\n No compilation logs found!";
                 }
-                panic!("{message}\n[Source]  \n{}", jitc_kernel.source);
+                message += format!("\n[Source]  \n{}", jitc_kernel.source).as_str();
+                return Err(CompilationError::BackendError(message));
             }
             assert_eq!(
                 status, hiprtcResult_HIPRTC_SUCCESS,
@@ -505,6 +688,8 @@ impl HipContext {
             jitc_kernel.entrypoint_name,
             jitc_kernel.cube_dim,
         );
+
+        Ok(())
     }
 
     fn load_compiled_binary(
@@ -1,11 +1,14 @@
 use cubecl_hip_sys::HIP_SUCCESS;
-use cubecl_runtime::storage::{ComputeStorage, StorageHandle, StorageId, StorageUtilization};
-use std::collections::HashMap;
+use cubecl_runtime::storage::{
+    ComputeStorage, DeviceAddress, StorageHandle, StorageId, StorageUtilization,
+};
+use std::collections::{HashMap, HashSet};
 
 /// Buffer storage for HIP.
 pub struct HipStorage {
     memory: HashMap<StorageId, cubecl_hip_sys::hipDeviceptr_t>,
     deallocations: Vec<StorageId>,
+    external: HashSet<StorageId>,
     stream: cubecl_hip_sys::hipStream_t,
     activate_slices: HashMap<ActiveResource, cubecl_hip_sys::hipDeviceptr_t>,
 }
@@ -30,15 +33,32 @@ impl HipStorage {
         Self {
             memory: HashMap::new(),
             deallocations: Vec::new(),
+            external: HashSet::new(),
             stream,
             activate_slices: HashMap::new(),
         }
     }
 
+    /// Registers `size` bytes of memory allocated outside of the storage at `ptr`. The memory is
+    /// never freed by the storage, deallocating it only forgets about it.
+    pub fn register_external(
+        &mut self,
+        ptr: cubecl_hip_sys::hipDeviceptr_t,
+        size: u64,
+    ) -> StorageHandle {
+        let id = StorageId::new();
+        self.memory.insert(id, ptr);
+        self.external.insert(id);
+        StorageHandle::new(id, StorageUtilization { offset: 0, size })
+    }
+
     /// Actually deallocates buffers tagged to be deallocated.
     pub fn perform_deallocations(&mut self) {
         for id in self.deallocations.drain(..) {
             if let Some(ptr) = self.memory.remove(&id) {
+                if self.external.remove(&id) {
+                    continue;
+                }
                 unsafe {
                     cubecl_hip_sys::hipFreeAsync(ptr, self.stream);
                 }
@@ -65,6 +85,12 @@ pub struct HipResource {
 
 unsafe impl Send for HipResource {}
 
+impl DeviceAddress for HipResource {
+    fn device_ptr(&self) -> u64 {
+        self.ptr as u64
+    }
+}
+
 impl ComputeStorage for HipStorage {
     const ALIGNMENT: u64 = 32;
 
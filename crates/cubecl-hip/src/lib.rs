@@ -17,7 +17,10 @@ pub use runtime::HipRuntime;
 #[cfg(feature = "wmma-intrinsics")]
 pub(crate) type HipWmmaCompiler = cubecl_cpp::hip::mma::WmmaIntrinsicCompiler;
 #[cfg(target_os = "linux")]
-#[cfg(not(feature = "wmma-intrinsics"))]
+#[cfg(all(feature = "mfma-intrinsics", not(feature = "wmma-intrinsics")))]
+pub(crate) type HipWmmaCompiler = cubecl_cpp::hip::mma::MfmaIntrinsicCompiler;
+#[cfg(target_os = "linux")]
+#[cfg(not(any(feature = "wmma-intrinsics", feature = "mfma-intrinsics")))]
 pub(crate) type HipWmmaCompiler = cubecl_cpp::hip::mma::RocWmmaCompiler;
 
 #[cfg(target_os = "linux")]
@@ -27,11 +30,16 @@ mod tests {
     pub type TestRuntime = crate::HipRuntime;
 
     cubecl_core::testgen_all!(f32: [f16, f32], i32: [i16, i32], u32: [u16, u32]);
+    cubecl_core::testgen_compilation!();
     cubecl_std::testgen!();
     cubecl_linalg::testgen_matmul_tiling2d!([f16, f32]);
     cubecl_linalg::testgen_matmul_accelerated!([f16]);
     cubecl_linalg::testgen_matmul_simple!([f16, f32]);
     cubecl_linalg::testgen_tensor_identity!([f32, u32]);
+    cubecl_linalg::testgen_tensor_transpose!([f32, u32]);
+    cubecl_linalg::testgen_tensor_reinterpret!();
+    cubecl_linalg::testgen_tensor_contiguous!();
+    cubecl_linalg::testgen_tensor_elemwise!();
     cubecl_reduce::testgen_reduce!([f16, bf16, f32, f64]);
     cubecl_reduce::testgen_shared_sum!([f32]);
 }
@@ -9,12 +9,12 @@ use cubecl_cpp::{
 };
 
 use cubecl_core::{
-    AtomicFeature, CubeDim, DeviceId, Feature, MemoryConfiguration, Runtime,
+    AtomicFeature, CubeDim, DeviceId, Feature, MemoryConfiguration, OutOfMemoryPolicy, Runtime,
     ir::{Elem, FloatKind},
 };
 use cubecl_hip_sys::HIP_SUCCESS;
 use cubecl_runtime::{
-    ComputeRuntime, DeviceProperties,
+    BackendInfo, ComputeRuntime, DeviceProperties,
     channel::MutexComputeChannel,
     client::ComputeClient,
     memory_management::{HardwareProperties, MemoryDeviceProperties, MemoryManagement},
@@ -32,6 +32,14 @@ use crate::{
 pub struct RuntimeOptions {
     /// Configures the memory management.
     pub memory_config: MemoryConfiguration,
+    /// Maximum number of bytes of device memory to reserve, `None` for no limit.
+    ///
+    /// Allocations beyond this limit fail, after releasing unused memory and retrying with the
+    /// default [policy](Self::on_oom). See
+    /// [try_empty](cubecl_runtime::client::ComputeClient::try_empty) to handle the failure.
+    pub memory_limit: Option<u64>,
+    /// What to do when an allocation would exceed the memory limit, unused without a limit.
+    pub on_oom: OutOfMemoryPolicy,
 }
 
 #[derive(Debug)]
@@ -59,6 +67,8 @@ fn create_client<M: DialectWmmaCompiler<HipDialect<M>>>(
     #[allow(unused_assignments)]
     let mut prop_max_threads = 0;
     let mut max_cube_dim = CubeDim::new_single();
+    #[allow(unused_assignments)]
+    let mut prop_name = String::new();
     unsafe {
         let mut ll_device_props = MaybeUninit::uninit();
         let status = cubecl_hip_sys::hipGetDevicePropertiesR0600(
@@ -68,6 +78,9 @@ fn create_client<M: DialectWmmaCompiler<HipDialect<M>>>(
         assert_eq!(status, HIP_SUCCESS, "Should get device properties");
         let ll_device_props = ll_device_props.assume_init();
         prop_warp_size = ll_device_props.warpSize;
+        prop_name = CStr::from_ptr(ll_device_props.name.as_ptr())
+            .to_string_lossy()
+            .into_owned();
         prop_arch_name = CStr::from_ptr(ll_device_props.gcnArchName.as_ptr())
             .to_str()
             .unwrap();
@@ -129,9 +142,14 @@ fn create_client<M: DialectWmmaCompiler<HipDialect<M>>>(
         max_cube_dim,
         num_streaming_multiprocessors: None,
         num_tensor_cores: None,
+        peak_flops: None,
+        peak_memory_bandwidth: None,
+        max_registers_per_cube: None,
     };
-    let memory_management =
+    let mut memory_management =
         MemoryManagement::from_configuration(storage, &mem_properties, options.memory_config);
+    memory_management.set_memory_limit(options.memory_limit);
+    memory_management.set_out_of_memory_policy(options.on_oom);
     let mut device_props = DeviceProperties::new(
         &[Feature::Plane],
         mem_properties,
@@ -139,6 +157,7 @@ fn create_client<M: DialectWmmaCompiler<HipDialect<M>>>(
         cubecl_runtime::TimeMeasurement::System,
     );
     register_supported_types(&mut device_props);
+    device_props.set_backend_info(backend_info(prop_name));
     // Not sure if there's a good way to check for support on HIP
     device_props.register_feature(Feature::Type(Elem::AtomicFloat(FloatKind::F64)));
     // TODO look into unsafeAtomicAdd (https://github.com/ROCm/HIP/issues/3573120)
@@ -157,12 +176,40 @@ fn create_client<M: DialectWmmaCompiler<HipDialect<M>>>(
         warp_size: arch.warp_size(),
         grid_constants: false,
         supports_clusters: false,
+        max_shared_memory_size: Some(prop_max_shared_memory_size),
+        max_static_shared_memory_size: None,
+        max_bindings: None,
     };
     let hip_ctx = HipContext::new(memory_management, comp_opts, stream);
     let server = HipServer::new(hip_ctx);
     ComputeClient::new(MutexComputeChannel::new(server), device_props, ())
 }
 
+fn backend_info(device_name: String) -> BackendInfo {
+    let (mut driver_version, mut runtime_version) = (0, 0);
+    let driver_queried =
+        unsafe { cubecl_hip_sys::hipDriverGetVersion(&mut driver_version) } == HIP_SUCCESS;
+    let runtime_queried =
+        unsafe { cubecl_hip_sys::hipRuntimeGetVersion(&mut runtime_version) } == HIP_SUCCESS;
+    // Encoded as `10_000_000 * major + 100_000 * minor + patch`.
+    let format_version = |queried: bool, version: i32| match queried {
+        true => format!(
+            "{}.{}.{}",
+            version / 10_000_000,
+            (version / 100_000) % 100,
+            version % 100_000
+        ),
+        false => String::new(),
+    };
+
+    BackendInfo {
+        name: "hip".to_string(),
+        device_name,
+        api_version: format_version(runtime_queried, runtime_version),
+        driver_version: format_version(driver_queried, driver_version),
+    }
+}
+
 impl Runtime for HipRuntime {
     type Compiler = HipCompiler;
     type Server = HipServer;
@@ -30,6 +30,8 @@ pub enum Arithmetic {
     Ceil(UnaryOperator),
     Erf(UnaryOperator),
     Recip(UnaryOperator),
+    RecipFast(UnaryOperator),
+    RsqrtFast(UnaryOperator),
     Clamp(ClampOperator),
     Modulo(BinaryOperator),
     Neg(UnaryOperator),
@@ -68,6 +70,8 @@ impl Display for Arithmetic {
             Arithmetic::Ceil(op) => write!(f, "{}.ceil()", op.input),
             Arithmetic::Erf(op) => write!(f, "{}.erf()", op.input),
             Arithmetic::Recip(op) => write!(f, "{}.recip()", op.input),
+            Arithmetic::RecipFast(op) => write!(f, "{}.recip_fast()", op.input),
+            Arithmetic::RsqrtFast(op) => write!(f, "{}.rsqrt_fast()", op.input),
             Arithmetic::Clamp(op) => {
                 write!(f, "{}.clamp({}, {})", op.input, op.min_value, op.max_value)
             }
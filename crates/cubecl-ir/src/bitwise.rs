@@ -24,6 +24,8 @@ pub enum Bitwise {
     LeadingZeros(UnaryOperator),
     /// Find least significant bit set
     FindFirstSet(UnaryOperator),
+    /// Count trailing zeros, the bit width of the input when it's zero
+    TrailingZeros(UnaryOperator),
 }
 
 impl Display for Bitwise {
@@ -39,6 +41,7 @@ impl Display for Bitwise {
             Bitwise::BitwiseNot(op) => write!(f, "!{}", op.input),
             Bitwise::LeadingZeros(op) => write!(f, "{}.leading_zeros()", op.input),
             Bitwise::FindFirstSet(op) => write!(f, "{}.find_first_set()", op.input),
+            Bitwise::TrailingZeros(op) => write!(f, "{}.trailing_zeros()", op.input),
         }
     }
 }
@@ -1,9 +1,9 @@
-use alloc::{boxed::Box, format, vec::Vec};
+use alloc::{boxed::Box, format, string::String, vec::Vec};
 use core::fmt::Display;
 
 use crate::OperationReflect;
 
-use super::{Elem, Item, OperationCode, Scope, UIntKind, Variable};
+use super::{Elem, ExpandElement, Item, OperationCode, Scope, UIntKind, Variable};
 use crate::TypeHash;
 
 /// All branching types.
@@ -22,6 +22,8 @@ pub enum Branch {
     RangeLoop(Box<RangeLoop>),
     /// A loop.
     Loop(Box<Loop>),
+    /// A call to a helper that shouldn't be inlined.
+    Call(Box<FunctionCall>),
     /// A return statement.
     Return,
     /// A break statement.
@@ -60,6 +62,10 @@ impl Display for Branch {
                 range_loop.end
             ),
             Branch::Loop(_) => write!(f, "loop{{}}"),
+            Branch::Call(call) => match call.out {
+                Some(out) => write!(f, "{out} = call {}()", call.name),
+                None => write!(f, "call {}()", call.name),
+            },
             Branch::Return => write!(f, "return"),
             Branch::Break => write!(f, "break"),
         }
@@ -111,6 +117,23 @@ pub struct Loop {
     pub scope: Scope,
 }
 
+/// A call to a helper marked `noinline`.
+///
+/// The body reads and writes the variables of the caller directly, only its return value is
+/// copied out. Backends that support device functions compile it to its own function that is
+/// never inlined, the others expand it in place.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, TypeHash, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub struct FunctionCall {
+    pub name: String,
+    pub scope: Scope,
+    /// The value returned by the body, declared in `scope`.
+    pub ret: Option<Variable>,
+    /// The variable of the caller receiving `ret`.
+    pub out: Option<Variable>,
+}
+
 impl If {
     /// Registers an if statement to the given scope.
     pub fn register<F: Fn(&mut Scope)>(parent_scope: &mut Scope, cond: Variable, func: F) {
@@ -187,6 +210,29 @@ impl Loop {
     }
 }
 
+impl FunctionCall {
+    /// Registers a call to the given scope, returning the variable receiving the value returned by
+    /// the body.
+    pub fn register<F: FnOnce(&mut Scope) -> Option<Variable>>(
+        parent_scope: &mut Scope,
+        name: impl Into<String>,
+        func: F,
+    ) -> Option<ExpandElement> {
+        let mut scope = parent_scope.child();
+
+        let ret = func(&mut scope);
+        let out = ret.map(|ret| parent_scope.create_local(ret.item));
+
+        parent_scope.register(Branch::Call(Box::new(Self {
+            name: name.into(),
+            scope,
+            ret,
+            out: out.as_deref().copied(),
+        })));
+        out
+    }
+}
+
 #[allow(missing_docs)]
 pub struct UnrolledRangeLoop;
 
@@ -54,6 +54,17 @@ impl Elem {
     ///
     /// The output will have the same type as the element.
     pub fn constant_from_f64(&self, val: f64) -> Variable {
+        // The bounds of `f64` stand for the bounds of a float of unknown kind, like `Float::MAX` in
+        // kernels expanded for any float, so they narrow to the bounds of the element type instead
+        // of overflowing to infinity.
+        if matches!(self, Elem::Float(_) | Elem::AtomicFloat(_)) {
+            if val == f64::MAX {
+                return self.max_variable();
+            } else if val == f64::MIN {
+                return self.min_variable();
+            }
+        }
+
         Variable::constant(match self {
             Elem::Float(kind) => ConstantScalarValue::Float(val, *kind),
             Elem::Int(kind) => ConstantScalarValue::Int(val as i64, *kind),
@@ -148,6 +159,34 @@ impl Elem {
         }
     }
 
+    /// Alignment in bytes of the element type, the one of the matching Rust type when a buffer
+    /// of elements is laid out by the host.
+    pub const fn align(&self) -> usize {
+        match self {
+            Elem::Float(kind) | Elem::AtomicFloat(kind) => match kind {
+                FloatKind::F16 => core::mem::align_of::<half::f16>(),
+                FloatKind::BF16 => core::mem::align_of::<half::bf16>(),
+                FloatKind::F32 => core::mem::align_of::<f32>(),
+                FloatKind::F64 => core::mem::align_of::<f64>(),
+                FloatKind::Flex32 => core::mem::align_of::<f32>(),
+                FloatKind::TF32 => core::mem::align_of::<f32>(),
+            },
+            Elem::Int(kind) | Elem::AtomicInt(kind) => match kind {
+                IntKind::I8 => core::mem::align_of::<i8>(),
+                IntKind::I16 => core::mem::align_of::<i16>(),
+                IntKind::I32 => core::mem::align_of::<i32>(),
+                IntKind::I64 => core::mem::align_of::<i64>(),
+            },
+            Elem::UInt(kind) | Elem::AtomicUInt(kind) => match kind {
+                UIntKind::U8 => core::mem::align_of::<u8>(),
+                UIntKind::U16 => core::mem::align_of::<u16>(),
+                UIntKind::U32 => core::mem::align_of::<u32>(),
+                UIntKind::U64 => core::mem::align_of::<u64>(),
+            },
+            Elem::Bool => core::mem::align_of::<bool>(),
+        }
+    }
+
     pub fn is_atomic(&self) -> bool {
         matches!(
             self,
@@ -30,6 +30,13 @@ pub enum NonSemantic {
     },
     /// Insert a comment into the compiled source
     Comment { content: String },
+    /// Hint that `bytes` bytes starting at `list[index]` will be read soon, so the hardware can
+    /// start pulling them into cache. Backends without a prefetch instruction ignore it.
+    Prefetch {
+        list: Variable,
+        index: Variable,
+        bytes: u32,
+    },
 }
 
 impl OperationReflect for NonSemantic {
@@ -50,6 +57,9 @@ impl Display for NonSemantic {
                 write!(f, "print({format_string}, {})", fmt_vararg(args))
             }
             NonSemantic::Comment { content } => write!(f, "//{content}"),
+            NonSemantic::Prefetch { list, index, bytes } => {
+                write!(f, "prefetch({list}[{index}], {bytes})")
+            }
             // Scopes don't have meaning to the user
             _ => Ok(()),
         }
@@ -123,6 +123,12 @@ impl Display for Instruction {
             Operation::Operator(Operator::Cast(op)) => {
                 write!(f, "{} = cast<{}>({})", self.out(), self.item(), op.input)
             }
+            Operation::Operator(Operator::CastRte(op)) => {
+                write!(f, "{} = cast_rte<{}>({})", self.out(), self.item(), op.input)
+            }
+            Operation::Operator(Operator::CastRtz(op)) => {
+                write!(f, "{} = cast_rtz<{}>({})", self.out(), self.item(), op.input)
+            }
             Operation::Operator(Operator::Reinterpret(op)) => {
                 write!(f, "{} = bitcast<{}>({})", self.out(), self.item(), op.input)
             }
@@ -33,6 +33,12 @@ pub enum Operator {
     Not(UnaryOperator),
     #[operation(pure)]
     Cast(UnaryOperator),
+    /// Cast rounding to the nearest representable value, with ties to even.
+    #[operation(pure)]
+    CastRte(UnaryOperator),
+    /// Cast rounding toward zero.
+    #[operation(pure)]
+    CastRtz(UnaryOperator),
     #[operation(pure)]
     Reinterpret(UnaryOperator),
     /// A select statement/ternary
@@ -78,6 +84,8 @@ impl Display for Operator {
                 write!(f, "{} ? {} : {}", op.cond, op.then, op.or_else)
             }
             Operator::Cast(op) => write!(f, "cast({})", op.input),
+            Operator::CastRte(op) => write!(f, "cast_rte({})", op.input),
+            Operator::CastRtz(op) => write!(f, "cast_rtz({})", op.input),
             Operator::Reinterpret(op) => write!(f, "reinterpret({})", op.input),
         }
     }
@@ -1,8 +1,8 @@
 use core::fmt::Display;
 
-use crate::OperationReflect;
+use crate::{OperationArgs, OperationReflect};
 
-use super::{BinaryOperator, UnaryOperator};
+use super::{BinaryOperator, UnaryOperator, Variable};
 use crate::TypeHash;
 
 /// All plane operations.
@@ -18,6 +18,8 @@ pub enum Plane {
     Any(UnaryOperator),
     Ballot(UnaryOperator),
     Broadcast(BinaryOperator),
+    ShuffleUp(BinaryOperator),
+    ShuffleDown(BinaryOperator),
     Sum(UnaryOperator),
     InclusiveSum(UnaryOperator),
     ExclusiveSum(UnaryOperator),
@@ -26,6 +28,24 @@ pub enum Plane {
     ExclusiveProd(UnaryOperator),
     Min(UnaryOperator),
     Max(UnaryOperator),
+    PartitionSum(PlanePartitionOperator),
+    PartitionProd(PlanePartitionOperator),
+    PartitionMin(PlanePartitionOperator),
+    PartitionMax(PlanePartitionOperator),
+    PartitionInclusiveSum(PlanePartitionOperator),
+    PartitionExclusiveSum(PlanePartitionOperator),
+    PartitionInclusiveProd(PlanePartitionOperator),
+    PartitionExclusiveProd(PlanePartitionOperator),
+}
+
+/// Reduction or scan restricted to each aligned partition of `partition_size` consecutive units
+/// of the plane. The partition size must be a power of two dividing the plane size.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, TypeHash, PartialEq, Eq, Hash, OperationArgs)]
+#[allow(missing_docs)]
+pub struct PlanePartitionOperator {
+    pub input: Variable,
+    pub partition_size: u32,
 }
 
 impl Display for Plane {
@@ -38,6 +58,8 @@ impl Display for Plane {
             Plane::Broadcast(op) => {
                 writeln!(f, "plane_broadcast({}, {})", op.lhs, op.rhs)
             }
+            Plane::ShuffleUp(op) => writeln!(f, "plane_shuffle_up({}, {})", op.lhs, op.rhs),
+            Plane::ShuffleDown(op) => writeln!(f, "plane_shuffle_down({}, {})", op.lhs, op.rhs),
             Plane::Sum(op) => writeln!(f, "plane_sum({})", op.input),
             Plane::InclusiveSum(op) => writeln!(f, "plane_inclusive_sum({})", op.input),
             Plane::ExclusiveSum(op) => writeln!(f, "plane_exclusive_sum({})", op.input),
@@ -46,6 +68,62 @@ impl Display for Plane {
             Plane::ExclusiveProd(op) => writeln!(f, "plane_exclusive_product({})", op.input),
             Plane::Min(op) => writeln!(f, "plane_min({})", op.input),
             Plane::Max(op) => writeln!(f, "plane_max({})", op.input),
+            Plane::PartitionSum(op) => {
+                writeln!(
+                    f,
+                    "plane_partition_sum({}, {})",
+                    op.input, op.partition_size
+                )
+            }
+            Plane::PartitionProd(op) => {
+                writeln!(
+                    f,
+                    "plane_partition_product({}, {})",
+                    op.input, op.partition_size
+                )
+            }
+            Plane::PartitionMin(op) => {
+                writeln!(
+                    f,
+                    "plane_partition_min({}, {})",
+                    op.input, op.partition_size
+                )
+            }
+            Plane::PartitionMax(op) => {
+                writeln!(
+                    f,
+                    "plane_partition_max({}, {})",
+                    op.input, op.partition_size
+                )
+            }
+            Plane::PartitionInclusiveSum(op) => {
+                writeln!(
+                    f,
+                    "plane_partition_inclusive_sum({}, {})",
+                    op.input, op.partition_size
+                )
+            }
+            Plane::PartitionExclusiveSum(op) => {
+                writeln!(
+                    f,
+                    "plane_partition_exclusive_sum({}, {})",
+                    op.input, op.partition_size
+                )
+            }
+            Plane::PartitionInclusiveProd(op) => {
+                writeln!(
+                    f,
+                    "plane_partition_inclusive_product({}, {})",
+                    op.input, op.partition_size
+                )
+            }
+            Plane::PartitionExclusiveProd(op) => {
+                writeln!(
+                    f,
+                    "plane_partition_exclusive_product({}, {})",
+                    op.input, op.partition_size
+                )
+            }
         }
     }
 }
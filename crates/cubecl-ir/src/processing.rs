@@ -105,6 +105,12 @@ impl ScopeProcessing {
                     Arithmetic::Recip(op) => {
                         sanitize_constant_scalar_ref_var(&mut op.input, &inst.out.unwrap());
                     }
+                    Arithmetic::RecipFast(op) => {
+                        sanitize_constant_scalar_ref_var(&mut op.input, &inst.out.unwrap());
+                    }
+                    Arithmetic::RsqrtFast(op) => {
+                        sanitize_constant_scalar_ref_var(&mut op.input, &inst.out.unwrap());
+                    }
                     Arithmetic::Clamp(op) => {
                         sanitize_constant_scalar_ref_var(&mut op.input, &inst.out.unwrap());
                         sanitize_constant_scalar_ref_var(&mut op.min_value, &inst.out.unwrap());
@@ -179,7 +185,10 @@ impl ScopeProcessing {
                         sanitize_constant_scalar_ref_var(&mut op.lhs, &inst.out.unwrap());
                         sanitize_constant_scalar_ref_var(&mut op.rhs, &inst.out.unwrap());
                     }
-                    Bitwise::CountOnes(_) | Bitwise::LeadingZeros(_) | Bitwise::FindFirstSet(_) => {
+                    Bitwise::CountOnes(_)
+                    | Bitwise::LeadingZeros(_)
+                    | Bitwise::FindFirstSet(_)
+                    | Bitwise::TrailingZeros(_) => {
                         // Nothing to do
                     }
                     Bitwise::ReverseBits(op) => {
@@ -264,6 +273,8 @@ impl ScopeProcessing {
                         sanitize_constant_scalar_ref_var(&mut op.or_else, &inst.out.unwrap());
                     }
                     Operator::Cast(_) => {}
+                    Operator::CastRte(_) => {}
+                    Operator::CastRtz(_) => {}
                     Operator::Reinterpret(_) => {}
                 },
                 Operation::Atomic(op) => match op {
@@ -1,8 +1,10 @@
-use alloc::{borrow::Cow, rc::Rc, vec::Vec};
-use core::{any::TypeId, cell::RefCell};
+use alloc::{borrow::Cow, rc::Rc, string::String, vec::Vec};
+use core::{any::TypeId, cell::RefCell, fmt::Write};
 use hashbrown::{HashMap, HashSet};
 
-use crate::{BarrierLevel, CubeFnSource, ExpandElement, Matrix, SourceLoc, TypeHash};
+use crate::{
+    BarrierLevel, Branch, CubeFnSource, ExpandElement, Matrix, Operation, SourceLoc, TypeHash,
+};
 
 use super::{
     Allocator, Elem, Id, Instruction, Item, Variable, VariableKind, processing::ScopeProcessing,
@@ -356,4 +358,69 @@ impl Scope {
                 .insert(variable, name.into());
         }
     }
+
+    /// A readable listing of the instructions of the scope, one per line with the type of its
+    /// output, and the scopes of branches and loops indented under them.
+    ///
+    /// Meant to inspect the IR produced by the expansion of a kernel, before any backend lowers
+    /// it. The scope can also be serialized with `serde` to get a form that can be read back.
+    pub fn to_debug_string(&self) -> String {
+        let mut out = String::new();
+        self.write_debug(&mut out, 0);
+        out
+    }
+
+    fn write_debug(&self, out: &mut String, depth: usize) {
+        let indent = "    ".repeat(depth);
+
+        for instruction in self.instructions.iter() {
+            match &instruction.operation {
+                Operation::Branch(branch @ Branch::If(op)) => {
+                    writeln!(out, "{indent}{branch} {{").unwrap();
+                    op.scope.write_debug(out, depth + 1);
+                    writeln!(out, "{indent}}}").unwrap();
+                }
+                Operation::Branch(branch @ Branch::RangeLoop(op)) => {
+                    writeln!(out, "{indent}{branch} {{").unwrap();
+                    op.scope.write_debug(out, depth + 1);
+                    writeln!(out, "{indent}}}").unwrap();
+                }
+                Operation::Branch(Branch::Loop(op)) => {
+                    writeln!(out, "{indent}loop {{").unwrap();
+                    op.scope.write_debug(out, depth + 1);
+                    writeln!(out, "{indent}}}").unwrap();
+                }
+                Operation::Branch(branch @ Branch::Call(op)) => {
+                    writeln!(out, "{indent}{branch} {{").unwrap();
+                    op.scope.write_debug(out, depth + 1);
+                    writeln!(out, "{indent}}}").unwrap();
+                }
+                Operation::Branch(branch @ Branch::IfElse(op)) => {
+                    writeln!(out, "{indent}{branch} {{").unwrap();
+                    op.scope_if.write_debug(out, depth + 1);
+                    writeln!(out, "{indent}}} else {{").unwrap();
+                    op.scope_else.write_debug(out, depth + 1);
+                    writeln!(out, "{indent}}}").unwrap();
+                }
+                Operation::Branch(Branch::Switch(op)) => {
+                    writeln!(out, "{indent}switch({}) {{", op.value).unwrap();
+                    for (value, scope) in op.cases.iter() {
+                        writeln!(out, "{indent}    case {value} {{").unwrap();
+                        scope.write_debug(out, depth + 2);
+                        writeln!(out, "{indent}    }}").unwrap();
+                    }
+                    writeln!(out, "{indent}    default {{").unwrap();
+                    op.scope_default.write_debug(out, depth + 2);
+                    writeln!(out, "{indent}    }}").unwrap();
+                    writeln!(out, "{indent}}}").unwrap();
+                }
+                _ => match instruction.out {
+                    Some(output) => {
+                        writeln!(out, "{indent}{instruction} : {}", output.item).unwrap()
+                    }
+                    None => writeln!(out, "{indent}{instruction}").unwrap(),
+                },
+            }
+        }
+    }
 }
@@ -1,6 +1,6 @@
 use core::fmt::Display;
 
-use crate::{OperationReflect, TypeHash};
+use crate::{BinaryOperator, OperationArgs, OperationReflect, TypeHash, Variable};
 
 /// All synchronization types.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -14,6 +14,55 @@ pub enum Synchronization {
     /// Sync CTA proxy.
     /// Experimental, CUDA only, SM 9.0+ only
     SyncProxyShared,
+    /// Sync units in a cube, then load `lhs[rhs]` from shared memory. The loaded value is uniform
+    /// across the cube.
+    /// Maps to `workgroupUniformLoad` in WGSL.
+    UniformLoad(BinaryOperator),
+    /// Order the memory accesses of the unit around the fence, as seen by the units of `scope`.
+    /// Unlike a barrier, units don't wait for each other.
+    MemoryFence {
+        scope: MemoryScope,
+        order: MemoryOrder,
+    },
+    /// Sync units in every cube of the cluster, making their shared memories visible to each
+    /// other. Same as [SyncUnits](Synchronization::SyncUnits) on targets without clusters.
+    SyncCluster,
+    /// Load `shared[index]` from the shared memory of the cube `rank` of the cluster.
+    /// Loads from the local shared memory on targets without clusters, where each cube is its own
+    /// cluster.
+    ClusterLoad(ClusterLoadOperator),
+}
+
+/// Operands of a [cluster load](Synchronization::ClusterLoad).
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, TypeHash, PartialEq, Eq, Hash, OperationArgs)]
+#[allow(missing_docs)]
+pub struct ClusterLoadOperator {
+    pub shared: Variable,
+    pub rank: Variable,
+    pub index: Variable,
+}
+
+/// The set of units that observe the ordering of a [memory fence](Synchronization::MemoryFence).
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, TypeHash, PartialEq, Eq, Hash)]
+pub enum MemoryScope {
+    /// All units in the same cube.
+    Cube,
+    /// All units on the device.
+    Device,
+}
+
+/// The ordering guaranteed by a [memory fence](Synchronization::MemoryFence).
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, TypeHash, PartialEq, Eq, Hash)]
+pub enum MemoryOrder {
+    /// Accesses after the fence can't be moved before it.
+    Acquire,
+    /// Accesses before the fence can't be moved after it.
+    Release,
+    /// Both [acquire](MemoryOrder::Acquire) and [release](MemoryOrder::Release).
+    AcquireRelease,
 }
 
 impl Display for Synchronization {
@@ -22,6 +71,16 @@ impl Display for Synchronization {
             Synchronization::SyncUnits => write!(f, "sync_units()"),
             Synchronization::SyncStorage => write!(f, "sync_storage()"),
             Synchronization::SyncProxyShared => write!(f, "sync_proxy_shared()"),
+            Synchronization::UniformLoad(op) => write!(f, "uniform_load({}[{}])", op.lhs, op.rhs),
+            Synchronization::MemoryFence { scope, order } => {
+                write!(f, "memory_fence({scope:?}, {order:?})")
+            }
+            Synchronization::SyncCluster => write!(f, "sync_cluster()"),
+            Synchronization::ClusterLoad(op) => write!(
+                f,
+                "cluster_load({}[{}], rank: {})",
+                op.shared, op.index, op.rank
+            ),
         }
     }
 }
@@ -2,12 +2,16 @@ use std::fmt::Debug;
 
 use cubecl_core::tune::AutotuneError;
 
-use crate::matmul::kernels::{MatmulAvailabilityError, MatmulLaunchError};
+use crate::{
+    matmul::kernels::{MatmulAvailabilityError, MatmulLaunchError},
+    tensor::LayoutError,
+};
 
 #[allow(clippy::large_enum_variant)]
 pub enum ConvLaunchError {
     Matmul(MatmulLaunchError),
     Groups(usize),
+    Layout(LayoutError),
     Unknown,
 }
 
@@ -23,6 +27,12 @@ impl Debug for ConvLaunchError {
                     "Unable to launch matmul because groups must be one, is actually {groups}",
                 )
             }
+            ConvLaunchError::Layout(err) => {
+                writeln!(
+                    f,
+                    "Unable to launch convolution because of an invalid layout: {err}"
+                )
+            }
             ConvLaunchError::Unknown => write!(f, "Unknown"),
         }
     }
@@ -34,6 +44,12 @@ impl From<MatmulLaunchError> for ConvLaunchError {
     }
 }
 
+impl From<LayoutError> for ConvLaunchError {
+    fn from(value: LayoutError) -> Self {
+        Self::Layout(value)
+    }
+}
+
 impl From<MatmulAvailabilityError> for ConvLaunchError {
     fn from(value: MatmulAvailabilityError) -> Self {
         Self::Matmul(MatmulLaunchError::Unavailable(value))
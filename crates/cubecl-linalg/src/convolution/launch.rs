@@ -1,20 +1,24 @@
-use std::any::TypeId;
+use std::{any::TypeId, marker::PhantomData};
 
-use cubecl_core::{Runtime, client::ComputeClient, prelude::*, tensor_line_size_parallel};
+use cubecl_core::{Runtime, client::ComputeClient, prelude::*, server, tensor_line_size_parallel};
 use half::f16;
 
 use crate::matmul::{
-    components::global::args::{ConcreteOutputFactory, MatmulArgs},
+    components::{
+        global::args::{ConcreteOutputFactory, MatmulArgs},
+        tile::accelerated::Accelerated,
+    },
     kernels::MatmulLaunchError,
 };
 use crate::{
     convolution::base::ConvolutionLaunch,
     matmul::components::{self, InputIdent, MatmulPrecision, MatmulSelection},
+    tensor::{TensorHandle, TensorLayout, into_contiguous_ref},
 };
 
 use super::{
     ConvLaunchError,
-    algorithm::{Algorithm, StageInput},
+    algorithm::{Algorithm, StageInput, simple::SimpleConvAlgorithm},
     args::ConvInputsLaunch,
     base::ConvolutionProblem,
     selection::select_matmul,
@@ -31,6 +35,119 @@ pub struct ConvolutionArgs {
     pub dilation: (usize, usize),
 }
 
+/// Perform a 2D convolution using the implicit GEMM (im2col) algorithm, using cubecl tiling matmul
+/// components and the default algorithm. The im2col matrix is never materialized, out-of-bounds
+/// reads caused by the padding load zeros.
+///
+/// * `input` - The input feature map, layout should be [batches, height, width, in_channels]
+/// * `weight` - The weights (filter) applied to each kernel, layout should be [out_channels, kernel_h, kernel_w, in_channels]
+/// * `bias` - The bias added to each out channel
+/// * `out` - The output feature map, layout should be [batches, out_height, out_width, out_channels]
+/// * `args` - The stride, padding and dilation of the convolution
+#[allow(clippy::result_large_err)]
+pub fn conv2d<R: Runtime, MP: MatmulPrecision>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: &TensorHandleRef<'_, R>,
+    weight: &TensorHandleRef<'_, R>,
+    bias: &Option<TensorHandleRef<'_, R>>,
+    out: &TensorHandleRef<'_, R>,
+    args: ConvolutionArgs,
+) -> Result<(), ConvLaunchError> {
+    launch_conv2d_nhwc::<R, MP, SimpleConvAlgorithm<Accelerated>>(
+        client, input, weight, bias, out, args,
+    )
+}
+
+/// A contiguous 4D tensor along with the [layout](TensorLayout) of its dimensions.
+#[derive(Clone, Copy)]
+pub struct LayoutTensorRef<'a, R: Runtime> {
+    /// The buffer where the data are stored.
+    pub handle: &'a server::Handle,
+    /// The shape of the tensor, in the order of its layout.
+    pub shape: &'a [usize],
+    /// The order of the dimensions in memory.
+    pub layout: TensorLayout,
+    runtime: PhantomData<R>,
+}
+
+impl<'a, R: Runtime> LayoutTensorRef<'a, R> {
+    /// Create a new tensor with the given `layout`, its `shape` is in the order of the layout.
+    pub fn new(handle: &'a server::Handle, shape: &'a [usize], layout: TensorLayout) -> Self {
+        Self {
+            handle,
+            shape,
+            layout,
+            runtime: PhantomData,
+        }
+    }
+}
+
+/// Perform a 2D convolution like [conv2d], on tensors whose layouts are given explicitly.
+///
+/// The strides are derived from the layouts, so the same logical data stored in NCHW or NHWC give
+/// the same result. Weights follow the layout of their descriptor, i.e. `[out_channels,
+/// in_channels, kernel_h, kernel_w]` for NCHW. Returns an error if a shape doesn't have the rank of
+/// its layout.
+#[allow(clippy::result_large_err)]
+pub fn conv2d_with_layout<R: Runtime, MP: MatmulPrecision>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: LayoutTensorRef<'_, R>,
+    weight: LayoutTensorRef<'_, R>,
+    bias: &Option<TensorHandleRef<'_, R>>,
+    out: LayoutTensorRef<'_, R>,
+    args: ConvolutionArgs,
+) -> Result<(), ConvLaunchError> {
+    let (input_shape, input_strides) = input.layout.nhwc_view(input.shape)?;
+    let (weight_shape, weight_strides) = weight.layout.nhwc_view(weight.shape)?;
+    let (out_shape, out_strides) = out.layout.nhwc_view(out.shape)?;
+
+    let size_in = size_of::<MP::EI>();
+    let size_out = size_of::<MP::EO>();
+    let input = unsafe {
+        TensorHandleRef::from_raw_parts(input.handle, &input_strides, &input_shape, size_in)
+    };
+    let weight = unsafe {
+        TensorHandleRef::from_raw_parts(weight.handle, &weight_strides, &weight_shape, size_in)
+    };
+
+    match out.layout {
+        TensorLayout::Nhwc => {
+            let out = unsafe {
+                TensorHandleRef::from_raw_parts(out.handle, &out_strides, &out_shape, size_out)
+            };
+            conv2d::<R, MP>(client, &input, &weight, bias, &out, args)
+        }
+        TensorLayout::Nchw => {
+            // The kernels write channels last, the result is then permuted into the output.
+            let result = TensorHandle::<R, MP::EO>::empty(client, out_shape.to_vec());
+            conv2d::<R, MP>(client, &input, &weight, bias, &result.as_ref(), args)?;
+
+            let [n, h, w, c] = out_shape;
+            let result_shape = [n, c, h, w];
+            let result_strides = [
+                result.strides[0],
+                result.strides[3],
+                result.strides[1],
+                result.strides[2],
+            ];
+            let out_strides = out.layout.strides(out.shape)?;
+            unsafe {
+                into_contiguous_ref::<R, MP::EO>(
+                    client,
+                    &TensorHandleRef::from_raw_parts(
+                        &result.handle,
+                        &result_strides,
+                        &result_shape,
+                        size_out,
+                    ),
+                    &TensorHandleRef::from_raw_parts(out.handle, &out_strides, out.shape, size_out),
+                );
+            }
+            Ok(())
+        }
+    }
+}
+
 /// Perform a 2D convolution using the implicit GEMM (im2col) algorithm, using cubecl tiling matmul
 /// components, using the specified algorithm.
 ///
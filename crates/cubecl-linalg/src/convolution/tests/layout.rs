@@ -0,0 +1,93 @@
+use cubecl_core::{CubeElement, Runtime, client::ComputeClient};
+
+use crate::{
+    convolution::{
+        ConvLaunchError,
+        launch::{ConvolutionArgs, LayoutTensorRef, conv2d_with_layout},
+    },
+    matmul::{components::MatmulPrecision, tests::Sample},
+    tensor::TensorLayout,
+};
+
+/// Permute a `[d0, d1, d2, d3]` tensor from channels last to channels first, i.e. to
+/// `[d0, d3, d1, d2]`.
+fn nhwc_to_nchw<E: Copy>(data: &[E], [n, h, w, c]: [usize; 4]) -> Vec<E> {
+    let mut out = Vec::with_capacity(data.len());
+    for b in 0..n {
+        for ch in 0..c {
+            for y in 0..h {
+                for x in 0..w {
+                    out.push(data[((b * h + y) * w + x) * c + ch]);
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Runs the same convolution with NHWC and NCHW tensors and checks that both give the same result.
+pub fn test_conv2d_layouts<R: Runtime, MP: MatmulPrecision>(
+    client: ComputeClient<R::Server, R::Channel>,
+) where
+    MP::EI: CubeElement + Sample,
+    MP::EO: CubeElement,
+{
+    let (batches, height, width, channels, out_channels, kernel) = (2, 6, 5, 8, 16, 3);
+    let input_nhwc = [batches, height, width, channels];
+    let weight_ohwi = [out_channels, kernel, kernel, channels];
+    let out_nhwc = [batches, height, width, out_channels];
+    let args = ConvolutionArgs {
+        stride: (1, 1),
+        padding: (1, 1),
+        dilation: (1, 1),
+    };
+
+    let input = MP::EI::sample(input_nhwc.iter().product(), 1234);
+    let weight = MP::EI::sample(weight_ohwi.iter().product(), 5678);
+    let out_size = out_nhwc.iter().product::<usize>() * size_of::<MP::EO>();
+
+    let run = |layout: TensorLayout| -> Result<Vec<MP::EO>, ConvLaunchError> {
+        let (input, weight, input_shape, weight_shape, out_shape) = match layout {
+            TensorLayout::Nhwc => (
+                input.clone(),
+                weight.clone(),
+                input_nhwc,
+                weight_ohwi,
+                out_nhwc,
+            ),
+            TensorLayout::Nchw => (
+                nhwc_to_nchw(&input, input_nhwc),
+                nhwc_to_nchw(&weight, weight_ohwi),
+                [batches, channels, height, width],
+                [out_channels, channels, kernel, kernel],
+                [batches, out_channels, height, width],
+            ),
+        };
+        let input_handle = client.create(MP::EI::as_bytes(&input));
+        let weight_handle = client.create(MP::EI::as_bytes(&weight));
+        let out_handle = client.empty(out_size);
+
+        conv2d_with_layout::<R, MP>(
+            &client,
+            LayoutTensorRef::new(&input_handle, &input_shape, layout),
+            LayoutTensorRef::new(&weight_handle, &weight_shape, layout),
+            &None,
+            LayoutTensorRef::new(&out_handle, &out_shape, layout),
+            args.clone(),
+        )?;
+
+        let bytes = client.read_one(out_handle.binding());
+        Ok(MP::EO::from_bytes(&bytes).to_vec())
+    };
+
+    let nhwc = match run(TensorLayout::Nhwc) {
+        Ok(out) => out,
+        Err(err) => {
+            println!("Skipped - not supported: {err:?}");
+            return;
+        }
+    };
+    let nchw = run(TensorLayout::Nchw).unwrap();
+
+    assert_eq!(nhwc_to_nchw(&nhwc, out_nhwc), nchw);
+}
@@ -1,4 +1,5 @@
 mod convolution_test_launcher;
+pub mod layout;
 pub mod test_macros;
 mod test_utils;
 
@@ -22,5 +22,11 @@ macro_rules! testgen_conv2d_accelerated {
         type Precision = ($eg, $es);
 
         $crate::conv2d_standard_tests!();
+
+        #[test]
+        pub fn conv2d_nchw_matches_nhwc() {
+            let client = TestRuntime::client(&Default::default());
+            $crate::convolution::tests::layout::test_conv2d_layouts::<TestRuntime, $eg>(client);
+        }
     };
 }
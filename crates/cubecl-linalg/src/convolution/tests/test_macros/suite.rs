@@ -23,6 +23,25 @@ pub struct ConvolutionSize {
     pub out_c: usize,
 }
 
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct ConvolutionParams {
+    pub kernel_size: (u32, u32),
+    pub stride: (u32, u32),
+    pub padding: (i32, i32),
+    pub dilation: (u32, u32),
+}
+
+impl Default for ConvolutionParams {
+    fn default() -> Self {
+        Self {
+            kernel_size: (4, 3),
+            stride: (1, 1),
+            padding: (3, 1),
+            dilation: (3, 2),
+        }
+    }
+}
+
 pub fn test_algo<A: Algorithm, Args: MatmulArgs, P: TestPrecision, R: Runtime>(
     tile_shape: MatmulSize,
     tile_count: MatmulSize,
@@ -30,6 +49,23 @@ pub fn test_algo<A: Algorithm, Args: MatmulArgs, P: TestPrecision, R: Runtime>(
 ) where
     Args::Input<P::EG>: ConvInputsLaunch,
     Args::Output<P::EG>: ConcreteOutputFactory,
+{
+    test_algo_with_params::<A, Args, P, R>(
+        tile_shape,
+        tile_count,
+        problem,
+        ConvolutionParams::default(),
+    )
+}
+
+pub fn test_algo_with_params<A: Algorithm, Args: MatmulArgs, P: TestPrecision, R: Runtime>(
+    tile_shape: MatmulSize,
+    tile_count: MatmulSize,
+    problem: ConvolutionSize,
+    params: ConvolutionParams,
+) where
+    Args::Input<P::EG>: ConvInputsLaunch,
+    Args::Output<P::EG>: ConcreteOutputFactory,
 {
     let client = R::client(&Default::default());
     let plane_dim = match client
@@ -44,12 +80,13 @@ pub fn test_algo<A: Algorithm, Args: MatmulArgs, P: TestPrecision, R: Runtime>(
         }
     };
 
-    // TODO: Automate more params
     let batches = 2;
-    let kernel_size = (4, 3);
-    let stride = (1, 1);
-    let padding = (3, 1);
-    let dilation = (3, 2);
+    let ConvolutionParams {
+        kernel_size,
+        stride,
+        padding,
+        dilation,
+    } = params;
 
     let out_h =
         calculate_conv_output_size(kernel_size.0, stride.0, padding.0, dilation.0, problem.h);
@@ -117,7 +154,7 @@ pub fn calculate_conv_output_size(
 #[macro_export]
 macro_rules! conv2d_standard_tests {
     () => {
-        use $crate::convolution::tests::ConvolutionSize;
+        use $crate::convolution::tests::{ConvolutionParams, ConvolutionSize};
         use $crate::matmul::components::MatmulSize;
 
         mod t8x8x8 {
@@ -302,31 +339,100 @@ macro_rules! conv2d_standard_tests {
                 }
             );
         }
+
+        mod p16x16x16x32_k1x1 {
+            use super::*;
+            $crate::conv2d_standard_tests!(
+                $tile,
+                $stage,
+                ConvolutionSize {
+                    h: 16,
+                    w: 16,
+                    c: 16,
+                    out_c: 32
+                },
+                ConvolutionParams {
+                    kernel_size: (1, 1),
+                    stride: (1, 1),
+                    padding: (0, 0),
+                    dilation: (1, 1),
+                }
+            );
+        }
+
+        mod p17x17x16x32_k3x3_s2_p1 {
+            use super::*;
+            $crate::conv2d_standard_tests!(
+                $tile,
+                $stage,
+                ConvolutionSize {
+                    h: 17,
+                    w: 17,
+                    c: 16,
+                    out_c: 32
+                },
+                ConvolutionParams {
+                    kernel_size: (3, 3),
+                    stride: (2, 2),
+                    padding: (1, 1),
+                    dilation: (1, 1),
+                }
+            );
+        }
+
+        mod p20x15x8x16_k5x3_s2x1_p2x0_d1x2 {
+            use super::*;
+            $crate::conv2d_standard_tests!(
+                $tile,
+                $stage,
+                ConvolutionSize {
+                    h: 20,
+                    w: 15,
+                    c: 8,
+                    out_c: 16
+                },
+                ConvolutionParams {
+                    kernel_size: (5, 3),
+                    stride: (2, 1),
+                    padding: (2, 0),
+                    dilation: (1, 2),
+                }
+            );
+        }
     };
 
     ($tile:expr, $stage:expr, $problem:expr) => {
+        $crate::conv2d_standard_tests!(
+            $tile,
+            $stage,
+            $problem,
+            $crate::convolution::tests::ConvolutionParams::default()
+        );
+    };
+
+    ($tile:expr, $stage:expr, $problem:expr, $params:expr) => {
         use $crate::convolution::algorithm::simple::SimpleConvAlgorithm;
         use $crate::convolution::algorithm::simple_tma::SimpleTmaConvAlgorithm;
         use $crate::matmul::components::global::args::{TensorArgs, TensorMapArgs};
 
         #[test]
         pub fn simple_coalesced_im2col() {
-            cubecl_linalg::convolution::tests::test_algo::<
+            cubecl_linalg::convolution::tests::test_algo_with_params::<
                 SimpleConvAlgorithm<TMM>,
                 TensorArgs,
                 Precision,
                 TestRuntime,
-            >($tile, $stage, $problem);
+            >($tile, $stage, $problem, $params);
         }
 
         #[test]
         pub fn simple_tma_im2col() {
-            cubecl_linalg::convolution::tests::test_algo::<
+            cubecl_linalg::convolution::tests::test_algo_with_params::<
                 SimpleTmaConvAlgorithm<TMM>,
                 TensorMapArgs,
                 Precision,
                 TestRuntime,
-            >($tile, $stage, $problem);
+            >($tile, $stage, $problem, $params);
         }
     };
 }
@@ -0,0 +1,254 @@
+use std::cmp::min;
+
+use cubecl_core::{
+    Runtime, RuntimeFeatures,
+    client::ComputeClient,
+    ir::{Elem, FloatKind},
+    prelude::{CubePrimitive, TensorHandleRef},
+};
+use half::f16;
+
+use super::{
+    Strategy, SyncLoadingStrategy,
+    components::{MatmulPrecision, MatmulSelection, MatmulSize, tile::accelerated::Accelerated},
+    kernels::{
+        MatmulLaunchError,
+        matmul::{
+            NUM_PLANES_PER_TENSOR_CORES, NUM_SM_APPROX, NUM_TENSOR_CORES_APPROX,
+            change_rows_per_plane, find_stage_size, launch_ref_with_selection,
+            select_instruction_shape, simple::SimpleAlgorithm,
+        },
+        tiling2d::{self, Tiling2dConfig},
+    },
+};
+
+/// Reductions over `k` at most this long can accumulate in half precision without losing too
+/// much accuracy.
+const MAX_HALF_ACCUMULATION_K: usize = 256;
+
+/// Tiling chosen by [matmul_autotile].
+#[derive(Debug, Clone)]
+pub struct MatmulAutotile {
+    /// The strategy to launch the matmul with.
+    pub strategy: Strategy,
+    /// The tile shape and the number of tiles of each stage when using cooperative matrices,
+    /// `None` when the strategy doesn't use them.
+    pub selection: Option<MatmulSelection>,
+    /// The element type used to accumulate the results.
+    pub accumulator: Elem,
+}
+
+/// Pick a matmul tiling heuristically from the shape of the problem, the element types of the
+/// inputs and output, and the capabilities of the device, without benchmarking any kernel.
+///
+/// Uses cooperative matrices when the device supports them with a plane of 32 or 64 units,
+/// picking the instruction shape from the aspect ratio of the output and the stage size to keep
+/// every streaming multiprocessor busy. Falls back to the tiling2d kernel otherwise, which
+/// accumulates in the input element type.
+///
+/// Cooperative matrices accumulate in `f16` only when both the inputs and the output are `f16`
+/// and the reduction is short, and in `f32` otherwise.
+pub fn matmul_autotile(
+    m: usize,
+    n: usize,
+    k: usize,
+    batches: usize,
+    input: Elem,
+    output: Elem,
+    features: &RuntimeFeatures,
+) -> MatmulAutotile {
+    let half = Elem::Float(FloatKind::F16);
+    let half_accumulation =
+        input == half && output == half && features.supports_f16 && k <= MAX_HALF_ACCUMULATION_K;
+    let plane_dim = features.plane_size_max;
+    let plane_dim_supported =
+        features.plane_size_min == plane_dim && (plane_dim == 32 || plane_dim == 64);
+
+    if !features.supports_cmma || !features.supports_plane || !plane_dim_supported {
+        return MatmulAutotile {
+            strategy: Strategy::Tiling2D(Tiling2dConfig::default()),
+            selection: None,
+            accumulator: input,
+        };
+    }
+
+    let supported = |m, n, k| features.cmma_shapes.contains(&(m, n, k));
+    let (instruction_m, instruction_n, instruction_k) = select_instruction_shape(supported, m, n);
+
+    let tensor_cores_channels = min(8, NUM_TENSOR_CORES_APPROX * NUM_PLANES_PER_TENSOR_CORES);
+    let stage_size = find_stage_size(
+        m,
+        n,
+        batches,
+        NUM_SM_APPROX as usize,
+        tensor_cores_channels as usize,
+        instruction_m,
+        instruction_n,
+    );
+    let (rows_per_plane, stage_size_m, stage_size_n) =
+        change_rows_per_plane(stage_size, instruction_m, m);
+
+    MatmulAutotile {
+        strategy: Strategy::Simple(SyncLoadingStrategy::Cyclic),
+        selection: Some(MatmulSelection {
+            tile_shape: MatmulSize {
+                m: instruction_m as u32,
+                n: instruction_n as u32,
+                k: instruction_k as u32,
+            },
+            tile_count: MatmulSize {
+                m: stage_size_m as u32,
+                n: stage_size_n as u32,
+                k: plane_dim / instruction_k as u32,
+            },
+            plane_dim,
+            rows_per_plane: rows_per_plane as u32,
+        }),
+        accumulator: if half_accumulation {
+            half
+        } else {
+            Elem::Float(FloatKind::F32)
+        },
+    }
+}
+
+/// Launch the matmul with the tiling and accumulator picked by [matmul_autotile] for the shapes
+/// of the tensors and the device, returning what was picked.
+#[allow(clippy::result_large_err)]
+pub fn launch_autotile<R: Runtime, MP: MatmulPrecision>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: &TensorHandleRef<R>,
+    rhs: &TensorHandleRef<R>,
+    out: &TensorHandleRef<R>,
+) -> Result<MatmulAutotile, MatmulLaunchError> {
+    let rank = out.shape.len();
+    let autotile = matmul_autotile(
+        lhs.shape[rank - 2],
+        rhs.shape[rank - 1],
+        lhs.shape[rank - 1],
+        out.shape[..rank - 2].iter().product(),
+        MP::EI::as_elem_native_unchecked(),
+        MP::EO::as_elem_native_unchecked(),
+        &RuntimeFeatures::from_properties(client.properties()),
+    );
+
+    match &autotile.selection {
+        Some(selection) if autotile.accumulator == Elem::Float(FloatKind::F16) => {
+            launch_ref_with_selection::<
+                R,
+                (MP::EI, MP::ES, f16, MP::EO),
+                SimpleAlgorithm<Accelerated>,
+            >(client, lhs, rhs, out, selection.clone())?
+        }
+        Some(selection) => launch_ref_with_selection::<
+            R,
+            (MP::EI, MP::ES, f32, MP::EO),
+            SimpleAlgorithm<Accelerated>,
+        >(client, lhs, rhs, out, selection.clone())?,
+        None => tiling2d::launch_ref::<R, MP::EI>(client, lhs, rhs, out, Tiling2dConfig::default()),
+    }
+
+    Ok(autotile)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matmul::test_features;
+
+    const F16: Elem = Elem::Float(FloatKind::F16);
+    const BF16: Elem = Elem::Float(FloatKind::BF16);
+    const F32: Elem = Elem::Float(FloatKind::F32);
+
+    fn tensor_cores() -> RuntimeFeatures {
+        test_features(vec![(8, 32, 16), (16, 16, 16), (32, 8, 16)])
+    }
+
+    fn tile_shape(autotile: &MatmulAutotile) -> MatmulSize {
+        autotile.selection.as_ref().unwrap().tile_shape
+    }
+
+    #[test]
+    fn square_uses_balanced_instructions() {
+        let autotile = matmul_autotile(4096, 4096, 4096, 1, F32, F32, &tensor_cores());
+
+        assert!(matches!(autotile.strategy, Strategy::Simple(_)));
+        assert_eq!(
+            tile_shape(&autotile),
+            MatmulSize {
+                m: 16,
+                n: 16,
+                k: 16
+            }
+        );
+        assert_eq!(autotile.accumulator, F32);
+    }
+
+    #[test]
+    fn tall_skinny_uses_tall_instructions() {
+        let tall = matmul_autotile(8192, 64, 512, 1, F32, F32, &tensor_cores());
+        let wide = matmul_autotile(64, 8192, 512, 1, F32, F32, &tensor_cores());
+
+        assert_eq!(tile_shape(&tall), MatmulSize { m: 32, n: 8, k: 16 });
+        assert_eq!(tile_shape(&wide), MatmulSize { m: 8, n: 32, k: 16 });
+    }
+
+    #[test]
+    fn batched_uses_larger_stages() {
+        // A single small matmul needs small stages to spread over the multiprocessors, while
+        // batches already provide enough cubes.
+        let single = matmul_autotile(256, 256, 256, 1, F32, F32, &tensor_cores());
+        let batched = matmul_autotile(256, 256, 256, 64, F32, F32, &tensor_cores());
+
+        assert_eq!(
+            single.selection.unwrap().tile_count,
+            MatmulSize { m: 2, n: 2, k: 2 }
+        );
+        assert_eq!(
+            batched.selection.unwrap().tile_count,
+            MatmulSize { m: 8, n: 8, k: 2 }
+        );
+    }
+
+    #[test]
+    fn short_half_reduction_accumulates_in_half_precision() {
+        let autotile = matmul_autotile(1024, 1024, 128, 1, F16, F16, &tensor_cores());
+
+        assert_eq!(autotile.accumulator, F16);
+    }
+
+    #[test]
+    fn short_reduction_keeps_single_precision_for_other_types() {
+        let single = matmul_autotile(1024, 1024, 128, 1, F32, F32, &tensor_cores());
+        let half_to_single = matmul_autotile(1024, 1024, 128, 1, F16, F32, &tensor_cores());
+        let brain = matmul_autotile(1024, 1024, 128, 1, BF16, BF16, &tensor_cores());
+
+        assert_eq!(single.accumulator, F32);
+        assert_eq!(half_to_single.accumulator, F32);
+        assert_eq!(brain.accumulator, F32);
+    }
+
+    #[test]
+    fn long_half_reduction_accumulates_in_single_precision() {
+        let autotile = matmul_autotile(1024, 1024, 4096, 1, F16, F16, &tensor_cores());
+
+        assert_eq!(autotile.accumulator, F32);
+    }
+
+    #[test]
+    fn only_available_shape_is_used() {
+        let autotile = matmul_autotile(8192, 64, 512, 1, F32, F32, &test_features(vec![(8, 8, 8)]));
+
+        assert_eq!(tile_shape(&autotile), MatmulSize { m: 8, n: 8, k: 8 });
+        assert_eq!(autotile.selection.unwrap().tile_count.k, 4);
+    }
+
+    #[test]
+    fn without_cmma_falls_back_to_tiling2d() {
+        let autotile = matmul_autotile(4096, 4096, 4096, 1, F16, F16, &test_features(vec![]));
+
+        assert!(matches!(autotile.strategy, Strategy::Tiling2D(_)));
+        assert!(autotile.selection.is_none());
+        assert_eq!(autotile.accumulator, F16);
+    }
+}
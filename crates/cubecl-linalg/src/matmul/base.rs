@@ -1,4 +1,6 @@
-use cubecl_core::{Runtime, client::ComputeClient, prelude::TensorHandleRef};
+use std::panic::AssertUnwindSafe;
+
+use cubecl_core::{Runtime, RuntimeFeatures, client::ComputeClient, prelude::TensorHandleRef};
 
 use crate::tensor::TensorHandle;
 
@@ -13,7 +15,7 @@ use super::{
         tile::accelerated::Accelerated,
     },
     kernels::{
-        MatmulLaunchError,
+        MatmulAvailabilityError, MatmulInvalidProblem, MatmulLaunchError, int8,
         matmul::{
             self, double_buffering::DoubleBufferingAlgorithm,
             double_buffering_barrier::DoubleBufferingBarrierAlgorithm, simple::SimpleAlgorithm,
@@ -35,10 +37,53 @@ pub enum Strategy {
     Specialized,
     Naive,
     Tiling2D(Tiling2dConfig),
+    /// Accumulate every output element sequentially over `k`, one element at a time, so results
+    /// are bitwise reproducible across runs and don't depend on the shapes or the strategies used
+    /// before.
+    ///
+    /// It never uses tensor cores, vectorized partial sums or splits the reduction over `k`, so
+    /// it's slower than the other strategies. Results are reproducible on a given device and
+    /// compiler, a different compiler may still fuse the multiply-adds differently.
+    Deterministic,
     #[default]
     Auto,
 }
 
+impl Strategy {
+    /// Whether the device has the features required by the strategy.
+    ///
+    /// The strategies using cooperative matrices need them along with plane operations, and the
+    /// ones loading asynchronously also need pipelines, barriers or TMA. The naive and tiling2d
+    /// strategies run everywhere, and [Auto](Strategy::Auto) picks a strategy itself.
+    pub fn is_supported(&self, features: &RuntimeFeatures) -> bool {
+        let accelerated = features.supports_cmma && features.supports_plane;
+
+        match self {
+            Strategy::Simple(_) | Strategy::DoubleBuffering | Strategy::Specialized => accelerated,
+            Strategy::SimpleBarrier(AsyncLoadingStrategy::Tma) => {
+                accelerated && features.supports_tma
+            }
+            Strategy::SimpleBarrier(_) | Strategy::DoubleBufferingBarrier => {
+                accelerated && features.supports_barrier
+            }
+            Strategy::SimplePipelined => accelerated && features.supports_async_copy,
+            Strategy::Naive | Strategy::Tiling2D(_) | Strategy::Deterministic | Strategy::Auto => {
+                true
+            }
+        }
+    }
+}
+
+/// The first of the ordered `strategies` supported by the device.
+pub fn select_strategy<'a>(
+    strategies: &'a [Strategy],
+    features: &RuntimeFeatures,
+) -> Option<&'a Strategy> {
+    strategies
+        .iter()
+        .find(|strategy| strategy.is_supported(features))
+}
+
 #[derive(Debug, Clone)]
 pub enum SyncLoadingStrategy {
     Cyclic,
@@ -72,6 +117,58 @@ pub fn launch<R: Runtime, MP: MatmulPrecision>(
     )
 }
 
+/// Launch the matmul with the first of the ordered `strategies` that works on the device, e.g.
+/// `[Strategy::Simple(..), Strategy::Tiling2D(..), Strategy::Naive]` to use cooperative matrices
+/// when available and fall back to kernels without them otherwise.
+///
+/// Strategies requiring [features](Strategy::is_supported) the device lacks are skipped, and a
+/// strategy that fails to launch falls through to the next one. The error of the last strategy
+/// tried is returned when none succeeds.
+///
+/// Kernels are compiled when first launched, and the backends panic when one fails to compile.
+/// A panic while launching a strategy is caught and reported as
+/// [CompilationFailed](MatmulAvailabilityError::CompilationFailed), falling through to the next
+/// strategy as well. The panic hook still prints the message of the failure.
+#[allow(clippy::result_large_err)]
+pub fn launch_ref_with_fallback<R: Runtime, MP: MatmulPrecision>(
+    strategies: &[Strategy],
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: &TensorHandleRef<R>,
+    rhs: &TensorHandleRef<R>,
+    out: &TensorHandleRef<R>,
+) -> Result<(), MatmulLaunchError> {
+    let features = RuntimeFeatures::from_properties(client.properties());
+    let mut error = MatmulAvailabilityError::NoSupportedStrategy.into();
+
+    for strategy in strategies
+        .iter()
+        .filter(|strategy| strategy.is_supported(&features))
+    {
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            launch_ref::<R, MP>(strategy, client, lhs, rhs, out)
+        }))
+        .unwrap_or_else(|panic| {
+            let message = panic
+                .downcast_ref::<String>()
+                .cloned()
+                .or_else(|| {
+                    panic
+                        .downcast_ref::<&str>()
+                        .map(|message| message.to_string())
+                })
+                .unwrap_or_default();
+            Err(MatmulAvailabilityError::CompilationFailed(message).into())
+        });
+
+        match result {
+            Ok(()) => return Ok(()),
+            Err(err) => error = err,
+        }
+    }
+
+    Err(error)
+}
+
 #[allow(clippy::result_large_err)]
 pub fn launch_ref<R: Runtime, MP: MatmulPrecision>(
     strategy: &Strategy,
@@ -79,6 +176,21 @@ pub fn launch_ref<R: Runtime, MP: MatmulPrecision>(
     lhs: &TensorHandleRef<R>,
     rhs: &TensorHandleRef<R>,
     out: &TensorHandleRef<R>,
+) -> Result<(), MatmulLaunchError> {
+    if !MP::QUANTIZED && int8::is_int8::<MP::EI>() {
+        return launch_ref_int8::<R, MP>(strategy, client, lhs, rhs, out);
+    }
+
+    launch_ref_strategy::<R, MP>(strategy, client, lhs, rhs, out)
+}
+
+#[allow(clippy::result_large_err)]
+fn launch_ref_strategy<R: Runtime, MP: MatmulPrecision>(
+    strategy: &Strategy,
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: &TensorHandleRef<R>,
+    rhs: &TensorHandleRef<R>,
+    out: &TensorHandleRef<R>,
 ) -> Result<(), MatmulLaunchError> {
     match strategy {
         Strategy::Simple(loading_strategy) => match loading_strategy {
@@ -168,6 +280,9 @@ pub fn launch_ref<R: Runtime, MP: MatmulPrecision>(
             naive::launch_ref::<R, MP::EI>(client, lhs, rhs, out)?;
             Ok(())
         }
+        Strategy::Deterministic => {
+            naive::launch_deterministic_ref::<R, MP::EI>(client, lhs, rhs, out)
+        }
         Strategy::Auto => {
             if let Err(err) =
                 matmul::launch_ref::<R, MP, SimpleAlgorithm<Accelerated>>(client, lhs, rhs, out)
@@ -191,3 +306,76 @@ pub fn launch_ref<R: Runtime, MP: MatmulPrecision>(
         }
     }
 }
+
+/// Launch the matmul of `i8` inputs, accumulated into `i32` whatever the strategy.
+///
+/// The strategies without cooperative matrices would accumulate into `i8`, so they're all
+/// replaced by the [integer matmul](int8::launch), using packed dot products where available.
+/// [Auto](Strategy::Auto) tries integer cooperative matrices first.
+#[allow(clippy::result_large_err)]
+fn launch_ref_int8<R: Runtime, MP: MatmulPrecision>(
+    strategy: &Strategy,
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: &TensorHandleRef<R>,
+    rhs: &TensorHandleRef<R>,
+    out: &TensorHandleRef<R>,
+) -> Result<(), MatmulLaunchError> {
+    // The cooperative matrices accumulate into `i32` as well, so they overflow on the same `k`.
+    let k = lhs.shape[lhs.shape.len() - 1];
+    if k > int8::MAX_K {
+        return Err(MatmulInvalidProblem::ExceededKSize {
+            k: k as u32,
+            max_k: int8::MAX_K as u32,
+        }
+        .into());
+    }
+
+    match strategy {
+        Strategy::Naive | Strategy::Deterministic | Strategy::Tiling2D(_) => {
+            int8::launch_ref::<R, MP::EO>(client, lhs, rhs, out)
+        }
+        Strategy::Auto => {
+            match matmul::launch_ref::<R, MP, SimpleAlgorithm<Accelerated>>(client, lhs, rhs, out) {
+                Err(MatmulLaunchError::Unavailable(_)) => {
+                    int8::launch_ref::<R, MP::EO>(client, lhs, rhs, out)
+                }
+                result => result,
+            }
+        }
+        _ => launch_ref_strategy::<R, MP>(strategy, client, lhs, rhs, out),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matmul::test_features;
+
+    fn fallback_chain() -> Vec<Strategy> {
+        vec![
+            Strategy::SimpleBarrier(AsyncLoadingStrategy::Tma),
+            Strategy::Simple(SyncLoadingStrategy::Cyclic),
+            Strategy::Tiling2D(Tiling2dConfig::default()),
+            Strategy::Naive,
+        ]
+    }
+
+    #[test]
+    fn device_without_cmma_falls_back_to_tiling2d() {
+        let strategies = fallback_chain();
+        let selected = select_strategy(&strategies, &test_features(vec![]));
+
+        assert!(matches!(selected, Some(Strategy::Tiling2D(_))));
+    }
+
+    #[test]
+    fn device_with_cmma_skips_unavailable_tma() {
+        let strategies = fallback_chain();
+        let selected = select_strategy(&strategies, &test_features(vec![(16, 16, 16)]));
+
+        assert!(matches!(
+            selected,
+            Some(Strategy::Simple(SyncLoadingStrategy::Cyclic))
+        ));
+    }
+}
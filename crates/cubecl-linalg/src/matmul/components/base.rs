@@ -10,7 +10,7 @@ pub struct MatmulSize {
     pub k: u32,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct MatmulSelection {
     pub tile_shape: MatmulSize,
     pub tile_count: MatmulSize,
@@ -598,33 +598,232 @@ impl MatmulArgs for TensorArgs {
     }
 
     fn quantization<MP: MatmulPrecision>(state: &Self::State<MP::EI, MP::EO>) -> Quantization<MP> {
-        // TODO Currently, this assume that the scaling is always the last value in the buffer.
-        //      Also, in burn the scaling is presently fix to f32, hence the extra conversions.
+        unsafe { tensor_quantization::<MP>(&(*state.0), &(*state.1)) }
+    }
+}
 
-        let (lhs, rhs, _) = *state;
-        unsafe {
-            let buffer_len_lhs = Self::buffer_len_lhs(state);
-            let line_size_lhs = (*lhs).line_size();
-            let reinterpreted_len_lhs = buffer_len_lhs * line_size_lhs / 4; // TODO Change this when we stop using u32 to pack 4 i8 in burn.
+#[cube]
+/// The quantization of `lhs` and `rhs` when they are materialized tensors.
+fn tensor_quantization<MP: MatmulPrecision>(
+    lhs: &Tensor<Line<MP::EI>>,
+    rhs: &Tensor<Line<MP::EI>>,
+) -> Quantization<MP> {
+    // TODO Currently, this assume that the scaling is always the last value in the buffer.
+    //      Also, in burn the scaling is presently fix to f32, hence the extra conversions.
+
+    let line_size_lhs = lhs.line_size();
+    let reinterpreted_len_lhs = lhs.buffer_len() * line_size_lhs / 4; // TODO Change this when we stop using u32 to pack 4 i8 in burn.
+
+    let scaling_lhs = ReinterpretSlice::<MP::EI, f32>::new(lhs.to_slice(), line_size_lhs)
+        .read(reinterpreted_len_lhs - 1);
+
+    let line_size_rhs = rhs.line_size();
+    let reinterpreted_len_rhs = rhs.buffer_len() * line_size_rhs / 4; // TODO See above comment.
+
+    let scaling_rhs = ReinterpretSlice::<MP::EI, f32>::new(rhs.to_slice(), line_size_rhs)
+        .read(reinterpreted_len_rhs - 1);
+
+    Quantization::<MP> {
+        scaling_lhs: MP::ES::cast_from(scaling_lhs),
+        scaling_rhs: MP::ES::cast_from(scaling_rhs),
+    }
+}
+
+#[derive(Clone)]
+/// Type implementing [MatmulArgs] like [TensorArgs], also keeping the maximum of each row of the
+/// output as the epilogue writes it, e.g. for the softmax following the scores of an attention.
+///
+/// Every written line updates the maximum of its row atomically, as the [key](row_max_key) of
+/// its value in `f32`. The rows of the output must follow each other like in the tensors
+/// allocated by the client. The keys are turned back into values with [row_max_value].
+pub struct RowMaxArgs;
+
+#[derive(CubeLaunch, CubeType)]
+/// Output representation for [RowMaxArgs] implementing [MatmulArgs].
+pub struct RowMaxOutput<EG: Numeric> {
+    /// The output tensor.
+    pub out: Tensor<Line<EG>>,
+    /// The keys of the maximum of each row of the output, zeroed before the launch.
+    pub row_max: Tensor<Atomic<u32>>,
+}
+
+#[cube]
+/// Map `value` to a key whose unsigned order is the order of the floats, so the maximum of
+/// floats can be kept with integer atomics, which every backend supports.
+///
+/// Every key is above zero, so a zeroed buffer holds no maximum yet. NaNs of either sign take the
+/// key of the positive quiet NaN, above every other key, so the maximum of a row with a NaN is NaN.
+pub fn row_max_key(value: f32) -> u32 {
+    let bits = u32::reinterpret(value);
+    // A negative NaN with every bit set would otherwise take the key 0.
+    let is_nan = (bits & 0x7FFF_FFFFu32) > 0x7F80_0000u32;
+    let bits = select(is_nan, 0x7FC0_0000u32, bits);
+    // Negative floats are ordered backwards, and below the positive ones.
+    select(
+        (bits & 0x8000_0000u32) != 0,
+        bits ^ 0xFFFF_FFFFu32,
+        bits | 0x8000_0000u32,
+    )
+}
+
+#[cube]
+/// The float mapped to `key` by [row_max_key].
+pub fn row_max_value(key: u32) -> f32 {
+    let bits = select(
+        (key & 0x8000_0000u32) != 0,
+        key ^ 0x8000_0000u32,
+        key ^ 0xFFFF_FFFFu32,
+    );
+    f32::reinterpret(bits)
+}
+
+#[cube]
+impl MatmulArgs for RowMaxArgs {
+    type Output<EO: Numeric> = RowMaxOutput<EO>;
+    type Input<EI: Numeric> = TensorInputs<EI>;
+    type State<EI: Numeric, EO: Numeric> = (
+        *const Tensor<Line<EI>>,
+        *const Tensor<Line<EI>>,
+        *mut Tensor<Line<EO>>,
+        *mut Tensor<Atomic<u32>>,
+    );
+
+    fn init_state<EI: Numeric, EO: Numeric>(
+        input: &Self::Input<EI>,
+        output: &mut Self::Output<EO>,
+    ) -> Self::State<EI, EO> {
+        (&input.lhs, &input.rhs, &mut output.out, &mut output.row_max)
+    }
+
+    fn read_lhs<EI: Numeric, EO: Numeric>(
+        state: &Self::State<EI, EO>,
+        coordinate: u32,
+    ) -> Line<EI> {
+        unsafe { (*state.0)[coordinate] }
+    }
+
+    fn read_rhs<EI: Numeric, EO: Numeric>(
+        state: &Self::State<EI, EO>,
+        coordinate: u32,
+    ) -> Line<EI> {
+        unsafe { (*state.1)[coordinate] }
+    }
+
+    fn read_window_lhs<EI: Numeric, EO: Numeric>(
+        state: &Self::State<EI, EO>,
+        start: u32,
+        end: u32,
+    ) -> Slice<Line<EI>> {
+        unsafe { (*state.0).slice(start, end) }
+    }
+
+    fn read_window_rhs<EI: Numeric, EO: Numeric>(
+        state: &Self::State<EI, EO>,
+        start: u32,
+        end: u32,
+    ) -> Slice<Line<EI>> {
+        unsafe { (*state.1).slice(start, end) }
+    }
+
+    fn as_tensor_map_lhs<EI: Numeric, EO: Numeric>(_state: &Self::State<EI, EO>) -> TensorMap<EI> {
+        comptime!(unimplemented!("Can't use `RowMaxArgs` as `TensorMap`"));
+        #[allow(unreachable_code)]
+        TensorMap::dummy()
+    }
 
-            let scaling_lhs =
-                ReinterpretSlice::<MP::EI, f32>::new((*lhs).to_slice(), line_size_lhs)
-                    .read(reinterpreted_len_lhs - 1);
+    fn as_tensor_map_rhs<EI: Numeric, EO: Numeric>(_state: &Self::State<EI, EO>) -> TensorMap<EI> {
+        comptime!(unimplemented!("Can't use `RowMaxArgs` as `TensorMap`"));
+        #[allow(unreachable_code)]
+        TensorMap::dummy()
+    }
 
-            let buffer_len_rhs = Self::buffer_len_rhs(state);
-            let line_size_rhs = (*rhs).line_size();
-            let reinterpreted_len_rhs = buffer_len_rhs * line_size_rhs / 4; // TODO See above comment.
+    fn shape_lhs<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>, dim: u32) -> u32 {
+        unsafe { (*state.0).shape(dim) }
+    }
 
-            let scaling_rhs =
-                ReinterpretSlice::<MP::EI, f32>::new((*rhs).to_slice(), line_size_rhs)
-                    .read(reinterpreted_len_rhs - 1);
+    fn shape_rhs<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>, dim: u32) -> u32 {
+        unsafe { (*state.1).shape(dim) }
+    }
 
-            Quantization::<MP> {
-                scaling_lhs: MP::ES::cast_from(scaling_lhs),
-                scaling_rhs: MP::ES::cast_from(scaling_rhs),
+    fn shape_out<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>, dim: u32) -> u32 {
+        unsafe { (*state.2).shape(dim) }
+    }
+
+    fn stride_lhs<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>, dim: u32) -> u32 {
+        unsafe { (*state.0).stride(dim) }
+    }
+
+    fn stride_rhs<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>, dim: u32) -> u32 {
+        unsafe { (*state.1).stride(dim) }
+    }
+
+    fn stride_out<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>, dim: u32) -> u32 {
+        unsafe { (*state.2).stride(dim) }
+    }
+
+    fn write_out<EI: Numeric, EO: Numeric>(
+        state: &mut Self::State<EI, EO>,
+        coordinate: u32,
+        value: Line<EO>,
+    ) {
+        unsafe {
+            (*state.2)[coordinate] = value;
+
+            let rank = (*state.2).rank();
+            let line_size = (*state.2).line_size();
+            // The rows of every batch follow each other, maybe padded.
+            let row = coordinate * line_size / (*state.2).stride(rank - 2);
+
+            let mut max = f32::cast_from(value[0]);
+            #[unroll]
+            for i in 1..line_size {
+                max = Max::max(max, f32::cast_from(value[i]));
             }
+
+            let row_max = &(*state.3);
+            Atomic::max(&row_max[row * row_max.stride(rank - 2)], row_max_key(max));
         }
     }
+
+    fn rank_lhs<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>) -> u32 {
+        unsafe { (*state.0).rank() }
+    }
+
+    fn rank_rhs<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>) -> u32 {
+        unsafe { (*state.1).rank() }
+    }
+
+    fn rank_out<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>) -> u32 {
+        unsafe { (*state.2).rank() }
+    }
+
+    fn len_lhs<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>) -> u32 {
+        unsafe { (*state.0).len() }
+    }
+
+    fn len_rhs<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>) -> u32 {
+        unsafe { (*state.1).len() }
+    }
+
+    fn len_out<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>) -> u32 {
+        unsafe { (*state.2).len() }
+    }
+
+    fn buffer_len_lhs<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>) -> u32 {
+        unsafe { (*state.0).buffer_len() }
+    }
+
+    fn buffer_len_rhs<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>) -> u32 {
+        unsafe { (*state.1).buffer_len() }
+    }
+
+    fn buffer_len_out<EI: Numeric, EO: Numeric>(state: &Self::State<EI, EO>) -> u32 {
+        unsafe { (*state.2).buffer_len() }
+    }
+
+    fn quantization<MP: MatmulPrecision>(state: &Self::State<MP::EI, MP::EO>) -> Quantization<MP> {
+        unsafe { tensor_quantization::<MP>(&(*state.0), &(*state.1)) }
+    }
 }
 
 #[derive(Clone)]
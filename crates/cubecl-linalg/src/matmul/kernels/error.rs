@@ -30,12 +30,16 @@ pub enum MatmulAvailabilityError {
     BarrierUnavailable,
     TmaUnavailable,
     DynamicLineSizeUnavailable,
+    NoSupportedStrategy,
+    /// The kernel of the strategy failed to compile on the device, with the reported message.
+    CompilationFailed(String),
 }
 
 pub enum MatmulInvalidProblem {
     ExceededMSize { m: u32, max_m: u32 },
     ExceededNSize { n: u32, max_n: u32 },
     ExceededBatchSize { b: u32, max_b: u32 },
+    ExceededKSize { k: u32, max_k: u32 },
     InvalidLineSizeLhs { size: u32, line_size: u8 },
     InvalidLineSizeRhs { size: u32, line_size: u8 },
     InvalidLineSizeOut { size: u32, line_size: u8 },
@@ -118,6 +122,10 @@ impl Debug for MatmulInvalidProblem {
                 "Problem has {} batches but these configs can only have batches<={}",
                 b, max_b,
             ),
+            MatmulInvalidProblem::ExceededKSize { k, max_k } => write!(
+                f,
+                "Problem has k={k} but the accumulator can only hold k<={max_k} products without overflowing"
+            ),
             MatmulInvalidProblem::InvalidLineSizeLhs { size, line_size } => write!(
                 f,
                 "the lhs tensor can't be read with line size={line_size} and dimension={size}"
@@ -185,6 +193,12 @@ impl Debug for MatmulAvailabilityError {
             MatmulAvailabilityError::DynamicLineSizeUnavailable => {
                 writeln!(f, "Dynamic line size is not available.")
             }
+            MatmulAvailabilityError::NoSupportedStrategy => {
+                writeln!(f, "None of the strategies is supported by the device.")
+            }
+            MatmulAvailabilityError::CompilationFailed(message) => {
+                writeln!(f, "The kernel failed to compile on the device: {message}")
+            }
         }
     }
 }
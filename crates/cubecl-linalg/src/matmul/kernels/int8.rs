@@ -0,0 +1,167 @@
+//! Integer matmul of `i8` inputs accumulated into `i32`, e.g. for quantized inference.
+//!
+//! Each local unit computes a single element of the output matrix, using packed dot products of
+//! 4 `i8` where available.
+use cubecl::prelude::*;
+use cubecl_core::{
+    self as cubecl, Feature,
+    ir::{Elem, IntKind},
+};
+
+use crate::tensor::TensorHandle;
+
+use super::{
+    MatmulAvailabilityError, MatmulInvalidProblem, MatmulLaunchError,
+    naive::{batch_offsets, prepare_inputs, simple_cube_count, vectorization_factor},
+};
+
+/// The largest `k` for which the `i32` accumulator can't overflow, since each product of two `i8`
+/// is at most `128 * 128`.
+pub const MAX_K: usize = i32::MAX as usize / (128 * 128);
+
+#[cube(launch_unchecked)]
+fn matmul_kernel(
+    lhs: &Tensor<Line<i8>>,
+    rhs: &Tensor<Line<i8>>,
+    out: &mut Tensor<i32>,
+    // number of dimensions not involved in the matmul
+    #[comptime] num_batches: Option<u32>,
+    #[comptime] packed_dot: bool,
+) {
+    let rank = out.rank();
+
+    let n_rows = lhs.shape(rank - 2);
+    let n_cols = rhs.shape(rank - 1);
+
+    let batch_pos = ABSOLUTE_POS_Z;
+    let row = CUBE_DIM_X * CUBE_POS_X + UNIT_POS_X;
+    let col = CUBE_DIM_Y * CUBE_POS_Y + UNIT_POS_Y;
+
+    if row >= n_rows || col >= n_cols {
+        terminate!();
+    }
+
+    let offset_out = batch_pos * out.stride(rank - 2) * out.shape(rank - 2);
+    let (offset_lhs, offset_rhs) = batch_offsets(lhs, rhs, out, offset_out, num_batches);
+
+    let line_size = lhs.line_size();
+    let k = rhs.shape(rank - 2) / line_size.runtime();
+    let mut sum = i32::from_int(0);
+
+    for i in 0..k {
+        let lhs_index = row * lhs.stride(rank - 2) / line_size + i + offset_lhs;
+        let rhs_index = col * rhs.stride(rank - 1) / line_size + i + offset_rhs;
+
+        sum += dot(lhs[lhs_index], rhs[rhs_index], line_size, packed_dot);
+    }
+
+    out[row * out.stride(rank - 2) + col + offset_out] = sum;
+}
+
+/// The dot product of two lines of `i8` accumulated into an `i32`.
+#[cube]
+fn dot(
+    lhs: Line<i8>,
+    rhs: Line<i8>,
+    #[comptime] line_size: u32,
+    #[comptime] packed_dot: bool,
+) -> i32 {
+    if comptime!(packed_dot && line_size == 4) {
+        lhs.dot_i32(rhs)
+    } else {
+        let product = Line::<i32>::cast_from(lhs) * Line::<i32>::cast_from(rhs);
+        let mut sum = i32::from_int(0);
+
+        #[unroll]
+        for v in 0..line_size {
+            sum += product[v];
+        }
+
+        sum
+    }
+}
+
+/// Whether the inputs of elements `EI` are multiplied by the integer matmul.
+pub fn is_int8<EI: Numeric>() -> bool {
+    EI::as_elem_native_unchecked() == Elem::Int(IntKind::I8)
+}
+
+/// Integer matmul of `i8` inputs into an `i32` output.
+#[allow(clippy::result_large_err)]
+pub fn launch_ref<R: Runtime, EO: Numeric>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: &TensorHandleRef<'_, R>,
+    rhs: &TensorHandleRef<'_, R>,
+    out: &TensorHandleRef<'_, R>,
+) -> Result<(), MatmulLaunchError> {
+    let lhs = TensorHandle::<R, i8>::from_ref(lhs);
+    let rhs = TensorHandle::<R, i8>::from_ref(rhs);
+
+    launch::<R, EO>(client, lhs, rhs, out)
+}
+
+/// Integer matmul of `i8` inputs into an `i32` output.
+///
+/// Lines of 4 `i8` are multiplied with a single packed dot product when the device supports
+/// [integer dot products](Feature::IntegerDotProduct), like `dp4a` on CUDA, and widened to `i32`
+/// otherwise.
+///
+/// Return an error if the output isn't `i32`, or if `k` is larger than [MAX_K], since the
+/// accumulator could then overflow.
+#[allow(clippy::result_large_err)]
+pub fn launch<R: Runtime, EO: Numeric>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: TensorHandle<R, i8>,
+    rhs: TensorHandle<R, i8>,
+    out: &TensorHandleRef<'_, R>,
+) -> Result<(), MatmulLaunchError> {
+    let output = EO::as_elem_native_unchecked();
+    if output != Elem::Int(IntKind::I32) {
+        return Err(MatmulAvailabilityError::TypesUnavailable {
+            input: i8::as_elem_native_unchecked(),
+            output,
+        }
+        .into());
+    }
+
+    let ndims = lhs.shape.len();
+    let k = lhs.shape[ndims - 1];
+    if k > MAX_K {
+        return Err(MatmulInvalidProblem::ExceededKSize {
+            k: k as u32,
+            max_k: MAX_K as u32,
+        }
+        .into());
+    }
+
+    let (cube_dim_x, cube_dim_y) = (32, 8);
+    let (lhs, rhs, rhs_original_shape) = prepare_inputs(client, lhs, rhs);
+
+    let cube_count = simple_cube_count(
+        &lhs.shape,
+        &rhs_original_shape,
+        out.shape,
+        cube_dim_x,
+        cube_dim_y,
+    )?;
+
+    let vectorization_factor = vectorization_factor(&lhs);
+    let packed_dot = client
+        .properties()
+        .feature_enabled(Feature::IntegerDotProduct);
+
+    unsafe {
+        matmul_kernel::launch_unchecked::<R>(
+            client,
+            cube_count,
+            CubeDim::new(cube_dim_x as u32, cube_dim_y as u32, 1),
+            lhs.as_arg(vectorization_factor),
+            rhs.as_arg(vectorization_factor),
+            out.as_tensor_arg(1),
+            Some(ndims as u32 - 2),
+            packed_dot,
+        );
+    };
+
+    Ok(())
+}
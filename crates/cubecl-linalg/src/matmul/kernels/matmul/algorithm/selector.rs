@@ -21,7 +21,7 @@ use super::Algorithm;
 
 pub(crate) const NUM_SM_APPROX: u32 = 50;
 pub(crate) const NUM_TENSOR_CORES_APPROX: u32 = 4;
-const NUM_PLANES_PER_TENSOR_CORES: u32 = 2;
+pub(crate) const NUM_PLANES_PER_TENSOR_CORES: u32 = 2;
 
 /// Select which kernel to launch for the given Algorithm.
 ///
@@ -41,6 +41,27 @@ where
 {
     let selection =
         matmul_selection::<A::TileMatmul, MS::Precision, R>(client, &problem, plane_dim);
+
+    launch_kernel_concrete::<MS, R, A>(client, lhs, rhs, out, problem, selection)
+}
+
+/// Launch the kernel of the given Algorithm with the tiling of `selection` instead of the one
+/// [selected](select_kernel_concrete) from the problem.
+///
+/// Only works for concrete tensor inputs and output.
+#[allow(clippy::result_large_err)]
+pub fn launch_kernel_concrete<MS: MatmulSpec, R: Runtime, A: Algorithm>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: &TensorHandleRef<'_, R>,
+    rhs: &TensorHandleRef<'_, R>,
+    out: &TensorHandleRef<'_, R>,
+    problem: MatmulProblem,
+    selection: MatmulSelection,
+) -> Result<(), MatmulLaunchError>
+where
+    InputArg<MS>: ConcreteInputsFactory,
+    OutputArg<MS>: ConcreteOutputFactory,
+{
     let config_input = CompleteStageTiling {
         tile_shape: selection.tile_shape,
         tile_count: selection.tile_count,
@@ -103,9 +124,18 @@ pub(crate) fn find_instruction_shape(
             .unwrap_or(true)
     };
 
+    select_instruction_shape(supported, m, n)
+}
+
+/// Pick the instruction shape for the problem among the ones that are `supported`.
+pub(crate) fn select_instruction_shape(
+    supported: impl Fn(u8, u8, u8) -> bool,
+    m: usize,
+    n: usize,
+) -> (usize, usize, usize) {
     if m >= 4 * n && supported(32, 8, 16) {
         (32, 8, 16)
-    } else if n >= 4 * n && supported(8, 32, 16) {
+    } else if n >= 4 * m && supported(8, 32, 16) {
         (8, 32, 16)
     } else if supported(16, 16, 16) {
         (16, 16, 16)
@@ -240,7 +270,7 @@ pub fn matmul_selection<TMM: TileMatmulFamily, MP: MatmulPrecision, R: Runtime>(
 const MINIMUM_STAGE_COUNT_FOR_MULTI_ROW_STAGE: usize = 8;
 const MULTI_ROW_ENABLED: bool = false;
 
-fn change_rows_per_plane(
+pub(crate) fn change_rows_per_plane(
     stage_size: usize,
     instruction_m: usize,
     problem_m: usize,
@@ -2,18 +2,25 @@ use crate::matmul::components::{
     InputRuntimeArg, MatmulConfigFactory, MatmulLaunch, MatmulPrecision, MatmulProblem,
     MatmulSelection, MatmulSpec, MatrixLayout, OutputRuntimeArg, ReplaceES,
 };
-use crate::matmul::components::{global::args::TensorMapArgs, tile::TileMatmulFamily};
+use crate::matmul::components::{
+    global::args::{
+        RowMaxArgs, RowMaxOutputLaunch, TensorInputsLaunch, TensorMapArgs, row_max_value,
+    },
+    tile::TileMatmulFamily,
+};
 use crate::matmul::kernels::{MatmulAvailabilityError, MatmulLaunchError};
 use crate::matmul::{self};
 use crate::tensor::into_contiguous_pitched;
 use crate::tensor::{MatrixBatchLayout, TensorHandle, matrix_batch_layout};
 use core::any::TypeId;
+use cubecl_core as cubecl;
 use cubecl_core::{Feature, prelude::*};
 use cubecl_core::{
-    Runtime, client::ComputeClient, frontend::TensorHandleRef, tensor_line_size_parallel,
+    Runtime, calculate_cube_count_elemwise, client::ComputeClient, frontend::TensorHandleRef,
+    tensor_line_size_parallel,
 };
 
-use super::{Algorithm, select_kernel_concrete};
+use super::{Algorithm, launch_kernel_concrete, select_kernel_concrete, select_kernel_virtual};
 
 /// Launch a matrix multiplication kernel.
 ///
@@ -45,28 +52,51 @@ pub fn launch_ref<R: Runtime, MP: MatmulPrecision, A: Algorithm>(
     rhs: &TensorHandleRef<'_, R>,
     out: &TensorHandleRef<'_, R>,
 ) -> Result<(), MatmulLaunchError> {
-    let check_layout = |tensor: &TensorHandleRef<'_, R>| match matrix_batch_layout(tensor.strides) {
-        MatrixBatchLayout::Contiguous => (false, false),
-        MatrixBatchLayout::MildlyPermuted {
-            transposed,
-            batch_swap: _,
-        } => (false, transposed),
-        MatrixBatchLayout::HighlyPermuted => (true, false),
-    };
+    launch_ref_selection::<R, MP, A>(client, lhs, rhs, out, None)
+}
+
+/// Launch a matrix multiplication kernel with the tiling of `selection`, e.g. the one picked by
+/// [matmul_autotile](crate::matmul::matmul_autotile), instead of selecting one from the problem.
+///
+/// Will fail if cmma is unavailable or if the tiling doesn't fit the device.
+#[allow(clippy::result_large_err)]
+pub fn launch_ref_with_selection<R: Runtime, MP: MatmulPrecision, A: Algorithm>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: &TensorHandleRef<'_, R>,
+    rhs: &TensorHandleRef<'_, R>,
+    out: &TensorHandleRef<'_, R>,
+    selection: MatmulSelection,
+) -> Result<(), MatmulLaunchError> {
+    launch_ref_selection::<R, MP, A>(client, lhs, rhs, out, Some(selection))
+}
 
+#[allow(clippy::result_large_err)]
+fn launch_ref_selection<R: Runtime, MP: MatmulPrecision, A: Algorithm>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: &TensorHandleRef<'_, R>,
+    rhs: &TensorHandleRef<'_, R>,
+    out: &TensorHandleRef<'_, R>,
+    selection: Option<MatmulSelection>,
+) -> Result<(), MatmulLaunchError> {
     let (lhs_make_contiguous, lhs_transposed) = check_layout(lhs);
     let (rhs_make_contiguous, rhs_transposed) = check_layout(rhs);
 
     match (lhs_make_contiguous, rhs_make_contiguous) {
-        (false, false) => {
-            matmul_cmma_ref::<R, MP, A>(client, lhs, rhs, out, (lhs_transposed, rhs_transposed))
-        }
+        (false, false) => matmul_cmma_ref::<R, MP, A>(
+            client,
+            lhs,
+            rhs,
+            out,
+            (lhs_transposed, rhs_transposed),
+            selection,
+        ),
         (false, true) => matmul_cmma_ref::<R, MP, A>(
             client,
             lhs,
             &into_contiguous_pitched::<R, MP::EI>(client, rhs).as_ref(),
             out,
             (lhs_transposed, rhs_transposed),
+            selection,
         ),
         (true, false) => matmul_cmma_ref::<R, MP, A>(
             client,
@@ -74,6 +104,7 @@ pub fn launch_ref<R: Runtime, MP: MatmulPrecision, A: Algorithm>(
             rhs,
             out,
             (lhs_transposed, rhs_transposed),
+            selection,
         ),
         (true, true) => matmul_cmma_ref::<R, MP, A>(
             client,
@@ -81,10 +112,119 @@ pub fn launch_ref<R: Runtime, MP: MatmulPrecision, A: Algorithm>(
             &into_contiguous_pitched::<R, MP::EI>(client, rhs).as_ref(),
             out,
             (lhs_transposed, rhs_transposed),
+            selection,
         ),
     }
 }
 
+/// Whether the tensor must be made contiguous to be multiplied, and whether it's transposed.
+fn check_layout<R: Runtime>(tensor: &TensorHandleRef<'_, R>) -> (bool, bool) {
+    match matrix_batch_layout(tensor.strides) {
+        MatrixBatchLayout::Contiguous => (false, false),
+        MatrixBatchLayout::MildlyPermuted {
+            transposed,
+            batch_swap: _,
+        } => (false, transposed),
+        MatrixBatchLayout::HighlyPermuted => (true, false),
+    }
+}
+
+/// Launch a matrix multiplication kernel returning the product along with the maximum of each of
+/// its rows, e.g. for the softmax following the scores of an attention.
+///
+/// The epilogue of the matmul keeps the maximums as it writes the product, see [RowMaxArgs], and
+/// a kernel over the rows then converts them to the output type. The maximums are taken in `f32`
+/// and have the shape of the product with a last dimension of 1.
+///
+/// Will fail if unavailable, like [launch]. Algorithms reading their inputs as tensor maps aren't
+/// supported.
+#[allow(clippy::result_large_err, clippy::type_complexity)]
+pub fn launch_with_row_max<R: Runtime, MP: MatmulPrecision, A: Algorithm>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: TensorHandle<R, MP::EI>,
+    rhs: TensorHandle<R, MP::EI>,
+) -> Result<(TensorHandle<R, MP::EO>, TensorHandle<R, MP::EO>), MatmulLaunchError> {
+    let rank = lhs.shape.len();
+
+    let mut out_shape = lhs.shape.clone();
+    out_shape[rank - 1] = rhs.shape[rank - 1];
+    for i in 0..rank - 2 {
+        out_shape[i] = lhs.shape[i].max(rhs.shape[i]);
+    }
+    let mut row_max_shape = out_shape.clone();
+    row_max_shape[rank - 1] = 1;
+
+    let (lhs_make_contiguous, lhs_transposed) = check_layout(&lhs.as_ref());
+    let (rhs_make_contiguous, rhs_transposed) = check_layout(&rhs.as_ref());
+    let lhs = match lhs_make_contiguous {
+        true => into_contiguous_pitched::<R, MP::EI>(client, &lhs.as_ref()),
+        false => lhs,
+    };
+    let rhs = match rhs_make_contiguous {
+        true => into_contiguous_pitched::<R, MP::EI>(client, &rhs.as_ref()),
+        false => rhs,
+    };
+
+    let out = TensorHandle::<R, MP::EO>::empty(client, out_shape);
+    let keys = TensorHandle::<R, u32>::zeros(client, row_max_shape.clone());
+
+    let (problem, plane_dim) = matmul_problem::<R, MP>(
+        client,
+        &lhs.as_ref(),
+        &rhs.as_ref(),
+        &out.as_ref(),
+        (lhs_transposed, rhs_transposed),
+    )?;
+
+    let input = TensorInputsLaunch::new(
+        lhs.as_arg(problem.lhs_line_size),
+        rhs.as_arg(problem.rhs_line_size),
+    );
+    let output = RowMaxOutputLaunch::new(out.as_arg(problem.out_line_size), keys.as_arg(1));
+
+    if <A::TileMatmul as TileMatmulFamily>::requires_tensor_cores()
+        && TypeId::of::<MP::ES>() == TypeId::of::<f32>()
+        && tf32::is_supported(client)
+    {
+        select_kernel_virtual::<(ReplaceES<MP, tf32>, RowMaxArgs), R, A>(
+            client, input, output, problem, plane_dim,
+        )?;
+    } else {
+        select_kernel_virtual::<(MP, RowMaxArgs), R, A>(client, input, output, problem, plane_dim)?;
+    }
+
+    let row_max = TensorHandle::<R, MP::EO>::empty(client, row_max_shape);
+    let num_rows: usize = row_max.shape.iter().product();
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_rows, cube_dim);
+
+    unsafe {
+        row_max_from_keys::launch_unchecked::<MP::EO, R>(
+            client,
+            cube_count,
+            cube_dim,
+            keys.as_arg(1),
+            row_max.as_arg(1),
+        );
+    }
+
+    Ok((out, row_max))
+}
+
+/// Convert the keys of the row maximums kept by [RowMaxArgs] to the output type.
+#[cube(launch_unchecked)]
+fn row_max_from_keys<E: Numeric>(keys: &Tensor<u32>, row_max: &mut Tensor<E>) {
+    let rank = row_max.rank();
+    let num_rows = row_max.len();
+
+    if ABSOLUTE_POS >= num_rows {
+        terminate!();
+    }
+
+    let key = keys[ABSOLUTE_POS * keys.stride(rank - 2)];
+    row_max[ABSOLUTE_POS * row_max.stride(rank - 2)] = E::cast_from(row_max_value(key));
+}
+
 #[allow(clippy::result_large_err)]
 fn matmul_cmma_ref<R: Runtime, MP: MatmulPrecision, A: Algorithm>(
     client: &ComputeClient<R::Server, R::Channel>,
@@ -92,7 +232,23 @@ fn matmul_cmma_ref<R: Runtime, MP: MatmulPrecision, A: Algorithm>(
     rhs: &TensorHandleRef<'_, R>,
     out: &TensorHandleRef<'_, R>,
     transposed: (bool, bool),
+    selection: Option<MatmulSelection>,
 ) -> Result<(), MatmulLaunchError> {
+    let (problem, plane_dim) = matmul_problem::<R, MP>(client, lhs, rhs, out, transposed)?;
+
+    matmul_launch_kernel::<R, MP, A>(client, lhs, rhs, out, problem, plane_dim, selection)
+}
+
+/// The problem of multiplying `lhs` with `rhs` into `out`, with the plane dimension of the
+/// device, or why the matmul can't run on it.
+#[allow(clippy::result_large_err)]
+fn matmul_problem<R: Runtime, MP: MatmulPrecision>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: &TensorHandleRef<'_, R>,
+    rhs: &TensorHandleRef<'_, R>,
+    out: &TensorHandleRef<'_, R>,
+    transposed: (bool, bool),
+) -> Result<(MatmulProblem, u32), MatmulLaunchError> {
     let rank = lhs.strides.len();
     let ei_elem = MP::EI::as_elem_native().expect("To be a native type");
     let eo_elem = MP::EO::as_elem_native().expect("To be a native type");
@@ -192,7 +348,7 @@ fn matmul_cmma_ref<R: Runtime, MP: MatmulPrecision, A: Algorithm>(
         }
     };
 
-    matmul_launch_kernel::<R, MP, A>(client, lhs, rhs, out, problem, plane_dim)
+    Ok((problem, plane_dim))
 }
 
 #[allow(clippy::result_large_err)]
@@ -203,16 +359,42 @@ fn matmul_launch_kernel<R: Runtime, MP: MatmulPrecision, A: Algorithm>(
     out: &TensorHandleRef<'_, R>,
     problem: MatmulProblem,
     plane_dim: u32,
+    selection: Option<MatmulSelection>,
 ) -> Result<(), MatmulLaunchError> {
     if <A::TileMatmul as TileMatmulFamily>::requires_tensor_cores()
         && TypeId::of::<MP::ES>() == TypeId::of::<f32>()
         && tf32::is_supported(client)
     {
-        select_kernel_concrete::<ReplaceES<MP, tf32>, R, A>(
-            client, lhs, rhs, out, problem, plane_dim,
+        launch_kernel_selection::<ReplaceES<MP, tf32>, R, A>(
+            client, lhs, rhs, out, problem, plane_dim, selection,
         )
     } else {
-        select_kernel_concrete::<MP, R, A>(client, lhs, rhs, out, problem, plane_dim)
+        launch_kernel_selection::<MP, R, A>(client, lhs, rhs, out, problem, plane_dim, selection)
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn launch_kernel_selection<MP: MatmulPrecision, R: Runtime, A: Algorithm>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: &TensorHandleRef<'_, R>,
+    rhs: &TensorHandleRef<'_, R>,
+    out: &TensorHandleRef<'_, R>,
+    problem: MatmulProblem,
+    plane_dim: u32,
+    selection: Option<MatmulSelection>,
+) -> Result<(), MatmulLaunchError> {
+    match selection {
+        Some(selection) => {
+            if selection.plane_dim != plane_dim {
+                return Err(MatmulLaunchError::Unavailable(
+                    MatmulAvailabilityError::PlaneDimUnsupported {
+                        plane_dim: selection.plane_dim,
+                    },
+                ));
+            }
+            launch_kernel_concrete::<MP, R, A>(client, lhs, rhs, out, problem, selection)
+        }
+        None => select_kernel_concrete::<MP, R, A>(client, lhs, rhs, out, problem, plane_dim),
     }
 }
 
@@ -3,4 +3,7 @@ mod base;
 mod algorithm;
 
 pub use algorithm::*;
-pub use base::{launch, launch_ref, matmul_cmma_tma_ref_no_check, matmul_cube_preparation};
+pub use base::{
+    launch, launch_ref, launch_ref_with_selection, launch_with_row_max,
+    matmul_cmma_tma_ref_no_check, matmul_cube_preparation,
+};
@@ -1,3 +1,5 @@
+/// Integer matmul of `i8` inputs accumulated into `i32`.
+pub mod int8;
 /// Matmul using Accelerator or PlaneMma
 pub mod matmul;
 /// Naive non-cooperative matmul without tiling that can be very fast on small matrices.
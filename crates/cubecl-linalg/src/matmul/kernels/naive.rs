@@ -17,12 +17,9 @@ fn matmul_kernel<N: Numeric>(
     #[comptime] num_batches: Option<u32>,
 ) {
     let rank = out.rank();
-    let end = num_batches.unwrap_or_else(|| rank - 2);
-    let unroll = num_batches.is_some();
 
     let n_rows = lhs.shape(rank - 2);
     let n_cols = rhs.shape(rank - 1);
-    let mut k = rhs.shape(rank - 2);
 
     let batch_pos = ABSOLUTE_POS_Z;
     let row = CUBE_DIM_X * CUBE_POS_X + UNIT_POS_X;
@@ -32,11 +29,79 @@ fn matmul_kernel<N: Numeric>(
         terminate!();
     }
 
+    let offset_out = batch_pos * out.stride(rank - 2) * out.shape(rank - 2);
+    let (offset_lhs, offset_rhs) = batch_offsets(lhs, rhs, out, offset_out, num_batches);
+
+    let out_index = row * out.stride(rank - 2) + col + offset_out;
+    out[out_index] = dot(lhs, rhs, row, col, offset_lhs, offset_rhs);
+}
+
+/// Matmul also writing the maximum of each row of the output into `row_max`.
+///
+/// Each cube computes whole rows, its units along `x` stepping over the columns and keeping the
+/// maximum of the elements they wrote, which are then combined in shared memory.
+#[cube(launch_unchecked)]
+fn matmul_row_max_kernel<N: Numeric>(
+    lhs: &Tensor<Line<N>>,
+    rhs: &Tensor<Line<N>>,
+    out: &mut Tensor<N>,
+    row_max: &mut Tensor<N>,
+    #[comptime] num_batches: Option<u32>,
+    #[comptime] units_per_row: u32,
+    #[comptime] rows_per_cube: u32,
+) {
+    let rank = out.rank();
+
+    let n_rows = lhs.shape(rank - 2);
+    let n_cols = rhs.shape(rank - 1);
+
+    let batch_pos = ABSOLUTE_POS_Z;
+    let row = CUBE_DIM_Y * CUBE_POS_Y + UNIT_POS_Y;
+
+    let offset_out = batch_pos * out.stride(rank - 2) * out.shape(rank - 2);
+    let (offset_lhs, offset_rhs) = batch_offsets(lhs, rhs, out, offset_out, num_batches);
+
+    let mut max = N::min_value();
+    if row < n_rows {
+        for col in range_stepped(UNIT_POS_X, n_cols, units_per_row) {
+            let value = dot(lhs, rhs, row, col, offset_lhs, offset_rhs);
+            out[row * out.stride(rank - 2) + col + offset_out] = value;
+            max = select(value > max, value, max);
+        }
+    }
+
+    let mut shared = SharedMemory::<N>::new(units_per_row * rows_per_cube);
+    shared[UNIT_POS_Y * units_per_row + UNIT_POS_X] = max;
+    sync_cube();
+
+    if UNIT_POS_X == 0 && row < n_rows {
+        for i in 1..units_per_row {
+            let value = shared[UNIT_POS_Y * units_per_row + i];
+            max = select(value > max, value, max);
+        }
+
+        let row_stride = row_max.stride(rank - 2);
+        row_max[(batch_pos * n_rows + row) * row_stride] = max;
+    }
+}
+
+/// The offsets of the matrices of `lhs` and `rhs` multiplied into the matrix of `out` starting
+/// at `offset_out`, broadcasting their batch dimensions.
+#[cube]
+pub(super) fn batch_offsets<N: Numeric, O: Numeric>(
+    lhs: &Tensor<Line<N>>,
+    rhs: &Tensor<Line<N>>,
+    out: &Tensor<O>,
+    offset_out: u32,
+    #[comptime] num_batches: Option<u32>,
+) -> (u32, u32) {
+    let rank = out.rank();
+    let end = num_batches.unwrap_or_else(|| rank - 2);
+    let unroll = num_batches.is_some();
     let line_size = lhs.line_size();
 
     let mut offset_lhs = 0;
     let mut offset_rhs = 0;
-    let offset_out = batch_pos * out.stride(rank - 2) * out.shape(rank - 2);
 
     #[unroll(unroll)]
     for i in 0..end {
@@ -49,6 +114,23 @@ fn matmul_kernel<N: Numeric>(
     offset_lhs /= line_size.runtime();
     offset_rhs /= line_size.runtime();
 
+    (offset_lhs, offset_rhs)
+}
+
+/// The dot product of a row of `lhs` with a column of `rhs`, both contiguous.
+#[cube]
+fn dot<N: Numeric>(
+    lhs: &Tensor<Line<N>>,
+    rhs: &Tensor<Line<N>>,
+    row: u32,
+    col: u32,
+    offset_lhs: u32,
+    offset_rhs: u32,
+) -> N {
+    let rank = lhs.rank();
+    let line_size = lhs.line_size();
+    let mut k = rhs.shape(rank - 2);
+
     let mut sum = Line::empty(line_size).fill(N::from_int(0));
 
     k /= line_size.runtime();
@@ -60,9 +142,6 @@ fn matmul_kernel<N: Numeric>(
         sum += lhs[lhs_index] * rhs[rhs_index];
     }
 
-    let mut out_index = row * out.stride(rank - 2) + col;
-    out_index += offset_out;
-
     let unroll_sum = line_size != 1;
     if unroll_sum {
         let mut accum = N::from_int(0);
@@ -73,9 +152,9 @@ fn matmul_kernel<N: Numeric>(
             accum += sum[v];
         }
 
-        out[out_index] = accum;
+        accum
     } else {
-        out[out_index] = sum[0];
+        sum[0]
     }
 }
 
@@ -101,6 +180,148 @@ pub fn launch<R: Runtime, E: Numeric>(
     out: &TensorHandleRef<'_, R>,
 ) -> Result<(), MatmulLaunchError> {
     let (cube_dim_x, cube_dim_y) = (32, 8);
+    let ndims = lhs.shape.len();
+    let (lhs, rhs, rhs_original_shape) = prepare_inputs(client, lhs, rhs);
+
+    let cube_count = simple_cube_count(
+        &lhs.shape,
+        &rhs_original_shape,
+        out.shape,
+        cube_dim_x,
+        cube_dim_y,
+    )?;
+
+    let vectorization_factor = vectorization_factor(&lhs);
+
+    unsafe {
+        matmul_kernel::launch_unchecked::<E, R>(
+            client,
+            cube_count,
+            CubeDim::new(cube_dim_x as u32, cube_dim_y as u32, 1),
+            lhs.as_arg(vectorization_factor),
+            rhs.as_arg(vectorization_factor),
+            out.as_tensor_arg(1),
+            Some(ndims as u32 - 2),
+        );
+    };
+
+    Ok(())
+}
+
+/// Matrix multiplication accumulating every output in a fixed order, so that the result is
+/// bitwise reproducible whatever the shapes and the state of the device.
+///
+/// Each unit sums the products of its row and column one element at a time, from the first to the
+/// last element of `k`, instead of keeping one partial sum per line element and adding them up at
+/// the end like [launch], whose order depends on the vectorization picked for the shapes.
+#[allow(clippy::result_large_err)]
+pub fn launch_deterministic_ref<R: Runtime, E: Numeric>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: &TensorHandleRef<'_, R>,
+    rhs: &TensorHandleRef<'_, R>,
+    out: &TensorHandleRef<'_, R>,
+) -> Result<(), MatmulLaunchError> {
+    let lhs = TensorHandle::<R, E>::from_ref(lhs);
+    let rhs = TensorHandle::<R, E>::from_ref(rhs);
+
+    let (cube_dim_x, cube_dim_y) = (32, 8);
+    let ndims = lhs.shape.len();
+    let (lhs, rhs, rhs_original_shape) = prepare_inputs(client, lhs, rhs);
+
+    let cube_count = simple_cube_count(
+        &lhs.shape,
+        &rhs_original_shape,
+        out.shape,
+        cube_dim_x,
+        cube_dim_y,
+    )?;
+
+    unsafe {
+        matmul_kernel::launch_unchecked::<E, R>(
+            client,
+            cube_count,
+            CubeDim::new(cube_dim_x as u32, cube_dim_y as u32, 1),
+            lhs.as_arg(1),
+            rhs.as_arg(1),
+            out.as_tensor_arg(1),
+            Some(ndims as u32 - 2),
+        );
+    };
+
+    Ok(())
+}
+
+/// Matrix multiplication returning the product along with the maximum of each of its rows,
+/// computed by the same kernel, e.g. for the softmax following the scores of an attention.
+///
+/// The row maximums have the shape of the product with a last dimension of 1. See
+/// [launch_with_row_max](crate::matmul::kernels::matmul::launch_with_row_max) for the same outputs
+/// from the accelerated matmuls.
+#[allow(clippy::result_large_err)]
+pub fn launch_with_row_max<R: Runtime, E: Numeric>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: TensorHandle<R, E>,
+    rhs: TensorHandle<R, E>,
+) -> Result<(TensorHandle<R, E>, TensorHandle<R, E>), MatmulLaunchError> {
+    let (units_per_row, rows_per_cube) = (32, 8);
+    let ndims = lhs.shape.len();
+
+    let mut out_shape = lhs.shape.clone();
+    out_shape[ndims - 1] = rhs.shape[ndims - 1];
+    for i in 0..ndims - 2 {
+        out_shape[i] = lhs.shape[i].max(rhs.shape[i]);
+    }
+    let mut row_max_shape = out_shape.clone();
+    row_max_shape[ndims - 1] = 1;
+
+    let (lhs, rhs, _) = prepare_inputs(client, lhs, rhs);
+
+    let num_rows = out_shape[ndims - 2];
+    let num_batches: usize = out_shape[..ndims - 2].iter().product();
+    let cube_count = CubeCount::Static(
+        1,
+        num_rows.div_ceil(rows_per_cube) as u32,
+        num_batches as u32,
+    );
+    let max_cube_count = u16::MAX as u32;
+    if num_rows.div_ceil(rows_per_cube) as u32 > max_cube_count
+        || num_batches as u32 > max_cube_count
+    {
+        return Err(MatmulLaunchError::Unavailable(
+            super::MatmulAvailabilityError::CubeCountTooBig(cube_count),
+        ));
+    }
+
+    let out = TensorHandle::<R, E>::empty(client, out_shape);
+    let row_max = TensorHandle::<R, E>::empty(client, row_max_shape);
+
+    let vectorization_factor = vectorization_factor(&lhs);
+
+    unsafe {
+        matmul_row_max_kernel::launch_unchecked::<E, R>(
+            client,
+            cube_count,
+            CubeDim::new(units_per_row as u32, rows_per_cube as u32, 1),
+            lhs.as_arg(vectorization_factor),
+            rhs.as_arg(vectorization_factor),
+            out.as_arg(1),
+            row_max.as_arg(1),
+            Some(ndims as u32 - 2),
+            units_per_row as u32,
+            rows_per_cube as u32,
+        );
+    };
+
+    Ok((out, row_max))
+}
+
+/// Make the rows of `lhs` and the columns of `rhs` contiguous, returning the shape of `rhs`
+/// before its columns were made contiguous.
+pub(super) fn prepare_inputs<R: Runtime, E: Numeric>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: TensorHandle<R, E>,
+    rhs: TensorHandle<R, E>,
+) -> (TensorHandle<R, E>, TensorHandle<R, E>, Vec<usize>) {
     let ndims = lhs.shape.len();
     let dim1 = ndims - 1;
     let dim2 = ndims - 2;
@@ -146,36 +367,18 @@ pub fn launch<R: Runtime, E: Numeric>(
         MatrixBatchLayout::HighlyPermuted => correct_rhs_layout(rhs),
     };
 
-    let cube_count = simple_cube_count(
-        &lhs.shape,
-        &rhs_original_shape,
-        out.shape,
-        cube_dim_x,
-        cube_dim_y,
-    )?;
+    (lhs, rhs, rhs_original_shape)
+}
 
-    let vectorization_factor = match lhs.shape[ndims - 1] % 4 == 0 {
+pub(super) fn vectorization_factor<R: Runtime, E: Numeric>(lhs: &TensorHandle<R, E>) -> u8 {
+    match lhs.shape[lhs.shape.len() - 1] % 4 == 0 {
         true => 4,
         false => 1,
-    };
-
-    unsafe {
-        matmul_kernel::launch_unchecked::<E, R>(
-            client,
-            cube_count,
-            CubeDim::new(cube_dim_x as u32, cube_dim_y as u32, 1),
-            lhs.as_arg(vectorization_factor),
-            rhs.as_arg(vectorization_factor),
-            out.as_tensor_arg(1),
-            Some(ndims as u32 - 2),
-        );
-    };
-
-    Ok(())
+    }
 }
 
 #[allow(clippy::result_large_err)]
-fn simple_cube_count(
+pub(super) fn simple_cube_count(
     lhs_shape: &[usize],
     rhs_shape: &[usize],
     output_shape: &[usize],
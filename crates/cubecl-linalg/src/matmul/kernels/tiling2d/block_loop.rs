@@ -22,11 +22,12 @@ pub(crate) fn block_loop<N: Numeric>(
     dims: Dimensions,
 ) {
     let mut results = init_results::<N>(config);
+    let mut rescaled = init_rescaled(config);
     let block_size_k = config.block_size_k;
     let n_loops = (dims.k + block_size_k - 1) / block_size_k;
 
-    for k in 0..n_loops {
-        let k = k * block_size_k;
+    for block in 0..n_loops {
+        let k = block * block_size_k;
 
         load_to_shared_memories::<N, TileLoader<N>>(
             lhs,
@@ -44,6 +45,23 @@ pub(crate) fn block_loop<N: Numeric>(
         compute_loop::<N>(coordinates, shared.lhs, shared.rhs, &mut results, config);
 
         sync_units();
+
+        if comptime!(config.rescale_period.is_some()) {
+            let period = comptime!(config.rescale_period.unwrap());
+            if (block + 1) % period == 0 || block + 1 == n_loops {
+                rescale_results::<N>(&mut results, &mut rescaled, config);
+            }
+        }
+    }
+
+    if comptime!(config.rescale_period.is_some()) {
+        let tile_size = config.tile_size;
+        let unroll = config.unroll_tile;
+
+        #[unroll(unroll)]
+        for i in 0..tile_size * tile_size {
+            results[i] = N::cast_from(rescaled[i]);
+        }
     }
 
     write_to_output::<N, TileWriter<N>>(out, &results, coordinates, offsets.out, dims, config);
@@ -62,3 +80,39 @@ fn init_results<N: Numeric>(#[comptime] config: CubeTiling2dConfig) -> Array<N>
 
     results
 }
+
+/// The f32 accumulator of the rescaled results, of a single element when there's no rescaling.
+#[cube]
+fn init_rescaled(#[comptime] config: CubeTiling2dConfig) -> Array<f32> {
+    let size = comptime!(match config.rescale_period {
+        Some(_) => config.tile_size * config.tile_size,
+        None => 1,
+    });
+    let unroll = config.unroll_tile;
+
+    let mut rescaled = Array::<f32>::new(size);
+    #[unroll(unroll)]
+    for i in 0..size {
+        rescaled[i] = f32::from_int(0);
+    }
+
+    rescaled
+}
+
+/// Add the results accumulated in the element type into the f32 accumulator, and restart them
+/// from zero.
+#[cube]
+fn rescale_results<N: Numeric>(
+    results: &mut Array<N>,
+    rescaled: &mut Array<f32>,
+    #[comptime] config: CubeTiling2dConfig,
+) {
+    let tile_size = config.tile_size;
+    let unroll = config.unroll_tile;
+
+    #[unroll(unroll)]
+    for i in 0..tile_size * tile_size {
+        rescaled[i] += f32::cast_from(results[i]);
+        results[i] = N::from_int(0);
+    }
+}
@@ -19,6 +19,11 @@ pub struct Tiling2dConfig {
     pub tile_size: usize,
     /// Loop unrolling
     pub unroll: bool,
+    /// Number of blocks along the common dimension accumulated in the element type before being
+    /// added into an f32 accumulator. Bounds the rounding error of half precision on a large
+    /// common dimension, at the cost of a second accumulator. When `None`, everything is
+    /// accumulated in the element type.
+    pub rescale_period: Option<usize>,
 }
 
 impl Default for Tiling2dConfig {
@@ -29,6 +34,7 @@ impl Default for Tiling2dConfig {
             block_size_n: 64,
             tile_size: TILE_SIZE,
             unroll: false,
+            rescale_period: None,
         }
     }
 }
@@ -58,6 +64,8 @@ pub struct CubeTiling2dConfig {
     pub lhs_transposed: bool,
     /// Rhs is transposed in global memory
     pub rhs_transposed: bool,
+    /// Number of blocks along common dimension accumulated before adding into an f32 accumulator
+    pub rescale_period: Option<u32>,
 }
 
 impl Init for CubeTiling2dConfig {
@@ -86,6 +94,10 @@ impl CubeTiling2dConfig {
                 && config.block_size_n % config.tile_size == 0,
             "Tiling 2d algorithm assumes tile size divides block size perfectly. "
         );
+        assert!(
+            config.rescale_period != Some(0),
+            "Rescale period must be at least one block."
+        );
 
         CubeTiling2dConfig {
             block_size_m: config.block_size_m as u32,
@@ -99,6 +111,7 @@ impl CubeTiling2dConfig {
             tile_size: config.tile_size as u32,
             lhs_transposed,
             rhs_transposed,
+            rescale_period: config.rescale_period.map(|period| period as u32),
         }
     }
 }
@@ -1,3 +1,4 @@
+mod autotile;
 mod base;
 /// Components for matrix multiplication
 pub mod components;
@@ -7,7 +8,32 @@ pub mod kernels;
 #[cfg(feature = "export_tests")]
 pub mod tests;
 
+pub use autotile::*;
 pub use base::*;
 
 /// Autotune key for matmul.
 pub mod tune_key;
+
+/// Features of a device with planes of 32 units supporting the given cooperative matrix shapes,
+/// used to test the selection of strategies and tilings.
+#[cfg(test)]
+pub(crate) fn test_features(cmma_shapes: Vec<(u8, u8, u8)>) -> cubecl_core::RuntimeFeatures {
+    cubecl_core::RuntimeFeatures {
+        supports_plane: true,
+        supports_cmma: !cmma_shapes.is_empty(),
+        cmma_shapes,
+        supports_f16: true,
+        supports_bf16: true,
+        supports_f64: false,
+        supports_atomic_float: true,
+        supports_async_copy: false,
+        supports_barrier: false,
+        supports_tma: false,
+        supports_cube_cluster: false,
+        plane_size_min: 32,
+        plane_size_max: 32,
+        max_shared_memory: 48000,
+        max_units_per_cube: 1024,
+        max_bindings: 32,
+    }
+}
@@ -1,8 +1,25 @@
 use std::fmt::Display;
 
-use cubecl_core::{CubeElement, Runtime, prelude::Float};
+use cubecl_core::{
+    CubeElement, Feature, Runtime, RuntimeFeatures,
+    client::ComputeClient,
+    ir::{Elem, FloatKind, IntKind},
+    prelude::Float,
+};
 
-use crate::{matmul::kernels::naive, tensor::TensorHandle};
+use crate::{
+    matmul::{
+        Strategy, SyncLoadingStrategy,
+        components::{MatmulPrecision, tile::accelerated::Accelerated},
+        kernels::{
+            MatmulInvalidProblem, MatmulLaunchError, int8,
+            matmul::{self, simple::SimpleAlgorithm},
+            naive,
+        },
+        launch_autotile,
+    },
+    tensor::TensorHandle,
+};
 
 use super::test_utils::{MatmulTestCase, Sample, assert_equals_approx};
 
@@ -54,6 +71,278 @@ pub fn test_with_batches<R: Runtime, F: Float + CubeElement + Display + Sample>(
     test_simple::<R, F>(case, device);
 }
 
+/// Runs the deterministic strategy before and after other strategies have been launched, and
+/// checks that both outputs are bitwise equal and match the CPU product.
+pub fn test_deterministic<
+    R: Runtime,
+    F: Float + CubeElement + Display + Sample + MatmulPrecision,
+>(
+    device: &R::Device,
+) {
+    let case = MatmulTestCase {
+        m: 60,
+        k: 256,
+        n: 60,
+        batch: 2,
+    };
+    let client = R::client(device);
+    let lhs = case.random_lhs::<R, F>(&client);
+    let rhs = case.random_rhs::<R, F>(&client);
+
+    let run = |strategy: &Strategy| {
+        let out: TensorHandle<R, F> = case.empty_out(&client);
+        crate::matmul::launch_ref::<R, F>(
+            strategy,
+            &client,
+            &lhs.as_ref(),
+            &rhs.as_ref(),
+            &out.as_ref(),
+        )
+        .unwrap();
+        out
+    };
+
+    let first = run(&Strategy::Deterministic);
+    run(&Strategy::Auto);
+    run(&Strategy::Tiling2D(Default::default()));
+    let second = run(&Strategy::Deterministic);
+
+    assert_eq!(
+        client.read_one(first.handle.clone().binding()),
+        client.read_one(second.handle.binding())
+    );
+
+    let expected = case.matmul_cpu::<R, F>(&lhs, &rhs, &client);
+    if let Err(e) = assert_equals_approx::<R, F>(
+        &client,
+        first.handle,
+        &first.shape,
+        &first.strides,
+        &expected,
+        10e-4,
+    ) {
+        panic!("{}", e);
+    }
+}
+
+/// Launches the tiling and accumulator picked by the autotiler for the device, and checks the
+/// product against the CPU, with a looser tolerance when it accumulates in half precision.
+pub fn test_autotile<R: Runtime, F: Float + CubeElement + Display + Sample + MatmulPrecision>(
+    device: &R::Device,
+) {
+    let case = MatmulTestCase {
+        m: 128,
+        k: 128,
+        n: 64,
+        batch: 2,
+    };
+    let client = R::client(device);
+    let lhs = case.random_lhs::<R, F>(&client);
+    let rhs = case.random_rhs::<R, F>(&client);
+    let expected = case.matmul_cpu::<R, F>(&lhs, &rhs, &client);
+    let out: TensorHandle<R, F> = case.empty_out(&client);
+
+    let autotile =
+        launch_autotile::<R, F>(&client, &lhs.as_ref(), &rhs.as_ref(), &out.as_ref()).unwrap();
+
+    let epsilon = match autotile.accumulator {
+        Elem::Float(FloatKind::F16) => 10e-3,
+        _ => 10e-4,
+    };
+    if let Err(e) = assert_equals_approx::<R, F>(
+        &client,
+        out.handle,
+        &out.shape,
+        &out.strides,
+        &expected,
+        epsilon,
+    ) {
+        panic!("{} with {:?}", e, autotile);
+    }
+}
+
+/// Computes the product and the maximum of each of its rows with a single kernel, on rows wider
+/// than a cube and with batches, and checks both against the CPU product.
+pub fn test_with_row_max<R: Runtime, F: Float + CubeElement + Display + Sample>(
+    device: &R::Device,
+) {
+    test_row_max::<R, F>(device, |client, lhs, rhs| {
+        naive::launch_with_row_max::<R, F>(client, lhs, rhs).unwrap()
+    });
+}
+
+/// Like [test_with_row_max], with the maximums kept by the epilogue of the accelerated matmul,
+/// combining those of every unit writing a row. Skipped on devices without cooperative matrices.
+pub fn test_with_row_max_accelerated<
+    R: Runtime,
+    F: Float + CubeElement + Display + Sample + MatmulPrecision,
+>(
+    device: &R::Device,
+) {
+    let client = R::client(device);
+    let features = RuntimeFeatures::from_properties(client.properties());
+    if !Strategy::Simple(SyncLoadingStrategy::Cyclic).is_supported(&features) {
+        return;
+    }
+
+    test_row_max::<R, F>(device, |client, lhs, rhs| {
+        let (out, row_max) = matmul::launch_with_row_max::<R, F, SimpleAlgorithm<Accelerated>>(
+            client,
+            TensorHandle::from_ref(&lhs.as_ref()),
+            TensorHandle::from_ref(&rhs.as_ref()),
+        )
+        .unwrap();
+        (
+            TensorHandle::from_ref(&out.as_ref()),
+            TensorHandle::from_ref(&row_max.as_ref()),
+        )
+    });
+}
+
+fn test_row_max<R: Runtime, F: Float + CubeElement + Display + Sample>(
+    device: &R::Device,
+    launch: impl FnOnce(
+        &ComputeClient<R::Server, R::Channel>,
+        TensorHandle<R, F>,
+        TensorHandle<R, F>,
+    ) -> (TensorHandle<R, F>, TensorHandle<R, F>),
+) {
+    let case = MatmulTestCase {
+        m: 60,
+        k: 64,
+        n: 100,
+        batch: 2,
+    };
+    let client = R::client(device);
+    let lhs = case.random_lhs::<R, F>(&client);
+    let rhs = case.random_rhs::<R, F>(&client);
+
+    let expected = case.matmul_cpu::<R, F>(&lhs, &rhs, &client);
+    let expected_row_max: Vec<F> = expected
+        .chunks(case.n)
+        .map(|row| {
+            row.iter().copied().fold(
+                F::min_value(),
+                |max, value| if value > max { value } else { max },
+            )
+        })
+        .collect();
+
+    let (out, row_max) = launch(&client, lhs, rhs);
+
+    assert_eq!(row_max.shape, vec![case.batch, case.m, 1]);
+    if let Err(e) = assert_equals_approx::<R, F>(
+        &client,
+        out.handle,
+        &out.shape,
+        &out.strides,
+        &expected,
+        10e-4,
+    ) {
+        panic!("{}", e);
+    }
+    if let Err(e) = assert_equals_approx::<R, F>(
+        &client,
+        row_max.handle,
+        &row_max.shape,
+        &row_max.strides,
+        &expected_row_max,
+        10e-4,
+    ) {
+        panic!("{}", e);
+    }
+}
+
+/// Multiplies `i8` matrices spanning the whole range of `i8` into `i32` with the strategy picked
+/// for integers and with the integer kernel, and checks both against the `i32` product on the CPU.
+pub fn test_int8<R: Runtime>(device: &R::Device) {
+    let client = R::client(device);
+    if !client
+        .properties()
+        .feature_enabled(Feature::Type(Elem::Int(IntKind::I8)))
+    {
+        return;
+    }
+
+    let (batch, m, k, n) = (2, 60, 256, 36);
+    let lhs_data: Vec<i8> = (0..batch * m * k)
+        .map(|i| (i * 37 % 256) as u8 as i8)
+        .collect();
+    let rhs_data: Vec<i8> = (0..batch * k * n)
+        .map(|i| (i * 91 % 256) as u8 as i8)
+        .collect();
+
+    let mut expected = vec![0i32; batch * m * n];
+    for b in 0..batch {
+        for row in 0..m {
+            for col in 0..n {
+                expected[(b * m + row) * n + col] = (0..k)
+                    .map(|i| {
+                        lhs_data[(b * m + row) * k + i] as i32
+                            * rhs_data[(b * k + i) * n + col] as i32
+                    })
+                    .sum();
+            }
+        }
+    }
+
+    let lhs = TensorHandle::<R, i8>::new_contiguous(
+        vec![batch, m, k],
+        client.create(i8::as_bytes(&lhs_data)),
+    );
+    let rhs = TensorHandle::<R, i8>::new_contiguous(
+        vec![batch, k, n],
+        client.create(i8::as_bytes(&rhs_data)),
+    );
+
+    for strategy in [Strategy::Auto, Strategy::Naive] {
+        let out = TensorHandle::<R, i32>::empty(&client, vec![batch, m, n]);
+        crate::matmul::launch_ref::<R, (i8, i8, i32, i32)>(
+            &strategy,
+            &client,
+            &lhs.as_ref(),
+            &rhs.as_ref(),
+            &out.as_ref(),
+        )
+        .unwrap();
+
+        let actual = client.read_one(out.handle.binding());
+        assert_eq!(i32::from_bytes(&actual), expected, "{strategy:?}");
+    }
+}
+
+/// Checks that integer matmuls with a `k` large enough to overflow the `i32` accumulator are
+/// rejected.
+pub fn test_int8_accumulation_overflow<R: Runtime>(device: &R::Device) {
+    let client = R::client(device);
+    if !client
+        .properties()
+        .feature_enabled(Feature::Type(Elem::Int(IntKind::I8)))
+    {
+        return;
+    }
+
+    let k = int8::MAX_K + 1;
+    let lhs = TensorHandle::<R, i8>::zeros(&client, vec![1, k]);
+    let rhs = TensorHandle::<R, i8>::zeros(&client, vec![k, 1]);
+    let out = TensorHandle::<R, i32>::empty(&client, vec![1, 1]);
+
+    let result = crate::matmul::launch_ref::<R, (i8, i8, i32, i32)>(
+        &Strategy::Auto,
+        &client,
+        &lhs.as_ref(),
+        &rhs.as_ref(),
+        &out.as_ref(),
+    );
+
+    assert!(matches!(
+        result,
+        Err(MatmulLaunchError::InvalidProblem(
+            MatmulInvalidProblem::ExceededKSize { .. }
+        ))
+    ));
+}
+
 fn test_simple<R: Runtime, F: Float + CubeElement + Display + Sample>(
     case: MatmulTestCase,
     device: &R::Device,
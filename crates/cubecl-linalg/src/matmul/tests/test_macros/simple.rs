@@ -5,6 +5,22 @@ macro_rules! testgen_matmul_simple {
     () => {
         mod simple {
             $crate::testgen_matmul_simple!(f32);
+            $crate::testgen_matmul_simple!(@integer);
+        }
+    };
+    (@integer) => {
+        #[test]
+        pub fn test_int8() {
+            cubecl_linalg::matmul::tests::simple::test_int8::<TestRuntime>(
+                &Default::default(),
+            )
+        }
+
+        #[test]
+        pub fn test_int8_accumulation_overflow() {
+            cubecl_linalg::matmul::tests::simple::test_int8_accumulation_overflow::<
+                TestRuntime,
+            >(&Default::default())
         }
     };
     ($float:ident) => {
@@ -43,6 +59,38 @@ macro_rules! testgen_matmul_simple {
                     FloatT,
                 >(&Default::default())
             }
+
+            #[test]
+            pub fn test_with_row_max() {
+                cubecl_linalg::matmul::tests::simple::test_with_row_max::<
+                    TestRuntime,
+                    FloatT,
+                >(&Default::default())
+            }
+
+            #[test]
+            pub fn test_with_row_max_accelerated() {
+                cubecl_linalg::matmul::tests::simple::test_with_row_max_accelerated::<
+                    TestRuntime,
+                    FloatT,
+                >(&Default::default())
+            }
+
+            #[test]
+            pub fn test_deterministic() {
+                cubecl_linalg::matmul::tests::simple::test_deterministic::<
+                    TestRuntime,
+                    FloatT,
+                >(&Default::default())
+            }
+
+            #[test]
+            pub fn test_autotile() {
+                cubecl_linalg::matmul::tests::simple::test_autotile::<
+                    TestRuntime,
+                    FloatT,
+                >(&Default::default())
+            }
     };
     ([$($float:ident),*]) => {
         mod simple {
@@ -54,6 +102,8 @@ macro_rules! testgen_matmul_simple {
                     $crate::testgen_matmul_simple!($float);
                 })*
             }
+
+            $crate::testgen_matmul_simple!(@integer);
         }
     };
 }
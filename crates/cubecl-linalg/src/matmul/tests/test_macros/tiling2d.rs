@@ -5,8 +5,17 @@ macro_rules! testgen_matmul_tiling2d {
     () => {
         mod matmul_tiling2d {
             $crate::testgen_matmul_tiling2d!(f32);
+            $crate::testgen_matmul_tiling2d!(@fixed_precision);
         }
     };
+    (@fixed_precision) => {
+            #[test]
+            pub fn test_rescale_period() {
+                cubecl_linalg::matmul::tests::tiling2d::test_rescale_period::<TestRuntime>(
+                    &Default::default(),
+                )
+            }
+    };
     ($float:ident) => {
             use super::*;
             use cubecl_linalg::matmul::tests;
@@ -56,6 +65,8 @@ macro_rules! testgen_matmul_tiling2d {
                     $crate::testgen_matmul_tiling2d!($float);
                 })*
             }
+
+            $crate::testgen_matmul_tiling2d!(@fixed_precision);
         }
     };
 }
@@ -1,10 +1,18 @@
 use std::fmt::Display;
 
-use cubecl_core::{CubeElement, Runtime, prelude::Float};
+use cubecl_core::{
+    CubeElement, Feature, Runtime,
+    client::ComputeClient,
+    ir::{Elem, FloatKind},
+    prelude::Float,
+};
 
-use crate::matmul::kernels::tiling2d;
+use crate::{
+    matmul::kernels::tiling2d::{self, Tiling2dConfig},
+    tensor::TensorHandle,
+};
 
-use super::test_utils::{MatmulTestCase, Sample, assert_equals_approx};
+use super::test_utils::{CastInto, MatmulTestCase, Sample, assert_equals_approx};
 
 pub fn test_one_cube<R: Runtime, F: Float + CubeElement + Display + Sample>(device: &R::Device) {
     let case = MatmulTestCase {
@@ -56,6 +64,115 @@ pub fn test_with_batches<R: Runtime, F: Float + CubeElement + Display + Sample>(
     test_tiling2d::<R, F>(case, device);
 }
 
+/// Accumulating a large common dimension in f16 loses most of the precision of the sums, which
+/// adding the partial sums into f32 every few blocks recovers.
+pub fn test_rescale_period<R: Runtime>(device: &R::Device) {
+    let client = R::client(device);
+    if !client
+        .properties()
+        .feature_enabled(Feature::Type(Elem::Float(FloatKind::F16)))
+    {
+        return;
+    }
+
+    let case = MatmulTestCase {
+        m: 64,
+        k: 4096,
+        n: 64,
+        batch: 1,
+    };
+    // Positive values, so the sums grow and the f16 rounding error with them. They are rounded
+    // to f16 so that every run multiplies the same values.
+    let values = |len: usize, seed: usize| {
+        (0..len)
+            .map(|i| half::f16::from_f32(((i * seed) % 101) as f32 / 101.0).to_f32())
+            .collect::<Vec<_>>()
+    };
+    let lhs = values(case.m * case.k, 37);
+    let rhs = values(case.k * case.n, 53);
+
+    let mut expected = vec![0.0f64; case.m * case.n];
+    for i in 0..case.m {
+        for j in 0..case.n {
+            for k in 0..case.k {
+                expected[i * case.n + j] += lhs[i * case.k + k] as f64 * rhs[k * case.n + j] as f64;
+            }
+        }
+    }
+
+    let error = |actual: Vec<f32>| {
+        actual
+            .iter()
+            .zip(expected.iter())
+            .map(|(actual, expected)| ((*actual as f64 - expected) / expected).abs())
+            .fold(0.0, f64::max)
+    };
+    let error_f16 = error(launch_f32_data::<R, half::f16>(
+        &client, &case, &lhs, &rhs, None,
+    ));
+    let error_rescaled = error(launch_f32_data::<R, half::f16>(
+        &client,
+        &case,
+        &lhs,
+        &rhs,
+        Some(4),
+    ));
+    let error_f32 = error(launch_f32_data::<R, f32>(&client, &case, &lhs, &rhs, None));
+
+    assert!(
+        error_rescaled < 0.01,
+        "Rescaled f16 error is too large: {error_rescaled}"
+    );
+    assert!(
+        error_rescaled * 4.0 < error_f16,
+        "Rescaled f16 error {error_rescaled} isn't much smaller than f16 error {error_f16}"
+    );
+    assert!(
+        error_f32 <= error_rescaled,
+        "f32 error {error_f32} is larger than rescaled f16 error {error_rescaled}"
+    );
+}
+
+/// Launch the tiling 2d matmul on the given values converted to `F`, and read the output back
+/// as f32.
+fn launch_f32_data<R: Runtime, F: Float + CubeElement + Sample + CastInto<f32>>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    case: &MatmulTestCase,
+    lhs: &[f32],
+    rhs: &[f32],
+    rescale_period: Option<usize>,
+) -> Vec<f32>
+where
+    f32: CastInto<F>,
+{
+    let create = |data: &[f32], shape: Vec<usize>| {
+        let data = data
+            .iter()
+            .map(|value| (*value).cast_into())
+            .collect::<Vec<F>>();
+        let (handle, strides) = client.create_tensor(F::as_bytes(&data), &shape, size_of::<F>());
+        TensorHandle::<R, F>::new(handle, shape, strides)
+    };
+    let lhs = create(lhs, vec![case.batch, case.m, case.k]);
+    let rhs = create(rhs, vec![case.batch, case.k, case.n]);
+
+    let config = Tiling2dConfig {
+        rescale_period,
+        ..Default::default()
+    };
+    let out = tiling2d::launch::<R, F>(client, lhs, rhs, case.empty_out(client), config);
+
+    let bytes = client.read_one_tensor(out.handle.binding_with_meta(
+        out.shape,
+        out.strides,
+        size_of::<F>(),
+    ));
+    F::from_bytes(&bytes)
+        .iter()
+        .map(|value| (*value).cast_into())
+        .collect()
+}
+
 fn test_tiling2d<R: Runtime, F: Float + CubeElement + Display + Sample>(
     case: MatmulTestCase,
     device: &R::Device,
@@ -127,6 +127,42 @@ where
         }
     }
 
+    /// View the same storage as elements of type `F`, without copying the data.
+    ///
+    /// When the element sizes differ, the last dimension must be contiguous, and its size as well
+    /// as every other stride are recomputed in elements of `F`, so they must be a multiple of its
+    /// size in bytes.
+    pub fn reinterpret<F: CubePrimitive>(&self) -> TensorHandle<R, F> {
+        let elem_size = size_of::<E>();
+        let new_elem_size = size_of::<F>();
+        let mut shape = self.shape.clone();
+        let mut strides = self.strides.clone();
+
+        if elem_size != new_elem_size {
+            let rank = shape.len();
+            assert!(
+                rank > 0 && strides[rank - 1] == 1,
+                "Can't reinterpret a tensor whose last dimension isn't contiguous"
+            );
+
+            let to_new_elems = |bytes: usize, what: &str| {
+                assert!(
+                    bytes % new_elem_size == 0,
+                    "The {what} of {bytes} bytes isn't a multiple of the {new_elem_size} bytes of {}",
+                    core::any::type_name::<F>()
+                );
+                bytes / new_elem_size
+            };
+
+            shape[rank - 1] = to_new_elems(shape[rank - 1] * elem_size, "last dimension");
+            for stride in strides[..rank - 1].iter_mut() {
+                *stride = to_new_elems(*stride * elem_size, "stride");
+            }
+        }
+
+        TensorHandle::new(self.handle.clone(), shape, strides)
+    }
+
     fn contiguous_strides(shape: &[usize]) -> Vec<usize> {
         let mut strides = Vec::with_capacity(shape.len());
 
@@ -1,4 +1,4 @@
-use super::TensorHandle;
+use super::{TensorHandle, is_contiguous_elemwise};
 use cubecl::prelude::*;
 use cubecl_core::{self as cubecl, calculate_cube_count_elemwise, tensor_line_size_parallel};
 use cubecl_std::{FastDivmod, FastDivmodArgs};
@@ -56,6 +56,9 @@ pub fn index_offset_contiguous<N: CubePrimitive>(
 #[derive(CubeType, CubeLaunch)]
 pub enum StridedLayout {
     Pitched(FastDivmod),
+    /// Any other layout, such as a transposed or sliced view. Consecutive indexes aren't
+    /// consecutive in memory, so each index has to be translated separately.
+    Strided,
     None,
 }
 
@@ -69,6 +72,11 @@ impl<R: Runtime> StridedLayoutArgs<'_, R> {
     pub fn strided(client: &ComputeClient<R::Server, R::Channel>, shape: u32) -> Self {
         Self::Pitched(FastDivmodArgs::new(client, shape))
     }
+
+    /// Arbitrary strides, translated with the full stride array of the tensor.
+    pub fn arbitrary() -> Self {
+        Self::Strided
+    }
 }
 
 #[cube]
@@ -82,6 +90,20 @@ impl StridedLayout {
                 let offset = y * tensor.stride(tensor.rank() - 2) + x;
                 offset / tensor.line_size()
             }
+            StridedLayout::Strided => {
+                let rank = tensor.rank();
+                let mut remainder = index * tensor.line_size();
+                let mut offset = 0;
+
+                for i in 0..rank {
+                    let dim = rank - i - 1;
+                    let shape = tensor.shape(dim);
+                    offset += remainder % shape * tensor.stride(dim);
+                    remainder /= shape;
+                }
+
+                offset / tensor.line_size()
+            }
             StridedLayout::None => index,
         }
     }
@@ -179,18 +201,9 @@ pub fn into_contiguous_pitched<R: Runtime, E: CubePrimitive>(
 pub fn into_contiguous_prefetch<R: Runtime, E: CubePrimitive>(
     client: &ComputeClient<R::Server, R::Channel>,
     input: &TensorHandleRef<'_, R>,
-    mut elems_per_unit: u32,
+    elems_per_unit: u32,
     pitched: bool,
 ) -> TensorHandle<R, E> {
-    // Vectorization is only enabled when the last dimension is contiguous.
-    let rank = input.strides.len();
-    let vectorization_factor = tensor_line_size_parallel(
-        R::supported_line_sizes().iter().cloned(),
-        input.shape,
-        input.strides,
-        rank - 1,
-    );
-
     let num_elems: usize = input.shape.iter().product();
     let output = if pitched {
         TensorHandle::empty(client, input.shape.to_vec())
@@ -199,20 +212,150 @@ pub fn into_contiguous_prefetch<R: Runtime, E: CubePrimitive>(
         TensorHandle::new_contiguous(input.shape.to_vec(), handle)
     };
 
+    into_contiguous_ref_prefetch::<R, E>(client, input, &output.as_ref(), elems_per_unit);
+
+    output
+}
+
+/// Copy a jit tensor into `output`, which must have the same shape.
+///
+/// The output can be any view with its own strides, e.g. a transposed or sliced region of a
+/// larger tensor, in which case only the elements of the view are written.
+pub fn into_contiguous_ref<R: Runtime, E: CubePrimitive>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: &TensorHandleRef<'_, R>,
+    output: &TensorHandleRef<'_, R>,
+) {
+    into_contiguous_ref_prefetch::<R, E>(client, input, output, 1)
+}
+
+/// How an op requiring a [contiguous](is_contiguous_elemwise) input handles a strided one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ContiguityPolicy {
+    /// Return a [NotContiguousError] when the input isn't contiguous.
+    #[default]
+    Require,
+    /// Copy the input into a contiguous buffer with [make_contiguous] when it isn't contiguous.
+    Copy,
+}
+
+/// Error returned when a tensor isn't contiguous under [ContiguityPolicy::Require].
+#[derive(Hash, Eq, PartialEq, Debug, Clone)]
+pub struct NotContiguousError {
+    /// The shape of the tensor.
+    pub shape: Vec<usize>,
+    /// The strides of the tensor.
+    pub strides: Vec<usize>,
+}
+
+impl core::fmt::Display for NotContiguousError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "A tensor with shape {:?} and strides {:?} isn't contiguous, use make_contiguous or ContiguityPolicy::Copy.",
+            self.shape, self.strides
+        )
+    }
+}
+
+/// Return a contiguous tensor with the same values as `input`.
+///
+/// A strided input is copied into a fresh contiguous buffer with [into_contiguous], while a
+/// contiguous one is returned as is, sharing its buffer.
+pub fn make_contiguous<R: Runtime, E: CubePrimitive>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: &TensorHandleRef<'_, R>,
+) -> TensorHandle<R, E> {
+    match is_contiguous_elemwise(&[input]) {
+        true => TensorHandle::from_ref(input),
+        false => into_contiguous(client, input),
+    }
+}
+
+/// Return `input` as a contiguous tensor following the `policy`, for the entry points of ops
+/// that only work on contiguous tensors.
+pub fn ensure_contiguous<R: Runtime, E: CubePrimitive>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: &TensorHandleRef<'_, R>,
+    policy: ContiguityPolicy,
+) -> Result<TensorHandle<R, E>, NotContiguousError> {
+    match policy {
+        ContiguityPolicy::Require if !is_contiguous_elemwise(&[input]) => Err(NotContiguousError {
+            shape: input.shape.to_vec(),
+            strides: input.strides.to_vec(),
+        }),
+        ContiguityPolicy::Require => Ok(TensorHandle::from_ref(input)),
+        ContiguityPolicy::Copy => Ok(make_contiguous(client, input)),
+    }
+}
+
+/// View a tensor with a different `shape` of the same number of elements, without copying.
+///
+/// The view is only valid when the input is contiguous, strided inputs are handled following the
+/// `policy`.
+pub fn reshape<R: Runtime, E: CubePrimitive>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: &TensorHandleRef<'_, R>,
+    shape: Vec<usize>,
+    policy: ContiguityPolicy,
+) -> Result<TensorHandle<R, E>, NotContiguousError> {
+    assert_eq!(
+        input.shape.iter().product::<usize>(),
+        shape.iter().product::<usize>(),
+        "Can't reshape {:?} into {shape:?}, the number of elements differs",
+        input.shape
+    );
+
+    let tensor = ensure_contiguous::<R, E>(client, input, policy)?;
+    Ok(TensorHandle::new_contiguous(shape, tensor.handle))
+}
+
+fn into_contiguous_ref_prefetch<R: Runtime, E: CubePrimitive>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: &TensorHandleRef<'_, R>,
+    output: &TensorHandleRef<'_, R>,
+    mut elems_per_unit: u32,
+) {
+    assert_eq!(input.shape, output.shape, "input and output shapes differ");
+
+    // Vectorization is only enabled when the last dimension is contiguous, in both tensors.
+    let rank = input.strides.len();
+    let line_size = |tensor: &TensorHandleRef<'_, R>| {
+        tensor_line_size_parallel(
+            R::supported_line_sizes().iter().cloned(),
+            tensor.shape,
+            tensor.strides,
+            rank - 1,
+        )
+    };
+    let vectorization_factor = line_size(input).min(line_size(output));
+
+    let num_elems: usize = input.shape.iter().product();
     let mut num_elems_per_unit = vectorization_factor as u32 * elems_per_unit;
 
     let last_dim = output.shape[rank - 1];
-    let is_padded = rank > 1 && last_dim != output.strides[rank - 2];
+    let layout = output_layout(output.shape, output.strides);
 
-    // If tensor is strided, elems_per_unit must be compatible with last dim
-    while is_padded && last_dim % num_elems_per_unit as usize != 0 {
-        elems_per_unit /= 2;
-        num_elems_per_unit /= 2;
+    match layout {
+        // If tensor is strided, elems_per_unit must be compatible with last dim
+        OutputLayout::Pitched => {
+            while last_dim % num_elems_per_unit as usize != 0 {
+                elems_per_unit /= 2;
+                num_elems_per_unit /= 2;
+            }
+        }
+        // The elements of a unit are only consecutive in memory within a line
+        OutputLayout::Strided => {
+            elems_per_unit = 1;
+            num_elems_per_unit = vectorization_factor as u32;
+        }
+        OutputLayout::Contiguous => {}
     }
 
-    let out_layout = match is_padded {
-        true => StridedLayoutArgs::strided(client, last_dim as u32),
-        false => StridedLayoutArgs::none(),
+    let out_layout = match layout {
+        OutputLayout::Contiguous => StridedLayoutArgs::none(),
+        OutputLayout::Pitched => StridedLayoutArgs::strided(client, last_dim as u32),
+        OutputLayout::Strided => StridedLayoutArgs::arbitrary(),
     };
 
     let cube_dim = CubeDim::default();
@@ -224,11 +367,33 @@ pub fn into_contiguous_prefetch<R: Runtime, E: CubePrimitive>(
         cube_count,
         cube_dim,
         input.as_tensor_arg(vectorization_factor),
-        output.as_ref().as_tensor_arg(vectorization_factor),
+        output.as_tensor_arg(vectorization_factor),
         out_layout,
         Some(rank as u32),
         elems_per_unit,
     );
+}
 
-    output
+enum OutputLayout {
+    Contiguous,
+    /// Contiguous rows padded to a pitch, as allocated by
+    /// [create_tensor](cubecl_runtime::client::ComputeClient::create_tensor).
+    Pitched,
+    Strided,
+}
+
+fn output_layout(shape: &[usize], strides: &[usize]) -> OutputLayout {
+    let rank = shape.len();
+    if strides[rank - 1] != 1 {
+        return OutputLayout::Strided;
+    }
+
+    // Only the rows can be padded, every other dimension must be packed.
+    let packed = (0..rank.saturating_sub(2)).all(|d| strides[d] == strides[d + 1] * shape[d + 1]);
+    match packed {
+        false => OutputLayout::Strided,
+        true if rank > 1 && strides[rank - 2] > shape[rank - 1] => OutputLayout::Pitched,
+        true if rank > 1 && strides[rank - 2] < shape[rank - 1] => OutputLayout::Strided,
+        true => OutputLayout::Contiguous,
+    }
 }
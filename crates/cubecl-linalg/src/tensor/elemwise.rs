@@ -0,0 +1,289 @@
+use cubecl::prelude::*;
+use cubecl_core::{
+    self as cubecl, MAX_ELEMWISE_CHUNK_SIZE, calculate_cube_count_elemwise,
+    calculate_cube_count_elemwise_chunked, tensor_line_size_parallel,
+};
+
+use super::index_offset_contiguous;
+
+/// Whether the tensors all have the same shape and are packed in row-major order, in which case
+/// the absolute position of an element is its index in every tensor.
+///
+/// Dimensions of size 1 are ignored, since their stride is never used.
+pub fn is_contiguous_elemwise<R: Runtime>(tensors: &[&TensorHandleRef<'_, R>]) -> bool {
+    let Some(first) = tensors.first() else {
+        return true;
+    };
+
+    tensors.iter().all(|tensor| {
+        let mut current = 1;
+        tensor.shape == first.shape
+            && tensor
+                .shape
+                .iter()
+                .zip(tensor.strides)
+                .rev()
+                .all(|(shape, stride)| {
+                    let packed = *shape == 1 || *stride == current;
+                    current *= shape;
+                    packed
+                })
+    })
+}
+
+/// Returns the index in `tensor` of the element at the absolute position `index`.
+///
+/// With `contiguous`, the index is used as is, otherwise it's translated with the shape and
+/// strides of the tensor. Kernels taking `contiguous` as a comptime argument are compiled once
+/// for each variant, so the contiguous one doesn't pay for the translation.
+#[cube]
+pub fn index_elemwise<N: CubePrimitive>(
+    tensor: &Tensor<Line<N>>,
+    index: u32,
+    #[comptime] contiguous: bool,
+) -> u32 {
+    if comptime!(contiguous) {
+        index
+    } else {
+        index_offset_contiguous::<N>(tensor, index, None)
+    }
+}
+
+/// Whether the tensor holds a single element, e.g. a rank-0 tensor produced by a reduction,
+/// which is then broadcast over the whole output by the elementwise operations.
+pub fn is_scalar_elemwise<R: Runtime>(tensor: &TensorHandleRef<'_, R>) -> bool {
+    tensor.shape.iter().product::<usize>() == 1
+}
+
+/// Reads the single element of a [scalar](is_scalar_elemwise) operand, filling a line of
+/// `line_size`. Nothing is read when the operand isn't a scalar.
+#[cube]
+fn read_scalar<N: Numeric>(
+    tensor: &Tensor<Line<N>>,
+    #[comptime] line_size: u32,
+    #[comptime] scalar: bool,
+) -> Line<N> {
+    if comptime!(scalar) {
+        Line::empty(line_size).fill(tensor[0][0])
+    } else {
+        Line::empty(line_size)
+    }
+}
+
+#[cube(launch_unchecked)]
+fn add_kernel<N: Numeric>(
+    lhs: &Tensor<Line<N>>,
+    rhs: &Tensor<Line<N>>,
+    output: &mut Tensor<Line<N>>,
+    num_lines: u32,
+    #[comptime] contiguous: bool,
+    #[comptime] lhs_scalar: bool,
+    #[comptime] rhs_scalar: bool,
+) {
+    let line_size = output.line_size();
+    let lhs_value = read_scalar::<N>(lhs, line_size, lhs_scalar);
+    let rhs_value = read_scalar::<N>(rhs, line_size, rhs_scalar);
+
+    // The buffer of a strided view can be larger than the view, so its length can't be used.
+    // The cube count of a chunk can be capped, so a unit may have to process multiple lines.
+    for index in range_stepped(ABSOLUTE_POS, num_lines, CUBE_COUNT * CUBE_DIM) {
+        let lhs_line = if comptime!(lhs_scalar) {
+            lhs_value
+        } else {
+            lhs[index_elemwise::<N>(lhs, index, contiguous)]
+        };
+        let rhs_line = if comptime!(rhs_scalar) {
+            rhs_value
+        } else {
+            rhs[index_elemwise::<N>(rhs, index, contiguous)]
+        };
+        output[index_elemwise::<N>(output, index, contiguous)] = lhs_line + rhs_line;
+    }
+}
+
+/// Add `lhs` and `rhs` elementwise into `output`, which must all have the same shape, except for
+/// [scalar](is_scalar_elemwise) operands of any rank broadcast over the output.
+///
+/// When all the tensors are [contiguous](is_contiguous_elemwise), the positions of the units are
+/// used directly as indexes. Otherwise each index is translated with the strides of its tensor,
+/// which supports any layout such as transposed or sliced views. The single element of a scalar
+/// operand is read once by each unit.
+///
+/// Contiguous tensors with more lines than a kernel can index are added one
+/// [chunk](cubecl_core::ElemwiseChunk) at a time.
+pub fn add<R: Runtime, N: Numeric>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: &TensorHandleRef<'_, R>,
+    rhs: &TensorHandleRef<'_, R>,
+    output: &TensorHandleRef<'_, R>,
+) {
+    let tensors = [lhs, rhs, output]
+        .into_iter()
+        .filter(|tensor| !is_scalar_elemwise(tensor) || tensor.shape == output.shape)
+        .collect::<Vec<_>>();
+    let contiguous = is_contiguous_elemwise(&tensors);
+    launch_add::<R, N>(client, lhs, rhs, output, contiguous)
+}
+
+/// Same as [add], but always translating the indexes with the strides of each tensor, even when
+/// the tensors are contiguous.
+pub fn add_strided<R: Runtime, N: Numeric>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: &TensorHandleRef<'_, R>,
+    rhs: &TensorHandleRef<'_, R>,
+    output: &TensorHandleRef<'_, R>,
+) {
+    launch_add::<R, N>(client, lhs, rhs, output, false)
+}
+
+fn launch_add<R: Runtime, N: Numeric>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: &TensorHandleRef<'_, R>,
+    rhs: &TensorHandleRef<'_, R>,
+    output: &TensorHandleRef<'_, R>,
+    contiguous: bool,
+) {
+    // Operands with the shape of the output are indexed like it, even with a single element.
+    let lhs_scalar = lhs.shape != output.shape && is_scalar_elemwise(lhs);
+    let rhs_scalar = rhs.shape != output.shape && is_scalar_elemwise(rhs);
+    assert!(
+        lhs_scalar || lhs.shape == output.shape,
+        "lhs and output shapes differ"
+    );
+    assert!(
+        rhs_scalar || rhs.shape == output.shape,
+        "rhs and output shapes differ"
+    );
+
+    // Vectorization is only enabled when the last dimension is contiguous, in all the tensors
+    // that aren't broadcast.
+    let rank = output.shape.len();
+    let line_size = |tensor: &TensorHandleRef<'_, R>, scalar: bool| {
+        if scalar {
+            return u8::MAX;
+        }
+        tensor_line_size_parallel(
+            R::supported_line_sizes().iter().cloned(),
+            tensor.shape,
+            tensor.strides,
+            rank.saturating_sub(1),
+        )
+    };
+    let line_size = line_size(lhs, lhs_scalar)
+        .min(line_size(rhs, rhs_scalar))
+        .min(line_size(output, false));
+    // Scalars are read one element at a time, and broadcast to the lines of the output.
+    let operand_line_size = |scalar: bool| if scalar { 1 } else { line_size };
+
+    let num_lines = output.shape.iter().product::<usize>() / line_size as usize;
+    if contiguous && num_lines as u64 > MAX_ELEMWISE_CHUNK_SIZE {
+        launch_add_chunked::<R, N>(
+            client,
+            (lhs, lhs_scalar),
+            (rhs, rhs_scalar),
+            output,
+            line_size,
+            num_lines as u64,
+        );
+        return;
+    }
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_lines, cube_dim);
+
+    unsafe {
+        add_kernel::launch_unchecked::<N, R>(
+            client,
+            cube_count,
+            cube_dim,
+            lhs.as_tensor_arg(operand_line_size(lhs_scalar)),
+            rhs.as_tensor_arg(operand_line_size(rhs_scalar)),
+            output.as_tensor_arg(line_size),
+            ScalarArg::new(num_lines as u32),
+            contiguous,
+            lhs_scalar,
+            rhs_scalar,
+        );
+    }
+}
+
+/// Launch one kernel per chunk of contiguous tensors, on flattened views of the tensors starting
+/// at the first line of the chunk, so every index fits in 32 bits. Scalar operands are passed to
+/// every chunk as they are.
+fn launch_add_chunked<R: Runtime, N: Numeric>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    (lhs, lhs_scalar): (&TensorHandleRef<'_, R>, bool),
+    (rhs, rhs_scalar): (&TensorHandleRef<'_, R>, bool),
+    output: &TensorHandleRef<'_, R>,
+    line_size: u8,
+    num_lines: u64,
+) {
+    let cube_dim = CubeDim::default();
+    let max_cube_count = client.properties().hardware_properties().max_cube_count;
+    let line_bytes = (line_size as usize * size_of::<N>()) as u64;
+
+    for chunk in calculate_cube_count_elemwise_chunked(num_lines, cube_dim, max_cube_count) {
+        let shape = [chunk.num_elems as usize * line_size as usize];
+        let strides = [1];
+        let offset = |tensor: &TensorHandleRef<'_, R>| {
+            tensor
+                .handle
+                .clone()
+                .offset_start(chunk.offset * line_bytes)
+        };
+        let output_chunk = offset(output);
+        let operand = |tensor: &TensorHandleRef<'_, R>, scalar: bool| {
+            if scalar {
+                tensor.handle.clone()
+            } else {
+                offset(tensor)
+            }
+        };
+        let (lhs_chunk, rhs_chunk) = (operand(lhs, lhs_scalar), operand(rhs, rhs_scalar));
+        let (lhs_layout, rhs_layout) = (
+            chunk_layout(lhs, lhs_scalar, &strides, &shape, line_size),
+            chunk_layout(rhs, rhs_scalar, &strides, &shape, line_size),
+        );
+
+        unsafe {
+            add_kernel::launch_unchecked::<N, R>(
+                client,
+                chunk.cube_count,
+                cube_dim,
+                TensorArg::from_raw_parts::<N>(
+                    &lhs_chunk,
+                    lhs_layout.0,
+                    lhs_layout.1,
+                    lhs_layout.2,
+                ),
+                TensorArg::from_raw_parts::<N>(
+                    &rhs_chunk,
+                    rhs_layout.0,
+                    rhs_layout.1,
+                    rhs_layout.2,
+                ),
+                TensorArg::from_raw_parts::<N>(&output_chunk, &strides, &shape, line_size),
+                ScalarArg::new(chunk.num_elems as u32),
+                true,
+                lhs_scalar,
+                rhs_scalar,
+            );
+        }
+    }
+}
+
+/// The strides, shape and line size of an operand of a chunk: the flattened chunk, or the
+/// operand itself when it's a scalar.
+fn chunk_layout<'a, R: Runtime>(
+    tensor: &TensorHandleRef<'a, R>,
+    scalar: bool,
+    strides: &'a [usize],
+    shape: &'a [usize],
+    line_size: u8,
+) -> (&'a [usize], &'a [usize], u8) {
+    if scalar {
+        (tensor.strides, tensor.shape, 1)
+    } else {
+        (strides, shape, line_size)
+    }
+}
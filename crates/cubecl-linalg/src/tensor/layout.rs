@@ -64,6 +64,72 @@ pub fn matrix_batch_layout(strides: &[usize]) -> MatrixBatchLayout {
     }
 }
 
+/// Order of the dimensions of a contiguous 4D tensor, from the outermost to the innermost.
+///
+/// Weights use the same descriptors with the output channels as the batch dimension, i.e.
+/// `[out_channels, in_channels, kernel_h, kernel_w]` for [NCHW](TensorLayout::Nchw).
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum TensorLayout {
+    /// `[batches, channels, height, width]`, channels first.
+    Nchw,
+    /// `[batches, height, width, channels]`, channels last.
+    Nhwc,
+}
+
+/// Error returned when a shape doesn't match its [TensorLayout].
+#[derive(Hash, Eq, PartialEq, Debug, Clone)]
+pub enum LayoutError {
+    /// The rank of the shape differs from the number of dimensions of the layout.
+    RankMismatch {
+        /// The layout of the tensor.
+        layout: TensorLayout,
+        /// The rank of the shape.
+        rank: usize,
+    },
+}
+
+impl core::fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            LayoutError::RankMismatch { layout, rank } => write!(
+                f,
+                "A tensor with the {layout:?} layout should have a rank of {}, got {rank}.",
+                TensorLayout::RANK
+            ),
+        }
+    }
+}
+
+impl TensorLayout {
+    /// The number of dimensions of every layout.
+    pub const RANK: usize = 4;
+
+    /// Contiguous strides of a tensor with `shape`, given in the order of this layout.
+    pub fn strides(&self, shape: &[usize]) -> Result<[usize; 4], LayoutError> {
+        let [_, d1, d2, d3] = self.dims(shape)?;
+        Ok([d1 * d2 * d3, d2 * d3, d3, 1])
+    }
+
+    /// Shape and strides viewing a contiguous tensor with `shape`, given in the order of this
+    /// layout, as a `[batches, height, width, channels]` tensor.
+    pub fn nhwc_view(&self, shape: &[usize]) -> Result<([usize; 4], [usize; 4]), LayoutError> {
+        let [n, d1, d2, d3] = self.dims(shape)?;
+        let [s0, s1, s2, s3] = self.strides(shape)?;
+
+        Ok(match self {
+            TensorLayout::Nchw => ([n, d2, d3, d1], [s0, s2, s3, s1]),
+            TensorLayout::Nhwc => ([n, d1, d2, d3], [s0, s1, s2, s3]),
+        })
+    }
+
+    fn dims(&self, shape: &[usize]) -> Result<[usize; 4], LayoutError> {
+        shape.try_into().map_err(|_| LayoutError::RankMismatch {
+            layout: *self,
+            rank: shape.len(),
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -199,4 +265,29 @@ mod tests {
             unreachable!()
         }
     }
+
+    #[test]
+    fn nchw_is_viewed_as_nhwc() {
+        let (shape, strides) = TensorLayout::Nchw.nhwc_view(&[2, 3, 4, 5]).unwrap();
+        assert_eq!(shape, [2, 4, 5, 3]);
+        assert_eq!(strides, [60, 5, 1, 20]);
+    }
+
+    #[test]
+    fn nhwc_view_is_contiguous() {
+        let (shape, strides) = TensorLayout::Nhwc.nhwc_view(&[2, 4, 5, 3]).unwrap();
+        assert_eq!(shape, [2, 4, 5, 3]);
+        assert_eq!(strides, [60, 15, 3, 1]);
+    }
+
+    #[test]
+    fn layout_rejects_wrong_rank() {
+        assert_eq!(
+            TensorLayout::Nchw.strides(&[3, 4, 5]),
+            Err(LayoutError::RankMismatch {
+                layout: TensorLayout::Nchw,
+                rank: 3
+            })
+        );
+    }
 }
@@ -1,10 +1,13 @@
 mod base;
 mod contiguous;
+mod elemwise;
 pub mod identity;
 mod layout;
+pub mod transpose;
 
 pub use base::*;
 pub use contiguous::*;
+pub use elemwise::*;
 pub use identity::*;
 pub use layout::*;
 
@@ -0,0 +1,118 @@
+use cubecl::prelude::*;
+use cubecl_core::{self as cubecl, CubeElement};
+
+use crate::tensor::{
+    ContiguityPolicy, NotContiguousError, TensorHandle, into_contiguous_ref, make_contiguous,
+    reshape,
+};
+
+/// Copies a contiguous `[3, 4]` tensor into a transposed view of the top left corner of a larger
+/// `[8, 6]` buffer, and checks that only the elements of the view changed.
+pub fn test_into_contiguous_strided_output<R: Runtime>(device: &R::Device) {
+    let client = R::client(device);
+    let (rows, cols) = (8, 6);
+    let values: Vec<f32> = (0..12).map(|x| x as f32).collect();
+    let input =
+        TensorHandle::<R, f32>::new_contiguous(vec![3, 4], client.create(f32::as_bytes(&values)));
+
+    let buffer = client.create(f32::as_bytes(&vec![-1.0f32; rows * cols]));
+    let view = TensorHandle::<R, f32>::new(buffer.clone(), vec![3, 4], vec![1, cols]);
+
+    into_contiguous_ref::<R, f32>(&client, &input.as_ref(), &view.as_ref());
+
+    let mut expected = vec![-1.0f32; rows * cols];
+    for i in 0..3 {
+        for j in 0..4 {
+            expected[j * cols + i] = values[i * 4 + j];
+        }
+    }
+    let actual = client.read_one(buffer.binding());
+
+    assert_eq!(f32::from_bytes(&actual), expected);
+}
+
+/// Copies a contiguous `[2, 3, 8]` tensor into every other row of the first 3 pairs of rows of
+/// each batch of a larger buffer, a view that is contiguous in its last dimension only.
+pub fn test_into_contiguous_sliced_output<R: Runtime>(device: &R::Device) {
+    let client = R::client(device);
+    let values: Vec<f32> = (0..2 * 3 * 8).map(|x| x as f32).collect();
+    let input = TensorHandle::<R, f32>::new_contiguous(
+        vec![2, 3, 8],
+        client.create(f32::as_bytes(&values)),
+    );
+
+    let buffer = client.create(f32::as_bytes(&vec![-1.0f32; 2 * 8 * 8]));
+    let view = TensorHandle::<R, f32>::new(buffer.clone(), vec![2, 3, 8], vec![64, 16, 1]);
+
+    into_contiguous_ref::<R, f32>(&client, &input.as_ref(), &view.as_ref());
+
+    let mut expected = vec![-1.0f32; 2 * 8 * 8];
+    for b in 0..2 {
+        for r in 0..3 {
+            for c in 0..8 {
+                expected[b * 64 + r * 16 + c] = values[(b * 3 + r) * 8 + c];
+            }
+        }
+    }
+    let actual = client.read_one(buffer.binding());
+
+    assert_eq!(f32::from_bytes(&actual), expected);
+}
+
+/// Reshapes a transposed `[3, 4]` view, which isn't contiguous, into a `[2, 6]` tensor. It's
+/// rejected when contiguity is required, and succeeds once the view is made contiguous.
+pub fn test_reshape_requires_contiguous<R: Runtime>(device: &R::Device) {
+    let client = R::client(device);
+    let values: Vec<f32> = (0..12).map(|x| x as f32).collect();
+    let transposed = TensorHandle::<R, f32>::new(
+        client.create(f32::as_bytes(&values)),
+        vec![3, 4],
+        vec![1, 3],
+    );
+
+    let error = reshape::<R, f32>(
+        &client,
+        &transposed.as_ref(),
+        vec![2, 6],
+        ContiguityPolicy::Require,
+    )
+    .expect_err("A transposed view isn't contiguous");
+    assert_eq!(
+        error,
+        NotContiguousError {
+            shape: vec![3, 4],
+            strides: vec![1, 3],
+        }
+    );
+
+    let mut expected = vec![0.0f32; 12];
+    for i in 0..3 {
+        for j in 0..4 {
+            expected[i * 4 + j] = values[j * 3 + i];
+        }
+    }
+
+    let contiguous = make_contiguous::<R, f32>(&client, &transposed.as_ref());
+    assert_eq!(contiguous.strides, vec![4, 1]);
+    let reshaped = reshape::<R, f32>(
+        &client,
+        &contiguous.as_ref(),
+        vec![2, 6],
+        ContiguityPolicy::Require,
+    )
+    .expect("A contiguous tensor can be reshaped");
+    assert_eq!(reshaped.shape, vec![2, 6]);
+    assert_eq!(reshaped.strides, vec![6, 1]);
+    let actual = client.read_one(reshaped.handle.binding());
+    assert_eq!(f32::from_bytes(&actual), expected);
+
+    let copied = reshape::<R, f32>(
+        &client,
+        &transposed.as_ref(),
+        vec![2, 6],
+        ContiguityPolicy::Copy,
+    )
+    .expect("A strided tensor is copied with the copy policy");
+    let actual = client.read_one(copied.handle.binding());
+    assert_eq!(f32::from_bytes(&actual), expected);
+}
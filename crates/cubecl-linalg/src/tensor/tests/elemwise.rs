@@ -0,0 +1,139 @@
+use cubecl::prelude::*;
+use cubecl_core::{self as cubecl, CubeElement};
+
+use cubecl_reduce::instructions::Sum;
+
+use crate::tensor::{TensorHandle, add, add_strided, is_contiguous_elemwise, is_scalar_elemwise};
+
+/// Adds two contiguous `[4, 64]` tensors, which takes the contiguous path, and checks it against
+/// the strided path on the same tensors.
+pub fn test_add_contiguous<R: Runtime>(device: &R::Device) {
+    let client = R::client(device);
+    let lhs_values: Vec<f32> = (0..256).map(|x| x as f32).collect();
+    let rhs_values: Vec<f32> = (0..256).map(|x| (x * 3) as f32).collect();
+    let lhs = TensorHandle::<R, f32>::new_contiguous(
+        vec![4, 64],
+        client.create(f32::as_bytes(&lhs_values)),
+    );
+    let rhs = TensorHandle::<R, f32>::new_contiguous(
+        vec![4, 64],
+        client.create(f32::as_bytes(&rhs_values)),
+    );
+    let output =
+        TensorHandle::<R, f32>::new_contiguous(vec![4, 64], client.empty(256 * size_of::<f32>()));
+    let output_strided =
+        TensorHandle::<R, f32>::new_contiguous(vec![4, 64], client.empty(256 * size_of::<f32>()));
+
+    assert!(is_contiguous_elemwise(&[
+        &lhs.as_ref(),
+        &rhs.as_ref(),
+        &output.as_ref()
+    ]));
+
+    add::<R, f32>(&client, &lhs.as_ref(), &rhs.as_ref(), &output.as_ref());
+    add_strided::<R, f32>(
+        &client,
+        &lhs.as_ref(),
+        &rhs.as_ref(),
+        &output_strided.as_ref(),
+    );
+
+    let expected: Vec<f32> = (0..256).map(|x| (x * 4) as f32).collect();
+    let actual = client.read_one(output.handle.binding());
+    let actual_strided = client.read_one(output_strided.handle.binding());
+
+    assert_eq!(f32::from_bytes(&actual), expected);
+    assert_eq!(f32::from_bytes(&actual_strided), expected);
+}
+
+/// Adds a contiguous `[3, 4]` tensor to a transposed view of a `[4, 3]` tensor, which takes the
+/// strided path.
+pub fn test_add_transposed<R: Runtime>(device: &R::Device) {
+    let client = R::client(device);
+    let lhs_values: Vec<f32> = (0..12).map(|x| x as f32).collect();
+    let rhs_values: Vec<f32> = (0..12).map(|x| (x * 100) as f32).collect();
+    let lhs = TensorHandle::<R, f32>::new_contiguous(
+        vec![3, 4],
+        client.create(f32::as_bytes(&lhs_values)),
+    );
+    let rhs = TensorHandle::<R, f32>::new(
+        client.create(f32::as_bytes(&rhs_values)),
+        vec![3, 4],
+        vec![1, 3],
+    );
+    let output =
+        TensorHandle::<R, f32>::new_contiguous(vec![3, 4], client.empty(12 * size_of::<f32>()));
+
+    assert!(!is_contiguous_elemwise(&[
+        &lhs.as_ref(),
+        &rhs.as_ref(),
+        &output.as_ref()
+    ]));
+
+    add::<R, f32>(&client, &lhs.as_ref(), &rhs.as_ref(), &output.as_ref());
+
+    let mut expected = vec![0.0f32; 12];
+    for i in 0..3 {
+        for j in 0..4 {
+            expected[i * 4 + j] = lhs_values[i * 4 + j] + rhs_values[j * 3 + i];
+        }
+    }
+    let actual = client.read_one(output.handle.binding());
+
+    assert_eq!(f32::from_bytes(&actual), expected);
+}
+
+/// Sums a tensor into a single element with a reduction, and adds it to a full `[4, 64]` tensor
+/// both as the `[1]` tensor produced by the reduction and as a rank-0 view of it, on either side.
+/// Checks the results against adding the sum on the host.
+pub fn test_add_reduced_scalar<R: Runtime>(device: &R::Device) {
+    let client = R::client(device);
+    let values: Vec<f32> = (0..16).map(|x| x as f32).collect();
+    let array_values: Vec<f32> = (0..256).map(|x| x as f32).collect();
+    let input =
+        TensorHandle::<R, f32>::new_contiguous(vec![16], client.create(f32::as_bytes(&values)));
+    let sum = TensorHandle::<R, f32>::new_contiguous(vec![1], client.empty(size_of::<f32>()));
+    cubecl_reduce::reduce::<R, f32, f32, Sum>(&client, input.as_ref(), sum.as_ref(), 0, None, ())
+        .unwrap();
+    let scalar = TensorHandle::<R, f32>::new(sum.handle.clone(), vec![], vec![]);
+    let array = TensorHandle::<R, f32>::new_contiguous(
+        vec![4, 64],
+        client.create(f32::as_bytes(&array_values)),
+    );
+
+    assert!(is_scalar_elemwise(&sum.as_ref()));
+    assert!(is_scalar_elemwise(&scalar.as_ref()));
+
+    let expected_sum: f32 = values.iter().sum();
+    let expected: Vec<f32> = array_values.iter().map(|x| x + expected_sum).collect();
+
+    for operand in [&sum, &scalar] {
+        let lhs_output = TensorHandle::<R, f32>::new_contiguous(
+            vec![4, 64],
+            client.empty(256 * size_of::<f32>()),
+        );
+        let rhs_output = TensorHandle::<R, f32>::new_contiguous(
+            vec![4, 64],
+            client.empty(256 * size_of::<f32>()),
+        );
+
+        add::<R, f32>(
+            &client,
+            &operand.as_ref(),
+            &array.as_ref(),
+            &lhs_output.as_ref(),
+        );
+        add::<R, f32>(
+            &client,
+            &array.as_ref(),
+            &operand.as_ref(),
+            &rhs_output.as_ref(),
+        );
+
+        let lhs_actual = client.read_one(lhs_output.handle.binding());
+        let rhs_actual = client.read_one(rhs_output.handle.binding());
+
+        assert_eq!(f32::from_bytes(&lhs_actual), expected);
+        assert_eq!(f32::from_bytes(&rhs_actual), expected);
+    }
+}
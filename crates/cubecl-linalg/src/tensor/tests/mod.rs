@@ -1,4 +1,8 @@
+pub mod contiguous;
+pub mod elemwise;
 pub mod identity;
+pub mod reinterpret;
+pub mod transpose;
 
 mod test_macros;
 mod test_utils;
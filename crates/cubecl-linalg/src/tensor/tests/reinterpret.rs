@@ -0,0 +1,75 @@
+use cubecl::prelude::*;
+use cubecl_core::{self as cubecl, CubeElement};
+
+use crate::tensor::TensorHandle;
+
+#[cube(launch_unchecked)]
+fn flip_sign_bits(tensor: &mut Tensor<u32>) {
+    if ABSOLUTE_POS < tensor.len() {
+        tensor[ABSOLUTE_POS] = tensor[ABSOLUTE_POS] ^ 0x8000_0000u32;
+    }
+}
+
+#[cube(launch_unchecked)]
+fn double(tensor: &mut Tensor<f32>) {
+    if ABSOLUTE_POS < tensor.len() {
+        tensor[ABSOLUTE_POS] *= 2.0;
+    }
+}
+
+/// Flips the sign bits through a `u32` view, then doubles the values through an `f32` view of the
+/// same buffer.
+pub fn test_reinterpret_u32_as_f32<R: Runtime>(device: &R::Device) {
+    let client = R::client(device);
+    let values = [1.0f32, -2.5, 3.25, 0.0, 8.0, -0.5];
+    let bits: Vec<u32> = values.iter().map(|value| value.to_bits()).collect();
+
+    let uints =
+        TensorHandle::<R, u32>::new_contiguous(vec![2, 3], client.create(u32::as_bytes(&bits)));
+    let floats = uints.reinterpret::<f32>();
+    assert_eq!(floats.shape, uints.shape);
+    assert_eq!(floats.strides, uints.strides);
+
+    let cube_count = CubeCount::Static(1, 1, 1);
+    let cube_dim = CubeDim::new_1d(values.len() as u32);
+    unsafe {
+        flip_sign_bits::launch_unchecked::<R>(
+            &client,
+            cube_count.clone(),
+            cube_dim,
+            uints.as_arg(1),
+        );
+        double::launch_unchecked::<R>(&client, cube_count, cube_dim, floats.as_arg(1));
+    }
+
+    let actual = client.read_one(floats.handle.clone().binding());
+    let expected: Vec<f32> = values.iter().map(|value| -value * 2.0).collect();
+
+    assert_eq!(f32::from_bytes(&actual), expected);
+}
+
+/// Reinterprets a tensor between element types of different sizes and checks the shape and
+/// strides are counted in the new elements.
+pub fn test_reinterpret_recomputes_layout<R: Runtime>(device: &R::Device) {
+    let client = R::client(device);
+    let tensor = TensorHandle::<R, u32>::new(client.empty(4 * 8 * 4), vec![4, 6], vec![8, 1]);
+
+    let bytes = tensor.reinterpret::<u8>();
+    assert_eq!(bytes.shape, [4, 24]);
+    assert_eq!(bytes.strides, [32, 1]);
+
+    let pairs = tensor.reinterpret::<u64>();
+    assert_eq!(pairs.shape, [4, 3]);
+    assert_eq!(pairs.strides, [4, 1]);
+
+    let odd = TensorHandle::<R, u32>::new(client.empty(4 * 3 * 4), vec![4, 3], vec![3, 1]);
+    let result =
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| odd.reinterpret::<u64>()));
+    let message = result.expect_err("Should reject an odd number of u32 in the last dimension");
+    assert!(
+        message
+            .downcast_ref::<String>()
+            .unwrap()
+            .contains("isn't a multiple")
+    );
+}
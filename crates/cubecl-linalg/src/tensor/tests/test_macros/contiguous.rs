@@ -0,0 +1,25 @@
+#![allow(missing_docs)]
+
+#[macro_export]
+macro_rules! testgen_tensor_contiguous {
+    () => {
+        mod contiguous {
+            use super::*;
+
+            #[test]
+            pub fn test_strided_output() {
+                cubecl_linalg::tensor::tests::contiguous::test_into_contiguous_strided_output::<TestRuntime>(&Default::default());
+            }
+
+            #[test]
+            pub fn test_sliced_output() {
+                cubecl_linalg::tensor::tests::contiguous::test_into_contiguous_sliced_output::<TestRuntime>(&Default::default());
+            }
+
+            #[test]
+            pub fn test_reshape_requires_contiguous() {
+                cubecl_linalg::tensor::tests::contiguous::test_reshape_requires_contiguous::<TestRuntime>(&Default::default());
+            }
+        }
+    };
+}
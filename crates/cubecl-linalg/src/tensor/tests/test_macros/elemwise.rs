@@ -0,0 +1,31 @@
+#![allow(missing_docs)]
+
+#[macro_export]
+macro_rules! testgen_tensor_elemwise {
+    () => {
+        mod elemwise {
+            use super::*;
+
+            #[test]
+            pub fn test_add_contiguous() {
+                cubecl_linalg::tensor::tests::elemwise::test_add_contiguous::<TestRuntime>(
+                    &Default::default(),
+                );
+            }
+
+            #[test]
+            pub fn test_add_transposed() {
+                cubecl_linalg::tensor::tests::elemwise::test_add_transposed::<TestRuntime>(
+                    &Default::default(),
+                );
+            }
+
+            #[test]
+            pub fn test_add_reduced_scalar() {
+                cubecl_linalg::tensor::tests::elemwise::test_add_reduced_scalar::<TestRuntime>(
+                    &Default::default(),
+                );
+            }
+        }
+    };
+}
@@ -1 +1,5 @@
+mod contiguous;
+mod elemwise;
 mod identity;
+mod reinterpret;
+mod transpose;
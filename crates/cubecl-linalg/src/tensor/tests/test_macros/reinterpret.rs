@@ -0,0 +1,20 @@
+#![allow(missing_docs)]
+
+#[macro_export]
+macro_rules! testgen_tensor_reinterpret {
+    () => {
+        mod reinterpret {
+            use super::*;
+
+            #[test]
+            pub fn test_u32_as_f32() {
+                cubecl_linalg::tensor::tests::reinterpret::test_reinterpret_u32_as_f32::<TestRuntime>(&Default::default());
+            }
+
+            #[test]
+            pub fn test_recomputes_layout() {
+                cubecl_linalg::tensor::tests::reinterpret::test_reinterpret_recomputes_layout::<TestRuntime>(&Default::default());
+            }
+        }
+    };
+}
@@ -0,0 +1,49 @@
+#![allow(missing_docs)]
+
+#[macro_export]
+macro_rules! testgen_tensor_transpose {
+    () => {
+        mod transpose {
+            $crate::testgen_tensor_transpose!(f32);
+        }
+    };
+    ($numeric:ident) => {
+            use super::*;
+            use cubecl_linalg::tensor::tests;
+            use cubecl_core::flex32;
+
+            pub type NumericT = $numeric;
+
+            #[test]
+            pub fn test_square() {
+                cubecl_linalg::tensor::tests::transpose::test_transpose::<TestRuntime, NumericT>(&Default::default(), vec![64, 64]);
+            }
+
+            #[test]
+            pub fn test_ragged() {
+                cubecl_linalg::tensor::tests::transpose::test_transpose::<TestRuntime, NumericT>(&Default::default(), vec![37, 91]);
+            }
+
+            #[test]
+            pub fn test_batched() {
+                cubecl_linalg::tensor::tests::transpose::test_transpose::<TestRuntime, NumericT>(&Default::default(), vec![2, 3, 50, 33])
+            }
+
+            #[test]
+            pub fn test_large() {
+                cubecl_linalg::tensor::tests::transpose::test_transpose::<TestRuntime, NumericT>(&Default::default(), vec![1000, 1500])
+            }
+    };
+    ([$($numeric:ident),*]) => {
+        mod transpose {
+            use super::*;
+            ::paste::paste! {
+                $(mod [<$numeric _ty>] {
+                    use super::*;
+
+                    $crate::testgen_tensor_transpose!($numeric);
+                })*
+            }
+        }
+    };
+}
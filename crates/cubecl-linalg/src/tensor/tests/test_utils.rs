@@ -12,3 +12,22 @@ pub(crate) fn identity_cpu<E: Numeric + CubeElement>(dim: usize) -> Vec<E> {
 
     result
 }
+
+/// Transposes the last two dimensions of a contiguous tensor of the given shape.
+pub(crate) fn transpose_cpu<E: Numeric + CubeElement>(input: &[E], shape: &[usize]) -> Vec<E> {
+    let rank = shape.len();
+    let rows = shape[rank - 2];
+    let cols = shape[rank - 1];
+    let mut result = input.to_vec();
+
+    for (batch, matrix) in input.chunks(rows * cols).enumerate() {
+        let offset = batch * rows * cols;
+        for row in 0..rows {
+            for col in 0..cols {
+                result[offset + col * rows + row] = matrix[row * cols + col];
+            }
+        }
+    }
+
+    result
+}
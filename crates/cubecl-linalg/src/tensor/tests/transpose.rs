@@ -0,0 +1,40 @@
+use std::fmt::Display;
+
+use cubecl_core::{
+    CubeElement,
+    prelude::{Numeric, Runtime},
+};
+
+use super::test_utils::transpose_cpu;
+use crate::tensor::{self, TensorHandle};
+
+pub fn test_transpose<R: Runtime, C: Numeric + CubeElement + Display>(
+    device: &R::Device,
+    shape: Vec<usize>,
+) {
+    let client = R::client(device);
+    let rank = shape.len();
+
+    // Values stay small enough to be exact in every tested type.
+    let num_elements = shape.iter().product::<usize>();
+    let data: Vec<C> = (0..num_elements)
+        .map(|i| C::from_int((i % 251) as i64))
+        .collect();
+    let expected = transpose_cpu::<C>(&data, &shape);
+
+    let mut shape_out = shape.clone();
+    shape_out.swap(rank - 2, rank - 1);
+
+    let input = TensorHandle::<R, C>::new_contiguous(shape, client.create(C::as_bytes(&data)));
+    let output = TensorHandle::<R, C>::empty(&client, shape_out);
+    tensor::transpose::launch(&client, &input, &output);
+
+    let actual = client.read_one_tensor(output.handle.clone().binding_with_meta(
+        output.shape,
+        output.strides,
+        size_of::<C>(),
+    ));
+    let actual = C::from_bytes(&actual);
+
+    assert_eq!(&expected[..], actual, "transposed tensors are not equal.");
+}
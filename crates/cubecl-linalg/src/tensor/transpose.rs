@@ -0,0 +1,115 @@
+use cubecl::frontend::TensorHandleRef;
+use cubecl::prelude::*;
+use cubecl_core as cubecl;
+use cubecl_std::PaddedSharedMemory;
+
+use super::TensorHandle;
+
+/// Width of the square tile transposed by each cube.
+const TILE_SIZE: u32 = 32;
+/// Number of rows of the tile handled by each unit per pass.
+const BLOCK_ROWS: u32 = 8;
+
+#[cube(launch_unchecked)]
+fn transpose_kernel<E: Numeric>(
+    input: &Tensor<E>,
+    output: &mut Tensor<E>,
+    #[comptime] tile_size: u32,
+) {
+    let rank = input.rank();
+    let rows = input.shape(rank - 2);
+    let cols = input.shape(rank - 1);
+
+    // Offsets of the current matrix, one batch per cube along z.
+    let mut batch = CUBE_POS_Z;
+    let mut offset_input = 0;
+    let mut offset_output = 0;
+    for i in 0..rank - 2 {
+        let dim = rank - 3 - i;
+        let index = batch % output.shape(dim);
+        batch /= output.shape(dim);
+        offset_input += index * input.stride(dim);
+        offset_output += index * output.stride(dim);
+    }
+
+    // The tile is transposed by reading its columns, which is conflict-free with padded rows.
+    let mut tile = PaddedSharedMemory::<E>::new(tile_size, tile_size);
+
+    let tile_row = CUBE_POS_Y * tile_size;
+    let tile_col = CUBE_POS_X * tile_size;
+
+    for j in range_stepped(0, tile_size, CUBE_DIM_Y) {
+        let row = tile_row + UNIT_POS_Y + j;
+        let col = tile_col + UNIT_POS_X;
+        if row < rows && col < cols {
+            tile.write(
+                UNIT_POS_Y + j,
+                UNIT_POS_X,
+                input[offset_input + row * input.stride(rank - 2) + col * input.stride(rank - 1)],
+            );
+        }
+    }
+
+    sync_cube();
+
+    for j in range_stepped(0, tile_size, CUBE_DIM_Y) {
+        let row = tile_col + UNIT_POS_Y + j;
+        let col = tile_row + UNIT_POS_X;
+        if row < cols && col < rows {
+            output[offset_output + row * output.stride(rank - 2) + col * output.stride(rank - 1)] =
+                tile.read(UNIT_POS_X, UNIT_POS_Y + j);
+        }
+    }
+}
+
+/// Launch the transpose kernel.
+/// Swaps the last two dimensions of `input` into `output`, batch dimensions are kept.
+/// `output` must have the shape of `input` with its last two dimensions swapped.
+pub fn launch<R: Runtime, E: Numeric>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: &TensorHandle<R, E>,
+    output: &TensorHandle<R, E>,
+) {
+    launch_ref::<R, E>(client, &input.as_ref(), &output.as_ref());
+}
+
+/// Launch the transpose kernel by ref.
+/// Swaps the last two dimensions of `input` into `output`, batch dimensions are kept.
+/// `output` must have the shape of `input` with its last two dimensions swapped.
+pub fn launch_ref<R: Runtime, E: Numeric>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: &TensorHandleRef<R>,
+    output: &TensorHandleRef<R>,
+) {
+    let rank = input.shape.len();
+    assert!(rank >= 2, "input should have at least two dimensions");
+    assert_eq!(rank, output.shape.len(), "input and output ranks differ");
+    assert_eq!(
+        input.shape[..rank - 2],
+        output.shape[..rank - 2],
+        "batch dimensions should match"
+    );
+    assert_eq!(
+        (input.shape[rank - 2], input.shape[rank - 1]),
+        (output.shape[rank - 1], output.shape[rank - 2]),
+        "output should have the last two dimensions of input swapped"
+    );
+
+    let rows = input.shape[rank - 2] as u32;
+    let cols = input.shape[rank - 1] as u32;
+    let batches = input.shape[..rank - 2].iter().product::<usize>() as u32;
+
+    let cube_dim = CubeDim::new_2d(TILE_SIZE, BLOCK_ROWS);
+    let cube_count = CubeCount::Static(cols.div_ceil(TILE_SIZE), rows.div_ceil(TILE_SIZE), batches);
+
+    unsafe {
+        transpose_kernel::launch_unchecked::<E, R>(
+            client,
+            cube_count,
+            cube_dim,
+            TensorArg::from_raw_parts::<E>(input.handle, input.strides, input.shape, 1),
+            TensorArg::from_raw_parts::<E>(output.handle, output.strides, output.shape, 1),
+            TILE_SIZE,
+        );
+    }
+}
@@ -88,6 +88,7 @@ pub enum Expression {
     ForLoop {
         range: Box<Expression>,
         unroll: Option<Box<Expression>>,
+        partial_unroll: Option<u32>,
         var_name: syn::Ident,
         var_ty: Option<syn::Type>,
         block: Block,
@@ -128,7 +129,8 @@ pub enum Expression {
     Slice {
         expr: Box<Expression>,
         span: Span,
-        _ranges: Vec<Expression>,
+        ranges: Vec<Expression>,
+        mutable: bool,
     },
     ArrayInit {
         init: Box<Expression>,
@@ -302,12 +304,14 @@ pub fn is_intrinsic(path: &Path) -> bool {
     let intrinsic_paths = [
         "::cubecl::prelude::vectorization_of",
         "::cubecl::frontend::vectorization_of",
+        "::cubecl::frontend::mem::size_of",
+        "::cubecl::frontend::mem::align_of",
     ];
 
     let mut path = path.clone();
     // Strip function generics
     path.segments.last_mut().unwrap().arguments = PathArguments::None;
-    let func_path = path.to_token_stream().to_string();
+    let func_path = path.to_token_stream().to_string().replace(' ', "");
     intrinsic_paths
         .iter()
         .any(|path| path.ends_with(&func_path))
@@ -176,6 +176,29 @@ impl Expression {
                     }
                 }
             }
+            Expression::FunctionCall {
+                func,
+                args,
+                associated_type: None,
+                span,
+                ..
+            } if matches!(**func, Expression::Variable(_)) => {
+                // A closure parameter, which is already expanded.
+                let (args, arg_names) = map_args(args, context);
+                let Expression::Variable(var) = &**func else {
+                    unreachable!()
+                };
+                let name = &var.name;
+                // Calling the closure only borrows it.
+                var.try_consume(context);
+
+                quote_spanned! {*span=>
+                    {
+                        #(#args)*
+                        #name(context, #(#arg_names),*)
+                    }
+                }
+            }
             Expression::FunctionCall {
                 func,
                 args,
@@ -280,6 +303,7 @@ impl Expression {
             Expression::ForLoop {
                 range,
                 unroll,
+                partial_unroll,
                 var_name,
                 var_ty,
                 block,
@@ -295,6 +319,15 @@ impl Expression {
                 let block = context.in_fn_mut(scope, |ctx| block.to_tokens(ctx));
                 let var_ty = var_ty.as_ref().map(|it| quote![: #it]);
 
+                if let Some(factor) = partial_unroll {
+                    return quote! {
+                        {
+                            let _range = #range;
+                            #for_ty::for_expand_partial_unroll(context, _range, #factor, |context, #var_name #var_ty| #block);
+                        }
+                    };
+                }
+
                 quote! {
                     {
                         let _range = #range;
@@ -440,8 +473,49 @@ impl Expression {
                 }
             }
 
-            Expression::Slice { span, .. } => {
-                error!(*span, "Slice expressions not yet implemented")
+            Expression::Slice {
+                expr,
+                span,
+                ranges,
+                mutable,
+            } => {
+                let (start, end, inclusive) = match ranges.as_slice() {
+                    [
+                        Expression::Range {
+                            start,
+                            end,
+                            inclusive,
+                            ..
+                        },
+                    ] => (start, end, *inclusive),
+                    _ => return error!(*span, "Multi-dimensional slices are not supported"),
+                };
+                if inclusive {
+                    return error!(*span, "Inclusive slice ranges are not supported");
+                }
+                let Some(end) = end else {
+                    return error!(*span, "Slice ranges must have an end");
+                };
+                let slice_op = frontend_type("SliceOperatorExpand");
+                let method = match mutable {
+                    true => quote![__expand_slice_mut_method],
+                    false => quote![__expand_slice_method],
+                };
+                let expr = expr.to_tokens(context);
+                let start = start
+                    .as_const(context)
+                    .unwrap_or_else(|| start.to_tokens(context));
+                let end = end
+                    .as_const(context)
+                    .unwrap_or_else(|| end.to_tokens(context));
+                quote_spanned! {*span=>
+                    {
+                        let _list = #expr;
+                        let _start = #start;
+                        let _end = #end;
+                        #slice_op::#method(&_list, context, _start.into(), _end.into())
+                    }
+                }
             }
             Expression::ArrayInit { init, len } => {
                 let init_ty = frontend_type("ArrayInit");
@@ -516,7 +590,8 @@ impl Expression {
             } => {
                 // Without knowing the closure type, we need to assume it's `FnMut`
                 let body = context.in_fn_mut(scope, |ctx| body.to_tokens(ctx));
-                quote![|context, #(#params),*| #body]
+                let scope_ty = prelude_type("Scope");
+                quote![|context: &mut #scope_ty, #(#params),*| #body]
             }
             Expression::Verbatim { tokens, .. } => tokens.clone(),
             Expression::Block(block) => block.to_tokens(context),
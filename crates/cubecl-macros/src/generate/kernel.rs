@@ -22,7 +22,11 @@ impl KernelFn {
         };
         let name = &self.full_name;
 
-        let (debug_source, debug_params) = if cfg!(debug_symbols) || self.debug_symbols {
+        let (debug_source, debug_params) = if self.inline {
+            // Inlined helpers don't declare their own source or parameter names either, so their
+            // body is attributed to the caller and no separate function shows up in the debug info.
+            (TokenStream::new(), Vec::new())
+        } else if cfg!(debug_symbols) || self.debug_symbols {
             let debug_source = frontend_type("debug_source_expand");
             let cube_debug = frontend_type("CubeDebug");
             let src_file = self.src_file.as_ref().map(|file| file.value());
@@ -56,14 +60,31 @@ impl KernelFn {
             (TokenStream::new(), Vec::new())
         };
 
-        let out = quote! {
-            #vis #sig {
-                #debug_source;
-                #(#debug_params)*
-                use #prelude_path::IntoRuntime as _;
+        let out = match &self.body {
+            KernelBody::Block(_) if self.noinline => {
+                let noinline_call = frontend_type("noinline_call_expand");
+                quote! {
+                    #vis #sig {
+                        use #prelude_path::IntoRuntime as _;
+
+                        #noinline_call(context, #name, |context| {
+                            #debug_source;
+                            #(#debug_params)*
 
-                #body
+                            #body
+                        })
+                    }
+                }
             }
+            _ => quote! {
+                #vis #sig {
+                    #debug_source;
+                    #(#debug_params)*
+                    use #prelude_path::IntoRuntime as _;
+
+                    #body
+                }
+            },
         };
 
         out
@@ -269,7 +290,10 @@ impl Launch {
     fn kernel_entrypoint_name(&self) -> TokenStream {
         // This base name is always used; a suffix might be added
         // based on generics.
-        let base_name = self.func.sig.name.to_string();
+        let base_name = match &self.args.name {
+            Some(name) => name.value(),
+            None => self.func.sig.name.to_string(),
+        };
 
         let generics = &self.kernel_generics;
         let suffix_producing_bounds = [format_ident!("Float"), format_ident!("Numeric")];
@@ -364,6 +388,9 @@ impl Launch {
             if let Some(cluster_dim) = &self.args.cluster_dim {
                 settings.extend(quote![.cluster_dim(#cluster_dim)]);
             }
+            if let Some(carveout) = &self.args.shared_memory_carveout {
+                settings.extend(quote![.shared_memory_carveout(#carveout)]);
+            }
 
             quote! {
                 #[doc = #kernel_doc]
@@ -14,6 +14,7 @@ impl ToTokens for Launch {
 
         let name = &self.func.sig.name;
         let launch = self.launch();
+        let try_launch = self.try_launch();
         let launch_unchecked = self.launch_unchecked();
         let dummy = self.create_dummy_kernel();
         let kernel = self.kernel_definition();
@@ -30,6 +31,7 @@ impl ToTokens for Launch {
 
                 #kernel
                 #launch
+                #try_launch
                 #launch_unchecked
                 #dummy
             }
@@ -77,6 +79,61 @@ impl Launch {
         }
     }
 
+    fn try_launch(&self) -> TokenStream {
+        if self.args.launch.is_present() {
+            let compute_client = prelude_type("ComputeClient");
+            let cube_count = prelude_type("CubeCount");
+            let cube_dim = prelude_type("CubeDim");
+            let launch_error = prelude_type("LaunchError");
+
+            let kernel_doc = format!(
+                "Launch the kernel [{}()] on the given runtime, returning an error if the arguments \
+                 or the cube count can't be used on the device",
+                self.func.sig.name
+            );
+            let aliased_doc = format!(
+                "Launch the kernel [{}()] on the given runtime like [try_launch], allowing each \
+                 pair of buffers at the given positions to point to overlapping memory",
+                self.func.sig.name
+            );
+            let generics = &self.launch_generics;
+            let args = self.launch_args();
+            let body = self.launch_body();
+
+            quote! {
+                #[allow(clippy::too_many_arguments)]
+                #[doc = #kernel_doc]
+                pub fn try_launch #generics(
+                    __client: &#compute_client<__R::Server, __R::Channel>,
+                    __cube_count: #cube_count,
+                    __cube_dim: #cube_dim,
+                    #(#args),*
+                ) -> Result<(), #launch_error> {
+                    #body
+                    launcher.try_launch(__cube_count, __kernel, __client)
+                }
+
+                #[allow(clippy::too_many_arguments)]
+                #[doc = #aliased_doc]
+                pub fn try_launch_aliased #generics(
+                    __client: &#compute_client<__R::Server, __R::Channel>,
+                    __cube_count: #cube_count,
+                    __cube_dim: #cube_dim,
+                    __aliases: &[(usize, usize)],
+                    #(#args),*
+                ) -> Result<(), #launch_error> {
+                    #body
+                    for (__first, __second) in __aliases {
+                        launcher.allow_aliasing(*__first, *__second);
+                    }
+                    launcher.try_launch(__cube_count, __kernel, __client)
+                }
+            }
+        } else {
+            TokenStream::new()
+        }
+    }
+
     fn launch_unchecked(&self) -> TokenStream {
         if self.args.launch_unchecked.is_present() {
             let compute_client = prelude_type("ComputeClient");
@@ -29,10 +29,19 @@ mod statement;
 /// Mark a cube function, trait or implementation for expansion.
 ///
 /// # Arguments
-/// * `launch` - generates a function to launch the kernel
+/// * `launch` - generates a function to launch the kernel, along with a `try_launch` variant that
+///   returns a `LaunchError` instead of panicking, and a `try_launch_aliased` variant that also
+///   allows the given pairs of buffers to alias
 /// * `launch_unchecked` - generates a launch function without checks
 /// * `debug` - panics after generation to print the output to console
 /// * `create_dummy_kernel` - Generates a function to create a kernel without launching it. Used for testing.
+/// * `name` - a readable name for the kernel entry point, shown in profilers and debuggers
+/// * `inline` - expands the helper's body directly in the scope of its caller, leaving no separate
+///   function in the compiled kernel. Unlike other helpers, it doesn't show up as its own function
+///   in the debug info either.
+/// * `noinline` - registers the helper as a call that is compiled to its own function that
+///   isn't inlined on the CUDA and HIP backends, other backends expand it in place. The helper can
+///   only return a primitive or nothing.
 ///
 /// # Example
 ///
@@ -11,7 +11,9 @@ use super::{helpers::Unroll, statement::parse_pat};
 
 pub fn expand_for_loop(for_loop: ExprForLoop, context: &mut Context) -> syn::Result<Expression> {
     let span = for_loop.span();
-    let unroll = Unroll::from_attributes(&for_loop.attrs, context)?.map(|it| it.value);
+    let unroll = Unroll::from_attributes(&for_loop.attrs, context)?;
+    let partial_unroll = unroll.as_ref().and_then(|it| it.factor);
+    let unroll = unroll.map(|it| it.value);
 
     let right = Expression::from_expr(*for_loop.expr.clone(), context)
         .map_err(|_| syn::Error::new(span, "Unsupported for loop expression"))?;
@@ -35,6 +37,7 @@ pub fn expand_for_loop(for_loop: ExprForLoop, context: &mut Context) -> syn::Res
     Ok(Expression::ForLoop {
         range: Box::new(right),
         unroll: unroll.map(Box::new),
+        partial_unroll,
         var_name: var.ident,
         var_ty: var.ty,
         block,
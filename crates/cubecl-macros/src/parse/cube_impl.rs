@@ -145,6 +145,8 @@ impl CubeImplItem {
             context: Context::new(func.context.return_type.clone(), func.debug_symbols),
             src_file: func.src_file.clone(),
             debug_symbols: func.debug_symbols,
+            inline: func.inline,
+            noinline: func.noinline,
         }
     }
 
@@ -199,6 +201,8 @@ impl CubeImplItem {
             context: Context::new(func.context.return_type.clone(), func.debug_symbols),
             src_file: func.src_file.clone(),
             debug_symbols: func.debug_symbols,
+            inline: func.inline,
+            noinline: func.noinline,
         }
     }
 }
@@ -1,9 +1,9 @@
 use std::mem::take;
 
-use quote::quote_spanned;
+use quote::{format_ident, quote, quote_spanned};
 use syn::{
-    Expr, ExprLoop, ExprWhile, Index, Local, LocalInit, Pat, PatStruct, PatTuple, PatTupleStruct,
-    Stmt, parse_quote,
+    Expr, ExprForLoop, ExprLoop, ExprWhile, Index, Local, LocalInit, Pat, PatTuple, PatTupleStruct,
+    PatType, Stmt, parse_quote,
     spanned::Spanned,
     visit_mut::{self, VisitMut},
 };
@@ -11,8 +11,10 @@ use syn::{
 pub struct Desugar;
 impl VisitMut for Desugar {
     fn visit_expr_mut(&mut self, i: &mut syn::Expr) {
-        if let Expr::While(inner) = i {
-            *i = Expr::Loop(desugar_while(inner))
+        match i {
+            Expr::While(inner) => *i = Expr::Loop(desugar_while(inner)),
+            Expr::ForLoop(inner) => desugar_for_pat(inner),
+            _ => {}
         }
         visit_mut::visit_expr_mut(self, i);
     }
@@ -26,22 +28,76 @@ impl VisitMut for Desugar {
 }
 
 fn desugar_pats(stmts: Vec<Stmt>) -> Vec<Stmt> {
-    stmts.into_iter().flat_map(|stmt| {
-        match stmt {
-            Stmt::Local(Local {
-                pat: Pat::Struct(pat),
-                init: Some(init),
-                ..
-            }) => desugar_struct_destructure(pat, init),
+    desugar_nested_pats(stmts, 0)
+}
+
+/// Desugar the destructuring `let` statements of `stmts`, then the nested patterns they produce
+/// with a deeper `depth`, so the value being destructured isn't shadowed before its last field.
+fn desugar_nested_pats(stmts: Vec<Stmt>, depth: usize) -> Vec<Stmt> {
+    stmts
+        .into_iter()
+        .flat_map(|stmt| match stmt {
             Stmt::Local(Local {
-                pat:
-                    Pat::Tuple(PatTuple { elems, .. }) | Pat::TupleStruct(PatTupleStruct { elems, .. }),
+                pat,
                 init: Some(init),
                 ..
-            }) => desugar_tuple_destructure(elems, init),
+            }) if is_destructure(&pat) => {
+                desugar_nested_pats(desugar_destructure(pat, init, depth), depth + 1)
+            }
             stmt => vec![stmt],
+        })
+        .collect()
+}
+
+fn is_destructure(pat: &Pat) -> bool {
+    match pat {
+        Pat::Tuple(_) | Pat::TupleStruct(_) | Pat::Struct(_) => true,
+        Pat::Type(pat) => is_destructure(&pat.pat),
+        _ => false,
+    }
+}
+
+fn desugar_destructure(pat: Pat, init: LocalInit, depth: usize) -> Vec<Stmt> {
+    let (pat, ty) = match pat {
+        Pat::Type(PatType { pat, ty, .. }) => (*pat, Some(ty)),
+        pat => (pat, None),
+    };
+    let ty = ty.map(|ty| quote![: #ty]);
+    let init = init.expr;
+
+    match pat {
+        Pat::Struct(pat) => {
+            let name = format_ident!("__struct_destructure_init_{depth}");
+            let fields = pat.fields.into_iter().map(|field| {
+                let attrs = field.attrs;
+                let pat = field.pat;
+                let member = field.member;
+                quote_spanned! {pat.span()=>
+                    #(#attrs)* let #pat = #name.#member;
+                }
+            });
+            let init = quote_spanned![init.span()=> let #name #ty = #init;];
+            parse_quote! {
+                #init
+                #(#fields)*
+            }
+        }
+        Pat::Tuple(PatTuple { elems, .. }) | Pat::TupleStruct(PatTupleStruct { elems, .. }) => {
+            let name = format_ident!("__tuple_destructure_init_{depth}");
+            let fields = elems.into_iter().enumerate().map(|(i, pat)| {
+                let member = Index::from(i);
+                quote_spanned! {pat.span()=>
+                    let #pat = #name.#member;
+                }
+            });
+            let init = quote_spanned![init.span()=> let #name #ty = #init;];
+            parse_quote! {
+                #init
+                #(#fields)*
+            }
         }
-    }).collect()
+        _ => unreachable!("Checked by is_destructure"),
+    }
 }
 
 fn desugar_while(inner: &ExprWhile) -> ExprLoop {
@@ -60,34 +116,17 @@ fn desugar_while(inner: &ExprWhile) -> ExprLoop {
     }
 }
 
-fn desugar_struct_destructure(pat: PatStruct, init: LocalInit) -> Vec<Stmt> {
-    let fields = pat.fields.into_iter().map(|field| {
-        let attrs = field.attrs;
-        let pat = field.pat;
-        let member = field.member;
-        quote_spanned! {pat.span()=>
-            #(#attrs)* let #pat = __struct_destructure_init.#member;
-        }
-    });
-    let init = init.expr;
-    let init = quote_spanned![init.span()=> let __struct_destructure_init = #init;];
-    parse_quote! {
-        #init
-        #(#fields)*
+/// Bind the item of a `for` loop destructuring a tuple or struct to a single variable, and
+/// destructure it at the start of the body instead.
+fn desugar_for_pat(inner: &mut ExprForLoop) {
+    if !matches!(
+        *inner.pat,
+        Pat::Tuple(_) | Pat::TupleStruct(_) | Pat::Struct(_)
+    ) {
+        return;
     }
-}
 
-fn desugar_tuple_destructure(fields: impl IntoIterator<Item = Pat>, init: LocalInit) -> Vec<Stmt> {
-    let fields = fields.into_iter().enumerate().map(|(i, pat)| {
-        let member = Index::from(i);
-        quote_spanned! {pat.span()=>
-            let #pat = __tuple_destructure_init.#member;
-        }
-    });
-    let init = init.expr;
-    let init = quote_spanned![init.span()=> let __tuple_destructure_init = #init;];
-    parse_quote! {
-        #init
-        #(#fields)*
-    }
+    let pat = core::mem::replace(&mut *inner.pat, parse_quote![__for_item]);
+    let init: Stmt = parse_quote![let #pat = __for_item;];
+    inner.body.stmts.insert(0, init);
 }
@@ -8,13 +8,16 @@ use syn::{
 use crate::{
     expression::{Block, Expression, MatchArm, is_intrinsic},
     operator::Operator,
+    paths::prelude_type,
     scope::Context,
+    statement::Pattern,
 };
 
 use super::{
     branch::{expand_for_loop, expand_if, expand_loop, numeric_match},
+    kernel::strip_ref,
     operator::{parse_binop, parse_unop},
-    statement::parse_macros,
+    statement::{parse_macros, parse_pat},
 };
 
 impl Expression {
@@ -106,6 +109,13 @@ impl Expression {
                     Expression::Path { path } if is_intrinsic(&path) => {
                         Expression::CompilerIntrinsic { func: path, args }
                     }
+                    // Calling a closure parameter.
+                    func @ Expression::Variable(_) => Expression::FunctionCall {
+                        func: Box::new(func),
+                        args,
+                        associated_type: None,
+                        span,
+                    },
                     func => {
                         let associated_type = fn_associated_type(&func);
                         Expression::FunctionCall {
@@ -233,7 +243,8 @@ impl Expression {
                     Expression::Slice {
                         expr: Box::new(expr),
                         span,
-                        _ranges: ranges,
+                        ranges,
+                        mutable: false,
                     }
                 } else {
                     let index = match index {
@@ -341,14 +352,37 @@ impl Expression {
             }
             Expr::Infer(_) => Expression::Verbatim { tokens: quote![_] },
             Expr::Verbatim(verbatim) => Expression::Verbatim { tokens: verbatim },
-            Expr::Reference(reference) => Expression::Reference {
-                inner: Box::new(Expression::from_expr(*reference.expr, context)?),
-            },
+            Expr::Reference(reference) => {
+                let inner = Expression::from_expr(*reference.expr, context)?;
+                match inner {
+                    // `&list[start..end]` and `&mut list[start..end]` are views into `list`
+                    Expression::Slice {
+                        expr, span, ranges, ..
+                    } => Expression::Slice {
+                        expr,
+                        span,
+                        ranges,
+                        mutable: reference.mutability.is_some(),
+                    },
+                    inner => Expression::Reference {
+                        inner: Box::new(inner),
+                    },
+                }
+            }
             Expr::Closure(expr) => {
-                let (body, scope) =
-                    context.in_scope(|ctx| Expression::from_expr(*expr.body, ctx))?;
+                let params = expr
+                    .inputs
+                    .into_iter()
+                    .map(expand_closure_param)
+                    .collect::<Vec<_>>();
+                let (body, scope) = context.in_scope(|ctx| {
+                    for param in params.iter() {
+                        let Pattern { ident, is_mut, .. } = parse_pat(param.clone())?;
+                        ctx.push_variable(ident, None, false, false, is_mut);
+                    }
+                    Expression::from_expr(*expr.body, ctx)
+                })?;
                 let body = Box::new(body);
-                let params = expr.inputs.into_iter().collect();
                 Expression::Closure {
                     params,
                     body,
@@ -502,3 +536,17 @@ fn fn_associated_type(path: &Expression) -> Option<(Path, PathSegment)> {
         _ => None,
     }
 }
+
+/// Closures are called with expand types, so the types of their parameters are replaced by the
+/// expand types.
+fn expand_closure_param(param: Pat) -> Pat {
+    match param {
+        Pat::Type(mut param) => {
+            let ty = strip_ref(*param.ty, &mut false, &mut false);
+            let cube_type = prelude_type("CubeType");
+            param.ty = Box::new(parse_quote![<#ty as #cube_type>::ExpandType]);
+            Pat::Type(param)
+        }
+        param => param,
+    }
+}
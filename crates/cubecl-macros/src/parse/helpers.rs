@@ -1,6 +1,6 @@
 use darling::FromMeta;
 use syn::{
-    Attribute, Expr, ExprReference, parse_quote,
+    Attribute, Expr, ExprReference, LitInt, parse_quote,
     visit_mut::{self, VisitMut},
 };
 
@@ -8,6 +8,8 @@ use crate::{expression::Expression, paths::prelude_path, scope::Context};
 
 pub struct Unroll {
     pub value: Expression,
+    /// The factor of a partial unroll, written `#[unroll(n)]` with an integer literal.
+    pub factor: Option<u32>,
 }
 
 impl Unroll {
@@ -29,16 +31,38 @@ impl Unroll {
         let res = match &attr.meta {
             syn::Meta::Path(_) => Self {
                 value: Expression::from_expr(parse_quote![true], context).unwrap(),
+                factor: None,
+            },
+            syn::Meta::List(list) => match syn::parse2::<LitInt>(list.tokens.clone()) {
+                Ok(factor) => {
+                    let factor = factor.base10_parse::<u32>()?;
+                    if factor == 0 {
+                        return Err(syn::Error::new_spanned(
+                            list,
+                            "Unroll factor must be at least 1",
+                        ));
+                    }
+                    Self {
+                        value: Expression::from_expr(parse_quote![false], context).unwrap(),
+                        factor: Some(factor),
+                    }
+                }
+                Err(_) => {
+                    let expr = syn::parse2(list.tokens.clone())?;
+                    let expr = Expression::from_expr(expr, context)?;
+                    Self {
+                        value: expr,
+                        factor: None,
+                    }
+                }
             },
-            syn::Meta::List(list) => {
-                let expr = syn::parse2(list.tokens.clone())?;
-                let expr = Expression::from_expr(expr, context)?;
-                Self { value: expr }
-            }
             meta => {
                 let expr = NameVal::from_meta(meta)?;
                 let expr = Expression::from_expr(expr.value, context)?;
-                Self { value: expr }
+                Self {
+                    value: expr,
+                    factor: None,
+                }
             }
         };
         Ok(Some(res))
@@ -4,9 +4,9 @@ use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, quote};
 use std::{collections::HashMap, iter};
 use syn::{
-    Expr, FnArg, Generics, Ident, ItemFn, LitStr, ReturnType, Signature, TraitItemFn, Type,
-    TypeMacro, Visibility, parse, parse_quote, punctuated::Punctuated, spanned::Spanned,
-    visit_mut::VisitMut,
+    Expr, FnArg, Generics, Ident, ItemFn, LitStr, PathArguments, ReturnType, Signature,
+    TraitItemFn, Type, TypeMacro, TypeParamBound, Visibility, WherePredicate, parse, parse_quote,
+    punctuated::Punctuated, spanned::Spanned, visit_mut::VisitMut,
 };
 
 use super::{desugar::Desugar, helpers::is_comptime_attr, statement::parse_pat};
@@ -19,8 +19,12 @@ pub(crate) struct KernelArgs {
     pub fast_math: Option<Expr>,
     pub debug: Flag,
     pub create_dummy_kernel: Flag,
+    pub inline: Flag,
+    pub noinline: Flag,
     pub cluster_dim: Option<Expr>,
+    pub shared_memory_carveout: Option<Expr>,
     pub src_file: Option<LitStr>,
+    pub name: Option<LitStr>,
 }
 
 pub fn from_tokens<T: FromMeta>(tokens: TokenStream) -> syn::Result<T> {
@@ -187,6 +191,10 @@ pub struct KernelFn {
     pub body: KernelBody,
     pub full_name: String,
     pub debug_symbols: bool,
+    /// Whether the body is expanded in the scope of the caller, without a debug function.
+    pub inline: bool,
+    /// Whether the body is registered as a call that backends keep as a separate function.
+    pub noinline: bool,
     pub span: Span,
     pub context: Context,
     pub src_file: Option<LitStr>,
@@ -239,7 +247,7 @@ pub struct KernelParam {
 }
 
 impl KernelParam {
-    fn from_param(param: FnArg) -> syn::Result<Self> {
+    fn from_param(param: FnArg, closures: &[Ident]) -> syn::Result<Self> {
         let param = match param {
             FnArg::Typed(param) => param,
             FnArg::Receiver(param) => {
@@ -269,7 +277,12 @@ impl KernelParam {
         } = parse_pat(*param.pat.clone())?;
         let is_const = param.attrs.iter().any(is_comptime_attr);
         let ty = *param.ty.clone();
-        let normalized_ty = normalize_kernel_ty(*param.ty, is_const, &mut is_ref, &mut is_mut);
+        let normalized_ty = match closure_ty(&ty, closures) {
+            // Closures are passed as their expanded form, which already takes and returns expand
+            // types.
+            Some(closure) => closure,
+            None => normalize_kernel_ty(*param.ty, is_const, &mut is_ref, &mut is_mut),
+        };
 
         Ok(Self {
             name: ident,
@@ -305,7 +318,8 @@ impl KernelParam {
 impl KernelSignature {
     pub fn from_signature(sig: Signature) -> syn::Result<Self> {
         let name = sig.ident;
-        let generics = sig.generics;
+        let mut generics = sig.generics;
+        let closures = expand_closure_bounds(&mut generics);
         let returns = match sig.output {
             syn::ReturnType::Default => KernelReturns::ExpandType(parse_quote![()]),
             syn::ReturnType::Type(_, ty) => match *ty.clone() {
@@ -324,7 +338,7 @@ impl KernelSignature {
         let parameters = sig
             .inputs
             .into_iter()
-            .map(KernelParam::from_param)
+            .map(|param| KernelParam::from_param(param, &closures))
             .collect::<Result<Vec<_>, _>>()?;
 
         Ok(KernelSignature {
@@ -380,6 +394,8 @@ impl KernelFn {
             src_file,
             context,
             debug_symbols,
+            inline: false,
+            noinline: false,
         })
     }
 }
@@ -391,7 +407,7 @@ impl Launch {
 
         let vis = function.vis;
         let full_name = function.sig.ident.to_string();
-        let func = KernelFn::from_sig_and_block(
+        let mut func = KernelFn::from_sig_and_block(
             // When generating code, this function will be wrapped in
             // a module. By setting the visibility to pub here, we
             // ensure that the function is visible outside that
@@ -403,6 +419,21 @@ impl Launch {
             args.src_file.clone(),
             args.debug_symbols.is_present(),
         )?;
+        func.inline = args.inline.is_present();
+        func.noinline = args.noinline.is_present();
+
+        if func.inline && func.noinline {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "A helper can't be both `inline` and `noinline`, remove one of them.",
+            ));
+        }
+        if args.is_launch() && (func.inline || func.noinline) {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "Only helper functions can be inlined, remove `inline` and `noinline` from the launch kernel.",
+            ));
+        }
 
         // Bail early if the user tries to have a return type in a launch kernel.
         if args.is_launch() {
@@ -439,6 +470,81 @@ impl Launch {
     }
 }
 
+/// Rewrite every `Fn(A) -> B` bound of the generics into the bound of the expanded closure,
+/// `Fn(&mut Scope, A::ExpandType) -> B::ExpandType`, and return the type parameters bound by them.
+fn expand_closure_bounds(generics: &mut Generics) -> Vec<Ident> {
+    let mut closures = Vec::new();
+
+    for param in generics.type_params_mut() {
+        if param.bounds.iter_mut().any(expand_closure_bound) {
+            closures.push(param.ident.clone());
+        }
+    }
+    if let Some(where_clause) = &mut generics.where_clause {
+        for predicate in where_clause.predicates.iter_mut() {
+            if let WherePredicate::Type(predicate) = predicate {
+                if predicate.bounds.iter_mut().any(expand_closure_bound) {
+                    if let Type::Path(ty) = &predicate.bounded_ty {
+                        closures.extend(ty.path.get_ident().cloned());
+                    }
+                }
+            }
+        }
+    }
+
+    closures
+}
+
+/// The expanded type of a closure parameter, either a type parameter bound by a closure or an
+/// `impl Fn(A) -> B`.
+fn closure_ty(ty: &Type, closures: &[Ident]) -> Option<Type> {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .get_ident()
+            .filter(|ident| closures.contains(ident))
+            .map(|_| ty.clone()),
+        Type::ImplTrait(impl_trait) => {
+            let mut impl_trait = impl_trait.clone();
+            let is_closure = impl_trait.bounds.iter_mut().any(expand_closure_bound);
+            is_closure.then_some(Type::ImplTrait(impl_trait))
+        }
+        _ => None,
+    }
+}
+
+fn expand_closure_bound(bound: &mut TypeParamBound) -> bool {
+    let TypeParamBound::Trait(bound) = bound else {
+        return false;
+    };
+    let Some(segment) = bound.path.segments.last_mut() else {
+        return false;
+    };
+    if !["Fn", "FnMut", "FnOnce"]
+        .into_iter()
+        .any(|closure| segment.ident == closure)
+    {
+        return false;
+    }
+    let PathArguments::Parenthesized(args) = &mut segment.arguments else {
+        return false;
+    };
+
+    let scope = prelude_type("Scope");
+    let cube_type = prelude_type("CubeType");
+    let inputs = args.inputs.iter().map(|input| {
+        let input = strip_ref(input.clone(), &mut false, &mut false);
+        quote![<#input as #cube_type>::ExpandType]
+    });
+    args.inputs = parse_quote![&mut #scope, #(#inputs),*];
+    if let ReturnType::Type(_, output) = &mut args.output {
+        let ty = &**output;
+        **output = parse_quote![<#ty as #cube_type>::ExpandType];
+    }
+
+    true
+}
+
 fn normalize_kernel_ty(ty: Type, is_const: bool, is_ref: &mut bool, is_mut: &mut bool) -> Type {
     let ty = strip_ref(ty, is_ref, is_mut);
     let cube_type = prelude_type("CubeType");
@@ -162,10 +162,14 @@ impl Ranges {
                 Builtin::UnitPosX => Range::uint(opt.cube_dim.x as u64 - 1),
                 Builtin::UnitPosY => Range::uint(opt.cube_dim.y as u64 - 1),
                 Builtin::UnitPosZ => Range::uint(opt.cube_dim.z as u64 - 1),
-                Builtin::CubeCount => Range::constant(opt.cube_dim.num_elems() as u64),
-                Builtin::CubeCountX => Range::constant(opt.cube_dim.x as u64),
-                Builtin::CubeCountY => Range::constant(opt.cube_dim.y as u64),
-                Builtin::CubeCountZ => Range::constant(opt.cube_dim.z as u64),
+                // The cube count is only known at launch time, but is never zero.
+                Builtin::CubeCount
+                | Builtin::CubeCountX
+                | Builtin::CubeCountY
+                | Builtin::CubeCountZ => Range {
+                    lower_bound: Some(1),
+                    upper_bound: None,
+                },
                 _ => Default::default(),
             },
             _ => Default::default(),
@@ -52,11 +52,23 @@ impl Uniformity {
                 Operation::Plane(plane) => match plane {
                     // Elect returns true on only one unit, so it's always non-uniform
                     // Inclusive/exclusive scans are non-uniform by definition
+                    // Partitioned reductions are only uniform within each partition
+                    // Shuffles read a different unit for each unit
                     Plane::Elect
                     | Plane::ExclusiveSum(_)
                     | Plane::InclusiveSum(_)
                     | Plane::ExclusiveProd(_)
-                    | Plane::InclusiveProd(_) => self.mark_uniformity(out, false)?,
+                    | Plane::InclusiveProd(_)
+                    | Plane::PartitionSum(_)
+                    | Plane::PartitionProd(_)
+                    | Plane::PartitionMin(_)
+                    | Plane::PartitionMax(_)
+                    | Plane::PartitionInclusiveSum(_)
+                    | Plane::PartitionExclusiveSum(_)
+                    | Plane::PartitionInclusiveProd(_)
+                    | Plane::PartitionExclusiveProd(_)
+                    | Plane::ShuffleUp(_)
+                    | Plane::ShuffleDown(_) => self.mark_uniformity(out, false)?,
                     // Reductions are always uniform if executed in uniform control flow
                     Plane::Sum(_)
                     | Plane::Prod(_)
@@ -74,10 +86,21 @@ impl Uniformity {
                     }
                 },
                 Operation::Synchronization(sync) => match sync {
-                    Synchronization::SyncUnits | Synchronization::SyncStorage => {
+                    Synchronization::SyncUnits
+                    | Synchronization::SyncStorage
+                    | Synchronization::SyncCluster => {
                         block_uniform = true;
                     }
-                    Synchronization::SyncProxyShared => {}
+                    Synchronization::UniformLoad(_) => {
+                        block_uniform = true;
+                        self.mark_uniformity(out, block_uniform)?;
+                    }
+                    Synchronization::ClusterLoad(op) => {
+                        let is_uniform =
+                            self.is_var_uniform(op.rank) && self.is_var_uniform(op.index);
+                        self.mark_uniformity(out, is_uniform && block_uniform)?;
+                    }
+                    Synchronization::SyncProxyShared | Synchronization::MemoryFence { .. } => {}
                 },
                 op => {
                     let is_uniform =
@@ -2,8 +2,8 @@ use std::mem::transmute;
 
 use crate::{BasicBlock, BlockUse, NodeIndex, Optimizer};
 use cubecl_ir::{
-    Arithmetic, BinaryOperator, Branch, Comparison, ConstantScalarValue, Elem, If, IfElse,
-    Instruction, Item, Loop, Operation, RangeLoop, Switch, Variable, VariableKind,
+    Arithmetic, BinaryOperator, Branch, Comparison, ConstantScalarValue, Elem, FunctionCall, If,
+    IfElse, Instruction, Item, Loop, Operation, RangeLoop, Switch, Variable, VariableKind,
 };
 use petgraph::visit::EdgeRef;
 
@@ -58,6 +58,7 @@ impl Optimizer {
                 self.parse_for_loop(*range_loop);
             }
             Branch::Loop(loop_) => self.parse_loop(*loop_),
+            Branch::Call(call) => self.parse_call(*call),
             Branch::Return => {
                 let current_block = self.current_block.take().unwrap();
                 let ret = self.ret();
@@ -71,6 +72,19 @@ impl Optimizer {
         }
     }
 
+    /// SPIR-V can't call a function with access to the caller's variables, so the body is
+    /// expanded in place and its return value copied out.
+    pub(crate) fn parse_call(&mut self, call: FunctionCall) {
+        self.parse_scope(call.scope);
+
+        if let (Some(ret), Some(out), Some(current_block)) =
+            (call.ret, call.out, self.current_block)
+        {
+            let copy = Instruction::new(Operation::Copy(ret), out);
+            self.program[current_block].ops.borrow_mut().push(copy);
+        }
+    }
+
     pub(crate) fn parse_if(&mut self, if_: If) {
         let current_block = self.current_block.unwrap();
         let then = self.program.add_node(BasicBlock::default());
@@ -5,7 +5,7 @@ use std::{
 
 use cubecl_ir::{
     self as ir, Arithmetic, Comparison, ComparisonOpCode, Elem, Item, Metadata, OpCode, Operation,
-    OperationReflect, Operator, UIntKind, Variable, VariableKind,
+    OperationReflect, Operator, Synchronization, UIntKind, Variable, VariableKind,
 };
 
 use crate::PhiInstruction;
@@ -121,7 +121,13 @@ impl ValueTable {
             Operation::Bitwise(bitwise) => self.create_expr_simple_op(bitwise, inst.out()),
             Operation::Operator(operator) => self.create_expr_operator(operator, inst.out()),
             Operation::Metadata(metadata) => self.create_expr_meta(metadata, inst.out()),
-            Operation::Plane(_) | Operation::Atomic(_) => Err(value_of_var(&inst.out())),
+            Operation::Plane(_)
+            | Operation::Atomic(_)
+            | Operation::Synchronization(
+                Synchronization::UniformLoad(_) | Synchronization::ClusterLoad(_),
+            ) => {
+                Err(value_of_var(&inst.out()))
+            }
             Operation::Branch(_)
             | Operation::Synchronization(_)
             | Operation::CoopMma(_)
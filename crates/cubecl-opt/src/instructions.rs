@@ -1,7 +1,7 @@
 use cubecl_ir::{
     Arithmetic, AtomicOp, BarrierOps, BinaryOperator, Bitwise, Comparison, CoopMma, Instruction,
-    Metadata, NonSemantic, Operation, Operator, PipelineOps, Plane, TmaOps, UnaryOperator,
-    Variable,
+    Metadata, NonSemantic, Operation, Operator, PipelineOps, Plane, Synchronization, TmaOps,
+    UnaryOperator, Variable,
 };
 
 use super::Optimizer;
@@ -45,8 +45,7 @@ impl Optimizer {
             Operation::Operator(operator) => self.visit_operator(operator, visit_read),
             Operation::Atomic(atomic) => self.visit_atomic(atomic, out, visit_read),
             Operation::Metadata(meta) => self.visit_meta(meta, visit_read),
-            // Sync has no outputs
-            Operation::Synchronization(_) => {}
+            Operation::Synchronization(sync) => self.visit_sync(sync, visit_read),
             Operation::Plane(plane) => self.visit_plane(plane, visit_read),
             Operation::CoopMma(coop_mma) => self.visit_cmma(coop_mma, visit_read),
             Operation::Branch(_) => unreachable!(),
@@ -97,6 +96,8 @@ impl Optimizer {
             | Arithmetic::Ceil(unary_operator)
             | Arithmetic::Erf(unary_operator)
             | Arithmetic::Recip(unary_operator)
+            | Arithmetic::RecipFast(unary_operator)
+            | Arithmetic::RsqrtFast(unary_operator)
             | Arithmetic::Neg(unary_operator)
             | Arithmetic::Magnitude(unary_operator)
             | Arithmetic::Normalize(unary_operator) => self.visit_unop(unary_operator, visit_read),
@@ -146,7 +147,8 @@ impl Optimizer {
             | Bitwise::BitwiseNot(unary_operator)
             | Bitwise::ReverseBits(unary_operator)
             | Bitwise::LeadingZeros(unary_operator)
-            | Bitwise::FindFirstSet(unary_operator) => self.visit_unop(unary_operator, visit_read),
+            | Bitwise::FindFirstSet(unary_operator)
+            | Bitwise::TrailingZeros(unary_operator) => self.visit_unop(unary_operator, visit_read),
         }
     }
 
@@ -166,6 +168,8 @@ impl Optimizer {
             | Operator::Or(binary_operator) => self.visit_binop(binary_operator, visit_read),
             Operator::Not(unary_operator)
             | Operator::Cast(unary_operator)
+            | Operator::CastRte(unary_operator)
+            | Operator::CastRtz(unary_operator)
             | Operator::Reinterpret(unary_operator) => self.visit_unop(unary_operator, visit_read),
             Operator::Slice(slice_operator) => {
                 visit_read(self, &mut slice_operator.start);
@@ -255,10 +259,39 @@ impl Optimizer {
         }
     }
 
-    fn visit_plane(&mut self, plane: &mut Plane, visit_read: impl FnMut(&mut Self, &mut Variable)) {
+    fn visit_sync(
+        &mut self,
+        sync: &mut Synchronization,
+        mut visit_read: impl FnMut(&mut Self, &mut Variable),
+    ) {
+        match sync {
+            Synchronization::UniformLoad(binary_operator) => {
+                self.visit_binop(binary_operator, visit_read)
+            }
+            Synchronization::ClusterLoad(cluster_load) => {
+                visit_read(self, &mut cluster_load.shared);
+                visit_read(self, &mut cluster_load.rank);
+                visit_read(self, &mut cluster_load.index);
+            }
+            // Barriers have no inputs or outputs
+            Synchronization::SyncUnits
+            | Synchronization::SyncStorage
+            | Synchronization::SyncProxyShared
+            | Synchronization::SyncCluster
+            | Synchronization::MemoryFence { .. } => {}
+        }
+    }
+
+    fn visit_plane(
+        &mut self,
+        plane: &mut Plane,
+        mut visit_read: impl FnMut(&mut Self, &mut Variable),
+    ) {
         match plane {
             Plane::Elect => {}
-            Plane::Broadcast(binary_operator) => self.visit_binop(binary_operator, visit_read),
+            Plane::Broadcast(binary_operator)
+            | Plane::ShuffleUp(binary_operator)
+            | Plane::ShuffleDown(binary_operator) => self.visit_binop(binary_operator, visit_read),
             Plane::All(unary_operator)
             | Plane::Any(unary_operator)
             | Plane::Sum(unary_operator)
@@ -270,6 +303,14 @@ impl Optimizer {
             | Plane::Min(unary_operator)
             | Plane::Max(unary_operator)
             | Plane::Ballot(unary_operator) => self.visit_unop(unary_operator, visit_read),
+            Plane::PartitionSum(op)
+            | Plane::PartitionProd(op)
+            | Plane::PartitionMin(op)
+            | Plane::PartitionMax(op)
+            | Plane::PartitionInclusiveSum(op)
+            | Plane::PartitionExclusiveSum(op)
+            | Plane::PartitionInclusiveProd(op)
+            | Plane::PartitionExclusiveProd(op) => visit_read(self, &mut op.input),
         }
     }
 
@@ -423,6 +464,10 @@ impl Optimizer {
                     visit_read(self, arg);
                 }
             }
+            NonSemantic::Prefetch { list, index, .. } => {
+                visit_read(self, list);
+                visit_read(self, index);
+            }
         }
     }
 
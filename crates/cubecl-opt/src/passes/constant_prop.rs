@@ -389,7 +389,9 @@ fn try_const_eval_arithmetic(op: &mut Arithmetic) -> Option<ConstantScalarValue>
         Arithmetic::Round(op) => const_eval_float!(op.input; num::Float::round),
         Arithmetic::Floor(op) => const_eval_float!(op.input; num::Float::floor),
         Arithmetic::Ceil(op) => const_eval_float!(op.input; num::Float::ceil),
-        Arithmetic::Recip(op) => const_eval_float!(op.input; num::Float::recip),
+        Arithmetic::Recip(op) | Arithmetic::RecipFast(op) => {
+            const_eval_float!(op.input; num::Float::recip)
+        }
         Arithmetic::Neg(op) => {
             use ConstantScalarValue::*;
             op.input.as_const().map(|input| match input {
@@ -439,7 +441,10 @@ fn try_const_eval_arithmetic(op: &mut Arithmetic) -> Option<ConstantScalarValue>
                 }
             })
         }
-        Arithmetic::Erf(_) | Arithmetic::Magnitude(_) | Arithmetic::Normalize(_) => None,
+        Arithmetic::Erf(_)
+        | Arithmetic::RsqrtFast(_)
+        | Arithmetic::Magnitude(_)
+        | Arithmetic::Normalize(_) => None,
     }
 }
 
@@ -485,7 +490,7 @@ fn try_const_eval_bitwise(op: &mut Bitwise) -> Option<ConstantScalarValue> {
                 _ => unreachable!(),
             })
         }
-        Bitwise::LeadingZeros(_) | Bitwise::FindFirstSet(_) => {
+        Bitwise::LeadingZeros(_) | Bitwise::FindFirstSet(_) | Bitwise::TrailingZeros(_) => {
             // Depends too much on type width and Rust semantics, leave this one out of const eval
             None
         }
@@ -504,6 +509,8 @@ fn try_const_eval_operator(op: &mut Operator) -> Option<ConstantScalarValue> {
             })
         }
         Operator::Cast(_)
+        | Operator::CastRte(_)
+        | Operator::CastRtz(_)
         | Operator::Index(_)
         | Operator::CopyMemory(_)
         | Operator::CopyMemoryBulk(_)
@@ -9,6 +9,8 @@ use crate::ReduceStrategy;
 // TODO: Should we allows the user to change that?
 const DEFAULT_CUBE_DIM: CubeDim = CubeDim::new_2d(32, 8);
 const DEFAULT_PLANE_COUNT: u32 = 8;
+// The number of elements a unit reduces at most before a slice is split over multiple cubes.
+const MAX_ELEMENTS_PER_UNIT: u32 = 16;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 pub enum LineMode {
@@ -43,6 +45,10 @@ pub struct ReduceConfig {
 }
 
 impl ReduceConfig {
+    /// The cube dimension used to [split](ReduceStrategy::split_strided) strided slices, with
+    /// adjacent slices along `x` and the units splitting each slice along `y`.
+    pub(crate) const SPLIT_CUBE_DIM: CubeDim = DEFAULT_CUBE_DIM;
+
     pub(crate) fn generate<R: Runtime, In: CubePrimitive>(
         client: &ComputeClient<R::Server, R::Channel>,
         input: &TensorHandleRef<R>,
@@ -51,9 +57,33 @@ impl ReduceConfig {
         strategy: &ReduceStrategy,
     ) -> ReduceConfig {
         let reduce_count = output.size() as u32;
-        ReduceConfig::new()
+        let config = ReduceConfig::new()
             .generate_line_mode(input, axis)
-            .generate_line_size::<R, In>(input, output, axis)
+            .generate_line_size::<R, In>(input, output, axis);
+        let config = match config.split_strided(strategy) {
+            true => ReduceConfig {
+                cube_dim: Self::SPLIT_CUBE_DIM,
+                ..config
+            },
+            false => config.generate_cube_dim(client, strategy.use_planes),
+        };
+        config.generate_cube_count::<R>(reduce_count, strategy)
+    }
+
+    /// Whether the slices are [split](ReduceStrategy::split_strided), which only happens when
+    /// the reduced axis is strided.
+    pub(crate) fn split_strided(&self, strategy: &ReduceStrategy) -> bool {
+        strategy.split_strided && self.line_mode == LineMode::Perpendicular
+    }
+
+    /// Generate a configuration with one agent per slice of `reduce_count` slices, each reading its
+    /// slice one element at a time.
+    pub(crate) fn generate_elementwise<R: Runtime>(
+        client: &ComputeClient<R::Server, R::Channel>,
+        reduce_count: u32,
+        strategy: &ReduceStrategy,
+    ) -> ReduceConfig {
+        ReduceConfig::new()
             .generate_cube_dim(client, strategy.use_planes)
             .generate_cube_count::<R>(reduce_count, strategy)
     }
@@ -188,6 +218,8 @@ impl ReduceConfig {
     ) -> Self {
         let agent_count_per_cube =  // An agent is either a unit, a plane or a whole cube depending on the strategy.
             match strategy {
+                // The units along `y` split the slices of the units along `x`.
+                _ if self.split_strided(strategy) => self.cube_dim.x,
                 ReduceStrategy { shared: true, .. } => 1,
                 ReduceStrategy { use_planes: true, .. } => self.cube_dim.y,
                 ReduceStrategy { use_planes: false, .. } => self.cube_dim.num_elems(),
@@ -220,6 +252,26 @@ impl ReduceConfig {
         self
     }
 
+    /// The length of the chunks to split slices of `shape_axis` elements into so that each cube
+    /// reduces at most what it can process at once, or `None` if a single cube is enough.
+    pub(crate) fn chunk_length(&self, shape_axis: u32) -> Option<u32> {
+        let capacity = self.cube_dim.num_elems() * self.line_size_input * MAX_ELEMENTS_PER_UNIT;
+        if shape_axis <= capacity {
+            return None;
+        }
+
+        // Balance the chunks, so a slice barely longer than the capacity is split in two halves
+        // instead of a full chunk followed by a tiny one.
+        let chunk_count = shape_axis.div_ceil(capacity);
+        let chunk_length = shape_axis.div_ceil(chunk_count);
+
+        Some(match self.line_mode {
+            // A chunk must start on a line.
+            LineMode::Parallel => chunk_length.next_multiple_of(self.line_size_input),
+            LineMode::Perpendicular => chunk_length,
+        })
+    }
+
     fn do_bound_checks_if(&mut self, condition: bool) {
         self.bound_checks = self.bound_checks || condition;
     }
@@ -12,11 +12,22 @@ pub enum ReduceError {
     ImprecisePlaneDim,
     /// Indicate the axis is too large.
     InvalidAxis { axis: usize, rank: usize },
+    /// Indicate that the axis can't be represented in the mask of the reduced axes.
+    AxisTooLarge { axis: usize, max_axis: usize },
     /// Indicate that the shape of the output tensor is invalid for the given input and axis.
     MismatchShape {
         expected_shape: Vec<usize>,
         output_shape: Vec<usize>,
     },
+    /// Indicate that the shape of the mask tensor doesn't match the shape of the input tensor.
+    MismatchMaskShape {
+        input_shape: Vec<usize>,
+        mask_shape: Vec<usize>,
+    },
+    /// Indicate that the number of output tensors doesn't match the number of outputs of the instruction.
+    MismatchOutputCount { expected: usize, got: usize },
+    /// Indicate that the output tensors of a multi-output reduction don't share the same strides.
+    MismatchOutputStrides,
     /// Indicate that we can't launch a shared sum because the atomic addition is not supported.
     MissingAtomicAdd(Elem),
 }
@@ -39,6 +50,10 @@ impl fmt::Display for ReduceError {
                 f,
                 "The provided axis ({axis}) must be smaller than the input tensor rank ({rank})."
             ),
+            Self::AxisTooLarge { axis, max_axis } => write!(
+                f,
+                "The provided axis ({axis}) must not be larger than {max_axis} to be reduced with other axes."
+            ),
             Self::MismatchShape {
                 expected_shape,
                 output_shape,
@@ -48,6 +63,24 @@ impl fmt::Display for ReduceError {
                     "The output shape (currently {output_shape:?}) should be {expected_shape:?}."
                 )
             }
+            Self::MismatchMaskShape {
+                input_shape,
+                mask_shape,
+            } => {
+                write!(
+                    f,
+                    "The mask shape (currently {mask_shape:?}) should be the input shape {input_shape:?}."
+                )
+            }
+            Self::MismatchOutputCount { expected, got } => {
+                write!(
+                    f,
+                    "The reduction writes {expected} outputs, but {got} output tensors were provided."
+                )
+            }
+            Self::MismatchOutputStrides => {
+                write!(f, "All the output tensors should have the same strides.")
+            }
             Self::MissingAtomicAdd(elem) => {
                 write!(f, "Atomic add not supported by the client for {elem}")
             }
@@ -4,6 +4,11 @@ use cubecl_core::prelude::*;
 pub trait ReduceFamily: Send + Sync + 'static + std::fmt::Debug {
     type Instruction<In: Numeric>: ReduceInstruction<In, Config = Self::Config>;
     type Config: CubeComptime + Send + Sync;
+
+    /// Whether reducing the outputs of this reduction over consecutive chunks of a slice gives the
+    /// reduction of the whole slice. This lets [`reduce`](crate::reduce) split slices too long for
+    /// a single cube over multiple passes.
+    const COMBINES_PARTIALS: bool = false;
 }
 
 #[derive(CubeType)]
@@ -88,6 +93,10 @@ pub trait ReduceInstruction<In: Numeric>:
     ) -> Line<Out>;
 }
 
+/// The coordinates of the items along the reduced axis, when the instruction requires them.
+///
+/// The lanes of the items outside of the reduced slice, which are read as the null input, have
+/// the coordinate `u32::MAX`.
 #[derive(CubeType)]
 pub enum ReduceCoordinate {
     Required(Line<u32>),
@@ -163,6 +172,44 @@ impl<In: Numeric> SharedAccumulator<In> for ArgAccumulator<In> {
     }
 }
 
+/// A pair of shared accumulators used by instructions accumulating two statistics at once,
+/// such as a [pair of reductions](super::ReducePair).
+#[derive(CubeType)]
+pub struct PairAccumulator<First: CubeType, Second: CubeType> {
+    pub first: First,
+    pub second: Second,
+}
+
+#[cube]
+impl<In: Numeric, First: SharedAccumulator<In>, Second: SharedAccumulator<In>> SharedAccumulator<In>
+    for PairAccumulator<First, Second>
+{
+    type Item = (First::Item, Second::Item);
+
+    fn allocate(
+        #[comptime] length: u32,
+        #[comptime] line_size: u32,
+        #[comptime] coordinate: bool,
+    ) -> Self {
+        PairAccumulator::<First, Second> {
+            first: First::allocate(length, line_size, coordinate),
+            second: Second::allocate(length, line_size, coordinate),
+        }
+    }
+
+    fn read(accumulator: &Self, index: u32) -> Self::Item {
+        (
+            First::read(&accumulator.first, index),
+            Second::read(&accumulator.second, index),
+        )
+    }
+
+    fn write(accumulator: &mut Self, index: u32, item: Self::Item) {
+        First::write(&mut accumulator.first, index, item.0);
+        Second::write(&mut accumulator.second, index, item.1);
+    }
+}
+
 #[cube]
 pub fn reduce_inplace<In: Numeric, R: ReduceInstruction<In>>(
     inst: &R,
@@ -0,0 +1,60 @@
+use cubecl_core as cubecl;
+use cubecl_core::prelude::*;
+
+/// An elementwise map applied to each element of the input before it is reduced by
+/// [`map_reduce_map`](crate::map_reduce_map).
+#[cube]
+pub trait ReduceMap: Send + Sync + 'static + std::fmt::Debug {
+    fn apply<F: Float>(value: Line<F>) -> Line<F>;
+}
+
+/// An elementwise map applied to each mapped element of a slice with the reduction of that slice,
+/// written to the output of [`map_reduce_map`](crate::map_reduce_map).
+#[cube]
+pub trait ReduceBroadcastMap: Send + Sync + 'static + std::fmt::Debug {
+    fn apply<F: Float>(value: Line<F>, reduced: F) -> Line<F>;
+}
+
+/// Keep the elements unchanged.
+#[derive(Debug)]
+pub struct Identity;
+
+#[cube]
+impl ReduceMap for Identity {
+    fn apply<F: Float>(value: Line<F>) -> Line<F> {
+        value
+    }
+}
+
+/// Take the exponential of the elements.
+#[derive(Debug)]
+pub struct ExpMap;
+
+#[cube]
+impl ReduceMap for ExpMap {
+    fn apply<F: Float>(value: Line<F>) -> Line<F> {
+        Exp::exp(value)
+    }
+}
+
+/// Divide the elements by the reduction of their slice, such as a sum to normalize them.
+#[derive(Debug)]
+pub struct DivideBy;
+
+#[cube]
+impl ReduceBroadcastMap for DivideBy {
+    fn apply<F: Float>(value: Line<F>, reduced: F) -> Line<F> {
+        value / Line::empty(value.size()).fill(reduced)
+    }
+}
+
+/// Subtract the reduction of their slice from the elements, such as a mean to center them.
+#[derive(Debug)]
+pub struct SubtractBy;
+
+#[cube]
+impl ReduceBroadcastMap for SubtractBy {
+    fn apply<F: Float>(value: Line<F>, reduced: F) -> Line<F> {
+        value - Line::empty(value.size()).fill(reduced)
+    }
+}
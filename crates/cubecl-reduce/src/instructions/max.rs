@@ -13,6 +13,7 @@ pub struct Max;
 impl ReduceFamily for Max {
     type Instruction<In: Numeric> = Self;
     type Config = ();
+    const COMBINES_PARTIALS: bool = true;
 }
 
 #[cube]
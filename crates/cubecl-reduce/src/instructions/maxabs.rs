@@ -13,6 +13,7 @@ pub struct MaxAbs;
 impl ReduceFamily for MaxAbs {
     type Instruction<In: Numeric> = Self;
     type Config = ();
+    const COMBINES_PARTIALS: bool = true;
 }
 
 #[cube]
@@ -13,6 +13,7 @@ pub struct Min;
 impl ReduceFamily for Min {
     type Instruction<In: Numeric> = Self;
     type Config = ();
+    const COMBINES_PARTIALS: bool = true;
 }
 
 #[cube]
@@ -1,11 +1,13 @@
 mod argmax;
 mod argmin;
 mod base;
+mod map;
 mod max;
 mod maxabs;
 mod mean;
 mod min;
 mod mixed;
+mod multi;
 mod prod;
 mod sum;
 mod utils;
@@ -13,11 +15,13 @@ mod utils;
 pub use argmax::*;
 pub use argmin::*;
 pub use base::*;
+pub use map::*;
 pub use max::*;
 pub use maxabs::*;
 pub use mean::*;
 pub use min::*;
 pub use mixed::*;
+pub use multi::*;
 pub use prod::*;
 pub use sum::*;
 pub(crate) use utils::*;
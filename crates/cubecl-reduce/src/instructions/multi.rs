@@ -0,0 +1,232 @@
+use core::marker::PhantomData;
+
+use cubecl_core as cubecl;
+use cubecl_core::prelude::*;
+
+use super::{
+    PairAccumulator, ReduceCoordinate, ReduceCoordinateExpand, ReduceFamily, ReduceInstruction,
+    ReduceRequirements,
+};
+
+/// The family of a [`MultiReduceInstruction`], see [`ReduceFamily`](super::ReduceFamily).
+pub trait MultiReduceFamily: Send + Sync + 'static + std::fmt::Debug {
+    type Instruction<In: Numeric>: MultiReduceInstruction<In, Config = Self::Config>;
+    type Config: CubeComptime + Send + Sync;
+
+    /// The number of outputs written by the reduction.
+    const NUM_OUTPUTS: usize;
+}
+
+/// An instruction computing multiple statistics in a single traversal of the input,
+/// such as the sum and the sum of squares needed for a variance.
+///
+/// The accumulator, the per-element update and the combine are the ones of [`ReduceInstruction`],
+/// generally over a tuple of accumulators with a [`PairAccumulator`] or a custom
+/// [`SharedAccumulator`](super::SharedAccumulator). When using planes, `reduce` must do the plane
+/// combine of every statistic. Only the conversion into the outputs differs from a regular
+/// reduction: each function returns one value per output, in the order of the output tensors.
+///
+/// See [`ReducePair`] to combine two existing reductions.
+#[cube]
+pub trait MultiReduceInstruction<In: Numeric>: ReduceInstruction<In> {
+    /// Reduce all elements of the accumulator into one output element of type `Out` per output.
+    fn merge_line_multi<Out: Numeric>(
+        this: &Self,
+        accumulator: Self::AccumulatorItem,
+        shape_axis_reduce: u32,
+    ) -> Sequence<Out>;
+
+    /// Convert each element of the accumulator into one line of type `Out` per output.
+    fn to_output_perpendicular_multi<Out: Numeric>(
+        this: &Self,
+        accumulator: Self::AccumulatorItem,
+        shape_axis_reduce: u32,
+    ) -> Sequence<Line<Out>>;
+}
+
+/// A multi-output reduction computing the reductions `A` and `B` of the same input in a single
+/// traversal, writing the result of `A` to the first output and the result of `B` to the second.
+///
+/// For example, `ReducePair<Sum, Max>` computes both the sum and the maximum of each slice.
+#[derive(Debug)]
+pub struct ReducePair<A: ReduceFamily, B: ReduceFamily> {
+    _phantom: PhantomData<(A, B)>,
+}
+
+impl<A: ReduceFamily, B: ReduceFamily> MultiReduceFamily for ReducePair<A, B> {
+    type Instruction<In: Numeric> = PairInstruction<A::Instruction<In>, B::Instruction<In>>;
+    type Config = (A::Config, B::Config);
+
+    const NUM_OUTPUTS: usize = 2;
+}
+
+/// The instruction of a [`ReducePair`].
+#[derive(Debug, CubeType, Clone)]
+pub struct PairInstruction<A: CubeType, B: CubeType> {
+    pub first: A,
+    pub second: B,
+}
+
+#[cube]
+impl<In: Numeric, A: ReduceInstruction<In>, B: ReduceInstruction<In>> ReduceInstruction<In>
+    for PairInstruction<A, B>
+{
+    type AccumulatorItem = (A::AccumulatorItem, B::AccumulatorItem);
+    type SharedAccumulator = PairAccumulator<A::SharedAccumulator, B::SharedAccumulator>;
+    type Config = (A::Config, B::Config);
+
+    // The coordinates tell which lanes are outside of the reduced slice, see `reduce`.
+    fn requirements(_this: &Self) -> ReduceRequirements {
+        ReduceRequirements { coordinates: true }
+    }
+
+    fn from_config(#[comptime] config: Self::Config) -> Self {
+        PairInstruction::<A, B> {
+            first: A::from_config(comptime!(config.0)),
+            second: B::from_config(comptime!(config.1)),
+        }
+    }
+
+    // No single input is null for both reductions, so the lanes read as the null input are
+    // replaced by the null input of each reduction in `reduce`.
+    fn null_input(this: &Self, #[comptime] line_size: u32) -> Line<In> {
+        A::null_input(&this.first, line_size)
+    }
+
+    fn null_accumulator(this: &Self, #[comptime] line_size: u32) -> Self::AccumulatorItem {
+        (
+            A::null_accumulator(&this.first, line_size),
+            B::null_accumulator(&this.second, line_size),
+        )
+    }
+
+    fn assign_accumulator(
+        this: &Self,
+        destination: &mut Self::AccumulatorItem,
+        source: &Self::AccumulatorItem,
+    ) {
+        A::assign_accumulator(&this.first, &mut destination.0, &source.0);
+        B::assign_accumulator(&this.second, &mut destination.1, &source.1);
+    }
+
+    fn reduce(
+        this: &Self,
+        accumulator: &Self::AccumulatorItem,
+        item: Line<In>,
+        coordinate: ReduceCoordinate,
+        #[comptime] use_planes: bool,
+    ) -> Self::AccumulatorItem {
+        let coordinate = match coordinate {
+            ReduceCoordinate::Required(val) => val,
+            ReduceCoordinate::NotRequired => {
+                comptime! {panic!("Coordinates are required for ReducePair")};
+                #[allow(unreachable_code)]
+                Line::new(0)
+            }
+        };
+        let line_size = item.size();
+        let outside = coordinate.equal(Line::empty(line_size).fill(u32::MAX));
+
+        let first = select_many(outside, A::null_input(&this.first, line_size), item);
+        let second = select_many(outside, B::null_input(&this.second, line_size), item);
+
+        (
+            A::reduce(
+                &this.first,
+                &accumulator.0,
+                first,
+                pair_coordinate(A::requirements(&this.first), coordinate),
+                use_planes,
+            ),
+            B::reduce(
+                &this.second,
+                &accumulator.1,
+                second,
+                pair_coordinate(B::requirements(&this.second), coordinate),
+                use_planes,
+            ),
+        )
+    }
+
+    fn fuse_accumulators(
+        this: &Self,
+        lhs: Self::AccumulatorItem,
+        rhs: Self::AccumulatorItem,
+    ) -> Self::AccumulatorItem {
+        (
+            A::fuse_accumulators(&this.first, lhs.0, rhs.0),
+            B::fuse_accumulators(&this.second, lhs.1, rhs.1),
+        )
+    }
+
+    // A pair is only launched as a multi-output reduction, which uses the conversions of
+    // `MultiReduceInstruction`, so the single output is the first reduction.
+    fn merge_line<Out: Numeric>(
+        this: &Self,
+        accumulator: Self::AccumulatorItem,
+        shape_axis_reduce: u32,
+    ) -> Out {
+        A::merge_line::<Out>(&this.first, accumulator.0, shape_axis_reduce)
+    }
+
+    fn to_output_perpendicular<Out: Numeric>(
+        this: &Self,
+        accumulator: Self::AccumulatorItem,
+        shape_axis_reduce: u32,
+    ) -> Line<Out> {
+        A::to_output_perpendicular::<Out>(&this.first, accumulator.0, shape_axis_reduce)
+    }
+}
+
+#[cube]
+impl<In: Numeric, A: ReduceInstruction<In>, B: ReduceInstruction<In>> MultiReduceInstruction<In>
+    for PairInstruction<A, B>
+{
+    fn merge_line_multi<Out: Numeric>(
+        this: &Self,
+        accumulator: Self::AccumulatorItem,
+        shape_axis_reduce: u32,
+    ) -> Sequence<Out> {
+        let mut outputs = Sequence::new();
+        outputs.push(A::merge_line::<Out>(
+            &this.first,
+            accumulator.0,
+            shape_axis_reduce,
+        ));
+        outputs.push(B::merge_line::<Out>(
+            &this.second,
+            accumulator.1,
+            shape_axis_reduce,
+        ));
+        outputs
+    }
+
+    fn to_output_perpendicular_multi<Out: Numeric>(
+        this: &Self,
+        accumulator: Self::AccumulatorItem,
+        shape_axis_reduce: u32,
+    ) -> Sequence<Line<Out>> {
+        let mut outputs = Sequence::new();
+        outputs.push(A::to_output_perpendicular::<Out>(
+            &this.first,
+            accumulator.0,
+            shape_axis_reduce,
+        ));
+        outputs.push(B::to_output_perpendicular::<Out>(
+            &this.second,
+            accumulator.1,
+            shape_axis_reduce,
+        ));
+        outputs
+    }
+}
+
+/// The coordinate given to one of the reductions of a pair, depending on its requirements.
+#[cube]
+fn pair_coordinate(requirements: ReduceRequirements, coordinate: Line<u32>) -> ReduceCoordinate {
+    if comptime![requirements.coordinates] {
+        ReduceCoordinate::new_Required(coordinate)
+    } else {
+        ReduceCoordinate::new_NotRequired()
+    }
+}
@@ -11,6 +11,7 @@ pub struct Prod {}
 impl ReduceFamily for Prod {
     type Instruction<In: Numeric> = Self;
     type Config = ();
+    const COMBINES_PARTIALS: bool = true;
 }
 
 #[cube]
@@ -9,6 +9,7 @@ pub struct Sum {}
 impl ReduceFamily for Sum {
     type Instruction<In: Numeric> = Self;
     type Config = ();
+    const COMBINES_PARTIALS: bool = true;
 }
 
 #[cube]
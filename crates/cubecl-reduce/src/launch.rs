@@ -23,21 +23,29 @@ pub(crate) fn launch_reduce<Run: Runtime, In: Numeric, Out: Numeric, Rd: ReduceF
     strategy: ReduceStrategy,
     inst: Rd::Config,
 ) {
-    let settings = ReduceParams {
-        shared: strategy.shared.then(|| {
-            if strategy.use_planes {
-                config.cube_dim.y
-            } else {
-                config.cube_dim.num_elems()
-            }
-        }),
-        use_planes: strategy.use_planes,
-        line_size_input: config.line_size_input,
-        line_size_output: config.line_size_output,
-        line_mode: config.line_mode,
-        bound_checks: config.bound_checks,
-        bound_checks_inner: config.bound_checks_inner,
-    };
+    let settings = ReduceParams::new(&config, &strategy);
+    if config.split_strided(&strategy) {
+        let settings = ReduceParams {
+            shared: None,
+            use_planes: false,
+            ..settings
+        };
+        unsafe {
+            reduce_split_strided_kernel::launch_unchecked::<In, Out, Rd, Run>(
+                client,
+                config.cube_count,
+                config.cube_dim,
+                input.as_tensor_arg(config.line_size_input as u8),
+                output.as_tensor_arg(config.line_size_output as u8),
+                ScalarArg::new(axis),
+                settings,
+                config.cube_dim.num_elems(),
+                inst,
+            );
+        }
+        return;
+    }
+
     unsafe {
         reduce_kernel::launch_unchecked::<In, Out, Rd, TensorArgs, Run>(
             client,
@@ -52,6 +60,167 @@ pub(crate) fn launch_reduce<Run: Runtime, In: Numeric, Out: Numeric, Rd: ReduceF
     }
 }
 
+/// Launch a reduce kernel over consecutive chunks of `chunk_length` elements along `axis`, writing
+/// the reduction of each chunk into `output`. This function assumes that all parameters are already
+/// validated. See `reduce_chunked` in `lib.rs`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn launch_reduce_chunks<Run: Runtime, In: Numeric, Out: Numeric, Rd: ReduceFamily>(
+    client: &ComputeClient<Run::Server, Run::Channel>,
+    input: TensorHandleRef<Run>,
+    output: TensorHandleRef<Run>,
+    axis: u32,
+    chunk_length: u32,
+    config: ReduceConfig,
+    strategy: ReduceStrategy,
+    inst: Rd::Config,
+) {
+    let settings = ReduceParams::new(&config, &strategy);
+    unsafe {
+        reduce_chunks_kernel::launch_unchecked::<In, Out, Rd, Run>(
+            client,
+            config.cube_count,
+            config.cube_dim,
+            input.as_tensor_arg(config.line_size_input as u8),
+            output.as_tensor_arg(config.line_size_output as u8),
+            ScalarArg::new(axis),
+            ScalarArg::new(chunk_length),
+            settings,
+            inst,
+        );
+    }
+}
+
+/// Launch a multi-output reduce kernel, writing one reduction per tensor of `outputs`.
+/// This function assumes that all parameters are already validated.
+/// See the entrypoint `reduce_multi` in `lib.rs`.
+pub(crate) fn launch_reduce_multi<
+    Run: Runtime,
+    In: Numeric,
+    Out: Numeric,
+    Rd: MultiReduceFamily,
+>(
+    client: &ComputeClient<Run::Server, Run::Channel>,
+    input: TensorHandleRef<Run>,
+    outputs: &[TensorHandleRef<Run>],
+    axis: u32,
+    config: ReduceConfig,
+    strategy: ReduceStrategy,
+    inst: Rd::Config,
+) {
+    let settings = ReduceParams::new(&config, &strategy);
+    let mut outputs_arg = SequenceArg::new();
+    for output in outputs {
+        outputs_arg.push(output.as_tensor_arg(config.line_size_output as u8));
+    }
+
+    unsafe {
+        reduce_multi_kernel::launch_unchecked::<In, Out, Rd, Run>(
+            client,
+            config.cube_count,
+            config.cube_dim,
+            input.as_tensor_arg(config.line_size_input as u8),
+            outputs_arg,
+            ScalarArg::new(axis),
+            settings,
+            inst,
+        );
+    }
+}
+
+/// Launch a reduce kernel over all the axes set in `axes_mask`, using one unit per output element.
+/// This function assumes that all parameters are already validated.
+/// See the entrypoint `reduce_axes` in `lib.rs`.
+pub(crate) fn launch_reduce_axes<Run: Runtime, In: Numeric, Out: Numeric, Rd: ReduceFamily>(
+    client: &ComputeClient<Run::Server, Run::Channel>,
+    input: TensorHandleRef<Run>,
+    output: TensorHandleRef<Run>,
+    axes_mask: u32,
+    cube_count: CubeCount,
+    cube_dim: CubeDim,
+    inst: Rd::Config,
+) {
+    unsafe {
+        reduce_axes_kernel::launch_unchecked::<In, Out, Rd, Run>(
+            client,
+            cube_count,
+            cube_dim,
+            input.as_tensor_arg(1),
+            output.as_tensor_arg(1),
+            ScalarArg::new(axes_mask),
+            inst,
+        );
+    }
+}
+
+/// Launch a kernel reducing each slice along `axis` and writing every element of the slice mapped
+/// with its reduction. This function assumes that all parameters are already validated.
+/// See the entrypoint `map_reduce_map` in `lib.rs`.
+pub(crate) fn launch_map_reduce_map<
+    Run: Runtime,
+    F: Float,
+    Pre: ReduceMap,
+    Rd: ReduceFamily,
+    Post: ReduceBroadcastMap,
+>(
+    client: &ComputeClient<Run::Server, Run::Channel>,
+    input: TensorHandleRef<Run>,
+    output: TensorHandleRef<Run>,
+    axis: u32,
+    config: ReduceConfig,
+    strategy: ReduceStrategy,
+    inst: Rd::Config,
+) {
+    let settings = ReduceParams::new(&config, &strategy);
+    unsafe {
+        map_reduce_map_kernel::launch_unchecked::<F, Pre, Rd, Post, Run>(
+            client,
+            config.cube_count,
+            config.cube_dim,
+            input.as_tensor_arg(1),
+            output.as_tensor_arg(1),
+            ScalarArg::new(axis),
+            settings,
+            inst,
+        );
+    }
+}
+
+/// Launch a kernel reducing the elements of each slice along `axis` selected by `mask`.
+/// This function assumes that all parameters are already validated.
+/// See the entrypoint `reduce_masked` in `lib.rs`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn launch_reduce_masked<
+    Run: Runtime,
+    In: Numeric,
+    M: Numeric,
+    Out: Numeric,
+    Rd: ReduceFamily,
+>(
+    client: &ComputeClient<Run::Server, Run::Channel>,
+    input: TensorHandleRef<Run>,
+    mask: TensorHandleRef<Run>,
+    output: TensorHandleRef<Run>,
+    axis: u32,
+    config: ReduceConfig,
+    strategy: ReduceStrategy,
+    inst: Rd::Config,
+) {
+    let settings = ReduceParams::new(&config, &strategy);
+    unsafe {
+        reduce_masked_kernel::launch_unchecked::<In, M, Out, Rd, Run>(
+            client,
+            config.cube_count,
+            config.cube_dim,
+            input.as_tensor_arg(1),
+            mask.as_tensor_arg(1),
+            output.as_tensor_arg(1),
+            ScalarArg::new(axis),
+            settings,
+            inst,
+        );
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ReduceParams {
     pub shared: Option<u32>, // shared if Some(x) where x is the accumulator size.
@@ -63,6 +232,26 @@ pub struct ReduceParams {
     pub bound_checks_inner: BoundChecksInner,
 }
 
+impl ReduceParams {
+    pub(crate) fn new(config: &ReduceConfig, strategy: &ReduceStrategy) -> Self {
+        Self {
+            shared: strategy.shared.then(|| {
+                if strategy.use_planes {
+                    config.cube_dim.y
+                } else {
+                    config.cube_dim.num_elems()
+                }
+            }),
+            use_planes: strategy.use_planes,
+            line_size_input: config.line_size_input,
+            line_size_output: config.line_size_output,
+            line_mode: config.line_mode,
+            bound_checks: config.bound_checks,
+            bound_checks_inner: config.bound_checks_inner,
+        }
+    }
+}
+
 #[cube(launch_unchecked)]
 pub fn reduce_kernel<In: Numeric, Out: Numeric, R: ReduceFamily, RA: ReduceArgs>(
     input: &RA::Input<In>,
@@ -83,10 +272,592 @@ pub fn reduce_kernel<In: Numeric, Out: Numeric, R: ReduceFamily, RA: ReduceArgs>
     let range = ReduceRange::new::<In, Out>(reduce_index, &input, &mut output, axis_reduce, params);
 
     let inst = &R::Instruction::<In>::from_config(config);
-    let accumulator = match comptime!((params.shared, params.use_planes)) {
+    let accumulator = reduce_accumulate::<In, R::Instruction<In>>(&input, inst, range, params);
+
+    if elected_writer(params) {
+        write_to_output::<In, Out, R::Instruction<In>>(
+            &mut output,
+            accumulator,
+            reduce_index,
+            input.shape(axis_reduce),
+            params,
+            inst,
+        );
+    }
+}
+
+/// Reduce the strided `axis_reduce` of `input`, with the units along `x` of a cube reducing
+/// adjacent slices so their loads are coalesced, and the units along `y` splitting each slice.
+///
+/// Each unit reduces every `CUBE_DIM_Y`-th element of its slice, then the units along `y` combine
+/// their accumulators in shared memory of `accumulator_size` entries, one per unit of the cube.
+/// The `params` must describe a [perpendicular](LineMode::Perpendicular) reduction without
+/// planes or shared memory.
+#[cube(launch_unchecked)]
+pub fn reduce_split_strided_kernel<In: Numeric, Out: Numeric, R: ReduceFamily>(
+    input: &Tensor<Line<In>>,
+    output: &mut Tensor<Line<Out>>,
+    axis_reduce: u32,
+    #[comptime] params: ReduceParams,
+    #[comptime] accumulator_size: u32,
+    #[comptime] config: R::Config,
+) {
+    let (input, mut output) = init_tensors::<TensorArgs, In, Out>(input, output);
+    let reduce_index = CUBE_POS * CUBE_DIM_X + UNIT_POS_X;
+
+    // Every unit takes part in combining the accumulators, so the units past the last slice
+    // reduce nothing instead of terminating.
+    let mut in_bounds = true.runtime();
+    if comptime![params.bound_checks] {
+        in_bounds = reduce_index < get_reduce_count(output.len() * params.line_size_output, params);
+    }
+
+    let range = ReduceRange::new::<In, Out>(reduce_index, &input, &mut output, axis_reduce, params);
+    let unit_range = ReduceRange {
+        index_start: range.index_start + UNIT_POS_Y * range.index_step,
+        index_step: range.index_step * CUBE_DIM_Y,
+        coordinate_start: UNIT_POS_Y,
+        coordinate_end: select(in_bounds, range.coordinate_end, 0u32),
+        coordinate_step: CUBE_DIM_Y,
+    };
+
+    let inst = &R::Instruction::<In>::from_config(config);
+    let accumulator = reduce_slice::<In, VirtualTensor<In>, R::Instruction<In>>(
+        &input,
+        unit_range,
+        inst,
+        params.line_size_input,
+        params.line_mode,
+    );
+    let accumulator = combine_split::<In, R::Instruction<In>>(
+        inst,
+        accumulator,
+        accumulator_size,
+        params.line_size_input,
+    );
+
+    if UNIT_POS_Y == 0 && in_bounds {
+        write_to_output::<In, Out, R::Instruction<In>>(
+            &mut output,
+            accumulator,
+            reduce_index,
+            input.shape(axis_reduce),
+            params,
+            inst,
+        );
+    }
+}
+
+// Combine the accumulators of the units along `y` through shared memory, folding the upper half
+// of the rows onto the lower half until a single row is left. Only the units of the first row get
+// the combined accumulator of their slice.
+#[cube]
+#[allow(unknown_lints)] // `manual_div_ceil` only appeared in 1.83
+#[allow(clippy::manual_div_ceil)]
+fn combine_split<In: Numeric, R: ReduceInstruction<In>>(
+    inst: &R,
+    accumulator: R::AccumulatorItem,
+    #[comptime] accumulator_size: u32,
+    #[comptime] line_size: u32,
+) -> R::AccumulatorItem {
+    let requirements = R::requirements(inst);
+    let mut shared =
+        R::SharedAccumulator::allocate(accumulator_size, line_size, requirements.coordinates);
+    R::SharedAccumulator::write(&mut shared, UNIT_POS, accumulator);
+    sync_units();
+
+    let mut remaining = CUBE_DIM_Y;
+    while remaining > 1 {
+        let half = (remaining + 1) / 2;
+        if UNIT_POS_Y + half < remaining {
+            fuse_accumulator_inplace::<In, R>(
+                inst,
+                &mut shared,
+                UNIT_POS,
+                UNIT_POS + half * CUBE_DIM_X,
+            );
+        }
+        remaining = half;
+        sync_units();
+    }
+
+    R::SharedAccumulator::read(&shared, UNIT_POS_X)
+}
+
+/// Reduce consecutive chunks of `chunk_length` elements along `axis_reduce` of `input`.
+///
+/// The `output` has the shape of `input` except along `axis_reduce`, where it has one element per
+/// chunk holding the reduction of that chunk. The last chunk may be shorter than the others.
+#[cube(launch_unchecked)]
+pub fn reduce_chunks_kernel<In: Numeric, Out: Numeric, R: ReduceFamily>(
+    input: &Tensor<Line<In>>,
+    output: &mut Tensor<Line<Out>>,
+    axis_reduce: u32,
+    chunk_length: u32,
+    #[comptime] params: ReduceParams,
+    #[comptime] config: R::Config,
+) {
+    let (input, mut output) = init_tensors::<TensorArgs, In, Out>(input, output);
+    let reduce_index = get_reduce_index(params);
+
+    if comptime![params.bound_checks]
+        && reduce_index >= get_reduce_count(output.len() * params.line_size_output, params)
+    {
+        terminate!();
+    }
+
+    let range = ReduceRange::new_chunk::<In, Out>(
+        reduce_index,
+        &input,
+        &mut output,
+        axis_reduce,
+        chunk_length,
+        params,
+    );
+
+    let inst = &R::Instruction::<In>::from_config(config);
+    let accumulator = reduce_accumulate::<In, R::Instruction<In>>(&input, inst, range, params);
+
+    if elected_writer(params) {
+        write_to_output::<In, Out, R::Instruction<In>>(
+            &mut output,
+            accumulator,
+            reduce_index,
+            chunk_length,
+            params,
+            inst,
+        );
+    }
+}
+
+#[cube(launch_unchecked)]
+pub fn reduce_multi_kernel<In: Numeric, Out: Numeric, R: MultiReduceFamily>(
+    input: &Tensor<Line<In>>,
+    outputs: &mut Sequence<Tensor<Line<Out>>>,
+    axis_reduce: u32,
+    #[comptime] params: ReduceParams,
+    #[comptime] config: R::Config,
+) {
+    // All outputs share the same layout, the first one drives the traversal.
+    let (input, mut output) = init_tensors::<TensorArgs, In, Out>(input, outputs.index_mut(0));
+    let reduce_index = get_reduce_index(params);
+
+    if comptime![params.bound_checks]
+        && reduce_index >= get_reduce_count(output.len() * params.line_size_output, params)
+    {
+        terminate!();
+    }
+
+    let range = ReduceRange::new::<In, Out>(reduce_index, &input, &mut output, axis_reduce, params);
+
+    let inst = &R::Instruction::<In>::from_config(config);
+    let accumulator = reduce_accumulate::<In, R::Instruction<In>>(&input, inst, range, params);
+
+    if elected_writer(params) {
+        write_to_outputs::<In, Out, R::Instruction<In>>(
+            outputs,
+            accumulator,
+            reduce_index,
+            input.shape(axis_reduce),
+            params,
+            inst,
+        );
+    }
+}
+
+/// Reduce every axis of `input` whose bit is set in `axes_mask` into `output`.
+///
+/// Each unit reduces a single output element, the units being assigned to the output elements in
+/// row-major order whatever the strides of `output`. The reduced axes are traversed as if they
+/// were flattened in row-major order, which is the coordinate given to the instruction.
+#[cube(launch_unchecked)]
+pub fn reduce_axes_kernel<In: Numeric, Out: Numeric, R: ReduceFamily>(
+    input: &Tensor<Line<In>>,
+    output: &mut Tensor<Line<Out>>,
+    axes_mask: u32,
+    #[comptime] config: R::Config,
+) {
+    let rank = input.rank();
+    let mut num_outputs = 1;
+    for axis in 0..rank {
+        num_outputs *= output.shape(axis);
+    }
+    if ABSOLUTE_POS >= num_outputs {
+        terminate!();
+    }
+
+    let mut remainder = ABSOLUTE_POS;
+    let mut offset = 0;
+    let mut offset_output = 0;
+    let mut reduce_size = 1;
+    for i in 0..rank {
+        let axis = rank - 1 - i;
+        if is_axis_reduced(axes_mask, axis) {
+            reduce_size *= input.shape(axis);
+        } else {
+            let shape = output.shape(axis);
+            let coordinate = remainder % shape;
+            remainder /= shape;
+            offset += coordinate * input.stride(axis);
+            offset_output += coordinate * output.stride(axis);
+        }
+    }
+
+    let inst = &R::Instruction::<In>::from_config(config);
+    let accumulator = reduce_axes_accumulate::<In, R::Instruction<In>>(
+        input,
+        inst,
+        offset,
+        reduce_size,
+        axes_mask,
+    );
+    let result = R::Instruction::<In>::merge_line::<Out>(inst, accumulator, reduce_size);
+    output[offset_output] = Line::cast_from(result);
+}
+
+#[cube]
+fn reduce_axes_accumulate<In: Numeric, R: ReduceInstruction<In>>(
+    input: &Tensor<Line<In>>,
+    inst: &R,
+    offset: u32,
+    reduce_size: u32,
+    axes_mask: u32,
+) -> R::AccumulatorItem {
+    let rank = input.rank();
+    let mut accumulator = R::null_accumulator(inst, 1);
+
+    for coordinate in 0..reduce_size {
+        // Split the flat coordinate over the reduced axes, the last axis being the fastest.
+        let mut remainder = coordinate;
+        let mut index = offset;
+        for i in 0..rank {
+            let axis = rank - 1 - i;
+            if is_axis_reduced(axes_mask, axis) {
+                let shape = input.shape(axis);
+                index += (remainder % shape) * input.stride(axis);
+                remainder /= shape;
+            }
+        }
+
+        let requirements = R::requirements(inst);
+        let coordinates = if comptime![requirements.coordinates] {
+            ReduceCoordinate::new_Required(Line::empty(1).fill(coordinate))
+        } else {
+            ReduceCoordinate::new_NotRequired()
+        };
+        reduce_inplace::<In, R>(inst, &mut accumulator, input[index], coordinates, false);
+    }
+
+    accumulator
+}
+
+/// Reduce the slice along `axis_reduce` of `input` mapped with `Pre`, then write each element of
+/// the slice mapped with `Pre` and `Post` into `output`, which has the same shape as `input`.
+///
+/// Each agent reduces one slice and keeps its reduction, broadcasted with planes or shared memory,
+/// to map the slice a second time without going through global memory.
+#[cube(launch_unchecked)]
+pub fn map_reduce_map_kernel<
+    F: Float,
+    Pre: ReduceMap,
+    R: ReduceFamily,
+    Post: ReduceBroadcastMap,
+>(
+    input: &Tensor<Line<F>>,
+    output: &mut Tensor<Line<F>>,
+    axis_reduce: u32,
+    #[comptime] params: ReduceParams,
+    #[comptime] config: R::Config,
+) {
+    let reduce_index = get_reduce_index(params);
+
+    // Split the index of the slice over the other axes, the last axis being the fastest.
+    let rank = input.rank();
+    let mut remainder = reduce_index;
+    let mut reduce_count = 1;
+    let mut input_offset = 0;
+    let mut output_offset = 0;
+    for i in 0..rank {
+        let axis = rank - 1 - i;
+        if axis != axis_reduce {
+            let shape = input.shape(axis);
+            let coordinate = remainder % shape;
+            remainder /= shape;
+            reduce_count *= shape;
+            input_offset += coordinate * input.stride(axis);
+            output_offset += coordinate * output.stride(axis);
+        }
+    }
+
+    if comptime![params.bound_checks] && reduce_index >= reduce_count {
+        terminate!();
+    }
+
+    let length = input.shape(axis_reduce);
+    let input_stride = input.stride(axis_reduce);
+    let output_stride = output.stride(axis_reduce);
+
+    let inst = &R::Instruction::<F>::from_config(config);
+    let accumulator = map_reduce_accumulate::<F, Pre, R::Instruction<F>>(
+        input,
+        inst,
+        input_offset,
+        input_stride,
+        length,
+        params,
+    );
+    let reduced = R::Instruction::<F>::merge_line::<F>(inst, accumulator, length);
+
+    for coordinate in range_stepped(slice_unit(params), length, slice_unit_count(params)) {
+        let value = Pre::apply::<F>(input[input_offset + coordinate * input_stride]);
+        output[output_offset + coordinate * output_stride] = Post::apply::<F>(value, reduced);
+    }
+}
+
+/// Reduce the `length` elements starting at `offset` mapped with `Pre`.
+///
+/// The returned accumulator is the reduction of the whole slice for every unit of the agent.
+#[cube]
+fn map_reduce_accumulate<F: Float, Pre: ReduceMap, R: ReduceInstruction<F>>(
+    input: &Tensor<Line<F>>,
+    inst: &R,
+    offset: u32,
+    stride: u32,
+    length: u32,
+    #[comptime] params: ReduceParams,
+) -> R::AccumulatorItem {
+    let unit = slice_unit(params);
+    let unit_count = slice_unit_count(params);
+    let requirements = R::requirements(inst);
+    let mut accumulator = R::null_accumulator(inst, 1);
+
+    for first_coordinate in range_stepped(0, length, unit_count) {
+        let coordinate = first_coordinate + unit;
+        let in_bounds = coordinate < length;
+        let index = offset + coordinate * stride * u32::cast_from(in_bounds);
+        let item = select(
+            in_bounds,
+            Pre::apply::<F>(input[index]),
+            R::null_input(inst, 1),
+        );
+
+        let coordinates = if comptime![requirements.coordinates] {
+            let coordinate = select(in_bounds, coordinate, u32::MAX);
+            ReduceCoordinate::new_Required(Line::empty(1).fill(coordinate))
+        } else {
+            ReduceCoordinate::new_NotRequired()
+        };
+        reduce_inplace::<F, R>(inst, &mut accumulator, item, coordinates, params.use_planes);
+    }
+
+    combine_agent::<F, R>(inst, accumulator, params)
+}
+
+/// Reduce the elements of each slice along `axis_reduce` of `input` whose `mask` isn't zero.
+///
+/// The `mask` has the shape of `input`, and the reduction of a slice is merged with the number of
+/// selected elements instead of the length of the slice, so that [Mean] only averages the selected
+/// elements.
+#[cube(launch_unchecked)]
+pub fn reduce_masked_kernel<In: Numeric, M: Numeric, Out: Numeric, R: ReduceFamily>(
+    input: &Tensor<Line<In>>,
+    mask: &Tensor<Line<M>>,
+    output: &mut Tensor<Line<Out>>,
+    axis_reduce: u32,
+    #[comptime] params: ReduceParams,
+    #[comptime] config: R::Config,
+) {
+    let reduce_index = get_reduce_index(params);
+
+    // Split the index of the slice over the other axes, the last axis being the fastest.
+    let rank = input.rank();
+    let mut remainder = reduce_index;
+    let mut reduce_count = 1;
+    let mut input_offset = 0;
+    let mut mask_offset = 0;
+    let mut output_offset = 0;
+    for i in 0..rank {
+        let axis = rank - 1 - i;
+        if axis != axis_reduce {
+            let shape = input.shape(axis);
+            let coordinate = remainder % shape;
+            remainder /= shape;
+            reduce_count *= shape;
+            input_offset += coordinate * input.stride(axis);
+            mask_offset += coordinate * mask.stride(axis);
+            output_offset += coordinate * output.stride(axis);
+        }
+    }
+
+    if comptime![params.bound_checks] && reduce_index >= reduce_count {
+        terminate!();
+    }
+
+    let inst = &R::Instruction::<In>::from_config(config);
+    let accumulator = masked_accumulate::<In, M, R::Instruction<In>>(
+        input,
+        mask,
+        inst,
+        input_offset,
+        mask_offset,
+        axis_reduce,
+        params,
+    );
+    let count = masked_count::<M, Sum>(&Sum {}, mask, mask_offset, axis_reduce, params);
+
+    if elected_writer(params) {
+        let result = R::Instruction::<In>::merge_line::<Out>(inst, accumulator, count);
+        output[output_offset] = Line::cast_from(result);
+    }
+}
+
+/// Reduce the elements of the slice along `axis_reduce` whose mask isn't zero, the others being
+/// replaced by the null input of the instruction.
+///
+/// The returned accumulator is the reduction of the whole slice for every unit of the agent.
+#[cube]
+fn masked_accumulate<In: Numeric, M: Numeric, R: ReduceInstruction<In>>(
+    input: &Tensor<Line<In>>,
+    mask: &Tensor<Line<M>>,
+    inst: &R,
+    input_offset: u32,
+    mask_offset: u32,
+    axis_reduce: u32,
+    #[comptime] params: ReduceParams,
+) -> R::AccumulatorItem {
+    let unit = slice_unit(params);
+    let unit_count = slice_unit_count(params);
+    let length = input.shape(axis_reduce);
+    let input_stride = input.stride(axis_reduce);
+    let mask_stride = mask.stride(axis_reduce);
+    let requirements = R::requirements(inst);
+    let mut accumulator = R::null_accumulator(inst, 1);
+
+    for first_coordinate in range_stepped(0, length, unit_count) {
+        let coordinate = first_coordinate + unit;
+        let in_bounds = coordinate < length;
+        let clamped = coordinate * u32::cast_from(in_bounds);
+        let selected = in_bounds && mask[mask_offset + clamped * mask_stride][0] != M::from_int(0);
+        let item = select(
+            selected,
+            input[input_offset + clamped * input_stride],
+            R::null_input(inst, 1),
+        );
+
+        let coordinates = if comptime![requirements.coordinates] {
+            let coordinate = select(selected, coordinate, u32::MAX);
+            ReduceCoordinate::new_Required(Line::empty(1).fill(coordinate))
+        } else {
+            ReduceCoordinate::new_NotRequired()
+        };
+        reduce_inplace::<In, R>(inst, &mut accumulator, item, coordinates, params.use_planes);
+    }
+
+    combine_agent::<In, R>(inst, accumulator, params)
+}
+
+/// Count the elements of the slice along `axis_reduce` whose mask isn't zero with the counting
+/// instruction `R`.
+#[cube]
+fn masked_count<M: Numeric, R: ReduceInstruction<u32>>(
+    inst: &R,
+    mask: &Tensor<Line<M>>,
+    mask_offset: u32,
+    axis_reduce: u32,
+    #[comptime] params: ReduceParams,
+) -> u32 {
+    let unit = slice_unit(params);
+    let unit_count = slice_unit_count(params);
+    let length = mask.shape(axis_reduce);
+    let stride = mask.stride(axis_reduce);
+    let mut accumulator = R::null_accumulator(inst, 1);
+
+    for first_coordinate in range_stepped(0, length, unit_count) {
+        let coordinate = first_coordinate + unit;
+        let in_bounds = coordinate < length;
+        let clamped = coordinate * u32::cast_from(in_bounds);
+        let selected = in_bounds && mask[mask_offset + clamped * stride][0] != M::from_int(0);
+        let item = Line::empty(1).fill(u32::cast_from(selected));
+
+        reduce_inplace::<u32, R>(
+            inst,
+            &mut accumulator,
+            item,
+            ReduceCoordinate::new_NotRequired(),
+            params.use_planes,
+        );
+    }
+
+    let accumulator = combine_agent::<u32, R>(inst, accumulator, params);
+    R::merge_line::<u32>(inst, accumulator, length)
+}
+
+// Combine the accumulators of all the units of the agent through shared memory when it is used,
+// those of a plane being already combined by the instruction.
+#[cube]
+fn combine_agent<In: Numeric, R: ReduceInstruction<In>>(
+    inst: &R,
+    accumulator: R::AccumulatorItem,
+    #[comptime] params: ReduceParams,
+) -> R::AccumulatorItem {
+    let requirements = R::requirements(inst);
+    match comptime!(params.shared) {
+        Some(accumulator_size) => {
+            let accumulator_index = if params.use_planes {
+                UNIT_POS_Y
+            } else {
+                UNIT_POS
+            };
+            let mut shared =
+                R::SharedAccumulator::allocate(accumulator_size, 1, requirements.coordinates);
+            R::SharedAccumulator::write(&mut shared, accumulator_index, accumulator);
+            sync_units();
+            reduce_tree::<In, R>(inst, &mut shared, accumulator_size)
+        }
+        None => accumulator,
+    }
+}
+
+// The position of the unit within the agent reducing a slice.
+#[cube]
+fn slice_unit(#[comptime] params: ReduceParams) -> u32 {
+    if params.shared.is_some() {
+        UNIT_POS
+    } else if params.use_planes {
+        UNIT_POS_X
+    } else {
+        0_u32.runtime()
+    }
+}
+
+// The number of units of the agent reducing a slice.
+#[cube]
+fn slice_unit_count(#[comptime] params: ReduceParams) -> u32 {
+    if params.shared.is_some() {
+        CUBE_DIM
+    } else if params.use_planes {
+        CUBE_DIM_X
+    } else {
+        1_u32.runtime()
+    }
+}
+
+#[cube]
+fn is_axis_reduced(axes_mask: u32, axis: u32) -> bool {
+    (axes_mask >> axis) & 1 == 1
+}
+
+#[cube]
+fn reduce_accumulate<In: Numeric, R: ReduceInstruction<In>>(
+    input: &VirtualTensor<In>,
+    inst: &R,
+    range: ReduceRange,
+    #[comptime] params: ReduceParams,
+) -> R::AccumulatorItem {
+    match comptime!((params.shared, params.use_planes)) {
         (Some(accumulator_size), use_planes) => {
-            let mut accumulator = reduce_slice_shared::<In, VirtualTensor<In>, R::Instruction<In>>(
-                &input,
+            let mut accumulator = reduce_slice_shared::<In, VirtualTensor<In>, R>(
+                input,
                 inst,
                 range,
                 accumulator_size,
@@ -96,34 +867,23 @@ pub fn reduce_kernel<In: Numeric, Out: Numeric, R: ReduceFamily, RA: ReduceArgs>
                 params.bound_checks_inner,
             );
             sync_units();
-            reduce_tree::<In, R::Instruction<In>>(inst, &mut accumulator, accumulator_size)
+            reduce_tree::<In, R>(inst, &mut accumulator, accumulator_size)
         }
-        (None, true) => reduce_slice_plane::<In, VirtualTensor<In>, R::Instruction<In>>(
-            &input,
+        (None, true) => reduce_slice_plane::<In, VirtualTensor<In>, R>(
+            input,
             inst,
             range,
             params.line_size_input,
             params.line_mode,
             params.bound_checks_inner,
         ),
-        (None, false) => reduce_slice::<In, VirtualTensor<In>, R::Instruction<In>>(
-            &input,
+        (None, false) => reduce_slice::<In, VirtualTensor<In>, R>(
+            input,
             range,
             inst,
             params.line_size_input,
             params.line_mode,
         ),
-    };
-
-    if elected_writer(params) {
-        write_to_output::<In, Out, R::Instruction<In>>(
-            &mut output,
-            accumulator,
-            reduce_index,
-            input.shape(axis_reduce),
-            params,
-            inst,
-        );
     }
 }
 
@@ -195,3 +955,53 @@ fn write_to_output<In: Numeric, Out: Numeric, R: ReduceInstruction<In>>(
         }
     }
 }
+
+#[cube]
+fn write_to_outputs<In: Numeric, Out: Numeric, R: MultiReduceInstruction<In>>(
+    outputs: &mut Sequence<Tensor<Line<Out>>>,
+    accumulator: R::AccumulatorItem,
+    reduce_index: u32,
+    shape_axis_reduce: u32,
+    #[comptime] settings: ReduceParams,
+    inst: &R,
+) {
+    match comptime!(settings.line_mode) {
+        LineMode::Parallel => {
+            let results = R::merge_line_multi::<Out>(inst, accumulator, shape_axis_reduce);
+
+            #[unroll]
+            for i in 0..results.len() {
+                let output = outputs.index_mut(i);
+                output[reduce_index] = Line::cast_from(*results.index(i));
+            }
+        }
+        LineMode::Perpendicular => {
+            let results =
+                R::to_output_perpendicular_multi::<Out>(inst, accumulator, shape_axis_reduce);
+
+            #[unroll]
+            for i in 0..results.len() {
+                let out = *results.index(i);
+                let output = outputs.index_mut(i);
+
+                if comptime![settings.line_size_output == settings.line_size_input] {
+                    output[reduce_index] = out;
+                } else {
+                    let num_iters = comptime![settings.line_size_input / settings.line_size_output];
+
+                    #[unroll]
+                    for k in 0..num_iters {
+                        let mut tmp = Line::empty(settings.line_size_output);
+
+                        #[unroll]
+                        for j in 0..settings.line_size_output {
+                            tmp[j] = out[k * settings.line_size_output + j];
+                        }
+
+                        output[num_iters * reduce_index + k] = tmp;
+                    }
+                }
+            }
+        }
+    }
+}
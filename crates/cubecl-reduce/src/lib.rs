@@ -18,19 +18,28 @@ pub mod tune_key;
 mod config;
 mod error;
 mod launch;
+mod scan;
 mod shared_sum;
 mod strategy;
 
 pub use config::*;
 pub use error::*;
+pub use instructions::MultiReduceFamily;
+pub use instructions::ReduceBroadcastMap;
 pub use instructions::ReduceFamily;
 pub use instructions::ReduceInstruction;
+pub use instructions::ReduceMap;
+pub use instructions::ReducePair;
+pub use scan::*;
 pub use shared_sum::*;
 pub use strategy::*;
 
 use launch::*;
 
-pub use launch::{ReduceParams, reduce_kernel};
+pub use launch::{
+    ReduceParams, map_reduce_map_kernel, reduce_axes_kernel, reduce_kernel, reduce_masked_kernel,
+    reduce_multi_kernel, reduce_split_strided_kernel,
+};
 
 #[cfg(feature = "export_tests")]
 pub mod test;
@@ -40,12 +49,18 @@ use cubecl_core::prelude::*;
 /// Reduce the given `axis` of the `input` tensor using the instruction `Inst` and write the result into `output`.
 ///
 /// An optional [`ReduceStrategy`] can be provided to force the reduction to use a specific algorithm. If omitted, a best effort
-/// is done to try and pick the best strategy supported for the provided `client`.
+/// is done to try and pick the best strategy supported for the provided `client`, depending on whether the `axis` is
+/// contiguous as described in [`ReduceStrategy::for_axis`].
 ///
 /// Return an error if `strategy` is `Some(strategy)` and the specified strategy is not supported by the `client`.
 /// Also returns an error if the `axis` is larger than the `input` rank or if the shape of `output` is invalid.
-/// The shape of `output` must be the same as input except with a value of 1 for the given `axis`.
+/// The shape of `output` must be the same as input except with a value of 1 for the given `axis`,
+/// or without the `axis` at all, as given by [`reduce_output_shape`].
 ///
+/// When the `axis` is longer than what a single cube can reduce and the instruction
+/// [combines partials](ReduceFamily::COMBINES_PARTIALS), the slices are split into chunks reduced
+/// by different cubes into a scratch tensor, which is then reduced the same way until a single
+/// element is left per slice.
 ///
 /// # Example
 ///
@@ -99,18 +114,38 @@ pub fn reduce<R: Runtime, In: Numeric, Out: Numeric, Inst: ReduceFamily>(
     inst_config: Inst::Config,
 ) -> Result<(), ReduceError> {
     validate_axis(input.shape.len(), axis)?;
-    valid_output_shape(input.shape, output.shape, axis)?;
+    let keep_dims = output.shape.len() == input.shape.len();
+    valid_output_shape(input.shape, output.shape, &[axis], keep_dims)?;
+    if !keep_dims {
+        return reduce_keeping_axis::<R, In, Out, Inst>(
+            client,
+            input,
+            output,
+            axis,
+            strategy,
+            inst_config,
+        );
+    }
     let strategy = strategy
         .map(|s| s.validate::<R>(client))
-        .unwrap_or(Ok(ReduceStrategy::new::<R>(client, true)))?;
+        .unwrap_or(Ok(ReduceStrategy::for_axis::<R>(client, &input, axis)))?;
     let config = ReduceConfig::generate::<R, In>(client, &input, &output, axis, &strategy);
 
-    if let CubeCount::Static(x, y, z) = config.cube_count {
-        let (max_x, max_y, max_z) = R::max_cube_count();
-        if x > max_x || y > max_y || z > max_z {
-            return Err(ReduceError::CubeCountTooLarge);
-        }
+    let chunk_length = config
+        .chunk_length(input.shape[axis] as u32)
+        .filter(|_| Inst::COMBINES_PARTIALS);
+    if let Some(chunk_length) = chunk_length {
+        return reduce_chunked::<R, In, Out, Inst>(
+            client,
+            input,
+            output,
+            axis,
+            chunk_length,
+            strategy,
+            inst_config,
+        );
     }
+    validate_cube_count::<R>(&config.cube_count)?;
 
     launch_reduce::<R, In, Out, Inst>(
         client,
@@ -124,6 +159,343 @@ pub fn reduce<R: Runtime, In: Numeric, Out: Numeric, Inst: ReduceFamily>(
     Ok(())
 }
 
+/// Reduce the given `axis` of the `input` tensor into multiple `outputs` in a single pass, using
+/// the multi-output instruction `Inst`.
+///
+/// This is useful to compute several statistics at once, such as the sum and the sum of squares
+/// of a variance, without reading the input multiple times. Each output must have the shape
+/// required by [`reduce`], all outputs must have the same strides and there must be exactly
+/// [`MultiReduceFamily::NUM_OUTPUTS`] of them, in the order the instruction writes them.
+///
+/// Return the same errors as [`reduce`], and an error if the outputs don't match the instruction.
+pub fn reduce_multi<R: Runtime, In: Numeric, Out: Numeric, Inst: MultiReduceFamily>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: TensorHandleRef<R>,
+    outputs: &[TensorHandleRef<R>],
+    axis: usize,
+    strategy: Option<ReduceStrategy>,
+    inst_config: Inst::Config,
+) -> Result<(), ReduceError> {
+    validate_axis(input.shape.len(), axis)?;
+    if outputs.len() != Inst::NUM_OUTPUTS {
+        return Err(ReduceError::MismatchOutputCount {
+            expected: Inst::NUM_OUTPUTS,
+            got: outputs.len(),
+        });
+    }
+    for output in outputs {
+        valid_output_shape(input.shape, output.shape, &[axis], true)?;
+        if output.strides != outputs[0].strides {
+            return Err(ReduceError::MismatchOutputStrides);
+        }
+    }
+    let strategy = strategy
+        .map(|s| s.validate::<R>(client))
+        .unwrap_or(Ok(ReduceStrategy::for_axis::<R>(client, &input, axis)))?;
+    // Only the kernel of `reduce` splits strided slices.
+    let strategy = ReduceStrategy {
+        split_strided: false,
+        ..strategy
+    };
+    let config = ReduceConfig::generate::<R, In>(client, &input, &outputs[0], axis, &strategy);
+    validate_cube_count::<R>(&config.cube_count)?;
+
+    launch_reduce_multi::<R, In, Out, Inst>(
+        client,
+        input,
+        outputs,
+        axis as u32,
+        config,
+        strategy,
+        inst_config,
+    );
+    Ok(())
+}
+
+/// Reduce all the given `axes` of the `input` tensor at once using the instruction `Inst` and write
+/// the result into `output`.
+///
+/// This is equivalent to chaining one [`reduce`] per axis, but runs as a single kernel without
+/// intermediate tensors. The axes don't need to be adjacent and the input can have arbitrary strides.
+/// Instructions requiring coordinates, such as [`ArgMax`](instructions::ArgMax), receive the index
+/// of the element within the reduced axes flattened in row-major order.
+///
+/// Return an error if an axis isn't smaller than the `input` rank or than 32, the axes being
+/// passed to the kernel as a bit mask, or if the shape of `output` is invalid.
+/// The shape of `output` must be the same as input except with a value of 1 for all the given `axes`.
+///
+/// # Example
+///
+/// ```ignore
+/// // Sum over the axes 1 and 3 of a `2 x 3 x 4 x 5` tensor into a `2 x 1 x 4 x 1` tensor.
+/// let result = reduce_axes::<R, f32, f32, Sum>(&client, input, output, &[1, 3], ());
+/// ```
+pub fn reduce_axes<R: Runtime, In: Numeric, Out: Numeric, Inst: ReduceFamily>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: TensorHandleRef<R>,
+    output: TensorHandleRef<R>,
+    axes: &[usize],
+    inst_config: Inst::Config,
+) -> Result<(), ReduceError> {
+    let rank = input.shape.len();
+    let mut axes_mask = 0u32;
+    for &axis in axes {
+        if axis >= rank {
+            return Err(ReduceError::InvalidAxis { axis, rank });
+        }
+        if axis >= u32::BITS as usize {
+            return Err(ReduceError::AxisTooLarge {
+                axis,
+                max_axis: u32::BITS as usize - 1,
+            });
+        }
+        axes_mask |= 1 << axis;
+    }
+    valid_output_shape(input.shape, output.shape, axes, true)?;
+
+    let cube_dim = CubeDim::default();
+    let cube_count =
+        cubecl_core::calculate_cube_count_elemwise(output.shape.iter().product(), cube_dim);
+    validate_cube_count::<R>(&cube_count)?;
+
+    launch_reduce_axes::<R, In, Out, Inst>(
+        client,
+        input,
+        output,
+        axes_mask,
+        cube_count,
+        cube_dim,
+        inst_config,
+    );
+    Ok(())
+}
+
+/// Reduce each slice along the given `axis` of the `input` tensor and map every element of the
+/// slice with its reduction into `output`, in a single kernel.
+///
+/// The elements are first mapped with `Pre`, then reduced with the instruction `Inst`, and finally
+/// each mapped element is written with `Post` applied to it and the reduction of its slice. This
+/// saves the read and write of the reduction compared to a [`reduce`] followed by a map, such as
+/// dividing by the sum of a slice to normalize it. The reduction is kept by the agent reducing the
+/// slice, so a strategy using planes or shared memory is preferred for long slices.
+///
+/// Return the same errors as [`reduce`], except that `output` must have the same shape as `input`.
+///
+/// # Example
+///
+/// ```ignore
+/// use cubecl_reduce::instructions::{DivideBy, ExpMap, Sum};
+///
+/// // Compute `exp(x) / sum(exp(x))` for each row of a matrix.
+/// let result =
+///     map_reduce_map::<R, f32, ExpMap, Sum, DivideBy>(&client, input, output, 1, None, ());
+/// ```
+pub fn map_reduce_map<
+    R: Runtime,
+    F: Float,
+    Pre: ReduceMap,
+    Inst: ReduceFamily,
+    Post: ReduceBroadcastMap,
+>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: TensorHandleRef<R>,
+    output: TensorHandleRef<R>,
+    axis: usize,
+    strategy: Option<ReduceStrategy>,
+    inst_config: Inst::Config,
+) -> Result<(), ReduceError> {
+    validate_axis(input.shape.len(), axis)?;
+    valid_output_shape(input.shape, output.shape, &[], true)?;
+    let strategy = strategy
+        .map(|s| s.validate::<R>(client))
+        .unwrap_or(Ok(ReduceStrategy::new::<R>(client, false)))?;
+    let reduce_count = (input.size() / input.shape[axis]) as u32;
+    let config = ReduceConfig::generate_elementwise::<R>(client, reduce_count, &strategy);
+    validate_cube_count::<R>(&config.cube_count)?;
+
+    launch_map_reduce_map::<R, F, Pre, Inst, Post>(
+        client,
+        input,
+        output,
+        axis as u32,
+        config,
+        strategy,
+        inst_config,
+    );
+    Ok(())
+}
+
+/// Reduce the given `axis` of the `input` tensor using the instruction `Inst`, only folding the
+/// elements where `mask` isn't zero, and write the result into `output`.
+///
+/// The `mask` must have the same shape as `input`, and `output` the shape of `input` with a value
+/// of 1 for the given `axis`. The reduction of a slice is merged with the number of selected
+/// elements rather than the length of the slice, so [`Mean`](instructions::Mean) averages the
+/// selected elements only.
+///
+/// A slice without any selected element reduces to the null input of the instruction, which is
+/// zero for [`Sum`](instructions::Sum). Its [`Mean`](instructions::Mean) divides that zero by a
+/// count of zero, which is NaN for float outputs.
+///
+/// Return the same errors as [`reduce`], and an error if the shape of `mask` isn't the shape of
+/// `input`.
+///
+/// # Example
+///
+/// ```ignore
+/// use cubecl_reduce::instructions::Mean;
+///
+/// // Average the elements of each row of a matrix where the `u32` mask is not zero.
+/// let result = reduce_masked::<R, f32, u32, f32, Mean>(&client, input, mask, output, 1, None, ());
+/// ```
+pub fn reduce_masked<R: Runtime, In: Numeric, M: Numeric, Out: Numeric, Inst: ReduceFamily>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: TensorHandleRef<R>,
+    mask: TensorHandleRef<R>,
+    output: TensorHandleRef<R>,
+    axis: usize,
+    strategy: Option<ReduceStrategy>,
+    inst_config: Inst::Config,
+) -> Result<(), ReduceError> {
+    validate_axis(input.shape.len(), axis)?;
+    if mask.shape != input.shape {
+        return Err(ReduceError::MismatchMaskShape {
+            input_shape: input.shape.to_vec(),
+            mask_shape: mask.shape.to_vec(),
+        });
+    }
+    valid_output_shape(input.shape, output.shape, &[axis], true)?;
+    let strategy = strategy
+        .map(|s| s.validate::<R>(client))
+        .unwrap_or(Ok(ReduceStrategy::new::<R>(client, false)))?;
+    let reduce_count = (input.size() / input.shape[axis]) as u32;
+    let config = ReduceConfig::generate_elementwise::<R>(client, reduce_count, &strategy);
+    validate_cube_count::<R>(&config.cube_count)?;
+
+    launch_reduce_masked::<R, In, M, Out, Inst>(
+        client,
+        input,
+        mask,
+        output,
+        axis as u32,
+        config,
+        strategy,
+        inst_config,
+    );
+    Ok(())
+}
+
+/// The shape of the output of a [`reduce`] of the given `axis` of a tensor of shape `input_shape`.
+///
+/// With `keep_dims`, the reduced axis is kept with a size of 1, like NumPy's `keepdims=True`, so
+/// the output broadcasts against the input. Otherwise the axis is removed and the output has one
+/// dimension less.
+pub fn reduce_output_shape(input_shape: &[usize], axis: usize, keep_dims: bool) -> Vec<usize> {
+    let mut shape = input_shape.to_vec();
+    match keep_dims {
+        true => shape[axis] = 1,
+        false => {
+            shape.remove(axis);
+        }
+    }
+    shape
+}
+
+// Reduce into an `output` without the reduced `axis`, by viewing it with the axis kept with a size
+// of 1 and the stride it would have in the same layout.
+fn reduce_keeping_axis<R: Runtime, In: Numeric, Out: Numeric, Inst: ReduceFamily>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: TensorHandleRef<R>,
+    output: TensorHandleRef<R>,
+    axis: usize,
+    strategy: Option<ReduceStrategy>,
+    inst_config: Inst::Config,
+) -> Result<(), ReduceError> {
+    let stride = match axis < output.shape.len() {
+        true => output.strides[axis] * output.shape[axis],
+        false => 1,
+    };
+    let mut shape = output.shape.to_vec();
+    let mut strides = output.strides.to_vec();
+    shape.insert(axis, 1);
+    strides.insert(axis, stride);
+
+    let output = unsafe {
+        TensorHandleRef::<R>::from_raw_parts(output.handle, &strides, &shape, output.elem_size)
+    };
+    reduce::<R, In, Out, Inst>(client, input, output, axis, strategy, inst_config)
+}
+
+// Reduce each chunk of `chunk_length` elements of the slices into a scratch tensor, then reduce
+// the scratch tensor into `output`, splitting it again if it is still too long.
+fn reduce_chunked<R: Runtime, In: Numeric, Out: Numeric, Inst: ReduceFamily>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: TensorHandleRef<R>,
+    output: TensorHandleRef<R>,
+    axis: usize,
+    chunk_length: u32,
+    strategy: ReduceStrategy,
+    inst_config: Inst::Config,
+) -> Result<(), ReduceError> {
+    let mut scratch_shape = input.shape.to_vec();
+    scratch_shape[axis] = input.shape[axis].div_ceil(chunk_length as usize);
+    let mut scratch_strides = vec![1; scratch_shape.len()];
+    for i in (0..scratch_shape.len().saturating_sub(1)).rev() {
+        scratch_strides[i] = scratch_strides[i + 1] * scratch_shape[i + 1];
+    }
+
+    let elem_size = Out::as_elem_native_unchecked().size();
+    let scratch_handle = client.empty(scratch_shape.iter().product::<usize>() * elem_size);
+    let scratch = unsafe {
+        TensorHandleRef::<R>::from_raw_parts(
+            &scratch_handle,
+            &scratch_strides,
+            &scratch_shape,
+            elem_size,
+        )
+    };
+
+    // The chunks are reduced without splitting strided slices, the chunks already split them.
+    let chunk_strategy = ReduceStrategy {
+        split_strided: false,
+        ..strategy
+    };
+    let config = ReduceConfig::generate::<R, In>(client, &input, &scratch, axis, &chunk_strategy);
+    validate_cube_count::<R>(&config.cube_count)?;
+
+    launch_reduce_chunks::<R, In, Out, Inst>(
+        client,
+        input,
+        scratch,
+        axis as u32,
+        chunk_length,
+        config,
+        chunk_strategy,
+        inst_config,
+    );
+
+    let scratch = unsafe {
+        TensorHandleRef::<R>::from_raw_parts(
+            &scratch_handle,
+            &scratch_strides,
+            &scratch_shape,
+            elem_size,
+        )
+    };
+    reduce::<R, Out, Out, Inst>(client, scratch, output, axis, Some(strategy), inst_config)
+}
+
+// Check that the cube count is supported by the runtime.
+fn validate_cube_count<R: Runtime>(cube_count: &CubeCount) -> Result<(), ReduceError> {
+    if let CubeCount::Static(x, y, z) = cube_count {
+        let (max_x, max_y, max_z) = R::max_cube_count();
+        if *x > max_x || *y > max_y || *z > max_z {
+            return Err(ReduceError::CubeCountTooLarge);
+        }
+    }
+    Ok(())
+}
+
 // Check that the given axis is less than the rank of the input.
 fn validate_axis(rank: usize, axis: usize) -> Result<(), ReduceError> {
     if axis > rank {
@@ -132,14 +504,26 @@ fn validate_axis(rank: usize, axis: usize) -> Result<(), ReduceError> {
     Ok(())
 }
 
-// Check that the output shape match the input shape with the given axis set to 1.
+// Check that the output shape match the input shape with the given axes set to 1, or removed
+// without `keep_dims`.
 fn valid_output_shape(
     input_shape: &[usize],
     output_shape: &[usize],
-    axis: usize,
+    axes: &[usize],
+    keep_dims: bool,
 ) -> Result<(), ReduceError> {
     let mut expected_shape = input_shape.to_vec();
-    expected_shape[axis] = 1;
+    for &axis in axes {
+        expected_shape[axis] = 1;
+    }
+    if !keep_dims {
+        expected_shape = expected_shape
+            .into_iter()
+            .enumerate()
+            .filter(|(axis, _)| !axes.contains(axis))
+            .map(|(_, shape)| shape)
+            .collect();
+    }
     if output_shape != expected_shape {
         return Err(ReduceError::MismatchShape {
             expected_shape,
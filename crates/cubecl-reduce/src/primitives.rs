@@ -37,6 +37,58 @@ impl ReduceRange {
         }
     }
 
+    /// Create a range over a single chunk of `chunk_length` elements of the slice along
+    /// `axis_reduce`. The chunk is given by the coordinate of the agent along `axis_reduce` in
+    /// `output`, which has one element per chunk along that axis.
+    pub(crate) fn new_chunk<In: Numeric, Out: Numeric>(
+        reduce_index: u32,
+        input: &VirtualTensor<In>,
+        output: &mut VirtualTensor<Out, ReadWrite>,
+        axis_reduce: u32,
+        chunk_length: u32,
+        #[comptime] params: ReduceParams,
+    ) -> ReduceRange {
+        let first_output = match comptime!(params.line_mode) {
+            LineMode::Parallel => reduce_index,
+            LineMode::Perpendicular => reduce_index * params.line_size_input,
+        };
+
+        let mut index_start = 0;
+        for axis in 0..input.rank() {
+            let coordinate = output.coordinate(first_output, axis);
+            let coordinate = select(axis == axis_reduce, coordinate * chunk_length, coordinate);
+            index_start += coordinate * input.stride(axis);
+        }
+        index_start /= params.line_size_input;
+
+        let chunk_start = output.coordinate(first_output, axis_reduce) * chunk_length;
+        let coordinate_end = Min::min(chunk_length, input.shape(axis_reduce) - chunk_start);
+
+        let agent_size = if params.shared.is_some() {
+            CUBE_DIM
+        } else if params.use_planes {
+            CUBE_DIM_X
+        } else {
+            1_u32.runtime()
+        };
+        let index_step = match comptime!(params.line_mode) {
+            LineMode::Parallel => 1_u32.runtime(),
+            LineMode::Perpendicular => input.stride(axis_reduce) / params.line_size_input,
+        };
+        let coordinate_step = match comptime!(params.line_mode) {
+            LineMode::Parallel => agent_size * params.line_size_input,
+            LineMode::Perpendicular => agent_size,
+        };
+
+        ReduceRange {
+            index_start,
+            index_step,
+            coordinate_start: 0,
+            coordinate_end,
+            coordinate_step,
+        }
+    }
+
     fn new_parallel<In: Numeric, Out: Numeric>(
         reduce_index: u32,
         input: &VirtualTensor<In>,
@@ -192,11 +244,14 @@ pub fn reduce_slice_plane<N: Numeric, I: List<Line<N>>, R: ReduceInstruction<N>>
 
         let requirements = R::requirements(inst);
         let coordinates = if comptime![requirements.coordinates] {
-            ReduceCoordinate::new_Required(fill_coordinate_line(
-                unit_coordinate,
-                line_size,
-                line_mode,
-            ))
+            let coordinate = fill_coordinate_line(unit_coordinate, line_size, line_mode);
+            let coordinate = select(
+                unit_coordinate < range.coordinate_end,
+                coordinate,
+                Line::empty(line_size).fill(u32::MAX),
+            );
+
+            ReduceCoordinate::new_Required(coordinate)
         } else {
             ReduceCoordinate::new_NotRequired()
         };
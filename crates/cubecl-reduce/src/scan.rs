@@ -0,0 +1,285 @@
+use cubecl_core::prelude::*;
+use cubecl_core::{self as cubecl};
+
+use crate::instructions::{Max, Min, Prod, Sum};
+use crate::{ReduceError, valid_output_shape, validate_axis, validate_cube_count};
+
+/// The maximum number of elements of a slice scanned by a single cube.
+const BLOCK_SIZE: usize = 256;
+
+/// Whether each element of a [scan] includes itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScanKind {
+    /// Each element is the combination of all the elements up to and including itself, like a
+    /// cumulative sum.
+    Inclusive,
+    /// Each element is the combination of all the elements before itself, the first element of
+    /// each slice being the [identity](ScanOp::identity).
+    Exclusive,
+}
+
+/// An associative binary operator to [scan] a tensor with.
+///
+/// The operator doesn't need to be commutative, the elements are always combined in the order of
+/// the axis. Implemented for [`Sum`], [`Prod`], [`Max`] and [`Min`].
+#[cube]
+pub trait ScanOp<N: Numeric>: Send + Sync + 'static {
+    /// The value such that `combine(identity(), x) == x` for any `x`.
+    fn identity() -> N;
+
+    /// Combine the accumulation of the elements before `rhs` with `rhs`.
+    fn combine(lhs: N, rhs: N) -> N;
+}
+
+#[cube]
+impl<N: Numeric> ScanOp<N> for Sum {
+    fn identity() -> N {
+        N::from_int(0)
+    }
+
+    fn combine(lhs: N, rhs: N) -> N {
+        lhs + rhs
+    }
+}
+
+#[cube]
+impl<N: Numeric> ScanOp<N> for Prod {
+    fn identity() -> N {
+        N::from_int(1)
+    }
+
+    fn combine(lhs: N, rhs: N) -> N {
+        lhs * rhs
+    }
+}
+
+#[cube]
+impl<N: Numeric> ScanOp<N> for Max {
+    fn identity() -> N {
+        N::min_value()
+    }
+
+    fn combine(lhs: N, rhs: N) -> N {
+        select(lhs > rhs, lhs, rhs)
+    }
+}
+
+#[cube]
+impl<N: Numeric> ScanOp<N> for Min {
+    fn identity() -> N {
+        N::max_value()
+    }
+
+    fn combine(lhs: N, rhs: N) -> N {
+        select(lhs < rhs, lhs, rhs)
+    }
+}
+
+/// Scan the given `axis` of the `input` tensor with the operator `Op` and write the running
+/// results into `output`, such as the cumulative sum of each slice with [`Sum`].
+///
+/// Each cube scans a block of up to 256 elements of a slice in shared memory and writes the total
+/// of its block. When a slice spans multiple blocks, the block totals are scanned the same way
+/// into the offset of each block, which is then combined with every element of the block.
+///
+/// Return an error if the `axis` is larger than the `input` rank, or if `output` doesn't have the
+/// same shape as `input`. The `output` can have different strides.
+///
+/// # Example
+///
+/// ```ignore
+/// use cubecl_reduce::{ScanKind, instructions::Sum};
+///
+/// // The cumulative sum of each row of a matrix.
+/// let result = scan::<R, f32, Sum>(&client, input, output, 1, ScanKind::Inclusive);
+/// ```
+pub fn scan<R: Runtime, N: Numeric, Op: ScanOp<N>>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: TensorHandleRef<R>,
+    output: TensorHandleRef<R>,
+    axis: usize,
+    kind: ScanKind,
+) -> Result<(), ReduceError> {
+    validate_axis(input.shape.len(), axis)?;
+    valid_output_shape(input.shape, output.shape, &[], true)?;
+
+    let length = input.shape[axis];
+    if input.size() == 0 {
+        return Ok(());
+    }
+    let num_slices = input.size() / length;
+    // Slices fitting in a single block are scanned by a cube just large enough.
+    let block_size = BLOCK_SIZE.min(length.next_power_of_two());
+    let num_blocks = length.div_ceil(block_size);
+
+    let cube_count = CubeCount::Static(num_blocks as u32, num_slices as u32, 1);
+    let cube_dim = CubeDim::new_1d(block_size as u32);
+    validate_cube_count::<R>(&cube_count)?;
+
+    let elem_size = N::as_elem_native_unchecked().size();
+    let block_shape = [num_slices, num_blocks];
+    let block_strides = [num_blocks, 1];
+    let block_sums_handle = client.empty(num_slices * num_blocks * elem_size);
+    let block_sums = unsafe {
+        TensorHandleRef::<R>::from_raw_parts(
+            &block_sums_handle,
+            &block_strides,
+            &block_shape,
+            elem_size,
+        )
+    };
+
+    unsafe {
+        scan_block_kernel::launch_unchecked::<N, Op, R>(
+            client,
+            cube_count.clone(),
+            cube_dim,
+            input.as_tensor_arg(1),
+            output.as_tensor_arg(1),
+            block_sums.as_tensor_arg(1),
+            ScalarArg::new(axis as u32),
+            block_size as u32,
+            kind == ScanKind::Exclusive,
+        );
+    }
+
+    if num_blocks == 1 {
+        return Ok(());
+    }
+
+    let offsets_handle = client.empty(num_slices * num_blocks * elem_size);
+    let offsets = unsafe {
+        TensorHandleRef::<R>::from_raw_parts(
+            &offsets_handle,
+            &block_strides,
+            &block_shape,
+            elem_size,
+        )
+    };
+    scan::<R, N, Op>(client, block_sums, offsets, 1, ScanKind::Exclusive)?;
+
+    let offsets = unsafe {
+        TensorHandleRef::<R>::from_raw_parts(
+            &offsets_handle,
+            &block_strides,
+            &block_shape,
+            elem_size,
+        )
+    };
+    unsafe {
+        scan_add_offsets_kernel::launch_unchecked::<N, Op, R>(
+            client,
+            cube_count,
+            cube_dim,
+            output.as_tensor_arg(1),
+            offsets.as_tensor_arg(1),
+            ScalarArg::new(axis as u32),
+            block_size as u32,
+        );
+    }
+
+    Ok(())
+}
+
+/// Scan each block of `block_size` elements of a slice with a work-efficient up-sweep and
+/// down-sweep in shared memory, and write the total of the block into `block_sums`.
+#[cube(launch_unchecked)]
+fn scan_block_kernel<N: Numeric, Op: ScanOp<N>>(
+    input: &Tensor<N>,
+    output: &mut Tensor<N>,
+    block_sums: &mut Tensor<N>,
+    axis: u32,
+    #[comptime] block_size: u32,
+    #[comptime] exclusive: bool,
+) {
+    let position = CUBE_POS_X * block_size + UNIT_POS_X;
+    let length = input.shape(axis);
+
+    let mut value = Op::identity();
+    if position < length {
+        value = input[slice_offset(input, CUBE_POS_Y, axis) + position * input.stride(axis)];
+    }
+
+    let mut shared = SharedMemory::<N>::new(block_size);
+    shared[UNIT_POS_X] = value;
+    sync_units();
+
+    // Up-sweep, each node of the tree keeps the combination of its subtree.
+    let mut offset = 1;
+    while offset < block_size {
+        let right = (UNIT_POS_X + 1) * offset * 2 - 1;
+        if right < block_size {
+            shared[right] = Op::combine(shared[right - offset], shared[right]);
+        }
+        offset *= 2;
+        sync_units();
+    }
+
+    let total = shared[block_size - 1];
+    sync_units();
+    if UNIT_POS_X == 0 {
+        shared[block_size - 1] = Op::identity();
+        block_sums[CUBE_POS_Y * CUBE_COUNT_X + CUBE_POS_X] = total;
+    }
+    sync_units();
+
+    // Down-sweep, each node receives the combination of everything before its subtree.
+    let mut offset = CUBE_DIM / 2;
+    while offset > 0 {
+        let right = (UNIT_POS_X + 1) * offset * 2 - 1;
+        if right < block_size {
+            let left = shared[right - offset];
+            let before = shared[right];
+            shared[right - offset] = before;
+            shared[right] = Op::combine(before, left);
+        }
+        offset /= 2;
+        sync_units();
+    }
+
+    let mut result = shared[UNIT_POS_X];
+    if !exclusive {
+        result = Op::combine(result, value);
+    }
+
+    if position < length {
+        output[slice_offset(output, CUBE_POS_Y, axis) + position * output.stride(axis)] = result;
+    }
+}
+
+/// Combine the offset of each block, the scan of the blocks before it, with its elements.
+#[cube(launch_unchecked)]
+fn scan_add_offsets_kernel<N: Numeric, Op: ScanOp<N>>(
+    output: &mut Tensor<N>,
+    offsets: &Tensor<N>,
+    axis: u32,
+    #[comptime] block_size: u32,
+) {
+    let position = CUBE_POS_X * block_size + UNIT_POS_X;
+
+    if position < output.shape(axis) {
+        let index = slice_offset(output, CUBE_POS_Y, axis) + position * output.stride(axis);
+        let offset = offsets[CUBE_POS_Y * CUBE_COUNT_X + CUBE_POS_X];
+        output[index] = Op::combine(offset, output[index]);
+    }
+}
+
+/// The offset of the first element of the `slice`-th slice along `axis`, with the slices ordered
+/// by the coordinates of the other axes in row-major order.
+#[cube]
+fn slice_offset<N: Numeric>(tensor: &Tensor<N>, slice: u32, axis: u32) -> u32 {
+    let rank = tensor.rank();
+    let mut remaining = slice;
+    let mut offset = 0;
+
+    for i in 0..rank {
+        let dim = rank - 1 - i;
+        if dim != axis {
+            let shape = tensor.shape(dim);
+            offset += (remaining % shape) * tensor.stride(dim);
+            remaining /= shape;
+        }
+    }
+
+    offset
+}
@@ -1,7 +1,7 @@
 use cubecl_core::{Feature, prelude::*};
 use serde::{Deserialize, Serialize};
 
-use crate::ReduceError;
+use crate::{ReduceConfig, ReduceError};
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Serialize, Deserialize)]
 pub struct ReduceStrategy {
@@ -13,6 +13,13 @@ pub struct ReduceStrategy {
     /// If true, all units within a single cube cooperate to reduce a single item in the output.
     /// Else, each unit or plane (if planes is true) reduce a single item by itself.
     pub shared: bool,
+
+    /// If true and the reduced axis is strided, the units of a cube along `x` reduce adjacent
+    /// slices so their loads are coalesced, while the units along `y` split each slice and combine
+    /// their results in shared memory, taking precedence over `shared` and `use_planes`.
+    /// Ignored when the reduced axis is contiguous.
+    #[serde(default)]
+    pub split_strided: bool,
 }
 
 impl ReduceStrategy {
@@ -36,6 +43,28 @@ impl ReduceStrategy {
         Self {
             use_planes: support_plane::<R>(client) && precise_plane_dim::<R>(client),
             shared,
+            split_strided: false,
+        }
+    }
+
+    /// The strategy best suited to reduce the given `axis` of the `input`, which must be less than
+    /// its rank.
+    ///
+    /// When the axis is contiguous, all the units of a cube cooperate to reduce a slice, so
+    /// consecutive units load consecutive elements of it. When the axis is strided, consecutive
+    /// units load adjacent slices instead, each slice being [split](Self::split_strided) over the
+    /// units along `y`. That needs at least one slice per unit along `x` of the cube, otherwise
+    /// the units of a cube still cooperate on a slice.
+    pub fn for_axis<R: Runtime>(
+        client: &ComputeClient<R::Server, R::Channel>,
+        input: &TensorHandleRef<R>,
+        axis: usize,
+    ) -> Self {
+        let slice_count = input.size() / input.shape[axis].max(1);
+        Self {
+            split_strided: input.strides[axis] != 1
+                && slice_count >= ReduceConfig::SPLIT_CUBE_DIM.x as usize,
+            ..Self::new::<R>(client, true)
         }
     }
 }
@@ -1,5 +1,6 @@
 #![allow(missing_docs)]
 
+use cubecl_core as cubecl;
 use cubecl_core::prelude::*;
 use rand::{
     SeedableRng,
@@ -7,7 +8,11 @@ use rand::{
     rngs::StdRng,
 };
 
-use crate::{ReduceError, ReduceStrategy, instructions::*, reduce, shared_sum};
+use crate::{
+    MultiReduceFamily, ReduceError, ReduceStrategy, ScanKind, ScanOp, instructions::*,
+    map_reduce_map, reduce, reduce_axes, reduce_masked, reduce_multi, reduce_output_shape, scan,
+    shared_sum,
+};
 
 // All random values generated for tests will be in the set
 // {-2, -2 + E, -2 + 2E, ..., 2 - E, 2} with E = 1 / PRECISION.
@@ -202,6 +207,221 @@ macro_rules! testgen_reduce {
                 }
             ]
         );
+
+        #[test]
+        pub fn sum_axes_non_adjacent() {
+            let test = TestCase {
+                shape: vec![4, 6, 5, 8],
+                stride: vec![1, 160, 4, 20],
+                axis: None,
+                strategy: None,
+            };
+            test.test_sum_axes::<$float, TestRuntime>(&Default::default(), &[1, 3]);
+        }
+
+        #[test]
+        pub fn mean_axes_non_adjacent() {
+            let test = TestCase {
+                shape: vec![4, 6, 5, 8],
+                stride: vec![1, 160, 4, 20],
+                axis: None,
+                strategy: None,
+            };
+            test.test_mean_axes::<$float, TestRuntime>(&Default::default(), &[1, 3]);
+        }
+
+        #[test]
+        pub fn sum_axes_column_major_output() {
+            let test = TestCase {
+                shape: vec![4, 6, 5, 8],
+                stride: vec![240, 40, 8, 1],
+                axis: None,
+                strategy: None,
+            };
+            test.test_sum_axes_column_major_output::<$float, TestRuntime>(
+                &Default::default(),
+                &[1],
+            );
+        }
+
+        #[test]
+        pub fn sum_keep_dims_middle_axis() {
+            let test = TestCase {
+                shape: vec![4, 6, 8],
+                stride: vec![48, 8, 1],
+                axis: Some(1),
+                strategy: None,
+            };
+            test.test_sum_dims::<$float, TestRuntime>(&Default::default(), true);
+        }
+
+        #[test]
+        pub fn sum_drop_dims_middle_axis() {
+            let test = TestCase {
+                shape: vec![4, 6, 8],
+                stride: vec![48, 8, 1],
+                axis: Some(1),
+                strategy: None,
+            };
+            test.test_sum_dims::<$float, TestRuntime>(&Default::default(), false);
+        }
+
+        #[test]
+        pub fn sum_chunked_vector() {
+            let test = TestCase {
+                shape: vec![1 << 20],
+                stride: vec![1],
+                axis: Some(0),
+                strategy: None,
+            };
+            // The inputs are multiples of 1/4, so their sum is exact in f32 whatever the order of
+            // the additions.
+            test.test_sum::<f32, TestRuntime>(&Default::default());
+        }
+
+        #[test]
+        pub fn max_chunked_columns() {
+            let test = TestCase {
+                shape: vec![1 << 18, 4],
+                stride: vec![4, 1],
+                axis: Some(0),
+                strategy: None,
+            };
+            test.test_max::<$float, TestRuntime>(&Default::default());
+        }
+
+        #[test]
+        pub fn cumsum_rows_multiple_blocks() {
+            let test = TestCase {
+                shape: vec![3, 1000],
+                stride: vec![1000, 1],
+                axis: Some(1),
+                strategy: None,
+            };
+            // The partial sums of multiples of 1/4 are exact in f32 whatever the order.
+            test.test_scan::<f32, TestRuntime, cubecl_reduce::instructions::Sum>(
+                &Default::default(),
+                cubecl_reduce::ScanKind::Inclusive,
+            );
+            test.test_scan::<f32, TestRuntime, cubecl_reduce::instructions::Sum>(
+                &Default::default(),
+                cubecl_reduce::ScanKind::Exclusive,
+            );
+        }
+
+        #[test]
+        pub fn cumsum_columns_multiple_levels() {
+            // Long enough for the block totals to span multiple blocks themselves.
+            let test = TestCase {
+                shape: vec![70000, 2],
+                stride: vec![2, 1],
+                axis: Some(0),
+                strategy: None,
+            };
+            test.test_scan::<f32, TestRuntime, cubecl_reduce::instructions::Sum>(
+                &Default::default(),
+                cubecl_reduce::ScanKind::Inclusive,
+            );
+        }
+
+        #[test]
+        pub fn cumsum_short_axis() {
+            let test = TestCase {
+                shape: vec![16, 5],
+                stride: vec![5, 1],
+                axis: Some(1),
+                strategy: None,
+            };
+            test.test_scan::<f32, TestRuntime, cubecl_reduce::instructions::Sum>(
+                &Default::default(),
+                cubecl_reduce::ScanKind::Inclusive,
+            );
+            test.test_scan::<f32, TestRuntime, cubecl_reduce::instructions::Sum>(
+                &Default::default(),
+                cubecl_reduce::ScanKind::Exclusive,
+            );
+        }
+
+        #[test]
+        pub fn cummax_transposed() {
+            let test = TestCase {
+                shape: vec![600, 4],
+                stride: vec![1, 600],
+                axis: Some(0),
+                strategy: None,
+            };
+            test.test_scan::<$float, TestRuntime, cubecl_reduce::instructions::Max>(
+                &Default::default(),
+                cubecl_reduce::ScanKind::Inclusive,
+            );
+        }
+
+        #[test]
+        pub fn softmax_normalization_rows() {
+            let test = TestCase {
+                shape: vec![8, 100],
+                stride: vec![100, 1],
+                axis: Some(1),
+                strategy: None,
+            };
+            test.test_softmax_normalization::<$float, TestRuntime>(&Default::default());
+        }
+
+        #[test]
+        pub fn softmax_normalization_rows_shared() {
+            let test = TestCase {
+                shape: vec![8, 100],
+                stride: vec![100, 1],
+                axis: Some(1),
+                strategy: Some(cubecl_reduce::ReduceStrategy {
+                    use_planes: false,
+                    shared: true,
+                    split_strided: false,
+                }),
+            };
+            test.test_softmax_normalization::<$float, TestRuntime>(&Default::default());
+        }
+
+        #[test]
+        pub fn softmax_normalization_columns_unit() {
+            let test = TestCase {
+                shape: vec![100, 8],
+                stride: vec![8, 1],
+                axis: Some(0),
+                strategy: Some(cubecl_reduce::ReduceStrategy {
+                    use_planes: false,
+                    shared: false,
+                    split_strided: false,
+                }),
+            };
+            test.test_softmax_normalization::<$float, TestRuntime>(&Default::default());
+        }
+
+        #[test]
+        pub fn masked_sum_mean_rows() {
+            let test = TestCase {
+                shape: vec![6, 100],
+                stride: vec![100, 1],
+                axis: Some(1),
+                strategy: None,
+            };
+            test.test_masked_sum_mean::<$float, TestRuntime>(&Default::default());
+        }
+
+        #[test]
+        pub fn masked_sum_mean_columns_shared() {
+            let test = TestCase {
+                shape: vec![100, 6],
+                stride: vec![6, 1],
+                axis: Some(0),
+                strategy: Some(cubecl_reduce::ReduceStrategy {
+                    use_planes: false,
+                    shared: true,
+                    split_strided: false,
+                }),
+            };
+            test.test_masked_sum_mean::<$float, TestRuntime>(&Default::default());
+        }
     };
 }
 
@@ -239,6 +459,15 @@ macro_rules! impl_test_reduce {
                     [ use_planes: false, shared: true ],
                     [ use_planes: true, shared: true ]
                 }
+                $crate::impl_test_reduce_split_strided!{
+                    $float,
+                    {
+                        id: $id,
+                        shape: $shape,
+                        stride: $stride,
+                        axis: $axis,
+                    }
+                }
             )*
         }
     };
@@ -264,7 +493,7 @@ macro_rules! impl_test_reduce_with_strategy {
                         shape: $shape.into(),
                         stride: $stride.into(),
                         axis: Some($axis),
-                        strategy: Some($crate::ReduceStrategy { use_planes: $use_planes, shared: $shared }),
+                        strategy: Some($crate::ReduceStrategy { use_planes: $use_planes, shared: $shared, split_strided: false }),
                     };
                     test.test_argmax::<$float, TestRuntime>(&Default::default());
                 }
@@ -276,7 +505,7 @@ macro_rules! impl_test_reduce_with_strategy {
                         shape: $shape.into(),
                         stride: $stride.into(),
                         axis: Some($axis),
-                        strategy: Some($crate::ReduceStrategy { use_planes: $use_planes, shared: $shared }),
+                        strategy: Some($crate::ReduceStrategy { use_planes: $use_planes, shared: $shared, split_strided: false }),
                     };
                     test.test_argmin::<$float, TestRuntime>(&Default::default());
                 }
@@ -287,7 +516,7 @@ macro_rules! impl_test_reduce_with_strategy {
                         shape: $shape.into(),
                         stride: $stride.into(),
                         axis: Some($axis),
-                        strategy: Some($crate::ReduceStrategy { use_planes: $use_planes, shared: $shared }),
+                        strategy: Some($crate::ReduceStrategy { use_planes: $use_planes, shared: $shared, split_strided: false }),
                     };
                     test.test_mean::<$float, TestRuntime>(&Default::default());
                 }
@@ -298,7 +527,7 @@ macro_rules! impl_test_reduce_with_strategy {
                         shape: $shape.into(),
                         stride: $stride.into(),
                         axis: Some($axis),
-                        strategy: Some($crate::ReduceStrategy { use_planes: $use_planes, shared: $shared }),
+                        strategy: Some($crate::ReduceStrategy { use_planes: $use_planes, shared: $shared, split_strided: false }),
                     };
                     test.test_prod::<$float, TestRuntime>(&Default::default());
                 }
@@ -309,15 +538,244 @@ macro_rules! impl_test_reduce_with_strategy {
                         shape: $shape.into(),
                         stride: $stride.into(),
                         axis: Some($axis),
-                        strategy: Some($crate::ReduceStrategy { use_planes: $use_planes, shared: $shared }),
+                        strategy: Some($crate::ReduceStrategy { use_planes: $use_planes, shared: $shared, split_strided: false }),
                     };
                     test.test_sum::<$float, TestRuntime>(&Default::default());
                 }
+
+                #[test]
+                pub fn [< sum_max_plane_ $use_planes _shared_ $shared _ $id >]() {
+                    let test = TestCase {
+                        shape: $shape.into(),
+                        stride: $stride.into(),
+                        axis: Some($axis),
+                        strategy: Some($crate::ReduceStrategy { use_planes: $use_planes, shared: $shared, split_strided: false }),
+                    };
+                    test.test_sum_max::<$float, TestRuntime>(&Default::default());
+                }
+
+                #[test]
+                pub fn [< log_sum_exp_plane_ $use_planes _shared_ $shared _ $id >]() {
+                    let test = TestCase {
+                        shape: $shape.into(),
+                        stride: $stride.into(),
+                        axis: Some($axis),
+                        strategy: Some($crate::ReduceStrategy { use_planes: $use_planes, shared: $shared, split_strided: false }),
+                    };
+                    test.test_log_sum_exp::<$float, TestRuntime>(&Default::default());
+                }
             )*
         }
     };
 }
 
+// Run the tests for `Sum`, `Mean`, `ArgMax`, `ArgMin` and `LogSumExp` splitting the slices of a
+// strided axis, which is the same as the other strategies when the axis is contiguous.
+#[macro_export]
+macro_rules! impl_test_reduce_split_strided {
+    (
+        $float:ident,
+        {
+            id: $id:literal,
+            shape: $shape:expr,
+            stride: $stride:expr,
+            axis: $axis:expr,
+        }
+    ) => {
+        ::paste::paste! {
+            #[test]
+            pub fn [< argmax_split_strided_ $id >]() {
+                let test = TestCase {
+                    shape: $shape.into(),
+                    stride: $stride.into(),
+                    axis: Some($axis),
+                    strategy: Some($crate::ReduceStrategy { use_planes: false, shared: false, split_strided: true }),
+                };
+                test.test_argmax::<$float, TestRuntime>(&Default::default());
+            }
+
+            #[test]
+            pub fn [< argmin_split_strided_ $id >]() {
+                let test = TestCase {
+                    shape: $shape.into(),
+                    stride: $stride.into(),
+                    axis: Some($axis),
+                    strategy: Some($crate::ReduceStrategy { use_planes: false, shared: false, split_strided: true }),
+                };
+                test.test_argmin::<$float, TestRuntime>(&Default::default());
+            }
+
+            #[test]
+            pub fn [< mean_split_strided_ $id >]() {
+                let test = TestCase {
+                    shape: $shape.into(),
+                    stride: $stride.into(),
+                    axis: Some($axis),
+                    strategy: Some($crate::ReduceStrategy { use_planes: false, shared: false, split_strided: true }),
+                };
+                test.test_mean::<$float, TestRuntime>(&Default::default());
+            }
+
+            #[test]
+            pub fn [< sum_split_strided_ $id >]() {
+                let test = TestCase {
+                    shape: $shape.into(),
+                    stride: $stride.into(),
+                    axis: Some($axis),
+                    strategy: Some($crate::ReduceStrategy { use_planes: false, shared: false, split_strided: true }),
+                };
+                test.test_sum::<$float, TestRuntime>(&Default::default());
+            }
+
+            #[test]
+            pub fn [< log_sum_exp_split_strided_ $id >]() {
+                let test = TestCase {
+                    shape: $shape.into(),
+                    stride: $stride.into(),
+                    axis: Some($axis),
+                    strategy: Some($crate::ReduceStrategy { use_planes: false, shared: false, split_strided: true }),
+                };
+                test.test_log_sum_exp::<$float, TestRuntime>(&Default::default());
+            }
+        }
+    };
+}
+
+/// Compute both the sum and the maximum in a single reduction.
+pub type SumMax = ReducePair<Sum, Max>;
+
+/// The log of the sum of the exponentials of a slice, accumulated as the maximum of the elements
+/// seen so far with the sum of their exponentials relative to that maximum, so the exponentials
+/// don't overflow.
+#[derive(Debug, CubeType, Clone)]
+pub struct LogSumExp {}
+
+#[cube]
+impl LogSumExp {
+    /// Combine two pairs of maximums and relative sums of exponentials.
+    pub fn combine<N: Numeric>(
+        max0: Line<N>,
+        sum0: Line<N>,
+        max1: Line<N>,
+        sum1: Line<N>,
+    ) -> (Line<N>, Line<N>) {
+        let max = select_many(max0.greater_than(max1), max0, max1);
+        let sum = sum0 * Self::exp_relative(max0, max) + sum1 * Self::exp_relative(max1, max);
+        (max, sum)
+    }
+
+    /// `exp(value - max)`, computed in `f32` since `N` may not be a float. It's 1 when both are
+    /// equal, even when they're so low that their difference is undefined in `f32`.
+    fn exp_relative<N: Numeric>(value: Line<N>, max: Line<N>) -> Line<N> {
+        let one = Line::empty(value.size()).fill(N::from_int(1));
+        let exp = Line::<N>::cast_from(Exp::exp(Line::<f32>::cast_from(value - max)));
+        select_many(value.equal(max), one, exp)
+    }
+
+    fn finish<N: Numeric>(max: Line<N>, sum: Line<N>) -> Line<N> {
+        max + Line::<N>::cast_from(Log::log(Line::<f32>::cast_from(sum)))
+    }
+}
+
+impl ReduceFamily for LogSumExp {
+    type Instruction<In: Numeric> = Self;
+    type Config = ();
+}
+
+#[cube]
+impl<In: Numeric> ReduceInstruction<In> for LogSumExp {
+    type AccumulatorItem = (Line<In>, Line<In>);
+    type SharedAccumulator = PairAccumulator<SharedMemory<Line<In>>, SharedMemory<Line<In>>>;
+    type Config = ();
+
+    fn requirements(_this: &Self) -> ReduceRequirements {
+        ReduceRequirements { coordinates: false }
+    }
+
+    fn from_config(_config: Self::Config) -> Self {
+        LogSumExp {}
+    }
+
+    fn null_input(_this: &Self, #[comptime] line_size: u32) -> Line<In> {
+        Line::empty(line_size).fill(In::min_value())
+    }
+
+    fn null_accumulator(this: &Self, #[comptime] line_size: u32) -> Self::AccumulatorItem {
+        (
+            Self::null_input(this, line_size),
+            Line::empty(line_size).fill(In::from_int(0)),
+        )
+    }
+
+    fn assign_accumulator(
+        _this: &Self,
+        destination: &mut Self::AccumulatorItem,
+        source: &Self::AccumulatorItem,
+    ) {
+        destination.0 = source.0;
+        destination.1 = source.1;
+    }
+
+    fn reduce(
+        _this: &Self,
+        accumulator: &Self::AccumulatorItem,
+        item: Line<In>,
+        _coordinate: ReduceCoordinate,
+        #[comptime] use_planes: bool,
+    ) -> Self::AccumulatorItem {
+        // A single element is its own maximum, with a relative exponential of 1.
+        let (max, sum) = if use_planes {
+            let max = plane_max(item);
+            (max, plane_sum(Self::exp_relative(item, max)))
+        } else {
+            (item, Line::empty(item.size()).fill(In::from_int(1)))
+        };
+
+        Self::combine(accumulator.0, accumulator.1, max, sum)
+    }
+
+    fn fuse_accumulators(
+        _this: &Self,
+        lhs: Self::AccumulatorItem,
+        rhs: Self::AccumulatorItem,
+    ) -> Self::AccumulatorItem {
+        Self::combine(lhs.0, lhs.1, rhs.0, rhs.1)
+    }
+
+    fn merge_line<Out: Numeric>(
+        _this: &Self,
+        accumulator: Self::AccumulatorItem,
+        _shape_axis_reduce: u32,
+    ) -> Out {
+        let line_size = accumulator.0.size();
+        let mut max = Line::new(accumulator.0[0]);
+        let mut sum = Line::new(accumulator.1[0]);
+
+        #[unroll]
+        for k in 1..line_size {
+            let (next_max, next_sum) = Self::combine(
+                max,
+                sum,
+                Line::new(accumulator.0[k]),
+                Line::new(accumulator.1[k]),
+            );
+            max = next_max;
+            sum = next_sum;
+        }
+
+        let result = Self::finish(max, sum);
+        Out::cast_from(result[0])
+    }
+
+    fn to_output_perpendicular<Out: Numeric>(
+        _this: &Self,
+        accumulator: Self::AccumulatorItem,
+        _shape_axis_reduce: u32,
+    ) -> Line<Out> {
+        Line::cast_from(Self::finish(accumulator.0, accumulator.1))
+    }
+}
+
 #[derive(Debug)]
 pub struct TestCase {
     pub shape: Vec<usize>,
@@ -463,6 +921,151 @@ impl TestCase {
         expected
     }
 
+    /// Sum into a contiguous output with the shape given by [reduce_output_shape], and check that
+    /// broadcasting it back to the shape of the input gives the sum of each slice.
+    pub fn test_sum_dims<F, R>(&self, device: &R::Device, keep_dims: bool)
+    where
+        F: Float + CubeElement + std::fmt::Display,
+        R: Runtime,
+    {
+        let client = R::client(device);
+        let axis = self.axis.unwrap();
+
+        let input_values: Vec<F> = self.random_input_values();
+        let expected_values = self.cpu_sum(&input_values);
+
+        let output_shape = reduce_output_shape(&self.shape, axis, keep_dims);
+        let mut expected_shape = self.shape.clone();
+        match keep_dims {
+            true => expected_shape[axis] = 1,
+            false => {
+                expected_shape.remove(axis);
+            }
+        }
+        assert_eq!(output_shape, expected_shape);
+        let output_stride = contiguous_strides(&output_shape);
+
+        let input_handle = client.create(F::as_bytes(&input_values));
+        let output_handle = client.empty(self.num_output_values() * size_of::<F>());
+        let input = unsafe {
+            TensorHandleRef::<R>::from_raw_parts(
+                &input_handle,
+                &self.stride,
+                &self.shape,
+                size_of::<F>(),
+            )
+        };
+        let output = unsafe {
+            TensorHandleRef::<R>::from_raw_parts(
+                &output_handle,
+                &output_stride,
+                &output_shape,
+                size_of::<F>(),
+            )
+        };
+        reduce::<R, F, F, Sum>(&client, input, output, axis, self.strategy, ()).unwrap();
+
+        let bytes = client.read_one(output_handle.binding());
+        let output_values = F::from_bytes(&bytes);
+
+        // Broadcast the output to every input element, with a stride of 0 for the reduced axis.
+        let mut broadcast_stride = output_stride;
+        match keep_dims {
+            true => broadcast_stride[axis] = 0,
+            false => broadcast_stride.insert(axis, 0),
+        }
+        let (actual, expected): (Vec<F>, Vec<F>) = (0..self.input_size())
+            .filter_map(|index| {
+                let coordinate = self.to_input_coordinate(index)?;
+                let broadcast_index = coordinate
+                    .iter()
+                    .zip(broadcast_stride.iter())
+                    .map(|(c, s)| c * s)
+                    .sum::<usize>();
+                let output_index = self.to_output_index(index)?;
+                Some((
+                    output_values[broadcast_index],
+                    expected_values[output_index],
+                ))
+            })
+            .unzip();
+        assert_approx_equal(&actual, &expected);
+    }
+
+    pub fn test_sum_max<F, R>(&self, device: &R::Device)
+    where
+        F: Float + CubeElement + std::fmt::Display,
+        R: Runtime,
+    {
+        let input_values: Vec<F> = self.random_input_values();
+        let expected_values = match self.axis {
+            Some(axis) if self.stride[axis] == 0 => [
+                input_values
+                    .iter()
+                    .map(|v| *v * F::from_int(self.shape[axis] as i64))
+                    .collect(),
+                input_values.clone(),
+            ],
+            _ => [self.cpu_sum(&input_values), self.cpu_max(&input_values)],
+        };
+        self.run_reduce_multi_test::<F, F, R, SumMax>(device, input_values, expected_values)
+    }
+
+    pub fn test_log_sum_exp<F, R>(&self, device: &R::Device)
+    where
+        F: Float + CubeElement + std::fmt::Display,
+        R: Runtime,
+    {
+        let input_values: Vec<F> = self.random_input_values();
+        let expected_values = match self.axis {
+            Some(axis) if self.stride[axis] == 0 => input_values
+                .iter()
+                .map(|v| F::new(v.to_f32().unwrap() + (self.shape[axis] as f32).ln()))
+                .collect(),
+            _ => self.cpu_log_sum_exp(&input_values),
+        };
+        self.run_reduce_test::<F, F, R, LogSumExp>(device, input_values, expected_values)
+    }
+
+    fn cpu_log_sum_exp<F: Float>(&self, values: &[F]) -> Vec<F> {
+        let max = self.cpu_max(values);
+        let mut sums = vec![0.0f32; self.num_output_values()];
+
+        for (input_index, value) in values.iter().enumerate() {
+            if let Some(output_index) = self.to_output_index(input_index) {
+                let max = max[output_index].to_f32().unwrap();
+                sums[output_index] += (value.to_f32().unwrap() - max).exp();
+            }
+        }
+        max.iter()
+            .zip(sums)
+            .map(|(max, sum)| F::new(max.to_f32().unwrap() + sum.ln()))
+            .collect()
+    }
+
+    pub fn test_max<F, R>(&self, device: &R::Device)
+    where
+        F: Float + CubeElement + std::fmt::Display,
+        R: Runtime,
+    {
+        let input_values: Vec<F> = self.random_input_values();
+        let expected_values = self.cpu_max(&input_values);
+        self.run_reduce_test::<F, F, R, Max>(device, input_values, expected_values)
+    }
+
+    fn cpu_max<F: Float>(&self, values: &[F]) -> Vec<F> {
+        let mut expected = vec![F::min_value(); self.num_output_values()];
+
+        for (input_index, value) in values.iter().enumerate() {
+            if let Some(output_index) = self.to_output_index(input_index) {
+                if *value > expected[output_index] {
+                    expected[output_index] = *value;
+                }
+            }
+        }
+        expected
+    }
+
     pub fn test_shared_sum<F, R>(&self, device: &R::Device)
     where
         F: Float + CubeElement + std::fmt::Display,
@@ -476,6 +1079,315 @@ impl TestCase {
         self.run_shared_sum_test::<F, R>(device, input_values, expected);
     }
 
+    pub fn test_sum_axes<F, R>(&self, device: &R::Device, axes: &[usize])
+    where
+        F: Float + CubeElement + std::fmt::Display,
+        R: Runtime,
+    {
+        self.run_reduce_axes_test::<F, R, Sum>(device, axes, false)
+    }
+
+    pub fn test_sum_axes_column_major_output<F, R>(&self, device: &R::Device, axes: &[usize])
+    where
+        F: Float + CubeElement + std::fmt::Display,
+        R: Runtime,
+    {
+        self.run_reduce_axes_test::<F, R, Sum>(device, axes, true)
+    }
+
+    pub fn test_mean_axes<F, R>(&self, device: &R::Device, axes: &[usize])
+    where
+        F: Float + CubeElement + std::fmt::Display,
+        R: Runtime,
+    {
+        self.run_reduce_axes_test::<F, R, Mean>(device, axes, false)
+    }
+
+    /// Reduce all `axes` at once and compare with one single-axis reduction per axis.
+    ///
+    /// With `column_major_output`, the output of [`reduce_axes`] has its first axis contiguous.
+    pub fn run_reduce_axes_test<F, R, K>(
+        &self,
+        device: &R::Device,
+        axes: &[usize],
+        column_major_output: bool,
+    ) where
+        F: Float + CubeElement + std::fmt::Display,
+        R: Runtime,
+        K: ReduceFamily<Config = ()>,
+    {
+        let client = R::client(device);
+
+        let input_values: Vec<F> = self.random_input_values();
+        let input_handle = client.create(F::as_bytes(&input_values));
+
+        let mut output_shape = self.shape.clone();
+        for &axis in axes {
+            output_shape[axis] = 1;
+        }
+        let output_stride = if column_major_output {
+            column_major_strides(&output_shape)
+        } else {
+            contiguous_strides(&output_shape)
+        };
+        let output_handle = client.empty(output_shape.iter().product::<usize>() * size_of::<F>());
+
+        let input = unsafe {
+            TensorHandleRef::<R>::from_raw_parts(
+                &input_handle,
+                &self.stride,
+                &self.shape,
+                size_of::<F>(),
+            )
+        };
+        let output = unsafe {
+            TensorHandleRef::<R>::from_raw_parts(
+                &output_handle,
+                &output_stride,
+                &output_shape,
+                size_of::<F>(),
+            )
+        };
+        reduce_axes::<R, F, F, K>(&client, input, output, axes, ()).unwrap();
+
+        let mut handle = input_handle;
+        let mut shape = self.shape.clone();
+        let mut stride = self.stride.clone();
+        for &axis in axes {
+            let mut next_shape = shape.clone();
+            next_shape[axis] = 1;
+            let next_stride = contiguous_strides(&next_shape);
+            let next_handle = client.empty(next_shape.iter().product::<usize>() * size_of::<F>());
+
+            let input = unsafe {
+                TensorHandleRef::<R>::from_raw_parts(&handle, &stride, &shape, size_of::<F>())
+            };
+            let output = unsafe {
+                TensorHandleRef::<R>::from_raw_parts(
+                    &next_handle,
+                    &next_stride,
+                    &next_shape,
+                    size_of::<F>(),
+                )
+            };
+            reduce::<R, F, F, K>(&client, input, output, axis, None, ()).unwrap();
+
+            handle = next_handle;
+            shape = next_shape;
+            stride = next_stride;
+        }
+
+        let expected = client.read_one(handle.binding());
+        let actual = client.read_one(output_handle.binding());
+        let actual = F::from_bytes(&actual);
+        // Read the output in row-major order, like the expected values.
+        let actual: Vec<F> = (0..actual.len())
+            .map(|index| {
+                let mut remainder = index;
+                let mut offset = 0;
+                for axis in (0..output_shape.len()).rev() {
+                    offset += (remainder % output_shape[axis]) * output_stride[axis];
+                    remainder /= output_shape[axis];
+                }
+                actual[offset]
+            })
+            .collect();
+        assert_approx_equal(&actual, F::from_bytes(&expected));
+    }
+
+    /// Normalize each slice by the sum of its exponentials with a single [`map_reduce_map`] and
+    /// compare with a reduction of the exponentials followed by a division.
+    pub fn test_softmax_normalization<F, R>(&self, device: &R::Device)
+    where
+        F: Float + CubeElement + std::fmt::Display,
+        R: Runtime,
+    {
+        let client = R::client(device);
+        let axis = self.axis.unwrap();
+
+        let input_values: Vec<F> = self.random_input_values();
+        let exp_values: Vec<F> = input_values
+            .iter()
+            .map(|value| F::new(value.to_f32().unwrap().exp()))
+            .collect();
+        let input_handle = client.create(F::as_bytes(&input_values));
+        let exp_handle = client.create(F::as_bytes(&exp_values));
+
+        let mut sum_shape = self.shape.clone();
+        sum_shape[axis] = 1;
+        let sum_stride = self.output_stride();
+        let sum_handle = client.empty(self.num_output_values() * size_of::<F>());
+        let output_handle = client.empty(input_values.len() * size_of::<F>());
+
+        let exp = unsafe {
+            TensorHandleRef::<R>::from_raw_parts(
+                &exp_handle,
+                &self.stride,
+                &self.shape,
+                size_of::<F>(),
+            )
+        };
+        let sum = unsafe {
+            TensorHandleRef::<R>::from_raw_parts(
+                &sum_handle,
+                &sum_stride,
+                &sum_shape,
+                size_of::<F>(),
+            )
+        };
+        let result = reduce::<R, F, F, Sum>(&client, exp, sum, axis, self.strategy, ());
+        if result.is_err_and(|e| {
+            e == ReduceError::PlanesUnavailable || e == ReduceError::ImprecisePlaneDim
+        }) {
+            return; // We don't test in that case.
+        }
+
+        let sums = client.read_one(sum_handle.binding());
+        let sums = F::from_bytes(&sums);
+        let expected: Vec<F> = exp_values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| *value / sums[self.to_output_index(index).unwrap()])
+            .collect();
+
+        let input = unsafe {
+            TensorHandleRef::<R>::from_raw_parts(
+                &input_handle,
+                &self.stride,
+                &self.shape,
+                size_of::<F>(),
+            )
+        };
+        let output = unsafe {
+            TensorHandleRef::<R>::from_raw_parts(
+                &output_handle,
+                &self.stride,
+                &self.shape,
+                size_of::<F>(),
+            )
+        };
+        map_reduce_map::<R, F, ExpMap, Sum, DivideBy>(
+            &client,
+            input,
+            output,
+            axis,
+            self.strategy,
+            (),
+        )
+        .unwrap();
+
+        let bytes = client.read_one(output_handle.binding());
+        let actual = F::from_bytes(&bytes);
+        assert_approx_equal(actual, &expected);
+    }
+
+    /// Sum and average the elements selected by a mask with [`reduce_masked`]. The first slice has
+    /// no selected element, so its sum is zero and its mean is NaN.
+    pub fn test_masked_sum_mean<F, R>(&self, device: &R::Device)
+    where
+        F: Float + CubeElement + std::fmt::Display,
+        R: Runtime,
+    {
+        let client = R::client(device);
+        let axis = self.axis.unwrap();
+
+        let input_values: Vec<F> = self.random_input_values();
+        let mask_values: Vec<u32> = (0..input_values.len())
+            .map(|index| {
+                let output_index = self.to_output_index(index).unwrap();
+                (output_index != 0 && index % 3 != 1) as u32
+            })
+            .collect();
+
+        let mut sums = vec![0.0; self.num_output_values()];
+        let mut counts = vec![0; self.num_output_values()];
+        for (index, value) in input_values.iter().enumerate() {
+            if mask_values[index] != 0 {
+                let output_index = self.to_output_index(index).unwrap();
+                sums[output_index] += value.to_f32().unwrap();
+                counts[output_index] += 1;
+            }
+        }
+        let expected_sums: Vec<F> = sums.iter().map(|sum| F::new(*sum)).collect();
+        let expected_means: Vec<F> = sums
+            .iter()
+            .zip(counts.iter())
+            .map(|(sum, count)| F::new(sum / *count as f32))
+            .collect();
+
+        let input_handle = client.create(F::as_bytes(&input_values));
+        let mask_handle = client.create(u32::as_bytes(&mask_values));
+        let mut output_shape = self.shape.clone();
+        output_shape[axis] = 1;
+        let output_stride = self.output_stride();
+
+        for (mean, expected) in [(false, expected_sums), (true, expected_means)] {
+            let output_handle = client.empty(self.num_output_values() * size_of::<F>());
+            let input = unsafe {
+                TensorHandleRef::<R>::from_raw_parts(
+                    &input_handle,
+                    &self.stride,
+                    &self.shape,
+                    size_of::<F>(),
+                )
+            };
+            let mask = unsafe {
+                TensorHandleRef::<R>::from_raw_parts(
+                    &mask_handle,
+                    &self.stride,
+                    &self.shape,
+                    size_of::<u32>(),
+                )
+            };
+            let output = unsafe {
+                TensorHandleRef::<R>::from_raw_parts(
+                    &output_handle,
+                    &output_stride,
+                    &output_shape,
+                    size_of::<F>(),
+                )
+            };
+            let result = match mean {
+                false => reduce_masked::<R, F, u32, F, Sum>(
+                    &client,
+                    input,
+                    mask,
+                    output,
+                    axis,
+                    self.strategy,
+                    (),
+                ),
+                true => reduce_masked::<R, F, u32, F, Mean>(
+                    &client,
+                    input,
+                    mask,
+                    output,
+                    axis,
+                    self.strategy,
+                    (),
+                ),
+            };
+            if result.is_err_and(|e| {
+                e == ReduceError::PlanesUnavailable || e == ReduceError::ImprecisePlaneDim
+            }) {
+                return; // We don't test in that case.
+            }
+
+            let bytes = client.read_one(output_handle.binding());
+            let actual = F::from_bytes(&bytes);
+            assert!(
+                actual[0].to_f32().unwrap().is_nan() == mean,
+                "The empty slice should reduce to {}, got {}",
+                if mean { "NaN" } else { "zero" },
+                actual[0]
+            );
+            assert_approx_equal(&actual[1..], &expected[1..]);
+            if !mean {
+                assert_approx_equal(&actual[..1], &expected[..1]);
+            }
+        }
+    }
+
     pub fn run_reduce_test<I, O, R, K>(
         &self,
         device: &R::Device,
@@ -536,6 +1448,130 @@ impl TestCase {
         assert_approx_equal(output_values, &expected_values);
     }
 
+    pub fn run_reduce_multi_test<I, O, R, K>(
+        &self,
+        device: &R::Device,
+        input_values: Vec<I>,
+        expected_values: [Vec<O>; 2],
+    ) where
+        I: Numeric + CubeElement + std::fmt::Display,
+        O: Numeric + CubeElement + std::fmt::Display,
+        R: Runtime,
+        K: MultiReduceFamily<Config = ((), ())>,
+    {
+        let client = R::client(device);
+
+        let input_handle = client.create(I::as_bytes(&input_values));
+        let output_handles = expected_values
+            .each_ref()
+            .map(|expected| client.create(O::as_bytes(&vec![O::from_int(0); expected.len()])));
+        let mut output_shape = self.shape.clone();
+        output_shape[self.axis.unwrap()] = 1;
+        let output_stride = self.output_stride();
+
+        let input = unsafe {
+            TensorHandleRef::<R>::from_raw_parts(
+                &input_handle,
+                &self.stride,
+                &self.shape,
+                size_of::<I>(),
+            )
+        };
+        let outputs = output_handles.each_ref().map(|handle| unsafe {
+            TensorHandleRef::<R>::from_raw_parts(
+                handle,
+                &output_stride,
+                &output_shape,
+                size_of::<O>(),
+            )
+        });
+
+        let result = reduce_multi::<R, I, O, K>(
+            &client,
+            input,
+            &outputs,
+            self.axis.unwrap(),
+            self.strategy,
+            ((), ()),
+        );
+        if result.is_err_and(|e| {
+            e == ReduceError::PlanesUnavailable || e == ReduceError::ImprecisePlaneDim
+        }) {
+            return; // We don't test in that case.
+        }
+
+        for (handle, expected) in output_handles.into_iter().zip(expected_values.iter()) {
+            let bytes = client.read_one(handle.binding());
+            let output_values = O::from_bytes(&bytes);
+            assert_approx_equal(output_values, expected);
+        }
+    }
+
+    /// Scan the axis into a contiguous output and compare it to a sequential scan of each slice.
+    pub fn test_scan<F, R, Op>(&self, device: &R::Device, kind: ScanKind)
+    where
+        F: Float + CubeElement + std::fmt::Display,
+        R: Runtime,
+        Op: ScanOp<F> + CpuScanOp,
+    {
+        let client = R::client(device);
+        let axis = self.axis.unwrap();
+        let input_values: Vec<F> = self.random_input_values();
+        let output_strides = contiguous_strides(&self.shape);
+        let size = self.shape.iter().product::<usize>();
+
+        let mut expected = vec![F::from_int(0); size];
+        let axis_stride = output_strides[axis];
+        for first in 0..size {
+            if (first / axis_stride) % self.shape[axis] != 0 {
+                continue;
+            }
+            let mut accumulator = Op::cpu_identity::<F>();
+            for k in 0..self.shape[axis] {
+                let index = first + k * axis_stride;
+                let input_index = self
+                    .shape
+                    .iter()
+                    .zip(output_strides.iter().zip(self.stride.iter()))
+                    .map(|(shape, (out_stride, in_stride))| {
+                        (index / out_stride) % shape * in_stride
+                    })
+                    .sum::<usize>();
+                let next = Op::cpu_combine(accumulator, input_values[input_index]);
+                expected[index] = match kind {
+                    ScanKind::Inclusive => next,
+                    ScanKind::Exclusive => accumulator,
+                };
+                accumulator = next;
+            }
+        }
+
+        let input_handle = client.create(F::as_bytes(&input_values));
+        let output_handle = client.empty(size * size_of::<F>());
+        let input = unsafe {
+            TensorHandleRef::<R>::from_raw_parts(
+                &input_handle,
+                &self.stride,
+                &self.shape,
+                size_of::<F>(),
+            )
+        };
+        let output = unsafe {
+            TensorHandleRef::<R>::from_raw_parts(
+                &output_handle,
+                &output_strides,
+                &self.shape,
+                size_of::<F>(),
+            )
+        };
+
+        scan::<R, F, Op>(&client, input, output, axis, kind).unwrap();
+
+        let bytes = client.read_one(output_handle.binding());
+        let output_values = F::from_bytes(&bytes);
+        assert_approx_equal(output_values, &expected);
+    }
+
     pub fn run_shared_sum_test<F, R>(&self, device: &R::Device, input_values: Vec<F>, expected: F)
     where
         F: Float + CubeElement + std::fmt::Display,
@@ -659,6 +1695,48 @@ impl TestCase {
     }
 }
 
+/// The sequential reference of a [ScanOp].
+pub trait CpuScanOp {
+    fn cpu_identity<F: Float>() -> F;
+    fn cpu_combine<F: Float>(lhs: F, rhs: F) -> F;
+}
+
+impl CpuScanOp for Sum {
+    fn cpu_identity<F: Float>() -> F {
+        F::from_int(0)
+    }
+
+    fn cpu_combine<F: Float>(lhs: F, rhs: F) -> F {
+        lhs + rhs
+    }
+}
+
+impl CpuScanOp for Max {
+    fn cpu_identity<F: Float>() -> F {
+        F::min_value()
+    }
+
+    fn cpu_combine<F: Float>(lhs: F, rhs: F) -> F {
+        if rhs > lhs { rhs } else { lhs }
+    }
+}
+
+fn contiguous_strides(shape: &[usize]) -> Vec<usize> {
+    let mut strides = vec![1; shape.len()];
+    for axis in (0..shape.len().saturating_sub(1)).rev() {
+        strides[axis] = strides[axis + 1] * shape[axis + 1];
+    }
+    strides
+}
+
+fn column_major_strides(shape: &[usize]) -> Vec<usize> {
+    let mut strides = vec![1; shape.len()];
+    for axis in 1..shape.len() {
+        strides[axis] = strides[axis - 1] * shape[axis - 1];
+    }
+    strides
+}
+
 pub fn assert_approx_equal<N: Numeric>(actual: &[N], expected: &[N]) {
     for (i, (a, e)) in actual.iter().zip(expected.iter()).enumerate() {
         let a = a.to_f32().unwrap();
@@ -0,0 +1,153 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use cubecl_common::cache::{Cache, CacheError, CacheOption};
+
+/// On-disk cache of compiled kernel binaries, keyed by a hash of the final backend source.
+///
+/// Unlike the cache keyed by [kernel id](crate::KernelId), it also hits when different kernels
+/// generate the same source, so only the backend compiler invocation is skipped. Each target
+/// device and compiler version gets its own cache file, so changing either invalidates every
+/// entry.
+#[derive(Debug)]
+pub struct BinaryCache {
+    cache: Cache<String, Vec<u8>>,
+}
+
+impl BinaryCache {
+    /// Open the binary cache of the given backend for a target device and compiler version.
+    pub fn new(name: &str, device: &str, compiler_version: &str) -> Self {
+        Self::with_option(name, device, compiler_version, Default::default())
+    }
+
+    fn with_option(name: &str, device: &str, compiler_version: &str, option: CacheOption) -> Self {
+        Self {
+            cache: Cache::new(
+                format!("binaries/{name}/{device}/{compiler_version}"),
+                option,
+            ),
+        }
+    }
+
+    /// Fetch the binary compiled from the given source.
+    pub fn get(&self, source: &str) -> Option<&[u8]> {
+        self.cache.get(&source_hash(source)).map(Vec::as_slice)
+    }
+
+    /// Save the binary compiled from the given source.
+    pub fn insert(&mut self, source: &str, binary: Vec<u8>) {
+        match self.cache.insert(source_hash(source), binary) {
+            Ok(()) => {}
+            // The backend compiler isn't deterministic, the first binary stays valid.
+            Err(CacheError::DuplicatedKey { key, .. } | CacheError::KeyOutOfSync { key, .. }) => {
+                log::warn!("Compiled binary for source {key} differs from the cached one");
+            }
+        }
+    }
+
+    /// Fetch the binary compiled from the given source, or compile and save it on a miss.
+    pub fn get_or_compile<E>(
+        &mut self,
+        source: &str,
+        compile: impl FnOnce() -> Result<Vec<u8>, E>,
+    ) -> Result<Vec<u8>, E> {
+        if let Some(binary) = self.get(source) {
+            log::trace!("Using compiled binary cache");
+            return Ok(binary.to_vec());
+        }
+
+        let binary = compile()?;
+        self.insert(source, binary.clone());
+
+        Ok(binary)
+    }
+
+    /// The number of cached binaries.
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// If the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+}
+
+fn source_hash(source: &str) -> String {
+    format!("{:x}", md5::compute(source))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn unique_source() -> String {
+        format!("extern \"C\" __global__ void kernel_{}() {{}}", unique_id())
+    }
+
+    fn unique_id() -> u128 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    }
+
+    /// A temporary directory for the cache files, so the tests don't write to the user cache.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new() -> Self {
+            Self(std::env::temp_dir().join(format!(
+                "cubecl-binary-cache-{}-{}",
+                std::process::id(),
+                unique_id()
+            )))
+        }
+
+        fn cache(&self, device: &str, compiler_version: &str) -> BinaryCache {
+            let option = CacheOption::default().base_dir(&self.0);
+            BinaryCache::with_option("test", device, compiler_version, option)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn test_second_compile_hits_disk_cache() {
+        let source = unique_source();
+        let mut compilations = 0;
+        let mut compile = || {
+            compilations += 1;
+            Ok::<_, ()>(source.as_bytes().to_vec())
+        };
+
+        let dir = TempDir::new();
+        let mut cache = dir.cache("device", "compiler-1.0");
+        let first = cache.get_or_compile(&source, &mut compile).unwrap();
+
+        // Reopen the cache so the binary can only come from the file.
+        let mut cache = dir.cache("device", "compiler-1.0");
+        let second = cache.get_or_compile(&source, &mut compile).unwrap();
+
+        assert_eq!(compilations, 1);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_compiler_version_invalidates_cache() {
+        let source = unique_source();
+
+        let dir = TempDir::new();
+        let mut cache = dir.cache("device", "compiler-1.0");
+        cache.insert(&source, vec![1]);
+
+        let cache = dir.cache("device", "compiler-2.0");
+        assert_eq!(cache.get(&source), None);
+        let cache = dir.cache("other-device", "compiler-1.0");
+        assert_eq!(cache.get(&source), None);
+    }
+}
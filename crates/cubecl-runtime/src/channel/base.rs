@@ -2,7 +2,11 @@ use core::future::Future;
 use cubecl_common::{ExecutionMode, benchmark::ProfileDuration};
 
 use crate::{
-    server::{Binding, BindingWithMeta, Bindings, ComputeServer, CubeCount, Handle},
+    memory_management::AllocationError,
+    server::{
+        Binding, BindingWithMeta, Bindings, CompilationError, CompileWarning, ComputeServer,
+        CubeCount, DeviceError, Handle, HostCallback, KernelResources, LaunchObserver,
+    },
     storage::{BindingResource, ComputeStorage},
 };
 use alloc::vec::Vec;
@@ -19,6 +23,9 @@ pub trait ComputeChannel<Server: ComputeServer>: Clone + core::fmt::Debug + Send
         bindings: Vec<BindingWithMeta>,
     ) -> impl Future<Output = Vec<Vec<u8>>> + Send;
 
+    /// Copies the content of the binding into `destination`, blocking until the copy is done.
+    fn read_into(&self, binding: Binding, destination: &mut [u8]);
+
     /// Given a resource handle, return the storage resource.
     fn get_resource(
         &self,
@@ -35,9 +42,48 @@ pub trait ComputeChannel<Server: ComputeServer>: Clone + core::fmt::Debug + Send
     /// Reserves `size` bytes in the storage, and returns a handle over them
     fn empty(&self, size: usize) -> Handle;
 
+    /// Reserves `size` bytes in the storage, or returns an error if the memory limit is exceeded
+    fn try_empty(&self, size: usize) -> Result<Handle, AllocationError>;
+
+    /// Wraps externally allocated device memory in a handle
+    ///
+    /// # Safety
+    ///
+    /// See [ComputeServer::register_external].
+    unsafe fn register_external(&self, ptr: u64, size: u64) -> Handle;
+
     /// Reserves a tensor with `shape` in the storage, and returns a handle to it
     fn empty_tensor(&self, shape: &[usize], elem_size: usize) -> (Handle, Vec<usize>);
 
+    /// Fills the memory of the `binding` with the 4-byte `pattern` repeated
+    fn fill(&self, binding: Binding, pattern: u32);
+
+    /// Copies `data` into the memory of the `binding`
+    fn write(&self, binding: Binding, data: &[u8]);
+
+    /// Copies `data` into the memory of the `binding` on a copy stream, see
+    /// [ComputeServer::write_staged].
+    fn write_staged(&self, binding: Binding, data: &[u8]);
+
+    /// Reads the memory of the `binding` back on a copy stream, see [ComputeServer::read_staged].
+    ///
+    /// The read is submitted right away, before the returned future is polled.
+    fn read_staged(&self, binding: Binding) -> impl Future<Output = Vec<u8>> + Send + 'static;
+
+    /// Compiles the `kernel` without executing it, returning the warnings of the backend compiler.
+    fn compile(
+        &self,
+        kernel: Server::Kernel,
+        mode: ExecutionMode,
+    ) -> Result<Vec<CompileWarning>, CompilationError>;
+
+    /// The resources used by each unit of the `kernel`, compiling it if needed.
+    fn kernel_resources(
+        &self,
+        kernel: Server::Kernel,
+        mode: ExecutionMode,
+    ) -> Result<KernelResources, CompilationError>;
+
     /// Executes the `kernel` over the given `bindings`.
     ///
     /// # Safety
@@ -51,12 +97,31 @@ pub trait ComputeChannel<Server: ComputeServer>: Clone + core::fmt::Debug + Send
         mode: ExecutionMode,
     );
 
+    /// Set the observer called on every kernel dispatch, or remove it with `None`.
+    fn set_launch_observer(&self, observer: Option<LaunchObserver>);
+
+    /// Start recording the executed kernels to submit them together.
+    fn start_batch(&self);
+
+    /// Submit the kernels recorded since the batch started.
+    fn end_batch(&self);
+
     /// Flush outstanding work of the server.
     fn flush(&self);
 
     /// Wait for the completion of every task in the server.
     fn sync(&self) -> impl Future<Output = ()> + Send;
 
+    /// Wait for the completion of every task in the server, returning an error if a kernel
+    /// faulted.
+    fn try_sync(&self) -> impl Future<Output = Result<(), DeviceError>> + Send;
+
+    /// Check without blocking whether every task submitted to the server is done.
+    fn poll(&self) -> bool;
+
+    /// Invoke the callback once every task submitted to the server so far is done.
+    fn on_complete(&self, callback: HostCallback);
+
     /// Get the current memory usage of the server.
     fn memory_usage(&self) -> crate::memory_management::MemoryUsage;
 
@@ -1,8 +1,13 @@
 use super::ComputeChannel;
-use crate::server::{Binding, BindingWithMeta, Bindings, ComputeServer, CubeCount, Handle};
+use crate::memory_management::AllocationError;
+use crate::server::{
+    Binding, BindingWithMeta, Bindings, CompilationError, CompileWarning, ComputeServer, CubeCount,
+    DeviceError, Handle, HostCallback, KernelResources, LaunchObserver,
+};
 use crate::storage::{BindingResource, ComputeStorage};
 use alloc::sync::Arc;
 use alloc::vec::Vec;
+use core::future::Future;
 use cubecl_common::ExecutionMode;
 use cubecl_common::benchmark::ProfileDuration;
 
@@ -60,6 +65,10 @@ where
         future.await
     }
 
+    fn read_into(&self, binding: Binding, destination: &mut [u8]) {
+        self.server.borrow_mut().read_into(binding, destination)
+    }
+
     fn get_resource(
         &self,
         binding: Binding,
@@ -86,10 +95,50 @@ where
         self.server.borrow_mut().empty(size)
     }
 
+    fn try_empty(&self, size: usize) -> Result<Handle, AllocationError> {
+        self.server.borrow_mut().try_empty(size)
+    }
+
+    unsafe fn register_external(&self, ptr: u64, size: u64) -> Handle {
+        unsafe { self.server.borrow_mut().register_external(ptr, size) }
+    }
+
     fn empty_tensor(&self, shape: &[usize], elem_size: usize) -> (Handle, Vec<usize>) {
         self.server.borrow_mut().empty_tensor(shape, elem_size)
     }
 
+    fn fill(&self, binding: Binding, pattern: u32) {
+        self.server.borrow_mut().fill(binding, pattern)
+    }
+
+    fn write(&self, binding: Binding, data: &[u8]) {
+        self.server.borrow_mut().write(binding, data)
+    }
+
+    fn write_staged(&self, binding: Binding, data: &[u8]) {
+        self.server.borrow_mut().write_staged(binding, data)
+    }
+
+    fn read_staged(&self, binding: Binding) -> impl Future<Output = Vec<u8>> + Send + 'static {
+        self.server.borrow_mut().read_staged(binding)
+    }
+
+    fn compile(
+        &self,
+        kernel: Server::Kernel,
+        mode: ExecutionMode,
+    ) -> Result<Vec<CompileWarning>, CompilationError> {
+        self.server.borrow_mut().compile(kernel, mode)
+    }
+
+    fn kernel_resources(
+        &self,
+        kernel: Server::Kernel,
+        mode: ExecutionMode,
+    ) -> Result<KernelResources, CompilationError> {
+        self.server.borrow_mut().kernel_resources(kernel, mode)
+    }
+
     unsafe fn execute(
         &self,
         kernel_description: Server::Kernel,
@@ -104,6 +153,18 @@ where
         }
     }
 
+    fn set_launch_observer(&self, observer: Option<LaunchObserver>) {
+        self.server.borrow_mut().set_launch_observer(observer)
+    }
+
+    fn start_batch(&self) {
+        self.server.borrow_mut().start_batch()
+    }
+
+    fn end_batch(&self) {
+        self.server.borrow_mut().end_batch()
+    }
+
     fn flush(&self) {
         self.server.borrow_mut().flush()
     }
@@ -116,6 +177,22 @@ where
         future.await
     }
 
+    async fn try_sync(&self) -> Result<(), DeviceError> {
+        let future = {
+            let mut server = self.server.borrow_mut();
+            server.try_sync()
+        };
+        future.await
+    }
+
+    fn poll(&self) -> bool {
+        self.server.borrow_mut().poll()
+    }
+
+    fn on_complete(&self, callback: HostCallback) {
+        self.server.borrow_mut().on_complete(callback)
+    }
+
     fn memory_usage(&self) -> crate::memory_management::MemoryUsage {
         self.server.borrow_mut().memory_usage()
     }
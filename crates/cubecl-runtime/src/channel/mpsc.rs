@@ -1,11 +1,14 @@
-use std::{sync::Arc, thread};
+use std::{future::Future, pin::Pin, sync::Arc, thread};
 
 use cubecl_common::{ExecutionMode, benchmark::ProfileDuration};
 
 use super::ComputeChannel;
 use crate::{
-    memory_management::MemoryUsage,
-    server::{Binding, BindingWithMeta, Bindings, ComputeServer, CubeCount, Handle},
+    memory_management::{AllocationError, MemoryUsage},
+    server::{
+        Binding, BindingWithMeta, Bindings, CompilationError, CompileWarning, ComputeServer,
+        CubeCount, DeviceError, Handle, HostCallback, KernelResources, LaunchObserver,
+    },
     storage::{BindingResource, ComputeStorage},
 };
 
@@ -30,12 +33,27 @@ where
 
 type Callback<Response> = async_channel::Sender<Response>;
 
+/// A [read_staged](ComputeChannel::read_staged) submitted by the server thread, awaited by the
+/// caller so the server keeps processing messages while the copy is in flight.
+type StagedRead = Pin<Box<dyn Future<Output = Vec<u8>> + Send>>;
+
+/// Destination of a [read_into](ComputeChannel::read_into), sent to the server thread.
+///
+/// The caller blocks until the server is done writing, so the slice outlives the access.
+struct HostSlice {
+    ptr: *mut u8,
+    len: usize,
+}
+
+unsafe impl Send for HostSlice {}
+
 enum Message<Server>
 where
     Server: ComputeServer,
 {
     Read(Vec<Binding>, Callback<Vec<Vec<u8>>>),
     ReadTensor(Vec<BindingWithMeta>, Callback<Vec<Vec<u8>>>),
+    ReadInto(Binding, HostSlice, Callback<()>),
     GetResource(
         Binding,
         Callback<BindingResource<<Server::Storage as ComputeStorage>::Resource>>,
@@ -43,10 +61,30 @@ where
     Create(Vec<u8>, Callback<Handle>),
     CreateTensor(Vec<u8>, Vec<usize>, usize, Callback<(Handle, Vec<usize>)>),
     Empty(usize, Callback<Handle>),
+    TryEmpty(usize, Callback<Result<Handle, AllocationError>>),
+    RegisterExternal(u64, u64, Callback<Handle>),
     EmptyTensor(Vec<usize>, usize, Callback<(Handle, Vec<usize>)>),
+    Fill(Binding, u32),
+    Write(Binding, Vec<u8>),
+    WriteStaged(Binding, Vec<u8>),
+    ReadStaged(Binding, Callback<StagedRead>),
+    Compile(
+        (Server::Kernel, ExecutionMode),
+        Callback<Result<Vec<CompileWarning>, CompilationError>>,
+    ),
+    KernelResources(
+        (Server::Kernel, ExecutionMode),
+        Callback<Result<KernelResources, CompilationError>>,
+    ),
     ExecuteKernel((Server::Kernel, CubeCount, ExecutionMode), Bindings),
+    SetLaunchObserver(Option<LaunchObserver>),
+    StartBatch,
+    EndBatch,
     Flush,
     Sync(Callback<()>),
+    TrySync(Callback<Result<(), DeviceError>>),
+    Poll(Callback<bool>),
+    OnComplete(HostCallback),
     MemoryUsage(Callback<MemoryUsage>),
     MemoryCleanup,
     StartProfile,
@@ -75,6 +113,13 @@ where
                             let data = server.read_tensor(bindings).await;
                             callback.send(data).await.unwrap();
                         }
+                        Message::ReadInto(binding, destination, callback) => {
+                            let destination = unsafe {
+                                core::slice::from_raw_parts_mut(destination.ptr, destination.len)
+                            };
+                            server.read_into(binding, destination);
+                            callback.send(()).await.unwrap();
+                        }
                         Message::GetResource(binding, callback) => {
                             let data = server.get_resource(binding);
                             callback.send(data).await.unwrap();
@@ -91,17 +136,65 @@ where
                             let handle = server.empty(size);
                             callback.send(handle).await.unwrap();
                         }
+                        Message::TryEmpty(size, callback) => {
+                            let handle = server.try_empty(size);
+                            callback.send(handle).await.unwrap();
+                        }
+                        Message::RegisterExternal(ptr, size, callback) => {
+                            let handle = unsafe { server.register_external(ptr, size) };
+                            callback.send(handle).await.unwrap();
+                        }
                         Message::EmptyTensor(shape, elem_size, callback) => {
                             let handle = server.empty_tensor(&shape, elem_size);
                             callback.send(handle).await.unwrap();
                         }
+                        Message::Fill(binding, pattern) => {
+                            server.fill(binding, pattern);
+                        }
+                        Message::Write(binding, data) => {
+                            server.write(binding, &data);
+                        }
+                        Message::WriteStaged(binding, data) => {
+                            server.write_staged(binding, &data);
+                        }
+                        Message::ReadStaged(binding, callback) => {
+                            let read: StagedRead = Box::pin(server.read_staged(binding));
+                            callback.send(read).await.unwrap();
+                        }
+                        Message::Compile((kernel, mode), callback) => {
+                            let result = server.compile(kernel, mode);
+                            callback.send(result).await.unwrap();
+                        }
+                        Message::KernelResources((kernel, mode), callback) => {
+                            let result = server.kernel_resources(kernel, mode);
+                            callback.send(result).await.unwrap();
+                        }
                         Message::ExecuteKernel(kernel, bindings) => unsafe {
                             server.execute(kernel.0, kernel.1, bindings, kernel.2);
                         },
+                        Message::SetLaunchObserver(observer) => {
+                            server.set_launch_observer(observer);
+                        }
                         Message::Sync(callback) => {
                             server.sync().await;
                             callback.send(()).await.unwrap();
                         }
+                        Message::TrySync(callback) => {
+                            let result = server.try_sync().await;
+                            callback.send(result).await.unwrap();
+                        }
+                        Message::Poll(callback) => {
+                            callback.send(server.poll()).await.unwrap();
+                        }
+                        Message::OnComplete(callback) => {
+                            server.on_complete(callback);
+                        }
+                        Message::StartBatch => {
+                            server.start_batch();
+                        }
+                        Message::EndBatch => {
+                            server.end_batch();
+                        }
                         Message::Flush => {
                             server.flush();
                         }
@@ -160,6 +253,21 @@ where
         handle_response(response.recv().await)
     }
 
+    fn read_into(&self, binding: Binding, destination: &mut [u8]) {
+        let (callback, response) = async_channel::unbounded();
+        let destination = HostSlice {
+            ptr: destination.as_mut_ptr(),
+            len: destination.len(),
+        };
+
+        self.state
+            .sender
+            .send_blocking(Message::ReadInto(binding, destination, callback))
+            .unwrap();
+
+        handle_response(response.recv_blocking())
+    }
+
     fn get_resource(
         &self,
         binding: Binding,
@@ -216,6 +324,26 @@ where
         handle_response(response.recv_blocking())
     }
 
+    fn try_empty(&self, size: usize) -> Result<Handle, AllocationError> {
+        let (callback, response) = async_channel::unbounded();
+        self.state
+            .sender
+            .send_blocking(Message::TryEmpty(size, callback))
+            .unwrap();
+
+        handle_response(response.recv_blocking())
+    }
+
+    unsafe fn register_external(&self, ptr: u64, size: u64) -> Handle {
+        let (callback, response) = async_channel::unbounded();
+        self.state
+            .sender
+            .send_blocking(Message::RegisterExternal(ptr, size, callback))
+            .unwrap();
+
+        handle_response(response.recv_blocking())
+    }
+
     fn empty_tensor(&self, shape: &[usize], elem_size: usize) -> (Handle, Vec<usize>) {
         let (callback, response) = async_channel::unbounded();
         self.state
@@ -226,6 +354,64 @@ where
         handle_response(response.recv_blocking())
     }
 
+    fn fill(&self, binding: Binding, pattern: u32) {
+        self.state
+            .sender
+            .send_blocking(Message::Fill(binding, pattern))
+            .unwrap();
+    }
+
+    fn write(&self, binding: Binding, data: &[u8]) {
+        self.state
+            .sender
+            .send_blocking(Message::Write(binding, data.to_vec()))
+            .unwrap();
+    }
+
+    fn write_staged(&self, binding: Binding, data: &[u8]) {
+        self.state
+            .sender
+            .send_blocking(Message::WriteStaged(binding, data.to_vec()))
+            .unwrap();
+    }
+
+    fn read_staged(&self, binding: Binding) -> impl Future<Output = Vec<u8>> + Send + 'static {
+        let (callback, response) = async_channel::unbounded();
+
+        self.state
+            .sender
+            .send_blocking(Message::ReadStaged(binding, callback))
+            .unwrap();
+
+        async move { handle_response(response.recv().await).await }
+    }
+
+    fn compile(
+        &self,
+        kernel: Server::Kernel,
+        mode: ExecutionMode,
+    ) -> Result<Vec<CompileWarning>, CompilationError> {
+        let (callback, response) = async_channel::unbounded();
+        self.state
+            .sender
+            .send_blocking(Message::Compile((kernel, mode), callback))
+            .unwrap();
+        handle_response(response.recv_blocking())
+    }
+
+    fn kernel_resources(
+        &self,
+        kernel: Server::Kernel,
+        mode: ExecutionMode,
+    ) -> Result<KernelResources, CompilationError> {
+        let (callback, response) = async_channel::unbounded();
+        self.state
+            .sender
+            .send_blocking(Message::KernelResources((kernel, mode), callback))
+            .unwrap();
+        handle_response(response.recv_blocking())
+    }
+
     unsafe fn execute(
         &self,
         kernel: Server::Kernel,
@@ -239,10 +425,35 @@ where
             .unwrap();
     }
 
+    fn set_launch_observer(&self, observer: Option<LaunchObserver>) {
+        self.state
+            .sender
+            .send_blocking(Message::SetLaunchObserver(observer))
+            .unwrap();
+    }
+
+    fn start_batch(&self) {
+        self.state
+            .sender
+            .send_blocking(Message::StartBatch)
+            .unwrap()
+    }
+
+    fn end_batch(&self) {
+        self.state.sender.send_blocking(Message::EndBatch).unwrap()
+    }
+
     fn flush(&self) {
         self.state.sender.send_blocking(Message::Flush).unwrap()
     }
 
+    fn on_complete(&self, callback: HostCallback) {
+        self.state
+            .sender
+            .send_blocking(Message::OnComplete(callback))
+            .unwrap()
+    }
+
     async fn sync(&self) {
         let (callback, response) = async_channel::unbounded();
         self.state
@@ -253,6 +464,25 @@ where
         handle_response(response.recv().await)
     }
 
+    async fn try_sync(&self) -> Result<(), DeviceError> {
+        let (callback, response) = async_channel::unbounded();
+        self.state
+            .sender
+            .send(Message::TrySync(callback))
+            .await
+            .unwrap();
+        handle_response(response.recv().await)
+    }
+
+    fn poll(&self) -> bool {
+        let (callback, response) = async_channel::unbounded();
+        self.state
+            .sender
+            .send_blocking(Message::Poll(callback))
+            .unwrap();
+        handle_response(response.recv_blocking())
+    }
+
     fn memory_usage(&self) -> crate::memory_management::MemoryUsage {
         let (callback, response) = async_channel::unbounded();
         self.state
@@ -1,8 +1,13 @@
 use super::ComputeChannel;
-use crate::server::{Binding, BindingWithMeta, Bindings, ComputeServer, CubeCount, Handle};
+use crate::memory_management::AllocationError;
+use crate::server::{
+    Binding, BindingWithMeta, Bindings, CompilationError, CompileWarning, ComputeServer, CubeCount,
+    DeviceError, Handle, HostCallback, KernelResources, LaunchObserver,
+};
 use crate::storage::{BindingResource, ComputeStorage};
 use alloc::sync::Arc;
 use alloc::vec::Vec;
+use core::future::Future;
 use cubecl_common::ExecutionMode;
 use cubecl_common::benchmark::ProfileDuration;
 use spin::Mutex;
@@ -57,6 +62,10 @@ where
         fut.await
     }
 
+    fn read_into(&self, binding: Binding, destination: &mut [u8]) {
+        self.server.lock().read_into(binding, destination)
+    }
+
     fn get_resource(
         &self,
         binding: Binding,
@@ -81,10 +90,50 @@ where
         self.server.lock().empty(size)
     }
 
+    fn try_empty(&self, size: usize) -> Result<Handle, AllocationError> {
+        self.server.lock().try_empty(size)
+    }
+
+    unsafe fn register_external(&self, ptr: u64, size: u64) -> Handle {
+        unsafe { self.server.lock().register_external(ptr, size) }
+    }
+
     fn empty_tensor(&self, shape: &[usize], elem_size: usize) -> (Handle, Vec<usize>) {
         self.server.lock().empty_tensor(shape, elem_size)
     }
 
+    fn fill(&self, binding: Binding, pattern: u32) {
+        self.server.lock().fill(binding, pattern)
+    }
+
+    fn write(&self, binding: Binding, data: &[u8]) {
+        self.server.lock().write(binding, data)
+    }
+
+    fn write_staged(&self, binding: Binding, data: &[u8]) {
+        self.server.lock().write_staged(binding, data)
+    }
+
+    fn read_staged(&self, binding: Binding) -> impl Future<Output = Vec<u8>> + Send + 'static {
+        self.server.lock().read_staged(binding)
+    }
+
+    fn compile(
+        &self,
+        kernel: Server::Kernel,
+        mode: ExecutionMode,
+    ) -> Result<Vec<CompileWarning>, CompilationError> {
+        self.server.lock().compile(kernel, mode)
+    }
+
+    fn kernel_resources(
+        &self,
+        kernel: Server::Kernel,
+        mode: ExecutionMode,
+    ) -> Result<KernelResources, CompilationError> {
+        self.server.lock().kernel_resources(kernel, mode)
+    }
+
     unsafe fn execute(
         &self,
         kernel: Server::Kernel,
@@ -95,6 +144,18 @@ where
         unsafe { self.server.lock().execute(kernel, count, handles, kind) }
     }
 
+    fn set_launch_observer(&self, observer: Option<LaunchObserver>) {
+        self.server.lock().set_launch_observer(observer)
+    }
+
+    fn start_batch(&self) {
+        self.server.lock().start_batch();
+    }
+
+    fn end_batch(&self) {
+        self.server.lock().end_batch();
+    }
+
     fn flush(&self) {
         self.server.lock().flush();
     }
@@ -109,6 +170,22 @@ where
         fut.await
     }
 
+    async fn try_sync(&self) -> Result<(), DeviceError> {
+        let future = {
+            let mut server = self.server.lock();
+            server.try_sync()
+        };
+        future.await
+    }
+
+    fn poll(&self) -> bool {
+        self.server.lock().poll()
+    }
+
+    fn on_complete(&self, callback: HostCallback) {
+        self.server.lock().on_complete(callback)
+    }
+
     fn memory_usage(&self) -> crate::memory_management::MemoryUsage {
         self.server.lock().memory_usage()
     }
@@ -1,15 +1,28 @@
 use crate::{
-    DeviceProperties, TimeMeasurement,
+    BackendInfo, DeviceProperties, TimeMeasurement,
     channel::ComputeChannel,
-    memory_management::MemoryUsage,
-    server::{Binding, BindingWithMeta, Bindings, ComputeServer, CubeCount, Handle},
-    storage::{BindingResource, ComputeStorage},
+    memory_management::{AllocationError, MemoryUsage},
+    server::{
+        Binding, BindingWithMeta, Bindings, CompilationError, CompileWarning, ComputeServer,
+        CubeCount, DeviceError, Handle, LaunchFeasibility, LaunchObserver,
+    },
+    storage::{BindingResource, ComputeStorage, DeviceAddress},
 };
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::string::String;
 use alloc::sync::Arc;
 use alloc::vec::Vec;
-use cubecl_common::{ExecutionMode, benchmark::ProfileDuration};
+use cubecl_common::{
+    CubeDim, ExecutionMode,
+    benchmark::{ProfileDuration, ProfileTimeline},
+};
 use spin::Mutex;
 
+/// The number of bytes copied at once by [upload_from_mmap](ComputeClient::upload_from_mmap).
+#[cfg(mmap)]
+pub const UPLOAD_CHUNK_SIZE: usize = 16 * 1024 * 1024;
+
 /// The ComputeClient is the entry point to require tasks from the ComputeServer.
 /// It should be obtained for a specific device via the Compute struct.
 #[derive(Debug)]
@@ -39,11 +52,30 @@ where
     }
 }
 
+/// Ends the batch of its client when dropped, including while unwinding.
+struct BatchGuard<'a, Server: ComputeServer, Channel: ComputeChannel<Server>> {
+    client: &'a ComputeClient<Server, Channel>,
+}
+
+impl<Server: ComputeServer, Channel: ComputeChannel<Server>> Drop
+    for BatchGuard<'_, Server, Channel>
+{
+    fn drop(&mut self) {
+        self.client.channel.end_batch();
+    }
+}
+
 impl<Server, Channel> ComputeClient<Server, Channel>
 where
     Server: ComputeServer,
     Channel: ComputeChannel<Server>,
 {
+    /// Whether both clients communicate with the same server, e.g. when they were retrieved for
+    /// the same device.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.state, &other.state)
+    }
+
     /// Get the info of the current backend.
     pub fn info(&self) -> &Server::Info {
         &self.state.info
@@ -125,6 +157,24 @@ where
         cubecl_common::reader::read_sync(self.channel.read_tensor([binding].into())).remove(0)
     }
 
+    /// Copies the content of the binding into `destination` without allocating any host buffer.
+    ///
+    /// # Remarks
+    ///
+    /// Blocks until the copy is done. Panics if the size of `destination` doesn't match the size
+    /// of the binding.
+    pub fn read_into(&self, binding: Binding, destination: &mut [u8]) {
+        self.channel.read_into(binding, destination)
+    }
+
+    /// Copies the content of the binding into a slice of elements without allocating any host
+    /// buffer.
+    ///
+    /// See [ComputeClient::read_into]
+    pub fn read_into_typed<E: bytemuck::Pod>(&self, binding: Binding, destination: &mut [E]) {
+        self.read_into(binding, bytemuck::cast_slice_mut(destination))
+    }
+
     /// Given a resource handle, returns the storage resource.
     pub fn get_resource(
         &self,
@@ -133,6 +183,31 @@ where
         self.channel.get_resource(binding)
     }
 
+    /// Returns the raw device address of the binding, its offset included, to share the memory
+    /// with external libraries.
+    ///
+    /// The address is only valid while the binding is alive: once it is dropped, the memory can be
+    /// reused for other allocations. Pending kernels writing to the binding aren't waited for,
+    /// call [sync](Self::sync) before handing the address to code running outside of the client.
+    pub fn device_ptr(&self, binding: Binding) -> u64
+    where
+        <Server::Storage as ComputeStorage>::Resource: DeviceAddress,
+    {
+        self.get_resource(binding).resource().device_ptr()
+    }
+
+    /// Wraps `size` bytes of device memory allocated outside of CubeCL, e.g. by another library,
+    /// starting at the raw device address `ptr`, in a handle usable like any other.
+    ///
+    /// # Safety
+    ///
+    /// The client never frees the memory, the caller keeps its ownership. It must stay valid
+    /// until the handle and all its bindings are dropped and every kernel using it has completed.
+    /// Only backends sharing the address space of the external library support this.
+    pub unsafe fn register_external(&self, ptr: u64, size: u64) -> Handle {
+        unsafe { self.channel.register_external(ptr, size) }
+    }
+
     /// Given a resource, stores it and returns the resource handle.
     pub fn create(&self, data: &[u8]) -> Handle {
         self.channel.create(data)
@@ -160,17 +235,211 @@ where
         self.channel.create_tensor(data, shape, elem_size)
     }
 
+    /// Whether the two handles point to overlapping device memory, in which case writing to one
+    /// in place can clobber the other. See [Handle::aliases].
+    pub fn aliases(&self, a: &Handle, b: &Handle) -> bool {
+        a.aliases(b)
+    }
+
     /// Reserves `size` bytes in the storage, and returns a handle over them.
     pub fn empty(&self, size: usize) -> Handle {
         self.channel.empty(size)
     }
 
+    /// Reserves `size` bytes in the storage, and returns a handle over them.
+    ///
+    /// Unlike [ComputeClient::empty], this returns an error instead of panicking when the
+    /// reservation would exceed the memory limit configured for the device, which allows to
+    /// recover, e.g. by freeing some tensors or by processing the data in smaller chunks.
+    pub fn try_empty(&self, size: usize) -> Result<Handle, AllocationError> {
+        self.channel.try_empty(size)
+    }
+
     /// Reserves `shape` in the storage, and returns a tensor handle for it.
     /// See [ComputeClient::create_tensor]
     pub fn empty_tensor(&self, shape: &[usize], elem_size: usize) -> (Handle, Vec<usize>) {
         self.channel.empty_tensor(shape, elem_size)
     }
 
+    /// Reserves `size` bytes in the storage, sets them to zero on the device, and returns a handle
+    /// over them.
+    pub fn zeros(&self, size: usize) -> Handle {
+        let handle = self.empty(size);
+        self.fill(&handle, 0);
+        handle
+    }
+
+    /// Fills the memory of the `handle` with the 4-byte `pattern` repeated, stored in
+    /// little-endian order.
+    pub fn fill(&self, handle: &Handle, pattern: u32) {
+        self.channel.fill(handle.clone().binding(), pattern)
+    }
+
+    /// Copies `data` into the memory of the `handle`, which must have the same size.
+    ///
+    /// Handles with [offsets](Handle::offset_start) write a part of their buffer.
+    pub fn write(&self, handle: &Handle, data: &[u8]) {
+        self.channel.write(handle.clone().binding(), data)
+    }
+
+    /// Memory-maps `size` bytes of the file at `path` starting at byte `offset` and uploads them
+    /// to a new handle, without reading the file into a host buffer.
+    ///
+    /// The mapping is uploaded in chunks of [UPLOAD_CHUNK_SIZE] bytes, so only the pages of the
+    /// chunk being copied need to be resident and files larger than the host memory can be
+    /// loaded.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated, e.g. by another process, until the function
+    /// returns.
+    #[cfg(mmap)]
+    pub unsafe fn upload_from_mmap(
+        &self,
+        path: impl AsRef<std::path::Path>,
+        offset: u64,
+        size: usize,
+    ) -> std::io::Result<Handle> {
+        let file = std::fs::File::open(path)?;
+        if offset + size as u64 > file.metadata()?.len() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "the range to upload goes past the end of the file",
+            ));
+        }
+
+        let handle = self.empty(size);
+        if size == 0 {
+            return Ok(handle);
+        }
+
+        let mmap = unsafe {
+            memmap2::MmapOptions::new()
+                .offset(offset)
+                .len(size)
+                .map(&file)?
+        };
+
+        let mut start = 0;
+        while start < size {
+            let end = usize::min(start + UPLOAD_CHUNK_SIZE, size);
+            let chunk = handle
+                .clone()
+                .offset_start(start as u64)
+                .offset_end((size - end) as u64);
+            self.write(&chunk, &mmap[start..end]);
+            start = end;
+        }
+
+        Ok(handle)
+    }
+
+    /// Compiles the `kernel` ahead of time without executing it.
+    ///
+    /// Returns the reason of the failure when the kernel can't be compiled for this device, which
+    /// allows to react to specific failures, e.g. by picking a smaller tile size when too much
+    /// shared memory is requested. On success, returns the warnings reported by the backend
+    /// compiler, like register spills, for backends that report them.
+    pub fn compile(&self, kernel: Server::Kernel) -> Result<Vec<CompileWarning>, CompilationError> {
+        self.channel.compile(kernel, ExecutionMode::Checked)
+    }
+
+    /// Check whether the `kernel` can be launched with cubes of `cube_dim` units using
+    /// `shared_bytes` bytes of shared memory, without launching it, or which limit of the device
+    /// the configuration exceeds.
+    ///
+    /// This allows to prune configurations that can't run, e.g. while autotuning. The block size
+    /// and shared memory are checked against the
+    /// [hardware properties](crate::memory_management::HardwareProperties) first. The kernel is
+    /// then compiled to check the registers used by its units, on the backends reporting them.
+    pub fn can_launch(
+        &self,
+        kernel: Server::Kernel,
+        cube_dim: CubeDim,
+        shared_bytes: usize,
+    ) -> LaunchFeasibility {
+        let hardware = self.properties().hardware_properties();
+        let units = cube_dim.num_elems();
+        let max_dim = hardware.max_cube_dim;
+
+        if units > hardware.max_units_per_cube {
+            return LaunchFeasibility::UnitsPerCubeExceeded {
+                requested: units,
+                max: hardware.max_units_per_cube,
+            };
+        }
+        if cube_dim.x > max_dim.x || cube_dim.y > max_dim.y || cube_dim.z > max_dim.z {
+            return LaunchFeasibility::CubeDimExceeded {
+                requested: cube_dim,
+                max: max_dim,
+            };
+        }
+        if shared_bytes > hardware.max_shared_memory_size {
+            return LaunchFeasibility::SharedMemoryExceeded {
+                requested: shared_bytes,
+                max: hardware.max_shared_memory_size,
+            };
+        }
+
+        let resources = match self
+            .channel
+            .kernel_resources(kernel, ExecutionMode::Checked)
+        {
+            Ok(resources) => resources,
+            Err(err) => return LaunchFeasibility::Uncompilable(err),
+        };
+        let registers = resources
+            .registers_per_unit
+            .zip(hardware.max_registers_per_cube);
+        match registers {
+            Some((per_unit, max)) if per_unit * units > max => {
+                LaunchFeasibility::RegistersExceeded {
+                    requested: per_unit * units,
+                    max,
+                }
+            }
+            _ => LaunchFeasibility::Feasible,
+        }
+    }
+
+    /// Compiles a batch of `kernels` up front, each with the execution mode it will be launched
+    /// with, so that their first launch doesn't pay for the compilation.
+    ///
+    /// Compiled kernels are cached by the server like the ones compiled at launch time. Stops at
+    /// the first kernel that can't be compiled, returning its error. On success, returns the
+    /// warnings reported by the backend compiler for all the kernels.
+    pub fn precompile(
+        &self,
+        kernels: impl IntoIterator<Item = (Server::Kernel, ExecutionMode)>,
+    ) -> Result<Vec<CompileWarning>, CompilationError> {
+        let mut warnings = Vec::new();
+        for (kernel, mode) in kernels {
+            warnings.extend(self.channel.compile(kernel, mode)?);
+        }
+        Ok(warnings)
+    }
+
+    /// Same as [precompile](Self::precompile), but compiling the `kernels` on a background
+    /// thread, so that the calling thread can keep going while they are compiled.
+    ///
+    /// Each kernel is compiled by the server through the channel, like a launch, so the other
+    /// operations of the client, including launches, wait for the kernel being compiled. They
+    /// aren't blocked for the whole batch, since the channel is released between kernels.
+    /// Kernels launched before their compilation is done are compiled as usual.
+    #[cfg(all(feature = "std", not(target_family = "wasm")))]
+    pub fn precompile_in_background(
+        &self,
+        kernels: Vec<(Server::Kernel, ExecutionMode)>,
+    ) -> std::thread::JoinHandle<Result<Vec<CompileWarning>, CompilationError>>
+    where
+        Server: 'static,
+        Server::Kernel: Send,
+        Channel: 'static,
+    {
+        let client = self.clone();
+        std::thread::spawn(move || client.precompile(kernels))
+    }
+
     /// Executes the `kernel` over the given `bindings`.
     pub fn execute(&self, kernel: Server::Kernel, count: CubeCount, bindings: Bindings) {
         unsafe {
@@ -196,6 +465,97 @@ where
         }
     }
 
+    /// Processes a dataset too large for the device chunk by chunk, overlapping the transfers with
+    /// the computation.
+    ///
+    /// The chunks go through three device buffers in turn: while the input of chunk `N + 1` is
+    /// uploaded, the kernel of chunk `N` runs and the result of chunk `N - 1` is read back.
+    /// Transfers are [staged](ComputeServer::write_staged) through pinned buffers on their own
+    /// copy streams, so they only wait for the work they depend on. `kernel` receives the uploaded
+    /// input of a chunk and returns the handle of its output, which is then given to `output` in
+    /// order along with the index of the chunk.
+    ///
+    /// Backends without copy streams still keep three chunks in flight, but their transfers are
+    /// serialized with the kernels.
+    pub fn process_chunks<'a, I, K, O>(&self, chunks: I, mut kernel: K, mut output: O)
+    where
+        I: IntoIterator<Item = &'a [u8]>,
+        K: FnMut(&Self, Handle) -> Handle,
+        O: FnMut(usize, Vec<u8>),
+    {
+        // Uploading, computing and reading back.
+        const NUM_SLOTS: usize = 3;
+
+        let mut inputs: [Option<Handle>; NUM_SLOTS] = Default::default();
+        let mut in_flight = VecDeque::with_capacity(NUM_SLOTS);
+
+        for (index, data) in chunks.into_iter().enumerate() {
+            // The buffer of this chunk is free once the chunk that used it before is read back.
+            if in_flight.len() == NUM_SLOTS {
+                let (index, read, _output) = in_flight.pop_front().unwrap();
+                output(index, cubecl_common::reader::read_sync(read));
+            }
+
+            let slot = index % NUM_SLOTS;
+            let fits = inputs[slot]
+                .as_ref()
+                .is_some_and(|input| input.size() >= data.len() as u64);
+            if !fits {
+                // Allocations are only ordered on the compute stream, so the copy stream waits for
+                // them with a sync. The buffers not used yet are allocated along with this one to
+                // sync once.
+                for (other, input) in inputs.iter_mut().enumerate() {
+                    if other == slot || input.is_none() {
+                        *input = Some(self.empty(data.len()));
+                    }
+                }
+                cubecl_common::reader::read_sync(self.sync());
+            }
+            let buffer = inputs[slot].clone().unwrap();
+            let unused = buffer.size() - data.len() as u64;
+            let input = buffer.offset_end(unused);
+
+            self.channel.write_staged(input.clone().binding(), data);
+            let computed = kernel(self, input);
+            // The output is kept alive until it is read back, so its memory isn't reused before.
+            let read = self.channel.read_staged(computed.clone().binding());
+            self.flush();
+
+            in_flight.push_back((index, read, computed));
+        }
+
+        for (index, read, _output) in in_flight {
+            output(index, cubecl_common::reader::read_sync(read));
+        }
+    }
+
+    /// Set the `observer` called, in order, on every kernel dispatched by this device.
+    ///
+    /// The observer receives the [launch information](crate::server::LaunchInfo) of each kernel,
+    /// which can be used to trace or visualize a pipeline. No work is done on dispatch when no
+    /// observer is set.
+    pub fn set_launch_observer(&self, observer: LaunchObserver) {
+        self.channel.set_launch_observer(Some(observer));
+    }
+
+    /// Remove the observer set with [set_launch_observer](Self::set_launch_observer).
+    pub fn clear_launch_observer(&self) {
+        self.channel.set_launch_observer(None);
+    }
+
+    /// Run `func` while recording every kernel it launches on this client, then submit them all
+    /// at once in launch order.
+    ///
+    /// This reduces the submission overhead of long sequences of small kernels. Reading data
+    /// inside the batch still submits the kernels recorded so far. The batch is also ended when
+    /// `func` panics, so the client keeps working after the panic is caught.
+    pub fn batch<O>(&self, func: impl FnOnce(&Self) -> O) -> O {
+        self.channel.start_batch();
+        let _guard = BatchGuard { client: self };
+
+        func(self)
+    }
+
     /// Flush all outstanding commands.
     pub fn flush(&self) {
         self.channel.flush();
@@ -206,11 +566,81 @@ where
         self.channel.sync().await
     }
 
+    /// Wait for the completion of every task in the server, returning a
+    /// [fault](DeviceError::Faulted) instead of panicking when a kernel hit an illegal memory
+    /// access or a trap.
+    ///
+    /// The device can't run anything anymore after a fault, so a long-running service should stop
+    /// using the client and report the error.
+    pub async fn try_sync(&self) -> Result<(), DeviceError> {
+        self.channel.try_sync().await
+    }
+
+    /// Flush outstanding commands and check without blocking whether every submitted task is
+    /// done, so progress can be polled from an event loop.
+    pub fn poll(&self) -> bool {
+        self.channel.poll()
+    }
+
+    /// Invoke `callback` once every task submitted to this client so far is done, without
+    /// blocking, e.g. to advance a progress bar or release a staging buffer when a launch
+    /// finishes.
+    ///
+    /// The callback runs on a thread of the runtime: the driver thread on CUDA and HIP, or the
+    /// thread polling the device on wgpu, which happens when the client syncs or polls.
+    ///
+    /// # Panics
+    ///
+    /// A panic in the callback can't unwind into the driver, so on CUDA and HIP it's caught and
+    /// logged as an error, then swallowed. On wgpu it unwinds into the thread polling the device.
+    ///
+    /// # Deadlocks
+    ///
+    /// The callback must not use the client, nor any other client of the same device. It may run
+    /// while the server is locked by a thread waiting for the callback itself to return, which
+    /// would never happen. Send a message to another thread instead.
+    pub fn on_complete(&self, callback: impl FnOnce() + Send + 'static) {
+        self.channel.on_complete(Box::new(callback));
+    }
+
+    /// Wait for the completion of every task in the server for at most `timeout`, returning
+    /// whether all of them completed in time.
+    ///
+    /// The server is polled with an exponential backoff, starting at a few microseconds so short
+    /// tasks are picked up quickly and capped at a millisecond so the thread sleeps instead of
+    /// spinning on long ones.
+    #[cfg(feature = "std")]
+    pub fn sync_timeout(&self, timeout: core::time::Duration) -> bool {
+        const MIN_BACKOFF: core::time::Duration = core::time::Duration::from_micros(10);
+        const MAX_BACKOFF: core::time::Duration = core::time::Duration::from_millis(1);
+
+        let start = std::time::Instant::now();
+        let mut backoff = MIN_BACKOFF;
+
+        loop {
+            if self.poll() {
+                return true;
+            }
+            let remaining = timeout.saturating_sub(start.elapsed());
+            if remaining.is_zero() {
+                return false;
+            }
+            std::thread::sleep(backoff.min(remaining));
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+        }
+    }
+
     /// Get the features supported by the compute server.
     pub fn properties(&self) -> &DeviceProperties<Server::Feature> {
         &self.state.properties
     }
 
+    /// Get the name and versions of the backend and device, e.g. to log them with bug reports or
+    /// to work around known driver issues.
+    pub fn backend_info(&self) -> &BackendInfo {
+        self.state.properties.backend_info()
+    }
+
     /// Get the current memory usage of this client.
     pub fn memory_usage(&self) -> MemoryUsage {
         self.channel.memory_usage()
@@ -232,8 +662,39 @@ where
         let guard = self.state.profile_lock.lock();
         self.channel.start_profile();
         func();
+        let result = self.end_profile();
+        core::mem::drop(guard);
+        result
+    }
+
+    /// Measure the execution time between named markers placed with [ProfileMarkers::mark],
+    /// e.g. at the boundaries of the phases of a sequence of kernels.
+    ///
+    /// Each marker is timestamped from the start of the profile, and the operations submitted
+    /// after the last marker aren't measured. The same restrictions as [profile](Self::profile)
+    /// apply.
+    pub fn profile_markers(
+        &self,
+        func: impl FnOnce(&mut ProfileMarkers<'_, Server, Channel>),
+    ) -> ProfileTimeline {
+        let guard = self.state.profile_lock.lock();
+        self.channel.start_profile();
+
+        let mut markers = ProfileMarkers {
+            client: self,
+            timeline: ProfileTimeline::default(),
+        };
+        func(&mut markers);
+
+        // Work after the last marker isn't part of any segment.
+        core::mem::drop(self.end_profile());
+        core::mem::drop(guard);
+        markers.timeline
+    }
+
+    fn end_profile(&self) -> ProfileDuration {
         let result = self.channel.end_profile();
-        let result = match self.properties().time_measurement() {
+        match self.properties().time_measurement() {
             TimeMeasurement::Device => result,
             TimeMeasurement::System => {
                 #[cfg(target_family = "wasm")]
@@ -247,8 +708,25 @@ where
                     ProfileDuration::from_duration(duration)
                 }
             }
-        };
-        core::mem::drop(guard);
-        result
+        }
+    }
+}
+
+/// Places the markers of a [profile](ComputeClient::profile_markers).
+pub struct ProfileMarkers<'a, Server: ComputeServer, Channel> {
+    client: &'a ComputeClient<Server, Channel>,
+    timeline: ProfileTimeline,
+}
+
+impl<Server, Channel> ProfileMarkers<'_, Server, Channel>
+where
+    Server: ComputeServer,
+    Channel: ComputeChannel<Server>,
+{
+    /// Place a marker after all the operations submitted so far.
+    pub fn mark(&mut self, name: impl Into<String>) {
+        let segment = self.client.end_profile();
+        self.timeline.push(name.into(), segment);
+        self.client.channel.start_profile();
     }
 }
@@ -0,0 +1,38 @@
+use alloc::boxed::Box;
+use core::any::{Any, TypeId};
+use hashbrown::HashMap;
+
+/// The default clients of every runtime, keyed by the type of the runtime.
+static DEFAULT_CLIENTS: spin::Mutex<Option<HashMap<TypeId, Box<dyn Any + Send>>>> =
+    spin::Mutex::new(None);
+
+/// Set the default client of the runtime `R`, shared by all threads, replacing the previous one.
+///
+/// The client is kept alive until it's replaced or [cleared](clear). It only affects code
+/// retrieving the default client, so code given a client explicitly keeps using that client.
+pub fn set<R: 'static, Client: Send + 'static>(client: Client) {
+    DEFAULT_CLIENTS
+        .lock()
+        .get_or_insert_with(HashMap::new)
+        .insert(TypeId::of::<R>(), Box::new(client));
+}
+
+/// Get the [default client](set) of the runtime `R`, if one is set.
+pub fn get<R: 'static, Client: Clone + 'static>() -> Option<Client> {
+    DEFAULT_CLIENTS
+        .lock()
+        .as_ref()?
+        .get(&TypeId::of::<R>())?
+        .downcast_ref::<Client>()
+        .cloned()
+}
+
+/// Clear the default client of the runtime `R`, returning it if one was set.
+pub fn clear<R: 'static, Client: 'static>() -> Option<Client> {
+    let client = DEFAULT_CLIENTS
+        .lock()
+        .as_mut()?
+        .remove(&TypeId::of::<R>())?;
+
+    client.downcast::<Client>().ok().map(|client| *client)
+}
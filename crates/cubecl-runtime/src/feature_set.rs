@@ -1,5 +1,6 @@
 use crate::memory_management::{HardwareProperties, MemoryDeviceProperties};
 use alloc::collections::BTreeSet;
+use alloc::string::String;
 
 /// Properties of what the device can do, like what `Feature` are
 /// supported by it and what its memory properties are.
@@ -9,6 +10,33 @@ pub struct DeviceProperties<Feature: Ord + Copy> {
     memory: MemoryDeviceProperties,
     hardware: HardwareProperties,
     time_measurement: TimeMeasurement,
+    backend_info: BackendInfo,
+}
+
+/// Description of the backend and device a client runs on, as reported by the native APIs.
+///
+/// Fields the backend can't query are left empty.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BackendInfo {
+    /// The name of the backend, e.g. `cuda` or `vulkan`.
+    pub name: String,
+    /// The name of the device, e.g. `NVIDIA GeForce RTX 4090`.
+    pub device_name: String,
+    /// The version of the API or runtime used to run kernels, e.g. the NVRTC version for CUDA or
+    /// the Vulkan API version.
+    pub api_version: String,
+    /// The version of the driver of the device.
+    pub driver_version: String,
+}
+
+impl core::fmt::Display for BackendInfo {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{} on {} (api {}, driver {})",
+            self.name, self.device_name, self.api_version, self.driver_version
+        )
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -42,6 +70,7 @@ impl<Feature: Ord + Copy> DeviceProperties<Feature> {
             memory: memory_props,
             hardware,
             time_measurement,
+            backend_info: BackendInfo::default(),
         }
     }
 
@@ -55,6 +84,11 @@ impl<Feature: Ord + Copy> DeviceProperties<Feature> {
         self.set.contains(&feature)
     }
 
+    /// Iterate over every `Feature` supported by the runtime.
+    pub fn features(&self) -> impl Iterator<Item = &Feature> {
+        self.set.iter()
+    }
+
     /// Register a `Feature` supported by the compute server.
     ///
     /// This should only be used by a [runtime](cubecl_core::Runtime) when initializing a device.
@@ -71,4 +105,16 @@ impl<Feature: Ord + Copy> DeviceProperties<Feature> {
     pub fn hardware_properties(&self) -> &HardwareProperties {
         &self.hardware
     }
+
+    /// The description of the backend and device of this client.
+    pub fn backend_info(&self) -> &BackendInfo {
+        &self.backend_info
+    }
+
+    /// Set the description of the backend and device.
+    ///
+    /// This should only be used by a runtime when initializing a device.
+    pub fn set_backend_info(&mut self, info: BackendInfo) {
+        self.backend_info = info;
+    }
 }
@@ -18,12 +18,16 @@ pub mod client;
 /// Autotune module
 pub mod tune;
 
+/// Default client of each runtime, for code not passing a client around.
+pub mod default_client;
 /// Memory management module.
 pub mod memory_management;
 /// Compute server module.
 pub mod server;
 /// Compute Storage module.
 pub mod storage;
+/// Pool of reusable output tensors.
+pub mod tensor_pool;
 
 mod feature_set;
 
@@ -38,6 +42,10 @@ pub mod debug;
 /// TMA-related runtime types
 pub mod tma;
 
+/// Persistent cache of compiled kernel binaries.
+#[cfg(autotune_persistent_cache)]
+pub mod binary_cache;
+
 /// Simple measuring for timestamps.
 #[cfg(feature = "std")]
 pub mod kernel_timestamps;
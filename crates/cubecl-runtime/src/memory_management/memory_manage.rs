@@ -37,6 +37,13 @@ impl MemoryPool for DynamicPool {
         }
     }
 
+    fn alloc_size(&self, size: u64) -> u64 {
+        match self {
+            DynamicPool::Sliced(m) => m.alloc_size(size),
+            DynamicPool::Exclusive(m) => m.alloc_size(size),
+        }
+    }
+
     fn get_memory_usage(&self) -> MemoryUsage {
         match self {
             DynamicPool::Sliced(m) => m.get_memory_usage(),
@@ -69,6 +76,55 @@ pub struct MemoryManagement<Storage> {
     pools: Vec<DynamicPool>,
     storage: Storage,
     alloc_reserve_count: u64,
+    memory_limit: Option<u64>,
+    on_oom: OutOfMemoryPolicy,
+    external: Vec<(SliceHandle, StorageHandle)>,
+}
+
+/// What to do when a reservation would exceed the [memory limit](MemoryManagement::set_memory_limit).
+///
+/// The policy only applies when a memory limit is set. Without one, reservations go straight to
+/// the storage, whose [alloc](ComputeStorage::alloc) can't fail: a device running out of memory
+/// panics in the storage without releasing unused memory first. Set a limit below the memory of
+/// the device to handle running out of memory.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum OutOfMemoryPolicy {
+    /// Release the unused memory of every pool, e.g. free pages too small for the reservation,
+    /// then retry once before failing.
+    #[default]
+    Retry,
+    /// Fail right away, keeping the unused memory for later reservations.
+    Fail,
+}
+
+/// Error that happened while reserving memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AllocationError {
+    /// The allocation would make the memory reserved on the device exceed the
+    /// [memory limit](MemoryManagement::set_memory_limit), even after releasing unused memory.
+    MemoryLimitExceeded {
+        /// The number of bytes the allocation would reserve.
+        requested: u64,
+        /// The number of bytes already reserved.
+        reserved: u64,
+        /// The maximum number of bytes that can be reserved.
+        limit: u64,
+    },
+}
+
+impl core::fmt::Display for AllocationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::MemoryLimitExceeded {
+                requested,
+                reserved,
+                limit,
+            } => write!(
+                f,
+                "Allocating {requested} bytes with {reserved} bytes already reserved exceeds the memory limit of {limit} bytes."
+            ),
+        }
+    }
 }
 
 /// Exclude certain storage buffers from being selected when reserving memory.
@@ -256,19 +312,68 @@ impl<Storage: ComputeStorage> MemoryManagement<Storage> {
             pools,
             storage,
             alloc_reserve_count: 0,
+            memory_limit: None,
+            on_oom: OutOfMemoryPolicy::default(),
+            external: Vec::new(),
         }
     }
 
+    /// Set the maximum number of bytes that can be reserved from the storage, or remove the limit
+    /// with `None`.
+    ///
+    /// Reservations that would exceed the limit fail with an [AllocationError], after releasing
+    /// unused memory and retrying unless the [policy](Self::set_out_of_memory_policy) is
+    /// [Fail](OutOfMemoryPolicy::Fail).
+    pub fn set_memory_limit(&mut self, limit: Option<u64>) {
+        self.memory_limit = limit;
+    }
+
+    /// Set what to do when a reservation would exceed the [memory limit](Self::set_memory_limit),
+    /// it has no effect without a limit.
+    pub fn set_out_of_memory_policy(&mut self, policy: OutOfMemoryPolicy) {
+        self.on_oom = policy;
+    }
+
     /// Cleanup allocations in pools that are deemed unnecessary.
     pub fn cleanup(&mut self, explicit: bool) {
         for pool in self.pools.iter_mut() {
             pool.cleanup(&mut self.storage, self.alloc_reserve_count, explicit);
         }
+
+        let storage = &mut self.storage;
+        self.external.retain(|(handle, external)| {
+            let free = handle.is_free();
+            if free {
+                storage.dealloc(external.id);
+            }
+            !free
+        });
     }
 
     /// Returns the storage from the specified binding
     pub fn get(&mut self, binding: SliceBinding) -> Option<StorageHandle> {
-        self.pools.iter().find_map(|p| p.get(&binding)).cloned()
+        self.pools
+            .iter()
+            .find_map(|p| p.get(&binding))
+            .or_else(|| {
+                self.external
+                    .iter()
+                    .find(|(handle, _)| handle.id() == binding.id())
+                    .map(|(_, storage)| storage)
+            })
+            .cloned()
+    }
+
+    /// Wraps memory that wasn't allocated by the pools in a slice handle, e.g. memory registered
+    /// in the storage from an external library.
+    ///
+    /// The memory is never reused for other allocations. Once the handle and its bindings are
+    /// dropped, the storage is released with [dealloc](ComputeStorage::dealloc) during the next
+    /// cleanup.
+    pub fn register_external(&mut self, storage: StorageHandle) -> SliceHandle {
+        let handle = SliceHandle::new();
+        self.external.push((handle.clone(), storage));
+        handle
     }
 
     /// Returns the resource from the storage at the specified handle
@@ -294,23 +399,62 @@ impl<Storage: ComputeStorage> MemoryManagement<Storage> {
     }
 
     /// Finds a spot in memory for a resource with the given size in bytes, and returns a handle to it
+    ///
+    /// # Panics
+    ///
+    /// If the reservation exceeds the [memory limit](Self::set_memory_limit), see
+    /// [try_reserve](Self::try_reserve) to handle that case.
     pub fn reserve(&mut self, size: u64, exclude: Option<&StorageExclude>) -> SliceHandle {
+        self.try_reserve(size, exclude)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Finds a spot in memory for a resource with the given size in bytes, and returns a handle to
+    /// it, or an error if no new memory can be reserved without exceeding the
+    /// [memory limit](Self::set_memory_limit).
+    pub fn try_reserve(
+        &mut self,
+        size: u64,
+        exclude: Option<&StorageExclude>,
+    ) -> Result<SliceHandle, AllocationError> {
         // If this happens every nanosecond, counts overflows after 585 years, so not worth thinking too
         // hard about overflow here.
         self.alloc_reserve_count += 1;
 
         // Find first pool that fits this allocation
-        let pool = self
+        let index = self
             .pools
-            .iter_mut()
-            .find(|p| p.max_alloc_size() >= size)
+            .iter()
+            .position(|p| p.max_alloc_size() >= size)
             .unwrap_or_else(|| panic!("No pool handles allocation of size {size}"));
 
-        if let Some(slice) = pool.try_reserve(size, exclude) {
-            return slice;
+        if let Some(slice) = self.pools[index].try_reserve(size, exclude) {
+            return Ok(slice);
         }
 
-        pool.alloc(&mut self.storage, size)
+        if let Some(limit) = self.memory_limit {
+            let requested = self.pools[index].alloc_size(size);
+            if self.memory_usage().bytes_reserved + requested > limit {
+                if self.on_oom == OutOfMemoryPolicy::Retry {
+                    // Release unused memory before giving up.
+                    self.cleanup(true);
+
+                    if let Some(slice) = self.pools[index].try_reserve(size, exclude) {
+                        return Ok(slice);
+                    }
+                }
+                let reserved = self.memory_usage().bytes_reserved;
+                if reserved + requested > limit {
+                    return Err(AllocationError::MemoryLimitExceeded {
+                        requested,
+                        reserved,
+                        limit,
+                    });
+                }
+            }
+        }
+
+        Ok(self.pools[index].alloc(&mut self.storage, size))
     }
 
     /// Fetch the storage used by the memory manager.
@@ -362,7 +506,10 @@ impl<Storage> core::fmt::Debug for MemoryManagement<Storage> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{memory_management::MemoryManagement, storage::BytesStorage};
+    use crate::{
+        memory_management::MemoryManagement,
+        storage::{BytesStorage, StorageUtilization},
+    };
 
     const DUMMY_MEM_PROPS: MemoryDeviceProperties = MemoryDeviceProperties {
         max_page_size: 128 * 1024 * 1024,
@@ -799,4 +946,131 @@ mod tests {
         assert_eq!(usage_before.bytes_in_use, usage_after.bytes_in_use);
         assert_eq!(usage_before.bytes_reserved, usage_after.bytes_reserved);
     }
+
+    #[test]
+    fn alloc_beyond_memory_limit_returns_error() {
+        let page_size = 1024;
+
+        let mut memory_management = MemoryManagement::from_configuration(
+            BytesStorage::default(),
+            &DUMMY_MEM_PROPS,
+            MemoryConfiguration::Custom {
+                pool_options: vec![MemoryPoolOptions {
+                    pool_type: PoolType::SlicedPages {
+                        page_size,
+                        max_slice_size: page_size,
+                    },
+                    dealloc_period: None,
+                }],
+            },
+        );
+        memory_management.set_memory_limit(Some(2 * page_size));
+
+        let _handles = [
+            memory_management.try_reserve(page_size, None).unwrap(),
+            memory_management.try_reserve(page_size, None).unwrap(),
+        ];
+        let result = memory_management.try_reserve(page_size, None);
+
+        assert_eq!(
+            result.err(),
+            Some(AllocationError::MemoryLimitExceeded {
+                requested: page_size,
+                reserved: 2 * page_size,
+                limit: 2 * page_size,
+            })
+        );
+        assert_eq!(
+            memory_management.memory_usage().bytes_reserved,
+            2 * page_size
+        );
+    }
+
+    #[test]
+    fn alloc_beyond_memory_limit_retries_after_cleanup() {
+        let small_page = 256;
+        let page_size = 1024;
+
+        for policy in [OutOfMemoryPolicy::Fail, OutOfMemoryPolicy::Retry] {
+            let mut memory_management = MemoryManagement::from_configuration(
+                BytesStorage::default(),
+                &DUMMY_MEM_PROPS,
+                MemoryConfiguration::Custom {
+                    pool_options: vec![
+                        MemoryPoolOptions {
+                            pool_type: PoolType::ExclusivePages {
+                                max_alloc_size: small_page,
+                            },
+                            dealloc_period: None,
+                        },
+                        MemoryPoolOptions {
+                            pool_type: PoolType::SlicedPages {
+                                page_size,
+                                max_slice_size: page_size,
+                            },
+                            dealloc_period: None,
+                        },
+                    ],
+                },
+            );
+            memory_management.set_memory_limit(Some(page_size));
+            memory_management.set_out_of_memory_policy(policy);
+
+            // Fill the limit with small pages, which are free but too small for a large slice.
+            let handles: Vec<_> = (0..page_size / small_page)
+                .map(|_| memory_management.try_reserve(small_page, None).unwrap())
+                .collect();
+            drop(handles);
+            assert_eq!(memory_management.memory_usage().bytes_in_use, 0);
+            assert_eq!(memory_management.memory_usage().bytes_reserved, page_size);
+
+            let result = memory_management.try_reserve(page_size, None);
+
+            match policy {
+                OutOfMemoryPolicy::Fail => assert_eq!(
+                    result.err(),
+                    Some(AllocationError::MemoryLimitExceeded {
+                        requested: page_size,
+                        reserved: page_size,
+                        limit: page_size,
+                    })
+                ),
+                OutOfMemoryPolicy::Retry => {
+                    assert!(result.is_ok());
+                    assert_eq!(memory_management.memory_usage().bytes_in_use, page_size);
+                }
+            }
+            assert_eq!(memory_management.memory_usage().bytes_reserved, page_size);
+        }
+    }
+
+    #[test]
+    fn external_memory_is_forgotten_once_free() {
+        let mut memory_management = MemoryManagement::from_configuration(
+            BytesStorage::default(),
+            &DUMMY_MEM_PROPS,
+            MemoryConfiguration::ExclusivePages,
+        );
+        let storage = StorageHandle::new(
+            StorageId::new(),
+            StorageUtilization {
+                offset: 0,
+                size: 64,
+            },
+        );
+
+        let handle = memory_management.register_external(storage.clone());
+        let binding = handle.clone().binding();
+        drop(handle);
+
+        // Still bound, so it must not be released.
+        memory_management.cleanup(true);
+        let found = memory_management.get(binding.clone()).unwrap();
+        assert_eq!(found.id, storage.id);
+        assert_eq!(found.size(), 64);
+
+        drop(binding);
+        memory_management.cleanup(true);
+        assert!(memory_management.external.is_empty());
+    }
 }
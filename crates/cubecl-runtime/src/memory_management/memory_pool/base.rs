@@ -43,6 +43,10 @@ pub trait MemoryPool {
 
     fn alloc<Storage: ComputeStorage>(&mut self, storage: &mut Storage, size: u64) -> SliceHandle;
 
+    /// The number of bytes that a call to [alloc](MemoryPool::alloc) with `size` would reserve
+    /// from the storage.
+    fn alloc_size(&self, size: u64) -> u64;
+
     fn get_memory_usage(&self) -> MemoryUsage;
 
     fn cleanup<Storage: ComputeStorage>(
@@ -70,10 +70,7 @@ impl ExclusiveMemoryPool {
         storage: &mut Storage,
         size: u64,
     ) -> &mut MemoryPage {
-        let alloc_size = (self.cur_avg_size as u64)
-            .max(size)
-            .next_multiple_of(self.alignment);
-
+        let alloc_size = self.alloc_size(size);
         let storage = storage.alloc(alloc_size);
 
         let handle = SliceHandle::new();
@@ -137,6 +134,12 @@ impl MemoryPool for ExclusiveMemoryPool {
         page.slice.handle.clone()
     }
 
+    fn alloc_size(&self, size: u64) -> u64 {
+        (self.cur_avg_size as u64)
+            .max(size)
+            .next_multiple_of(self.alignment)
+    }
+
     fn get_memory_usage(&self) -> MemoryUsage {
         let used_slices: Vec<_> = self
             .pages
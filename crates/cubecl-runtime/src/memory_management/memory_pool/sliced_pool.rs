@@ -153,6 +153,10 @@ impl MemoryPool for SlicedPool {
         handle_slice
     }
 
+    fn alloc_size(&self, _size: u64) -> u64 {
+        self.page_size
+    }
+
     fn get_memory_usage(&self) -> MemoryUsage {
         let used_slices: Vec<_> = self
             .slices
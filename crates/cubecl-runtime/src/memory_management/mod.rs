@@ -116,6 +116,12 @@ pub struct HardwareProperties {
     pub num_streaming_multiprocessors: Option<u32>,
     /// Number of tensor cores per SM, if any
     pub num_tensor_cores: Option<u32>,
+    /// Peak throughput of `f32` operations in FLOP/s, if available
+    pub peak_flops: Option<f64>,
+    /// Peak bandwidth of the global memory in bytes/s, if available
+    pub peak_memory_bandwidth: Option<f64>,
+    /// Maximum number of registers used by all the units of a cube, if available
+    pub max_registers_per_cube: Option<u32>,
 }
 
 impl HardwareProperties {
@@ -1,15 +1,20 @@
 use crate::{
     memory_management::{
-        MemoryHandle, MemoryUsage,
+        AllocationError, MemoryHandle, MemoryUsage,
         memory_pool::{SliceBinding, SliceHandle},
     },
     storage::{BindingResource, ComputeStorage},
     tma::{OobFill, TensorMapFormat, TensorMapInterleave, TensorMapPrefetch, TensorMapSwizzle},
 };
+use alloc::boxed::Box;
 use alloc::collections::BTreeMap;
+use alloc::string::String;
 use alloc::vec::Vec;
-use core::{fmt::Debug, future::Future};
-use cubecl_common::{ExecutionMode, benchmark::ProfileDuration};
+use core::{
+    fmt::{Debug, Display},
+    future::Future,
+};
+use cubecl_common::{CubeDim, ExecutionMode, benchmark::ProfileDuration};
 use cubecl_ir::Elem;
 
 /// The compute server is responsible for handling resources and computations over resources.
@@ -41,6 +46,10 @@ where
         bindings: Vec<BindingWithMeta>,
     ) -> impl Future<Output = Vec<Vec<u8>>> + Send + 'static;
 
+    /// Copies the content of the `binding` into `destination`, which must have the same size,
+    /// without allocating any intermediate host buffer. Blocks until the copy is done.
+    fn read_into(&mut self, binding: Binding, destination: &mut [u8]);
+
     /// Given a resource handle, returns the storage resource.
     fn get_resource(
         &mut self,
@@ -65,9 +74,76 @@ where
     /// Reserves `size` bytes in the storage, and returns a handle over them.
     fn empty(&mut self, size: usize) -> Handle;
 
+    /// Reserves `size` bytes in the storage like [empty](ComputeServer::empty), but returns an
+    /// error instead of panicking when the memory limit of the server would be exceeded.
+    fn try_empty(&mut self, size: usize) -> Result<Handle, AllocationError>;
+
+    /// Wraps `size` bytes of device memory allocated outside of the server, starting at the raw
+    /// device address `ptr`, in a handle.
+    ///
+    /// # Safety
+    ///
+    /// The memory is never freed by the server, the caller keeps its ownership. It must stay
+    /// valid until the handle and all its bindings are dropped and every kernel using it has
+    /// completed.
+    unsafe fn register_external(&mut self, ptr: u64, size: u64) -> Handle;
+
     /// Reserves `shape` bytes in the storage, and returns a handle to it.
     fn empty_tensor(&mut self, shape: &[usize], elem_size: usize) -> (Handle, Vec<usize>);
 
+    /// Fills the memory of the `binding` with the 4-byte `pattern` repeated, stored in
+    /// little-endian order. Done on the device without any host transfer when the backend
+    /// supports it.
+    fn fill(&mut self, binding: Binding, pattern: u32);
+
+    /// Copies `data` into the memory of the `binding`, which must have the same size.
+    ///
+    /// Backends copying in 4-byte words may overwrite the bytes after the end of the binding up
+    /// to the next multiple of 4, which are always part of the allocation when the binding ends
+    /// with its handle.
+    fn write(&mut self, binding: Binding, data: &[u8]);
+
+    /// Copies `data` into the memory of the `binding` on a copy stream, through a pinned staging
+    /// buffer, so the upload overlaps with the kernels already submitted. The kernels submitted
+    /// afterwards wait for the copy.
+    ///
+    /// The kernels submitted before must be done with the memory of the `binding`, and the memory
+    /// must already be allocated on the device. Backends without copy streams fall back to
+    /// [write](Self::write).
+    fn write_staged(&mut self, binding: Binding, data: &[u8]) {
+        self.write(binding, data)
+    }
+
+    /// Reads the memory of the `binding` back on a copy stream, through a pinned staging buffer,
+    /// once the kernels submitted before are done.
+    ///
+    /// Unlike [read](Self::read), waiting for the result doesn't wait for the kernels submitted
+    /// afterwards on backends with copy streams. The `binding` must be kept alive until the result
+    /// is ready.
+    fn read_staged(&mut self, binding: Binding) -> impl Future<Output = Vec<u8>> + Send + 'static {
+        let read = self.read(alloc::vec![binding]);
+        async move { read.await.remove(0) }
+    }
+
+    /// Compiles the `kernel` without executing it, caching the result so later executions of the
+    /// same kernel don't need to compile it again.
+    ///
+    /// Returns the warnings reported by the backend compiler, which are kept with the cached
+    /// kernel so compiling it again returns them as well.
+    fn compile(
+        &mut self,
+        kernel: Self::Kernel,
+        mode: ExecutionMode,
+    ) -> Result<Vec<CompileWarning>, CompilationError>;
+
+    /// The resources used by each unit of the `kernel`, compiling it like
+    /// [compile](ComputeServer::compile) if it isn't already.
+    fn kernel_resources(
+        &mut self,
+        kernel: Self::Kernel,
+        mode: ExecutionMode,
+    ) -> Result<KernelResources, CompilationError>;
+
     /// Executes the `kernel` over the given memory `handles`.
     ///
     /// Kernels have mutable access to every resource they are given
@@ -84,12 +160,37 @@ where
         kind: ExecutionMode,
     );
 
+    /// Set the [observer](LaunchObserver) called on every kernel dispatch, or remove it with
+    /// `None`.
+    fn set_launch_observer(&mut self, observer: Option<LaunchObserver>);
+
+    /// Start recording the executed kernels to submit them together once
+    /// [end_batch](ComputeServer::end_batch) is called. Batches can be nested, only the outermost
+    /// one submits.
+    fn start_batch(&mut self);
+
+    /// Submit the kernels recorded since [start_batch](ComputeServer::start_batch), in the order
+    /// they were executed.
+    fn end_batch(&mut self);
+
     /// Flush all outstanding tasks in the server.
     fn flush(&mut self);
 
     /// Wait for the completion of every task in the server.
     fn sync(&mut self) -> impl Future<Output = ()> + Send + 'static;
 
+    /// Wait for the completion of every task in the server like [sync](ComputeServer::sync), but
+    /// return an error instead of panicking when a kernel faulted on the device.
+    fn try_sync(&mut self) -> impl Future<Output = Result<(), DeviceError>> + Send + 'static;
+
+    /// Flush outstanding tasks and process the completed ones without blocking, returning whether
+    /// every submitted task is done.
+    fn poll(&mut self) -> bool;
+
+    /// Invoke the [callback](HostCallback) from a runtime thread once every task submitted so far
+    /// is done, without blocking.
+    fn on_complete(&mut self, callback: HostCallback);
+
     /// The current memory usage of the server.
     fn memory_usage(&self) -> MemoryUsage;
 
@@ -103,6 +204,276 @@ where
     fn end_profile(&mut self) -> ProfileDuration;
 }
 
+/// Information about a kernel dispatch, given to the [launch observer](LaunchObserver).
+#[derive(Debug)]
+pub struct LaunchInfo<'a> {
+    /// The name of the kernel.
+    pub name: &'a str,
+    /// The identifier of the kernel, in its stable format.
+    pub id: String,
+    /// The number of cubes launched.
+    pub cube_count: CubeCount,
+    /// The dimension of each cube, as resolved by the compiled kernel.
+    pub cube_dim: CubeDim,
+    /// The bindings the kernel is launched with.
+    pub bindings: &'a Bindings,
+    /// Whether the backend compiler ran for this dispatch, `false` when the kernel was already
+    /// compiled by an earlier dispatch or
+    /// [precompiled](crate::client::ComputeClient::precompile).
+    pub compiled: bool,
+}
+
+/// Callback invoked by the server, in order, on every kernel dispatch.
+pub type LaunchObserver = Box<dyn Fn(&LaunchInfo) + Send + Sync>;
+
+/// Callback invoked by the server once the tasks submitted before it are done.
+pub type HostCallback = Box<dyn FnOnce() + Send>;
+
+/// Holds the [launch observer](LaunchObserver) of a server, if any.
+#[derive(Default)]
+pub struct LaunchObserverSlot {
+    observer: Option<LaunchObserver>,
+}
+
+impl LaunchObserverSlot {
+    /// Replace the current observer.
+    pub fn set(&mut self, observer: Option<LaunchObserver>) {
+        self.observer = observer;
+    }
+
+    /// Call the observer with the launch information created by `info`.
+    ///
+    /// The information is only created when an observer is set, so dispatching stays free
+    /// otherwise.
+    pub fn observe<'a>(&self, info: impl FnOnce() -> LaunchInfo<'a>) {
+        if let Some(observer) = &self.observer {
+            observer(&info());
+        }
+    }
+}
+
+impl Debug for LaunchObserverSlot {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("LaunchObserverSlot")
+            .field("activated", &self.observer.is_some())
+            .finish()
+    }
+}
+
+/// Error that happened while compiling a kernel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CompilationError {
+    /// The kernel declares more shared memory than the device allows per cube.
+    SharedMemoryExceeded {
+        /// The amount of shared memory requested by the kernel, in bytes.
+        requested: usize,
+        /// The maximum amount of shared memory available per cube, in bytes.
+        limit: usize,
+    },
+    /// The kernel uses more bindings than the device allows.
+    TooManyBindings {
+        /// The number of bindings used by the kernel.
+        requested: u32,
+        /// The maximum number of bindings supported by the device.
+        limit: u32,
+    },
+    /// The push constants of the kernel, with the padding aligning each type, are larger than the
+    /// guaranteed limit.
+    PushConstantsExceeded {
+        /// The size of the push constants of the kernel, in bytes.
+        requested: usize,
+        /// The maximum size of the push constants, in bytes.
+        limit: usize,
+    },
+    /// The kernel uses an instruction that isn't supported by the backend.
+    UnsupportedInstruction {
+        /// The unsupported operation.
+        op: String,
+        /// The backend that rejected the operation.
+        backend: &'static str,
+    },
+    /// The kernel partitions the plane in groups of units that don't divide the plane size.
+    InvalidPlanePartition {
+        /// The number of units per partition requested by the kernel.
+        partition_size: u32,
+        /// The number of units per plane of the device.
+        plane_size: u32,
+    },
+    /// Any other error reported by the backend compiler, with its log.
+    BackendError(String),
+}
+
+impl Display for CompilationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::SharedMemoryExceeded { requested, limit } => write!(
+                f,
+                "The kernel requires {requested} bytes of shared memory, but the device only supports {limit} bytes."
+            ),
+            Self::TooManyBindings { requested, limit } => write!(
+                f,
+                "The kernel requires {requested} bindings, but the device only supports {limit}."
+            ),
+            Self::PushConstantsExceeded { requested, limit } => write!(
+                f,
+                "The kernel requires {requested} bytes of push constants, but only {limit} bytes are guaranteed."
+            ),
+            Self::UnsupportedInstruction { op, backend } => {
+                write!(f, "The instruction {op} is not supported by {backend}.")
+            }
+            Self::InvalidPlanePartition {
+                partition_size,
+                plane_size,
+            } => write!(
+                f,
+                "The kernel partitions planes in groups of {partition_size} units, which doesn't divide the plane size of {plane_size}."
+            ),
+            Self::BackendError(log) => write!(f, "[Compilation Error] {log}"),
+        }
+    }
+}
+
+/// Error reported by the device while running the submitted tasks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeviceError {
+    /// A kernel faulted, e.g. with an illegal memory access or a trap.
+    ///
+    /// The error is sticky: the device context can't run anything anymore, so every later task
+    /// of the server fails as well.
+    Faulted {
+        /// The error code reported by the backend, like `CUDA_ERROR_ILLEGAL_ADDRESS`.
+        code: String,
+        /// The name of the last kernel launched before the fault was detected, if any.
+        kernel: Option<String>,
+    },
+}
+
+impl Display for DeviceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Faulted {
+                code,
+                kernel: Some(kernel),
+            } => write!(
+                f,
+                "The device faulted with {code} after launching {kernel}."
+            ),
+            Self::Faulted { code, kernel: None } => write!(f, "The device faulted with {code}."),
+        }
+    }
+}
+
+/// Diagnostic reported by the backend compiler that doesn't prevent the kernel from running, e.g.
+/// registers spilled to local memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompileWarning {
+    /// The message of the backend compiler.
+    pub message: String,
+    /// How important the message is.
+    pub severity: WarningSeverity,
+    /// Where the message applies, like a function name or a source line, when it's reported.
+    pub location: Option<String>,
+}
+
+/// The resources used by a compiled kernel, for the backends reporting them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KernelResources {
+    /// The number of registers used by each unit, if reported by the backend.
+    pub registers_per_unit: Option<u32>,
+}
+
+/// Whether a kernel can be launched with a given configuration, as checked by
+/// [can_launch](crate::client::ComputeClient::can_launch), or which limit of the device it exceeds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LaunchFeasibility {
+    /// The configuration is within all the known limits of the device.
+    Feasible,
+    /// The cube has more units than the device supports.
+    UnitsPerCubeExceeded {
+        /// The number of units of the cube.
+        requested: u32,
+        /// The maximum number of units of a cube on the device.
+        max: u32,
+    },
+    /// A dimension of the cube is larger than the device supports.
+    CubeDimExceeded {
+        /// The dimensions of the cube.
+        requested: CubeDim,
+        /// The maximum dimensions of a cube on the device.
+        max: CubeDim,
+    },
+    /// The cube uses more shared memory than the device supports.
+    SharedMemoryExceeded {
+        /// The bytes of shared memory of the cube.
+        requested: usize,
+        /// The maximum bytes of shared memory of a cube on the device.
+        max: usize,
+    },
+    /// The units of the cube use more registers than the device supports.
+    RegistersExceeded {
+        /// The registers used by all the units of the cube.
+        requested: u32,
+        /// The maximum number of registers of a cube on the device.
+        max: u32,
+    },
+    /// The kernel can't be compiled for the device.
+    Uncompilable(CompilationError),
+}
+
+impl LaunchFeasibility {
+    /// Whether the configuration can be launched.
+    pub fn is_feasible(&self) -> bool {
+        matches!(self, Self::Feasible)
+    }
+}
+
+impl Display for LaunchFeasibility {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Feasible => write!(f, "The launch configuration is feasible."),
+            Self::UnitsPerCubeExceeded { requested, max } => write!(
+                f,
+                "The cube has {requested} units, which exceeds the limit of {max} units."
+            ),
+            Self::CubeDimExceeded { requested, max } => write!(
+                f,
+                "The cube dimensions {requested:?} exceed the limit of {max:?}."
+            ),
+            Self::SharedMemoryExceeded { requested, max } => write!(
+                f,
+                "The cube uses {requested} bytes of shared memory, which exceeds the limit of {max} bytes."
+            ),
+            Self::RegistersExceeded { requested, max } => write!(
+                f,
+                "The cube uses {requested} registers, which exceeds the limit of {max} registers."
+            ),
+            Self::Uncompilable(err) => write!(f, "The kernel can't be compiled: {err}"),
+        }
+    }
+}
+
+/// The severity of a [compile warning](CompileWarning).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WarningSeverity {
+    /// Information about the generated code, like the number of registers used.
+    Info,
+    /// Something that may hurt the performance or correctness of the kernel.
+    Warning,
+}
+
+impl Display for CompileWarning {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let severity = match self.severity {
+            WarningSeverity::Info => "info",
+            WarningSeverity::Warning => "warning",
+        };
+        match &self.location {
+            Some(location) => write!(f, "[{severity}] {location}: {}", self.message),
+            None => write!(f, "[{severity}] {}", self.message),
+        }
+    }
+}
+
 /// Server handle containing the [memory handle](crate::server::Handle).
 #[derive(new, Debug)]
 pub struct Handle {
@@ -142,6 +513,27 @@ impl Handle {
     pub fn size(&self) -> u64 {
         self.size - self.offset_start.unwrap_or(0) - self.offset_end.unwrap_or(0)
     }
+
+    /// Whether the two handles point to overlapping device memory, such as a tensor and a slice
+    /// of it. Separate allocations never alias, even when they share the same storage buffer,
+    /// since the memory management never hands out overlapping slices.
+    pub fn aliases(&self, other: &Handle) -> bool {
+        if self.memory.id() != other.memory.id() {
+            return false;
+        }
+
+        let (start, end) = self.byte_range();
+        let (other_start, other_end) = other.byte_range();
+        start < other_end && other_start < end
+    }
+
+    /// The start and end of the handle in bytes, relative to its allocation.
+    fn byte_range(&self) -> (u64, u64) {
+        (
+            self.offset_start.unwrap_or(0),
+            self.size - self.offset_end.unwrap_or(0),
+        )
+    }
 }
 
 /// Bindings to execute a kernel.
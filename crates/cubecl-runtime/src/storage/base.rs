@@ -82,6 +82,13 @@ pub trait ComputeStorage: Send {
     fn dealloc(&mut self, id: StorageId);
 }
 
+/// A storage resource located at a raw address of the device memory, which can be shared with
+/// external libraries.
+pub trait DeviceAddress {
+    /// The raw device address of the resource, its offset included.
+    fn device_ptr(&self) -> u64;
+}
+
 /// Access to the underlying resource for a given binding.
 #[derive(new, Debug)]
 pub struct BindingResource<Resource: Send> {
@@ -0,0 +1,94 @@
+use crate::{
+    channel::ComputeChannel,
+    client::ComputeClient,
+    server::{ComputeServer, Handle},
+};
+use alloc::vec::Vec;
+use cubecl_ir::Elem;
+use hashbrown::HashMap;
+
+/// A tensor acquired from a [TensorPool], to give back with [TensorPool::release] once it's no
+/// longer needed.
+#[derive(Debug)]
+pub struct PooledTensor {
+    /// The handle of the tensor memory.
+    pub handle: Handle,
+    /// The shape of the tensor.
+    pub shape: Vec<usize>,
+    /// The strides of the tensor.
+    pub strides: Vec<usize>,
+    /// The element type of the tensor.
+    pub elem: Elem,
+}
+
+/// Pool of output tensors reused across launches, to avoid going through the allocator for
+/// every tensor of a loop that repeatedly produces tensors of the same shapes.
+///
+/// Unlike the memory manager, which reuses any free slice large enough, the pool hands back the
+/// exact tensors that were released for a shape and element type. A released tensor keeps its
+/// memory reserved until it's acquired again or the pool is [cleared](TensorPool::clear).
+pub struct TensorPool<Server: ComputeServer, Channel> {
+    client: ComputeClient<Server, Channel>,
+    free: HashMap<(Vec<usize>, Elem), Vec<(Handle, Vec<usize>)>>,
+}
+
+impl<Server, Channel> TensorPool<Server, Channel>
+where
+    Server: ComputeServer,
+    Channel: ComputeChannel<Server>,
+{
+    /// Create an empty pool allocating its tensors with the given client.
+    pub fn new(client: ComputeClient<Server, Channel>) -> Self {
+        Self {
+            client,
+            free: HashMap::new(),
+        }
+    }
+
+    /// Acquire a contiguous tensor of the given shape and element type, reusing a released
+    /// tensor when one matches, or allocating a new one otherwise.
+    ///
+    /// The content of a reused tensor is whatever was last written to it.
+    pub fn acquire(&mut self, shape: &[usize], elem: Elem) -> PooledTensor {
+        let reused = self
+            .free
+            .get_mut(&(shape.to_vec(), elem))
+            .and_then(|tensors| tensors.pop());
+
+        let (handle, strides) =
+            reused.unwrap_or_else(|| self.client.empty_tensor(shape, elem.size()));
+
+        PooledTensor {
+            handle,
+            shape: shape.to_vec(),
+            strides,
+            elem,
+        }
+    }
+
+    /// Give a tensor back to the pool so it can be acquired again.
+    ///
+    /// Tensors whose handle is still referenced elsewhere, e.g. by a clone kept by the caller,
+    /// aren't pooled since reusing them would alias that reference. They're dropped instead and
+    /// their memory is freed by the memory manager once the last reference goes away.
+    pub fn release(&mut self, tensor: PooledTensor) {
+        if !tensor.handle.can_mut() {
+            return;
+        }
+
+        self.free
+            .entry((tensor.shape, tensor.elem))
+            .or_default()
+            .push((tensor.handle, tensor.strides));
+    }
+
+    /// The number of released tensors waiting to be acquired again.
+    pub fn num_free(&self) -> usize {
+        self.free.values().map(Vec::len).sum()
+    }
+
+    /// Drop all the released tensors, letting the memory manager reuse their memory.
+    pub fn clear(&mut self) {
+        self.free.clear();
+    }
+}
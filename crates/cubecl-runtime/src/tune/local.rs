@@ -1,7 +1,8 @@
-use super::{AutotuneKey, AutotuneOutput, TunableSet, Tuner};
+use super::{AutotuneConfig, AutotuneKey, AutotuneOutput, TunableSet, Tuner};
 use crate::{
     channel::ComputeChannel, client::ComputeClient, server::ComputeServer, tune::TuneCacheResult,
 };
+use alloc::format;
 use core::{fmt::Display, hash::Hash};
 use hashbrown::HashMap;
 
@@ -11,10 +12,13 @@ use alloc::string::ToString;
 /// A local tuner allows to create a tuner for a specific key that can be different from the server
 /// key.
 pub struct LocalTuner<AK: AutotuneKey, ID> {
-    state: spin::RwLock<Option<HashMap<ID, Tuner<AK>>>>,
+    state: spin::RwLock<Option<HashMap<ID, KernelTuners<AK>>>>,
     name: &'static str,
 }
 
+/// The tuners of an id, one per [kernel id](TunableSet::with_kernel_id).
+type KernelTuners<AK> = HashMap<Option<&'static str>, Tuner<AK>>;
+
 /// Create a local tuner with the provided name.
 #[macro_export]
 macro_rules! local_tuner {
@@ -43,6 +47,72 @@ impl<AK: AutotuneKey + 'static, ID: Hash + PartialEq + Eq + Clone + Display> Loc
         *state = None;
     }
 
+    /// Pin the operation at `index` of the tunable sets with the given
+    /// [kernel id](TunableSet::kernel_id) for the given key.
+    ///
+    /// A pinned operation is executed directly without any benchmarking. If it fails, execution
+    /// panics instead of falling back to another operation.
+    pub fn force_config(&self, id: &ID, kernel_id: Option<&'static str>, key: AK, index: usize) {
+        let mut state = self.state.write();
+        let map = state.get_or_insert_with(Default::default);
+        let tuner = self.tuner_mut(map, id, kernel_id);
+        tuner.force_config(key, index);
+    }
+
+    /// Set how the operations of the tunable sets with the given
+    /// [kernel id](TunableSet::kernel_id) are benchmarked by the next autotunes for the given id.
+    pub fn set_config(&self, id: &ID, kernel_id: Option<&'static str>, config: AutotuneConfig) {
+        let mut state = self.state.write();
+        let map = state.get_or_insert_with(Default::default);
+        let tuner = self.tuner_mut(map, id, kernel_id);
+        tuner.set_config(config);
+    }
+
+    /// The tuner of the id and kernel id, created if needed.
+    ///
+    /// Each kernel id has its own tuner, so its own persistent cache.
+    fn tuner_mut<'a>(
+        &self,
+        map: &'a mut HashMap<ID, KernelTuners<AK>>,
+        id: &ID,
+        kernel_id: Option<&'static str>,
+    ) -> &'a mut Tuner<AK> {
+        map.entry(id.clone())
+            .or_default()
+            .entry(kernel_id)
+            .or_insert_with(|| {
+                let name = self.name.replace("::", "-");
+                let name = match kernel_id {
+                    Some(kernel_id) => format!("{name}-{}", kernel_id.replace("::", "-")),
+                    None => name,
+                };
+                Tuner::new(&name, &id.to_string())
+            })
+    }
+
+    /// The pinned operation for the key, either with [force_config](Self::force_config) or with
+    /// the `CUBECL_AUTOTUNE_FORCE` environment variable.
+    #[cfg_attr(not(feature = "std"), allow(unused_variables))]
+    fn forced<In: Send + Clone + 'static, Out: AutotuneOutput>(
+        &self,
+        id: &ID,
+        key: &AK,
+        operations: &TunableSet<AK, In, Out>,
+    ) -> Option<usize> {
+        let forced = self
+            .state
+            .read()
+            .as_ref()
+            .and_then(|map| map.get(id))
+            .and_then(|tuners| tuners.get(&operations.kernel_id()))
+            .and_then(|tuner| tuner.forced(key));
+
+        #[cfg(feature = "std")]
+        let forced = forced.or_else(|| forced_by_env(operations));
+
+        forced
+    }
+
     #[cfg(feature = "autotune-checks")]
     fn checks<In: Send + Clone + 'static, Out: AutotuneOutput>(
         &self,
@@ -71,10 +141,21 @@ impl<AK: AutotuneKey + 'static, ID: Hash + PartialEq + Eq + Clone + Display> Loc
         C: ComputeChannel<S> + 'static,
     {
         let key = operations.generate_key(&inputs);
+        let kernel_id = operations.kernel_id();
+
+        // A pinned operation is used as is, without benchmarking nor falling back.
+        if let Some(index) = self.forced(id, &key, operations) {
+            return operations
+                .fastest(index)
+                .execute(inputs)
+                .unwrap_or_else(|err| {
+                    panic!("Forced autotune operation {index} failed for {key}: {err:?}")
+                });
+        }
 
         // If this is cached and ready, use the operation.
         if let Some(map) = self.state.read().as_ref() {
-            if let Some(tuner) = map.get(id) {
+            if let Some(tuner) = map.get(id).and_then(|tuners| tuners.get(&kernel_id)) {
                 if let TuneCacheResult::Hit { fastest_index } = tuner.fastest(&key) {
                     #[cfg(feature = "autotune-checks")]
                     self.checks(operations, &inputs);
@@ -94,10 +175,7 @@ impl<AK: AutotuneKey + 'static, ID: Hash + PartialEq + Eq + Clone + Display> Loc
         let (fastest, run_autotune) = {
             let mut state = self.state.write();
             let map = state.get_or_insert_with(Default::default);
-            let tuner = map.entry(id.clone()).or_insert_with(move || {
-                let name = self.name.replace("::", "-");
-                Tuner::new(&name, &id.to_string())
-            });
+            let tuner = self.tuner_mut(map, id, kernel_id);
 
             #[allow(unused_mut)]
             let mut fastest = tuner.fastest(&key);
@@ -147,6 +225,7 @@ impl<AK: AutotuneKey + 'static, ID: Hash + PartialEq + Eq + Clone + Display> Loc
                     let tuner = state
                         .as_ref()
                         .and_then(|s| s.get(id))
+                        .and_then(|tuners| tuners.get(&kernel_id))
                         .expect("Should be initialized");
                     tuner.execute_autotune(key.clone(), &inputs, operations, client);
                 } else {
@@ -166,6 +245,7 @@ impl<AK: AutotuneKey + 'static, ID: Hash + PartialEq + Eq + Clone + Display> Loc
             let tuner = state
                 .as_mut()
                 .and_then(|s| s.get_mut(id))
+                .and_then(|tuners| tuners.get_mut(&kernel_id))
                 .expect("Should be initialized");
 
             // Read all results that have come in since.
@@ -207,3 +287,35 @@ impl<AK: AutotuneKey + 'static, ID: Hash + PartialEq + Eq + Clone + Display> Loc
             .expect("Should run when selected by autotune.")
     }
 }
+
+/// Names of the operations pinned with the `CUBECL_AUTOTUNE_FORCE` environment variable, a
+/// comma-separated list matched against the operation names.
+#[cfg(feature = "std")]
+static FORCED_NAMES: std::sync::LazyLock<Vec<String>> = std::sync::LazyLock::new(|| {
+    std::env::var("CUBECL_AUTOTUNE_FORCE")
+        .map(|names| {
+            names
+                .split(',')
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .map(ToString::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+});
+
+/// Index of the first operation pinned by the `CUBECL_AUTOTUNE_FORCE` environment variable.
+#[cfg(feature = "std")]
+fn forced_by_env<AK: AutotuneKey, In: Send + Clone + 'static, Out: 'static>(
+    operations: &TunableSet<AK, In, Out>,
+) -> Option<usize> {
+    if FORCED_NAMES.is_empty() {
+        return None;
+    }
+
+    operations.autotunables().iter().position(|op| {
+        FORCED_NAMES
+            .iter()
+            .any(|name| op.name().contains(name.as_str()))
+    })
+}
@@ -28,6 +28,7 @@ pub struct TunableSet<K: AutotuneKey, Inputs: Send + 'static, Output: 'static> {
     tunables: Vec<Arc<dyn Tunable<Inputs = Inputs, Output = Output>>>,
     key_gen: Arc<dyn KeyGenerator<K, Inputs>>,
     input_gen: Arc<dyn InputGenerator<K, Inputs>>,
+    kernel_id: Option<&'static str>,
     #[allow(clippy::type_complexity)]
     checksum_override: Option<Arc<dyn Fn(&Self) -> String + Send + Sync>>,
 }
@@ -52,6 +53,7 @@ impl<K: AutotuneKey, Inputs: Clone + Send + 'static, Output: 'static>
             tunables: Default::default(),
             input_gen: Arc::new(input_gen.into_input_gen()),
             key_gen: Arc::new(key_gen.into_key_gen()),
+            kernel_id: None,
             checksum_override: None,
         }
     }
@@ -65,6 +67,21 @@ impl<K: AutotuneKey, Inputs: Clone + Send + 'static, Output: 'static>
         self
     }
 
+    /// Identify the kernel variant tuned by this set, so it is tuned and cached separately from
+    /// the sets with a different id, even when their keys are the same.
+    ///
+    /// The id must be stable across runs for the persistent cache to be reused, and is part of the
+    /// name of its file.
+    pub fn with_kernel_id(mut self, kernel_id: &'static str) -> Self {
+        self.kernel_id = Some(kernel_id);
+        self
+    }
+
+    /// The id of the kernel variant tuned by this set, if any.
+    pub fn kernel_id(&self) -> Option<&'static str> {
+        self.kernel_id
+    }
+
     /// Override the checksum algorithm
     pub fn with_custom_checksum(
         mut self,
@@ -1,5 +1,6 @@
 use alloc::sync::Arc;
 use alloc::vec::Vec;
+use core::time::Duration;
 
 use crate::channel::ComputeChannel;
 use crate::client::ComputeClient;
@@ -8,12 +9,55 @@ use cubecl_common::benchmark::ProfileDuration;
 
 use super::{AutotuneError, Tunable};
 
+/// How each candidate operation is benchmarked when autotuning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AutotuneConfig {
+    /// The number of timed executions, whose median selects the fastest operation. More samples
+    /// make the selection less sensitive to noise, e.g. on a loaded device, at the cost of a
+    /// longer tuning.
+    ///
+    /// At least one sample is always taken, so zero behaves like one.
+    pub samples: usize,
+    /// The number of untimed executions before the samples, at least one to compile the operation
+    /// and tune the operations it uses itself.
+    pub warmup: usize,
+    /// The time after which a candidate is abandoned and marked as failed, measured from the start
+    /// of its warmup until all its samples completed.
+    ///
+    /// It's checked after each warmup and sample by waiting for the device with
+    /// [sync_timeout](ComputeClient::sync_timeout) until the deadline at most. An execution is
+    /// never interrupted, so a candidate whose single execution takes longer, e.g. to compile or
+    /// submit its kernels, only fails once that execution returns. The work it already submitted
+    /// still runs to completion on the device.
+    ///
+    /// Only enforced with `std` on native targets.
+    pub per_candidate_timeout: Option<Duration>,
+}
+
+impl AutotuneConfig {
+    /// Ten samples after a single warmup, without timeout.
+    pub const fn new() -> Self {
+        Self {
+            samples: 10,
+            warmup: 1,
+            per_candidate_timeout: None,
+        }
+    }
+}
+
+impl Default for AutotuneConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// A benchmark that runs on server handles
 #[derive(new)]
 pub struct TuneBenchmark<S: ComputeServer, C, In: Clone + Send + 'static, Out: Send + 'static> {
     operation: Arc<dyn Tunable<Inputs = In, Output = Out>>,
     inputs: In,
     client: ComputeClient<S, C>,
+    config: AutotuneConfig,
 }
 
 /// The trait to be implemented by an autotune output.
@@ -43,26 +87,73 @@ impl<
         self.operation.clone().execute(self.inputs.clone())
     }
 
-    /// Benchmark how long this operation takes for a number of samples.
+    /// Benchmark how long this operation takes for the [samples](AutotuneConfig::samples) of the
+    /// config, returning [a timeout](AutotuneError::Timeout) if it takes longer than the
+    /// [per candidate timeout](AutotuneConfig::per_candidate_timeout).
     pub fn profile(self) -> Result<Vec<ProfileDuration>, AutotuneError> {
         let operation = self.operation;
+        let deadline = Deadline::new(self.config.per_candidate_timeout);
+
         // If the inner operation need autotuning as well, we need to call it before. This will
         // recurse and keep calling operations until a leaf operation tunes, and so on. This effectively
         // does a depth-first traversal of the operation tree. Without this, client.profile() would have to
         // support profiling recursively.
-        operation.execute(self.inputs.clone())?;
-
-        let num_samples = 10;
-        let durations = (0..num_samples)
-            .map(|_| {
-                self.client.profile(|| {
-                    operation
-                        .execute(self.inputs.clone())
-                        .expect("Should not fail when previously tried during the warmup.");
-                })
-            })
-            .collect();
+        for _ in 0..self.config.warmup.max(1) {
+            operation.execute(self.inputs.clone())?;
+            deadline.check(&self.client)?;
+        }
+
+        let samples = self.config.samples.max(1);
+        let mut durations = Vec::with_capacity(samples);
+        for _ in 0..samples {
+            durations.push(self.client.profile(|| {
+                operation
+                    .execute(self.inputs.clone())
+                    .expect("Should not fail when previously tried during the warmup.");
+            }));
+            deadline.check(&self.client)?;
+        }
 
         Ok(durations)
     }
 }
+
+/// The time left to a candidate before it's abandoned.
+struct Deadline {
+    #[cfg(all(feature = "std", not(target_family = "wasm")))]
+    timeout: Option<(std::time::Instant, Duration)>,
+}
+
+impl Deadline {
+    #[cfg_attr(
+        not(all(feature = "std", not(target_family = "wasm"))),
+        allow(unused_variables)
+    )]
+    fn new(timeout: Option<Duration>) -> Self {
+        Self {
+            #[cfg(all(feature = "std", not(target_family = "wasm")))]
+            timeout: timeout.map(|timeout| (std::time::Instant::now(), timeout)),
+        }
+    }
+
+    /// Wait for the submitted work until the deadline, returning an error if it isn't done by
+    /// then.
+    #[cfg_attr(
+        not(all(feature = "std", not(target_family = "wasm"))),
+        allow(unused_variables)
+    )]
+    fn check<S: ComputeServer, C: ComputeChannel<S>>(
+        &self,
+        client: &ComputeClient<S, C>,
+    ) -> Result<(), AutotuneError> {
+        #[cfg(all(feature = "std", not(target_family = "wasm")))]
+        if let Some((start, timeout)) = self.timeout {
+            let remaining = timeout.saturating_sub(start.elapsed());
+            if remaining.is_zero() || !client.sync_timeout(remaining) {
+                return Err(AutotuneError::Timeout(timeout));
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -2,7 +2,7 @@ use alloc::format;
 use alloc::vec::Vec;
 use async_channel::{Receiver, Sender};
 use cubecl_common::future;
-use hashbrown::HashSet;
+use hashbrown::{HashMap, HashSet};
 
 use core::time::Duration;
 
@@ -11,10 +11,10 @@ use cubecl_common::benchmark::{BenchmarkComputations, BenchmarkDurations};
 
 use crate::channel::ComputeChannel;
 use crate::client::ComputeClient;
-use crate::server::ComputeServer;
+use crate::server::{CompilationError, ComputeServer};
 use crate::tune::{TuneBenchmark, TuneCache};
 
-use super::{AutotuneKey, AutotuneOutput, TunableSet, TuneCacheResult};
+use super::{AutotuneConfig, AutotuneKey, AutotuneOutput, TunableSet, TuneCacheResult};
 
 #[derive(Debug)]
 /// Executes autotune benchmarking and caching
@@ -22,6 +22,8 @@ pub struct Tuner<K: AutotuneKey> {
     tune_cache: TuneCache<K>,
     channel: (Sender<AutotuneMessage<K>>, Receiver<AutotuneMessage<K>>),
     pub(crate) autotuning: HashSet<K>,
+    forced: HashMap<K, usize>,
+    config: AutotuneConfig,
 }
 
 /// The measured outcome for a given autotune invocation.
@@ -55,6 +57,11 @@ enum AutotuneMessage<K> {
 pub enum AutotuneError {
     /// An unknown error happened.
     Unknown(String),
+    /// The tunable couldn't be compiled for the current device.
+    Compilation(CompilationError),
+    /// The tunable took longer than the
+    /// [per candidate timeout](AutotuneConfig::per_candidate_timeout) to benchmark.
+    Timeout(Duration),
 }
 
 impl From<String> for AutotuneError {
@@ -63,6 +70,12 @@ impl From<String> for AutotuneError {
     }
 }
 
+impl From<CompilationError> for AutotuneError {
+    fn from(value: CompilationError) -> Self {
+        Self::Compilation(value)
+    }
+}
+
 #[allow(clippy::new_without_default)]
 impl<K: AutotuneKey> Tuner<K> {
     /// Returns a tuner with cache initialized from persistent cache
@@ -73,9 +86,26 @@ impl<K: AutotuneKey> Tuner<K> {
             tune_cache: TuneCache::new(name, device_id),
             channel,
             autotuning: HashSet::new(),
+            forced: HashMap::new(),
+            config: AutotuneConfig::new(),
         }
     }
 
+    /// Set how the operations are benchmarked by the next autotunes.
+    pub fn set_config(&mut self, config: AutotuneConfig) {
+        self.config = config;
+    }
+
+    /// Pin the operation at `index` for the given key, bypassing benchmarking for that key.
+    pub fn force_config(&mut self, key: K, index: usize) {
+        self.forced.insert(key, index);
+    }
+
+    /// The operation index pinned for an autotune key with [force_config](Self::force_config).
+    pub fn forced(&self, key: &K) -> Option<usize> {
+        self.forced.get(key).copied()
+    }
+
     /// Fetch the fastest autotune operation index for an autotune key.
     pub fn fastest(&self, key: &K) -> TuneCacheResult {
         self.tune_cache.fastest(key)
@@ -175,7 +205,8 @@ impl<K: AutotuneKey> Tuner<K> {
 
             for (index, op) in autotunables.into_iter() {
                 let name = op.name().to_string();
-                let tuner = TuneBenchmark::new(op, test_inputs.clone(), client.clone());
+                let tuner =
+                    TuneBenchmark::new(op, test_inputs.clone(), client.clone(), self.config);
                 #[cfg(feature = "autotune-checks")]
                 checks_outputs.push(tuner.output_for_checks());
                 let profiles = tuner.profile().map(|bench| (name, index, bench));
@@ -187,6 +218,10 @@ impl<K: AutotuneKey> Tuner<K> {
                 let first_error = tunable_profiles.into_iter().next().unwrap().err().unwrap();
                 match first_error {
                     AutotuneError::Unknown(reason) => panic!("{reason}"),
+                    AutotuneError::Compilation(err) => panic!("{err}"),
+                    AutotuneError::Timeout(timeout) => {
+                        panic!("All the operations took longer than {timeout:?} to benchmark")
+                    }
                 }
             }
 
@@ -31,6 +31,12 @@ pub fn autotune_execute(
 }
 
 pub fn init_client() -> ComputeClient<DummyServer, MutexComputeChannel<DummyServer>> {
+    init_client_with_memory_limit(None)
+}
+
+pub fn init_client_with_memory_limit(
+    memory_limit: Option<u64>,
+) -> ComputeClient<DummyServer, MutexComputeChannel<DummyServer>> {
     let storage = BytesStorage::default();
     let mem_properties = MemoryDeviceProperties {
         max_page_size: 1024 * 1024 * 512,
@@ -46,12 +52,16 @@ pub fn init_client() -> ComputeClient<DummyServer, MutexComputeChannel<DummyServ
         max_cube_dim: CubeDim::new_3d(1024, 1024, 64),
         num_streaming_multiprocessors: None,
         num_tensor_cores: None,
+        peak_flops: None,
+        peak_memory_bandwidth: None,
+        max_registers_per_cube: None,
     };
-    let memory_management = MemoryManagement::from_configuration(
+    let mut memory_management = MemoryManagement::from_configuration(
         storage,
         &mem_properties,
         MemoryConfiguration::default(),
     );
+    memory_management.set_memory_limit(memory_limit);
     let server = DummyServer::new(memory_management);
     let channel = MutexComputeChannel::new(server);
     ComputeClient::new(
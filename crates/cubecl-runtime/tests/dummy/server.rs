@@ -1,11 +1,15 @@
-use cubecl_common::{ExecutionMode, benchmark::ProfileDuration};
+use cubecl_common::{CubeDim, ExecutionMode, benchmark::ProfileDuration};
 use cubecl_runtime::kernel_timestamps::KernelTimestamps;
-use cubecl_runtime::server::{BindingWithMeta, Bindings};
+use cubecl_runtime::server::{
+    BindingWithMeta, Bindings, CompilationError, CompileWarning, DeviceError, HostCallback,
+    KernelResources, LaunchInfo, LaunchObserver, LaunchObserverSlot,
+};
+use std::collections::HashSet;
 use std::future::Future;
 use std::sync::Arc;
 
 use super::DummyKernel;
-use cubecl_runtime::memory_management::MemoryUsage;
+use cubecl_runtime::memory_management::{AllocationError, MemoryUsage};
 use cubecl_runtime::server::CubeCount;
 use cubecl_runtime::storage::{BindingResource, BytesResource, ComputeStorage};
 use cubecl_runtime::{
@@ -20,6 +24,9 @@ use cubecl_runtime::{
 pub struct DummyServer {
     memory_management: MemoryManagement<BytesStorage>,
     timestamps: KernelTimestamps,
+    launch_observer: LaunchObserverSlot,
+    /// The kernels considered compiled, identified like in their [LaunchInfo].
+    compiled: HashSet<(String, ExecutionMode)>,
 }
 
 impl ComputeServer for DummyServer {
@@ -48,6 +55,21 @@ impl ComputeServer for DummyServer {
         self.read(bindings)
     }
 
+    fn read_into(&mut self, binding: Binding, destination: &mut [u8]) {
+        let handle = self.memory_management.get(binding.memory).unwrap();
+        let bytes = self.memory_management.storage().get(&handle);
+        let bytes = bytes.read();
+
+        assert_eq!(
+            destination.len(),
+            bytes.len(),
+            "Destination of {} bytes doesn't match the {} bytes of the binding",
+            destination.len(),
+            bytes.len()
+        );
+        destination.copy_from_slice(bytes);
+    }
+
     fn get_resource(&mut self, binding: Binding) -> BindingResource<BytesResource> {
         let handle = self.memory_management.get(binding.clone().memory).unwrap();
         BindingResource::new(binding, self.memory_management.storage().get(&handle))
@@ -89,6 +111,15 @@ impl ComputeServer for DummyServer {
         )
     }
 
+    fn try_empty(&mut self, size: usize) -> Result<Handle, AllocationError> {
+        let handle = self.memory_management.try_reserve(size as u64, None)?;
+        Ok(Handle::new(handle, None, None, size as u64))
+    }
+
+    unsafe fn register_external(&mut self, _ptr: u64, _size: u64) -> Handle {
+        panic!("The dummy server doesn't support external memory")
+    }
+
     fn empty_tensor(&mut self, shape: &[usize], elem_size: usize) -> (Handle, Vec<usize>) {
         let rank = shape.len();
         let mut strides = vec![1; rank];
@@ -100,13 +131,63 @@ impl ComputeServer for DummyServer {
         (handle, strides)
     }
 
+    fn fill(&mut self, binding: Binding, pattern: u32) {
+        let resource = self.get_resource(binding);
+        let bytes = resource.resource().write();
+        let pattern = pattern.to_le_bytes();
+        for (i, val) in bytes.iter_mut().enumerate() {
+            *val = pattern[i % 4];
+        }
+    }
+
+    fn write(&mut self, binding: Binding, data: &[u8]) {
+        let resource = self
+            .memory_management
+            .get_resource(binding.memory, binding.offset_start, binding.offset_end)
+            .unwrap();
+        resource.write().copy_from_slice(data);
+    }
+
+    fn compile(
+        &mut self,
+        kernel: Self::Kernel,
+        mode: ExecutionMode,
+    ) -> Result<Vec<CompileWarning>, CompilationError> {
+        // Dummy kernels are plain Rust closures, there is nothing to compile, but the kernels are
+        // still cached like real ones.
+        self.compiled.insert((format!("{kernel:?}"), mode));
+        Ok(Vec::new())
+    }
+
+    fn kernel_resources(
+        &mut self,
+        kernel: Self::Kernel,
+        mode: ExecutionMode,
+    ) -> Result<KernelResources, CompilationError> {
+        // Dummy kernels don't use any register.
+        self.compile(kernel, mode)
+            .map(|_| KernelResources::default())
+    }
+
     unsafe fn execute(
         &mut self,
         kernel: Self::Kernel,
-        _count: CubeCount,
+        count: CubeCount,
         bindings: Bindings,
-        _mode: ExecutionMode,
+        mode: ExecutionMode,
     ) {
+        let id = format!("{kernel:?}");
+        let compiled = self.compiled.insert((id.clone(), mode));
+        self.launch_observer.observe(|| LaunchInfo {
+            name: "dummy_kernel",
+            id,
+            cube_count: count,
+            // Dummy kernels are executed once, on the host.
+            cube_dim: CubeDim::new_single(),
+            bindings: &bindings,
+            compiled,
+        });
+
         let mut resources: Vec<_> = bindings
             .buffers
             .into_iter()
@@ -127,6 +208,18 @@ impl ComputeServer for DummyServer {
         kernel.compute(&mut resources);
     }
 
+    fn set_launch_observer(&mut self, observer: Option<LaunchObserver>) {
+        self.launch_observer.set(observer);
+    }
+
+    fn start_batch(&mut self) {
+        // Nothing to do with dummy backend.
+    }
+
+    fn end_batch(&mut self) {
+        // Nothing to do with dummy backend.
+    }
+
     fn flush(&mut self) {
         // Nothing to do with dummy backend.
     }
@@ -136,6 +229,21 @@ impl ComputeServer for DummyServer {
         async move {}
     }
 
+    #[allow(clippy::manual_async_fn)]
+    fn try_sync(&mut self) -> impl Future<Output = Result<(), DeviceError>> + 'static {
+        async move { Ok(()) }
+    }
+
+    fn poll(&mut self) -> bool {
+        // Tasks are executed right away with the dummy backend.
+        true
+    }
+
+    fn on_complete(&mut self, callback: HostCallback) {
+        // Tasks are executed right away with the dummy backend.
+        callback();
+    }
+
     fn memory_usage(&self) -> MemoryUsage {
         self.memory_management.memory_usage()
     }
@@ -158,6 +266,8 @@ impl DummyServer {
         Self {
             memory_management,
             timestamps: KernelTimestamps::default(),
+            launch_observer: LaunchObserverSlot::default(),
+            compiled: HashSet::new(),
         }
     }
 }
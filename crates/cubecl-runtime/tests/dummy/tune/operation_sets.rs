@@ -1,6 +1,10 @@
 #[cfg(autotune_persistent_cache)]
 use rand::{Rng, distr::Alphanumeric};
-use std::sync::Arc;
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+use std::time::Duration;
 
 use cubecl_runtime::{
     server::{Binding, Bindings, CubeCount},
@@ -58,6 +62,50 @@ pub fn multiplication_set(client: DummyClient, shapes: Vec<Vec<usize>>) -> TestS
     ))
 }
 
+/// Set of the given kernel variant, either an addition or a multiplication, with the same key for
+/// both variants.
+pub fn kernel_variant_set(
+    client: DummyClient,
+    shapes: Vec<Vec<usize>>,
+    kernel_id: &'static str,
+) -> TestSet {
+    let set = TestSet::new(
+        move |_input: &Vec<Binding>| format!("{}-{}", "variant", log_shape_input_key(&shapes)),
+        clone_bindings,
+    );
+
+    let set = match kernel_id {
+        "add" => set
+            .with_tunable(OneKernelAutotuneOperation::new(
+                Arc::new(DummyElementwiseAddition),
+                client.clone(),
+            ))
+            .with_tunable(OneKernelAutotuneOperation::new(
+                Arc::new(DummyElementwiseAdditionSlowWrong),
+                client.clone(),
+            )),
+        _ => set
+            .with_tunable(OneKernelAutotuneOperation::new(
+                Arc::new(DummyElementwiseMultiplicationSlowWrong),
+                client.clone(),
+            ))
+            .with_tunable(OneKernelAutotuneOperation::new(
+                Arc::new(DummyElementwiseMultiplication),
+                client.clone(),
+            )),
+    };
+    set.with_kernel_id(kernel_id)
+}
+
+/// Addition set whose last operation panics when it is benchmarked.
+pub fn forced_test_set(client: DummyClient, shapes: Vec<Vec<usize>>) -> TestSet {
+    fn never_benchmarked(_bindings: Vec<Binding>) {
+        panic!("A forced config should skip benchmarking");
+    }
+
+    addition_set(client, shapes).with_tunable(never_benchmarked.ok())
+}
+
 pub fn cache_test_set(
     client: DummyClient,
     shapes: Vec<Vec<usize>>,
@@ -97,6 +145,53 @@ pub fn cache_test_set(
     set
 }
 
+/// Addition set whose first operation sleeps for `delay` before each execution, counting the
+/// executions of each operation in `executions`.
+pub fn slow_candidate_set(
+    client: DummyClient,
+    shapes: Vec<Vec<usize>>,
+    delay: Duration,
+    executions: Arc<[AtomicUsize; 2]>,
+) -> TestSet {
+    fn tunable(
+        client: DummyClient,
+        delay: Duration,
+        executions: Arc<[AtomicUsize; 2]>,
+        index: usize,
+    ) -> impl Fn(Vec<Binding>) {
+        move |bindings| {
+            executions[index].fetch_add(1, Ordering::Relaxed);
+            std::thread::sleep(delay);
+            client.execute(
+                Arc::new(DummyElementwiseAddition),
+                CubeCount::Static(1, 1, 1),
+                Bindings::new().with_buffers(bindings),
+            );
+        }
+    }
+
+    #[allow(unused_mut)]
+    let mut set = TestSet::new(
+        move |_input: &Vec<Binding>| format!("{}-{}", "slow", log_shape_input_key(&shapes)),
+        clone_bindings,
+    )
+    .with_tunable(tunable(client.clone(), delay, executions.clone(), 0).ok())
+    .with_tunable(tunable(client, Duration::ZERO, executions, 1).ok());
+
+    // Always tune instead of reusing a result cached by a previous run.
+    #[cfg(autotune_persistent_cache)]
+    {
+        set = set.with_custom_checksum(|_| {
+            rand::rng()
+                .sample_iter(&Alphanumeric)
+                .take(16)
+                .map(char::from)
+                .collect()
+        });
+    }
+    set
+}
+
 pub fn log_shape_input_key(shapes: &[Vec<usize>]) -> String {
     let mut hash = String::new();
     let lhs = &shapes[0];
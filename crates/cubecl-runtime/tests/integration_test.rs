@@ -1,11 +1,18 @@
 mod dummy;
 
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
 
 use crate::dummy::{DummyDevice, DummyElementwiseAddition, client};
 
-use cubecl_runtime::server::CubeCount;
-use cubecl_runtime::{ComputeRuntime, server::Bindings};
+use cubecl_common::{CubeDim, ExecutionMode};
+use cubecl_ir::{Elem, FloatKind, UIntKind};
+use cubecl_runtime::memory_management::AllocationError;
+use cubecl_runtime::server::{CubeCount, LaunchFeasibility};
+use cubecl_runtime::tensor_pool::TensorPool;
+use cubecl_runtime::tune::AutotuneConfig;
+use cubecl_runtime::{ComputeRuntime, default_client, server::Bindings};
 use dummy::*;
 
 #[allow(unused)]
@@ -34,6 +41,99 @@ fn empty_allocates_memory() {
     assert_eq!(empty_resource.len(), 4);
 }
 
+#[test]
+fn try_empty_beyond_memory_limit_returns_error() {
+    const MB: usize = 1024 * 1024;
+    let limit = 8 * MB as u64;
+    let client = init_client_with_memory_limit(Some(limit));
+
+    // Small allocations are sliced from pages of 8 MB, which fills the budget.
+    let handle = client.try_empty(MB);
+    assert!(handle.is_ok());
+    assert_eq!(client.memory_usage().bytes_reserved, limit);
+
+    let result = client.try_empty(8 * MB);
+    assert!(matches!(
+        result,
+        Err(AllocationError::MemoryLimitExceeded { reserved, limit: max, .. })
+            if reserved == limit && max == limit
+    ));
+}
+
+#[test]
+fn zeros_allocates_zeroed_memory() {
+    let client = client(&DummyDevice);
+    let handle = client.create(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    client.fill(&handle, 0);
+
+    assert_eq!(client.read_one(handle.binding()), vec![0; 8]);
+    assert_eq!(client.read_one(client.zeros(8).binding()), vec![0; 8]);
+}
+
+#[test]
+fn fill_repeats_pattern() {
+    let client = client(&DummyDevice);
+    let handle = client.empty(8);
+    client.fill(&handle, 0x04030201);
+
+    assert_eq!(
+        client.read_one(handle.binding()),
+        vec![1, 2, 3, 4, 1, 2, 3, 4]
+    );
+}
+
+#[test]
+fn slices_alias_their_parent_but_not_other_allocations() {
+    let client = client(&DummyDevice);
+    let parent = client.empty(16);
+    let other = client.empty(16);
+    let head = parent.clone().offset_end(8);
+    let tail = parent.clone().offset_start(8);
+
+    assert!(client.aliases(&parent, &head));
+    assert!(client.aliases(&tail, &parent));
+    assert!(!client.aliases(&head, &tail));
+    assert!(!client.aliases(&parent, &other));
+}
+
+#[test]
+fn tensor_pool_reuses_released_tensors_of_the_same_shape() {
+    let client = init_client();
+    let mut pool = TensorPool::new(client.clone());
+    let f32 = Elem::Float(FloatKind::F32);
+
+    for _ in 0..8 {
+        let tensor = pool.acquire(&[4, 8], f32);
+        assert_eq!(tensor.strides, vec![8, 1]);
+        assert_eq!(pool.num_free(), 0);
+        // A new allocation would leave the released tensor pooled next to it.
+        assert_eq!(client.memory_usage().number_allocs, 1);
+        pool.release(tensor);
+    }
+    assert_eq!(pool.num_free(), 1);
+
+    let other_shape = pool.acquire(&[8, 4], f32);
+    let other_elem = pool.acquire(&[4, 8], Elem::UInt(UIntKind::U32));
+    assert_eq!(pool.num_free(), 1);
+    assert_eq!(client.memory_usage().number_allocs, 3);
+    assert!(!client.aliases(&other_shape.handle, &other_elem.handle));
+}
+
+#[test]
+fn tensor_pool_drops_tensors_still_referenced() {
+    let client = init_client();
+    let mut pool = TensorPool::new(client.clone());
+    let f32 = Elem::Float(FloatKind::F32);
+
+    let tensor = pool.acquire(&[16], f32);
+    let kept = tensor.handle.clone();
+    pool.release(tensor);
+    assert_eq!(pool.num_free(), 0);
+
+    let tensor = pool.acquire(&[16], f32);
+    assert!(!client.aliases(&kept, &tensor.handle));
+}
+
 #[test]
 fn execute_elementwise_addition() {
     let client = client(&DummyDevice);
@@ -52,6 +152,313 @@ fn execute_elementwise_addition() {
     assert_eq!(obtained_resource, Vec::from([4, 5, 6]))
 }
 
+#[test]
+fn default_client_is_kept_per_runtime() {
+    struct OtherRuntime;
+
+    let default = init_client();
+    let other = init_client();
+    default_client::set::<Runtime, DummyClient>(default.clone());
+    default_client::set::<OtherRuntime, DummyClient>(other.clone());
+
+    let used = default_client::get::<Runtime, DummyClient>().unwrap();
+    assert!(used.ptr_eq(&default));
+    assert!(!used.ptr_eq(&other));
+
+    let cleared = default_client::clear::<Runtime, DummyClient>().unwrap();
+    assert!(cleared.ptr_eq(&default));
+    assert!(default_client::get::<Runtime, DummyClient>().is_none());
+    assert!(default_client::clear::<Runtime, DummyClient>().is_none());
+
+    let used = default_client::get::<OtherRuntime, DummyClient>().unwrap();
+    assert!(used.ptr_eq(&other));
+    default_client::clear::<OtherRuntime, DummyClient>();
+}
+
+#[test]
+fn poll_reports_completed_work() {
+    let client = client(&DummyDevice);
+    let lhs = client.create(&[0, 1, 2]);
+    let rhs = client.create(&[4, 4, 4]);
+    let out = client.empty(3);
+
+    client.execute(
+        Arc::new(DummyElementwiseAddition),
+        CubeCount::Static(1, 1, 1),
+        Bindings::new().with_buffers(vec![lhs.binding(), rhs.binding(), out.binding()]),
+    );
+
+    assert!(client.poll());
+    assert!(client.sync_timeout(std::time::Duration::ZERO));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn profile_markers_timestamp_each_kernel() {
+    let client = client(&DummyDevice);
+    let lhs = client.create(&[0, 1, 2]);
+    let rhs = client.create(&[4, 4, 4]);
+    let out = client.empty(3);
+    let launch = || {
+        client.execute(
+            Arc::new(DummyElementwiseAdditionSlowWrong),
+            CubeCount::Static(1, 1, 1),
+            Bindings::new().with_buffers(vec![
+                lhs.clone().binding(),
+                rhs.clone().binding(),
+                out.clone().binding(),
+            ]),
+        )
+    };
+
+    let timeline = client.profile_markers(|markers| {
+        markers.mark("start");
+        launch();
+        markers.mark("first");
+        launch();
+        markers.mark("second");
+    });
+    let markers = cubecl_common::future::block_on(timeline.resolve());
+
+    let names: Vec<_> = markers.iter().map(|marker| marker.name.as_str()).collect();
+    assert_eq!(names, ["start", "first", "second"]);
+    // The kernel sleeps for a millisecond per element.
+    for pair in markers.windows(2) {
+        assert!(pair[1].timestamp > pair[0].timestamp);
+        assert!(pair[1].delta >= Duration::from_millis(3));
+        assert_eq!(pair[1].timestamp, pair[0].timestamp + pair[1].delta);
+    }
+}
+
+#[test]
+fn process_chunks_handles_a_dataset_larger_than_the_buffers() {
+    const CHUNK_SIZE: usize = 3;
+    // Inputs and outputs of the three chunks in flight.
+    const BUFFER_SIZE: usize = 6 * CHUNK_SIZE;
+
+    let client = client(&DummyDevice);
+    // The last chunk is shorter than the others.
+    let dataset: Vec<u8> = (0..(3 * BUFFER_SIZE + 2) as u8).collect();
+    let rhs = client.create(&[4; CHUNK_SIZE]);
+    let events = std::cell::RefCell::new(Vec::new());
+    let mut inputs = Vec::new();
+    let mut result = Vec::new();
+
+    client.process_chunks(
+        dataset.chunks(CHUNK_SIZE),
+        |client, input| {
+            events
+                .borrow_mut()
+                .push(format!("compute {}", inputs.len()));
+            let out = client.empty(input.size() as usize);
+            client.execute(
+                Arc::new(DummyElementwiseAddition),
+                CubeCount::Static(1, 1, 1),
+                Bindings::new().with_buffers(vec![
+                    input.clone().binding(),
+                    rhs.clone().binding(),
+                    out.clone().binding(),
+                ]),
+            );
+            inputs.push(input);
+            out
+        },
+        |index, bytes| {
+            events.borrow_mut().push(format!("read {index}"));
+            result.extend(bytes);
+        },
+    );
+
+    // Every value is processed once and in order, including the ones on each side of the chunk
+    // boundaries.
+    let expected: Vec<u8> = dataset.iter().map(|value| value + 4).collect();
+    assert_eq!(result, expected);
+
+    // The chunks cycle through three input buffers.
+    let num_chunks = dataset.len().div_ceil(CHUNK_SIZE);
+    assert_eq!(inputs.len(), num_chunks);
+    assert_eq!(inputs[num_chunks - 1].size(), 2);
+    for (index, input) in inputs.iter().enumerate() {
+        for (other_index, other) in inputs.iter().enumerate().skip(index + 1) {
+            assert_eq!(input.aliases(other), (other_index - index) % 3 == 0);
+        }
+    }
+
+    // The result of a chunk is read back once the two next chunks are submitted, and before the
+    // buffer of the chunk is reused.
+    let events = events.into_inner();
+    for index in 0..num_chunks {
+        let read = position(&events, &format!("read {index}"));
+        assert!(
+            read > position(
+                &events,
+                &format!("compute {}", (index + 2).min(num_chunks - 1))
+            )
+        );
+        if index + 3 < num_chunks {
+            assert!(read < position(&events, &format!("compute {}", index + 3)));
+        }
+    }
+}
+
+fn position(events: &[String], event: &str) -> usize {
+    events.iter().position(|it| it == event).unwrap()
+}
+
+#[test]
+fn launch_observer_traces_every_dispatch() {
+    let client = client(&DummyDevice);
+    let traces = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let lhs = client.create(&[0, 1, 2]);
+    let rhs = client.create(&[4, 4, 4]);
+    let out = client.empty(3);
+
+    let traces_observer = traces.clone();
+    client.set_launch_observer(Box::new(move |info| {
+        traces_observer.lock().unwrap().push((
+            info.id.clone(),
+            format!("{:?}", info.cube_count),
+            info.bindings.buffers.len(),
+        ));
+    }));
+
+    for count in [CubeCount::Static(7, 3, 1), CubeCount::Static(7, 3, 2)] {
+        client.execute(
+            Arc::new(DummyElementwiseAddition),
+            count,
+            Bindings::new().with_buffers(vec![
+                lhs.clone().binding(),
+                rhs.clone().binding(),
+                out.clone().binding(),
+            ]),
+        );
+    }
+    client.clear_launch_observer();
+    client.execute(
+        Arc::new(DummyElementwiseAddition),
+        CubeCount::Static(7, 3, 3),
+        Bindings::new().with_buffers(vec![lhs.binding(), rhs.binding(), out.binding()]),
+    );
+
+    // Other tests may dispatch on the same device concurrently.
+    let traces: Vec<_> = traces
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|(_, count, _)| count.starts_with("(7, 3,"))
+        .cloned()
+        .collect();
+    assert_eq!(
+        traces,
+        vec![
+            (
+                "DummyElementwiseAddition".to_string(),
+                "(7, 3, 1)".to_string(),
+                3
+            ),
+            (
+                "DummyElementwiseAddition".to_string(),
+                "(7, 3, 2)".to_string(),
+                3
+            ),
+        ]
+    );
+}
+
+fn launch_compiled_flags(client: &DummyClient, launches: usize) -> Vec<bool> {
+    let compiled = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let lhs = client.create(&[0, 1, 2]);
+    let rhs = client.create(&[4, 4, 4]);
+    let out = client.empty(3);
+
+    let compiled_observer = compiled.clone();
+    client.set_launch_observer(Box::new(move |info| {
+        compiled_observer.lock().unwrap().push(info.compiled);
+    }));
+    for _ in 0..launches {
+        client.execute(
+            Arc::new(DummyElementwiseAddition),
+            CubeCount::Static(1, 1, 1),
+            Bindings::new().with_buffers(vec![
+                lhs.clone().binding(),
+                rhs.clone().binding(),
+                out.clone().binding(),
+            ]),
+        );
+    }
+    client.clear_launch_observer();
+
+    compiled.lock().unwrap().clone()
+}
+
+fn addition_kernel() -> (Arc<dyn DummyKernel>, ExecutionMode) {
+    (Arc::new(DummyElementwiseAddition), ExecutionMode::Checked)
+}
+
+#[test]
+fn kernel_is_compiled_on_its_first_launch() {
+    // A client of its own, so no other test compiles the kernel first.
+    let client = init_client();
+
+    assert_eq!(launch_compiled_flags(&client, 2), vec![true, false]);
+}
+
+#[test]
+fn precompiled_kernel_is_not_compiled_on_launch() {
+    let client = init_client();
+
+    client.precompile([addition_kernel()]).unwrap();
+
+    assert_eq!(launch_compiled_flags(&client, 1), vec![false]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn kernel_precompiled_in_background_is_not_compiled_on_launch() {
+    let client = init_client();
+
+    client
+        .precompile_in_background(vec![addition_kernel()])
+        .join()
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(launch_compiled_flags(&client, 1), vec![false]);
+}
+
+#[test]
+fn can_launch_reports_the_exceeded_limit() {
+    let client = init_client();
+    let kernel = || addition_kernel().0;
+
+    assert_eq!(
+        client.can_launch(kernel(), CubeDim::new_2d(64, 32), 0),
+        LaunchFeasibility::UnitsPerCubeExceeded {
+            requested: 2048,
+            max: 1024,
+        }
+    );
+    assert_eq!(
+        client.can_launch(kernel(), CubeDim::new_3d(1, 1, 128), 0),
+        LaunchFeasibility::CubeDimExceeded {
+            requested: CubeDim::new_3d(1, 1, 128),
+            max: CubeDim::new_3d(1024, 1024, 64),
+        }
+    );
+    assert_eq!(
+        client.can_launch(kernel(), CubeDim::new_1d(256), 64000),
+        LaunchFeasibility::SharedMemoryExceeded {
+            requested: 64000,
+            max: 48000,
+        }
+    );
+    assert!(
+        client
+            .can_launch(kernel(), CubeDim::new_1d(256), 1024)
+            .is_feasible()
+    );
+}
+
 #[test]
 #[serial]
 #[cfg(feature = "std")]
@@ -96,6 +503,173 @@ fn autotune_basic_multiplication_execution() {
     assert_eq!(obtained_resource, Vec::from([0, 4, 8]));
 }
 
+#[test]
+#[serial]
+#[cfg(feature = "std")]
+fn autotune_forced_config_is_used_without_benchmarking() {
+    TEST_TUNER.clear();
+    let client = client(&DummyDevice);
+
+    let shapes = vec![vec![1, 3], vec![1, 3], vec![1, 3]];
+    let lhs = client.create(&[0, 1, 2]);
+    let rhs = client.create(&[4, 4, 4]);
+    let out = client.empty(3);
+    let handles = vec![lhs.binding(), rhs.binding(), out.clone().binding()];
+
+    let test_set = dummy::forced_test_set(client.clone(), shapes);
+    let key = test_set.generate_key(&handles);
+    TEST_TUNER.force_config(&TUNER_DEVICE_ID.to_string(), None, key, 1);
+    autotune_execute(&client, &test_set, handles);
+
+    let obtained_resource = client.read_one(out.binding());
+
+    // The slow kernel is forced even if the fast one would win, benchmarking would panic
+    assert_eq!(obtained_resource, Vec::from([0, 1, 2]));
+}
+
+#[test]
+#[serial]
+#[cfg(feature = "std")]
+fn autotune_kernel_ids_with_the_same_key_are_tuned_separately() {
+    TEST_TUNER.clear();
+    let client = client(&DummyDevice);
+
+    let shapes = vec![vec![1, 3], vec![1, 3], vec![1, 3]];
+    let lhs = client.create(&[0, 1, 2]);
+    let rhs = client.create(&[4, 4, 4]);
+    let out_add = client.empty(3);
+    let out_mul = client.empty(3);
+    let handles_add = vec![
+        lhs.clone().binding(),
+        rhs.clone().binding(),
+        out_add.clone().binding(),
+    ];
+    let handles_mul = vec![lhs.binding(), rhs.binding(), out_mul.clone().binding()];
+
+    let add_set = dummy::kernel_variant_set(client.clone(), shapes.clone(), "add");
+    let mul_set = dummy::kernel_variant_set(client.clone(), shapes, "mul");
+    assert_eq!(
+        add_set.generate_key(&handles_add),
+        mul_set.generate_key(&handles_mul)
+    );
+
+    autotune_execute(&client, &add_set, handles_add.clone());
+    autotune_execute(&client, &mul_set, handles_mul.clone());
+    // Both results are cached by now, each for its own kernel variant.
+    autotune_execute(&client, &add_set, handles_add);
+    autotune_execute(&client, &mul_set, handles_mul);
+
+    // Sharing the cache, the multiplication would reuse the index of the fast addition, which is
+    // its slow and wrong operation returning lhs.
+    assert_eq!(client.read_one(out_add.binding()), Vec::from([4, 5, 6]));
+    assert_eq!(client.read_one(out_mul.binding()), Vec::from([0, 4, 8]));
+}
+
+#[test]
+#[serial]
+#[cfg(feature = "std")]
+fn autotune_forced_config_only_applies_to_its_kernel_id() {
+    TEST_TUNER.clear();
+    let client = client(&DummyDevice);
+
+    let shapes = vec![vec![1, 3], vec![1, 3], vec![1, 3]];
+    let lhs = client.create(&[0, 1, 2]);
+    let rhs = client.create(&[4, 4, 4]);
+    let out_add = client.empty(3);
+    let out_mul = client.empty(3);
+    let handles_add = vec![
+        lhs.clone().binding(),
+        rhs.clone().binding(),
+        out_add.clone().binding(),
+    ];
+    let handles_mul = vec![lhs.binding(), rhs.binding(), out_mul.clone().binding()];
+
+    let add_set = dummy::kernel_variant_set(client.clone(), shapes.clone(), "add");
+    let mul_set = dummy::kernel_variant_set(client.clone(), shapes, "mul");
+    let key = add_set.generate_key(&handles_add);
+    TEST_TUNER.force_config(&TUNER_DEVICE_ID.to_string(), Some("add"), key, 1);
+
+    autotune_execute(&client, &add_set, handles_add);
+    autotune_execute(&client, &mul_set, handles_mul);
+
+    // The slow addition is forced, returning lhs, while the multiplication is still tuned.
+    assert_eq!(client.read_one(out_add.binding()), Vec::from([0, 1, 2]));
+    assert_eq!(client.read_one(out_mul.binding()), Vec::from([0, 4, 8]));
+}
+
+#[test]
+#[serial]
+#[cfg(all(feature = "std", not(feature = "autotune-checks")))]
+fn autotune_candidate_exceeding_timeout_is_excluded() {
+    TEST_TUNER.clear();
+    let client = client(&DummyDevice);
+
+    let shapes = vec![vec![1, 3], vec![1, 3], vec![1, 3]];
+    let lhs = client.create(&[0, 1, 2]);
+    let rhs = client.create(&[4, 4, 4]);
+    let out = client.empty(3);
+    let handles = vec![lhs.binding(), rhs.binding(), out.clone().binding()];
+
+    let executions = Arc::new([AtomicUsize::new(0), AtomicUsize::new(0)]);
+    let test_set = dummy::slow_candidate_set(
+        client.clone(),
+        shapes,
+        Duration::from_millis(100),
+        executions.clone(),
+    );
+    let config = AutotuneConfig {
+        samples: 4,
+        warmup: 2,
+        per_candidate_timeout: Some(Duration::from_millis(50)),
+    };
+    TEST_TUNER.set_config(&TUNER_DEVICE_ID.to_string(), None, config);
+    autotune_execute(&client, &test_set, handles);
+
+    let obtained_resource = client.read_one(out.binding());
+
+    // The slow operation is abandoned after its first warmup, while the other one runs all its
+    // warmups and samples before being selected and executed.
+    assert_eq!(executions[0].load(Ordering::Relaxed), 1);
+    assert_eq!(executions[1].load(Ordering::Relaxed), 2 + 4 + 1);
+    assert_eq!(obtained_resource, Vec::from([4, 5, 6]));
+}
+
+#[test]
+#[serial]
+#[cfg(all(feature = "std", not(feature = "autotune-checks")))]
+fn autotune_without_samples_takes_one() {
+    TEST_TUNER.clear();
+    let client = client(&DummyDevice);
+
+    let shapes = vec![vec![1, 3], vec![1, 3], vec![1, 3]];
+    let lhs = client.create(&[0, 1, 2]);
+    let rhs = client.create(&[4, 4, 4]);
+    let out = client.empty(3);
+    let handles = vec![lhs.binding(), rhs.binding(), out.clone().binding()];
+
+    let executions = Arc::new([AtomicUsize::new(0), AtomicUsize::new(0)]);
+    let test_set = dummy::slow_candidate_set(
+        client.clone(),
+        shapes,
+        Duration::from_millis(10),
+        executions.clone(),
+    );
+    let config = AutotuneConfig {
+        samples: 0,
+        warmup: 1,
+        per_candidate_timeout: None,
+    };
+    TEST_TUNER.set_config(&TUNER_DEVICE_ID.to_string(), None, config);
+    autotune_execute(&client, &test_set, handles);
+
+    let obtained_resource = client.read_one(out.binding());
+
+    // Both operations run their warmup and a single sample, and the fast one is then executed.
+    assert_eq!(executions[0].load(Ordering::Relaxed), 1 + 1);
+    assert_eq!(executions[1].load(Ordering::Relaxed), 1 + 1 + 1);
+    assert_eq!(obtained_resource, Vec::from([4, 5, 6]));
+}
+
 #[test]
 #[serial]
 #[cfg(feature = "std")]
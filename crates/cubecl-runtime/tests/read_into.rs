@@ -0,0 +1,62 @@
+#[allow(dead_code)]
+mod dummy;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use crate::dummy::{DummyDevice, client};
+
+/// Allocator counting the allocations made by the current thread.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
+
+#[test]
+fn read_into_does_not_allocate() {
+    let client = client(&DummyDevice);
+    let data = [1.0f32, 2.0, 3.0, 4.0];
+    let handle = client.create(bytemuck::cast_slice(&data));
+    let mut destination = [0.0f32; 4];
+
+    // The first read can lazily initialize the client state.
+    client.read_into_typed(handle.clone().binding(), &mut destination);
+    destination = [0.0; 4];
+
+    let binding = handle.binding();
+    let before = allocations();
+    client.read_into_typed(binding, &mut destination);
+    let after = allocations();
+
+    assert_eq!(after - before, 0);
+    assert_eq!(destination, data);
+}
+
+#[test]
+#[should_panic(expected = "doesn't match")]
+fn read_into_checks_destination_size() {
+    let client = client(&DummyDevice);
+    let handle = client.create(&[0, 1, 2, 3]);
+    let mut destination = [0u8; 3];
+
+    client.read_into(handle.binding(), &mut destination);
+}
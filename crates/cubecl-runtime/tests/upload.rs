@@ -0,0 +1,48 @@
+#[allow(dead_code)]
+mod dummy;
+
+use std::io::Write;
+
+use cubecl_runtime::client::UPLOAD_CHUNK_SIZE;
+
+use crate::dummy::{DummyDevice, client};
+
+/// Write `data` to a new file in the temporary directory and return its path.
+fn temp_file(name: &str, data: &[u8]) -> std::path::PathBuf {
+    let path = std::env::temp_dir().join(format!("cubecl-{}-{name}", std::process::id()));
+    std::fs::File::create(&path)
+        .unwrap()
+        .write_all(data)
+        .unwrap();
+    path
+}
+
+#[test]
+fn upload_from_mmap_copies_the_range() {
+    let client = client(&DummyDevice);
+    // Spans several chunks and ends with a partial one.
+    let data = (0..2 * UPLOAD_CHUNK_SIZE + 1000)
+        .map(|i| (i % 251) as u8)
+        .collect::<Vec<_>>();
+    let path = temp_file("range", &data);
+
+    let handle = unsafe { client.upload_from_mmap(&path, 4096, data.len() - 4096) }.unwrap();
+    let actual = client.read_one(handle.binding());
+    std::fs::remove_file(path).unwrap();
+
+    assert_eq!(actual, &data[4096..]);
+}
+
+#[test]
+fn upload_from_mmap_checks_the_file_length() {
+    let client = client(&DummyDevice);
+    let path = temp_file("short", &[0u8; 64]);
+
+    let result = unsafe { client.upload_from_mmap(&path, 32, 64) };
+    std::fs::remove_file(path).unwrap();
+
+    assert_eq!(
+        result.unwrap_err().kind(),
+        std::io::ErrorKind::UnexpectedEof
+    );
+}
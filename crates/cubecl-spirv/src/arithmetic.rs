@@ -134,6 +134,11 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
                     if matches!(lhs.elem(), Elem::Int(_, _)) {
                         self.capabilities.insert(Capability::DotProduct);
                     }
+                    // Lines of 4 8-bit integers, like the ones accumulated into 32-bit integers by
+                    // `dot_i32`, need their own capability.
+                    if matches!(lhs.item(), Item::Vector(Elem::Int(8, _), 4)) {
+                        self.capabilities.insert(Capability::DotProductInput4x8Bit);
+                    }
 
                     match (lhs.elem(), rhs.elem()) {
                         (Elem::Int(_, false), Elem::Int(_, false)) => {
@@ -197,6 +202,19 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
                     b.f_div(ty, Some(out), one, input).unwrap();
                 });
             }
+            Arithmetic::RecipFast(op) => {
+                self.compile_unary_op_cast(op, out, uniform, |b, out_ty, ty, input, out| {
+                    let one = b.static_cast(ConstVal::Bit32(1), &Elem::Int(32, false), &out_ty);
+                    b.f_div(ty, Some(out), one, input).unwrap();
+                    b.decorate(out, Decoration::RelaxedPrecision, []);
+                });
+            }
+            Arithmetic::RsqrtFast(op) => {
+                self.compile_unary_op_cast(op, out, uniform, |b, _out_ty, ty, input, out| {
+                    T::inverse_sqrt(b, ty, input, out);
+                    b.decorate(out, Decoration::RelaxedPrecision, []);
+                })
+            }
             Arithmetic::Neg(op) => {
                 self.compile_unary_op_cast(op, out, uniform, |b, out_ty, ty, input, out| {
                     match out_ty.elem() {
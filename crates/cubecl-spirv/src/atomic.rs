@@ -1,10 +1,10 @@
-use cubecl_core::ir::{AtomicOp, Variable};
-use rspirv::spirv::{Capability, MemorySemantics, Scope};
+use cubecl_core::ir::{AtomicOp, Variable as IrVariable};
+use rspirv::spirv::{Capability, MemorySemantics, Scope, Word};
 
-use crate::{SpirvCompiler, SpirvTarget, item::Elem};
+use crate::{SpirvCompiler, SpirvTarget, item::Elem, variable::Variable};
 
 impl<T: SpirvTarget> SpirvCompiler<T> {
-    pub fn compile_atomic(&mut self, atomic: AtomicOp, out: Option<Variable>) {
+    pub fn compile_atomic(&mut self, atomic: AtomicOp, out: Option<IrVariable>) {
         let out = out.unwrap();
         match atomic {
             AtomicOp::Load(op) => {
@@ -16,8 +16,7 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
                 let out_id = self.write_id(&out);
 
                 let ty = out_ty.id(self);
-                let memory = self.const_u32(Scope::Device as u32);
-                let semantics = self.const_u32(MemorySemantics::UNIFORM_MEMORY.bits());
+                let (memory, semantics) = self.atomic_scope(&input);
 
                 self.atomic_load(ty, Some(out_id), input_id, memory, semantics)
                     .unwrap();
@@ -30,8 +29,7 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
                 let input_id = self.read(&input);
                 let out_id = out.id(self);
 
-                let memory = self.const_u32(Scope::Device as u32);
-                let semantics = self.const_u32(MemorySemantics::UNIFORM_MEMORY.bits());
+                let (memory, semantics) = self.atomic_scope(&out);
 
                 self.atomic_store(out_id, memory, semantics, input_id)
                     .unwrap();
@@ -47,8 +45,7 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
                 let out_id = self.write_id(&out);
 
                 let ty = out_ty.id(self);
-                let memory = self.const_u32(Scope::Device as u32);
-                let semantics = self.const_u32(MemorySemantics::UNIFORM_MEMORY.bits());
+                let (memory, semantics) = self.atomic_scope(&lhs);
 
                 self.atomic_exchange(ty, Some(out_id), lhs_id, memory, semantics, rhs_id)
                     .unwrap();
@@ -67,9 +64,7 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
                 let out_id = self.write_id(&out);
 
                 let ty = out_ty.id(self);
-                let memory = self.const_u32(Scope::Device as u32);
-                let semantics_success = self.const_u32(MemorySemantics::UNIFORM_MEMORY.bits());
-                let semantics_failure = self.const_u32(MemorySemantics::UNIFORM_MEMORY.bits());
+                let (memory, semantics) = self.atomic_scope(&atomic);
 
                 assert!(
                     matches!(out_ty.elem(), Elem::Int(_, _)),
@@ -80,8 +75,8 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
                     Some(out_id),
                     atomic_id,
                     memory,
-                    semantics_success,
-                    semantics_failure,
+                    semantics,
+                    semantics,
                     val_id,
                     cmp_id,
                 )
@@ -99,8 +94,7 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
                 let out_id = self.write_id(&out);
 
                 let ty = out_ty.id(self);
-                let memory = self.const_u32(Scope::Device as u32);
-                let semantics = self.const_u32(MemorySemantics::UNIFORM_MEMORY.bits());
+                let (memory, semantics) = self.atomic_scope(&lhs);
 
                 match out_ty.elem() {
                     Elem::Int(_, _) => self
@@ -132,8 +126,7 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
                 let out_id = self.write_id(&out);
 
                 let ty = out_ty.id(self);
-                let memory = self.const_u32(Scope::Device as u32);
-                let semantics = self.const_u32(MemorySemantics::UNIFORM_MEMORY.bits());
+                let (memory, semantics) = self.atomic_scope(&lhs);
 
                 assert!(
                     matches!(out_ty.elem(), Elem::Int(_, _)),
@@ -171,8 +164,7 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
                 let out_id = self.write_id(&out);
 
                 let ty = out_ty.id(self);
-                let memory = self.const_u32(Scope::Device as u32);
-                let semantics = self.const_u32(MemorySemantics::UNIFORM_MEMORY.bits());
+                let (memory, semantics) = self.atomic_scope(&lhs);
 
                 match out_ty.elem() {
                     Elem::Int(_, false) => self
@@ -206,8 +198,7 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
                 let out_id = self.write_id(&out);
 
                 let ty = out_ty.id(self);
-                let memory = self.const_u32(Scope::Device as u32);
-                let semantics = self.const_u32(MemorySemantics::UNIFORM_MEMORY.bits());
+                let (memory, semantics) = self.atomic_scope(&lhs);
 
                 match out_ty.elem() {
                     Elem::Int(_, false) => self
@@ -241,8 +232,7 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
                 let out_id = self.write_id(&out);
 
                 let ty = out_ty.id(self);
-                let memory = self.const_u32(Scope::Device as u32);
-                let semantics = self.const_u32(MemorySemantics::UNIFORM_MEMORY.bits());
+                let (memory, semantics) = self.atomic_scope(&lhs);
 
                 assert!(
                     matches!(out_ty.elem(), Elem::Int(_, _)),
@@ -263,8 +253,7 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
                 let out_id = self.write_id(&out);
 
                 let ty = out_ty.id(self);
-                let memory = self.const_u32(Scope::Device as u32);
-                let semantics = self.const_u32(MemorySemantics::UNIFORM_MEMORY.bits());
+                let (memory, semantics) = self.atomic_scope(&lhs);
 
                 assert!(
                     matches!(out_ty.elem(), Elem::Int(_, _)),
@@ -285,8 +274,7 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
                 let out_id = self.write_id(&out);
 
                 let ty = out_ty.id(self);
-                let memory = self.const_u32(Scope::Device as u32);
-                let semantics = self.const_u32(MemorySemantics::UNIFORM_MEMORY.bits());
+                let (memory, semantics) = self.atomic_scope(&lhs);
 
                 assert!(
                     matches!(out_ty.elem(), Elem::Int(_, _)),
@@ -298,4 +286,22 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
             }
         }
     }
+
+    /// The memory scope and semantics of an atomic operation on `pointer`. Atomics in shared memory
+    /// are only visible to the workgroup, so they use the workgroup scope and memory.
+    fn atomic_scope(&mut self, pointer: &Variable) -> (Word, Word) {
+        let shared = pointer
+            .as_binding()
+            .is_some_and(|id| self.state.shared_atomics.contains(&id));
+        let (scope, semantics) = if shared {
+            (Scope::Workgroup, MemorySemantics::WORKGROUP_MEMORY)
+        } else {
+            (Scope::Device, MemorySemantics::UNIFORM_MEMORY)
+        };
+
+        (
+            self.const_u32(scope as u32),
+            self.const_u32(semantics.bits()),
+        )
+    }
 }
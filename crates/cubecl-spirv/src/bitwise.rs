@@ -70,6 +70,9 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
                     b.i_add(ty, Some(out), lsb, one).unwrap();
                 });
             }
+            Bitwise::TrailingZeros(_) => {
+                unreachable!("Replaced by transformer")
+            }
         }
     }
 }
@@ -144,3 +147,37 @@ pub(crate) fn u64_ffs<I: Int>(x: Line<I>, out: &mut Line<u32>) {
     );
     *out = select_many(low_ffs.equal(Line::new(0)), high_ffs, low_ffs);
 }
+
+/// The first set bit is one-based and zero when no bit is set, in which case all `width` bits are
+/// trailing zeros.
+#[cube]
+pub(crate) fn trailing_zeros<I: Int>(x: Line<I>, out: &mut Line<u32>, #[comptime] width: u32) {
+    let ffs = Line::find_first_set(x);
+    *out = select_many(
+        ffs.equal(Line::new(0)),
+        Line::new(width),
+        ffs - Line::new(1),
+    );
+}
+
+/// Same as [trailing_zeros], with the high half only counted when the low half is empty.
+#[cube]
+pub(crate) fn u64_trailing_zeros<I: Int>(x: Line<I>, out: &mut Line<u32>) {
+    let shift = Line::new(I::new(32));
+
+    let low = Line::<u32>::cast_from(x);
+    let high = Line::<u32>::cast_from(x >> shift);
+    let low_ffs = Line::find_first_set(low);
+    let high_ffs = Line::find_first_set(high);
+
+    let high_zeros = select_many(
+        high_ffs.equal(Line::new(0)),
+        Line::new(64),
+        high_ffs + Line::new(31),
+    );
+    *out = select_many(
+        low_ffs.equal(Line::new(0)),
+        high_zeros,
+        low_ffs - Line::new(1),
+    );
+}
@@ -10,7 +10,7 @@ use std::{
     rc::Rc,
 };
 
-use cubecl_core::{Compiler, compute::KernelDefinition};
+use cubecl_core::{CompilationError, CompilationTarget, Compiler, compute::KernelDefinition};
 use rspirv::{
     dr::{Builder, InsertPoint, Instruction, Module, Operand},
     spirv::{self, BuiltIn, Capability, Decoration, FPFastMathMode, Op, StorageClass, Word},
@@ -22,7 +22,7 @@ use crate::{
     item::Item,
     lookups::LookupTables,
     target::{GLCompute, SpirvTarget},
-    transformers::{BitwiseTransform, ErfTransform},
+    transformers::{BitwiseTransform, CastRoundedTransform, ErfTransform},
 };
 
 pub struct SpirvCompiler<Target: SpirvTarget = GLCompute> {
@@ -45,7 +45,7 @@ pub struct SpirvCompiler<Target: SpirvTarget = GLCompute> {
     pub ext_meta_pos: Vec<u32>,
     pub metadata: Metadata,
     pub debug_info: Option<DebugInfo>,
-    compilation_options: WgpuCompilationOptions,
+    pub compilation_options: WgpuCompilationOptions,
 }
 
 unsafe impl<T: SpirvTarget> Send for SpirvCompiler<T> {}
@@ -125,7 +125,7 @@ impl<T: SpirvTarget> Compiler for SpirvCompiler<T> {
         value: KernelDefinition,
         compilation_options: &Self::CompilationOptions,
         mode: ExecutionMode,
-    ) -> Self::Representation {
+    ) -> Result<Self::Representation, CompilationError> {
         let bindings = value.buffers.clone();
         let scalars = value
             .scalars
@@ -158,13 +158,39 @@ impl<T: SpirvTarget> Compiler for SpirvCompiler<T> {
         self.ext_meta_pos = ext_meta_pos;
 
         let (module, optimizer) = self.compile_kernel(value);
-        SpirvKernel {
+
+        if let Some(op) = self.state.unsupported_instruction.take() {
+            return Err(CompilationError::UnsupportedInstruction {
+                op,
+                backend: "SPIR-V",
+            });
+        }
+
+        if let Some(limit) = self.compilation_options.max_shared_memory_size {
+            let requested = self.shared_memory_size();
+            if requested > limit {
+                return Err(CompilationError::SharedMemoryExceeded { requested, limit });
+            }
+        }
+
+        if let Some(plane_size) = self.compilation_options.plane_size_min {
+            let partition_size = self.state.max_plane_partition_size;
+            if partition_size > plane_size {
+                return Err(CompilationError::InvalidPlanePartition {
+                    partition_size,
+                    plane_size,
+                });
+            }
+        }
+
+        Ok(SpirvKernel {
             module,
             optimizer,
             bindings,
             scalars,
+            push_constant_offsets: self.state.push_constant_offsets.clone(),
             has_metadata: self.metadata.static_len() > 0,
-        }
+        })
     }
 
     fn elem_size(&self, elem: core::Elem) -> usize {
@@ -174,6 +200,10 @@ impl<T: SpirvTarget> Compiler for SpirvCompiler<T> {
     fn extension(&self) -> &'static str {
         "spv"
     }
+
+    fn target(&self) -> CompilationTarget {
+        CompilationTarget::SpirV
+    }
 }
 
 impl<Target: SpirvTarget> Debug for SpirvCompiler<Target> {
@@ -207,6 +237,7 @@ impl<Target: SpirvTarget> SpirvCompiler<Target> {
         let mut opt = OptimizerBuilder::default()
             .with_transformer(ErfTransform)
             .with_transformer(BitwiseTransform)
+            .with_transformer(CastRoundedTransform)
             .optimize(kernel.body, kernel.cube_dim, self.mode);
 
         self.uniformity = opt.analysis::<Uniformity>();
@@ -354,6 +385,15 @@ impl<Target: SpirvTarget> SpirvCompiler<Target> {
         id
     }
 
+    /// Total size in bytes of the workgroup memory declared by the kernel.
+    pub fn shared_memory_size(&self) -> usize {
+        self.state
+            .shared_memories
+            .values()
+            .map(|memory| memory.item.size() as usize * memory.len as usize)
+            .sum()
+    }
+
     fn declare_shared_memories(&mut self) {
         let shared_memories = self.state.shared_memories.clone();
         for (_, memory) in shared_memories {
@@ -371,7 +411,8 @@ impl<Target: SpirvTarget> SpirvCompiler<Target> {
         let types = self.builder.module_ref().types_global_values.clone();
         let scalars = types
             .iter()
-            .filter(|inst| inst.class.opcode == Op::TypeFloat)
+            // bf16 only supports conversions, so it has no fast math mode
+            .filter(|inst| inst.class.opcode == Op::TypeFloat && inst.operands.len() == 1)
             .map(|it| it.result_id.expect("OpTypeFloat always has result ID"))
             .collect::<Vec<_>>();
         for ty in scalars {
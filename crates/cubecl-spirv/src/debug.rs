@@ -255,6 +255,9 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
                 core::NonSemantic::Comment { .. } => {
                     // Comments not supported for SPIR-V
                 }
+                core::NonSemantic::Prefetch { .. } => {
+                    // SPIR-V has no prefetch instruction
+                }
                 core::NonSemantic::EnterDebugScope => {
                     let new_top = self.stack_top().clone();
                     self.stack().push(new_top);
@@ -78,7 +78,7 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
             | Builtin::CubeClusterDimX
             | Builtin::CubeClusterDimY
             | Builtin::CubeClusterDimZ => self.constant_var(1),
-            Builtin::CubeCount => Variable::WorkgroupSize(self.get_or_insert_global(
+            Builtin::CubeCount => Variable::NumWorkgroups(self.get_or_insert_global(
                 Globals::NumWorkgroupsTotal,
                 |b: &mut SpirvCompiler<T>| {
                     let int = b.type_int(32, 0);
@@ -13,6 +13,10 @@ use crate::{
 
 impl<T: SpirvTarget> SpirvCompiler<T> {
     pub fn compile_operation(&mut self, inst: Instruction) {
+        // The kernel is rejected once an instruction isn't supported, so the rest isn't compiled.
+        if self.state.unsupported_instruction.is_some() {
+            return;
+        }
         // Setting source loc for non-semantic ops is pointless, they don't show up in a profiler/debugger.
         if !matches!(inst.operation, Operation::NonSemantic(_)) {
             self.set_source_loc(&inst.source_loc);
@@ -38,15 +42,22 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
             Operation::Branch(_) => unreachable!("Branches shouldn't exist in optimized IR"),
             Operation::Metadata(meta) => self.compile_meta(meta, inst.out, uniform),
             Operation::Plane(plane) => self.compile_plane(plane, inst.out, uniform),
-            Operation::Synchronization(sync) => self.compile_sync(sync),
+            Operation::Synchronization(sync) => self.compile_sync(sync, inst.out),
             Operation::CoopMma(cmma) => self.compile_cmma(cmma, inst.out),
             Operation::NonSemantic(debug) => self.compile_debug(debug),
-            Operation::Pipeline(_) => panic!("Pipeline not supported in SPIR-V"),
-            Operation::Barrier(_) => panic!("Barrier not supported in SPIR-V"),
-            Operation::Tma(_) => panic!("TMA not supported in SPIR-V"),
+            Operation::Pipeline(_) => self.unsupported("pipeline"),
+            Operation::Barrier(_) => self.unsupported("barrier"),
+            Operation::Tma(_) => self.unsupported("TMA"),
         }
     }
 
+    /// Reject the kernel because it uses an instruction that SPIR-V doesn't support.
+    pub fn unsupported(&mut self, op: &str) {
+        self.state
+            .unsupported_instruction
+            .get_or_insert_with(|| op.to_string());
+    }
+
     pub fn compile_cmp(&mut self, op: Comparison, out: Option<core::Variable>, uniform: bool) {
         let out = out.unwrap();
         match op {
@@ -60,7 +71,7 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
                             b.decorate(out, Decoration::RelaxedPrecision, []);
                             b.f_ord_equal(ty, Some(out), lhs, rhs)
                         }
-                        Elem::Void => unreachable!(),
+                        Elem::Void | Elem::BFloat16 => unreachable!(),
                     }
                     .unwrap();
                 });
@@ -75,7 +86,7 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
                             b.decorate(out, Decoration::RelaxedPrecision, []);
                             b.f_ord_not_equal(ty, Some(out), lhs, rhs)
                         }
-                        Elem::Void => unreachable!(),
+                        Elem::Void | Elem::BFloat16 => unreachable!(),
                     }
                     .unwrap();
                 });
@@ -159,6 +170,9 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
                         _ => unreachable!("Atomic is always pointer"),
                     };
                     let out_id = out.as_binding().unwrap();
+                    if value.is_shared_memory() {
+                        self.state.shared_atomics.insert(out_id);
+                    }
 
                     // This isn't great but atomics can't currently be constructed so should be fine
                     self.merge_binding(out_id, ptr);
@@ -223,8 +237,9 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
                     },
                 );
             }
-            Operator::ReinterpretSlice(_) => {
-                todo!()
+            Operator::ReinterpretSlice(_) => self.unsupported("reinterpret slice"),
+            Operator::CastRte(_) | Operator::CastRtz(_) => {
+                unreachable!("Replaced by transformer")
             }
             Operator::Cast(op) => {
                 let input = self.compile_variable(op.input);
@@ -1,5 +1,7 @@
 use cubecl_core::ir::{self as core, FloatKind, IntKind, UIntKind};
-use rspirv::spirv::{Capability, CooperativeMatrixUse, Decoration, Scope, StorageClass, Word};
+use rspirv::spirv::{
+    Capability, CooperativeMatrixUse, Decoration, FPEncoding, Scope, StorageClass, Word,
+};
 
 use crate::{compiler::SpirvCompiler, target::SpirvTarget, variable::ConstVal};
 
@@ -102,6 +104,15 @@ impl Item {
         }
     }
 
+    /// The item values of this item are computed with, which only differs for bf16 storage.
+    pub fn compute_item(&self) -> Item {
+        match self {
+            Item::Scalar(Elem::BFloat16) => Item::Scalar(Elem::Float(32)),
+            Item::Vector(Elem::BFloat16, factor) => Item::Vector(Elem::Float(32), *factor),
+            other => other.clone(),
+        }
+    }
+
     pub fn same_vectorization(&self, elem: Elem) -> Item {
         match self {
             Item::Scalar(_) => Item::Scalar(elem),
@@ -259,6 +270,9 @@ pub enum Elem {
     Int(u32, bool),
     Float(u32),
     Relaxed,
+    /// bf16 in memory, loaded and stored from f32 values. Uses the native type when supported and
+    /// the `u16` bits otherwise.
+    BFloat16,
 }
 
 impl Elem {
@@ -269,6 +283,10 @@ impl Elem {
             Elem::Int(width, _) => b.type_int(*width, 0),
             Elem::Float(width) => b.type_float(*width, None),
             Elem::Relaxed => b.type_float(32, None),
+            Elem::BFloat16 if b.compilation_options.supports_bf16 => {
+                b.type_float(16, Some(FPEncoding::BFloat16KHR))
+            }
+            Elem::BFloat16 => b.type_int(16, 0),
         };
         if b.debug_symbols && !b.state.debug_types.contains(&id) {
             b.debug_name(id, format!("{self}"));
@@ -284,6 +302,7 @@ impl Elem {
             Elem::Int(size, _) => *size / 8,
             Elem::Float(size) => *size / 8,
             Elem::Relaxed => 4,
+            Elem::BFloat16 => 2,
         }
     }
 
@@ -314,9 +333,29 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
         }
     }
 
+    /// Compile the item of an array in memory, where bf16 values are kept in 16 bits while they are
+    /// computed with as f32 everywhere else.
+    pub fn compile_storage_item(&mut self, item: core::Item) -> Item {
+        match item.elem {
+            core::Elem::Float(FloatKind::BF16) => {
+                match self.compilation_options.supports_bf16 {
+                    true => self.capabilities.insert(Capability::BFloat16TypeKHR),
+                    false => self.capabilities.insert(Capability::Int16),
+                };
+                let vectorization = item.vectorization.map(|it| it.get()).unwrap_or(1);
+                match vectorization {
+                    1 => Item::Scalar(Elem::BFloat16),
+                    _ => Item::Vector(Elem::BFloat16, vectorization as u32),
+                }
+            }
+            _ => self.compile_item(item),
+        }
+    }
+
     pub fn compile_elem(&mut self, elem: core::Elem) -> Elem {
         match elem {
-            core::Elem::Float(core::FloatKind::BF16) => panic!("BFloat16 not supported in SPIR-V"),
+            // Only memory holds bf16, see `compile_storage_item`.
+            core::Elem::Float(core::FloatKind::BF16) => Elem::Float(32),
             core::Elem::Float(FloatKind::F16) => {
                 self.capabilities.insert(Capability::Float16);
                 Elem::Float(16)
@@ -456,7 +495,7 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
             (core::ConstantScalarValue::Bool(val), Elem::Relaxed) => {
                 ConstVal::from_float(val as u32 as f64, 32)
             }
-            (_, Elem::Void) => unreachable!(),
+            (_, Elem::Void | Elem::BFloat16) => unreachable!(),
         };
         item.constant(self, value)
     }
@@ -502,7 +541,7 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
             (Elem::Float(in_w), Elem::Relaxed) => ConstVal::from_float(val.as_float(in_w), 32),
             (Elem::Bool, Elem::Bool) => val,
             (Elem::Relaxed, Elem::Relaxed) => val,
-            (_, Elem::Void) | (Elem::Void, _) => unreachable!(),
+            (_, Elem::Void | Elem::BFloat16) | (Elem::Void | Elem::BFloat16, _) => unreachable!(),
         };
         item.constant(self, elem_cast)
     }
@@ -537,6 +576,7 @@ impl std::fmt::Display for Elem {
             Elem::Int(width, true) => write!(f, "i{width}"),
             Elem::Float(width) => write!(f, "f{width}"),
             Elem::Relaxed => write!(f, "flex32"),
+            Elem::BFloat16 => write!(f, "bf16"),
         }
     }
 }
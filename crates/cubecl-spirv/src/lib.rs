@@ -36,6 +36,9 @@ pub struct SpirvKernel {
     pub optimizer: Optimizer,
     pub bindings: Vec<Binding>,
     pub scalars: Vec<(Elem, usize)>,
+    /// Byte offsets of the scalar arrays in the push constant block, in the order of the scalars,
+    /// when the scalars are sent as push constants.
+    pub push_constant_offsets: Option<Vec<u32>>,
     pub has_metadata: bool,
 }
 
@@ -1,13 +1,15 @@
 use std::collections::VecDeque;
 
 use cubecl_core::{
-    compute::{Binding, Location, Visibility},
+    compute::{Binding, Location, ScalarBinding, Visibility},
     ir::{self, Id, VariableKind},
     prelude::KernelDefinition,
 };
 use cubecl_opt::{ConstArray, NodeIndex};
 use hashbrown::{HashMap, HashSet};
-use rspirv::spirv::{BuiltIn, CooperativeMatrixLayout, CooperativeMatrixUse, StorageClass, Word};
+use rspirv::spirv::{
+    BuiltIn, CooperativeMatrixLayout, CooperativeMatrixUse, Decoration, StorageClass, Word,
+};
 
 use crate::{
     SpirvCompiler, SpirvTarget,
@@ -19,6 +21,10 @@ use crate::{
 pub struct LookupTables {
     pub buffers: Vec<Word>,
     pub scalar_bindings: HashMap<ir::Elem, Word>,
+    /// Push constant block holding the scalars instead of the scalar bindings, if any.
+    pub push_constants: Option<Word>,
+    pub push_constant_members: HashMap<ir::Elem, u32>,
+    pub push_constant_offsets: Option<Vec<u32>>,
     pub info: Word,
     pub cube_dims: Vec<Word>,
     pub cube_size: Word,
@@ -41,12 +47,18 @@ pub struct LookupTables {
     pub end_labels: HashMap<NodeIndex, Word>,
 
     pub slices: HashMap<Id, Slice>,
+    /// Bindings of pointers to atomics in shared memory.
+    pub shared_atomics: HashSet<Id>,
 
     pub extensions: HashMap<String, Word>,
     // For break, continue
     pub loops: VecDeque<Loop>,
 
     pub debug_types: HashSet<Word>,
+    /// Largest partition size used by a partitioned plane operation, `0` when there are none.
+    pub max_plane_partition_size: u32,
+    /// The first instruction of the kernel that SPIR-V doesn't support, if any.
+    pub unsupported_instruction: Option<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -127,30 +139,81 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
             offset += 1;
         }
 
-        self.state.scalar_bindings = kernel
-            .scalars
-            .into_iter()
-            .enumerate()
-            .map(|(i, binding)| {
-                let elem = binding.elem;
-                let binding = Binding {
-                    id: i as u32 + offset,
-                    location: Location::Storage,
-                    visibility: Visibility::Read,
-                    item: ir::Item::new(elem),
-                    size: Some(binding.count),
-                    has_extended_meta: false,
-                };
-                let name = format!("scalars({elem})");
-                (elem, target.generate_binding(self, binding, name))
-            })
-            .collect();
+        // Push constants smaller than 32 bits need extra capabilities, keep them in buffers.
+        let push_constants = kernel.options.push_constants
+            && self.compilation_options.supports_push_constants
+            && kernel.scalars.iter().all(|scalar| scalar.elem.size() >= 4);
+
+        if push_constants {
+            self.declare_push_constants(&kernel.scalars);
+        } else {
+            self.state.scalar_bindings = kernel
+                .scalars
+                .into_iter()
+                .enumerate()
+                .map(|(i, binding)| {
+                    let elem = binding.elem;
+                    let binding = Binding {
+                        id: i as u32 + offset,
+                        location: Location::Storage,
+                        visibility: Visibility::Read,
+                        item: ir::Item::new(elem),
+                        size: Some(binding.count),
+                        has_extended_meta: false,
+                    };
+                    let name = format!("scalars({elem})");
+                    (elem, target.generate_binding(self, binding, name))
+                })
+                .collect();
+        }
 
         let cube_dims = [kernel.cube_dim.x, kernel.cube_dim.y, kernel.cube_dim.z];
         self.state.cube_dims = cube_dims.iter().map(|dim| self.const_u32(*dim)).collect();
         self.state.cube_size = self.const_u32(cube_dims.iter().product());
     }
 
+    /// Declare the scalars as one array per type in a push constant block, each array aligned on
+    /// the size of its type, the layout measured by [cubecl_core::compute::push_constants_size].
+    fn declare_push_constants(&mut self, scalars: &[ScalarBinding]) {
+        let mut members = Vec::with_capacity(scalars.len());
+        let mut offsets = Vec::with_capacity(scalars.len());
+        let mut offset = 0;
+
+        for (i, binding) in scalars.iter().enumerate() {
+            let size = binding.elem.size() as u32;
+            offset = offset.next_multiple_of(size);
+
+            let item = self.compile_item(ir::Item::new(binding.elem));
+            let array = Item::Array(Box::new(item), binding.count as u32);
+            members.push(array.id(self));
+            offsets.push(offset);
+            self.state
+                .push_constant_members
+                .insert(binding.elem, i as u32);
+
+            offset += size * binding.count as u32;
+        }
+
+        let struct_ty = self.id();
+        self.type_struct_id(Some(struct_ty), members);
+        self.decorate(struct_ty, Decoration::Block, vec![]);
+        for (i, offset) in offsets.iter().enumerate() {
+            self.member_decorate(
+                struct_ty,
+                i as u32,
+                Decoration::Offset,
+                vec![(*offset).into()],
+            );
+        }
+
+        let ptr_ty = self.type_pointer(None, StorageClass::PushConstant, struct_ty);
+        let var = self.variable(ptr_ty, None, StorageClass::PushConstant, None);
+        self.debug_name(var, "push_constants");
+
+        self.state.push_constants = Some(var);
+        self.state.push_constant_offsets = Some(offsets);
+    }
+
     pub fn const_u32(&mut self, value: u32) -> Word {
         let ty = Item::Scalar(Elem::Int(32, false));
         let ty_id = ty.id(self);
@@ -263,15 +326,27 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
             let current_block = self.selected_block();
             let setup = self.setup_block;
             self.select_block(Some(setup)).unwrap();
-            let arr_id = self.state.scalar_bindings[&elem];
             let item = self.compile_item(ir::Item::new(elem));
-            let arr = Variable::GlobalInputArray(arr_id, item.clone(), 0);
             let const_id = self.const_u32(id);
-            let index = Variable::ConstantScalar(const_id, id.into(), Elem::Int(32, false));
             let read_id = self.id();
             let var = Variable::GlobalScalar(read_id, item.elem());
             self.debug_var_name(read_id, ir_var);
-            self.read_indexed_unchecked(&var, &arr, &index);
+            if let Some(push_constants) = self.state.push_constants {
+                let member = self.state.push_constant_members[&elem];
+                let member = self.const_u32(member);
+                let ty = item.id(self);
+                let ptr_ty = self.type_pointer(None, StorageClass::PushConstant, ty);
+                let ptr = self
+                    .access_chain(ptr_ty, None, push_constants, vec![member, const_id])
+                    .unwrap();
+                self.load(ty, Some(read_id), ptr, None, vec![]).unwrap();
+            } else {
+                let arr_id = self.state.scalar_bindings[&elem];
+                let arr_item = self.compile_storage_item(ir::Item::new(elem));
+                let arr = Variable::GlobalInputArray(arr_id, arr_item, 0);
+                let index = Variable::ConstantScalar(const_id, id.into(), Elem::Int(32, false));
+                self.read_indexed_unchecked(&var, &arr, &index);
+            }
             self.select_block(current_block).unwrap();
             self.state.scalars.insert((id, elem), read_id);
             var
@@ -1,7 +1,12 @@
-use cubecl_core::ir::{Plane, UnaryOperator, Variable};
+use cubecl_core::ir::{
+    BinaryOperator, Builtin, Plane, PlanePartitionOperator, UnaryOperator, Variable,
+};
 use rspirv::spirv::{Capability, GroupOperation, Scope, Word};
 
-use crate::{SpirvCompiler, SpirvTarget, item::Elem};
+use crate::{
+    SpirvCompiler, SpirvTarget,
+    item::{Elem, Item},
+};
 
 impl<T: SpirvTarget> SpirvCompiler<T> {
     pub fn compile_plane(&mut self, plane: Plane, out: Option<Variable>, uniform: bool) {
@@ -103,87 +108,67 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
                     }
                 });
             }
+            Plane::ShuffleUp(op) => {
+                self.plane_shuffle_relative(op, out, uniform, false);
+            }
+            Plane::ShuffleDown(op) => {
+                self.plane_shuffle_relative(op, out, uniform, true);
+            }
             Plane::Sum(op) => {
-                self.plane_sum(op, out, GroupOperation::Reduce, uniform);
+                self.plane_sum(op, out, GroupOperation::Reduce, None, uniform);
             }
             Plane::ExclusiveSum(op) => {
-                self.plane_sum(op, out, GroupOperation::ExclusiveScan, uniform);
+                self.plane_sum(op, out, GroupOperation::ExclusiveScan, None, uniform);
             }
             Plane::InclusiveSum(op) => {
-                self.plane_sum(op, out, GroupOperation::InclusiveScan, uniform);
+                self.plane_sum(op, out, GroupOperation::InclusiveScan, None, uniform);
             }
             Plane::Prod(op) => {
-                self.plane_prod(op, out, GroupOperation::Reduce, uniform);
+                self.plane_prod(op, out, GroupOperation::Reduce, None, uniform);
             }
             Plane::ExclusiveProd(op) => {
-                self.plane_prod(op, out, GroupOperation::ExclusiveScan, uniform);
+                self.plane_prod(op, out, GroupOperation::ExclusiveScan, None, uniform);
             }
             Plane::InclusiveProd(op) => {
-                self.plane_prod(op, out, GroupOperation::InclusiveScan, uniform);
+                self.plane_prod(op, out, GroupOperation::InclusiveScan, None, uniform);
             }
             Plane::Min(op) => {
-                self.compile_unary_op(op, out, uniform, |b, out_ty, ty, input, out| {
-                    match out_ty.elem() {
-                        Elem::Int(_, false) => b.group_non_uniform_u_min(
-                            ty,
-                            Some(out),
-                            subgroup,
-                            GroupOperation::Reduce,
-                            input,
-                            None,
-                        ),
-                        Elem::Int(_, true) => b.group_non_uniform_s_min(
-                            ty,
-                            Some(out),
-                            subgroup,
-                            GroupOperation::Reduce,
-                            input,
-                            None,
-                        ),
-                        Elem::Float(_) | Elem::Relaxed => b.group_non_uniform_f_min(
-                            ty,
-                            Some(out),
-                            subgroup,
-                            GroupOperation::Reduce,
-                            input,
-                            None,
-                        ),
-                        _ => unreachable!(),
-                    }
-                    .unwrap();
-                });
+                self.plane_min(op, out, GroupOperation::Reduce, None, uniform);
             }
             Plane::Max(op) => {
-                self.compile_unary_op(op, out, uniform, |b, out_ty, ty, input, out| {
-                    match out_ty.elem() {
-                        Elem::Int(_, false) => b.group_non_uniform_u_max(
-                            ty,
-                            Some(out),
-                            subgroup,
-                            GroupOperation::Reduce,
-                            input,
-                            None,
-                        ),
-                        Elem::Int(_, true) => b.group_non_uniform_s_max(
-                            ty,
-                            Some(out),
-                            subgroup,
-                            GroupOperation::Reduce,
-                            input,
-                            None,
-                        ),
-                        Elem::Float(_) | Elem::Relaxed => b.group_non_uniform_f_max(
-                            ty,
-                            Some(out),
-                            subgroup,
-                            GroupOperation::Reduce,
-                            input,
-                            None,
-                        ),
-                        _ => unreachable!(),
-                    }
-                    .unwrap();
-                });
+                self.plane_max(op, out, GroupOperation::Reduce, None, uniform);
+            }
+            Plane::PartitionSum(op) => {
+                let (op, cluster_size) = self.plane_partition(op);
+                let action = GroupOperation::ClusteredReduce;
+                self.plane_sum(op, out, action, Some(cluster_size), uniform);
+            }
+            Plane::PartitionProd(op) => {
+                let (op, cluster_size) = self.plane_partition(op);
+                let action = GroupOperation::ClusteredReduce;
+                self.plane_prod(op, out, action, Some(cluster_size), uniform);
+            }
+            Plane::PartitionMin(op) => {
+                let (op, cluster_size) = self.plane_partition(op);
+                let action = GroupOperation::ClusteredReduce;
+                self.plane_min(op, out, action, Some(cluster_size), uniform);
+            }
+            Plane::PartitionMax(op) => {
+                let (op, cluster_size) = self.plane_partition(op);
+                let action = GroupOperation::ClusteredReduce;
+                self.plane_max(op, out, action, Some(cluster_size), uniform);
+            }
+            Plane::PartitionInclusiveSum(op) => {
+                self.plane_partition_scan(op, out, uniform, false, false);
+            }
+            Plane::PartitionExclusiveSum(op) => {
+                self.plane_partition_scan(op, out, uniform, false, true);
+            }
+            Plane::PartitionInclusiveProd(op) => {
+                self.plane_partition_scan(op, out, uniform, true, false);
+            }
+            Plane::PartitionExclusiveProd(op) => {
+                self.plane_partition_scan(op, out, uniform, true, true);
             }
         }
     }
@@ -193,16 +178,17 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
         op: UnaryOperator,
         out: Variable,
         action: GroupOperation,
+        cluster_size: Option<Word>,
         uniform: bool,
     ) {
         let subgroup = self.subgroup();
         self.compile_unary_op(op, out, uniform, |b, out_ty, ty, input, out| {
             match out_ty.elem() {
                 Elem::Int(_, _) => {
-                    b.group_non_uniform_i_add(ty, Some(out), subgroup, action, input, None)
+                    b.group_non_uniform_i_add(ty, Some(out), subgroup, action, input, cluster_size)
                 }
                 Elem::Float(_) | Elem::Relaxed => {
-                    b.group_non_uniform_f_add(ty, Some(out), subgroup, action, input, None)
+                    b.group_non_uniform_f_add(ty, Some(out), subgroup, action, input, cluster_size)
                 }
                 elem => unreachable!("{elem}"),
             }
@@ -215,16 +201,43 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
         op: UnaryOperator,
         out: Variable,
         action: GroupOperation,
+        cluster_size: Option<Word>,
         uniform: bool,
     ) {
         let subgroup = self.subgroup();
         self.compile_unary_op(op, out, uniform, |b, out_ty, ty, input, out| {
             match out_ty.elem() {
                 Elem::Int(_, _) => {
-                    b.group_non_uniform_i_mul(ty, Some(out), subgroup, action, input, None)
+                    b.group_non_uniform_i_mul(ty, Some(out), subgroup, action, input, cluster_size)
+                }
+                Elem::Float(_) | Elem::Relaxed => {
+                    b.group_non_uniform_f_mul(ty, Some(out), subgroup, action, input, cluster_size)
+                }
+                _ => unreachable!(),
+            }
+            .unwrap();
+        });
+    }
+
+    fn plane_min(
+        &mut self,
+        op: UnaryOperator,
+        out: Variable,
+        action: GroupOperation,
+        cluster_size: Option<Word>,
+        uniform: bool,
+    ) {
+        let subgroup = self.subgroup();
+        self.compile_unary_op(op, out, uniform, |b, out_ty, ty, input, out| {
+            match out_ty.elem() {
+                Elem::Int(_, false) => {
+                    b.group_non_uniform_u_min(ty, Some(out), subgroup, action, input, cluster_size)
+                }
+                Elem::Int(_, true) => {
+                    b.group_non_uniform_s_min(ty, Some(out), subgroup, action, input, cluster_size)
                 }
                 Elem::Float(_) | Elem::Relaxed => {
-                    b.group_non_uniform_f_mul(ty, Some(out), subgroup, action, input, None)
+                    b.group_non_uniform_f_min(ty, Some(out), subgroup, action, input, cluster_size)
                 }
                 _ => unreachable!(),
             }
@@ -232,6 +245,165 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
         });
     }
 
+    fn plane_max(
+        &mut self,
+        op: UnaryOperator,
+        out: Variable,
+        action: GroupOperation,
+        cluster_size: Option<Word>,
+        uniform: bool,
+    ) {
+        let subgroup = self.subgroup();
+        self.compile_unary_op(op, out, uniform, |b, out_ty, ty, input, out| {
+            match out_ty.elem() {
+                Elem::Int(_, false) => {
+                    b.group_non_uniform_u_max(ty, Some(out), subgroup, action, input, cluster_size)
+                }
+                Elem::Int(_, true) => {
+                    b.group_non_uniform_s_max(ty, Some(out), subgroup, action, input, cluster_size)
+                }
+                Elem::Float(_) | Elem::Relaxed => {
+                    b.group_non_uniform_f_max(ty, Some(out), subgroup, action, input, cluster_size)
+                }
+                _ => unreachable!(),
+            }
+            .unwrap();
+        });
+    }
+
+    /// Use the partition size as the cluster size of a clustered reduction, which reduces each
+    /// aligned cluster of units independently.
+    fn plane_partition(&mut self, op: PlanePartitionOperator) -> (UnaryOperator, Word) {
+        self.capabilities
+            .insert(Capability::GroupNonUniformClustered);
+        self.state.max_plane_partition_size =
+            self.state.max_plane_partition_size.max(op.partition_size);
+        let cluster_size = self.const_u32(op.partition_size);
+        (UnaryOperator { input: op.input }, cluster_size)
+    }
+
+    /// Hillis-Steele scan over each partition built from shuffles, since there are no clustered
+    /// scans. Each step adds the value `offset` units up, unless that unit is in a previous
+    /// partition. Exclusive scans then shift the inclusive scan up by one unit.
+    fn plane_partition_scan(
+        &mut self,
+        op: PlanePartitionOperator,
+        out: Variable,
+        uniform: bool,
+        prod: bool,
+        exclusive: bool,
+    ) {
+        self.capabilities
+            .insert(Capability::GroupNonUniformShuffleRelative);
+        self.state.max_plane_partition_size =
+            self.state.max_plane_partition_size.max(op.partition_size);
+        let subgroup = self.subgroup();
+        let lane = self.compile_builtin(Builtin::UnitPosPlane).id(self);
+        let partition_size = op.partition_size;
+        let op = UnaryOperator { input: op.input };
+
+        self.compile_unary_op(op, out, uniform, |b, out_ty, ty, input, out| {
+            let bool = b.type_bool();
+            let u32_ty = Item::Scalar(Elem::Int(32, false)).id(b);
+            let mask = b.const_u32(partition_size - 1);
+            let lane = b.bitwise_and(u32_ty, None, lane, mask).unwrap();
+            let within_partition = |b: &mut Self, offset: Word| {
+                let in_partition = b.u_greater_than_equal(bool, None, lane, offset).unwrap();
+                match &out_ty {
+                    Item::Vector(_, factor) => {
+                        let bool_vec = Item::Vector(Elem::Bool, *factor).id(b);
+                        b.composite_construct(bool_vec, None, vec![in_partition; *factor as usize])
+                            .unwrap()
+                    }
+                    _ => in_partition,
+                }
+            };
+
+            let mut acc = input;
+            let mut offset = 1;
+            while offset < partition_size {
+                let offset_id = b.const_u32(offset);
+                let shuffled = b
+                    .group_non_uniform_shuffle_up(ty, None, subgroup, acc, offset_id)
+                    .unwrap();
+                let combined = match (out_ty.elem(), prod) {
+                    (Elem::Int(_, _), false) => b.i_add(ty, None, acc, shuffled),
+                    (Elem::Int(_, _), true) => b.i_mul(ty, None, acc, shuffled),
+                    (Elem::Float(_) | Elem::Relaxed, false) => b.f_add(ty, None, acc, shuffled),
+                    (Elem::Float(_) | Elem::Relaxed, true) => b.f_mul(ty, None, acc, shuffled),
+                    (elem, _) => unreachable!("{elem}"),
+                }
+                .unwrap();
+                let in_partition = within_partition(b, offset_id);
+                acc = b.select(ty, None, in_partition, combined, acc).unwrap();
+                offset *= 2;
+            }
+
+            match exclusive {
+                false => {
+                    b.copy_object(ty, Some(out), acc).unwrap();
+                }
+                true => {
+                    let one = b.const_u32(1);
+                    let shuffled = b
+                        .group_non_uniform_shuffle_up(ty, None, subgroup, acc, one)
+                        .unwrap();
+                    let identity = out_ty.const_u32(b, prod as u32);
+                    let in_partition = within_partition(b, one);
+                    b.select(ty, Some(out), in_partition, shuffled, identity)
+                        .unwrap();
+                }
+            }
+        });
+    }
+
+    /// Shuffle up or down, keeping the value of the units without a source unit since the result
+    /// of the shuffle is undefined for them.
+    fn plane_shuffle_relative(
+        &mut self,
+        op: BinaryOperator,
+        out: Variable,
+        uniform: bool,
+        down: bool,
+    ) {
+        self.capabilities
+            .insert(Capability::GroupNonUniformShuffleRelative);
+        let subgroup = self.subgroup();
+        let lane = self.compile_builtin(Builtin::UnitPosPlane).id(self);
+        let plane_dim = self.compile_builtin(Builtin::PlaneDim).id(self);
+
+        self.compile_binary_op_no_cast(op, out, uniform, |b, out_ty, ty, lhs, rhs, out| {
+            let bool = b.type_bool();
+            let (shuffled, in_range) = match down {
+                false => {
+                    let shuffled = b
+                        .group_non_uniform_shuffle_up(ty, None, subgroup, lhs, rhs)
+                        .unwrap();
+                    let in_range = b.u_greater_than_equal(bool, None, lane, rhs).unwrap();
+                    (shuffled, in_range)
+                }
+                true => {
+                    let u32_ty = Item::Scalar(Elem::Int(32, false)).id(b);
+                    let shuffled = b
+                        .group_non_uniform_shuffle_down(ty, None, subgroup, lhs, rhs)
+                        .unwrap();
+                    let source = b.i_add(u32_ty, None, lane, rhs).unwrap();
+                    let in_range = b.u_less_than(bool, None, source, plane_dim).unwrap();
+                    (shuffled, in_range)
+                }
+            };
+            let in_range = match out_ty {
+                Item::Vector(_, factor) => {
+                    let bool_vec = Item::Vector(Elem::Bool, factor).id(b);
+                    b.composite_construct(bool_vec, None, vec![in_range; factor as usize])
+                        .unwrap()
+                }
+                _ => in_range,
+            };
+            b.select(ty, Some(out), in_range, shuffled, lhs).unwrap();
+        });
+    }
+
     fn subgroup(&mut self) -> Word {
         self.const_u32(Scope::Subgroup as u32)
     }
@@ -1,19 +1,12 @@
-use cubecl_core::ir::Synchronization;
+use cubecl_core::ir::{self as core, MemoryOrder, MemoryScope, Synchronization};
 use rspirv::spirv::{MemorySemantics, Scope};
 
 use crate::{SpirvCompiler, SpirvTarget};
 
 impl<T: SpirvTarget> SpirvCompiler<T> {
-    pub fn compile_sync(&mut self, sync: Synchronization) {
+    pub fn compile_sync(&mut self, sync: Synchronization, out: Option<core::Variable>) {
         match sync {
-            Synchronization::SyncUnits => {
-                // Adopting wgpu semantics
-                let scope = self.const_u32(Scope::Workgroup as u32);
-                let semantics =
-                    MemorySemantics::ACQUIRE_RELEASE | MemorySemantics::WORKGROUP_MEMORY;
-                let semantics = self.const_u32(semantics.bits());
-                self.control_barrier(scope, scope, semantics).unwrap();
-            }
+            Synchronization::SyncUnits => self.sync_units(),
             Synchronization::SyncStorage => {
                 // Adopting wgpu semantics
                 let scope_exec = self.const_u32(Scope::Workgroup as u32);
@@ -23,7 +16,53 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
                 self.control_barrier(scope_exec, scope_mem, semantics)
                     .unwrap();
             }
-            Synchronization::SyncProxyShared => panic!("TMA proxy sync not supported in SPIR-V"),
+            Synchronization::SyncProxyShared => self.unsupported("TMA proxy synchronization"),
+            Synchronization::UniformLoad(op) => {
+                // Same lowering as naga's `workgroupUniformLoad`
+                let value = self.compile_variable(op.lhs);
+                let index = self.compile_variable(op.rhs);
+                let out = self.compile_variable(out.unwrap());
+
+                self.sync_units();
+                let out_id = self.read_indexed_unchecked(&out, &value, &index);
+                self.sync_units();
+                self.write(&out, out_id);
+            }
+            Synchronization::MemoryFence { scope, order } => {
+                let scope = match scope {
+                    MemoryScope::Cube => Scope::Workgroup,
+                    MemoryScope::Device => Scope::Device,
+                };
+                let order = match order {
+                    MemoryOrder::Acquire => MemorySemantics::ACQUIRE,
+                    MemoryOrder::Release => MemorySemantics::RELEASE,
+                    MemoryOrder::AcquireRelease => MemorySemantics::ACQUIRE_RELEASE,
+                };
+                let semantics =
+                    order | MemorySemantics::UNIFORM_MEMORY | MemorySemantics::WORKGROUP_MEMORY;
+
+                let scope = self.const_u32(scope as u32);
+                let semantics = self.const_u32(semantics.bits());
+                self.memory_barrier(scope, semantics).unwrap();
+            }
+            // No clusters in SPIR-V, each workgroup is its own cluster.
+            Synchronization::SyncCluster => self.sync_units(),
+            Synchronization::ClusterLoad(op) => {
+                let value = self.compile_variable(op.shared);
+                let index = self.compile_variable(op.index);
+                let out = self.compile_variable(out.unwrap());
+
+                let out_id = self.read_indexed_unchecked(&out, &value, &index);
+                self.write(&out, out_id);
+            }
         }
     }
+
+    fn sync_units(&mut self) {
+        // Adopting wgpu semantics
+        let scope = self.const_u32(Scope::Workgroup as u32);
+        let semantics = MemorySemantics::ACQUIRE_RELEASE | MemorySemantics::WORKGROUP_MEMORY;
+        let semantics = self.const_u32(semantics.bits());
+        self.control_barrier(scope, scope, semantics).unwrap();
+    }
 }
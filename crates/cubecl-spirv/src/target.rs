@@ -64,6 +64,7 @@ impl SpirvTarget for GLCompute {
             .chain(b.state.buffers.iter().copied())
             .chain(iter::once(b.state.info))
             .chain(b.state.scalar_bindings.values().copied())
+            .chain(b.state.push_constants)
             .chain(b.state.shared_memories.values().map(|it| it.id))
             .collect();
 
@@ -101,6 +102,10 @@ impl SpirvTarget for GLCompute {
             b.extension("SPV_KHR_float_controls2");
         }
 
+        if caps.contains(&Capability::BFloat16TypeKHR) {
+            b.extension("SPV_KHR_bfloat16");
+        }
+
         if b.debug_symbols {
             b.extension("SPV_KHR_non_semantic_info");
         }
@@ -126,7 +131,7 @@ impl SpirvTarget for GLCompute {
         name: String,
     ) -> Word {
         let index = binding.id;
-        let item = b.compile_item(binding.item);
+        let item = b.compile_storage_item(binding.item);
         let item = match binding.size {
             Some(size) => Item::Array(Box::new(item), size as u32),
             None => Item::RuntimeArray(Box::new(item)),
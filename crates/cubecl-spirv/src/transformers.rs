@@ -1,13 +1,16 @@
 use cubecl_core::{
     ir::{
-        Arithmetic, Bitwise, Elem, ExpandElement, Instruction, IntKind, Operation, Scope, UIntKind,
-        Variable,
+        Arithmetic, Bitwise, Elem, ExpandElement, Instruction, IntKind, Operation, Operator, Scope,
+        UIntKind, Variable,
     },
-    prelude::{IntExpand, expand_erf},
+    prelude::{IntExpand, expand_cast_rounded, expand_erf},
 };
 use cubecl_opt::{IrTransformer, TransformAction};
 
-use crate::bitwise::{small_int_reverse, u64_count_bits, u64_ffs, u64_leading_zeros, u64_reverse};
+use crate::bitwise::{
+    small_int_reverse, trailing_zeros, u64_count_bits, u64_ffs, u64_leading_zeros, u64_reverse,
+    u64_trailing_zeros,
+};
 
 /// Expand erf
 #[derive(Debug)]
@@ -26,6 +29,24 @@ impl IrTransformer for ErfTransform {
     }
 }
 
+/// Expand casts with a rounding mode, since `FPRoundingMode` is only allowed on conversions stored
+/// straight to 16-bit buffers
+#[derive(Debug)]
+pub(crate) struct CastRoundedTransform;
+
+impl IrTransformer for CastRoundedTransform {
+    fn maybe_transform(&self, scope: &mut Scope, inst: &Instruction) -> TransformAction {
+        let (op, nearest) = match &inst.operation {
+            Operation::Operator(Operator::CastRte(op)) => (op, true),
+            Operation::Operator(Operator::CastRtz(op)) => (op, false),
+            _ => return TransformAction::Ignore,
+        };
+        let mut scope = scope.child();
+        expand_cast_rounded(&mut scope, op.input, inst.out(), nearest);
+        TransformAction::Replace(into_instructions(scope))
+    }
+}
+
 /// Transform operations that only support 32 bits using polyfills
 #[derive(Debug)]
 pub(crate) struct BitwiseTransform;
@@ -88,6 +109,30 @@ impl IrTransformer for BitwiseTransform {
                 );
                 TransformAction::Replace(into_instructions(scope))
             }
+            Bitwise::TrailingZeros(op) => {
+                let mut scope = scope.child();
+                scope.register_elem::<IntExpand<0>>(op.input.elem());
+                let input = ExpandElement::Plain(op.input);
+                let out = ExpandElement::Plain(inst.out());
+                match is_u64(op.input) {
+                    true => {
+                        u64_trailing_zeros::expand::<IntExpand<0>>(
+                            &mut scope,
+                            input.into(),
+                            out.into(),
+                        );
+                    }
+                    false => {
+                        trailing_zeros::expand::<IntExpand<0>>(
+                            &mut scope,
+                            input.into(),
+                            out.into(),
+                            op.input.elem().size() as u32 * 8,
+                        );
+                    }
+                }
+                TransformAction::Replace(into_instructions(scope))
+            }
             _ => TransformAction::Ignore,
         }
     }
@@ -155,9 +155,8 @@ impl From<ConstantScalarValue> for ConstVal {
         let width = value.elem().size() as u32 * 8;
         match value {
             ConstantScalarValue::Int(val, _) => ConstVal::from_int(val, width),
-            ConstantScalarValue::Float(_, FloatKind::BF16) => {
-                panic!("bf16 not supported in SPIR-V")
-            }
+            // bf16 is computed with as f32
+            ConstantScalarValue::Float(val, FloatKind::BF16) => ConstVal::from_float(val, 32),
             ConstantScalarValue::Float(val, _) => ConstVal::from_float(val, width),
             ConstantScalarValue::UInt(val, _) => ConstVal::from_uint(val, width),
             ConstantScalarValue::Bool(val) => ConstVal::from_bool(val),
@@ -306,6 +305,15 @@ impl Variable {
             _ => None,
         }
     }
+
+    /// Whether the variable is in shared memory, directly or through a slice.
+    pub fn is_shared_memory(&self) -> bool {
+        match self {
+            Self::SharedMemory(..) => true,
+            Self::Slice { ptr, .. } => ptr.is_shared_memory(),
+            _ => false,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -373,11 +381,11 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
                 .into(),
             ir::VariableKind::GlobalInputArray(pos) => {
                 let id = self.state.buffers[pos as usize];
-                Variable::GlobalInputArray(id, self.compile_item(item), pos)
+                Variable::GlobalInputArray(id, self.compile_storage_item(item), pos)
             }
             ir::VariableKind::GlobalOutputArray(pos) => {
                 let id = self.state.buffers[pos as usize];
-                Variable::GlobalOutputArray(id, self.compile_item(item), pos)
+                Variable::GlobalOutputArray(id, self.compile_storage_item(item), pos)
             }
             ir::VariableKind::GlobalScalar(id) => self.global_scalar(id, item.elem),
             ir::VariableKind::LocalMut { id } => {
@@ -405,7 +413,7 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
                 length,
                 alignment,
             } => {
-                let item = self.compile_item(item);
+                let item = self.compile_storage_item(item);
                 let id = if let Some(arr) = self.state.shared_memories.get(&id) {
                     arr.id
                 } else {
@@ -597,9 +605,8 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
 
         let read = |b: &mut Self| match indexed {
             IndexedVariable::Pointer(ptr, item) => {
-                let ty = item.id(b);
                 let out_id = b.write_id(out);
-                b.load(ty, Some(out_id), ptr, None, vec![]).unwrap()
+                b.load_ptr(ptr, &item, out_id)
             }
             IndexedVariable::Composite(var, index, item) => {
                 let elem = item.elem();
@@ -625,7 +632,7 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
             }
         };
         if checked && !always_in_bounds {
-            self.compile_read_bound(variable, index_id, variable.item(), read)
+            self.compile_read_bound(variable, index_id, variable.item().compute_item(), read)
         } else {
             read(self)
         }
@@ -641,9 +648,8 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
 
         match indexed {
             IndexedVariable::Pointer(ptr, item) => {
-                let ty = item.id(self);
                 let out_id = self.write_id(out);
-                self.load(ty, Some(out_id), ptr, None, vec![]).unwrap()
+                self.load_ptr(ptr, &item, out_id)
             }
             IndexedVariable::Composite(var, index, item) => {
                 let elem = item.elem();
@@ -670,6 +676,62 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
         }
     }
 
+    /// Load the value behind `ptr` into `out_id`, unpacking bf16 storage to f32.
+    fn load_ptr(&mut self, ptr: Word, item: &Item, out_id: Word) -> Word {
+        let ty = item.id(self);
+        if item.elem() != Elem::BFloat16 {
+            return self.load(ty, Some(out_id), ptr, None, vec![]).unwrap();
+        }
+
+        let value = self.load(ty, None, ptr, None, vec![]).unwrap();
+        let f32_ty = item.compute_item().id(self);
+        if self.compilation_options.supports_bf16 {
+            return self.f_convert(f32_ty, Some(out_id), value).unwrap();
+        }
+
+        // The bf16 bits are the high half of the f32 bits.
+        let u32_item = item.same_vectorization(Elem::Int(32, false));
+        let u32_ty = u32_item.id(self);
+        let sixteen = u32_item.const_u32(self, 16);
+        let bits = self.u_convert(u32_ty, None, value).unwrap();
+        let bits = self
+            .shift_left_logical(u32_ty, None, bits, sixteen)
+            .unwrap();
+        self.bitcast(f32_ty, Some(out_id), bits).unwrap()
+    }
+
+    /// Store `value` behind `ptr`, packing f32 values to bf16 storage.
+    fn store_ptr(&mut self, ptr: Word, item: &Item, value: Word) {
+        let value = match item.elem() {
+            Elem::BFloat16 if self.compilation_options.supports_bf16 => {
+                let ty = item.id(self);
+                self.f_convert(ty, None, value).unwrap()
+            }
+            Elem::BFloat16 => {
+                // Round the f32 bits to nearest even before keeping their high half.
+                let ty = item.id(self);
+                let u32_item = item.same_vectorization(Elem::Int(32, false));
+                let u32_ty = u32_item.id(self);
+                let sixteen = u32_item.const_u32(self, 16);
+                let one = u32_item.const_u32(self, 1);
+                let half = u32_item.const_u32(self, 0x7FFF);
+                let bits = self.bitcast(u32_ty, None, value).unwrap();
+                let lsb = self
+                    .shift_right_logical(u32_ty, None, bits, sixteen)
+                    .unwrap();
+                let lsb = self.bitwise_and(u32_ty, None, lsb, one).unwrap();
+                let bias = self.i_add(u32_ty, None, half, lsb).unwrap();
+                let rounded = self.i_add(u32_ty, None, bits, bias).unwrap();
+                let high = self
+                    .shift_right_logical(u32_ty, None, rounded, sixteen)
+                    .unwrap();
+                self.u_convert(ty, None, high).unwrap()
+            }
+            _ => value,
+        };
+        self.store(ptr, value, None, vec![]).unwrap()
+    }
+
     pub fn index_ptr(&mut self, var: &Variable, index: &Variable) -> Word {
         match self.index(var, index, false) {
             IndexedVariable::Pointer(ptr, _) => ptr,
@@ -711,7 +773,7 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
         let variable = self.index(out, index, always_in_bounds);
 
         let write = |b: &mut Self| match variable {
-            IndexedVariable::Pointer(ptr, _) => b.store(ptr, value, None, vec![]).unwrap(),
+            IndexedVariable::Pointer(ptr, item) => b.store_ptr(ptr, &item, value),
             IndexedVariable::Composite(var, index, item) => {
                 let ty = item.id(b);
                 let id = b
@@ -739,7 +801,7 @@ impl<T: SpirvTarget> SpirvCompiler<T> {
         let variable = self.index(out, index, true);
 
         match variable {
-            IndexedVariable::Pointer(ptr, _) => self.store(ptr, value, None, vec![]).unwrap(),
+            IndexedVariable::Pointer(ptr, item) => self.store_ptr(ptr, &item, value),
             IndexedVariable::Composite(var, index, item) => {
                 let ty = item.id(self);
                 let out_id = self
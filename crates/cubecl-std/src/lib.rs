@@ -11,6 +11,18 @@ pub use quantization::*;
 mod option;
 pub use option::*;
 
+mod outputs;
+pub use outputs::*;
+
+mod padded_shared_memory;
+pub use padded_shared_memory::*;
+
+mod plane_sort;
+pub use plane_sort::*;
+
+mod thread_accumulator;
+pub use thread_accumulator::*;
+
 pub mod tensor;
 
 use cubecl::prelude::*;
@@ -1,6 +1,11 @@
 use cubecl::prelude::*;
 use cubecl_core as cubecl;
 
+/// An optional value, whose presence is known at compile time.
+///
+/// When used as a kernel argument, only the present case registers a binding and the kernel is
+/// compiled once per case, so branches on [is_some](CubeOption::is_some) are resolved during
+/// expansion instead of at runtime.
 #[derive(CubeType, Clone, Copy)]
 pub enum CubeOption<T: CubeType> {
     Some(T),
@@ -0,0 +1,99 @@
+use cubecl::prelude::*;
+use cubecl_core::{self as cubecl, unexpanded};
+
+/// Write each value of a tuple to the output at the same position, all at the same index.
+///
+/// Kernels producing several results per element, like a value and its gradients, otherwise
+/// repeat `out[index] = value` once per output. Both traits must be in scope to call it in a
+/// kernel.
+///
+/// Consecutive units writing consecutive indexes keep the stores to each output coalesced, and
+/// outputs of [Line] write a whole line per unit.
+///
+/// # Example
+///
+/// ```ignore
+/// #[cube(launch)]
+/// fn gradients(input: &Array<f32>, dx: &mut Array<f32>, dy: &mut Array<f32>, dz: &mut Array<f32>) {
+///     if ABSOLUTE_POS < input.len() {
+///         let (x, y, z) = compute_gradients(input[ABSOLUTE_POS]);
+///         (dx, dy, dz).write_outputs((x, y, z), ABSOLUTE_POS);
+///     }
+/// }
+/// ```
+pub trait WriteOutputs: CubeType {
+    /// The values written to the outputs, one per output.
+    type Values: CubeType;
+
+    /// Write each value to its output at the given index.
+    fn write_outputs(self, _values: Self::Values, _index: u32)
+    where
+        Self: Sized,
+    {
+        unexpanded!()
+    }
+}
+
+/// Expand type of [WriteOutputs].
+pub trait WriteOutputsExpand {
+    /// The expanded values written to the outputs, one per output.
+    type Values;
+
+    fn __expand_write_outputs_method(
+        self,
+        scope: &mut Scope,
+        values: Self::Values,
+        index: ExpandElementTyped<u32>,
+    );
+}
+
+macro_rules! impl_write_outputs {
+    ($(($O:ident, $o:ident, $v:ident)),*) => {
+        impl<$($O: CubeType + CubeIndex<u32>),*> WriteOutputs for ($($O,)*)
+        where
+            $($O::Output: CubeType + Sized,)*
+        {
+            type Values = ($($O::Output,)*);
+        }
+
+        impl<$($O: CubeType + CubeIndex<u32>),*> WriteOutputsExpand
+            for ($(ExpandElementTyped<$O>,)*)
+        where
+            $($O::Output: CubeType + Sized,)*
+        {
+            type Values = ($(ExpandElementTyped<$O::Output>,)*);
+
+            fn __expand_write_outputs_method(
+                self,
+                scope: &mut Scope,
+                values: Self::Values,
+                index: ExpandElementTyped<u32>,
+            ) {
+                let ($($o,)*) = self;
+                let ($($v,)*) = values;
+                $(
+                    cubecl::frontend::index_assign::expand::<$O>(scope, $o, index.clone(), $v);
+                )*
+            }
+        }
+    };
+}
+
+impl_write_outputs!((O1, o1, v1), (O2, o2, v2));
+impl_write_outputs!((O1, o1, v1), (O2, o2, v2), (O3, o3, v3));
+impl_write_outputs!((O1, o1, v1), (O2, o2, v2), (O3, o3, v3), (O4, o4, v4));
+impl_write_outputs!(
+    (O1, o1, v1),
+    (O2, o2, v2),
+    (O3, o3, v3),
+    (O4, o4, v4),
+    (O5, o5, v5)
+);
+impl_write_outputs!(
+    (O1, o1, v1),
+    (O2, o2, v2),
+    (O3, o3, v3),
+    (O4, o4, v4),
+    (O5, o5, v5),
+    (O6, o6, v6)
+);
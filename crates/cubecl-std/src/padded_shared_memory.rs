@@ -0,0 +1,62 @@
+use cubecl::prelude::*;
+use cubecl_core as cubecl;
+
+/// Width of a shared memory bank in bytes.
+const BANK_WIDTH: u32 = 4;
+
+/// A 2D tile in shared memory whose rows are padded so that accessing a column doesn't cause bank
+/// conflicts, indexed by row and column.
+///
+/// Without padding, the elements of a column are a multiple of 32 banks apart when the row width
+/// is, so every unit reading the column hits the same bank. The padding is chosen from the element
+/// size so that the row stride is an odd number of bank-wide words, which spreads a column over
+/// all the banks.
+#[derive(CubeType)]
+pub struct PaddedSharedMemory<T: CubePrimitive> {
+    memory: SharedMemory<T>,
+    #[cube(comptime)]
+    stride: u32,
+}
+
+#[cube]
+impl<T: CubePrimitive> PaddedSharedMemory<T> {
+    /// Allocate a tile of `rows` by `cols` elements.
+    pub fn new(#[comptime] rows: u32, #[comptime] cols: u32) -> PaddedSharedMemory<T> {
+        let elem_size = size_of::<T>();
+        let stride = comptime!(padded_stride(cols, elem_size));
+
+        PaddedSharedMemory::<T> {
+            memory: SharedMemory::new(rows * stride),
+            stride,
+        }
+    }
+
+    /// Read the element at the given row and column.
+    pub fn read(&self, row: u32, col: u32) -> T {
+        self.memory[row * self.stride + col]
+    }
+
+    /// Write the element at the given row and column.
+    pub fn write(&mut self, row: u32, col: u32, value: T) {
+        self.memory[row * self.stride + col] = value;
+    }
+}
+
+/// The number of elements between the start of two rows of `cols` elements of `elem_size` bytes,
+/// so that the stride spans an odd number of banks.
+///
+/// Elements smaller than a bank are padded by a whole bank, and elements wider than a bank are
+/// accessed in multiple bank-wide words, so their stride is counted in elements.
+pub fn padded_stride(cols: u32, elem_size: u32) -> u32 {
+    let word = elem_size.max(BANK_WIDTH);
+    let elems_per_word = (word / elem_size).max(1);
+    let stride = cols.next_multiple_of(elems_per_word);
+    let words = stride * elem_size / word;
+
+    // An odd number of words is coprime with the number of banks, so it's already conflict-free.
+    if words % 2 == 1 {
+        stride
+    } else {
+        stride + elems_per_word
+    }
+}
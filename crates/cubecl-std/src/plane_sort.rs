@@ -0,0 +1,47 @@
+use cubecl::prelude::*;
+use cubecl_core as cubecl;
+
+/// The value and payload held by a unit after [plane_sort].
+#[derive(CubeType)]
+pub struct PlaneSorted<F: Float, P: CubePrimitive> {
+    pub value: F,
+    pub payload: P,
+}
+
+/// Sort one value per unit across the plane in ascending order, carrying a payload such as the
+/// index of the value along with it.
+///
+/// Uses a bitonic network where each step exchanges values between units with plane shuffles, so
+/// the plane size must be a power of two. Every unit of the plane must call it: the units at or
+/// past `len` are padded with +inf and end up holding the padding, which leaves the `len` smallest
+/// values sorted in the first `len` units.
+#[cube]
+pub fn plane_sort<F: Float, P: CubePrimitive>(value: F, payload: P, len: u32) -> PlaneSorted<F, P> {
+    let lane = UNIT_POS_PLANE;
+    let mut value = select(lane < len, value, F::new(comptime!(f32::INFINITY)));
+    let mut payload = payload;
+
+    // Merge sorted sequences of size k, alternating their direction, until the whole plane is
+    // one ascending sequence.
+    let mut k = 2u32;
+    while k <= PLANE_DIM {
+        let ascending = (lane & k) == 0;
+        let mut j = k / 2;
+        while j > 0 {
+            let partner = lane ^ j;
+            let partner_value = plane_broadcast(value, partner);
+            let partner_payload = plane_broadcast(payload, partner);
+
+            // The lower unit of the pair keeps the smallest value in ascending sequences.
+            let keep_min = ascending == ((lane & j) == 0);
+            let take_partner = select(keep_min, partner_value < value, partner_value > value);
+            value = select(take_partner, partner_value, value);
+            payload = select(take_partner, partner_payload, payload);
+
+            j = j / 2;
+        }
+        k = k * 2;
+    }
+
+    PlaneSorted::<F, P> { value, payload }
+}
@@ -1,7 +1,9 @@
 use core::marker::PhantomData;
 
 use cubecl::prelude::*;
-use cubecl_core::{self as cubecl, unexpanded};
+use cubecl_core as cubecl;
+
+pub use cubecl::frontend::mem::size_of;
 
 /// This struct allows to take a slice of `Line<S>` and reinterpret it
 /// as a slice of `T`. Semantically, this is equivalent to reinterpreting the slice of `Line<S>`
@@ -179,15 +181,3 @@ fn optimize_line_size(
         core::cmp::Ordering::Equal => (None, None),
     }
 }
-
-pub fn size_of<S: CubePrimitive>() -> u32 {
-    unexpanded!()
-}
-
-pub mod size_of {
-    use super::*;
-    #[allow(unused, clippy::all)]
-    pub fn expand<S: CubePrimitive>(context: &mut cubecl::prelude::Scope) -> u32 {
-        S::as_elem(context).size() as u32
-    }
-}
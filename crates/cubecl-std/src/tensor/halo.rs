@@ -0,0 +1,102 @@
+use cubecl::prelude::*;
+use cubecl_core as cubecl;
+
+/// How the elements of the halo that fall outside of the tensor are loaded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HaloBoundary {
+    /// Repeat the closest element of the tensor, like an edge padding.
+    Clamp,
+    /// Load zeros, like a zero padding.
+    Zero,
+}
+
+/// A tile of a matrix in shared memory, surrounded by a halo of its neighboring elements, as
+/// loaded by [load_tile_with_halo].
+///
+/// The tile is indexed with the row and column of the tile including the halo, so the element at
+/// row `r` and column `c` of the tile itself is at `r + halo` and `c + halo`, and its neighbors
+/// are the elements around it.
+#[derive(CubeType)]
+pub struct HaloTile<E: Numeric> {
+    memory: SharedMemory<E>,
+    #[cube(comptime)]
+    stride: u32,
+}
+
+#[cube]
+impl<E: Numeric> HaloTile<E> {
+    /// Read the element at the given row and column of the tile including the halo.
+    pub fn read(&self, row: u32, col: u32) -> E {
+        self.memory[row * self.stride + col]
+    }
+}
+
+/// Load the `tile_height` by `tile_width` tile of the matrix `input` starting at `tile_row` and
+/// `tile_col`, surrounded by a halo of `halo` elements on each side, into shared memory.
+///
+/// The elements of the tile and halo outside of the matrix are loaded according to `boundary`,
+/// so the compute on the tile doesn't need to check the bounds of the matrix. All the units of the
+/// cube must call it, since they load the tile together and synchronize once it's loaded.
+///
+/// # Example
+///
+/// ```ignore
+/// #[cube(launch)]
+/// fn blur(input: &Tensor<f32>, output: &mut Tensor<f32>) {
+///     let tile_row = CUBE_POS_Y * 16;
+///     let tile_col = CUBE_POS_X * 16;
+///     let tile = load_tile_with_halo(input, tile_row, tile_col, 16, 16, 1, HaloBoundary::Clamp);
+///
+///     let mut sum = 0.0;
+///     for i in 0..3 {
+///         for j in 0..3 {
+///             sum += tile.read(UNIT_POS_Y + i, UNIT_POS_X + j);
+///         }
+///     }
+///     // Write `sum / 9.0` to the output if the unit is within its bounds.
+/// }
+/// ```
+#[cube]
+pub fn load_tile_with_halo<E: Numeric>(
+    input: &Tensor<E>,
+    tile_row: u32,
+    tile_col: u32,
+    #[comptime] tile_height: u32,
+    #[comptime] tile_width: u32,
+    #[comptime] halo: u32,
+    #[comptime] boundary: HaloBoundary,
+) -> HaloTile<E> {
+    let height = comptime!(tile_height + 2 * halo);
+    let width = comptime!(tile_width + 2 * halo);
+    let mut memory = SharedMemory::<E>::new(height * width);
+
+    let rows = input.shape(0);
+    let cols = input.shape(1);
+
+    for index in range_stepped(UNIT_POS, height * width, CUBE_DIM) {
+        // Coordinates in the matrix shifted by the halo, so the halo before it stays unsigned.
+        let row = tile_row + index / width;
+        let col = tile_col + index % width;
+
+        if comptime!(boundary == HaloBoundary::Zero) {
+            let inside = row >= halo && row < rows + halo && col >= halo && col < cols + halo;
+            if inside {
+                memory[index] =
+                    input[(row - halo) * input.stride(0) + (col - halo) * input.stride(1)];
+            } else {
+                memory[index] = E::from_int(0);
+            }
+        } else {
+            let row = Min::min(Max::max(row, halo.runtime()), rows + halo - 1) - halo;
+            let col = Min::min(Max::max(col, halo.runtime()), cols + halo - 1) - halo;
+            memory[index] = input[row * input.stride(0) + col * input.stride(1)];
+        }
+    }
+
+    sync_cube();
+
+    HaloTile::<E> {
+        memory,
+        stride: width,
+    }
+}
@@ -1,4 +1,8 @@
 mod base;
+mod halo;
+mod window;
 
 pub use base::*;
+pub use halo::*;
+pub use window::*;
 pub mod r#virtual;
@@ -0,0 +1,116 @@
+use cubecl::prelude::*;
+use cubecl_core as cubecl;
+
+/// A window of a tensor, bound as its own kernel argument and indexed in local coordinates.
+///
+/// The window has the shape of its extent and the strides of its parent tensor, so an index
+/// computed from the local coordinates and the [strides](TensorWindow::stride) of the window reads
+/// the element at the same coordinates relative to the origin of the window in the parent tensor.
+/// Like with [Tensor], indexes are in lines when the window is vectorized.
+///
+/// # Example
+///
+/// ```ignore
+/// #[cube(launch)]
+/// fn double(window: &mut TensorWindow<f32>) {
+///     if ABSOLUTE_POS_Y < window.shape(0) && ABSOLUTE_POS_X < window.shape(1) {
+///         let index = ABSOLUTE_POS_Y * window.stride(0) + ABSOLUTE_POS_X * window.stride(1);
+///         window.write(index, window.read(index) * Line::new(2.0));
+///     }
+/// }
+///
+/// // The 16x16 block of a matrix starting at row 32 and column 64.
+/// let window = TensorWindowLaunch::from_tensor(&matrix.as_ref(), &[32, 64], &[16, 16], 1);
+/// ```
+#[derive(CubeLaunch, CubeType)]
+pub struct TensorWindow<E: Numeric> {
+    tensor: Tensor<Line<E>>,
+    origin: u32,
+}
+
+#[cube]
+impl<E: Numeric> TensorWindow<E> {
+    /// Read the line at the given local index.
+    pub fn read(&self, index: u32) -> Line<E> {
+        self.tensor[self.origin + index]
+    }
+
+    /// Write the line at the given local index.
+    pub fn write(&mut self, index: u32, value: Line<E>) {
+        self.tensor[self.origin + index] = value;
+    }
+
+    /// The extent of the window at the given axis.
+    pub fn shape(&self, axis: u32) -> u32 {
+        self.tensor.shape(axis)
+    }
+
+    /// The stride of the parent tensor at the given axis.
+    pub fn stride(&self, axis: u32) -> u32 {
+        self.tensor.stride(axis)
+    }
+
+    /// The rank of the window, always the rank of the parent tensor.
+    pub fn rank(&self) -> u32 {
+        self.tensor.rank()
+    }
+}
+
+impl<'a, E: Numeric, R: Runtime> TensorWindowLaunch<'a, E, R> {
+    /// Bind the window of `tensor` starting at the `origin` coordinates and spanning `extent`
+    /// elements along each axis.
+    ///
+    /// # Panics
+    ///
+    /// If the origin or the extent don't have the rank of the tensor, if the window goes past the
+    /// end of the tensor, or if the origin isn't aligned to the line size.
+    pub fn from_tensor(
+        tensor: &TensorHandleRef<'a, R>,
+        origin: &[usize],
+        extent: &'a [usize],
+        line_size: u8,
+    ) -> Self {
+        let rank = tensor.shape.len();
+        assert_eq!(
+            origin.len(),
+            rank,
+            "Window origin should have the tensor rank"
+        );
+        assert_eq!(
+            extent.len(),
+            rank,
+            "Window extent should have the tensor rank"
+        );
+
+        for (axis, ((origin, extent), shape)) in
+            origin.iter().zip(extent).zip(tensor.shape).enumerate()
+        {
+            assert!(
+                origin + extent <= *shape,
+                "Window goes past the end of axis {axis}: {origin} + {extent} > {shape}"
+            );
+        }
+
+        let offset = origin
+            .iter()
+            .zip(tensor.strides)
+            .map(|(coordinate, stride)| coordinate * stride)
+            .sum::<usize>();
+        assert!(
+            offset % line_size as usize == 0,
+            "Window origin should be aligned to the line size {line_size}"
+        );
+
+        let tensor = unsafe {
+            TensorArg::from_raw_parts_and_size(
+                tensor.handle,
+                tensor.strides,
+                extent,
+                line_size,
+                tensor.elem_size,
+            )
+        };
+
+        Self::new(tensor, ScalarArg::new((offset / line_size as usize) as u32))
+    }
+}
@@ -0,0 +1,116 @@
+use cubecl::prelude::*;
+use cubecl_core as cubecl;
+
+use crate::tensor::{HaloBoundary, load_tile_with_halo};
+
+const TILE_SIZE: u32 = 4;
+
+#[cube(launch)]
+fn kernel_stencil_3x3(
+    input: &Tensor<f32>,
+    output: &mut Tensor<f32>,
+    #[comptime] boundary: HaloBoundary,
+) {
+    let tile_row = CUBE_POS_Y * TILE_SIZE;
+    let tile_col = CUBE_POS_X * TILE_SIZE;
+    let tile = load_tile_with_halo(input, tile_row, tile_col, TILE_SIZE, TILE_SIZE, 1, boundary);
+
+    let mut sum = 0.0;
+    for i in 0..3 {
+        for j in 0..3 {
+            sum += tile.read(UNIT_POS_Y + i, UNIT_POS_X + j);
+        }
+    }
+
+    let row = tile_row + UNIT_POS_Y;
+    let col = tile_col + UNIT_POS_X;
+    if row < output.shape(0) && col < output.shape(1) {
+        output[row * output.stride(0) + col * output.stride(1)] = sum;
+    }
+}
+
+/// The 3x3 sum around each element of a `rows` by `cols` matrix, with the elements outside of the
+/// matrix loaded according to `boundary`.
+fn stencil_3x3(data: &[f32], rows: usize, cols: usize, boundary: HaloBoundary) -> Vec<f32> {
+    let mut output = vec![0.0; rows * cols];
+    for row in 0..rows as i64 {
+        for col in 0..cols as i64 {
+            let mut sum = 0.0;
+            for i in row - 1..=row + 1 {
+                for j in col - 1..=col + 1 {
+                    let inside = (0..rows as i64).contains(&i) && (0..cols as i64).contains(&j);
+                    sum += match (boundary, inside) {
+                        (HaloBoundary::Zero, false) => 0.0,
+                        _ => {
+                            let i = i.clamp(0, rows as i64 - 1) as usize;
+                            let j = j.clamp(0, cols as i64 - 1) as usize;
+                            data[i * cols + j]
+                        }
+                    };
+                }
+            }
+            output[row as usize * cols + col as usize] = sum;
+        }
+    }
+    output
+}
+
+pub fn run_test_stencil_halo<R: Runtime>(
+    client: ComputeClient<R::Server, R::Channel>,
+    boundary: HaloBoundary,
+) {
+    // The last tiles go past the end of the matrix, and every tile touches one of its corners.
+    let shape = [6, 7];
+    let data: Vec<f32> = (0..42).map(|i| i as f32).collect();
+    let (input, strides) = client.create_tensor(f32::as_bytes(&data), &shape, size_of::<f32>());
+    let (output, _) = client.create_tensor(f32::as_bytes(&[0.0; 42]), &shape, size_of::<f32>());
+
+    kernel_stencil_3x3::launch::<R>(
+        &client,
+        CubeCount::new_2d(2, 2),
+        CubeDim::new_2d(TILE_SIZE, TILE_SIZE),
+        unsafe { TensorArg::from_raw_parts::<f32>(&input, &strides, &shape, 1) },
+        unsafe { TensorArg::from_raw_parts::<f32>(&output, &strides, &shape, 1) },
+        boundary,
+    );
+
+    let actual =
+        client.read_one_tensor(output.binding_with_meta(shape.to_vec(), strides, size_of::<f32>()));
+    let actual = f32::from_bytes(&actual);
+    let expected = stencil_3x3(&data, shape[0], shape[1], boundary);
+
+    assert_eq!(actual, expected);
+    // At the top-left corner, the halo is either all zeros or a repeat of the corner element.
+    match boundary {
+        HaloBoundary::Zero => assert_eq!(actual[0], 0.0 + 1.0 + 7.0 + 8.0),
+        HaloBoundary::Clamp => assert_eq!(actual[0], 4.0 * 0.0 + 2.0 * 1.0 + 2.0 * 7.0 + 8.0),
+    }
+}
+
+#[macro_export]
+macro_rules! testgen_halo_tile {
+    () => {
+        mod halo_tile {
+            use super::*;
+            use cubecl_std::tensor::HaloBoundary;
+
+            #[test]
+            fn stencil_halo_zero() {
+                let client = TestRuntime::client(&Default::default());
+                cubecl_std::tests::halo_tile::run_test_stencil_halo::<TestRuntime>(
+                    client,
+                    HaloBoundary::Zero,
+                );
+            }
+
+            #[test]
+            fn stencil_halo_clamp() {
+                let client = TestRuntime::client(&Default::default());
+                cubecl_std::tests::halo_tile::run_test_stencil_halo::<TestRuntime>(
+                    client,
+                    HaloBoundary::Clamp,
+                );
+            }
+        }
+    };
+}
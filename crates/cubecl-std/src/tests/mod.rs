@@ -1,4 +1,11 @@
+pub mod halo_tile;
+pub mod option;
+pub mod padded_shared_memory;
+pub mod plane_sort;
 pub mod reinterpret_slice;
+pub mod tensor_window;
+pub mod thread_accumulator;
+pub mod write_outputs;
 
 #[macro_export]
 macro_rules! testgen {
@@ -6,7 +13,14 @@ macro_rules! testgen {
         mod test_cubecl_std {
             use super::*;
 
+            cubecl_std::testgen_halo_tile!();
+            cubecl_std::testgen_option!();
+            cubecl_std::testgen_padded_shared_memory!();
+            cubecl_std::testgen_plane_sort!();
             cubecl_std::testgen_reinterpret_slice!();
+            cubecl_std::testgen_tensor_window!();
+            cubecl_std::testgen_thread_accumulator!();
+            cubecl_std::testgen_write_outputs!();
         }
     };
 }
@@ -0,0 +1,79 @@
+use cubecl::prelude::*;
+use cubecl_core as cubecl;
+
+use crate::{CubeOption, CubeOptionArgs};
+
+#[cube(launch)]
+fn kernel_optional_bias(
+    input: &Tensor<f32>,
+    bias: &CubeOption<Tensor<f32>>,
+    output: &mut Tensor<f32>,
+) {
+    if ABSOLUTE_POS < output.len() {
+        let mut value = input[ABSOLUTE_POS];
+        if bias.is_some() {
+            value += bias.unwrap()[ABSOLUTE_POS];
+        }
+        output[ABSOLUTE_POS] = value;
+    }
+}
+
+pub fn run_test_optional_bias<R: Runtime>(
+    client: ComputeClient<R::Server, R::Channel>,
+    with_bias: bool,
+) {
+    let input = [1.0, 2.0, 3.0, 4.0];
+    let bias = [10.0, 20.0, 30.0, 40.0];
+    let shape = [input.len()];
+    let strides = [1];
+
+    let input_handle = client.create(f32::as_bytes(&input));
+    let bias_handle = client.create(f32::as_bytes(&bias));
+    let output_handle = client.empty(size_of_val(&input));
+
+    let bias_arg = match with_bias {
+        true => CubeOptionArgs::Some(unsafe {
+            TensorArg::from_raw_parts::<f32>(&bias_handle, &strides, &shape, 1)
+        }),
+        false => CubeOptionArgs::None,
+    };
+
+    kernel_optional_bias::launch::<R>(
+        &client,
+        CubeCount::new_single(),
+        CubeDim::new_1d(input.len() as u32),
+        unsafe { TensorArg::from_raw_parts::<f32>(&input_handle, &strides, &shape, 1) },
+        bias_arg,
+        unsafe { TensorArg::from_raw_parts::<f32>(&output_handle, &strides, &shape, 1) },
+    );
+
+    let actual = client.read_one(output_handle.binding());
+    let actual = f32::from_bytes(&actual);
+
+    let expected: Vec<f32> = match with_bias {
+        true => input.iter().zip(bias).map(|(x, b)| x + b).collect(),
+        false => input.to_vec(),
+    };
+    assert_eq!(actual, expected);
+}
+
+#[macro_export]
+macro_rules! testgen_option {
+    () => {
+        mod option {
+            use super::*;
+
+            #[test]
+            fn optional_bias_present() {
+                let client = TestRuntime::client(&Default::default());
+                cubecl_std::tests::option::run_test_optional_bias::<TestRuntime>(client, true);
+            }
+
+            #[test]
+            fn optional_bias_absent() {
+                let client = TestRuntime::client(&Default::default());
+                cubecl_std::tests::option::run_test_optional_bias::<TestRuntime>(client, false);
+            }
+        }
+    };
+}
@@ -0,0 +1,73 @@
+use cubecl::prelude::*;
+use cubecl_core as cubecl;
+
+use crate::{PaddedSharedMemory, padded_stride};
+
+#[cube(launch)]
+fn kernel_transpose_tile(input: &Array<f32>, output: &mut Array<f32>, #[comptime] size: u32) {
+    let mut tile = PaddedSharedMemory::<f32>::new(size, size);
+
+    tile.write(
+        UNIT_POS_Y,
+        UNIT_POS_X,
+        input[UNIT_POS_Y * size + UNIT_POS_X],
+    );
+    sync_cube();
+    output[UNIT_POS_Y * size + UNIT_POS_X] = tile.read(UNIT_POS_X, UNIT_POS_Y);
+}
+
+pub fn run_test_padded_stride() {
+    // One extra bank for 4-byte elements, a whole bank of smaller elements.
+    assert_eq!(padded_stride(32, 4), 33);
+    assert_eq!(padded_stride(32, 2), 34);
+    assert_eq!(padded_stride(32, 1), 36);
+    assert_eq!(padded_stride(32, 8), 33);
+    // Strides that are already conflict-free aren't padded.
+    assert_eq!(padded_stride(33, 4), 33);
+    assert_eq!(padded_stride(31, 2), 34);
+}
+
+pub fn run_test_transpose_tile<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let size = 16;
+    let data: Vec<f32> = (0..size * size).map(|i| i as f32).collect();
+    let input = client.create(f32::as_bytes(&data));
+    let output = client.empty(data.len() * size_of::<f32>());
+
+    kernel_transpose_tile::launch::<R>(
+        &client,
+        CubeCount::new_single(),
+        CubeDim::new_2d(size as u32, size as u32),
+        unsafe { ArrayArg::from_raw_parts::<f32>(&input, data.len(), 1) },
+        unsafe { ArrayArg::from_raw_parts::<f32>(&output, data.len(), 1) },
+        size as u32,
+    );
+
+    let actual = client.read_one(output.binding());
+    let expected: Vec<f32> = (0..size * size)
+        .map(|i| ((i % size) * size + i / size) as f32)
+        .collect();
+
+    assert_eq!(f32::from_bytes(&actual), expected);
+}
+
+#[macro_export]
+macro_rules! testgen_padded_shared_memory {
+    () => {
+        mod padded_shared_memory {
+            use super::*;
+
+            #[test]
+            fn padded_stride() {
+                cubecl_std::tests::padded_shared_memory::run_test_padded_stride();
+            }
+
+            #[test]
+            fn transpose_tile() {
+                let client = TestRuntime::client(&Default::default());
+                cubecl_std::tests::padded_shared_memory::run_test_transpose_tile::<TestRuntime>(
+                    client,
+                );
+            }
+        }
+    };
+}
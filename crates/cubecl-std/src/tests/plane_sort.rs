@@ -0,0 +1,89 @@
+use cubecl::prelude::*;
+use cubecl_core as cubecl;
+
+use crate::plane_sort;
+
+#[cube(launch)]
+fn kernel_plane_sort(
+    input: &Array<f32>,
+    values: &mut Array<f32>,
+    payloads: &mut Array<u32>,
+    len: u32,
+) {
+    let sorted = plane_sort::<f32, u32>(input[UNIT_POS_PLANE], UNIT_POS_PLANE, len);
+    values[UNIT_POS_PLANE] = sorted.value;
+    payloads[UNIT_POS_PLANE] = sorted.payload;
+}
+
+pub fn run_test_plane_sort<R: Runtime>(client: ComputeClient<R::Server, R::Channel>, len: u32) {
+    let plane_size = 32;
+    let props = client.properties();
+    let hardware = props.hardware_properties();
+    if !props.feature_enabled(cubecl::Feature::Plane)
+        || hardware.plane_size_min != plane_size
+        || hardware.plane_size_max != plane_size
+    {
+        return;
+    }
+
+    // A permutation of 0..32, since 13 is coprime with 32.
+    let input: Vec<f32> = (0..plane_size)
+        .map(|i| ((i * 13 + 7) % plane_size) as f32)
+        .collect();
+    let input_handle = client.create(f32::as_bytes(&input));
+    let values = client.empty(plane_size as usize * size_of::<f32>());
+    let payloads = client.empty(plane_size as usize * size_of::<u32>());
+
+    kernel_plane_sort::launch::<R>(
+        &client,
+        CubeCount::new_single(),
+        CubeDim::new_1d(plane_size),
+        unsafe { ArrayArg::from_raw_parts::<f32>(&input_handle, input.len(), 1) },
+        unsafe { ArrayArg::from_raw_parts::<f32>(&values, input.len(), 1) },
+        unsafe { ArrayArg::from_raw_parts::<u32>(&payloads, input.len(), 1) },
+        ScalarArg::new(len),
+    );
+
+    let mut expected: Vec<(f32, u32)> = input[..len as usize]
+        .iter()
+        .enumerate()
+        .map(|(i, value)| (*value, i as u32))
+        .collect();
+    expected.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let values = client.read_one(values.binding());
+    let payloads = client.read_one(payloads.binding());
+    let actual: Vec<(f32, u32)> = f32::from_bytes(&values)
+        .iter()
+        .zip(u32::from_bytes(&payloads))
+        .map(|(value, payload)| (*value, *payload))
+        .collect();
+
+    assert_eq!(&actual[..len as usize], expected.as_slice());
+    assert!(
+        actual[len as usize..]
+            .iter()
+            .all(|(value, _)| *value == f32::INFINITY)
+    );
+}
+
+#[macro_export]
+macro_rules! testgen_plane_sort {
+    () => {
+        mod plane_sort {
+            use super::*;
+
+            #[test]
+            fn full_plane() {
+                let client = TestRuntime::client(&Default::default());
+                cubecl_std::tests::plane_sort::run_test_plane_sort::<TestRuntime>(client, 32);
+            }
+
+            #[test]
+            fn partial_plane() {
+                let client = TestRuntime::client(&Default::default());
+                cubecl_std::tests::plane_sort::run_test_plane_sort::<TestRuntime>(client, 20);
+            }
+        }
+    };
+}
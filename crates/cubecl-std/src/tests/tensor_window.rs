@@ -0,0 +1,68 @@
+use cubecl::prelude::*;
+use cubecl_core as cubecl;
+
+use crate::tensor::{TensorWindow, TensorWindowLaunch};
+
+#[cube(launch)]
+fn kernel_copy_window(input: &TensorWindow<f32>, output: &mut TensorWindow<f32>) {
+    let row = UNIT_POS_Y;
+    let col = UNIT_POS_X;
+
+    if row < input.shape(0) && col < input.shape(1) {
+        let value = input.read(row * input.stride(0) + col * input.stride(1));
+        output.write(row * output.stride(0) + col * output.stride(1), value);
+    }
+}
+
+pub fn run_test_copy_window<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let input_shape = [6, 8];
+    let data: Vec<f32> = (0..48).map(|i| i as f32).collect();
+    let (input, input_strides) =
+        client.create_tensor(f32::as_bytes(&data), &input_shape, size_of::<f32>());
+    let output_shape = [5, 5];
+    let (output, output_strides) =
+        client.create_tensor(f32::as_bytes(&[0.0; 25]), &output_shape, size_of::<f32>());
+
+    let input_ref =
+        unsafe { TensorHandleRef::<R>::from_raw_parts(&input, &input_strides, &input_shape, 4) };
+    let output_ref =
+        unsafe { TensorHandleRef::<R>::from_raw_parts(&output, &output_strides, &output_shape, 4) };
+    let extent = [3, 4];
+
+    kernel_copy_window::launch::<R>(
+        &client,
+        CubeCount::new_single(),
+        CubeDim::new_2d(8, 8),
+        TensorWindowLaunch::from_tensor(&input_ref, &[1, 2], &extent, 1),
+        TensorWindowLaunch::from_tensor(&output_ref, &[2, 1], &extent, 1),
+    );
+
+    let actual = client.read_one_tensor(output.binding_with_meta(
+        output_shape.to_vec(),
+        output_strides,
+        size_of::<f32>(),
+    ));
+    let mut expected = [0.0; 25];
+    for row in 0..3 {
+        for col in 0..4 {
+            expected[(row + 2) * 5 + col + 1] = data[(row + 1) * 8 + col + 2];
+        }
+    }
+
+    assert_eq!(f32::from_bytes(&actual), expected);
+}
+
+#[macro_export]
+macro_rules! testgen_tensor_window {
+    () => {
+        mod tensor_window {
+            use super::*;
+
+            #[test]
+            fn copy_window() {
+                let client = TestRuntime::client(&Default::default());
+                cubecl_std::tests::tensor_window::run_test_copy_window::<TestRuntime>(client);
+            }
+        }
+    };
+}
@@ -0,0 +1,117 @@
+use cubecl::prelude::*;
+use cubecl_core::{
+    self as cubecl,
+    compute::KernelBuilder,
+    ir::{Branch, Elem, FloatKind, Item, Operation, Operator},
+};
+
+use crate::ThreadAccumulator;
+
+#[cube(launch)]
+fn kernel_grid_stride_sum(
+    input: &Array<f32>,
+    output: &mut Array<f32>,
+    #[comptime] cube_size: u32,
+    #[comptime] use_planes: bool,
+) {
+    let mut acc = ThreadAccumulator::<f32>::new();
+    for i in grid_stride_range(input.len()) {
+        acc.accumulate(input[i]);
+    }
+
+    let sum = acc.sum_cube(cube_size, use_planes);
+    if UNIT_POS == 0 {
+        output[CUBE_POS] = sum;
+    }
+}
+
+pub fn run_test_grid_stride_sum<R: Runtime>(
+    client: ComputeClient<R::Server, R::Channel>,
+    use_planes: bool,
+) {
+    if use_planes && !client.properties().feature_enabled(cubecl::Feature::Plane) {
+        return;
+    }
+
+    // More elements than units, with a cube size that isn't a power of two.
+    let (num_cubes, cube_size) = (4, 48);
+    let input: Vec<f32> = (0..10_000).map(|i| (i % 7) as f32).collect();
+    let input_handle = client.create(f32::as_bytes(&input));
+    let output = client.empty(num_cubes * size_of::<f32>());
+
+    kernel_grid_stride_sum::launch::<R>(
+        &client,
+        CubeCount::new_1d(num_cubes as u32),
+        CubeDim::new_1d(cube_size),
+        unsafe { ArrayArg::from_raw_parts::<f32>(&input_handle, input.len(), 1) },
+        unsafe { ArrayArg::from_raw_parts::<f32>(&output, num_cubes, 1) },
+        cube_size,
+        use_planes,
+    );
+
+    let actual = client.read_one(output.binding());
+    let actual: f32 = f32::from_bytes(&actual).iter().sum();
+    let expected: f32 = input.iter().sum();
+
+    assert_eq!(actual, expected);
+}
+
+/// Checks that the grid-stride loop only updates the accumulator in place, without storing it to
+/// memory between iterations.
+pub fn run_test_accumulator_stays_local() {
+    let mut builder = KernelBuilder::default();
+    let item = Item::new(Elem::Float(FloatKind::F32));
+    let input = builder.input_array(item);
+    let output = builder.output_array(item);
+
+    kernel_grid_stride_sum::expand(&mut builder.context, input.into(), output.into(), 32, false);
+
+    let loops: Vec<_> = builder
+        .context
+        .instructions
+        .iter()
+        .filter_map(|inst| match &inst.operation {
+            Operation::Branch(Branch::RangeLoop(range_loop)) => Some(range_loop),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(loops.len(), 1);
+
+    let stores = loops[0].scope.instructions.iter().filter(|inst| {
+        matches!(
+            inst.operation,
+            Operation::Operator(Operator::IndexAssign(_) | Operator::UncheckedIndexAssign(_))
+        )
+    });
+    assert_eq!(stores.count(), 0);
+}
+
+#[macro_export]
+macro_rules! testgen_thread_accumulator {
+    () => {
+        mod thread_accumulator {
+            use super::*;
+
+            #[test]
+            fn grid_stride_sum_shared() {
+                let client = TestRuntime::client(&Default::default());
+                cubecl_std::tests::thread_accumulator::run_test_grid_stride_sum::<TestRuntime>(
+                    client, false,
+                );
+            }
+
+            #[test]
+            fn grid_stride_sum_planes() {
+                let client = TestRuntime::client(&Default::default());
+                cubecl_std::tests::thread_accumulator::run_test_grid_stride_sum::<TestRuntime>(
+                    client, true,
+                );
+            }
+
+            #[test]
+            fn accumulator_stays_local() {
+                cubecl_std::tests::thread_accumulator::run_test_accumulator_stays_local();
+            }
+        }
+    };
+}
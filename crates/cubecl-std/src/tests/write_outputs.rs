@@ -0,0 +1,64 @@
+use cubecl::prelude::*;
+use cubecl_core as cubecl;
+
+use crate::{WriteOutputs, WriteOutputsExpand};
+
+#[cube(launch)]
+fn kernel_write_outputs(
+    input: &Array<f32>,
+    sums: &mut Array<f32>,
+    products: &mut Array<f32>,
+    indexes: &mut Array<u32>,
+) {
+    if ABSOLUTE_POS < input.len() {
+        let value = input[ABSOLUTE_POS];
+        (sums, products, indexes)
+            .write_outputs((value + 1.0, value * 2.0, ABSOLUTE_POS), ABSOLUTE_POS);
+    }
+}
+
+pub fn run_test_write_outputs<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let input = [1.0, 2.0, 3.0, 4.0, 5.0];
+
+    let input_handle = client.create(f32::as_bytes(&input));
+    let sums_handle = client.empty(size_of_val(&input));
+    let products_handle = client.empty(size_of_val(&input));
+    let indexes_handle = client.empty(input.len() * size_of::<u32>());
+
+    kernel_write_outputs::launch::<R>(
+        &client,
+        CubeCount::new_single(),
+        CubeDim::new_1d(input.len() as u32),
+        unsafe { ArrayArg::from_raw_parts::<f32>(&input_handle, input.len(), 1) },
+        unsafe { ArrayArg::from_raw_parts::<f32>(&sums_handle, input.len(), 1) },
+        unsafe { ArrayArg::from_raw_parts::<f32>(&products_handle, input.len(), 1) },
+        unsafe { ArrayArg::from_raw_parts::<u32>(&indexes_handle, input.len(), 1) },
+    );
+
+    let sums = client.read_one(sums_handle.binding());
+    let expected: Vec<f32> = input.iter().map(|x| x + 1.0).collect();
+    assert_eq!(f32::from_bytes(&sums), expected, "Sums");
+
+    let products = client.read_one(products_handle.binding());
+    let expected: Vec<f32> = input.iter().map(|x| x * 2.0).collect();
+    assert_eq!(f32::from_bytes(&products), expected, "Products");
+
+    let indexes = client.read_one(indexes_handle.binding());
+    let expected: Vec<u32> = (0..input.len() as u32).collect();
+    assert_eq!(u32::from_bytes(&indexes), expected, "Indexes");
+}
+
+#[macro_export]
+macro_rules! testgen_write_outputs {
+    () => {
+        mod write_outputs {
+            use super::*;
+
+            #[test]
+            fn write_three_outputs() {
+                let client = TestRuntime::client(&Default::default());
+                cubecl_std::tests::write_outputs::run_test_write_outputs::<TestRuntime>(client);
+            }
+        }
+    };
+}
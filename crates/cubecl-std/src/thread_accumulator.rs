@@ -0,0 +1,101 @@
+use cubecl::prelude::*;
+use cubecl_core as cubecl;
+
+use crate::div_ceil;
+
+/// A value accumulated by each unit across the iterations of a grid-stride loop, then summed over
+/// the units of the cube.
+///
+/// The accumulator is a local variable, never indexed, so updating it doesn't read or write memory
+/// and the backend keeps it in a register for the whole loop. Only the final
+/// [sum_cube](ThreadAccumulator::sum_cube) goes through shared memory, once per unit.
+///
+/// # Example
+///
+/// ```ignore
+/// #[cube(launch)]
+/// fn partial_sums(input: &Array<f32>, output: &mut Array<f32>, #[comptime] cube_size: u32) {
+///     let mut acc = ThreadAccumulator::<f32>::new();
+///     for i in grid_stride_range(input.len()) {
+///         acc.accumulate(input[i]);
+///     }
+///
+///     let sum = acc.sum_cube(cube_size, false);
+///     if UNIT_POS == 0 {
+///         output[CUBE_POS] = sum;
+///     }
+/// }
+/// ```
+#[derive(CubeType)]
+pub struct ThreadAccumulator<T: Numeric> {
+    value: T,
+}
+
+impl<T: Numeric> Default for ThreadAccumulator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cube]
+impl<T: Numeric> ThreadAccumulator<T> {
+    /// Create an accumulator starting at zero, which must be bound to a mutable variable.
+    pub fn new() -> ThreadAccumulator<T> {
+        ThreadAccumulator::<T> {
+            value: T::from_int(0),
+        }
+    }
+
+    /// Add the value to the accumulator.
+    pub fn accumulate(&mut self, value: T) {
+        self.value += value;
+    }
+
+    /// The value accumulated by this unit.
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    /// Sum the accumulators of all the units of the cube, returned to every unit.
+    ///
+    /// With `use_planes`, each plane is first summed with [plane_sum], so only one value per plane
+    /// goes through shared memory. Otherwise, the accumulators are summed with a tree in shared
+    /// memory. `cube_size` must be the number of units in the cube, and all of them must call it,
+    /// since it synchronizes the cube.
+    pub fn sum_cube(self, #[comptime] cube_size: u32, #[comptime] use_planes: bool) -> T {
+        let mut shared = SharedMemory::<T>::new(cube_size);
+
+        if comptime!(use_planes) {
+            let plane_total = plane_sum(self.value);
+            if UNIT_POS_PLANE == 0 {
+                shared[UNIT_POS / PLANE_DIM] = plane_total;
+            }
+            sync_cube();
+
+            let num_planes = div_ceil(CUBE_DIM, PLANE_DIM);
+            let mut total = T::from_int(0);
+            for plane in 0..num_planes {
+                total += shared[plane];
+            }
+            total
+        } else {
+            shared[UNIT_POS] = self.value;
+            sync_cube();
+
+            let mut num_remaining = cube_size.runtime();
+            let mut jump = 1;
+            while num_remaining > 1 {
+                let destination = jump * 2 * UNIT_POS;
+                let origin = jump * (2 * UNIT_POS + 1);
+                if UNIT_POS < num_remaining / 2 {
+                    let value = shared[origin];
+                    shared[destination] += value;
+                }
+                num_remaining = div_ceil(num_remaining, 2);
+                jump *= 2;
+                sync_cube();
+            }
+            shared[0]
+        }
+    }
+}
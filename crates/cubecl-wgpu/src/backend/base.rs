@@ -1,13 +1,17 @@
 use std::{borrow::Cow, sync::Arc};
 
-use cubecl_core::{ExecutionMode, Feature, WgpuCompilationOptions, prelude::CompiledKernel};
+use cubecl_core::{
+    ExecutionMode, Feature, WgpuCompilationOptions, compute::MAX_PUSH_CONSTANTS_SIZE,
+    prelude::CompiledKernel,
+};
 use cubecl_runtime::DeviceProperties;
 use wgpu::{
     Adapter, BindGroupLayoutDescriptor, BindGroupLayoutEntry, BindingType, BufferBindingType,
-    ComputePipeline, Device, PipelineLayoutDescriptor, Queue, ShaderModuleDescriptor, ShaderStages,
+    ComputePipeline, Device, PipelineLayoutDescriptor, PushConstantRange, Queue,
+    ShaderModuleDescriptor, ShaderStages,
 };
 
-use crate::{AutoCompiler, AutoRepresentation, WgpuServer};
+use crate::{AutoCompiler, AutoRepresentation, WgpuServer, compute::stream::PushConstantOffsets};
 
 use super::wgsl;
 
@@ -19,6 +23,19 @@ use super::metal;
 #[cfg(all(feature = "msl", target_os = "macos"))]
 use cubecl_cpp::metal as cpp_metal;
 
+/// The push constant offsets of the kernel, if it receives its scalars as push constants.
+pub(crate) fn push_constant_offsets(
+    kernel: &CompiledKernel<AutoCompiler>,
+) -> Option<PushConstantOffsets> {
+    match &kernel.repr {
+        #[cfg(feature = "spirv")]
+        Some(AutoRepresentation::SpirV(repr)) => {
+            repr.push_constant_offsets.as_deref().map(Into::into)
+        }
+        _ => None,
+    }
+}
+
 impl WgpuServer {
     pub fn create_pipeline(
         &mut self,
@@ -73,7 +90,7 @@ impl WgpuServer {
                 unsafe {
                     self.device.create_shader_module_trusted(
                         ShaderModuleDescriptor {
-                            label: None,
+                            label: Some(&kernel.entrypoint_name),
                             source: wgpu::ShaderSource::Wgsl(Cow::Borrowed(source)),
                         },
                         checks,
@@ -89,6 +106,13 @@ impl WgpuServer {
             Some(AutoRepresentation::SpirV(repr)) => Some(vulkan::bindings(repr)),
             _ => None,
         };
+        let push_constant_ranges = match push_constant_offsets(&kernel) {
+            Some(_) => vec![PushConstantRange {
+                stages: ShaderStages::COMPUTE,
+                range: 0..MAX_PUSH_CONSTANTS_SIZE as u32,
+            }],
+            None => Vec::new(),
+        };
         let layout = bindings.map(|bindings| {
             let bindings = bindings
                 .into_iter()
@@ -121,7 +145,7 @@ impl WgpuServer {
                 .create_pipeline_layout(&PipelineLayoutDescriptor {
                     label: None,
                     bind_group_layouts: &[&layout],
-                    push_constant_ranges: &[],
+                    push_constant_ranges: &push_constant_ranges,
                 })
         });
 
@@ -170,38 +194,56 @@ pub async fn request_device(adapter: &Adapter) -> (Device, Queue) {
 #[cfg(all(not(feature = "spirv"), not(feature = "msl")))]
 pub fn register_features(
     adapter: &Adapter,
+    device: &Device,
     props: &mut DeviceProperties<Feature>,
     comp_options: &mut WgpuCompilationOptions,
 ) {
-    wgsl::register_wgsl_features(adapter, props, comp_options);
+    wgsl::register_wgsl_features(device, props, comp_options);
 }
 
 #[cfg(feature = "spirv")]
 pub fn register_features(
     adapter: &Adapter,
+    device: &Device,
     props: &mut DeviceProperties<Feature>,
     comp_options: &mut WgpuCompilationOptions,
 ) {
     if is_vulkan(adapter) {
-        vulkan::register_vulkan_features(adapter, props, comp_options);
+        vulkan::register_vulkan_features(adapter, device, props, comp_options);
     } else {
-        wgsl::register_wgsl_features(adapter, props, comp_options);
+        wgsl::register_wgsl_features(device, props, comp_options);
     }
 }
 
 #[cfg(all(feature = "msl", target_os = "macos"))]
 pub fn register_features(
     adapter: &Adapter,
+    device: &Device,
     props: &mut DeviceProperties<Feature>,
     comp_options: &mut WgpuCompilationOptions,
 ) {
     if is_metal(adapter) {
-        metal::register_metal_features(adapter, props, comp_options);
+        metal::register_metal_features(adapter, device, props, comp_options);
     } else {
         panic!("metal device not found!");
     }
 }
 
+/// The version of the graphics API of the adapter, when it can be queried.
+#[cfg(feature = "spirv")]
+pub fn api_version(adapter: &Adapter) -> Option<String> {
+    match is_vulkan(adapter) {
+        true => vulkan::api_version(adapter),
+        false => None,
+    }
+}
+
+/// The version of the graphics API of the adapter, when it can be queried.
+#[cfg(not(feature = "spirv"))]
+pub fn api_version(_adapter: &Adapter) -> Option<String> {
+    None
+}
+
 #[cfg(feature = "spirv")]
 fn is_vulkan(adapter: &Adapter) -> bool {
     unsafe { adapter.as_hal::<wgpu::hal::api::Vulkan, _, _>(|adapter| adapter.is_some()) }
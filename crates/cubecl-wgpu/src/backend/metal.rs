@@ -56,7 +56,7 @@ pub fn register_metal_features(
     props: &mut cubecl_runtime::DeviceProperties<cubecl_core::Feature>,
     comp_options: &mut WgpuCompilationOptions,
 ) {
-    let features = adapter.features();
+    let features = device.features();
     unsafe {
         adapter.as_hal::<hal::api::Metal, _, _>(|hal_adapter| {
             if let Some(adapter) = hal_adapter {
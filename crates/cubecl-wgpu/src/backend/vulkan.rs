@@ -1,13 +1,13 @@
 use ash::{
     khr::cooperative_matrix,
     vk::{
-        ComponentTypeKHR, DeviceCreateInfo, DeviceQueueCreateInfo, EXT_ROBUSTNESS2_NAME, ScopeKHR,
-        TRUE,
+        self, ComponentTypeKHR, DeviceCreateInfo, DeviceQueueCreateInfo, EXT_ROBUSTNESS2_NAME,
+        ScopeKHR, TRUE,
     },
 };
 use cubecl_core::{
-    AtomicFeature, ExecutionMode, Feature, WgpuCompilationOptions,
-    compute::Visibility,
+    AtomicFeature, CompilationError, ExecutionMode, Feature, WgpuCompilationOptions,
+    compute::{MAX_PUSH_CONSTANTS_SIZE, Visibility},
     ir::{Elem, FloatKind, IntKind, UIntKind},
     prelude::CompiledKernel,
     server::ComputeServer,
@@ -34,7 +34,9 @@ pub fn bindings(repr: &SpirvKernel) -> Vec<(usize, Visibility)> {
     if repr.has_metadata {
         bindings.push(Visibility::Read);
     }
-    bindings.extend(repr.scalars.iter().map(|_| Visibility::Read));
+    if repr.push_constant_offsets.is_none() {
+        bindings.extend(repr.scalars.iter().map(|_| Visibility::Read));
+    }
     bindings.into_iter().enumerate().collect()
 }
 
@@ -50,10 +52,13 @@ pub async fn request_vulkan_device(adapter: &wgpu::Adapter) -> (wgpu::Device, wg
 
 pub fn register_vulkan_features(
     adapter: &wgpu::Adapter,
+    device: &wgpu::Device,
     props: &mut cubecl_runtime::DeviceProperties<cubecl_core::Feature>,
     comp_options: &mut WgpuCompilationOptions,
 ) {
-    let features = adapter.features();
+    let features = device.features();
+    comp_options.supports_push_constants = features.contains(Features::PUSH_CONSTANTS)
+        && device.limits().max_push_constant_size as usize >= MAX_PUSH_CONSTANTS_SIZE;
     unsafe {
         adapter.as_hal::<hal::api::Vulkan, _, _>(|hal_adapter| {
             if let Some(adapter) = hal_adapter {
@@ -63,6 +68,27 @@ pub fn register_vulkan_features(
     }
 }
 
+/// The Vulkan API version supported by the physical device of the adapter.
+pub(crate) fn api_version(adapter: &wgpu::Adapter) -> Option<String> {
+    unsafe {
+        adapter.as_hal::<hal::api::Vulkan, _, _>(|hal_adapter| {
+            hal_adapter.map(|adapter| {
+                let version = adapter
+                    .shared_instance()
+                    .raw_instance()
+                    .get_physical_device_properties(adapter.raw_physical_device())
+                    .api_version;
+                format!(
+                    "{}.{}.{}",
+                    vk::api_version_major(version),
+                    vk::api_version_minor(version),
+                    vk::api_version_patch(version)
+                )
+            })
+        })
+    }
+}
+
 /// Request device with required features, plus CMMA if available.
 fn request_device(
     wgpu_adapter: &wgpu::Adapter,
@@ -182,6 +208,18 @@ fn register_features(
         }
     }
 
+    if let Some(bfloat16) = &extended_feat.bfloat16 {
+        if bfloat16.shader_b_float16_type == TRUE {
+            comp_options.supports_bf16 = true;
+        }
+    }
+
+    if let Some(integer_dot_product) = &extended_feat.integer_dot_product {
+        if integer_dot_product.shader_integer_dot_product == TRUE {
+            props.register_feature(Feature::IntegerDotProduct);
+        }
+    }
+
     if extended_feat.cmma.is_some() {
         register_cmma(ash, adapter, props);
     }
@@ -222,6 +260,11 @@ fn register_types(props: &mut DeviceProperties<Feature>, ext_feat: &ExtendedFeat
         register(Elem::UInt(UIntKind::U8));
     }
 
+    // bf16 is computed with as f32, so it only needs 16-bit storage buffers.
+    if ext_feat.buf_16.storage_buffer16_bit_access == TRUE {
+        register(Elem::Float(FloatKind::BF16));
+    }
+
     if let Some(atomic_float) = ext_feat.atomic_float {
         if atomic_float.shader_buffer_float32_atomics == TRUE {
             register(Elem::AtomicFloat(FloatKind::F32));
@@ -287,13 +330,44 @@ fn convert_type(vk_ty: ComponentTypeKHR) -> Option<Elem> {
     Some(ty)
 }
 
+/// Vulkan feature profile to generate SPIR-V for, which doesn't have to match the local device.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CompileTarget {
+    /// Assume `VK_KHR_shader_float_controls2` is available, required by fast math modes.
+    pub float_controls2: bool,
+}
+
+impl CompileTarget {
+    /// The compilation options supported by every device of the target profile.
+    pub fn compilation_options(&self) -> WgpuCompilationOptions {
+        WgpuCompilationOptions {
+            supports_fp_fast_math: self.float_controls2,
+            supports_u64: true,
+            max_shared_memory_size: None,
+            supports_push_constants: true,
+            supports_bf16: false,
+            plane_size_min: None,
+        }
+    }
+}
+
+/// Compile the kernel to SPIR-V for the given target, without using any local device.
+pub fn compile_for_target(
+    kernel: <WgpuServer as ComputeServer>::Kernel,
+    target: CompileTarget,
+    mode: ExecutionMode,
+) -> Result<CompiledKernel<AutoCompiler>, CompilationError> {
+    let mut compiler = AutoCompiler::SpirV(Default::default());
+    kernel.compile(&mut compiler, &target.compilation_options(), mode)
+}
+
 /// Check robustness, compile, and optionally dump SPIR-V
 pub(crate) fn compile(
     dyn_comp: &mut AutoCompiler,
     server: &mut WgpuServer,
     kernel: <WgpuServer as ComputeServer>::Kernel,
     mode: ExecutionMode,
-) -> CompiledKernel<AutoCompiler> {
+) -> Result<CompiledKernel<AutoCompiler>, CompilationError> {
     // `wgpu` currently always enables `robustness2` on Vulkan if available, so default to
     // unchecked execution if robustness is enabled and let Vulkan handle it
     let mode = if is_robust(&server.device) {
@@ -302,10 +376,34 @@ pub(crate) fn compile(
         mode
     };
     log::debug!("Compiling {}", kernel.name());
-    let compiled = kernel.compile(dyn_comp, &server.compilation_options, mode);
+    let compiled = kernel.compile(dyn_comp, &server.compilation_options, mode)?;
     #[cfg(feature = "spirv-dump")]
     dump_spirv(&compiled, kernel.name(), kernel.id());
-    compiled
+    #[cfg(feature = "spirv-val")]
+    if std::env::var("CUBECL_VALIDATE_SPIRV").is_ok() {
+        if let Some(repr) = compiled.repr.as_ref().and_then(|repr| repr.as_spirv()) {
+            validate_spirv(&repr.assemble())?;
+        }
+    }
+    Ok(compiled)
+}
+
+/// Run the SPIR-V module through `spirv-val`, returning the validation errors along with the
+/// offending instruction.
+///
+/// Catches malformed modules before they reach the driver, which often rejects them without a
+/// useful message. Done on every compilation when `CUBECL_VALIDATE_SPIRV` is set.
+#[cfg(feature = "spirv-val")]
+pub fn validate_spirv(words: &[u32]) -> Result<(), CompilationError> {
+    use spirv_tools::{
+        TargetEnv,
+        val::{self, Validator},
+    };
+
+    let validator = val::create(Some(TargetEnv::Vulkan_1_2));
+    validator
+        .validate(words, None)
+        .map_err(|err| CompilationError::BackendError(format!("[SPIR-V Validation] {err}")))
 }
 
 fn is_robust(device: &wgpu::Device) -> bool {
@@ -1,17 +1,51 @@
-use std::{ffi::CStr, ptr::null_mut};
+use std::{
+    ffi::{CStr, c_void},
+    ptr::null_mut,
+};
 
 use ash::vk::{
-    DeviceCreateInfo, EXT_SHADER_ATOMIC_FLOAT_NAME, EXT_SHADER_ATOMIC_FLOAT2_NAME,
+    Bool32, DeviceCreateInfo, EXT_SHADER_ATOMIC_FLOAT_NAME, EXT_SHADER_ATOMIC_FLOAT2_NAME,
     ExtendsDeviceCreateInfo, ExtendsPhysicalDeviceFeatures2, KHR_COOPERATIVE_MATRIX_NAME,
-    KHR_SHADER_FLOAT_CONTROLS2_NAME, PhysicalDevice8BitStorageFeatures,
-    PhysicalDevice16BitStorageFeatures, PhysicalDeviceCooperativeMatrixFeaturesKHR,
-    PhysicalDeviceFeatures2, PhysicalDeviceShaderAtomicFloat2FeaturesEXT,
-    PhysicalDeviceShaderAtomicFloatFeaturesEXT, PhysicalDeviceShaderFloat16Int8Features,
-    PhysicalDeviceShaderFloatControls2FeaturesKHR,
+    KHR_SHADER_FLOAT_CONTROLS2_NAME, KHR_SHADER_INTEGER_DOT_PRODUCT_NAME,
+    PhysicalDevice8BitStorageFeatures, PhysicalDevice16BitStorageFeatures,
+    PhysicalDeviceCooperativeMatrixFeaturesKHR, PhysicalDeviceFeatures2,
+    PhysicalDeviceShaderAtomicFloat2FeaturesEXT, PhysicalDeviceShaderAtomicFloatFeaturesEXT,
+    PhysicalDeviceShaderFloat16Int8Features, PhysicalDeviceShaderFloatControls2FeaturesKHR,
+    PhysicalDeviceShaderIntegerDotProductFeatures,
     PhysicalDeviceShaderSubgroupExtendedTypesFeatures, PhysicalDeviceVulkanMemoryModelFeatures,
+    StructureType,
 };
 use wgpu::{Features, hal::vulkan};
 
+/// `VK_KHR_shader_bfloat16`, not exposed by `ash` yet.
+pub const KHR_SHADER_BFLOAT16_NAME: &CStr = c"VK_KHR_shader_bfloat16";
+
+/// `VkPhysicalDeviceShaderBfloat16FeaturesKHR`, not exposed by `ash` yet.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct PhysicalDeviceShaderBfloat16FeaturesKHR {
+    pub s_type: StructureType,
+    pub p_next: *mut c_void,
+    pub shader_b_float16_type: Bool32,
+    pub shader_b_float16_dot_product: Bool32,
+    pub shader_b_float16_cooperative_matrix: Bool32,
+}
+
+impl Default for PhysicalDeviceShaderBfloat16FeaturesKHR {
+    fn default() -> Self {
+        Self {
+            s_type: StructureType::from_raw(1000141000),
+            p_next: null_mut(),
+            shader_b_float16_type: 0,
+            shader_b_float16_dot_product: 0,
+            shader_b_float16_cooperative_matrix: 0,
+        }
+    }
+}
+
+unsafe impl ExtendsPhysicalDeviceFeatures2 for PhysicalDeviceShaderBfloat16FeaturesKHR {}
+unsafe impl ExtendsDeviceCreateInfo for PhysicalDeviceShaderBfloat16FeaturesKHR {}
+
 #[derive(Default, Debug)]
 pub struct ExtendedFeatures<'a> {
     pub mem_model: PhysicalDeviceVulkanMemoryModelFeatures<'a>,
@@ -25,6 +59,8 @@ pub struct ExtendedFeatures<'a> {
     pub atomic_float: Option<PhysicalDeviceShaderAtomicFloatFeaturesEXT<'a>>,
     pub atomic_float2: Option<PhysicalDeviceShaderAtomicFloat2FeaturesEXT<'a>>,
     pub float_controls2: Option<PhysicalDeviceShaderFloatControls2FeaturesKHR<'a>>,
+    pub bfloat16: Option<PhysicalDeviceShaderBfloat16FeaturesKHR>,
+    pub integer_dot_product: Option<PhysicalDeviceShaderIntegerDotProductFeatures<'a>>,
 
     pub extensions: Vec<&'static CStr>,
 }
@@ -64,6 +100,17 @@ impl<'a> ExtendedFeatures<'a> {
             self.extensions.push(KHR_SHADER_FLOAT_CONTROLS2_NAME);
             self.float_controls2 = Some(PhysicalDeviceShaderFloatControls2FeaturesKHR::default());
         }
+
+        if phys_caps.supports_extension(KHR_SHADER_BFLOAT16_NAME) {
+            self.extensions.push(KHR_SHADER_BFLOAT16_NAME);
+            self.bfloat16 = Some(PhysicalDeviceShaderBfloat16FeaturesKHR::default());
+        }
+
+        if phys_caps.supports_extension(KHR_SHADER_INTEGER_DOT_PRODUCT_NAME) {
+            self.extensions.push(KHR_SHADER_INTEGER_DOT_PRODUCT_NAME);
+            self.integer_dot_product =
+                Some(PhysicalDeviceShaderIntegerDotProductFeatures::default());
+        }
     }
 
     pub fn add_to_device_create(&'a mut self, info: DeviceCreateInfo<'a>) -> DeviceCreateInfo<'a> {
@@ -88,6 +135,8 @@ impl<'a> ExtendedFeatures<'a> {
         info = push_opt(info, &mut self.atomic_float);
         info = push_opt(info, &mut self.atomic_float2);
         info = push_opt(info, &mut self.float_controls2);
+        info = push_opt(info, &mut self.bfloat16);
+        info = push_opt(info, &mut self.integer_dot_product);
 
         info
     }
@@ -114,6 +163,8 @@ impl<'a> ExtendedFeatures<'a> {
         features = push_opt(features, &mut self.atomic_float);
         features = push_opt(features, &mut self.atomic_float2);
         features = push_opt(features, &mut self.float_controls2);
+        features = push_opt(features, &mut self.bfloat16);
+        features = push_opt(features, &mut self.integer_dot_product);
 
         unsafe {
             ash.get_physical_device_features2(adapter.raw_physical_device(), &mut features);
@@ -142,5 +193,11 @@ impl<'a> ExtendedFeatures<'a> {
         if let Some(float_controls2) = &mut self.float_controls2 {
             float_controls2.p_next = null_mut();
         }
+        if let Some(bfloat16) = &mut self.bfloat16 {
+            bfloat16.p_next = null_mut();
+        }
+        if let Some(integer_dot_product) = &mut self.integer_dot_product {
+            integer_dot_product.p_next = null_mut();
+        }
     }
 }
@@ -45,17 +45,17 @@ pub async fn request_device(adapter: &wgpu::Adapter) -> (wgpu::Device, wgpu::Que
 }
 
 pub fn register_wgsl_features(
-    adapter: &wgpu::Adapter,
+    device: &wgpu::Device,
     props: &mut cubecl_runtime::DeviceProperties<cubecl_core::Feature>,
     comp_options: &mut WgpuCompilationOptions,
 ) {
-    register_types(props, adapter);
+    register_types(props, device);
     if props.feature_enabled(Feature::Type(Elem::UInt(UIntKind::U64))) {
         comp_options.supports_u64 = true;
     }
 }
 
-pub fn register_types(props: &mut DeviceProperties<Feature>, adapter: &wgpu::Adapter) {
+pub fn register_types(props: &mut DeviceProperties<Feature>, device: &wgpu::Device) {
     use cubecl_core::ir::{Elem, FloatKind, IntKind};
 
     let supported_types = [
@@ -76,7 +76,7 @@ pub fn register_types(props: &mut DeviceProperties<Feature>, adapter: &wgpu::Ada
         register(ty)
     }
 
-    let feats = adapter.features();
+    let feats = device.features();
 
     if feats.contains(wgpu::Features::SHADER_INT64) {
         register(Elem::Int(IntKind::I64));
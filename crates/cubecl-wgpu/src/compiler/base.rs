@@ -2,7 +2,7 @@ use std::fmt::Display;
 
 use cubecl_common::ExecutionMode;
 use cubecl_core::{
-    Compiler, WgpuCompilationOptions,
+    CompilationError, CompilationTarget, Compiler, WgpuCompilationOptions,
     prelude::{CompiledKernel, KernelDefinition},
     server::ComputeServer,
 };
@@ -76,23 +76,29 @@ impl Compiler for AutoCompiler {
         kernel: KernelDefinition,
         compilation_options: &Self::CompilationOptions,
         mode: ExecutionMode,
-    ) -> Self::Representation {
-        match self {
+    ) -> Result<Self::Representation, CompilationError> {
+        let repr = match self {
             AutoCompiler::Wgsl(wgsl_compiler) => {
-                Compiler::compile(wgsl_compiler, kernel, compilation_options, mode).into()
+                warn_unsupported_cluster(&kernel);
+                Compiler::compile(wgsl_compiler, kernel, compilation_options, mode)?.into()
             }
             #[cfg(feature = "spirv")]
             AutoCompiler::SpirV(spirv_compiler) => {
-                Compiler::compile(spirv_compiler, kernel, compilation_options, mode).into()
+                warn_unsupported_cluster(&kernel);
+                Compiler::compile(spirv_compiler, kernel, compilation_options, mode)?.into()
             }
             #[cfg(feature = "msl")]
             AutoCompiler::Msl(msl_compiler) => {
                 // override compilation options with cpp compiler options for metal
                 use cubecl_cpp;
-                let compilation_options = cubecl_cpp::shared::CompilationOptions::default();
-                Compiler::compile(msl_compiler, kernel, &compilation_options, mode).into()
+                let compilation_options = cubecl_cpp::shared::CompilationOptions {
+                    max_shared_memory_size: compilation_options.max_shared_memory_size,
+                    ..Default::default()
+                };
+                Compiler::compile(msl_compiler, kernel, &compilation_options, mode)?.into()
             }
-        }
+        };
+        Ok(repr)
     }
 
     fn elem_size(&self, elem: cubecl_core::ir::Elem) -> usize {
@@ -114,6 +120,28 @@ impl Compiler for AutoCompiler {
             AutoCompiler::Msl(_) => "msl",
         }
     }
+
+    fn target(&self) -> CompilationTarget {
+        match self {
+            AutoCompiler::Wgsl(_) => CompilationTarget::Wgsl,
+            #[cfg(feature = "spirv")]
+            AutoCompiler::SpirV(_) => CompilationTarget::SpirV,
+            #[cfg(feature = "msl")]
+            AutoCompiler::Msl(_) => CompilationTarget::Metal,
+        }
+    }
+}
+
+/// Clusters aren't supported by WGSL and SPIR-V, so the cluster dim of the kernel is ignored. The
+/// Metal compiler already warns about it.
+fn warn_unsupported_cluster(kernel: &KernelDefinition) {
+    if kernel.options.cluster_dim.is_some() {
+        log::warn!(
+            "Kernel {} requests a cluster dim, but the target doesn't support clusters. \
+             Ignoring it, each cube is its own cluster.",
+            kernel.options.kernel_name
+        );
+    }
 }
 
 impl AutoCompiler {
@@ -122,7 +150,7 @@ impl AutoCompiler {
         server: &mut WgpuServer,
         kernel: <WgpuServer as ComputeServer>::Kernel,
         mode: ExecutionMode,
-    ) -> CompiledKernel<Self> {
+    ) -> Result<CompiledKernel<Self>, CompilationError> {
         match self {
             AutoCompiler::Wgsl(_) => kernel.compile(self, &server.compilation_options, mode),
             #[cfg(feature = "spirv")]
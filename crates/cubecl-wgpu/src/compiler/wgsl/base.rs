@@ -303,6 +303,10 @@ impl Display for Variable {
                     FloatKind::F16 | FloatKind::BF16 | FloatKind::TF32 => {
                         todo!("Unsupported")
                     }
+                    // WGSL has no literal for infinities and NaN, so bitcast their bits instead.
+                    FloatKind::F32 | FloatKind::Flex32 | FloatKind::F64 if !val.is_finite() => {
+                        write!(f, "bitcast<f32>({:#x}u)", (*val as f32).to_bits())
+                    }
                     FloatKind::F32 | FloatKind::Flex32 | FloatKind::F64 => {
                         f.write_str(&format_number(*val))
                     }
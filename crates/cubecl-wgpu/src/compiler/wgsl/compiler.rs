@@ -7,9 +7,9 @@ use cubecl_common::ExecutionMode;
 use cubecl_core::ir::{ConstantScalarValue, ExpandElement, UIntKind};
 use cubecl_core::prelude::{FloatExpand, Line};
 use cubecl_core::{
-    Metadata, WgpuCompilationOptions, compute,
+    CompilationError, Metadata, WgpuCompilationOptions, compute,
     ir::{self as cube, Scope},
-    prelude::{expand_checked_index_assign, expand_erf},
+    prelude::{expand_cast_rounded, expand_checked_index_assign, expand_erf},
 };
 use cubecl_core::{io::read_tensor_checked, prelude::*};
 
@@ -37,6 +37,9 @@ pub struct WgslCompiler {
     compilation_options: WgpuCompilationOptions,
     strategy: ExecutionMode,
     subgroup_instructions_used: bool,
+    max_plane_partition_size: u32,
+    /// The first instruction of the kernel that WGSL doesn't support, if any.
+    unsupported_instruction: Option<String>,
 }
 
 impl core::fmt::Debug for WgslCompiler {
@@ -54,9 +57,35 @@ impl cubecl_core::Compiler for WgslCompiler {
         shader: compute::KernelDefinition,
         compilation_options: &Self::CompilationOptions,
         mode: ExecutionMode,
-    ) -> Self::Representation {
+    ) -> Result<Self::Representation, CompilationError> {
         self.compilation_options = compilation_options.clone();
-        self.compile_shader(shader, mode)
+        let shader = self.compile_shader(shader, mode);
+
+        if let Some(op) = self.unsupported_instruction.take() {
+            return Err(CompilationError::UnsupportedInstruction {
+                op,
+                backend: "WGSL",
+            });
+        }
+
+        if let Some(limit) = self.compilation_options.max_shared_memory_size {
+            let requested = shader.shared_memory_size();
+            if requested > limit {
+                return Err(CompilationError::SharedMemoryExceeded { requested, limit });
+            }
+        }
+
+        if let Some(plane_size) = self.compilation_options.plane_size_min {
+            let partition_size = self.max_plane_partition_size;
+            if partition_size > plane_size {
+                return Err(CompilationError::InvalidPlanePartition {
+                    partition_size,
+                    plane_size,
+                });
+            }
+        }
+
+        Ok(shader)
     }
 
     fn elem_size(&self, elem: cube::Elem) -> usize {
@@ -66,6 +95,10 @@ impl cubecl_core::Compiler for WgslCompiler {
     fn extension(&self) -> &'static str {
         "wgsl"
     }
+
+    fn target(&self) -> cubecl_core::CompilationTarget {
+        cubecl_core::CompilationTarget::Wgsl
+    }
 }
 
 impl WgslCompiler {
@@ -365,9 +398,22 @@ impl WgslCompiler {
         let processing = scope.process();
 
         for var in processing.variables {
-            // We don't declare slices.
-            if let cube::VariableKind::Slice { .. } = var.kind {
-                continue;
+            match var.kind {
+                // We don't declare slices.
+                cube::VariableKind::Slice { .. } => continue,
+                cube::VariableKind::Matrix { .. } => {
+                    self.unsupported("cooperative matrix-multiply and accumulate");
+                    continue;
+                }
+                cube::VariableKind::Pipeline { .. } => {
+                    self.unsupported("pipeline");
+                    continue;
+                }
+                cube::VariableKind::Barrier { .. } => {
+                    self.unsupported("barrier");
+                    continue;
+                }
+                _ => {}
             }
 
             instructions.push(wgsl::Instruction::DeclareVariable {
@@ -390,6 +436,11 @@ impl WgslCompiler {
         out: Option<cube::Variable>,
         scope: &mut cube::Scope,
     ) {
+        // The kernel is rejected once an instruction isn't supported, so the rest isn't compiled.
+        if self.unsupported_instruction.is_some() {
+            return;
+        }
+
         match operation {
             cube::Operation::Copy(variable) => instructions.push(wgsl::Instruction::Assign {
                 input: self.compile_variable(variable),
@@ -405,26 +456,28 @@ impl WgslCompiler {
             cube::Operation::Metadata(op) => instructions.push(self.compile_metadata(op, out)),
             cube::Operation::Branch(val) => self.compile_branch(instructions, val),
             cube::Operation::Synchronization(val) => {
-                self.compile_synchronization(instructions, val)
+                self.compile_synchronization(instructions, val, out)
             }
             cube::Operation::Plane(op) => self.compile_subgroup(instructions, op, out),
             cube::Operation::CoopMma(_) => {
-                panic!("Cooperative matrix-multiply and accumulate isn't supported on wgpu.")
+                self.unsupported("cooperative matrix-multiply and accumulate")
             }
             cube::Operation::NonSemantic(cube::NonSemantic::Comment { content }) => {
                 self.compile_comment(instructions, content)
             }
             cube::Operation::NonSemantic(_) => {}
-            cube::Operation::Pipeline(_) => {
-                panic!("Pipeline isn't supported on wgpu.")
-            }
-            cube::Operation::Barrier(_) => {
-                panic!("Barrier isn't supported on wgpu.")
-            }
-            cube::Operation::Tma(_) => panic!("TMA isn't supported on wgpu."),
+            cube::Operation::Pipeline(_) => self.unsupported("pipeline"),
+            cube::Operation::Barrier(_) => self.unsupported("barrier"),
+            cube::Operation::Tma(_) => self.unsupported("TMA"),
         }
     }
 
+    /// Reject the kernel because it uses an instruction that WGSL doesn't support.
+    fn unsupported(&mut self, op: &str) {
+        self.unsupported_instruction
+            .get_or_insert_with(|| op.to_string());
+    }
+
     fn compile_subgroup(
         &mut self,
         instructions: &mut Vec<wgsl::Instruction>,
@@ -455,6 +508,23 @@ impl WgslCompiler {
                 rhs: self.compile_variable(op.rhs),
                 out: self.compile_variable(out),
             },
+            cube::Plane::ShuffleUp(op) => {
+                self.subgroup_invocation_id = true;
+                Subgroup::ShuffleUp {
+                    lhs: self.compile_variable(op.lhs),
+                    rhs: self.compile_variable(op.rhs),
+                    out: self.compile_variable(out),
+                }
+            }
+            cube::Plane::ShuffleDown(op) => {
+                self.subgroup_invocation_id = true;
+                self.subgroup_size = true;
+                Subgroup::ShuffleDown {
+                    lhs: self.compile_variable(op.lhs),
+                    rhs: self.compile_variable(op.rhs),
+                    out: self.compile_variable(out),
+                }
+            }
             cube::Plane::Sum(op) => Subgroup::Sum {
                 input: self.compile_variable(op.input),
                 out: self.compile_variable(out),
@@ -487,11 +557,53 @@ impl WgslCompiler {
                 input: self.compile_variable(op.input),
                 out: self.compile_variable(out),
             },
+            cube::Plane::PartitionSum(op) => {
+                self.compile_plane_partition(wgsl::PartitionOp::Sum, op, out)
+            }
+            cube::Plane::PartitionProd(op) => {
+                self.compile_plane_partition(wgsl::PartitionOp::Prod, op, out)
+            }
+            cube::Plane::PartitionMin(op) => {
+                self.compile_plane_partition(wgsl::PartitionOp::Min, op, out)
+            }
+            cube::Plane::PartitionMax(op) => {
+                self.compile_plane_partition(wgsl::PartitionOp::Max, op, out)
+            }
+            cube::Plane::PartitionInclusiveSum(op) => {
+                self.compile_plane_partition(wgsl::PartitionOp::InclusiveSum, op, out)
+            }
+            cube::Plane::PartitionExclusiveSum(op) => {
+                self.compile_plane_partition(wgsl::PartitionOp::ExclusiveSum, op, out)
+            }
+            cube::Plane::PartitionInclusiveProd(op) => {
+                self.compile_plane_partition(wgsl::PartitionOp::InclusiveProd, op, out)
+            }
+            cube::Plane::PartitionExclusiveProd(op) => {
+                self.compile_plane_partition(wgsl::PartitionOp::ExclusiveProd, op, out)
+            }
         };
 
         instructions.push(wgsl::Instruction::Subgroup(op));
     }
 
+    fn compile_plane_partition(
+        &mut self,
+        op: wgsl::PartitionOp,
+        partition: cube::PlanePartitionOperator,
+        out: cube::Variable,
+    ) -> Subgroup {
+        self.max_plane_partition_size = self.max_plane_partition_size.max(partition.partition_size);
+        if op.is_scan() {
+            self.subgroup_invocation_id = true;
+        }
+        Subgroup::Partition {
+            op,
+            input: self.compile_variable(partition.input),
+            partition_size: partition.partition_size,
+            out: self.compile_variable(out),
+        }
+    }
+
     fn compile_branch(&mut self, instructions: &mut Vec<wgsl::Instruction>, branch: cube::Branch) {
         match branch {
             cube::Branch::If(mut op) => instructions.push(wgsl::Instruction::If {
@@ -529,6 +641,17 @@ impl WgslCompiler {
             cube::Branch::Loop(mut op) => instructions.push(wgsl::Instruction::Loop {
                 instructions: self.compile_scope(&mut op.scope),
             }),
+            // WGSL functions can't access the locals of their caller, so the body is expanded in
+            // place.
+            cube::Branch::Call(mut call) => {
+                instructions.extend(self.compile_scope(&mut call.scope));
+                if let (Some(ret), Some(out)) = (call.ret, call.out) {
+                    instructions.push(wgsl::Instruction::Assign {
+                        input: self.compile_variable(ret),
+                        out: self.compile_variable(out),
+                    });
+                }
+            }
         };
     }
 
@@ -536,6 +659,7 @@ impl WgslCompiler {
         &mut self,
         instructions: &mut Vec<wgsl::Instruction>,
         synchronization: cube::Synchronization,
+        out: Option<cube::Variable>,
     ) {
         match synchronization {
             cube::Synchronization::SyncUnits => {
@@ -544,7 +668,25 @@ impl WgslCompiler {
             cube::Synchronization::SyncStorage => {
                 instructions.push(wgsl::Instruction::StorageBarrier)
             }
-            cube::Synchronization::SyncProxyShared => panic!("TMA is not supported in WGSL"),
+            cube::Synchronization::SyncProxyShared => self.unsupported("TMA proxy synchronization"),
+            // WGSL has no standalone fence, and the barriers would also wait for the other units.
+            cube::Synchronization::MemoryFence { .. } => self.unsupported("memory fence"),
+            cube::Synchronization::UniformLoad(op) => {
+                instructions.push(wgsl::Instruction::WorkgroupUniformLoad {
+                    lhs: self.compile_variable(op.lhs),
+                    rhs: self.compile_variable(op.rhs),
+                    out: self.compile_variable(out.unwrap()),
+                })
+            }
+            // WGSL has no clusters, each workgroup is its own cluster.
+            cube::Synchronization::SyncCluster => {
+                instructions.push(wgsl::Instruction::WorkgroupBarrier)
+            }
+            cube::Synchronization::ClusterLoad(op) => instructions.push(wgsl::Instruction::Index {
+                lhs: self.compile_variable(op.shared),
+                rhs: self.compile_variable(op.index),
+                out: self.compile_variable(out.unwrap()),
+            }),
         };
     }
 
@@ -740,7 +882,14 @@ impl WgslCompiler {
                 }
                 instructions.extend(self.compile_scope(&mut scope));
             }
-            cube::Arithmetic::Recip(op) => instructions.push(wgsl::Instruction::Recip {
+            // WGSL has no approximate reciprocal, the division is left to the driver.
+            cube::Arithmetic::Recip(op) | cube::Arithmetic::RecipFast(op) => {
+                instructions.push(wgsl::Instruction::Recip {
+                    input: self.compile_variable(op.input),
+                    out: self.compile_variable(out),
+                })
+            }
+            cube::Arithmetic::RsqrtFast(op) => instructions.push(wgsl::Instruction::InverseSqrt {
                 input: self.compile_variable(op.input),
                 out: self.compile_variable(out),
             }),
@@ -871,6 +1020,12 @@ impl WgslCompiler {
                 input: self.compile_variable(op.input),
                 out: self.compile_variable(out),
             }),
+            cube::Bitwise::TrailingZeros(op) => {
+                instructions.push(wgsl::Instruction::TrailingZeros {
+                    input: self.compile_variable(op.input),
+                    out: self.compile_variable(out),
+                })
+            }
         }
     }
 
@@ -887,6 +1042,17 @@ impl WgslCompiler {
                 input: self.compile_variable(op.input),
                 out: self.compile_variable(out),
             }),
+            // WGSL has no rounding mode for conversions, so they're rounded in software.
+            cube::Operator::CastRte(op) => {
+                let mut scope = scope.child();
+                expand_cast_rounded(&mut scope, op.input, out, true);
+                instructions.extend(self.compile_scope(&mut scope));
+            }
+            cube::Operator::CastRtz(op) => {
+                let mut scope = scope.child();
+                expand_cast_rounded(&mut scope, op.input, out, false);
+                instructions.extend(self.compile_scope(&mut scope));
+            }
             cube::Operator::Index(op) => {
                 if matches!(self.strategy, ExecutionMode::Checked)
                     && op.lhs.has_length()
@@ -987,9 +1153,7 @@ impl WgslCompiler {
                     });
                 }
             }
-            cube::Operator::ReinterpretSlice(_) => {
-                todo!()
-            }
+            cube::Operator::ReinterpretSlice(_) => self.unsupported("reinterpret slice"),
             cube::Operator::Reinterpret(op) => instructions.push(wgsl::Instruction::Bitcast {
                 input: self.compile_variable(op.input),
                 out: self.compile_variable(out),
@@ -1131,6 +1295,9 @@ fn register_extensions(instructions: &[wgsl::Instruction]) -> Vec<wgsl::Extensio
             wgsl::Instruction::Tanh { input, out: _ } => {
                 register_extension(wgsl::Extension::SafeTanh(input.item()))
             }
+            wgsl::Instruction::Subgroup(Subgroup::Partition { op, input, .. }) => {
+                register_extension(wgsl::Extension::PlanePartition(*op, input.item()))
+            }
             wgsl::Instruction::If { instructions, .. } => {
                 for extension in register_extensions(instructions) {
                     register_extension(extension);
@@ -1,4 +1,4 @@
-use super::base::Item;
+use super::{PartitionOp, base::Item};
 use std::fmt::Display;
 
 /// Not all functions are native to WGSL, so this struct allows to support more functions.
@@ -9,6 +9,7 @@ pub enum Extension {
     Powf(Item),
     #[cfg(target_os = "macos")]
     SafeTanh(Item),
+    PlanePartition(PartitionOp, Item),
 }
 
 impl Display for Extension {
@@ -19,10 +20,85 @@ impl Display for Extension {
             Extension::Powf(elem) => format_powf(f, elem),
             #[cfg(target_os = "macos")]
             Extension::SafeTanh(elem) => format_safe_tanh(f, elem),
+            Extension::PlanePartition(op, item) => format_plane_partition(f, *op, *item),
         }
     }
 }
 
+fn format_plane_partition(
+    f: &mut core::fmt::Formatter<'_>,
+    op: PartitionOp,
+    item: Item,
+) -> core::fmt::Result {
+    let function = op.function_name(item);
+    let shuffled = "subgroupShuffleXor(acc, offset)";
+    let combined = match op {
+        PartitionOp::Sum => format!("acc + {shuffled}"),
+        PartitionOp::Prod => format!("acc * {shuffled}"),
+        PartitionOp::Min => format!("min(acc, {shuffled})"),
+        PartitionOp::Max => format!("max(acc, {shuffled})"),
+        PartitionOp::InclusiveSum => {
+            return format_plane_partition_scan(f, &function, item, "+", None);
+        }
+        PartitionOp::ExclusiveSum => {
+            return format_plane_partition_scan(f, &function, item, "+", Some(0));
+        }
+        PartitionOp::InclusiveProd => {
+            return format_plane_partition_scan(f, &function, item, "*", None);
+        }
+        PartitionOp::ExclusiveProd => {
+            return format_plane_partition_scan(f, &function, item, "*", Some(1));
+        }
+    };
+
+    // Offsets below the partition size only flip the bits of the lane within its partition, so
+    // the butterfly never exchanges values across partitions.
+    write!(
+        f,
+        "
+fn {function}(input: {item}, partition_size: u32) -> {item} {{
+    var acc = input;
+    for (var offset = 1u; offset < partition_size; offset *= 2u) {{
+        acc = {combined};
+    }}
+    return acc;
+}}
+"
+    )
+}
+
+/// Hillis-Steele scan, the units closer than `offset` to the start of their partition keep
+/// their value. Exclusive scans, given the identity of the operation, then shift the inclusive
+/// scan up by one unit.
+fn format_plane_partition_scan(
+    f: &mut core::fmt::Formatter<'_>,
+    function: &str,
+    item: Item,
+    op: &str,
+    exclusive_identity: Option<u32>,
+) -> core::fmt::Result {
+    let result = match exclusive_identity {
+        Some(identity) => {
+            format!("select({item}({identity}), subgroupShuffleUp(acc, 1u), lane >= 1u)")
+        }
+        None => "acc".to_string(),
+    };
+
+    write!(
+        f,
+        "
+fn {function}(input: {item}, partition_size: u32, unit_pos_plane: u32) -> {item} {{
+    let lane = unit_pos_plane % partition_size;
+    var acc = input;
+    for (var offset = 1u; offset < partition_size; offset *= 2u) {{
+        acc = select(acc, acc {op} subgroupShuffleUp(acc, offset), lane >= offset);
+    }}
+    return {result};
+}}
+"
+    )
+}
+
 fn format_powf_scalar(f: &mut core::fmt::Formatter<'_>, item: &Item) -> core::fmt::Result {
     match item {
         Item::Vec4(elem) => write!(
@@ -56,6 +56,11 @@ pub enum Instruction {
     Break,
     WorkgroupBarrier,
     StorageBarrier,
+    WorkgroupUniformLoad {
+        lhs: Variable,
+        rhs: Variable,
+        out: Variable,
+    },
     // Index handles casting to correct local variable.
     Index {
         lhs: Variable,
@@ -134,6 +139,10 @@ pub enum Instruction {
         input: Variable,
         out: Variable,
     },
+    InverseSqrt {
+        input: Variable,
+        out: Variable,
+    },
     Equal {
         lhs: Variable,
         rhs: Variable,
@@ -253,6 +262,10 @@ pub enum Instruction {
         input: Variable,
         out: Variable,
     },
+    TrailingZeros {
+        input: Variable,
+        out: Variable,
+    },
     Round {
         input: Variable,
         out: Variable,
@@ -636,6 +649,10 @@ impl Display for Instruction {
                 let out = out.fmt_left();
                 write!(f, "{out} = 1.0 / {input};")
             }
+            Instruction::InverseSqrt { input, out } => {
+                let out = out.fmt_left();
+                writeln!(f, "{out} = inverseSqrt({input});")
+            }
             Instruction::Equal { lhs, rhs, out } => comparison(lhs, rhs, out, "==", f),
             Instruction::Lower { lhs, rhs, out } => comparison(lhs, rhs, out, "<", f),
             Instruction::Greater { lhs, rhs, out } => comparison(lhs, rhs, out, ">", f),
@@ -778,6 +795,12 @@ for (var {i}: {i_ty} = {start}; {i} {cmp} {end}; {increment}) {{
             Instruction::Return => f.write_str("return;\n"),
             Instruction::Break => f.write_str("break;\n"),
             Instruction::WorkgroupBarrier => f.write_str("workgroupBarrier();\n"),
+            Instruction::WorkgroupUniformLoad { lhs, rhs, out } => {
+                let value = format!("workgroupUniformLoad(&{lhs}[{rhs}])");
+                let value = lhs.item().fmt_cast_to(out.item(), value);
+                let out = out.fmt_left();
+                writeln!(f, "{out} = {value};")
+            }
             Instruction::StorageBarrier => f.write_str("storageBarrier();\n"),
             Instruction::Length { var, out } => {
                 let out = out.fmt_left();
@@ -853,6 +876,18 @@ for (var {i}: {i_ty} = {start}; {i} {cmp} {end}; {increment}) {{
                 let out = out.fmt_left();
                 writeln!(f, "{out} = firstTrailingBit({input}) + 1;")
             }
+            Instruction::TrailingZeros { input, out } => {
+                let u32_ty = match input.item() {
+                    Item::Vec4(_) => Item::Vec4(Elem::U32),
+                    Item::Vec3(_) => Item::Vec3(Elem::U32),
+                    Item::Vec2(_) => Item::Vec2(Elem::U32),
+                    Item::Scalar(_) => Item::Scalar(Elem::U32),
+                };
+
+                let input = input.fmt_cast_to(u32_ty);
+                let out = out.fmt_left();
+                writeln!(f, "{out} = countTrailingZeros({input});")
+            }
             Instruction::Round { input, out } => {
                 let out = out.fmt_left();
                 writeln!(f, "{out} = round({input});")
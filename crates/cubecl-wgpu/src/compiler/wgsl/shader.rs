@@ -36,6 +36,11 @@ impl SharedMemory {
             alignment,
         }
     }
+
+    /// Size of the shared memory in bytes.
+    pub fn size_bytes(&self) -> usize {
+        self.size as usize * self.item.vectorization_factor() * self.item.elem().size()
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -215,6 +220,14 @@ fn {}(
 }
 
 impl ComputeShader {
+    /// Total size in bytes of the workgroup memory declared by the shader.
+    pub fn shared_memory_size(&self) -> usize {
+        self.shared_memories
+            .iter()
+            .map(SharedMemory::size_bytes)
+            .sum()
+    }
+
     fn format_bindings(
         f: &mut core::fmt::Formatter<'_>,
         prefix: &str,
@@ -24,6 +24,16 @@ pub enum Subgroup {
         rhs: Variable,
         out: Variable,
     },
+    ShuffleUp {
+        lhs: Variable,
+        rhs: Variable,
+        out: Variable,
+    },
+    ShuffleDown {
+        lhs: Variable,
+        rhs: Variable,
+        out: Variable,
+    },
     Sum {
         input: Variable,
         out: Variable,
@@ -56,6 +66,59 @@ pub enum Subgroup {
         input: Variable,
         out: Variable,
     },
+    Partition {
+        op: PartitionOp,
+        input: Variable,
+        partition_size: u32,
+        out: Variable,
+    },
+}
+
+/// Reduction or scan computed by a [`Subgroup::Partition`].
+///
+/// WGSL has no clustered subgroup operations, so partitioned reductions are emulated with a
+/// butterfly of `subgroupShuffleXor` and partitioned scans with `subgroupShuffleUp`, in a helper
+/// function registered as an extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartitionOp {
+    Sum,
+    Prod,
+    Min,
+    Max,
+    InclusiveSum,
+    ExclusiveSum,
+    InclusiveProd,
+    ExclusiveProd,
+}
+
+impl PartitionOp {
+    /// Name of the helper function reducing or scanning a value of the given item.
+    pub fn function_name(&self, item: Item) -> String {
+        let op = match self {
+            PartitionOp::Sum => "sum",
+            PartitionOp::Prod => "prod",
+            PartitionOp::Min => "min",
+            PartitionOp::Max => "max",
+            PartitionOp::InclusiveSum => "inclusive_sum",
+            PartitionOp::ExclusiveSum => "exclusive_sum",
+            PartitionOp::InclusiveProd => "inclusive_prod",
+            PartitionOp::ExclusiveProd => "exclusive_prod",
+        };
+        let elem = item.elem();
+        let vectorization = item.vectorization_factor();
+        format!("plane_partition_{op}_{elem}_{vectorization}")
+    }
+
+    /// Whether the operation is a scan, which depends on the position of the unit in the plane.
+    pub fn is_scan(&self) -> bool {
+        matches!(
+            self,
+            PartitionOp::InclusiveSum
+                | PartitionOp::ExclusiveSum
+                | PartitionOp::InclusiveProd
+                | PartitionOp::ExclusiveProd
+        )
+    }
 }
 
 impl Display for Subgroup {
@@ -121,7 +184,27 @@ impl Display for Subgroup {
             }
             Subgroup::Broadcast { lhs, rhs, out } => {
                 let out = out.fmt_left();
-                writeln!(f, "{out} = subgroupBroadcast({lhs}, {rhs});")
+                // `subgroupBroadcast` needs a constant index, shuffle from any other index.
+                match matches!(rhs, Variable::ConstantScalar(..)) {
+                    true => writeln!(f, "{out} = subgroupBroadcast({lhs}, {rhs});"),
+                    false => writeln!(f, "{out} = subgroupShuffle({lhs}, {rhs});"),
+                }
+            }
+            // Shuffling from outside of the subgroup is indeterminate in WGSL, so those units keep
+            // their own value explicitly.
+            Subgroup::ShuffleUp { lhs, rhs, out } => {
+                let out = out.fmt_left();
+                writeln!(
+                    f,
+                    "{out} = select({lhs}, subgroupShuffleUp({lhs}, {rhs}), subgroup_invocation_id >= {rhs});"
+                )
+            }
+            Subgroup::ShuffleDown { lhs, rhs, out } => {
+                let out = out.fmt_left();
+                writeln!(
+                    f,
+                    "{out} = select({lhs}, subgroupShuffleDown({lhs}, {rhs}), subgroup_invocation_id + {rhs} < subgroup_size);"
+                )
             }
             Subgroup::Ballot { input, out } => {
                 let out = out.fmt_left();
@@ -159,6 +242,22 @@ impl Display for Subgroup {
                 let out = out.fmt_left();
                 writeln!(f, "{out} = subgroupMax({input});")
             }
+            Subgroup::Partition {
+                op,
+                input,
+                partition_size,
+                out,
+            } => {
+                let function = op.function_name(input.item());
+                let out = out.fmt_left();
+                match op.is_scan() {
+                    true => writeln!(
+                        f,
+                        "{out} = {function}({input}, {partition_size}u, subgroup_invocation_id);"
+                    ),
+                    false => writeln!(f, "{out} = {function}({input}, {partition_size}u);"),
+                }
+            }
         }
     }
 }
@@ -1,10 +1,11 @@
 use cubecl_core::{
-    MemoryConfiguration,
+    MemoryConfiguration, OutOfMemoryPolicy,
     server::{Binding, Handle},
 };
 use cubecl_runtime::{
     memory_management::{
-        MemoryDeviceProperties, MemoryManagement, MemoryPoolOptions, PoolType, StorageExclude,
+        AllocationError, MemoryDeviceProperties, MemoryManagement, MemoryPoolOptions, PoolType,
+        StorageExclude,
     },
     storage::ComputeStorage,
 };
@@ -24,6 +25,8 @@ impl WgpuMemManager {
         device: wgpu::Device,
         memory_properties: MemoryDeviceProperties,
         memory_config: MemoryConfiguration,
+        memory_limit: Option<u64>,
+        on_oom: OutOfMemoryPolicy,
     ) -> Self {
         // Allocate storage & memory management for the main memory buffers. Any calls
         // to empty() or create() with a small enough size will be allocated from this
@@ -40,6 +43,8 @@ impl WgpuMemManager {
             &memory_properties,
             memory_config.clone(),
         );
+        memory_main.set_memory_limit(memory_limit);
+        memory_main.set_out_of_memory_policy(on_oom);
 
         // Memory pool for timing queries.
         let memory_pool_queries = MemoryManagement::from_configuration(
@@ -95,6 +100,11 @@ impl WgpuMemManager {
         Handle::new(self.memory_pool.reserve(size, exclude), None, None, size)
     }
 
+    pub(crate) fn try_reserve(&mut self, size: u64) -> Result<Handle, AllocationError> {
+        let slice = self.memory_pool.try_reserve(size, None)?;
+        Ok(Handle::new(slice, None, None, size))
+    }
+
     pub(crate) fn get_resource(&mut self, binding: Binding) -> WgpuResource {
         match self.memory_pool.get(binding.memory.clone()) {
             Some(handle) => {
@@ -1,21 +1,27 @@
 use std::{future::Future, time::Duration};
 
 use super::WgpuResource;
-use super::{WgpuStorage, stream::WgpuStream};
-use crate::AutoCompiler;
+use super::{
+    WgpuStorage,
+    stream::{PushConstantOffsets, WgpuStream},
+};
+use crate::{AutoCompiler, backend::push_constant_offsets};
 use alloc::sync::Arc;
 use cubecl_common::future;
 use cubecl_core::benchmark::ProfileDuration;
 use cubecl_core::{
-    Feature, KernelId, MemoryConfiguration, WgpuCompilationOptions,
+    Feature, KernelId, MemoryConfiguration, OutOfMemoryPolicy, WgpuCompilationOptions,
     compute::DebugInformation,
     prelude::*,
-    server::{Binding, BindingWithMeta, Bindings, Handle},
+    server::{
+        Binding, BindingWithMeta, Bindings, CompilationError, CompileWarning, DeviceError, Handle,
+        HostCallback, KernelResources, LaunchInfo, LaunchObserver, LaunchObserverSlot,
+    },
 };
 use cubecl_runtime::TimeMeasurement;
 use cubecl_runtime::{
     debug::{DebugLogger, ProfileLevel},
-    memory_management::MemoryDeviceProperties,
+    memory_management::{AllocationError, MemoryDeviceProperties},
     server::{self, ComputeServer},
     storage::BindingResource,
 };
@@ -26,7 +32,8 @@ use wgpu::ComputePipeline;
 #[derive(Debug)]
 pub struct WgpuServer {
     pub(crate) device: wgpu::Device,
-    pipelines: HashMap<KernelId, Arc<ComputePipeline>>,
+    pipelines: HashMap<KernelId, (Arc<ComputePipeline>, CubeDim, Option<PushConstantOffsets>)>,
+    launch_observer: LaunchObserverSlot,
     logger: DebugLogger,
     duration_profiled: Option<Duration>,
     stream: WgpuStream,
@@ -40,6 +47,8 @@ impl WgpuServer {
     pub fn new(
         memory_properties: MemoryDeviceProperties,
         memory_config: MemoryConfiguration,
+        memory_limit: Option<u64>,
+        on_oom: OutOfMemoryPolicy,
         compilation_options: WgpuCompilationOptions,
         device: wgpu::Device,
         queue: wgpu::Queue,
@@ -54,6 +63,8 @@ impl WgpuServer {
             queue.clone(),
             memory_properties,
             memory_config,
+            memory_limit,
+            on_oom,
             tasks_max,
             time_measurement,
         );
@@ -62,6 +73,7 @@ impl WgpuServer {
             compilation_options,
             device,
             pipelines: HashMap::new(),
+            launch_observer: LaunchObserverSlot::default(),
             logger,
             duration_profiled: None,
             stream,
@@ -73,16 +85,17 @@ impl WgpuServer {
         &mut self,
         kernel: <Self as ComputeServer>::Kernel,
         mode: ExecutionMode,
-    ) -> Arc<ComputePipeline> {
+    ) -> Result<(Arc<ComputePipeline>, CubeDim, Option<PushConstantOffsets>), CompilationError>
+    {
         let mut kernel_id = kernel.id();
         kernel_id.mode(mode);
 
-        if let Some(pipeline) = self.pipelines.get(&kernel_id) {
-            return pipeline.clone();
+        if let Some(entry) = self.pipelines.get(&kernel_id) {
+            return Ok(entry.clone());
         }
 
         let mut compiler = compiler(self.backend);
-        let mut compile = compiler.compile(self, kernel, mode);
+        let mut compile = compiler.compile(self, kernel, mode)?;
 
         if self.logger.is_activated() {
             compile.debug_info = Some(DebugInformation::new(
@@ -113,10 +126,15 @@ impl WgpuServer {
         //         .expect("should launch the command");
         //     // std::process::exit(status.code().unwrap());
         // }
+        let cube_dim = compile.cube_dim;
+        let push_constants = push_constant_offsets(&compile);
         let pipeline = self.create_pipeline(compile, mode);
-        self.pipelines.insert(kernel_id.clone(), pipeline.clone());
+        self.pipelines.insert(
+            kernel_id.clone(),
+            (pipeline.clone(), cube_dim, push_constants.clone()),
+        );
 
-        pipeline
+        Ok((pipeline, cube_dim, push_constants))
     }
 }
 
@@ -133,6 +151,10 @@ impl ComputeServer for WgpuServer {
         self.stream.read_buffers(bindings)
     }
 
+    fn read_into(&mut self, binding: Binding, destination: &mut [u8]) {
+        self.stream.read_buffer_into(binding, destination)
+    }
+
     fn get_resource(&mut self, binding: Binding) -> BindingResource<WgpuResource> {
         let resource = self.stream.mem_manage.get_resource(binding.clone());
         BindingResource::new(binding, resource)
@@ -151,6 +173,41 @@ impl ComputeServer for WgpuServer {
         self.stream.empty(size as u64)
     }
 
+    fn try_empty(&mut self, size: usize) -> Result<server::Handle, AllocationError> {
+        self.stream.try_empty(size as u64)
+    }
+
+    unsafe fn register_external(&mut self, _ptr: u64, _size: u64) -> server::Handle {
+        panic!("wgpu buffers can't be created from raw device addresses")
+    }
+
+    fn fill(&mut self, binding: Binding, pattern: u32) {
+        self.stream.fill(binding, pattern)
+    }
+
+    fn write(&mut self, binding: Binding, data: &[u8]) {
+        self.stream.write(binding, data)
+    }
+
+    fn compile(
+        &mut self,
+        kernel: Self::Kernel,
+        mode: ExecutionMode,
+    ) -> Result<Vec<CompileWarning>, CompilationError> {
+        // Naga only reports errors, which fail the compilation.
+        self.pipeline(kernel, mode).map(|_| Vec::new())
+    }
+
+    fn kernel_resources(
+        &mut self,
+        kernel: Self::Kernel,
+        mode: ExecutionMode,
+    ) -> Result<KernelResources, CompilationError> {
+        // The resources of a pipeline aren't exposed by wgpu.
+        self.pipeline(kernel, mode)
+            .map(|_| KernelResources::default())
+    }
+
     unsafe fn execute(
         &mut self,
         kernel: Self::Kernel,
@@ -180,9 +237,25 @@ impl ComputeServer for WgpuServer {
             self.stream.start_profile();
         }
 
+        let kernel_name = kernel.name();
+        let mut kernel_id = kernel.id();
+        kernel_id.mode(mode);
+
         // Start execution.
-        let pipeline = self.pipeline(kernel, mode);
-        self.stream.register(pipeline, bindings, &count);
+        let compiled = !self.pipelines.contains_key(&kernel_id);
+        let (pipeline, cube_dim, push_constants) = self
+            .pipeline(kernel, mode)
+            .unwrap_or_else(|err| panic!("{err}"));
+        self.launch_observer.observe(|| LaunchInfo {
+            name: kernel_name,
+            id: kernel_id.stable_format(),
+            cube_count: count.clone(),
+            cube_dim,
+            bindings: &bindings,
+            compiled,
+        });
+        self.stream
+            .register(pipeline, push_constants.as_deref(), bindings, &count);
 
         // If profiling, write out results.
         if let Some(level) = profile_level {
@@ -215,6 +288,18 @@ impl ComputeServer for WgpuServer {
         }
     }
 
+    fn set_launch_observer(&mut self, observer: Option<LaunchObserver>) {
+        self.launch_observer.set(observer);
+    }
+
+    fn start_batch(&mut self) {
+        self.stream.start_batch();
+    }
+
+    fn end_batch(&mut self) {
+        self.stream.end_batch();
+    }
+
     fn flush(&mut self) {
         // End the current compute pass.
         self.stream.flush();
@@ -226,6 +311,23 @@ impl ComputeServer for WgpuServer {
         self.stream.sync()
     }
 
+    // Faults lose the device on wgpu, which is reported through its own error handling.
+    fn try_sync(&mut self) -> impl Future<Output = Result<(), DeviceError>> + 'static {
+        let sync = self.sync();
+        async move {
+            sync.await;
+            Ok(())
+        }
+    }
+
+    fn poll(&mut self) -> bool {
+        self.stream.poll()
+    }
+
+    fn on_complete(&mut self, callback: HostCallback) {
+        self.stream.on_complete(callback);
+    }
+
     fn start_profile(&mut self) {
         self.stream.start_profile();
     }
@@ -1,8 +1,8 @@
 use cubecl_core::{
-    CubeCount, MemoryConfiguration,
+    CubeCount, MemoryConfiguration, OutOfMemoryPolicy,
     benchmark::ProfileDuration,
     future,
-    server::{Binding, Bindings, Handle},
+    server::{Binding, Bindings, Handle, HostCallback},
 };
 use std::{future::Future, num::NonZero, pin::Pin, sync::Arc};
 
@@ -10,9 +10,16 @@ use web_time::{Duration, Instant};
 use wgpu::QuerySet;
 
 use super::{mem_manager::WgpuMemManager, poll::WgpuPoll};
-use cubecl_runtime::{TimeMeasurement, memory_management::MemoryDeviceProperties};
+use cubecl_runtime::{
+    TimeMeasurement,
+    memory_management::{AllocationError, MemoryDeviceProperties},
+};
 use wgpu::{ComputePipeline, QuerySetDescriptor, QueryType};
 
+/// Byte offsets of the scalar arrays in the push constants of a pipeline, in the order of the
+/// scalars.
+pub type PushConstantOffsets = Arc<[u32]>;
+
 #[derive(Debug)]
 enum KernelTimestamps {
     Device { query_set: QuerySet, init: bool },
@@ -26,9 +33,12 @@ pub struct WgpuStream {
 
     timestamps: KernelTimestamps,
     sync_buffer: Option<Handle>,
+    // Staging buffer of `read_buffer_into`, kept between reads and grown when too small.
+    read_staging_buffer: Option<wgpu::Buffer>,
     compute_pass: Option<wgpu::ComputePass<'static>>,
     tasks_count: usize,
     tasks_max: usize,
+    batch_depth: usize,
     device: wgpu::Device,
     queue: wgpu::Queue,
     encoder: wgpu::CommandEncoder,
@@ -38,18 +48,27 @@ pub struct WgpuStream {
 }
 
 impl WgpuStream {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         device: wgpu::Device,
         queue: wgpu::Queue,
         memory_properties: MemoryDeviceProperties,
         memory_config: MemoryConfiguration,
+        memory_limit: Option<u64>,
+        on_oom: OutOfMemoryPolicy,
         tasks_max: usize,
         time_measurement: TimeMeasurement,
     ) -> Self {
         let poll = WgpuPoll::new(device.clone());
 
         #[allow(unused_mut)]
-        let mut mem_manage = WgpuMemManager::new(device.clone(), memory_properties, memory_config);
+        let mut mem_manage = WgpuMemManager::new(
+            device.clone(),
+            memory_properties,
+            memory_config,
+            memory_limit,
+            on_oom,
+        );
 
         // Allocate a small buffer to use for synchronization.
         #[cfg(target_family = "wasm")]
@@ -71,8 +90,10 @@ impl WgpuStream {
             queue,
             tasks_count: 0,
             tasks_max,
+            batch_depth: 0,
             poll,
             sync_buffer,
+            read_staging_buffer: None,
             submission_load: SubmissionLoad::default(),
             time_measurement,
         }
@@ -81,6 +102,7 @@ impl WgpuStream {
     pub fn register(
         &mut self,
         pipeline: Arc<ComputePipeline>,
+        push_constants: Option<&[u32]>,
         bindings: Bindings,
         dispatch: &CubeCount,
     ) {
@@ -91,11 +113,15 @@ impl WgpuStream {
 
         let info = (!bindings.metadata.data.is_empty())
             .then(|| self.create(bytemuck::cast_slice(&bindings.metadata.data)));
-        let scalars = bindings
-            .scalars
-            .values()
-            .map(|s| self.create(s.data()))
-            .collect::<Vec<_>>();
+        let push_constants = push_constants.map(|offsets| push_constant_data(offsets, &bindings));
+        let scalars = match push_constants {
+            Some(_) => Vec::new(),
+            None => bindings
+                .scalars
+                .values()
+                .map(|s| self.create(s.data()))
+                .collect::<Vec<_>>(),
+        };
 
         // Store all the resources we'll be using. This could be eliminated if
         // there was a way to tie the lifetime of the resource to the memory handle.
@@ -162,6 +188,9 @@ impl WgpuStream {
 
         pass.set_pipeline(&pipeline);
         pass.set_bind_group(0, &bind_group, &[]);
+        if let Some(data) = &push_constants {
+            pass.set_push_constants(0, data);
+        }
 
         match dispatch {
             CubeCount::Static(x, y, z) => {
@@ -252,6 +281,67 @@ impl WgpuStream {
         }
     }
 
+    /// Copies the content of the binding into `destination`, blocking until the copy is done.
+    ///
+    /// The staging buffer is reused by the following reads, and only reallocated for a read larger
+    /// than every previous one.
+    pub fn read_buffer_into(&mut self, binding: Binding, destination: &mut [u8]) {
+        self.compute_pass = None;
+
+        // Copying into a buffer has to be 4 byte aligned. We can safely do so, as
+        // memory is 32 bytes aligned (see WgpuStorage).
+        let align = wgpu::COPY_BUFFER_ALIGNMENT;
+        let resource = self.mem_manage.get_resource(binding);
+        let size = resource.size() as usize;
+
+        assert_eq!(
+            destination.len(),
+            size,
+            "Destination of {} bytes doesn't match the {} bytes of the binding",
+            destination.len(),
+            size
+        );
+
+        let aligned_len = resource.size().div_ceil(align) * align;
+        let staging_buffer = match self.read_staging_buffer.take() {
+            Some(buffer) if buffer.size() >= aligned_len => buffer,
+            _ => self.device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("CubeCL Read Staging Buffer"),
+                size: aligned_len,
+                usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            }),
+        };
+        self.encoder.copy_buffer_to_buffer(
+            resource.buffer(),
+            resource.offset(),
+            &staging_buffer,
+            0,
+            aligned_len,
+        );
+
+        // Flush all commands to the queue, so GPU gets started on copying to the staging buffer.
+        self.flush();
+
+        let (sender, receiver) = async_channel::bounded(1);
+        staging_buffer
+            .slice(..aligned_len)
+            .map_async(wgpu::MapMode::Read, move |v| {
+                let _ = sender.try_send(v);
+            });
+
+        let poll = self.poll.start_polling();
+        future::block_on(receiver.recv())
+            .expect("Unable to receive buffer slice result.")
+            .expect("Failed to map buffer");
+        core::mem::drop(poll);
+
+        destination
+            .copy_from_slice(&staging_buffer.slice(..aligned_len).get_mapped_range()[0..size]);
+        staging_buffer.unmap();
+        self.read_staging_buffer = Some(staging_buffer);
+    }
+
     pub fn is_profiling(&self) -> bool {
         !matches!(self.timestamps, KernelTimestamps::Disabled)
     }
@@ -379,10 +469,31 @@ impl WgpuStream {
         }
     }
 
+    /// Submit the pending tasks and check whether the queue is done, without blocking.
+    pub fn poll(&mut self) -> bool {
+        self.flush();
+
+        match self.device.poll(wgpu::PollType::Poll) {
+            Ok(status) => status.is_queue_empty(),
+            Err(e) => panic!("wgpu: failed to poll the device. ({e})"),
+        }
+    }
+
+    /// Submit the pending tasks and invoke the callback once the queue is done with them, the next
+    /// time the device is polled.
+    pub fn on_complete(&mut self, callback: HostCallback) {
+        self.flush();
+        self.queue.on_submitted_work_done(callback);
+    }
+
     pub fn empty(&mut self, size: u64) -> Handle {
         self.mem_manage.reserve(size, false)
     }
 
+    pub fn try_empty(&mut self, size: u64) -> Result<Handle, AllocationError> {
+        self.mem_manage.try_reserve(size)
+    }
+
     pub fn create(&mut self, data: &[u8]) -> Handle {
         // Copying into a buffer has to be 4 byte aligned. We can safely do so, as
         // memory is 32 bytes aligned (see WgpuStorage).
@@ -423,11 +534,80 @@ impl WgpuStream {
         alloc
     }
 
+    pub fn fill(&mut self, binding: Binding, pattern: u32) {
+        // Filling has to be 4 byte aligned. We can safely do so, as
+        // memory is 32 bytes aligned (see WgpuStorage).
+        let align = wgpu::COPY_BUFFER_ALIGNMENT;
+        let resource = self.mem_manage.get_resource(binding);
+        let aligned_len = resource.size().div_ceil(align) * align;
+
+        // Clearing and copying are recorded on the encoder, in order with the kernels already
+        // registered, so the current compute pass has to end first.
+        if pattern == 0 {
+            self.compute_pass = None;
+            self.encoder
+                .clear_buffer(resource.buffer(), resource.offset(), Some(aligned_len));
+        } else {
+            // wgpu can only clear to zero, so other patterns are uploaded once and copied.
+            let data = pattern.to_le_bytes().repeat(aligned_len as usize / 4);
+            let source = self.create(&data);
+            let source = self.mem_manage.get_resource(source.binding());
+            self.compute_pass = None;
+            self.encoder.copy_buffer_to_buffer(
+                source.buffer(),
+                source.offset(),
+                resource.buffer(),
+                resource.offset(),
+                aligned_len,
+            );
+        }
+
+        self.tasks_count += 1;
+        self.flush_if_needed();
+    }
+
+    pub fn write(&mut self, binding: Binding, data: &[u8]) {
+        let align = wgpu::COPY_BUFFER_ALIGNMENT;
+        let resource = self.mem_manage.get_resource(binding);
+        assert_eq!(data.len() as u64, resource.size());
+        let aligned_len = resource.size().div_ceil(align) * align;
+
+        // The queue writes all happen before the kernels of the encoder, so the data is uploaded
+        // to a new buffer and copied on the encoder to stay in order with the kernels.
+        let source = self.create(data);
+        let source = self.mem_manage.get_resource(source.binding());
+        self.compute_pass = None;
+        self.encoder.copy_buffer_to_buffer(
+            source.buffer(),
+            source.offset(),
+            resource.buffer(),
+            resource.offset(),
+            aligned_len,
+        );
+
+        self.tasks_count += 1;
+        self.flush_if_needed();
+    }
+
     fn flush_if_needed(&mut self) {
         // Flush when there are too many tasks, or when too many handles are locked.
         // Locked handles should only accumulate in rare circumstances (where uniforms
         // are being created but no work is submitted).
-        if self.tasks_count >= self.tasks_max || self.mem_manage.needs_flush(self.tasks_max * 8) {
+        // Batched tasks are kept in the same encoder regardless of their count.
+        let tasks_full = self.batch_depth == 0 && self.tasks_count >= self.tasks_max;
+        if tasks_full || self.mem_manage.needs_flush(self.tasks_max * 8) {
+            self.flush();
+        }
+    }
+
+    pub fn start_batch(&mut self) {
+        self.batch_depth += 1;
+    }
+
+    pub fn end_batch(&mut self) {
+        self.batch_depth = self.batch_depth.saturating_sub(1);
+
+        if self.batch_depth == 0 {
             self.flush();
         }
     }
@@ -460,6 +640,21 @@ impl WgpuStream {
     }
 }
 
+/// Pack the scalars of the bindings at their offsets, padded to a multiple of 4 bytes.
+fn push_constant_data(offsets: &[u32], bindings: &Bindings) -> Vec<u8> {
+    let mut data = Vec::new();
+
+    for (scalar, offset) in bindings.scalars.values().zip(offsets) {
+        let offset = *offset as usize;
+        let bytes = &scalar.data()[..scalar.length * scalar.elem.size()];
+        data.resize(offset + bytes.len(), 0);
+        data[offset..].copy_from_slice(bytes);
+    }
+
+    data.resize(data.len().next_multiple_of(4), 0);
+    data
+}
+
 #[cfg(not(target_family = "wasm"))]
 mod __submission_load {
     #[derive(Default, Debug)]
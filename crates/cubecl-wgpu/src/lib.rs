@@ -31,10 +31,122 @@ mod tests {
     pub type TestRuntime = crate::WgpuRuntime;
 
     cubecl_core::testgen_all!();
+    cubecl_core::testgen_compilation!();
     cubecl_std::testgen!();
+
+    #[test]
+    fn test_native_line_sizes_of_f16() {
+        use cubecl_core::ir::{Elem, FloatKind};
+
+        let sizes =
+            <TestRuntime as cubecl_core::Runtime>::native_line_sizes(&Elem::Float(FloatKind::F16));
+
+        assert_eq!(sizes, [4, 2, 1]);
+    }
+
+    #[test]
+    fn test_kernel_name_is_the_entry_point() {
+        let client = TestRuntime::client(&Default::default());
+        cubecl_core::runtime_tests::compilation::test_kernel_name::<TestRuntime>(
+            client,
+            &mut crate::AutoCompiler::Wgsl(Default::default()),
+        );
+    }
+
+    #[test]
+    fn test_kernel_bindings_match_signature() {
+        let client = TestRuntime::client(&Default::default());
+        cubecl_core::runtime_tests::compilation::test_kernel_bindings::<TestRuntime>(
+            client,
+            &mut crate::AutoCompiler::Wgsl(Default::default()),
+        );
+    }
+
+    #[test]
+    fn test_roofline_estimate_counts_operations() {
+        let client = TestRuntime::client(&Default::default());
+        cubecl_core::runtime_tests::compilation::test_roofline_estimate::<TestRuntime>(
+            client,
+            &mut crate::AutoCompiler::Wgsl(Default::default()),
+        );
+    }
+
+    #[test]
+    fn test_memory_fences_are_unsupported_instructions() {
+        let client = TestRuntime::client(&Default::default());
+        cubecl_core::runtime_tests::compilation::test_unsupported_memory_fences::<TestRuntime>(
+            client,
+            &mut crate::AutoCompiler::Wgsl(Default::default()),
+            "WGSL",
+        );
+    }
+
+    #[test]
+    fn test_inline_helper_is_expanded_in_caller() {
+        cubecl_core::runtime_tests::compilation::test_inline_helper::<TestRuntime>(
+            &mut crate::AutoCompiler::Wgsl(Default::default()),
+        );
+    }
+
+    #[test]
+    fn test_cmma_is_an_unsupported_instruction() {
+        let client = TestRuntime::client(&Default::default());
+        cubecl_core::runtime_tests::compilation::test_unsupported_instruction::<TestRuntime>(
+            client,
+            &mut crate::AutoCompiler::Wgsl(Default::default()),
+            "WGSL",
+        );
+    }
+
+    #[test]
+    fn test_setup_builder_with_required_limits() {
+        let limits = wgpu::Limits {
+            max_storage_buffer_binding_size: 256 * 1024 * 1024,
+            max_compute_invocations_per_workgroup: 1024,
+            ..wgpu::Limits::downlevel_defaults()
+        };
+        let setup = crate::WgpuSetup::builder()
+            .required_limits(limits.clone())
+            .power_preference(wgpu::PowerPreference::HighPerformance)
+            .adapter_filter(|info| info.device_type != wgpu::DeviceType::Cpu)
+            .build::<crate::AutoGraphicsApi>();
+        assert!(limits.check_limits(&setup.device.limits()));
+
+        let device = crate::init_device(setup, Default::default());
+        let features = TestRuntime::features(&device);
+        assert!(features.max_units_per_cube >= 1024);
+
+        let client = TestRuntime::client(&device);
+        let max_page_size = client.properties().memory_properties().max_page_size;
+        assert!(max_page_size >= limits.max_storage_buffer_binding_size as u64);
+    }
+
+    #[test]
+    fn test_client_on_existing_device() {
+        use cubecl_common::future::block_on;
+
+        let instance = wgpu::Instance::default();
+        let adapter =
+            block_on(instance.request_adapter(&Default::default())).expect("No adapter available");
+        let (device, queue) = block_on(adapter.request_device(&wgpu::DeviceDescriptor {
+            required_features: adapter.features(),
+            required_limits: adapter.limits(),
+            ..Default::default()
+        }))
+        .unwrap();
+
+        let cube_device = TestRuntime::from_existing(device, queue, adapter, Default::default());
+        let client = TestRuntime::client(&cube_device);
+        cubecl_core::runtime_tests::launch::test_kernel_without_generics::<TestRuntime>(client);
+    }
+
     cubecl_linalg::testgen_matmul_tiling2d!([flex32, f32]);
     cubecl_linalg::testgen_matmul_simple!([flex32, f32]);
     cubecl_linalg::testgen_tensor_identity!([flex32, f32, u32]);
+    cubecl_linalg::testgen_tensor_transpose!([flex32, f32, u32]);
+    cubecl_linalg::testgen_tensor_reinterpret!();
+    cubecl_linalg::testgen_tensor_contiguous!();
+    cubecl_linalg::testgen_tensor_elemwise!();
     cubecl_reduce::testgen_reduce!();
     cubecl_reduce::testgen_shared_sum!([f32]);
 }
@@ -47,7 +159,123 @@ mod tests_spirv {
     use half::f16;
 
     cubecl_core::testgen_all!(f32: [f16, flex32, f32, f64], i32: [i8, i16, i32, i64], u32: [u8, u16, u32, u64]);
+    cubecl_core::testgen_compilation!();
     cubecl_std::testgen!();
+
+    #[test]
+    fn test_kernel_name_is_the_entry_point() {
+        let client = TestRuntime::client(&Default::default());
+        cubecl_core::runtime_tests::compilation::test_kernel_name::<TestRuntime>(
+            client,
+            &mut crate::AutoCompiler::SpirV(Default::default()),
+        );
+    }
+
+    #[test]
+    fn test_kernel_bindings_match_signature() {
+        let client = TestRuntime::client(&Default::default());
+        cubecl_core::runtime_tests::compilation::test_kernel_bindings::<TestRuntime>(
+            client,
+            &mut crate::AutoCompiler::SpirV(Default::default()),
+        );
+    }
+
+    #[test]
+    fn test_roofline_estimate_counts_operations() {
+        let client = TestRuntime::client(&Default::default());
+        cubecl_core::runtime_tests::compilation::test_roofline_estimate::<TestRuntime>(
+            client,
+            &mut crate::AutoCompiler::SpirV(Default::default()),
+        );
+    }
+
+    #[test]
+    fn test_memory_fences_emit_memory_barriers() {
+        let client = TestRuntime::client(&Default::default());
+        cubecl_core::runtime_tests::compilation::test_memory_fences::<TestRuntime>(
+            client,
+            &mut crate::AutoCompiler::SpirV(Default::default()),
+            |_, _| "OpMemoryBarrier",
+        );
+    }
+
+    #[test]
+    fn test_inline_helper_has_no_debug_function() {
+        cubecl_core::runtime_tests::compilation::test_inline_helper_debug_info::<TestRuntime>(
+            &mut crate::AutoCompiler::SpirV(Default::default()),
+        );
+    }
+
+    #[test]
+    fn test_inline_helper_is_expanded_in_caller() {
+        cubecl_core::runtime_tests::compilation::test_inline_helper::<TestRuntime>(
+            &mut crate::AutoCompiler::SpirV(Default::default()),
+        );
+    }
+
+    #[test]
+    fn test_debug_symbols_emit_debug_lines() {
+        cubecl_core::runtime_tests::compilation::test_debug_line_info::<TestRuntime>(
+            &mut crate::AutoCompiler::SpirV(Default::default()),
+            "NonSemantic.Shader.DebugInfo.100",
+        );
+    }
+
+    #[test]
+    fn test_push_constants_declare_push_constant_block() {
+        use crate::vulkan::{CompileTarget, compile_for_target};
+        use cubecl_core::ExecutionMode;
+
+        let client = TestRuntime::client(&Default::default());
+        let kernel =
+            cubecl_core::runtime_tests::launch::push_constants_kernel::<TestRuntime>(&client);
+        let compiled =
+            compile_for_target(kernel, CompileTarget::default(), ExecutionMode::Checked).unwrap();
+
+        assert!(compiled.source.contains("PushConstant"));
+    }
+
+    #[test]
+    fn test_compile_for_other_targets() {
+        use crate::vulkan::{CompileTarget, compile_for_target};
+        use cubecl_core::ExecutionMode;
+
+        let client = TestRuntime::client(&Default::default());
+
+        for float_controls2 in [false, true] {
+            let kernel =
+                cubecl_core::runtime_tests::compilation::fast_math_kernel::<TestRuntime>(&client);
+            let target = CompileTarget { float_controls2 };
+            let compiled = compile_for_target(kernel, target, ExecutionMode::Checked).unwrap();
+
+            assert_eq!(
+                compiled.source.contains("SPV_KHR_float_controls2"),
+                float_controls2
+            );
+        }
+    }
+
+    #[cfg(feature = "spirv-val")]
+    #[test]
+    fn test_validation_catches_malformed_spirv() {
+        use crate::vulkan::{CompileTarget, compile_for_target, validate_spirv};
+        use cubecl_core::ExecutionMode;
+
+        let client = TestRuntime::client(&Default::default());
+        let kernel =
+            cubecl_core::runtime_tests::launch::push_constants_kernel::<TestRuntime>(&client);
+        let compiled =
+            compile_for_target(kernel, CompileTarget::default(), ExecutionMode::Checked).unwrap();
+        let mut words = compiled.repr.unwrap().as_spirv().unwrap().assemble();
+        assert!(validate_spirv(&words).is_ok());
+
+        // Shrink the id bound of the header below the ids used by the module.
+        words[3] = 1;
+        let err = validate_spirv(&words).unwrap_err();
+
+        assert!(err.to_string().contains("SPIR-V Validation"));
+    }
+
     cubecl_linalg::testgen_matmul_tiling2d!([f16, f32, f64]);
     cubecl_linalg::testgen_matmul_simple!([f32]);
     cubecl_linalg::testgen_matmul_accelerated!([f16]);
@@ -63,6 +291,37 @@ mod tests_msl {
 
     cubecl_core::testgen_all!(f32: [f16, f32], i32: [i16, i32], u32: [u16, u32]);
     cubecl_std::testgen!();
+
+    #[test]
+    fn test_cmma_shapes_are_simdgroup_matrices() {
+        let features = TestRuntime::features(&Default::default());
+
+        assert_eq!(features.cmma_shapes, vec![(8, 8, 8)]);
+    }
+
+    #[test]
+    fn test_matmul_selects_simdgroup_matrices() {
+        use cubecl_linalg::matmul::{
+            components::{MatmulProblem, MatmulSize, MatrixLayout, tile::accelerated::Accelerated},
+            kernels::matmul::matmul_selection,
+        };
+
+        let client = TestRuntime::client(&Default::default());
+        let problem = MatmulProblem {
+            m: 256,
+            n: 256,
+            k: 256,
+            batches: (vec![], vec![]),
+            lhs_layout: MatrixLayout::RowMajor,
+            rhs_layout: MatrixLayout::RowMajor,
+            lhs_line_size: 4,
+            rhs_line_size: 4,
+            out_line_size: 4,
+        };
+        let selection = matmul_selection::<Accelerated, f16, TestRuntime>(&client, &problem, 32);
+
+        assert_eq!(selection.tile_shape, MatmulSize { m: 8, n: 8, k: 8 });
+    }
     cubecl_linalg::testgen_matmul_tiling2d!([f16, f32]);
     cubecl_linalg::testgen_matmul_simple!([f16, f32]);
     cubecl_linalg::testgen_matmul_accelerated!([f16]);
@@ -4,17 +4,17 @@ use crate::{
 };
 use cubecl_common::future;
 use cubecl_core::{
-    AtomicFeature, CubeDim, DeviceId, Feature, Runtime,
+    AtomicFeature, CubeDim, DeviceId, Feature, Runtime, WgpuCompilationOptions,
     ir::{Elem, FloatKind},
 };
-pub use cubecl_runtime::memory_management::MemoryConfiguration;
+pub use cubecl_runtime::memory_management::{MemoryConfiguration, OutOfMemoryPolicy};
+use cubecl_runtime::{BackendInfo, DeviceProperties, memory_management::HardwareProperties};
 use cubecl_runtime::{
     ComputeRuntime, TimeMeasurement,
     channel::MutexComputeChannel,
     client::ComputeClient,
     debug::{DebugLogger, ProfileLevel},
 };
-use cubecl_runtime::{DeviceProperties, memory_management::HardwareProperties};
 use cubecl_runtime::{memory_management::MemoryDeviceProperties, storage::ComputeStorage};
 use wgpu::{InstanceFlags, RequestAdapterOptions};
 
@@ -76,6 +76,13 @@ impl Runtime for WgpuRuntime {
         }
     }
 
+    fn native_line_sizes(elem: &Elem) -> Vec<u8> {
+        // WGSL, SPIR-V and MSL vectors have at most 4 components, wider lines are emulated.
+        Self::line_size_elem(elem)
+            .filter(|size| *size <= 4)
+            .collect()
+    }
+
     fn max_cube_count() -> (u32, u32, u32) {
         let max_dim = u16::MAX as u32;
         (max_dim, max_dim, max_dim)
@@ -100,6 +107,14 @@ pub struct RuntimeOptions {
     pub tasks_max: usize,
     /// Configures the memory management.
     pub memory_config: MemoryConfiguration,
+    /// Maximum number of bytes of device memory to reserve, `None` for no limit.
+    ///
+    /// Allocations beyond this limit fail, after releasing unused memory and retrying with the
+    /// default [policy](Self::on_oom). See
+    /// [try_empty](cubecl_runtime::client::ComputeClient::try_empty) to handle the failure.
+    pub memory_limit: Option<u64>,
+    /// What to do when an allocation would exceed the memory limit, unused without a limit.
+    pub on_oom: OutOfMemoryPolicy,
 }
 
 impl Default for RuntimeOptions {
@@ -119,6 +134,8 @@ impl Default for RuntimeOptions {
         Self {
             tasks_max,
             memory_config: MemoryConfiguration::default(),
+            memory_limit: None,
+            on_oom: OutOfMemoryPolicy::default(),
         }
     }
 }
@@ -150,6 +167,11 @@ pub struct WgpuSetup {
 /// This function generates a new, globally unique ID for the device every time it is called,
 /// even if called on the same device multiple times.
 pub fn init_device(setup: WgpuSetup, options: RuntimeOptions) -> WgpuDevice {
+    register_client(create_client_on_setup(setup, options))
+}
+
+/// Register a client created on an external setup under a new, globally unique device ID.
+fn register_client(client: ComputeClient<Server, MutexComputeChannel<Server>>) -> WgpuDevice {
     use core::sync::atomic::{AtomicU32, Ordering};
 
     static COUNTER: AtomicU32 = AtomicU32::new(0);
@@ -160,11 +182,44 @@ pub fn init_device(setup: WgpuSetup, options: RuntimeOptions) -> WgpuDevice {
     }
 
     let device_id = WgpuDevice::Existing(device_id);
-    let client = create_client_on_setup(setup, options);
     RUNTIME.register(&device_id, client);
     device_id
 }
 
+impl WgpuRuntime {
+    /// Create a [`WgpuDevice`] running on a wgpu `device` and `queue` created by the caller, e.g.
+    /// by a rendering engine, so that compute and graphics share the same device and resources.
+    ///
+    /// The `adapter` must be the one the device was requested from. The features and limits of
+    /// the runtime are the ones the `device` was requested with, so kernels only use what was
+    /// enabled on it; the adapter is only queried for its info and, with `spirv`, the extended
+    /// Vulkan features. Request the device with the [features](wgpu::Adapter::features) and
+    /// [limits](wgpu::Adapter::limits) of the adapter that kernels may use, e.g.
+    /// [`SHADER_F16`](wgpu::Features::SHADER_F16) or the subgroup sizes.
+    ///
+    /// # Synchronization
+    ///
+    /// CubeCL submits its work to the shared `queue` in batches of at most
+    /// [`tasks_max`](RuntimeOptions::tasks_max) tasks, so kernels launched through the client
+    /// may not be submitted yet when the launch returns. Work is executed in queue submission
+    /// order: call [`flush`](ComputeClient::flush) before submitting work from external code that
+    /// depends on CubeCL kernels, and submit external work before launching CubeCL kernels that
+    /// depend on it. Reading results back with the client waits for every submitted task.
+    ///
+    /// Like [`init_device`], every call returns a new device with its own memory pools, so the
+    /// same wgpu device should only be wrapped once.
+    pub fn from_existing(
+        device: wgpu::Device,
+        queue: wgpu::Queue,
+        adapter: wgpu::Adapter,
+        options: RuntimeOptions,
+    ) -> WgpuDevice {
+        let backend = adapter.get_info().backend;
+        let client = create_client(&adapter, device, queue, backend, options);
+        register_client(client)
+    }
+}
+
 /// Like [`init_setup_async`], but synchronous.
 /// On wasm, it is necessary to use [`init_setup_async`] instead.
 pub fn init_setup<G: GraphicsApi>(device: &WgpuDevice, options: RuntimeOptions) -> WgpuSetup {
@@ -192,42 +247,232 @@ pub async fn init_setup_async<G: GraphicsApi>(
     return_setup
 }
 
+impl WgpuSetup {
+    /// Start building a setup with custom requirements on the adapter and device, to register
+    /// with [`init_device`].
+    pub fn builder() -> WgpuSetupBuilder {
+        WgpuSetupBuilder::default()
+    }
+}
+
+/// Builder of a [`WgpuSetup`] that picks the adapter meeting the required features and limits.
+///
+/// The device is created like with [`init_setup`], requesting every feature and the highest
+/// limits of the adapter along with the backend specific extensions, so the required features
+/// and limits are a minimum the adapter must support.
+#[derive(Default)]
+pub struct WgpuSetupBuilder {
+    required_features: wgpu::Features,
+    required_limits: Option<wgpu::Limits>,
+    power_preference: wgpu::PowerPreference,
+    adapter_filter: Option<Box<dyn Fn(&wgpu::AdapterInfo) -> bool>>,
+}
+
+impl WgpuSetupBuilder {
+    /// Features the adapter must support.
+    pub fn required_features(mut self, features: wgpu::Features) -> Self {
+        self.required_features = features;
+        self
+    }
+
+    /// Limits the adapter must support.
+    pub fn required_limits(mut self, limits: wgpu::Limits) -> Self {
+        self.required_limits = Some(limits);
+        self
+    }
+
+    /// Which kind of adapter to prefer when multiple meet the requirements.
+    pub fn power_preference(mut self, preference: wgpu::PowerPreference) -> Self {
+        self.power_preference = preference;
+        self
+    }
+
+    /// Only consider the adapters for which the predicate returns true.
+    pub fn adapter_filter(mut self, filter: impl Fn(&wgpu::AdapterInfo) -> bool + 'static) -> Self {
+        self.adapter_filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Like [`build_async`](Self::build_async), but synchronous.
+    /// On wasm, it is necessary to use [`build_async`](Self::build_async) instead.
+    pub fn build<G: GraphicsApi>(self) -> WgpuSetup {
+        cfg_if::cfg_if! {
+            if #[cfg(target_family = "wasm")] {
+                let _ = self;
+                panic!("Creating a wgpu setup synchronously is unsupported on wasm. Use build_async instead");
+            } else {
+                future::block_on(self.build_async::<G>())
+            }
+        }
+    }
+
+    /// Select the adapter and create the device.
+    ///
+    /// # Panics
+    ///
+    /// If no adapter of the graphics API meets the requirements.
+    pub async fn build_async<G: GraphicsApi>(self) -> WgpuSetup {
+        let backend = G::backend();
+        let instance = create_instance(backend);
+        let adapter = self.select_adapter(&instance, backend).await;
+        let (device, queue) = backend::request_device(&adapter).await;
+
+        log::info!(
+            "Created wgpu compute server on device {:?} => {:?}",
+            device,
+            adapter.get_info()
+        );
+
+        WgpuSetup {
+            instance,
+            adapter,
+            device,
+            queue,
+            backend,
+        }
+    }
+
+    fn is_suitable(&self, adapter: &wgpu::Adapter) -> bool {
+        let meets_limits = match &self.required_limits {
+            Some(limits) => limits.check_limits(&adapter.limits()),
+            None => true,
+        };
+        let passes_filter = match &self.adapter_filter {
+            Some(filter) => filter(&adapter.get_info()),
+            None => true,
+        };
+
+        adapter.features().contains(self.required_features) && meets_limits && passes_filter
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    async fn select_adapter(
+        &self,
+        instance: &wgpu::Instance,
+        backend: wgpu::Backend,
+    ) -> wgpu::Adapter {
+        let adapters = instance.enumerate_adapters(backend.into());
+        let infos = adapters
+            .iter()
+            .map(|adapter| adapter.get_info())
+            .collect::<Vec<_>>();
+
+        // Sorting is stable, so adapters of the same type keep the order of the system.
+        let mut suitable = adapters
+            .into_iter()
+            .filter(|adapter| self.is_suitable(adapter))
+            .collect::<Vec<_>>();
+        suitable.sort_by_key(|adapter| self.rank(adapter.get_info().device_type));
+
+        if suitable.is_empty() {
+            panic!("No adapter meets the requirements of the setup, adapters {infos:?}");
+        }
+        suitable.remove(0)
+    }
+
+    #[cfg(target_family = "wasm")]
+    async fn select_adapter(
+        &self,
+        instance: &wgpu::Instance,
+        _backend: wgpu::Backend,
+    ) -> wgpu::Adapter {
+        let adapter = instance
+            .request_adapter(&RequestAdapterOptions {
+                power_preference: self.power_preference,
+                force_fallback_adapter: false,
+                compatible_surface: None,
+            })
+            .await
+            .expect("No possible adapter available for backend.");
+
+        if !self.is_suitable(&adapter) {
+            panic!(
+                "The adapter {:?} doesn't meet the requirements of the setup",
+                adapter.get_info()
+            );
+        }
+        adapter
+    }
+
+    /// Order of preference of the adapter types, lowest first.
+    #[cfg(not(target_family = "wasm"))]
+    fn rank(&self, device_type: wgpu::DeviceType) -> u8 {
+        use wgpu::DeviceType;
+
+        let order: [DeviceType; 3] = match self.power_preference {
+            wgpu::PowerPreference::HighPerformance => [
+                DeviceType::DiscreteGpu,
+                DeviceType::IntegratedGpu,
+                DeviceType::VirtualGpu,
+            ],
+            wgpu::PowerPreference::LowPower => [
+                DeviceType::IntegratedGpu,
+                DeviceType::DiscreteGpu,
+                DeviceType::VirtualGpu,
+            ],
+            wgpu::PowerPreference::None => return 0,
+        };
+
+        order
+            .iter()
+            .position(|ty| *ty == device_type)
+            .unwrap_or(order.len()) as u8
+    }
+}
+
 pub(crate) fn create_client_on_setup(
     setup: WgpuSetup,
     options: RuntimeOptions,
 ) -> ComputeClient<WgpuServer, MutexComputeChannel<WgpuServer>> {
-    let limits = setup.device.limits();
-    let adapter_limits = setup.adapter.limits();
+    create_client(
+        &setup.adapter,
+        setup.device,
+        setup.queue,
+        setup.backend,
+        options,
+    )
+}
+
+fn create_client(
+    adapter: &wgpu::Adapter,
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    backend: wgpu::Backend,
+    options: RuntimeOptions,
+) -> ComputeClient<WgpuServer, MutexComputeChannel<WgpuServer>> {
+    let limits = device.limits();
 
     let mem_props = MemoryDeviceProperties {
         max_page_size: limits.max_storage_buffer_binding_size as u64,
         alignment: WgpuStorage::ALIGNMENT.max(limits.min_storage_buffer_offset_alignment as u64),
     };
-    let max_count = adapter_limits.max_compute_workgroups_per_dimension;
+    let max_count = limits.max_compute_workgroups_per_dimension;
     let hardware_props = HardwareProperties {
-        plane_size_min: adapter_limits.min_subgroup_size,
-        plane_size_max: adapter_limits.max_subgroup_size,
+        plane_size_min: limits.min_subgroup_size,
+        plane_size_max: limits.max_subgroup_size,
         max_bindings: limits.max_storage_buffers_per_shader_stage,
         max_shared_memory_size: limits.max_compute_workgroup_storage_size as usize,
         max_cube_count: CubeDim::new_3d(max_count, max_count, max_count),
-        max_units_per_cube: adapter_limits.max_compute_invocations_per_workgroup,
+        max_units_per_cube: limits.max_compute_invocations_per_workgroup,
         max_cube_dim: CubeDim::new_3d(
-            adapter_limits.max_compute_workgroup_size_x,
-            adapter_limits.max_compute_workgroup_size_y,
-            adapter_limits.max_compute_workgroup_size_z,
+            limits.max_compute_workgroup_size_x,
+            limits.max_compute_workgroup_size_y,
+            limits.max_compute_workgroup_size_z,
         ),
         num_streaming_multiprocessors: None,
         num_tensor_cores: None,
+        peak_flops: None,
+        peak_memory_bandwidth: None,
+        max_registers_per_cube: None,
     };
 
-    let mut compilation_options = Default::default();
+    let mut compilation_options = WgpuCompilationOptions {
+        max_shared_memory_size: Some(hardware_props.max_shared_memory_size),
+        ..Default::default()
+    };
 
-    let features = setup.adapter.features();
-    let time_measurement = match setup
-        .device
-        .features()
-        .contains(wgpu::Features::TIMESTAMP_QUERY)
-    {
+    let features = device.features();
+    let time_measurement = match features.contains(wgpu::Features::TIMESTAMP_QUERY) {
         #[cfg(target_family = "wasm")]
         true => TimeMeasurement::Device, // Still unstable, but the only one that works on wasm.
         #[cfg(not(target_family = "wasm"))]
@@ -241,25 +486,44 @@ pub(crate) fn create_client_on_setup(
     // Workaround: WebGPU does support subgroups and correctly reports this, but wgpu
     // doesn't plumb through this info. Instead min/max are just reported as 0, which can cause issues.
     // For now just disable subgroups on WebGPU, until this information is added.
-    let fake_plane_info =
-        adapter_limits.min_subgroup_size == 0 && adapter_limits.max_subgroup_size == 0;
+    let fake_plane_info = limits.min_subgroup_size == 0 && limits.max_subgroup_size == 0;
 
     if features.contains(wgpu::Features::SUBGROUP)
-        && setup.adapter.get_info().device_type != wgpu::DeviceType::Cpu
+        && adapter.get_info().device_type != wgpu::DeviceType::Cpu
         && !fake_plane_info
     {
         device_props.register_feature(Feature::Plane);
+        compilation_options.plane_size_min = Some(limits.min_subgroup_size);
     }
-    backend::register_features(&setup.adapter, &mut device_props, &mut compilation_options);
+    backend::register_features(
+        adapter,
+        &device,
+        &mut device_props,
+        &mut compilation_options,
+    );
+
+    let info = adapter.get_info();
+    let driver_version = match info.driver_info.is_empty() {
+        true => info.driver.clone(),
+        false => format!("{} {}", info.driver, info.driver_info),
+    };
+    device_props.set_backend_info(BackendInfo {
+        name: info.backend.to_str().to_string(),
+        device_name: info.name,
+        api_version: backend::api_version(adapter).unwrap_or_default(),
+        driver_version,
+    });
 
     let server = WgpuServer::new(
         mem_props,
         options.memory_config,
+        options.memory_limit,
+        options.on_oom,
         compilation_options,
-        setup.device.clone(),
-        setup.queue,
+        device,
+        queue,
         options.tasks_max,
-        setup.backend,
+        backend,
         time_measurement,
     );
     let channel = MutexComputeChannel::new(server);
@@ -271,7 +535,7 @@ pub(crate) fn create_client_on_setup(
         device_props.register_feature(Feature::AtomicFloat(AtomicFeature::Add));
     }
 
-    ComputeClient::new(channel, device_props, setup.backend)
+    ComputeClient::new(channel, device_props, backend)
 }
 
 /// Select the wgpu device and queue based on the provided [device](WgpuDevice) and
@@ -298,10 +562,7 @@ pub(crate) async fn create_setup_for_device(
     }
 }
 
-async fn request_adapter(
-    device: &WgpuDevice,
-    backend: wgpu::Backend,
-) -> (wgpu::Instance, wgpu::Adapter) {
+fn create_instance(backend: wgpu::Backend) -> wgpu::Instance {
     let debug = DebugLogger::default();
     let instance_flags = match (debug.profile_level(), debug.is_activated()) {
         (Some(ProfileLevel::Full), _) => InstanceFlags::advanced_debugging(),
@@ -309,11 +570,18 @@ async fn request_adapter(
         (_, false) => InstanceFlags::default(),
     };
     log::debug!("{instance_flags:?}");
-    let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor {
+    wgpu::Instance::new(&wgpu::InstanceDescriptor {
         backends: backend.into(),
         flags: instance_flags,
         ..Default::default()
-    });
+    })
+}
+
+async fn request_adapter(
+    device: &WgpuDevice,
+    backend: wgpu::Backend,
+) -> (wgpu::Instance, wgpu::Adapter) {
+    let instance = create_instance(backend);
 
     #[allow(deprecated)]
     let override_device = if matches!(
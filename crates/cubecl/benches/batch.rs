@@ -0,0 +1,101 @@
+use cubecl::prelude::*;
+use cubecl::server::Handle;
+
+use cubecl::benchmark::{Benchmark, TimingMethod};
+use cubecl::future;
+
+#[cube(launch)]
+fn increment(values: &mut Array<f32>) {
+    if ABSOLUTE_POS < values.len() {
+        values[ABSOLUTE_POS] += 1.0;
+    }
+}
+
+impl<R: Runtime> Benchmark for BatchBench<R> {
+    type Args = Handle;
+
+    fn prepare(&self) -> Self::Args {
+        self.client.empty(self.num_elems * size_of::<f32>())
+    }
+
+    fn execute(&self, values: Self::Args) {
+        if self.batched {
+            self.client
+                .batch(|client| self.launch_all(client, &values, false));
+        } else {
+            self.launch_all(&self.client, &values, true);
+        }
+    }
+
+    fn name(&self) -> String {
+        let client = R::client(&self.device);
+        let mode = if self.batched {
+            "batched"
+        } else {
+            "individual"
+        };
+
+        format!("batch-{}-{mode}-{}", R::name(&client), self.num_kernels).to_lowercase()
+    }
+
+    fn sync(&self) {
+        future::block_on(self.client.sync())
+    }
+}
+
+impl<R: Runtime> BatchBench<R> {
+    /// Launches all the tiny kernels, submitting each one right away when `submit_each` is set.
+    fn launch_all(
+        &self,
+        client: &ComputeClient<R::Server, R::Channel>,
+        values: &Handle,
+        submit_each: bool,
+    ) {
+        for _ in 0..self.num_kernels {
+            increment::launch::<R>(
+                client,
+                CubeCount::Static(1, 1, 1),
+                CubeDim::new_1d(self.num_elems as u32),
+                unsafe { ArrayArg::from_raw_parts::<f32>(values, self.num_elems, 1) },
+            );
+
+            if submit_each {
+                client.flush();
+            }
+        }
+    }
+}
+
+#[allow(dead_code)]
+struct BatchBench<R: Runtime> {
+    num_kernels: usize,
+    num_elems: usize,
+    batched: bool,
+    device: R::Device,
+    client: ComputeClient<R::Server, R::Channel>,
+}
+
+#[allow(dead_code)]
+fn run<R: Runtime>(device: R::Device, batched: bool) {
+    let client = R::client(&device);
+    let bench = BatchBench::<R> {
+        num_kernels: 64,
+        num_elems: 256,
+        batched,
+        client,
+        device,
+    };
+    println!("{}", bench.name());
+    println!("{}", bench.run(TimingMethod::Full));
+}
+
+fn main() {
+    #[cfg(feature = "cuda")]
+    run::<cubecl::cuda::CudaRuntime>(Default::default(), false);
+    #[cfg(feature = "cuda")]
+    run::<cubecl::cuda::CudaRuntime>(Default::default(), true);
+    #[cfg(feature = "wgpu")]
+    run::<cubecl::wgpu::WgpuRuntime>(Default::default(), false);
+    #[cfg(feature = "wgpu")]
+    run::<cubecl::wgpu::WgpuRuntime>(Default::default(), true);
+}
@@ -0,0 +1,88 @@
+use cubecl::{frontend, prelude::*};
+use std::marker::PhantomData;
+
+use cubecl::benchmark::{Benchmark, TimingMethod};
+use cubecl::future;
+use cubecl_linalg::tensor::{self, TensorHandle};
+
+impl<R: Runtime, E: Float> Benchmark for AddBench<R, E> {
+    type Args = (TensorHandle<R, E>, TensorHandle<R, E>, TensorHandle<R, E>);
+
+    fn prepare(&self) -> Self::Args {
+        let client = R::client(&self.device);
+        let lhs = TensorHandle::zeros(&client, self.shape.clone());
+        let rhs = TensorHandle::zeros(&client, self.shape.clone());
+        let out = TensorHandle::zeros(&client, self.shape.clone());
+
+        (lhs, rhs, out)
+    }
+
+    fn execute(&self, (lhs, rhs, out): Self::Args) {
+        match self.strided {
+            true => tensor::add_strided::<R, E>(
+                &self.client,
+                &lhs.as_ref(),
+                &rhs.as_ref(),
+                &out.as_ref(),
+            ),
+            false => tensor::add::<R, E>(&self.client, &lhs.as_ref(), &rhs.as_ref(), &out.as_ref()),
+        }
+    }
+
+    fn name(&self) -> String {
+        let client = R::client(&self.device);
+        let path = match self.strided {
+            true => "strided",
+            false => "contiguous",
+        };
+
+        format!(
+            "add-{}-{}-{}",
+            R::name(&client),
+            E::as_elem_native_unchecked(),
+            path
+        )
+        .to_lowercase()
+    }
+
+    fn sync(&self) {
+        future::block_on(self.client.sync())
+    }
+
+    fn profile(&self, args: Self::Args) -> cubecl::benchmark::ProfileDuration {
+        self.client.profile(|| self.execute(args))
+    }
+}
+
+#[allow(dead_code)]
+struct AddBench<R: Runtime, E> {
+    shape: Vec<usize>,
+    strided: bool,
+    device: R::Device,
+    client: ComputeClient<R::Server, R::Channel>,
+    _e: PhantomData<E>,
+}
+
+/// Adds contiguous tensors with both the contiguous fast path and the general strided path.
+#[allow(dead_code)]
+fn run<R: Runtime, E: frontend::Float>(device: R::Device) {
+    for strided in [false, true] {
+        let client = R::client(&device);
+        let bench = AddBench::<R, E> {
+            shape: vec![32, 512, 2048],
+            strided,
+            client,
+            device: device.clone(),
+            _e: PhantomData,
+        };
+        println!("{}", bench.name());
+        println!("{}", bench.run(TimingMethod::DeviceOnly));
+    }
+}
+
+fn main() {
+    #[cfg(feature = "cuda")]
+    run::<cubecl::cuda::CudaRuntime, f32>(Default::default());
+    #[cfg(feature = "wgpu")]
+    run::<cubecl::wgpu::WgpuRuntime, f32>(Default::default());
+}
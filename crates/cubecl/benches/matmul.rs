@@ -157,5 +157,10 @@ fn main() {
     #[cfg(feature = "wgpu-msl")]
     {
         run_benches::<cubecl::wgpu::WgpuRuntime, half::f16>();
+        // Scalar path, to compare with the simdgroup matrices used by the accelerated kernels.
+        run::<cubecl::wgpu::WgpuRuntime, half::f16>(
+            Default::default(),
+            matmul::Strategy::Tiling2D(Default::default()),
+        );
     }
 }
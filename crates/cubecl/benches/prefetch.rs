@@ -0,0 +1,111 @@
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use cubecl::benchmark::{Benchmark, TimingMethod};
+use cubecl::future;
+use cubecl_linalg::tensor::TensorHandle;
+
+/// Each unit sums `chunks` values that are `out.len()` elements apart, so every iteration waits
+/// on a global memory load.
+#[cube(launch)]
+fn sum_chunks(
+    input: &Tensor<f32>,
+    out: &mut Tensor<f32>,
+    #[comptime] chunks: u32,
+    #[comptime] prefetch_next: bool,
+) {
+    if ABSOLUTE_POS < out.len() {
+        let stride = out.len();
+        let mut acc = 0.0f32;
+        for i in 0..chunks {
+            let index = i * stride + ABSOLUTE_POS;
+            if prefetch_next {
+                if i + 1 < chunks {
+                    prefetch(input, index + stride, 4u32);
+                }
+            }
+            acc += input[index];
+        }
+        out[ABSOLUTE_POS] = acc;
+    }
+}
+
+impl<R: Runtime> Benchmark for PrefetchBench<R> {
+    type Args = (TensorHandle<R, f32>, TensorHandle<R, f32>);
+
+    fn prepare(&self) -> Self::Args {
+        let client = R::client(&self.device);
+        let input = TensorHandle::zeros(&client, vec![self.chunks as usize, self.num_elems]);
+        let out = TensorHandle::zeros(&client, vec![self.num_elems]);
+
+        (input, out)
+    }
+
+    fn execute(&self, (input, out): Self::Args) {
+        let cube_dim = CubeDim::new(256, 1, 1);
+        let cube_count = calculate_cube_count_elemwise(self.num_elems, cube_dim);
+
+        sum_chunks::launch::<R>(
+            &self.client,
+            cube_count,
+            cube_dim,
+            input.as_arg(1),
+            out.as_arg(1),
+            self.chunks,
+            self.prefetch,
+        )
+    }
+
+    fn name(&self) -> String {
+        let client = R::client(&self.device);
+
+        format!(
+            "prefetch-{}-{}-{}",
+            R::name(&client),
+            self.chunks,
+            if self.prefetch { "on" } else { "off" }
+        )
+        .to_lowercase()
+    }
+
+    fn sync(&self) {
+        future::block_on(self.client.sync())
+    }
+
+    fn profile(&self, args: Self::Args) -> cubecl::benchmark::ProfileDuration {
+        self.client.profile(|| self.execute(args))
+    }
+}
+
+#[allow(dead_code)]
+struct PrefetchBench<R: Runtime> {
+    num_elems: usize,
+    chunks: u32,
+    prefetch: bool,
+    device: R::Device,
+    client: ComputeClient<R::Server, R::Channel>,
+}
+
+#[allow(dead_code)]
+fn run<R: Runtime>(device: R::Device, prefetch: bool) {
+    let client = R::client(&device);
+    let bench = PrefetchBench::<R> {
+        num_elems: 1024 * 1024,
+        chunks: 64,
+        prefetch,
+        client,
+        device,
+    };
+    println!("{}", bench.name());
+    println!("{}", bench.run(TimingMethod::DeviceOnly));
+}
+
+fn main() {
+    #[cfg(feature = "cuda")]
+    run::<cubecl::cuda::CudaRuntime>(Default::default(), false);
+    #[cfg(feature = "cuda")]
+    run::<cubecl::cuda::CudaRuntime>(Default::default(), true);
+    #[cfg(feature = "wgpu")]
+    run::<cubecl::wgpu::WgpuRuntime>(Default::default(), false);
+    #[cfg(feature = "wgpu")]
+    run::<cubecl::wgpu::WgpuRuntime>(Default::default(), true);
+}
@@ -0,0 +1,100 @@
+use cubecl::prelude::*;
+use cubecl::server::Handle;
+
+use cubecl::benchmark::{Benchmark, TimingMethod};
+use cubecl::future;
+
+#[cube(launch)]
+fn offset_push(values: &mut Array<u32>, iteration: Push<u32>) {
+    if ABSOLUTE_POS < values.len() {
+        values[ABSOLUTE_POS] += iteration;
+    }
+}
+
+#[cube(launch)]
+fn offset_scalar(values: &mut Array<u32>, iteration: u32) {
+    if ABSOLUTE_POS < values.len() {
+        values[ABSOLUTE_POS] += iteration;
+    }
+}
+
+impl<R: Runtime> Benchmark for PushConstantsBench<R> {
+    type Args = Handle;
+
+    fn prepare(&self) -> Self::Args {
+        self.client.empty(self.num_elems * size_of::<u32>())
+    }
+
+    fn execute(&self, values: Self::Args) {
+        for iteration in 0..self.num_launches as u32 {
+            let cube_count = CubeCount::Static(1, 1, 1);
+            let cube_dim = CubeDim::new_1d(self.num_elems as u32);
+            let values = unsafe { ArrayArg::from_raw_parts::<u32>(&values, self.num_elems, 1) };
+
+            if self.push {
+                offset_push::launch::<R>(
+                    &self.client,
+                    cube_count,
+                    cube_dim,
+                    values,
+                    ScalarArg::new(iteration),
+                );
+            } else {
+                offset_scalar::launch::<R>(
+                    &self.client,
+                    cube_count,
+                    cube_dim,
+                    values,
+                    ScalarArg::new(iteration),
+                );
+            }
+        }
+    }
+
+    fn name(&self) -> String {
+        let client = R::client(&self.device);
+        let mode = if self.push { "push" } else { "scalar" };
+
+        format!(
+            "push-constants-{}-{mode}-{}",
+            R::name(&client),
+            self.num_launches
+        )
+        .to_lowercase()
+    }
+
+    fn sync(&self) {
+        future::block_on(self.client.sync())
+    }
+}
+
+#[allow(dead_code)]
+struct PushConstantsBench<R: Runtime> {
+    num_launches: usize,
+    num_elems: usize,
+    push: bool,
+    device: R::Device,
+    client: ComputeClient<R::Server, R::Channel>,
+}
+
+#[allow(dead_code)]
+fn run<R: Runtime>(device: R::Device, push: bool) {
+    let client = R::client(&device);
+    let bench = PushConstantsBench::<R> {
+        num_launches: 256,
+        num_elems: 64,
+        push,
+        client,
+        device,
+    };
+    println!("{}", bench.name());
+    println!("{}", bench.run(TimingMethod::Full));
+}
+
+fn main() {
+    // Push constants are only used by the Vulkan backend.
+    #[cfg(feature = "wgpu-spirv")]
+    run::<cubecl::wgpu::WgpuRuntime>(Default::default(), false);
+    #[cfg(feature = "wgpu-spirv")]
+    run::<cubecl::wgpu::WgpuRuntime>(Default::default(), true);
+}
@@ -0,0 +1,124 @@
+use cubecl::prelude::*;
+use std::marker::PhantomData;
+
+use cubecl::benchmark::{Benchmark, TimingMethod};
+use cubecl::future;
+use cubecl_linalg::tensor::TensorHandle;
+use cubecl_reduce::{ReduceStrategy, instructions::Sum};
+
+impl<R: Runtime, E: Float> Benchmark for ReduceBench<R, E> {
+    type Args = (TensorHandle<R, E>, TensorHandle<R, E>);
+
+    fn prepare(&self) -> Self::Args {
+        let client = R::client(&self.device);
+        let input = TensorHandle::zeros(&client, self.shape.clone());
+        let mut output_shape = self.shape.clone();
+        output_shape[self.axis] = 1;
+        let output = TensorHandle::zeros(&client, output_shape);
+
+        (input, output)
+    }
+
+    fn execute(&self, (input, output): Self::Args) {
+        cubecl_reduce::reduce::<R, E, E, Sum>(
+            &self.client,
+            input.as_ref(),
+            output.as_ref(),
+            self.axis,
+            Some(self.strategy),
+            (),
+        )
+        .unwrap();
+    }
+
+    fn name(&self) -> String {
+        let client = R::client(&self.device);
+
+        format!(
+            "reduce-{}-{}-axis{}-{}",
+            R::name(&client),
+            E::as_elem_native_unchecked(),
+            self.axis,
+            self.label
+        )
+        .to_lowercase()
+    }
+
+    fn sync(&self) {
+        future::block_on(self.client.sync())
+    }
+
+    fn profile(&self, args: Self::Args) -> cubecl::benchmark::ProfileDuration {
+        self.client.profile(|| self.execute(args))
+    }
+}
+
+#[allow(dead_code)]
+struct ReduceBench<R: Runtime, E> {
+    shape: Vec<usize>,
+    axis: usize,
+    strategy: ReduceStrategy,
+    label: &'static str,
+    device: R::Device,
+    client: ComputeClient<R::Server, R::Channel>,
+    _e: PhantomData<E>,
+}
+
+#[allow(dead_code)]
+fn run<R: Runtime, E: Float>(
+    device: R::Device,
+    axis: usize,
+    strategy: ReduceStrategy,
+    label: &'static str,
+) {
+    let client = R::client(&device);
+    let bench = ReduceBench::<R, E> {
+        shape: vec![4096, 4096],
+        axis,
+        strategy,
+        label,
+        client,
+        device,
+        _e: PhantomData,
+    };
+    println!("{}", bench.name());
+    println!("{}", bench.run(TimingMethod::DeviceOnly));
+}
+
+#[allow(dead_code)]
+fn run_all<R: Runtime, E: Float>(device: R::Device) {
+    let client = R::client(&device);
+    let cube = ReduceStrategy::new::<R>(&client, true);
+    let unit = ReduceStrategy {
+        use_planes: false,
+        shared: false,
+        split_strided: false,
+    };
+    let split = ReduceStrategy {
+        split_strided: true,
+        ..unit
+    };
+
+    // The last axis is contiguous and the first one strided, splitting slices only applies to the
+    // strided one.
+    for axis in [1, 0] {
+        run::<R, E>(device.clone(), axis, cube, "cube");
+        if cube.use_planes {
+            run::<R, E>(
+                device.clone(),
+                axis,
+                ReduceStrategy::new::<R>(&client, false),
+                "plane",
+            );
+        }
+        run::<R, E>(device.clone(), axis, unit, "unit");
+    }
+    run::<R, E>(device, 0, split, "split");
+}
+
+fn main() {
+    #[cfg(feature = "cuda")]
+    run_all::<cubecl::cuda::CudaRuntime, f32>(Default::default());
+    #[cfg(feature = "wgpu")]
+    run_all::<cubecl::wgpu::WgpuRuntime, f32>(Default::default());
+}
@@ -0,0 +1,166 @@
+use cubecl::prelude::*;
+use std::marker::PhantomData;
+
+use cubecl::benchmark::{Benchmark, TimingMethod};
+use cubecl::future;
+use cubecl_linalg::tensor::{self, TensorHandle};
+
+#[cube(launch)]
+fn transpose_naive<F: Float>(input: &Tensor<F>, output: &mut Tensor<F>) {
+    let rows = input.shape(0);
+    let cols = input.shape(1);
+
+    if ABSOLUTE_POS_Y < rows && ABSOLUTE_POS_X < cols {
+        output[ABSOLUTE_POS_X * output.stride(0) + ABSOLUTE_POS_Y] =
+            input[ABSOLUTE_POS_Y * input.stride(0) + ABSOLUTE_POS_X];
+    }
+}
+
+/// Tiled transpose through a shared memory tile without padding, so reading a column of the tile
+/// has bank conflicts. [TransposeKind::Tiled] uses a padded tile instead.
+#[cube(launch)]
+fn transpose_tiled_unpadded<F: Float>(
+    input: &Tensor<F>,
+    output: &mut Tensor<F>,
+    #[comptime] tile_size: u32,
+) {
+    let rows = input.shape(0);
+    let cols = input.shape(1);
+    let mut tile = SharedMemory::<F>::new(tile_size * tile_size);
+
+    let tile_row = CUBE_POS_Y * tile_size;
+    let tile_col = CUBE_POS_X * tile_size;
+
+    for j in range_stepped(0, tile_size, CUBE_DIM_Y) {
+        let row = tile_row + UNIT_POS_Y + j;
+        let col = tile_col + UNIT_POS_X;
+        if row < rows && col < cols {
+            tile[(UNIT_POS_Y + j) * tile_size + UNIT_POS_X] = input[row * input.stride(0) + col];
+        }
+    }
+
+    sync_cube();
+
+    for j in range_stepped(0, tile_size, CUBE_DIM_Y) {
+        let row = tile_col + UNIT_POS_Y + j;
+        let col = tile_row + UNIT_POS_X;
+        if row < cols && col < rows {
+            output[row * output.stride(0) + col] = tile[UNIT_POS_X * tile_size + UNIT_POS_Y + j];
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum TransposeKind {
+    Naive,
+    TiledUnpadded,
+    Tiled,
+}
+
+impl<R: Runtime, E: Float> Benchmark for TransposeBench<R, E> {
+    type Args = (TensorHandle<R, E>, TensorHandle<R, E>);
+
+    fn prepare(&self) -> Self::Args {
+        let client = R::client(&self.device);
+        let input = TensorHandle::zeros(&client, self.shape.clone());
+        let output = TensorHandle::zeros(&client, vec![self.shape[1], self.shape[0]]);
+
+        (input, output)
+    }
+
+    fn execute(&self, (input, output): Self::Args) {
+        match self.kind {
+            TransposeKind::Naive => {
+                let cube_dim = CubeDim::new_2d(16, 16);
+                let cube_count = CubeCount::new_2d(
+                    (self.shape[1] as u32).div_ceil(cube_dim.x),
+                    (self.shape[0] as u32).div_ceil(cube_dim.y),
+                );
+
+                transpose_naive::launch::<E, R>(
+                    &self.client,
+                    cube_count,
+                    cube_dim,
+                    input.as_arg(1),
+                    output.as_arg(1),
+                )
+            }
+            TransposeKind::TiledUnpadded => {
+                let tile_size = 32;
+                let cube_dim = CubeDim::new_2d(tile_size, 8);
+                let cube_count = CubeCount::new_2d(
+                    (self.shape[1] as u32).div_ceil(tile_size),
+                    (self.shape[0] as u32).div_ceil(tile_size),
+                );
+
+                transpose_tiled_unpadded::launch::<E, R>(
+                    &self.client,
+                    cube_count,
+                    cube_dim,
+                    input.as_arg(1),
+                    output.as_arg(1),
+                    tile_size,
+                )
+            }
+            TransposeKind::Tiled => tensor::transpose::launch(&self.client, &input, &output),
+        }
+    }
+
+    fn name(&self) -> String {
+        let client = R::client(&self.device);
+
+        format!(
+            "transpose-{}-{}-{:?}",
+            R::name(&client),
+            E::as_elem_native_unchecked(),
+            self.kind
+        )
+        .to_lowercase()
+    }
+
+    fn sync(&self) {
+        future::block_on(self.client.sync())
+    }
+
+    fn profile(&self, args: Self::Args) -> cubecl::benchmark::ProfileDuration {
+        self.client.profile(|| self.execute(args))
+    }
+}
+
+#[allow(dead_code)]
+struct TransposeBench<R: Runtime, E> {
+    shape: Vec<usize>,
+    kind: TransposeKind,
+    device: R::Device,
+    client: ComputeClient<R::Server, R::Channel>,
+    _e: PhantomData<E>,
+}
+
+#[allow(dead_code)]
+fn run<R: Runtime, E: Float>(device: R::Device, kind: TransposeKind) {
+    let client = R::client(&device);
+    let bench = TransposeBench::<R, E> {
+        shape: vec![4096, 4095],
+        kind,
+        client,
+        device,
+        _e: PhantomData,
+    };
+    println!("{}", bench.name());
+    println!("{}", bench.run(TimingMethod::DeviceOnly));
+}
+
+fn main() {
+    #[cfg(feature = "cuda")]
+    run::<cubecl::cuda::CudaRuntime, f32>(Default::default(), TransposeKind::Naive);
+    #[cfg(feature = "cuda")]
+    run::<cubecl::cuda::CudaRuntime, f32>(Default::default(), TransposeKind::TiledUnpadded);
+    #[cfg(feature = "cuda")]
+    run::<cubecl::cuda::CudaRuntime, f32>(Default::default(), TransposeKind::Tiled);
+    #[cfg(feature = "wgpu")]
+    run::<cubecl::wgpu::WgpuRuntime, f32>(Default::default(), TransposeKind::Naive);
+    #[cfg(feature = "wgpu")]
+    run::<cubecl::wgpu::WgpuRuntime, f32>(Default::default(), TransposeKind::TiledUnpadded);
+    #[cfg(feature = "wgpu")]
+    run::<cubecl::wgpu::WgpuRuntime, f32>(Default::default(), TransposeKind::Tiled);
+}
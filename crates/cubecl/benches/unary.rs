@@ -95,6 +95,11 @@ fn run<R: Runtime, E: frontend::Float>(device: R::Device, vectorization: u8) {
 }
 
 fn main() {
+    // Line size 1 uses scalar half math, line sizes 2 and more use packed `__half2` math.
+    #[cfg(feature = "cuda")]
+    run::<cubecl::cuda::CudaRuntime, f16>(Default::default(), 1);
+    #[cfg(feature = "cuda")]
+    run::<cubecl::cuda::CudaRuntime, f16>(Default::default(), 2);
     #[cfg(feature = "cuda")]
     run::<cubecl::cuda::CudaRuntime, f16>(Default::default(), 8);
     #[cfg(feature = "cuda")]